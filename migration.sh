@@ -1,9 +1,14 @@
-diesel migration revert
-diesel migration run
+# MIGRATION_DIR=migrations_postgres for a PostgreSQL database
+MIGRATION_DIR=${MIGRATION_DIR:-migrations}
+if [ "$MIGRATION_DIR" = "migrations_postgres" ]; then
+    BACKEND=postgres
+else
+    BACKEND=mysql
+fi
 
-mv src/db/schema.rs src/db/schema_old.rs
+diesel migration revert --migration-dir $MIGRATION_DIR
+diesel migration run --migration-dir $MIGRATION_DIR
 
+# each backend keeps the schema diesel prints for it, src/db/mod.rs picks one by feature
 diesel print-schema > schema.rs
-mv schema.rs src/db/schema.rs
-
-rm src/db/schema_old.rs
\ No newline at end of file
+mv schema.rs src/db/schema_$BACKEND.rs
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE games_2v2 DROP COLUMN replay_state;
+
+ALTER TABLE competitions
+    DROP COLUMN replay_compress_after,
+    DROP COLUMN replay_delete_after;
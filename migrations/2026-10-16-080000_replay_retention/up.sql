@@ -0,0 +1,6 @@
+ALTER TABLE competitions
+    ADD COLUMN replay_compress_after    INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN replay_delete_after      INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE games_2v2
+    ADD COLUMN replay_state             VARCHAR(255) NOT NULL DEFAULT 'STORED';
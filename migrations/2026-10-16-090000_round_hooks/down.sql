@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE round_hooks;
+DROP TABLE round_events;
\ No newline at end of file
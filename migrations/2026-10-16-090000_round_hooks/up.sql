@@ -0,0 +1,18 @@
+CREATE TABLE round_hooks (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    kind            VARCHAR(255) NOT NULL,
+    target          TEXT NOT NULL,
+    timeout_secs    INTEGER NOT NULL,
+    created         DATETIME NOT NULL
+);
+
+CREATE TABLE round_events (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    round           INTEGER NOT NULL,
+    kind            VARCHAR(255) NOT NULL,
+    status          VARCHAR(255) NOT NULL,
+    message         TEXT NOT NULL,
+    created         DATETIME NOT NULL
+);
\ No newline at end of file
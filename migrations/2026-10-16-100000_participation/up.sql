@@ -0,0 +1,11 @@
+CREATE TABLE participations (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    owner                   VARCHAR(255) NOT NULL,
+    partner                 VARCHAR(255) NOT NULL,
+    submitted_working_bot   BOOLEAN NOT NULL,
+    games_played            INTEGER NOT NULL,
+    created                 DATETIME NOT NULL
+);
\ No newline at end of file
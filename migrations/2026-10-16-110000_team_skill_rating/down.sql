@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE teams
+    DROP COLUMN rating_mu,
+    DROP COLUMN rating_sigma;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE teams
+    ADD COLUMN rating_mu        DOUBLE NOT NULL DEFAULT 25.0,
+    ADD COLUMN rating_sigma     DOUBLE NOT NULL DEFAULT 8.333333333333334;
\ No newline at end of file
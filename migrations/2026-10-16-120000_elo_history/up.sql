@@ -0,0 +1,9 @@
+CREATE TABLE elo_history (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    team_id         VARCHAR(255) NOT NULL,
+    competition_id  VARCHAR(255) NOT NULL,
+    round           INTEGER NOT NULL,
+    elo             INTEGER NOT NULL,
+    elo_change      INTEGER NOT NULL,
+    created         DATETIME NOT NULL
+);
\ No newline at end of file
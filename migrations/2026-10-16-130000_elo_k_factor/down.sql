@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    DROP COLUMN elo_k_factor,
+    DROP COLUMN provisional_games,
+    DROP COLUMN provisional_k_factor;
\ No newline at end of file
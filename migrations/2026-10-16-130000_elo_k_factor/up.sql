@@ -0,0 +1,4 @@
+ALTER TABLE competitions
+    ADD COLUMN elo_k_factor             INTEGER NOT NULL DEFAULT 16,
+    ADD COLUMN provisional_games        INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN provisional_k_factor     INTEGER NOT NULL DEFAULT 32;
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE round_leniencies;
+
+ALTER TABLE games_2v2
+    DROP COLUMN timeout_secs,
+    DROP COLUMN attempts;
\ No newline at end of file
@@ -0,0 +1,13 @@
+CREATE TABLE round_leniencies (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    from_round      INTEGER NOT NULL,
+    to_round        INTEGER NOT NULL,
+    timeout_secs    INTEGER NOT NULL,
+    crash_retries   INTEGER NOT NULL,
+    created         DATETIME NOT NULL
+);
+
+ALTER TABLE games_2v2
+    ADD COLUMN timeout_secs     INTEGER NOT NULL DEFAULT 120,
+    ADD COLUMN attempts         INTEGER NOT NULL DEFAULT 1;
\ No newline at end of file
@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    DROP COLUMN scoring_system,
+    DROP COLUMN points_win,
+    DROP COLUMN points_draw,
+    DROP COLUMN points_loss,
+    DROP COLUMN points_bye;
+
+ALTER TABLE games_2v2
+    DROP COLUMN team1_score,
+    DROP COLUMN team2_score;
\ No newline at end of file
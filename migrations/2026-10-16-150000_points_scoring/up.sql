@@ -0,0 +1,10 @@
+ALTER TABLE competitions
+    ADD COLUMN scoring_system   VARCHAR(255) NOT NULL DEFAULT 'ELO',
+    ADD COLUMN points_win       INTEGER NOT NULL DEFAULT 3,
+    ADD COLUMN points_draw      INTEGER NOT NULL DEFAULT 1,
+    ADD COLUMN points_loss      INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN points_bye       INTEGER NOT NULL DEFAULT 3;
+
+ALTER TABLE games_2v2
+    ADD COLUMN team1_score      INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN team2_score      INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
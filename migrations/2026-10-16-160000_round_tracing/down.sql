@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE trace_spans;
+
+ALTER TABLE games_2v2
+    DROP COLUMN trace_id;
+
+ALTER TABLE round_events
+    DROP COLUMN trace_id;
\ No newline at end of file
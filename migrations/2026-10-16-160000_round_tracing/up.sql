@@ -0,0 +1,19 @@
+CREATE TABLE trace_spans (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    trace_id        VARCHAR(255) NOT NULL,
+    competition_id  VARCHAR(255) NOT NULL,
+    name            VARCHAR(255) NOT NULL,
+    status          VARCHAR(255) NOT NULL,
+    detail          TEXT NOT NULL,
+    started         DATETIME(3) NOT NULL,
+    finished        DATETIME(3) NOT NULL,
+    duration_ms     BIGINT NOT NULL
+);
+
+CREATE INDEX trace_spans_trace_id ON trace_spans (trace_id);
+
+ALTER TABLE games_2v2
+    ADD COLUMN trace_id         VARCHAR(255) NOT NULL DEFAULT '';
+
+ALTER TABLE round_events
+    ADD COLUMN trace_id         VARCHAR(255) NOT NULL DEFAULT '';
\ No newline at end of file
@@ -0,0 +1,26 @@
+-- This file should undo anything in `up.sql`
+UPDATE games_2v2 g SET additional_data = (
+    SELECT JSON_OBJECTAGG(s.slot, JSON_OBJECT(
+        'turns_played', s.turns_played,
+        'survived', IF(s.survived, CAST('true' AS JSON), CAST('false' AS JSON)),
+        'fleet_generated', s.fleet_generated,
+        'fleet_lost', s.fleet_lost,
+        'fleet_reinforced', s.fleet_reinforced,
+        'largest_attack', s.largest_attack,
+        'largest_loss', s.largest_loss,
+        'largest_reinforcement', s.largest_reinforcement,
+        'planets_lost', s.planets_lost,
+        'planets_conquered', s.planets_conquered,
+        'planets_defended', s.planets_defended,
+        'planets_attacked', s.planets_attacked,
+        'num_fleet_lost', s.num_fleet_lost,
+        'num_fleet_reinforced', s.num_fleet_reinforced,
+        'num_fleet_generated', s.num_fleet_generated,
+        'total_troops_generated', s.total_troops_generated
+    ))
+    FROM game_player_stats s
+    WHERE s.game_id = g.id
+)
+WHERE g.id IN (SELECT game_id FROM game_player_stats);
+
+DROP TABLE game_player_stats;
\ No newline at end of file
@@ -0,0 +1,61 @@
+CREATE TABLE game_player_stats (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    game_id                 VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    bot_id                  VARCHAR(255) NOT NULL,
+    slot                    VARCHAR(255) NOT NULL,
+    turns_played            INTEGER NOT NULL,
+    survived                BOOLEAN NOT NULL,
+    fleet_generated         INTEGER NOT NULL,
+    fleet_lost              INTEGER NOT NULL,
+    fleet_reinforced        INTEGER NOT NULL,
+    largest_attack          INTEGER NOT NULL,
+    largest_loss            INTEGER NOT NULL,
+    largest_reinforcement   INTEGER NOT NULL,
+    planets_lost            INTEGER NOT NULL,
+    planets_conquered       INTEGER NOT NULL,
+    planets_defended        INTEGER NOT NULL,
+    planets_attacked        INTEGER NOT NULL,
+    num_fleet_lost          INTEGER NOT NULL,
+    num_fleet_reinforced    INTEGER NOT NULL,
+    num_fleet_generated     INTEGER NOT NULL,
+    total_troops_generated  INTEGER NOT NULL,
+    created                 DATETIME NOT NULL
+);
+
+CREATE INDEX game_player_stats_game_id ON game_player_stats (game_id);
+CREATE INDEX game_player_stats_team_id ON game_player_stats (team_id);
+
+-- move the stats of already played games out of the additional_data JSON
+INSERT INTO game_player_stats (id, game_id, team_id, bot_id, slot, turns_played, survived, fleet_generated, fleet_lost, fleet_reinforced, largest_attack, largest_loss, largest_reinforcement, planets_lost, planets_conquered, planets_defended, planets_attacked, num_fleet_lost, num_fleet_reinforced, num_fleet_generated, total_troops_generated, created)
+SELECT
+    UUID(), s.game_id, s.team_id, s.bot_id, s.slot,
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.turns_played')) AS SIGNED), 0),
+    JSON_UNQUOTE(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.survived'))) = 'true',
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.fleet_generated')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.fleet_lost')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.fleet_reinforced')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.largest_attack')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.largest_loss')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.largest_reinforcement')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.planets_lost')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.planets_conquered')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.planets_defended')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.planets_attacked')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.num_fleet_lost')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.num_fleet_reinforced')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.num_fleet_generated')) AS SIGNED), 0),
+    COALESCE(CAST(JSON_EXTRACT(s.data, CONCAT('$.', s.slot, '.total_troops_generated')) AS SIGNED), 0),
+    s.created
+FROM (
+    SELECT id AS game_id, team1_id AS team_id, team1bot1_id AS bot_id, 'team1bot1' AS slot, additional_data AS data, created FROM games_2v2
+    UNION ALL
+    SELECT id AS game_id, team1_id AS team_id, team1bot2_id AS bot_id, 'team1bot2' AS slot, additional_data AS data, created FROM games_2v2
+    UNION ALL
+    SELECT id AS game_id, team2_id AS team_id, team2bot1_id AS bot_id, 'team2bot1' AS slot, additional_data AS data, created FROM games_2v2
+    UNION ALL
+    SELECT id AS game_id, team2_id AS team_id, team2bot2_id AS bot_id, 'team2bot2' AS slot, additional_data AS data, created FROM games_2v2
+) AS s
+WHERE JSON_VALID(s.data) AND JSON_CONTAINS_PATH(s.data, 'one', CONCAT('$.', s.slot));
+
+UPDATE games_2v2 SET additional_data = '' WHERE id IN (SELECT game_id FROM game_player_stats);
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TABLE practice_bots (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    bot_id          VARCHAR(255) NOT NULL,
+    team_id         VARCHAR(255) NOT NULL,
+    competition_id  VARCHAR(255) NOT NULL,
+    name            VARCHAR(255) NOT NULL,
+    anonymous       BOOLEAN NOT NULL,
+    created         DATETIME NOT NULL
+);
\ No newline at end of file
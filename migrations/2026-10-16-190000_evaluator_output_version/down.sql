@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE games_2v2
+    DROP COLUMN output_version;
\ No newline at end of file
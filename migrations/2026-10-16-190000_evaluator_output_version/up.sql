@@ -0,0 +1,2 @@
+ALTER TABLE games_2v2
+    ADD COLUMN output_version   VARCHAR(16) NOT NULL DEFAULT 'v1';
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE round_stats;
+
+ALTER TABLE games_2v2
+    DROP COLUMN duration_ms;
\ No newline at end of file
@@ -0,0 +1,32 @@
+ALTER TABLE games_2v2
+    ADD COLUMN duration_ms      BIGINT NOT NULL DEFAULT 0;
+
+CREATE TABLE round_stats (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    round               INTEGER NOT NULL,
+    games_played        INTEGER NOT NULL,
+    failed_matches      INTEGER NOT NULL,
+    crashed_games       INTEGER NOT NULL,
+    avg_duration_ms     BIGINT NOT NULL,
+    avg_score           DOUBLE NOT NULL,
+    biggest_elo_swing   INTEGER NOT NULL,
+    created             DATETIME NOT NULL,
+    UNIQUE KEY round_stats_competition_round (competition_id, round)
+);
+
+-- roll up the rounds played so far, their game durations weren't recorded
+INSERT INTO round_stats (id, competition_id, round, games_played, failed_matches, crashed_games, avg_duration_ms, avg_score, biggest_elo_swing, created)
+SELECT
+    UUID(),
+    competition_id,
+    round,
+    COUNT(*),
+    0,
+    SUM(additional_data <> ''),
+    0,
+    AVG((team1_score + team2_score) / 2),
+    MAX(GREATEST(ABS(team1_elo), ABS(team2_elo))),
+    MAX(created)
+FROM games_2v2
+GROUP BY competition_id, round;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE bot_violations;
+
+DROP TABLE validation_rules;
\ No newline at end of file
@@ -0,0 +1,21 @@
+CREATE TABLE validation_rules (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL UNIQUE,
+    forbidden_apis      TEXT NOT NULL,
+    allowed_languages   TEXT NOT NULL,
+    max_size_kb         INTEGER NOT NULL DEFAULT 0,
+    grace_hours         INTEGER NOT NULL DEFAULT 48,
+    updated             DATETIME NOT NULL
+);
+
+CREATE TABLE bot_violations (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    bot_id              VARCHAR(255) NOT NULL,
+    team_id             VARCHAR(255) NOT NULL,
+    competition_id      VARCHAR(255) NOT NULL,
+    reason              TEXT NOT NULL,
+    deadline            DATETIME NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX bot_violations_competition_id ON bot_violations (competition_id);
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    DROP COLUMN evaluator_source,
+    DROP COLUMN evaluator_sha256;
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE competitions
+    ADD COLUMN evaluator_source VARCHAR(4096) NOT NULL DEFAULT '',
+    ADD COLUMN evaluator_sha256 VARCHAR(64) NOT NULL DEFAULT '';
\ No newline at end of file
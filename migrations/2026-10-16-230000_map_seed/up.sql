@@ -0,0 +1,2 @@
+ALTER TABLE games_2v2
+    ADD COLUMN map_seed         BIGINT NOT NULL DEFAULT 0;
\ No newline at end of file
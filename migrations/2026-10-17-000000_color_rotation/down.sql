@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE games_2v2
+    DROP COLUMN team1_colors,
+    DROP COLUMN team2_colors;
\ No newline at end of file
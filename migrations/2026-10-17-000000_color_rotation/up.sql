@@ -0,0 +1,3 @@
+ALTER TABLE games_2v2
+    ADD COLUMN team1_colors     VARCHAR(16) NOT NULL DEFAULT 'YELLOW_GREEN',
+    ADD COLUMN team2_colors     VARCHAR(16) NOT NULL DEFAULT 'BLUE_CYAN';
\ No newline at end of file
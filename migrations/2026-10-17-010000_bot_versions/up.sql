@@ -0,0 +1,21 @@
+CREATE TABLE bot_versions (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    team_id             VARCHAR(255) NOT NULL,
+    slot                VARCHAR(16) NOT NULL,
+    bot_id              VARCHAR(255) NOT NULL,
+    source_sha256       VARCHAR(64) NOT NULL DEFAULT '',
+    compile_status      VARCHAR(16) NOT NULL,
+    uploaded            DATETIME NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX bot_versions_team_id ON bot_versions (team_id);
+
+-- the bots that are currently active are the first version of each slot
+INSERT INTO bot_versions (id, team_id, slot, bot_id, source_sha256, compile_status, uploaded, created)
+SELECT UUID(), t.id, 'BOT1', b.id, '', IF(b.compile_error = '', 'OK', 'FAILED'), b.created, b.created
+FROM teams t JOIN bots b ON b.id = t.bot1;
+
+INSERT INTO bot_versions (id, team_id, slot, bot_id, source_sha256, compile_status, uploaded, created)
+SELECT UUID(), t.id, 'BOT2', b.id, '', IF(b.compile_error = '', 'OK', 'FAILED'), b.created, b.created
+FROM teams t JOIN bots b ON b.id = t.bot2;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    DROP COLUMN submission_freeze_minutes;
\ No newline at end of file
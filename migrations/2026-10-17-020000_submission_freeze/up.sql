@@ -0,0 +1,2 @@
+ALTER TABLE competitions
+    ADD COLUMN submission_freeze_minutes  INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE bots
+    DROP COLUMN compile_diagnostics;
\ No newline at end of file
@@ -0,0 +1,7 @@
+ALTER TABLE bots
+    ADD COLUMN compile_diagnostics  TEXT;
+
+UPDATE bots SET compile_diagnostics = '{}';
+
+ALTER TABLE bots
+    MODIFY COLUMN compile_diagnostics  TEXT NOT NULL;
\ No newline at end of file
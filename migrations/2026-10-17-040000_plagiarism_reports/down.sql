@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE plagiarism_pairs;
+DROP TABLE plagiarism_reports;
\ No newline at end of file
@@ -0,0 +1,23 @@
+CREATE TABLE plagiarism_reports (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    bots_compared       INTEGER NOT NULL,
+    pairs_flagged       INTEGER NOT NULL,
+    threshold           DOUBLE NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX plagiarism_reports_competition_id ON plagiarism_reports (competition_id);
+
+CREATE TABLE plagiarism_pairs (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    report_id           VARCHAR(255) NOT NULL,
+    team1_id            VARCHAR(255) NOT NULL,
+    bot1_id             VARCHAR(255) NOT NULL,
+    team2_id            VARCHAR(255) NOT NULL,
+    bot2_id             VARCHAR(255) NOT NULL,
+    similarity          DOUBLE NOT NULL,
+    shared_fingerprints INTEGER NOT NULL
+);
+
+CREATE INDEX plagiarism_pairs_report_id ON plagiarism_pairs (report_id);
\ No newline at end of file
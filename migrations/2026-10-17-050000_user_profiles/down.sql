@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE teams
+    DROP FOREIGN KEY fk_teams_owner,
+    DROP FOREIGN KEY fk_teams_partner;
+
+UPDATE teams SET partner = '' WHERE partner IS NULL;
+
+ALTER TABLE teams
+    MODIFY COLUMN partner       VARCHAR(255) NOT NULL;
+
+ALTER TABLE users
+    DROP COLUMN display_name,
+    DROP COLUMN email;
\ No newline at end of file
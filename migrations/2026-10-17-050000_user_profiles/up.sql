@@ -0,0 +1,19 @@
+ALTER TABLE users
+    ADD COLUMN display_name     VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN email            VARCHAR(255) NOT NULL DEFAULT '';
+
+-- teams without a partner used an empty string, which can't reference a user
+ALTER TABLE teams
+    MODIFY COLUMN partner       VARCHAR(255) NULL;
+
+UPDATE teams SET partner = NULL WHERE partner = '' OR partner NOT IN (SELECT id FROM users);
+
+-- owners can't be left out, users that are gone get a placeholder row without an LDAP entry
+INSERT INTO users (id, username, ldap_dn, role, created)
+    SELECT DISTINCT owner, owner, '', 'STUDENT', NOW()
+    FROM teams
+    WHERE owner NOT IN (SELECT id FROM users);
+
+ALTER TABLE teams
+    ADD CONSTRAINT fk_teams_owner FOREIGN KEY (owner) REFERENCES users(id),
+    ADD CONSTRAINT fk_teams_partner FOREIGN KEY (partner) REFERENCES users(id);
\ No newline at end of file
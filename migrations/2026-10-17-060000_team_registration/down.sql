@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE teams
+    DROP COLUMN waitlisted;
+
+ALTER TABLE competitions
+    DROP COLUMN registration_open,
+    DROP COLUMN registration_close,
+    DROP COLUMN max_teams;
\ No newline at end of file
@@ -0,0 +1,10 @@
+ALTER TABLE competitions
+    ADD COLUMN registration_open    DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00',
+    ADD COLUMN registration_close   DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00',
+    ADD COLUMN max_teams            INTEGER NOT NULL DEFAULT 0;
+
+-- existing competitions keep accepting teams for as long as they run
+UPDATE competitions SET registration_open = created, registration_close = end;
+
+ALTER TABLE teams
+    ADD COLUMN waitlisted           BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
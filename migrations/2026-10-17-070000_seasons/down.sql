@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE season_competitions;
+DROP TABLE seasons;
\ No newline at end of file
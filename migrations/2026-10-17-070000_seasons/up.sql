@@ -0,0 +1,11 @@
+CREATE TABLE seasons (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    name                VARCHAR(255) NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE TABLE season_competitions (
+    season_id           VARCHAR(255) NOT NULL,
+    competition_id      VARCHAR(255) NOT NULL,
+    PRIMARY KEY (season_id, competition_id)
+);
\ No newline at end of file
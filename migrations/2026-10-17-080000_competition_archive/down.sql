@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    DROP COLUMN archived,
+    DROP COLUMN archive_path;
\ No newline at end of file
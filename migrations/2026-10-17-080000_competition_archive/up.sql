@@ -0,0 +1,3 @@
+ALTER TABLE competitions
+    ADD COLUMN archived             BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN archive_path         VARCHAR(4096) NOT NULL DEFAULT '';
\ No newline at end of file
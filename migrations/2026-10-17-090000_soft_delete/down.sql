@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+-- rows that were soft deleted are removed for good
+DELETE FROM bots WHERE deleted_at IS NOT NULL;
+DELETE FROM teams WHERE deleted_at IS NOT NULL;
+DELETE FROM competitions WHERE deleted_at IS NOT NULL;
+
+ALTER TABLE competitions
+    DROP COLUMN deleted_at;
+
+ALTER TABLE teams
+    DROP COLUMN deleted_at;
+
+ALTER TABLE bots
+    DROP COLUMN deleted_at;
\ No newline at end of file
@@ -0,0 +1,8 @@
+ALTER TABLE competitions
+    ADD COLUMN deleted_at           DATETIME NULL;
+
+ALTER TABLE teams
+    ADD COLUMN deleted_at           DATETIME NULL;
+
+ALTER TABLE bots
+    ADD COLUMN deleted_at           DATETIME NULL;
\ No newline at end of file
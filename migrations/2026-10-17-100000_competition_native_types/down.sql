@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions
+    MODIFY COLUMN allowed_submissions   VARCHAR(255) NOT NULL,
+    MODIFY COLUMN round                 VARCHAR(255) NOT NULL;
+
+UPDATE competitions SET allowed_submissions = IF(allowed_submissions = '1', 'true', 'false');
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- values that couldn't be parsed before fall back to a fresh competition
+UPDATE competitions SET round = '0' WHERE round NOT REGEXP '^[0-9]+$';
+UPDATE competitions SET allowed_submissions = IF(LOWER(allowed_submissions) = 'true', '1', '0');
+
+ALTER TABLE competitions
+    MODIFY COLUMN allowed_submissions   BOOLEAN NOT NULL DEFAULT TRUE,
+    MODIFY COLUMN round                 INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
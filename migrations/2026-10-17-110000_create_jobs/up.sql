@@ -0,0 +1,14 @@
+CREATE TABLE jobs (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    kind                VARCHAR(255) NOT NULL,
+    competition_id      VARCHAR(255) NOT NULL,
+    status              VARCHAR(255) NOT NULL,
+    progress            INTEGER NOT NULL DEFAULT 0,
+    log                 TEXT NOT NULL,
+    trace_id            VARCHAR(255) NOT NULL,
+    created             DATETIME NOT NULL,
+    started             DATETIME NULL,
+    finished            DATETIME NULL
+);
+
+CREATE INDEX idx_jobs_status_created ON jobs (status, created);
\ No newline at end of file
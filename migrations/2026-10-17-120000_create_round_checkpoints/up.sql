@@ -0,0 +1,13 @@
+-- progress of a round that was interrupted by a shutdown, one row per match
+CREATE TABLE round_checkpoints (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    round               INTEGER NOT NULL,
+    team1_id            VARCHAR(255) NOT NULL,
+    team2_id            VARCHAR(255) NOT NULL,
+    -- the finished game with its player stats as JSON, empty if the match still has to be played
+    game                TEXT NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX idx_round_checkpoints_competition ON round_checkpoints (competition_id, round);
\ No newline at end of file
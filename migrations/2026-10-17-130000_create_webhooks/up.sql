@@ -0,0 +1,13 @@
+-- endpoints notified about the rounds and games of a competition
+CREATE TABLE webhooks (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    url                 TEXT NOT NULL,
+    -- key of the HMAC-SHA256 signature sent with every payload
+    secret              VARCHAR(255) NOT NULL,
+    -- comma separated ROUND_STARTED, ROUND_FINISHED and GAME_FINISHED
+    events              VARCHAR(255) NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX idx_webhooks_competition ON webhooks (competition_id);
\ No newline at end of file
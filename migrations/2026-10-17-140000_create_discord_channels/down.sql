@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE discord_channels;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Discord channel a competition's round summaries are posted to, through a channel webhook
+CREATE TABLE discord_channels (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL UNIQUE,
+    webhook_url         TEXT NOT NULL,
+    -- teams listed in the standings of a summary
+    standings_size      INTEGER NOT NULL DEFAULT 10,
+    updated             DATETIME NOT NULL
+);
\ No newline at end of file
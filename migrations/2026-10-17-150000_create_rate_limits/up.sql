@@ -0,0 +1,8 @@
+-- Per-competition overrides of the server's rate limits, 0 disables a limit
+CREATE TABLE rate_limits (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL UNIQUE,
+    uploads_per_hour    INTEGER NOT NULL,
+    requests_per_minute INTEGER NOT NULL,
+    updated             DATETIME NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,13 @@
+-- Keys of the read-only public API, only a hash of the key is stored
+CREATE TABLE api_keys (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    user_id             VARCHAR(255) NOT NULL,
+    name                VARCHAR(255) NOT NULL,
+    key_hash            VARCHAR(64) NOT NULL UNIQUE,
+    -- first characters of the key, so its owner can tell their keys apart
+    key_prefix          VARCHAR(16) NOT NULL,
+    requests_per_minute INTEGER NOT NULL,
+    revoked             BOOLEAN NOT NULL DEFAULT FALSE,
+    created             DATETIME NOT NULL,
+    last_used           DATETIME NULL
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX games_2v2_competition_round ON games_2v2;
+DROP INDEX games_2v2_competition_created ON games_2v2;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- cursor pagination of a competition's games, see get_games_page
+CREATE INDEX games_2v2_competition_created ON games_2v2 (competition_id, created, id);
+CREATE INDEX games_2v2_competition_round ON games_2v2 (competition_id, round, id);
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE game_rating_shadows;
+
+ALTER TABLE teams
+    DROP COLUMN shadow_elo,
+    DROP COLUMN shadow_rating_mu,
+    DROP COLUMN shadow_rating_sigma;
\ No newline at end of file
@@ -0,0 +1,15 @@
+-- ratings recomputed by replaying a competition's games, kept apart until an admin applies them
+ALTER TABLE teams
+    ADD COLUMN shadow_elo               INTEGER,
+    ADD COLUMN shadow_rating_mu         DOUBLE,
+    ADD COLUMN shadow_rating_sigma      DOUBLE;
+
+-- the recomputed ELO changes of each game, games_2v2 is at diesel's column limit
+CREATE TABLE game_rating_shadows (
+    game_id             VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    team1_elo           INTEGER NOT NULL,
+    team2_elo           INTEGER NOT NULL
+);
+
+CREATE INDEX game_rating_shadows_competition_id ON game_rating_shadows (competition_id);
\ No newline at end of file
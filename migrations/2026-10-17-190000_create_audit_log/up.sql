@@ -0,0 +1,12 @@
+-- Admin actions that changed results after the fact, with what they changed
+CREATE TABLE audit_log (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    user_id             VARCHAR(255) NOT NULL,
+    action              VARCHAR(64) NOT NULL,
+    competition_id      VARCHAR(255) NOT NULL,
+    target_id           VARCHAR(255) NOT NULL,
+    details             TEXT NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX audit_log_competition_id ON audit_log (competition_id);
\ No newline at end of file
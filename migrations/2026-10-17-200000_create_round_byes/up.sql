@@ -0,0 +1,12 @@
+-- Rounds a team sat out, either because it was left over when pairing an odd number of
+-- teams (BYE) or because none of its bots compiled (FORFEIT)
+CREATE TABLE round_byes (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    round               INT NOT NULL,
+    team_id             VARCHAR(255) NOT NULL,
+    kind                VARCHAR(16) NOT NULL,
+    created             DATETIME NOT NULL
+);
+
+CREATE INDEX round_byes_competition_id_round ON round_byes (competition_id, round);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE pairing_constraints;
+ALTER TABLE users DROP COLUMN lab_group;
\ No newline at end of file
@@ -0,0 +1,13 @@
+-- Lab group of a student, teams whose members share one can be kept apart when pairing
+ALTER TABLE users ADD COLUMN lab_group VARCHAR(255) NOT NULL DEFAULT '';
+
+-- How strongly pairing avoids rematches and teams from the same lab group, a weight of 0
+-- turns the constraint off
+CREATE TABLE pairing_constraints (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL UNIQUE,
+    rematch_window      INTEGER NOT NULL DEFAULT 0,
+    rematch_weight      INTEGER NOT NULL DEFAULT 0,
+    lab_group_weight    INTEGER NOT NULL DEFAULT 0,
+    updated             DATETIME NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,11 @@
+-- The seed each round was paired with and everything the pairing was based on, so the
+-- pairing can be reproduced later
+CREATE TABLE rounds (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    round               INTEGER NOT NULL,
+    seed                BIGINT NOT NULL,
+    metadata            TEXT NOT NULL,
+    created             DATETIME NOT NULL,
+    UNIQUE (competition_id, round)
+);
\ No newline at end of file
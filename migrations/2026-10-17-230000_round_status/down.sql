@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE rounds DROP COLUMN failed_matches;
+ALTER TABLE rounds DROP COLUMN games_played;
+ALTER TABLE rounds DROP COLUMN status;
+ALTER TABLE rounds DROP COLUMN finished_at;
+ALTER TABLE rounds CHANGE started_at created DATETIME NOT NULL;
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- Rounds are written when they start and updated when they end, so it's known when a round
+-- ran and whether it completed
+ALTER TABLE rounds CHANGE created started_at DATETIME NOT NULL;
+ALTER TABLE rounds ADD COLUMN finished_at DATETIME NULL;
+ALTER TABLE rounds ADD COLUMN status VARCHAR(16) NOT NULL DEFAULT 'RUNNING';
+ALTER TABLE rounds ADD COLUMN games_played INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE rounds ADD COLUMN failed_matches INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
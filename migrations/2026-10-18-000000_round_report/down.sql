@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE round_stats DROP COLUMN biggest_elo_loss;
+ALTER TABLE round_stats DROP COLUMN biggest_elo_gain;
+ALTER TABLE round_stats DROP COLUMN avg_elo_change;
+ALTER TABLE round_stats DROP COLUMN compile_failures;
+ALTER TABLE round_stats DROP COLUMN timeouts;
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Round summaries also count timed out matches and failed compiles and summarize how ELO moved
+ALTER TABLE round_stats ADD COLUMN timeouts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE round_stats ADD COLUMN compile_failures INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE round_stats ADD COLUMN avg_elo_change DOUBLE NOT NULL DEFAULT 0;
+ALTER TABLE round_stats ADD COLUMN biggest_elo_gain INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE round_stats ADD COLUMN biggest_elo_loss INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
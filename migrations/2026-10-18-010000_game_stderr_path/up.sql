@@ -0,0 +1,2 @@
+-- Games point to the file holding their bots' error output, next to the replay in log_file_path
+ALTER TABLE games_2v2 ADD COLUMN stderr_path VARCHAR(4096) NOT NULL DEFAULT '';
\ No newline at end of file
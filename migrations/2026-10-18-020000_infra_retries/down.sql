@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE round_leniencies DROP COLUMN infra_retries;
\ No newline at end of file
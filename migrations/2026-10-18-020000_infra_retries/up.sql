@@ -0,0 +1,2 @@
+-- Games failing because of the host rather than a bot are played again up to infra_retries times
+ALTER TABLE round_leniencies ADD COLUMN infra_retries INTEGER NOT NULL DEFAULT 2;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE competitions DROP COLUMN stall_timeout_secs;
+ALTER TABLE competitions DROP COLUMN game_timeout_secs;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- Games time out after game_timeout_secs unless a round range overrides it, and are killed early
+-- once they print nothing for stall_timeout_secs (0 turns the watchdog off)
+ALTER TABLE competitions ADD COLUMN game_timeout_secs INTEGER NOT NULL DEFAULT 120;
+ALTER TABLE competitions ADD COLUMN stall_timeout_secs INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
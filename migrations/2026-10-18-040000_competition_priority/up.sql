@@ -0,0 +1,2 @@
+-- Competitions playing rounds at the same time share the game slots in proportion to their priority
+ALTER TABLE competitions ADD COLUMN priority INTEGER NOT NULL DEFAULT 1;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE ladder_games;
+DROP TABLE ladder_bots;
\ No newline at end of file
@@ -0,0 +1,21 @@
+CREATE TABLE ladder_bots (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    name            VARCHAR(255) NOT NULL,
+    rating          INTEGER NOT NULL,
+    created         DATETIME NOT NULL
+);
+
+CREATE TABLE ladder_games (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    team_id         VARCHAR(255) NOT NULL,
+    ladder_bot_id   VARCHAR(255) NOT NULL,
+    won             BOOLEAN NOT NULL,
+    team_score      INTEGER NOT NULL,
+    bot_score       INTEGER NOT NULL,
+    rating          INTEGER NOT NULL,
+    rating_change   INTEGER NOT NULL,
+    created         DATETIME NOT NULL,
+    INDEX ladder_games_team (competition_id, team_id, created)
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE practice_games;
+DROP TABLE practice_queue;
\ No newline at end of file
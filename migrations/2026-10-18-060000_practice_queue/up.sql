@@ -0,0 +1,20 @@
+CREATE TABLE practice_queue (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    team_id         VARCHAR(255) NOT NULL UNIQUE,
+    created         DATETIME NOT NULL
+);
+
+CREATE TABLE practice_games (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    team1_id        VARCHAR(255) NOT NULL,
+    team2_id        VARCHAR(255) NOT NULL,
+    winner_id       VARCHAR(255) NOT NULL,
+    team1_score     INTEGER NOT NULL,
+    team2_score     INTEGER NOT NULL,
+    error           TEXT NOT NULL,
+    created         DATETIME NOT NULL,
+    INDEX practice_games_team1 (team1_id),
+    INDEX practice_games_team2 (team2_id)
+);
\ No newline at end of file
@@ -0,0 +1,16 @@
+CREATE TABLE challenges (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    challenger_id       VARCHAR(255) NOT NULL,
+    challenged_id       VARCHAR(255) NOT NULL,
+    status              VARCHAR(32) NOT NULL,
+    winner_id           VARCHAR(255) NOT NULL DEFAULT '',
+    challenger_score    INTEGER NOT NULL DEFAULT 0,
+    challenged_score    INTEGER NOT NULL DEFAULT 0,
+    log_file_path       VARCHAR(4096) NOT NULL DEFAULT '',
+    error               TEXT NOT NULL,
+    created             DATETIME NOT NULL,
+    updated             DATETIME NOT NULL,
+    INDEX challenges_challenger (challenger_id, created),
+    INDEX challenges_challenged (challenged_id)
+);
\ No newline at end of file
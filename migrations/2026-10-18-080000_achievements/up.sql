@@ -0,0 +1,12 @@
+CREATE TABLE achievements (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    team_id         VARCHAR(255) NOT NULL,
+    user_id         VARCHAR(255) NOT NULL,
+    kind            VARCHAR(64) NOT NULL,
+    round           INTEGER NOT NULL,
+    game_id         VARCHAR(255) NOT NULL DEFAULT '',
+    created         DATETIME NOT NULL,
+    UNIQUE KEY achievements_once (team_id, user_id, kind),
+    INDEX achievements_user (user_id)
+);
\ No newline at end of file
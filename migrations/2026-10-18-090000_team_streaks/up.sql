@@ -0,0 +1,10 @@
+CREATE TABLE team_streaks (
+    team_id             VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    current_streak      INTEGER NOT NULL DEFAULT 0,
+    longest_win_streak  INTEGER NOT NULL DEFAULT 0,
+    upset_score         INTEGER NOT NULL DEFAULT 0,
+    upset_team_id       VARCHAR(255) NOT NULL DEFAULT '',
+    updated             DATETIME NOT NULL,
+    INDEX team_streaks_competition (competition_id)
+);
\ No newline at end of file
@@ -0,0 +1,16 @@
+CREATE TABLE standings_history (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id  VARCHAR(255) NOT NULL,
+    round           INTEGER NOT NULL,
+    team_id         VARCHAR(255) NOT NULL,
+    team_name       VARCHAR(255) NOT NULL,
+    position        INTEGER NOT NULL,
+    elo             INTEGER NOT NULL,
+    points          INTEGER NOT NULL,
+    wins            INTEGER NOT NULL,
+    losses          INTEGER NOT NULL,
+    draws           INTEGER NOT NULL,
+    games_played    INTEGER NOT NULL,
+    created         DATETIME NOT NULL,
+    UNIQUE KEY standings_history_team_round (competition_id, round, team_id)
+);
\ No newline at end of file
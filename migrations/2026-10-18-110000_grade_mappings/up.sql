@@ -0,0 +1,8 @@
+CREATE TABLE grade_mappings (
+    competition_id  VARCHAR(255) NOT NULL PRIMARY KEY,
+    basis           VARCHAR(16) NOT NULL,
+    bands           TEXT NOT NULL,
+    default_grade   DOUBLE NOT NULL DEFAULT 0,
+    updated_by      VARCHAR(255) NOT NULL,
+    updated         DATETIME NOT NULL
+);
\ No newline at end of file
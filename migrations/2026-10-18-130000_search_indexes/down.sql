@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX teams_name ON teams;
+DROP INDEX users_username ON users;
+DROP INDEX users_display_name ON users;
+DROP INDEX competitions_name ON competitions;
\ No newline at end of file
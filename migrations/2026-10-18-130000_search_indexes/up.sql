@@ -0,0 +1,5 @@
+-- Prefix lookups of the search bar, the default collation already compares case insensitively
+CREATE INDEX teams_name ON teams (name);
+CREATE INDEX users_username ON users (username);
+CREATE INDEX users_display_name ON users (display_name);
+CREATE INDEX competitions_name ON competitions (name);
\ No newline at end of file
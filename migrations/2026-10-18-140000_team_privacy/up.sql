@@ -0,0 +1,4 @@
+-- Name shown for the team instead of its real name on public pages, and whether its members are hidden there
+ALTER TABLE teams
+    ADD COLUMN alias                VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN hide_members         BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
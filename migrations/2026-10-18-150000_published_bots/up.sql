@@ -0,0 +1,16 @@
+-- Bots teams published to the public gallery after their competition ended
+CREATE TABLE published_bots (
+    id              VARCHAR(255) NOT NULL PRIMARY KEY,
+    bot_id          VARCHAR(255) NOT NULL UNIQUE,
+    team_id         VARCHAR(255) NOT NULL,
+    competition_id  VARCHAR(255) NOT NULL,
+    name            VARCHAR(255) NOT NULL,
+    description     TEXT NOT NULL,
+    anonymous       BOOLEAN NOT NULL,
+    -- storage key of the published archive, the bot's upload
+    archive_path    VARCHAR(4096) NOT NULL,
+    final_elo       INTEGER NOT NULL,
+    created         DATETIME NOT NULL
+);
+
+CREATE INDEX published_bots_competition_id ON published_bots (competition_id);
\ No newline at end of file
@@ -0,0 +1,16 @@
+-- Archived champion bots playing a competition's rounds without being rated or ranked
+CREATE TABLE ghost_teams (
+    id                  VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id      VARCHAR(255) NOT NULL,
+    published_bot_id    VARCHAR(255) NOT NULL,
+    -- the published bot, it plays both slots of the team
+    bot_id              VARCHAR(255) NOT NULL,
+    name                VARCHAR(255) NOT NULL,
+    elo                 INTEGER NOT NULL,
+    created             DATETIME NOT NULL,
+    UNIQUE (competition_id, published_bot_id)
+);
+
+-- Games against ghost teams change no ratings and don't count towards the standings
+ALTER TABLE games_2v2
+    ADD COLUMN unrated              BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
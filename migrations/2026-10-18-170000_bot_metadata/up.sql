@@ -0,0 +1,10 @@
+-- Name, description and language teams give their bots, shown instead of the bot's id
+ALTER TABLE bots
+    ADD COLUMN display_name         VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN description          TEXT,
+    ADD COLUMN language             VARCHAR(32) NOT NULL DEFAULT 'Java';
+
+UPDATE bots SET description = '';
+
+ALTER TABLE bots
+    MODIFY COLUMN description       TEXT NOT NULL;
\ No newline at end of file
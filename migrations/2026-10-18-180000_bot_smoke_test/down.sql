@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE bots
+    DROP COLUMN smoke_test,
+    DROP COLUMN smoke_test_message;
\ No newline at end of file
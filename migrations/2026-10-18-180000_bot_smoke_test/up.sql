@@ -0,0 +1,10 @@
+-- Verdict of the short test game every uploaded bot plays against idle bots, bots uploaded
+-- before it existed are marked as not tested
+ALTER TABLE bots
+    ADD COLUMN smoke_test           VARCHAR(16) NOT NULL DEFAULT 'SKIPPED',
+    ADD COLUMN smoke_test_message   TEXT;
+
+UPDATE bots SET smoke_test_message = '';
+
+ALTER TABLE bots
+    MODIFY COLUMN smoke_test_message TEXT NOT NULL;
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE game_player_stats
+    DROP COLUMN turns_timed,
+    DROP COLUMN turn_time_p50_ms,
+    DROP COLUMN turn_time_p95_ms,
+    DROP COLUMN turn_time_max_ms;
\ No newline at end of file
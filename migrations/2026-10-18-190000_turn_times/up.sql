@@ -0,0 +1,6 @@
+-- Decision time per turn of each bot in a game, reported by game packs that time their bots
+ALTER TABLE game_player_stats
+    ADD COLUMN turns_timed          INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN turn_time_p50_ms     INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN turn_time_p95_ms     INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN turn_time_max_ms     INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- The hour a round was scheduled for, its submission cutoff is taken from it however late the
+-- round runs or how often it's resumed
+ALTER TABLE rounds ADD COLUMN scheduled_at DATETIME NULL;
+UPDATE rounds SET scheduled_at = started_at;
+ALTER TABLE rounds MODIFY scheduled_at DATETIME NOT NULL;
\ No newline at end of file
@@ -0,0 +1,43 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE
+    webhooks,
+    validation_rules,
+    users,
+    trace_spans,
+    teams,
+    team_streaks,
+    standings_history,
+    seasons,
+    season_competitions,
+    rounds,
+    round_stats,
+    round_leniencies,
+    round_hooks,
+    round_events,
+    round_checkpoints,
+    round_byes,
+    rate_limits,
+    practice_queue,
+    practice_games,
+    practice_bots,
+    plagiarism_reports,
+    plagiarism_pairs,
+    participations,
+    pairing_constraints,
+    ladder_games,
+    ladder_bots,
+    jobs,
+    grade_mappings,
+    games_2v2,
+    game_rating_shadows,
+    game_player_stats,
+    elo_history,
+    discord_channels,
+    competitions,
+    challenges,
+    bots,
+    bot_violations,
+    bot_versions,
+    audit_log,
+    api_keys,
+    achievements;
\ No newline at end of file
@@ -0,0 +1,555 @@
+-- The whole schema as the MySQL migrations in ../migrations leave it, for deployments running
+-- on PostgreSQL. Migrations changing the schema after this one are added to both folders.
+
+CREATE TABLE achievements (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    user_id                 VARCHAR(255) NOT NULL,
+    kind                    VARCHAR(64) NOT NULL,
+    round                   INTEGER NOT NULL,
+    game_id                 VARCHAR(255) NOT NULL DEFAULT '',
+    created                 TIMESTAMP NOT NULL,
+    CONSTRAINT achievements_once UNIQUE (team_id, user_id, kind)
+);
+
+CREATE INDEX achievements_user ON achievements (user_id);
+
+CREATE TABLE api_keys (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    user_id                 VARCHAR(255) NOT NULL,
+    name                    VARCHAR(255) NOT NULL,
+    key_hash                VARCHAR(64) NOT NULL UNIQUE,
+    key_prefix              VARCHAR(16) NOT NULL,
+    requests_per_minute     INTEGER NOT NULL,
+    revoked                 BOOLEAN NOT NULL DEFAULT FALSE,
+    created                 TIMESTAMP NOT NULL,
+    last_used               TIMESTAMP
+);
+
+CREATE TABLE audit_log (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    user_id                 VARCHAR(255) NOT NULL,
+    action                  VARCHAR(64) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    target_id               VARCHAR(255) NOT NULL,
+    details                 TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX audit_log_competition_id ON audit_log (competition_id);
+
+CREATE TABLE bot_versions (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    team_id                 VARCHAR(255) NOT NULL,
+    slot                    VARCHAR(16) NOT NULL,
+    bot_id                  VARCHAR(255) NOT NULL,
+    source_sha256           VARCHAR(64) NOT NULL DEFAULT '',
+    compile_status          VARCHAR(16) NOT NULL,
+    uploaded                TIMESTAMP NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX bot_versions_team_id ON bot_versions (team_id);
+
+CREATE TABLE bot_violations (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    bot_id                  VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    reason                  TEXT NOT NULL,
+    deadline                TIMESTAMP NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX bot_violations_competition_id ON bot_violations (competition_id);
+
+CREATE TABLE bots (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    team_id                 VARCHAR(255) NOT NULL,
+    bot_name                VARCHAR(255) NOT NULL,
+    source_path             VARCHAR(255) NOT NULL,
+    compile_error           TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    compile_diagnostics     TEXT NOT NULL,
+    deleted_at              TIMESTAMP
+);
+
+CREATE TABLE challenges (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    challenger_id           VARCHAR(255) NOT NULL,
+    challenged_id           VARCHAR(255) NOT NULL,
+    status                  VARCHAR(32) NOT NULL,
+    winner_id               VARCHAR(255) NOT NULL DEFAULT '',
+    challenger_score        INTEGER NOT NULL DEFAULT 0,
+    challenged_score        INTEGER NOT NULL DEFAULT 0,
+    log_file_path           VARCHAR(4096) NOT NULL DEFAULT '',
+    error                   TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX challenges_challenger ON challenges (challenger_id, created);
+
+CREATE INDEX challenges_challenged ON challenges (challenged_id);
+
+CREATE TABLE competitions (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    name                    VARCHAR(255) NOT NULL,
+    start                   TIMESTAMP NOT NULL,
+    "end"                   TIMESTAMP NOT NULL,
+    allowed_submissions     BOOLEAN NOT NULL DEFAULT TRUE,
+    round                   INTEGER NOT NULL DEFAULT 0,
+    type                    VARCHAR(255) NOT NULL,
+    games_per_round         INTEGER NOT NULL,
+    game_pack               VARCHAR(255) NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    replay_compress_after   INTEGER NOT NULL DEFAULT 0,
+    replay_delete_after     INTEGER NOT NULL DEFAULT 0,
+    elo_k_factor            INTEGER NOT NULL DEFAULT 16,
+    provisional_games       INTEGER NOT NULL DEFAULT 0,
+    provisional_k_factor    INTEGER NOT NULL DEFAULT 32,
+    scoring_system          VARCHAR(255) NOT NULL DEFAULT 'ELO',
+    points_win              INTEGER NOT NULL DEFAULT 3,
+    points_draw             INTEGER NOT NULL DEFAULT 1,
+    points_loss             INTEGER NOT NULL DEFAULT 0,
+    points_bye              INTEGER NOT NULL DEFAULT 3,
+    evaluator_source        VARCHAR(4096) NOT NULL DEFAULT '',
+    evaluator_sha256        VARCHAR(64) NOT NULL DEFAULT '',
+    submission_freeze_minutes INTEGER NOT NULL DEFAULT 0,
+    registration_open       TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:00',
+    registration_close      TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:00',
+    max_teams               INTEGER NOT NULL DEFAULT 0,
+    archived                BOOLEAN NOT NULL DEFAULT FALSE,
+    archive_path            VARCHAR(4096) NOT NULL DEFAULT '',
+    deleted_at              TIMESTAMP,
+    game_timeout_secs       INTEGER NOT NULL DEFAULT 120,
+    stall_timeout_secs      INTEGER NOT NULL DEFAULT 0,
+    priority                INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE TABLE discord_channels (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL UNIQUE,
+    webhook_url             TEXT NOT NULL,
+    standings_size          INTEGER NOT NULL DEFAULT 10,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE elo_history (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    team_id                 VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    elo                     INTEGER NOT NULL,
+    elo_change              INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE game_player_stats (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    game_id                 VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    bot_id                  VARCHAR(255) NOT NULL,
+    slot                    VARCHAR(255) NOT NULL,
+    turns_played            INTEGER NOT NULL,
+    survived                BOOLEAN NOT NULL,
+    fleet_generated         INTEGER NOT NULL,
+    fleet_lost              INTEGER NOT NULL,
+    fleet_reinforced        INTEGER NOT NULL,
+    largest_attack          INTEGER NOT NULL,
+    largest_loss            INTEGER NOT NULL,
+    largest_reinforcement   INTEGER NOT NULL,
+    planets_lost            INTEGER NOT NULL,
+    planets_conquered       INTEGER NOT NULL,
+    planets_defended        INTEGER NOT NULL,
+    planets_attacked        INTEGER NOT NULL,
+    num_fleet_lost          INTEGER NOT NULL,
+    num_fleet_reinforced    INTEGER NOT NULL,
+    num_fleet_generated     INTEGER NOT NULL,
+    total_troops_generated  INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX game_player_stats_game_id ON game_player_stats (game_id);
+
+CREATE INDEX game_player_stats_team_id ON game_player_stats (team_id);
+
+CREATE TABLE game_rating_shadows (
+    game_id                 VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    team1_elo               INTEGER NOT NULL,
+    team2_elo               INTEGER NOT NULL
+);
+
+CREATE INDEX game_rating_shadows_competition_id ON game_rating_shadows (competition_id);
+
+CREATE TABLE games_2v2 (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team1_id                VARCHAR(255) NOT NULL,
+    team2_id                VARCHAR(255) NOT NULL,
+    winner_id               VARCHAR(255) NOT NULL,
+    team1bot1_id            VARCHAR(255) NOT NULL,
+    team1bot2_id            VARCHAR(255) NOT NULL,
+    team2bot1_id            VARCHAR(255) NOT NULL,
+    team2bot2_id            VARCHAR(255) NOT NULL,
+    team1bot1_survived      BOOLEAN NOT NULL,
+    team1bot2_survived      BOOLEAN NOT NULL,
+    team2bot1_survived      BOOLEAN NOT NULL,
+    team2bot2_survived      BOOLEAN NOT NULL,
+    log_file_path           VARCHAR(4096) NOT NULL,
+    public                  BOOLEAN NOT NULL,
+    additional_data         TEXT NOT NULL,
+    team1_elo               INTEGER NOT NULL,
+    team2_elo               INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    replay_state            VARCHAR(255) NOT NULL DEFAULT 'STORED',
+    timeout_secs            INTEGER NOT NULL DEFAULT 120,
+    attempts                INTEGER NOT NULL DEFAULT 1,
+    team1_score             INTEGER NOT NULL DEFAULT 0,
+    team2_score             INTEGER NOT NULL DEFAULT 0,
+    trace_id                VARCHAR(255) NOT NULL DEFAULT '',
+    output_version          VARCHAR(16) NOT NULL DEFAULT 'v1',
+    duration_ms             BIGINT NOT NULL DEFAULT 0,
+    map_seed                BIGINT NOT NULL DEFAULT 0,
+    team1_colors            VARCHAR(16) NOT NULL DEFAULT 'YELLOW_GREEN',
+    team2_colors            VARCHAR(16) NOT NULL DEFAULT 'BLUE_CYAN',
+    stderr_path             VARCHAR(4096) NOT NULL DEFAULT ''
+);
+
+CREATE INDEX games_2v2_competition_created ON games_2v2 (competition_id, created, id);
+
+CREATE INDEX games_2v2_competition_round ON games_2v2 (competition_id, round, id);
+
+CREATE TABLE grade_mappings (
+    competition_id          VARCHAR(255) NOT NULL PRIMARY KEY,
+    basis                   VARCHAR(16) NOT NULL,
+    bands                   TEXT NOT NULL,
+    default_grade           DOUBLE PRECISION NOT NULL DEFAULT 0,
+    updated_by              VARCHAR(255) NOT NULL,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE jobs (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    kind                    VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    status                  VARCHAR(255) NOT NULL,
+    progress                INTEGER NOT NULL DEFAULT 0,
+    log                     TEXT NOT NULL,
+    trace_id                VARCHAR(255) NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    started                 TIMESTAMP,
+    finished                TIMESTAMP
+);
+
+CREATE INDEX idx_jobs_status_created ON jobs (status, created);
+
+CREATE TABLE ladder_bots (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    name                    VARCHAR(255) NOT NULL,
+    rating                  INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE ladder_games (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    ladder_bot_id           VARCHAR(255) NOT NULL,
+    won                     BOOLEAN NOT NULL,
+    team_score              INTEGER NOT NULL,
+    bot_score               INTEGER NOT NULL,
+    rating                  INTEGER NOT NULL,
+    rating_change           INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX ladder_games_team ON ladder_games (competition_id, team_id, created);
+
+CREATE TABLE pairing_constraints (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL UNIQUE,
+    rematch_window          INTEGER NOT NULL DEFAULT 0,
+    rematch_weight          INTEGER NOT NULL DEFAULT 0,
+    lab_group_weight        INTEGER NOT NULL DEFAULT 0,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE participations (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    owner                   VARCHAR(255) NOT NULL,
+    partner                 VARCHAR(255) NOT NULL,
+    submitted_working_bot   BOOLEAN NOT NULL,
+    games_played            INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE plagiarism_pairs (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    report_id               VARCHAR(255) NOT NULL,
+    team1_id                VARCHAR(255) NOT NULL,
+    bot1_id                 VARCHAR(255) NOT NULL,
+    team2_id                VARCHAR(255) NOT NULL,
+    bot2_id                 VARCHAR(255) NOT NULL,
+    similarity              DOUBLE PRECISION NOT NULL,
+    shared_fingerprints     INTEGER NOT NULL
+);
+
+CREATE INDEX plagiarism_pairs_report_id ON plagiarism_pairs (report_id);
+
+CREATE TABLE plagiarism_reports (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    bots_compared           INTEGER NOT NULL,
+    pairs_flagged           INTEGER NOT NULL,
+    threshold               DOUBLE PRECISION NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX plagiarism_reports_competition_id ON plagiarism_reports (competition_id);
+
+CREATE TABLE practice_bots (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    bot_id                  VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    name                    VARCHAR(255) NOT NULL,
+    anonymous               BOOLEAN NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE practice_games (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    team1_id                VARCHAR(255) NOT NULL,
+    team2_id                VARCHAR(255) NOT NULL,
+    winner_id               VARCHAR(255) NOT NULL,
+    team1_score             INTEGER NOT NULL,
+    team2_score             INTEGER NOT NULL,
+    error                   TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX practice_games_team1 ON practice_games (team1_id);
+
+CREATE INDEX practice_games_team2 ON practice_games (team2_id);
+
+CREATE TABLE practice_queue (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL UNIQUE,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE rate_limits (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL UNIQUE,
+    uploads_per_hour        INTEGER NOT NULL,
+    requests_per_minute     INTEGER NOT NULL,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE round_byes (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    kind                    VARCHAR(16) NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX round_byes_competition_id_round ON round_byes (competition_id, round);
+
+CREATE TABLE round_checkpoints (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team1_id                VARCHAR(255) NOT NULL,
+    team2_id                VARCHAR(255) NOT NULL,
+    game                    TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX idx_round_checkpoints_competition ON round_checkpoints (competition_id, round);
+
+CREATE TABLE round_events (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    kind                    VARCHAR(255) NOT NULL,
+    status                  VARCHAR(255) NOT NULL,
+    message                 TEXT NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    trace_id                VARCHAR(255) NOT NULL DEFAULT ''
+);
+
+CREATE TABLE round_hooks (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    kind                    VARCHAR(255) NOT NULL,
+    target                  TEXT NOT NULL,
+    timeout_secs            INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE round_leniencies (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    from_round              INTEGER NOT NULL,
+    to_round                INTEGER NOT NULL,
+    timeout_secs            INTEGER NOT NULL,
+    crash_retries           INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    infra_retries           INTEGER NOT NULL DEFAULT 2
+);
+
+CREATE TABLE round_stats (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    games_played            INTEGER NOT NULL,
+    failed_matches          INTEGER NOT NULL,
+    crashed_games           INTEGER NOT NULL,
+    avg_duration_ms         BIGINT NOT NULL,
+    avg_score               DOUBLE PRECISION NOT NULL,
+    biggest_elo_swing       INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    timeouts                INTEGER NOT NULL DEFAULT 0,
+    compile_failures        INTEGER NOT NULL DEFAULT 0,
+    avg_elo_change          DOUBLE PRECISION NOT NULL DEFAULT 0,
+    biggest_elo_gain        INTEGER NOT NULL DEFAULT 0,
+    biggest_elo_loss        INTEGER NOT NULL DEFAULT 0,
+    CONSTRAINT round_stats_competition_round UNIQUE (competition_id, round)
+);
+
+CREATE TABLE rounds (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    seed                    BIGINT NOT NULL,
+    metadata                TEXT NOT NULL,
+    started_at              TIMESTAMP NOT NULL,
+    finished_at             TIMESTAMP,
+    status                  VARCHAR(16) NOT NULL DEFAULT 'RUNNING',
+    games_played            INTEGER NOT NULL DEFAULT 0,
+    failed_matches          INTEGER NOT NULL DEFAULT 0,
+    CONSTRAINT rounds_competition_id_round UNIQUE (competition_id, round)
+);
+
+CREATE TABLE season_competitions (
+    season_id               VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    PRIMARY KEY (season_id, competition_id)
+);
+
+CREATE TABLE seasons (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    name                    VARCHAR(255) NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE standings_history (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    round                   INTEGER NOT NULL,
+    team_id                 VARCHAR(255) NOT NULL,
+    team_name               VARCHAR(255) NOT NULL,
+    position                INTEGER NOT NULL,
+    elo                     INTEGER NOT NULL,
+    points                  INTEGER NOT NULL,
+    wins                    INTEGER NOT NULL,
+    losses                  INTEGER NOT NULL,
+    draws                   INTEGER NOT NULL,
+    games_played            INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    CONSTRAINT standings_history_team_round UNIQUE (competition_id, round, team_id)
+);
+
+CREATE TABLE team_streaks (
+    team_id                 VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    current_streak          INTEGER NOT NULL DEFAULT 0,
+    longest_win_streak      INTEGER NOT NULL DEFAULT 0,
+    upset_score             INTEGER NOT NULL DEFAULT 0,
+    upset_team_id           VARCHAR(255) NOT NULL DEFAULT '',
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX team_streaks_competition ON team_streaks (competition_id);
+
+CREATE TABLE teams (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    name                    VARCHAR(255) NOT NULL,
+    owner                   VARCHAR(255) NOT NULL,
+    partner                 VARCHAR(255),
+    competition_id          VARCHAR(255) NOT NULL,
+    bot1                    VARCHAR(255) NOT NULL,
+    bot2                    VARCHAR(255) NOT NULL,
+    elo                     INTEGER NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    rating_mu               DOUBLE PRECISION NOT NULL DEFAULT 25.0,
+    rating_sigma            DOUBLE PRECISION NOT NULL DEFAULT 8.333333333333334,
+    waitlisted              BOOLEAN NOT NULL DEFAULT FALSE,
+    deleted_at              TIMESTAMP,
+    shadow_elo              INTEGER,
+    shadow_rating_mu        DOUBLE PRECISION,
+    shadow_rating_sigma     DOUBLE PRECISION
+);
+
+CREATE TABLE trace_spans (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    trace_id                VARCHAR(255) NOT NULL,
+    competition_id          VARCHAR(255) NOT NULL,
+    name                    VARCHAR(255) NOT NULL,
+    status                  VARCHAR(255) NOT NULL,
+    detail                  TEXT NOT NULL,
+    started                 TIMESTAMP(3) NOT NULL,
+    finished                TIMESTAMP(3) NOT NULL,
+    duration_ms             BIGINT NOT NULL
+);
+
+CREATE INDEX trace_spans_trace_id ON trace_spans (trace_id);
+
+CREATE TABLE users (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    username                VARCHAR(255) NOT NULL,
+    ldap_dn                 VARCHAR(255) NOT NULL,
+    role                    VARCHAR(255) NOT NULL,
+    created                 TIMESTAMP NOT NULL,
+    display_name            VARCHAR(255) NOT NULL DEFAULT '',
+    email                   VARCHAR(255) NOT NULL DEFAULT '',
+    lab_group               VARCHAR(255) NOT NULL DEFAULT ''
+);
+
+CREATE TABLE validation_rules (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL UNIQUE,
+    forbidden_apis          TEXT NOT NULL,
+    allowed_languages       TEXT NOT NULL,
+    max_size_kb             INTEGER NOT NULL DEFAULT 0,
+    grace_hours             INTEGER NOT NULL DEFAULT 48,
+    updated                 TIMESTAMP NOT NULL
+);
+
+CREATE TABLE webhooks (
+    id                      VARCHAR(255) NOT NULL PRIMARY KEY,
+    competition_id          VARCHAR(255) NOT NULL,
+    url                     TEXT NOT NULL,
+    secret                  VARCHAR(255) NOT NULL,
+    events                  VARCHAR(255) NOT NULL,
+    created                 TIMESTAMP NOT NULL
+);
+
+CREATE INDEX idx_webhooks_competition ON webhooks (competition_id);
+
+ALTER TABLE teams
+    ADD CONSTRAINT fk_teams_owner FOREIGN KEY (owner) REFERENCES users(id),
+    ADD CONSTRAINT fk_teams_partner FOREIGN KEY (partner) REFERENCES users(id);
\ No newline at end of file
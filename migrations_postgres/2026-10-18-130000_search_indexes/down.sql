@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX teams_name, users_username, users_display_name, competitions_name, teams_owner, teams_partner;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Prefix lookups of the search bar, which match on the lowercase value
+CREATE INDEX teams_name ON teams (lower(name) text_pattern_ops);
+CREATE INDEX users_username ON users (lower(username) text_pattern_ops);
+CREATE INDEX users_display_name ON users (lower(display_name) text_pattern_ops);
+CREATE INDEX competitions_name ON competitions (lower(name) text_pattern_ops);
+-- foreign keys aren't indexed on their own, members are looked up when searching for a user's team
+CREATE INDEX teams_owner ON teams (owner);
+CREATE INDEX teams_partner ON teams (partner);
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE teams
+    DROP COLUMN alias,
+    DROP COLUMN hide_members;
\ No newline at end of file
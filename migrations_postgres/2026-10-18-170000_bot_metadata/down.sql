@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE bots
+    DROP COLUMN display_name,
+    DROP COLUMN description,
+    DROP COLUMN language;
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Name, description and language teams give their bots, shown instead of the bot's id
+ALTER TABLE bots
+    ADD COLUMN display_name         VARCHAR(255) NOT NULL DEFAULT '',
+    ADD COLUMN description          TEXT NOT NULL DEFAULT '',
+    ADD COLUMN language             VARCHAR(32) NOT NULL DEFAULT 'Java';
\ No newline at end of file
@@ -0,0 +1,187 @@
+use crate::{
+    controllers::{evaluator_artifact::supports_batches, jvm_warmup::jvm_options},
+    models::game_2v2::NewGame2v2,
+    parsers::{EvaluatorOutput, EvaluatorVersion},
+};
+
+use super::GameAdapter;
+
+/// Most games played in a single run of an Evaluator that supports `--seeds`.
+const MAX_BATCH_GAMES: usize = 8;
+
+/// Batalja played 2v2 through the Evaluator JAR, in any of its output versions.
+///
+/// Evaluators listing `--seeds` in their `--help` play a game on each of the comma-separated
+/// seeds in a single run, the output of each game starting with a `GAME <seed>` line.
+pub struct BataljaAdapter {
+    version: EvaluatorVersion,
+    evaluator: String,
+}
+
+impl BataljaAdapter {
+    pub fn new(version: EvaluatorVersion, evaluator: String) -> Self {
+        Self { version, evaluator }
+    }
+}
+
+impl GameAdapter for BataljaAdapter {
+    fn name(&self) -> &str {
+        "batalja"
+    }
+
+    fn bot_count(&self) -> usize {
+        4
+    }
+
+    fn launch_args(&self, mut bot_paths: Vec<String>, map_seed: i64) -> Vec<String> {
+        let mut command_args = jvm_options(&self.evaluator);
+        command_args.extend([
+            "-jar".to_string(),
+            self.evaluator.clone(),
+            "--gui=false".to_string(),
+            format!("--seed={}", map_seed),
+        ]);
+        command_args.append(&mut bot_paths);
+        command_args
+    }
+
+    fn max_batch_size(&self) -> usize {
+        if supports_batches(&self.evaluator) {
+            MAX_BATCH_GAMES
+        } else {
+            1
+        }
+    }
+
+    fn batch_launch_args(&self, mut bot_paths: Vec<String>, map_seeds: &[i64]) -> Vec<String> {
+        let seeds = map_seeds.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        let mut command_args = jvm_options(&self.evaluator);
+        command_args.extend([
+            "-jar".to_string(),
+            self.evaluator.clone(),
+            "--gui=false".to_string(),
+            format!("--seeds={}", seeds.join(",")),
+        ]);
+        command_args.append(&mut bot_paths);
+        command_args
+    }
+
+    /// Every game of a batch starts with a `GAME <seed>` line, the games are matched to the
+    /// seeds by it, whatever order the Evaluator played them in.
+    fn split_batch_output(&self, lines: &[String], map_seeds: &[i64]) -> Option<Vec<Vec<String>>> {
+        let mut played: Vec<(i64, Vec<String>)> = vec![];
+        for line in lines.iter() {
+            if let Some(seed) = line.trim().strip_prefix("GAME ") {
+                played.push((seed.trim().parse().ok()?, vec![]));
+            } else if let Some((_, game)) = played.last_mut() {
+                game.push(line.to_owned());
+            }
+        }
+        if played.len() != map_seeds.len() {
+            return None;
+        }
+
+        let mut games = Vec::with_capacity(map_seeds.len());
+        for seed in map_seeds.iter() {
+            let index = played.iter().position(|(played_seed, _)| played_seed == seed)?;
+            games.push(played.swap_remove(index).1);
+        }
+        Some(games)
+    }
+
+    fn output_version(&self) -> EvaluatorVersion {
+        self.version
+    }
+
+    fn parse_output(&self, lines: &[String], bot_ids: &[&str]) -> EvaluatorOutput {
+        self.version.parse(lines, bot_ids)
+    }
+
+    /// The team with the only surviving bots wins, if both teams still have bots alive when
+    /// the game times out the higher score wins.
+    fn determine_winner(&self, match_game: &mut NewGame2v2) {
+        match (
+            &match_game.team1bot1_survived,
+            &match_game.team1bot2_survived,
+            &match_game.team2bot1_survived,
+            &match_game.team2bot2_survived
+        ) {
+            (true,  true,  false, false) => match_game.winner_id = match_game.team1_id.clone(),
+            (true,  false, false, false) => match_game.winner_id = match_game.team1_id.clone(),
+            (false, true,  false, false) => match_game.winner_id = match_game.team1_id.clone(),
+            (false, false, true,  true)  => match_game.winner_id = match_game.team2_id.clone(),
+            (false, false, true,  false) => match_game.winner_id = match_game.team2_id.clone(),
+            (false, false, false, true)  => match_game.winner_id = match_game.team2_id.clone(),
+            (_, _, _, _) => match_game.winner_id = "".to_string(),
+        }
+
+        // if multiple teams alive at the end (timeout) check who won by score
+        if match_game.winner_id.is_empty() {
+            if match_game.team1_score > match_game.team2_score {
+                match_game.winner_id = match_game.team1_id.clone();
+            } else {
+                match_game.winner_id = match_game.team2_id.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter() -> BataljaAdapter {
+        BataljaAdapter::new(EvaluatorVersion::V1, "Evaluator.jar".to_string())
+    }
+
+    fn lines(output: &[&str]) -> Vec<String> {
+        output.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_games_in_seed_order() {
+        let output = lines(&["GAME 1", "a1", "a2", "GAME 2", "b1"]);
+        let games = adapter().split_batch_output(&output, &[1, 2]).unwrap();
+        assert_eq!(games, vec![lines(&["a1", "a2"]), lines(&["b1"])]);
+    }
+
+    #[test]
+    fn matches_games_played_out_of_order_to_their_seeds() {
+        let output = lines(&["GAME 30", "c", "GAME 10", "a", "GAME 20", "b"]);
+        let games = adapter().split_batch_output(&output, &[10, 20, 30]).unwrap();
+        assert_eq!(games, vec![lines(&["a"]), lines(&["b"]), lines(&["c"])]);
+    }
+
+    #[test]
+    fn plays_a_repeated_seed_once_per_game() {
+        let output = lines(&["GAME 7", "a", "GAME 7", "b"]);
+        let games = adapter().split_batch_output(&output, &[7, 7]).unwrap();
+        assert_eq!(games, vec![lines(&["a"]), lines(&["b"])]);
+    }
+
+    #[test]
+    fn fails_on_a_seed_played_twice_instead_of_another() {
+        let output = lines(&["GAME 1", "a", "GAME 1", "b"]);
+        assert_eq!(adapter().split_batch_output(&output, &[1, 2]), None);
+    }
+
+    #[test]
+    fn fails_on_a_missing_game() {
+        let output = lines(&["GAME 1", "a"]);
+        assert_eq!(adapter().split_batch_output(&output, &[1, 2]), None);
+        assert_eq!(adapter().split_batch_output(&[], &[1]), None);
+    }
+
+    #[test]
+    fn fails_on_an_unparsable_header() {
+        let output = lines(&["GAME 1", "a", "GAME two", "b"]);
+        assert_eq!(adapter().split_batch_output(&output, &[1, 2]), None);
+    }
+
+    #[test]
+    fn ignores_lines_before_the_first_header() {
+        let output = lines(&["Picked up JAVA_TOOL_OPTIONS", "  GAME 4  ", "a"]);
+        let games = adapter().split_batch_output(&output, &[4]).unwrap();
+        assert_eq!(games, vec![lines(&["a"])]);
+    }
+}
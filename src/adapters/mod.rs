@@ -0,0 +1,88 @@
+//! Adapters between the matchmaker and the games it runs.
+//!
+//! The matchmaker prepares the bots, runs the game process, retries crashes and stores the
+//! results; everything specific to a game (how it is launched, how many bots play and how
+//! its output is read) lives behind `GameAdapter`. A new game is added by implementing the
+//! trait and returning it from `adapter_for_competition`.
+
+use std::collections::HashMap;
+
+use crate::{
+    controllers::evaluator_artifact::resolve_evaluator,
+    models::{competition::Competition, errors::MatchMakerError, game_2v2::NewGame2v2},
+    parsers::{bot_prints, EvaluatorOutput, EvaluatorVersion},
+};
+
+pub mod batalja;
+
+pub trait GameAdapter: Send + Sync {
+    /// Name of the game, used in logs.
+    fn name(&self) -> &str;
+
+    /// Number of bots in a game, the bots are passed in slot order (`team1bot1`, ...).
+    fn bot_count(&self) -> usize;
+
+    /// Arguments of the `java` process running a game of the bots in the given folders on
+    /// the map generated from `map_seed`.
+    fn launch_args(&self, bot_paths: Vec<String>, map_seed: i64) -> Vec<String>;
+
+    /// Most games a single run of the game can play one after another with the same bots,
+    /// `1` if the game can only play one game per run. May start the game to find out, so it
+    /// isn't called on an async worker thread.
+    fn max_batch_size(&self) -> usize {
+        1
+    }
+
+    /// Arguments of the `java` process playing a game on each map of `map_seeds` with the
+    /// bots in the given folders, only used if `max_batch_size` is larger than 1.
+    fn batch_launch_args(&self, bot_paths: Vec<String>, map_seeds: &[i64]) -> Vec<String> {
+        self.launch_args(bot_paths, map_seeds.first().copied().unwrap_or_default())
+    }
+
+    /// Splits the stdout of a batch run into the output of each game, in the order of
+    /// `map_seeds`. `None` if the output doesn't hold exactly one game for each seed.
+    fn split_batch_output(&self, lines: &[String], map_seeds: &[i64]) -> Option<Vec<Vec<String>>> {
+        match map_seeds.len() {
+            1 => Some(vec![lines.to_vec()]),
+            _ => None,
+        }
+    }
+
+    /// Version of the game's output format, recorded on every game.
+    fn output_version(&self) -> EvaluatorVersion;
+
+    /// Reads the stdout of a game that didn't crash.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines the game wrote to stdout.
+    /// * `bot_ids` - Ids of the bots in slot order.
+    ///
+    fn parse_output(&self, lines: &[String], bot_ids: &[&str]) -> EvaluatorOutput;
+
+    /// Splits a game's stdout and stderr into what each bot printed, keyed by slot.
+    fn split_bot_output(&self, lines: &[String], bot_ids: &[&str]) -> HashMap<String, Vec<String>> {
+        bot_prints(lines, bot_ids)
+    }
+
+    /// Sets the winner of a game whose scores and surviving bots are already filled in.
+    /// An empty winner id is a draw.
+    fn determine_winner(&self, match_game: &mut NewGame2v2);
+}
+
+/// Picks the adapter of the game a competition is played in.
+///
+/// Resolves the competition's evaluator artifact, which may have to be downloaded first.
+pub fn adapter_for_competition(competition: &Competition) -> Result<Box<dyn GameAdapter>, MatchMakerError> {
+    adapter_for_game(&competition.game_pack, &competition.evaluator_source, &competition.evaluator_sha256)
+}
+
+/// Picks the adapter of a game by the competition settings it depends on, used by match
+/// workers that don't have the competition itself.
+pub fn adapter_for_game(game_pack: &str, evaluator_source: &str, evaluator_sha256: &str) -> Result<Box<dyn GameAdapter>, MatchMakerError> {
+    let evaluator = resolve_evaluator(evaluator_source, evaluator_sha256)?;
+    Ok(Box::new(batalja::BataljaAdapter::new(
+        EvaluatorVersion::for_game_pack(game_pack),
+        evaluator,
+    )))
+}
\ No newline at end of file
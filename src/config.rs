@@ -0,0 +1,326 @@
+use std::{env, path::PathBuf};
+
+use figment::{Figment, providers::{Env, Format, Serialized, Toml}};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// Read unless `CONFIG_FILE` points elsewhere, a missing file leaves the defaults in place.
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// Server settings, read once on first use.
+///
+/// The defaults are overridden by the TOML config file, which is overridden by `BATALJA_`
+/// environment variables, nested keys are separated by `__` (`BATALJA_PATHS__GAMES`,
+/// `BATALJA_ELO__K_FACTOR`). The variables used before the config file existed
+/// (`DATABASE_URL`, `DB_POOL_SIZE`, `DB_POOL_TIMEOUT_SECS`, `MATCHES_DIR`, `MATCH_DISK_QUOTA_MB`,
+/// `SHUTDOWN_DRAIN_SECS`, `UNRANKED_POLICY`, `UNRANKED_THROTTLE_LIMIT`,
+/// `EMERGENCY_DISK_THRESHOLD_MB`, `REPLAY_OFFLOAD_DIR`, `SAFE_MODE`) are still read and take
+/// precedence.
+static SETTINGS: Lazy<Settings> = Lazy::new(|| {
+    let config_file = env::var("CONFIG_FILE").unwrap_or_else(|_| DEFAULT_CONFIG_FILE.to_string());
+    Figment::from(Serialized::defaults(Settings::default()))
+        .merge(Toml::file(config_file))
+        .merge(Env::prefixed("BATALJA_").split("__"))
+        .merge(legacy_env())
+        // lossy, so `SAFE_MODE=1` still reads as true
+        .extract_lossy()
+        .expect("Invalid configuration")
+});
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    pub database: DatabaseSettings,
+    pub paths: PathSettings,
+    pub timeouts: TimeoutSettings,
+    pub threads: ThreadSettings,
+    pub elo: EloSettings,
+    pub logging: LoggingSettings,
+    pub rate_limits: RateLimitSettings,
+    pub storage: StorageSettings,
+    pub jvm: JvmSettings,
+    pub workloads: WorkloadSettings,
+    pub server: ServerSettings,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseSettings {
+    pub url: String,
+    /// Enough for a round that writes the results of its matches in parallel while requests
+    /// are still being served.
+    pub pool_size: u32,
+    pub pool_timeout_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathSettings {
+    /// uploaded bot archives
+    pub uploads: PathBuf,
+    /// compiled bots, one folder per bot
+    pub bots_workdir: PathBuf,
+    /// replays and error logs, one folder per round
+    pub games: PathBuf,
+    /// folders the games are played in, can be a tmpfs mount
+    pub matches: PathBuf,
+    /// scratch folders uploads are test-compiled in before they are accepted
+    pub sandbox: PathBuf,
+    /// reference bots new uploads play a validation match against
+    pub reference_bots: PathBuf,
+    /// compiled ladder bots, one folder per ladder bot
+    pub ladder_bots: PathBuf,
+    /// compiled practice bots, one folder per practice bot
+    pub practice_bots: PathBuf,
+    /// compiled files of the bot that never issues an order, smoke tests play against it
+    pub idle_bot: PathBuf,
+    /// archives of finished competitions
+    pub archives: PathBuf,
+    /// forbidden API patterns of the static scan, one per line
+    pub forbidden_apis: PathBuf,
+    /// Evaluator of competitions that don't reference their own
+    pub default_evaluator: PathBuf,
+    /// downloaded Evaluator JARs
+    pub evaluator_cache: PathBuf,
+    /// process groups of running games, left behind by a crash and killed on the next start
+    pub game_processes: PathBuf,
+    /// where replays go while the disk is low, unset to drop them and keep only the results
+    pub replay_offload: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeoutSettings {
+    /// how long javac may take to compile a single bot
+    pub compile_secs: u64,
+    /// how often the size of a running game's folder is checked against the disk quota
+    pub disk_quota_check_secs: u64,
+    /// how much a single game's folder may grow to, 0 for no quota
+    pub match_disk_quota_mb: u64,
+    /// how long the game packs give a bot for a single turn, bots that routinely come close
+    /// are flagged in the turn time report
+    pub turn_limit_ms: u64,
+    /// how long running games get to finish on shutdown before they are killed
+    pub shutdown_drain_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadSettings {
+    /// games played at once by all running rounds, one per logical core but one if 0
+    pub concurrent_games: usize,
+    /// rounds of different competitions played at the same time
+    pub concurrent_rounds: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EloSettings {
+    /// K-factor of new competitions that don't set their own
+    pub k_factor: i32,
+    /// K-factor of teams still in their placement games, for new competitions
+    pub provisional_k_factor: i32,
+    /// performance variance of a single game in the skill rating (TrueSkill's beta)
+    pub skill_beta: f64,
+    /// lower bound for the variance shrink factor, keeps sigma from collapsing to zero
+    pub skill_kappa: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingSettings {
+    /// `text` for people reading the console, `json` for log collectors
+    pub format: LogFormat,
+    /// used when `RUST_LOG` isn't set, e.g. `info` or `batalja_dashboard_be=debug,info`
+    pub filter: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitSettings {
+    /// bot uploads a user may make per hour, competitions can set their own, 0 for no limit
+    pub uploads_per_hour: u32,
+    /// API requests a user (or an address, without a token) may make per minute, 0 for no limit
+    pub requests_per_minute: u32,
+    /// requests per minute of new public API keys, admins can give keys their own limit
+    pub api_key_requests_per_minute: u32,
+    /// challenges a team may send to other teams per day, 0 for no limit
+    pub challenges_per_day: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageSettings {
+    /// where replays and bot archives are kept, `local` for the paths above or `s3` for an
+    /// S3-compatible object store
+    pub backend: StorageBackend,
+    /// e.g. `https://s3.eu-central-1.amazonaws.com` or `http://minio:9000`, buckets are
+    /// addressed path-style
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+    /// how long presigned download links stay valid
+    pub presign_secs: u64,
+    /// free space of the games folder below which rounds stop writing replays
+    pub emergency_threshold_mb: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JvmSettings {
+    /// share the Evaluator's loaded classes between games through a class data sharing
+    /// archive, which the first game of an Evaluator JAR writes for the ones after it
+    pub class_data_sharing: bool,
+    /// passed to every game's JVM before `-jar`, e.g. `-XX:TieredStopAtLevel=1`
+    pub options: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadSettings {
+    /// how unranked workloads (test matches, smoke tests, ...) are treated during ranked rounds
+    pub unranked_policy: UnrankedMode,
+    /// unranked workloads running at once in `throttle` mode
+    pub unranked_throttle_limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerSettings {
+    /// serve the API without playing games or compiling bots, same as `--safe-mode`
+    pub safe_mode: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Local,
+    S3,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UnrankedMode {
+    Pause,
+    Throttle,
+    Unrestricted,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            database: DatabaseSettings {
+                url: "".to_string(),
+                pool_size: 16,
+                pool_timeout_secs: 10,
+            },
+            paths: PathSettings {
+                uploads: PathBuf::from("./resources/uploads"),
+                bots_workdir: PathBuf::from("./resources/workdir/bots"),
+                games: PathBuf::from("./resources/games"),
+                matches: PathBuf::from("./resources/matches"),
+                sandbox: PathBuf::from("./resources/sandbox"),
+                reference_bots: PathBuf::from("./resources/reference"),
+                ladder_bots: PathBuf::from("./resources/ladder"),
+                practice_bots: PathBuf::from("./resources/practice"),
+                idle_bot: PathBuf::from("./resources/idle"),
+                archives: PathBuf::from("./resources/archives"),
+                forbidden_apis: PathBuf::from("./resources/forbidden_apis.txt"),
+                default_evaluator: PathBuf::from("resources/gamefiles/Evaluator.jar"),
+                evaluator_cache: PathBuf::from("resources/gamefiles/cache"),
+                game_processes: PathBuf::from("./resources/game_processes"),
+                replay_offload: None,
+            },
+            timeouts: TimeoutSettings {
+                compile_secs: 120,
+                disk_quota_check_secs: 1,
+                match_disk_quota_mb: 0,
+                turn_limit_ms: 1000,
+                shutdown_drain_secs: 120,
+            },
+            threads: ThreadSettings {
+                concurrent_games: 0,
+                concurrent_rounds: 2,
+            },
+            elo: EloSettings {
+                k_factor: 16,
+                provisional_k_factor: 32,
+                skill_beta: 25.0 / 3.0 / 2.0,
+                skill_kappa: 0.0001,
+            },
+            logging: LoggingSettings {
+                format: LogFormat::Text,
+                filter: "info".to_string(),
+            },
+            rate_limits: RateLimitSettings {
+                uploads_per_hour: 5,
+                requests_per_minute: 120,
+                api_key_requests_per_minute: 60,
+                challenges_per_day: 3,
+            },
+            storage: StorageSettings {
+                backend: StorageBackend::Local,
+                endpoint: "".to_string(),
+                bucket: "".to_string(),
+                region: "us-east-1".to_string(),
+                access_key: "".to_string(),
+                secret_key: "".to_string(),
+                presign_secs: 300,
+                emergency_threshold_mb: 1024,
+            },
+            jvm: JvmSettings {
+                class_data_sharing: true,
+                options: vec![],
+            },
+            workloads: WorkloadSettings {
+                unranked_policy: UnrankedMode::Throttle,
+                unranked_throttle_limit: 1,
+            },
+            server: ServerSettings {
+                safe_mode: false,
+            },
+        }
+    }
+}
+
+impl ThreadSettings {
+    /// Games played at once, one core is left for the server unless configured.
+    pub fn concurrent_games(&self) -> usize {
+        match self.concurrent_games {
+            0 => num_cpus::get().saturating_sub(1).max(1),
+            n => n,
+        }
+    }
+}
+
+/// The server's settings.
+pub fn settings() -> &'static Settings {
+    &SETTINGS
+}
+
+fn legacy_env() -> Env {
+    Env::raw()
+        .only(&[
+            "DATABASE_URL",
+            "DB_POOL_SIZE",
+            "DB_POOL_TIMEOUT_SECS",
+            "MATCHES_DIR",
+            "MATCH_DISK_QUOTA_MB",
+            "SHUTDOWN_DRAIN_SECS",
+            "UNRANKED_POLICY",
+            "UNRANKED_THROTTLE_LIMIT",
+            "EMERGENCY_DISK_THRESHOLD_MB",
+            "REPLAY_OFFLOAD_DIR",
+            "SAFE_MODE",
+        ])
+        .map(|key| match key.as_str().to_ascii_lowercase().as_str() {
+            "database_url" => "database.url".into(),
+            "db_pool_size" => "database.pool_size".into(),
+            "db_pool_timeout_secs" => "database.pool_timeout_secs".into(),
+            "matches_dir" => "paths.matches".into(),
+            "shutdown_drain_secs" => "timeouts.shutdown_drain_secs".into(),
+            "unranked_policy" => "workloads.unranked_policy".into(),
+            "unranked_throttle_limit" => "workloads.unranked_throttle_limit".into(),
+            "emergency_disk_threshold_mb" => "storage.emergency_threshold_mb".into(),
+            "replay_offload_dir" => "paths.replay_offload".into(),
+            "safe_mode" => "server.safe_mode".into(),
+            _ => "timeouts.match_disk_quota_mb".into(),
+        })
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    db::{
+        operations_achievements::insert_achievements,
+        operations_elo_history::get_elo_history_by_round,
+        operations_game2v2::get_games_by_competition_id,
+    },
+    models::{
+        achievement::{AchievementKind, NewAchievement},
+        competition::Competition,
+        game_2v2::Game2v2,
+        team::{Team, DEFAULT_ELO},
+    },
+};
+
+/// Wins in a row that earn `WinStreak`.
+const WIN_STREAK_LENGTH: usize = 5;
+/// How far the beaten team's ELO has to be above the winner's for `GiantKiller`.
+const GIANT_KILLER_ELO_GAP: i32 = 200;
+
+/// Awards the achievements the teams earned in the round that was just played.
+///
+/// Achievements are earned once per team and are stored for each of its members, so they stay
+/// on a student's profile when the team changes later on. Only games between two different
+/// teams count.
+///
+/// * `FirstWin` - the team won a game.
+/// * `WinStreak` - the team's last `WIN_STREAK_LENGTH` games of the competition were all wins.
+/// * `FlawlessRound` - the team won every game of the round and none of its bots was destroyed.
+/// * `GiantKiller` - the team beat a team whose ELO was at least `GIANT_KILLER_ELO_GAP` above
+///   its own before the round.
+///
+/// # Arguments
+///
+/// * `competition` - The competition, its current round is the one that finished.
+/// * `teams` - All teams of the competition.
+/// * `games` - The games played in the round, unrated games earn nothing.
+///
+/// # Returns
+///
+/// The number of achievements that were newly awarded to members.
+///
+pub fn award_round_achievements(competition: &Competition, teams: &[Team], games: &[Game2v2]) -> Result<usize, Error> {
+    let games = games.iter().filter(|g| g.team1_id != g.team2_id && !g.unrated).collect::<Vec<&Game2v2>>();
+    if games.is_empty() {
+        return Ok(0);
+    }
+
+    // ELO before the round, the history of the round holds the ELO after it
+    let elo_before = get_elo_history_by_round(competition.id.clone(), competition.round)?
+        .into_iter()
+        .map(|h| (h.team_id, h.elo - h.elo_change))
+        .collect::<HashMap<String, i32>>();
+    let history = get_games_by_competition_id(competition.id.clone())?;
+
+    let mut earned: Vec<(&Team, AchievementKind, String)> = vec![];
+    for team in teams.iter() {
+        let round_games = games.iter().filter(|g| plays_in(g, &team.id)).collect::<Vec<_>>();
+        if round_games.is_empty() {
+            continue;
+        }
+        let wins = round_games.iter().filter(|g| g.winner_id == team.id).collect::<Vec<_>>();
+
+        if let Some(first_win) = wins.first() {
+            earned.push((team, AchievementKind::FirstWin, first_win.id.clone()));
+        }
+
+        let team_history = history
+            .iter()
+            .filter(|g| g.team1_id != g.team2_id && plays_in(g, &team.id))
+            .collect::<Vec<&Game2v2>>();
+        let streak = team_history.iter().rev().take_while(|g| g.winner_id == team.id).count();
+        if streak >= WIN_STREAK_LENGTH {
+            if let Some(last) = team_history.last() {
+                earned.push((team, AchievementKind::WinStreak, last.id.clone()));
+            }
+        }
+
+        if wins.len() == round_games.len() && round_games.iter().all(|g| bots_survived(g, &team.id)) {
+            earned.push((team, AchievementKind::FlawlessRound, "".to_string()));
+        }
+
+        let own_elo = elo_before.get(&team.id).copied().unwrap_or(DEFAULT_ELO);
+        let upset = wins.iter().find(|g| {
+            let opponent = if g.team1_id == team.id { &g.team2_id } else { &g.team1_id };
+            elo_before.get(opponent).copied().unwrap_or(DEFAULT_ELO) - own_elo >= GIANT_KILLER_ELO_GAP
+        });
+        if let Some(upset) = upset {
+            earned.push((team, AchievementKind::GiantKiller, upset.id.clone()));
+        }
+    }
+
+    let rows = earned
+        .into_iter()
+        .flat_map(|(team, kind, game_id)| {
+            [&team.owner, &team.partner]
+                .into_iter()
+                .filter(|member| !member.is_empty())
+                .map(|member| NewAchievement {
+                    competition_id: competition.id.clone(),
+                    team_id: team.id.clone(),
+                    user_id: member.clone(),
+                    kind,
+                    round: competition.round,
+                    game_id: game_id.clone(),
+                })
+                .collect::<Vec<NewAchievement>>()
+        })
+        .collect::<Vec<NewAchievement>>();
+    if rows.is_empty() {
+        return Ok(0);
+    }
+    insert_achievements(rows)
+}
+
+fn plays_in(game: &Game2v2, team_id: &str) -> bool {
+    game.team1_id == team_id || game.team2_id == team_id
+}
+
+fn bots_survived(game: &Game2v2, team_id: &str) -> bool {
+    if game.team1_id == team_id {
+        game.team1bot1_survived && game.team1bot2_survived
+    } else {
+        game.team2bot1_survived && game.team2bot2_survived
+    }
+}
\ No newline at end of file
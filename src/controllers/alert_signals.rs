@@ -0,0 +1,107 @@
+use std::{sync::{atomic::{AtomicBool, Ordering}, Mutex}, time::Instant};
+
+use once_cell::sync::Lazy;
+
+use crate::db::operations_db::db_pool;
+
+/// Set once the round scheduler is running.
+static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Default)]
+struct RoundState {
+    rounds_running: usize,
+    /// last time a running round started or finished a match
+    last_progress: Option<Instant>,
+    consecutive_infra_failures: u64,
+    /// disk the last round to start was estimated to need and the disk that was free for it
+    round_disk_needed: u64,
+    round_disk_free: u64,
+}
+
+static STATE: Lazy<Mutex<RoundState>> = Lazy::new(|| Mutex::new(RoundState::default()));
+
+/// Marks a round as running for as long as it is alive.
+pub struct RoundAlertGuard;
+
+impl Drop for RoundAlertGuard {
+    fn drop(&mut self) {
+        let mut state = STATE.lock().unwrap();
+        state.rounds_running -= 1;
+        if state.rounds_running == 0 {
+            state.last_progress = None;
+        }
+    }
+}
+
+/// Registers a running round, its progress is tracked until the returned guard is dropped.
+pub fn begin_round_signal() -> RoundAlertGuard {
+    let mut state = STATE.lock().unwrap();
+    state.rounds_running += 1;
+    state.last_progress = Some(Instant::now());
+    RoundAlertGuard
+}
+
+/// Records a match that produced a game, bot crashes included.
+pub fn record_match_success() {
+    let mut state = STATE.lock().unwrap();
+    state.last_progress = Some(Instant::now());
+    state.consecutive_infra_failures = 0;
+}
+
+/// Records a failure of the infrastructure rather than the bots: a match or round that
+/// couldn't be run or stored.
+pub fn record_infra_failure() {
+    let mut state = STATE.lock().unwrap();
+    if state.rounds_running > 0 {
+        state.last_progress = Some(Instant::now());
+    }
+    state.consecutive_infra_failures += 1;
+}
+
+/// Records the disk the starting round is estimated to need and how much was free for it.
+pub fn record_round_disk(needed: u64, free: u64) {
+    let mut state = STATE.lock().unwrap();
+    state.round_disk_needed = needed;
+    state.round_disk_free = free;
+}
+
+pub fn set_scheduler_running(running: bool) {
+    SCHEDULER_RUNNING.store(running, Ordering::SeqCst);
+}
+
+/// Renders the alert signals in the Prometheus text format.
+///
+/// * `round_stuck_seconds` - seconds since a running round last made progress, `0` when no
+///   round is running,
+/// * `consecutive_infra_failures` - matches and rounds that failed in a row for reasons
+///   other than the bots,
+/// * `scheduler_paused` - `1` while the round scheduler isn't running (safe mode, or it
+///   failed to start),
+/// * `round_disk_low` - `1` if the last round to start had less than twice the disk it was
+///   estimated to need, a round with less than it needs doesn't start at all.
+///
+pub fn render_alert_signals() -> String {
+    let state = STATE.lock().unwrap();
+    let stuck_seconds = match (state.rounds_running, state.last_progress) {
+        (0, _) | (_, None) => 0,
+        (_, Some(progress)) => progress.elapsed().as_secs(),
+    };
+    let scheduler_paused = if SCHEDULER_RUNNING.load(Ordering::SeqCst) { 0 } else { 1 };
+    let pool_state = db_pool().state();
+    let disk_low = if state.round_disk_free < state.round_disk_needed.saturating_mul(2) { 1 } else { 0 };
+
+    [
+        gauge("round_stuck_seconds", "Seconds since the running round last made progress, 0 when no round is running.", stuck_seconds),
+        gauge("consecutive_infra_failures", "Matches and rounds that failed in a row because of the infrastructure.", state.consecutive_infra_failures),
+        gauge("scheduler_paused", "1 while the round scheduler is not running.", scheduler_paused),
+        gauge("db_pool_connections", "Open database connections in the pool.", pool_state.connections as u64),
+        gauge("db_pool_idle_connections", "Database connections in the pool that are not in use.", pool_state.idle_connections as u64),
+        gauge("round_disk_needed_bytes", "Disk the last round to start was estimated to need for replays and match folders.", state.round_disk_needed),
+        gauge("round_disk_free_bytes", "Disk that was free when the last round started.", state.round_disk_free),
+        gauge("round_disk_low", "1 if the last round to start had less than twice the disk it was estimated to need.", disk_low),
+    ].concat()
+}
+
+fn gauge(name: &str, help: &str, value: u64) -> String {
+    format!("# HELP {} {}\n# TYPE {} gauge\n{} {}\n", name, help, name, name, value)
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use actix_web::{HttpRequest, HttpResponse, http::header::RETRY_AFTER};
+use chrono::{Duration, Local};
+use diesel::result::Error;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    config::settings,
+    db::operations_api_keys::{get_active_api_key_by_hash, insert_api_key, touch_api_key},
+    models::{api_key::{ApiKey, CreatedApiKey, NewApiKey, PublicApiKey, SqlApiKey}, user::{Role, User}},
+};
+
+use super::rate_limit::{check_api_key_rate, remember_api_key};
+
+/// Header the public API reads the key from.
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+/// Makes keys recognisable, e.g. in secret scanners.
+const KEY_PREFIX: &str = "bk_";
+/// How stale `last_used` may get before it's written again, saves a write per request.
+const LAST_USED_RESOLUTION_MINUTES: i64 = 5;
+
+/// Creates a key of the public API for the user. Only admins may set the key's rate limit.
+///
+/// # Returns
+///
+/// The key and its record, the key itself can't be retrieved again.
+pub fn create_api_key(user: &User, new_key: NewApiKey) -> Result<CreatedApiKey, Error> {
+    let mut secret = [0u8; 24];
+    rand::thread_rng().fill_bytes(&mut secret);
+    let key = format!("{}{}", KEY_PREFIX, hex::encode(secret));
+
+    let requests_per_minute = match new_key.requests_per_minute {
+        Some(limit) if user.role == Role::Admin => limit,
+        _ => settings().rate_limits.api_key_requests_per_minute as i32,
+    };
+
+    let api_key = insert_api_key(SqlApiKey {
+        id: Uuid::new_v4().to_string(),
+        user_id: user.id.clone(),
+        name: new_key.name.trim().to_string(),
+        key_hash: hash_api_key(&key),
+        key_prefix: key.chars().take(KEY_PREFIX.len() + 6).collect(),
+        requests_per_minute,
+        revoked: false,
+        created: Local::now().naive_utc(),
+        last_used: None,
+    })?;
+
+    Ok(CreatedApiKey {
+        key,
+        api_key: PublicApiKey::from(api_key),
+    })
+}
+
+/// Resolves the key of a public API request and counts the request against its limit.
+/// The error is the response the route should return.
+pub fn authorize_api_key(req: &HttpRequest) -> Result<ApiKey, HttpResponse> {
+    let key = match req.headers().get(API_KEY_HEADER).and_then(|h| h.to_str().ok()) {
+        Some(k) if !k.trim().is_empty() => k.trim(),
+        _ => return Err(HttpResponse::Unauthorized().body(format!("Missing the {} header", API_KEY_HEADER))),
+    };
+
+    let key_hash = hash_api_key(key);
+    let api_key = match get_active_api_key_by_hash(key_hash.clone()) {
+        Ok(Some(k)) => k,
+        Ok(None) => return Err(HttpResponse::Unauthorized().body("Unknown or revoked API key")),
+        Err(e) => {
+            error!("Failed looking up an API key: {:?}", e);
+            return Err(HttpResponse::InternalServerError().finish());
+        }
+    };
+
+    remember_api_key(key_hash);
+
+    if let Err(retry_after) = check_api_key_rate(&api_key) {
+        return Err(HttpResponse::TooManyRequests()
+            .insert_header((RETRY_AFTER, retry_after.to_string()))
+            .finish());
+    }
+
+    let stale = Local::now().naive_utc() - Duration::minutes(LAST_USED_RESOLUTION_MINUTES);
+    if api_key.last_used.is_none_or(|used| used < stale) {
+        if let Err(e) = touch_api_key(api_key.id.clone()) {
+            error!("Failed updating the last use of API key {}: {:?}", api_key.id, e);
+        }
+    }
+    Ok(api_key)
+}
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
\ No newline at end of file
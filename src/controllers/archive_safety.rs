@@ -0,0 +1,253 @@
+use std::{fs::{self, File}, io::{self, Read}, path::{Component, Path}};
+
+use zip::ZipArchive;
+
+use crate::models::errors::MatchMakerError;
+
+/// More entries than any bot needs, keeps an archive of empty files from exhausting inodes.
+const MAX_ARCHIVE_ENTRIES: usize = 2000;
+/// Total size of the extracted files.
+const MAX_EXTRACTED_BYTES: u64 = 100 * 1024 * 1024;
+/// Sources compress well, but not a hundredfold.
+const MAX_COMPRESSION_RATIO: u64 = 100;
+/// Entries smaller than this aren't checked for their ratio, tiny files of repeated
+/// characters compress extremely well without being dangerous.
+const RATIO_CHECK_MIN_BYTES: u64 = 1024 * 1024;
+const NESTED_ARCHIVE_EXTENSIONS: [&str; 11] = ["zip", "jar", "war", "tar", "gz", "tgz", "bz2", "xz", "zst", "7z", "rar"];
+const S_IFMT: u32 = 0o170000;
+const S_IFLNK: u32 = 0o120000;
+
+/// Checks an uploaded archive before anything is extracted from it.
+///
+/// Rejects zip bombs (too many entries, too much extracted data, suspicious compression
+/// ratios), nested archives, entries that would land outside the target directory (absolute
+/// paths, `..`) and symlinks.
+///
+/// # Returns
+///
+/// What is wrong with the archive, empty if it's safe to extract.
+pub fn inspect_archive(zip_path: &Path) -> Result<Vec<String>, MatchMakerError> {
+    let file = File::open(zip_path).map_err(MatchMakerError::IOError)?;
+    let mut archive = ZipArchive::new(file).map_err(MatchMakerError::ZippingError)?;
+
+    if archive.len() > MAX_ARCHIVE_ENTRIES {
+        return Ok(vec![format!("Archive has {} entries, the limit is {}", archive.len(), MAX_ARCHIVE_ENTRIES)]);
+    }
+
+    let mut problems = vec![];
+    let mut total_size: u64 = 0;
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i).map_err(MatchMakerError::ZippingError)?;
+        let name = entry.name().to_string();
+
+        if !is_enclosed(&name) || entry.enclosed_name().is_none() {
+            problems.push(format!("{} points outside of the archive", name));
+            continue;
+        }
+        if entry.unix_mode().is_some_and(|mode| mode & S_IFMT == S_IFLNK) {
+            problems.push(format!("{} is a symlink", name));
+            continue;
+        }
+        if entry.is_dir() {
+            continue;
+        }
+
+        let extension = Path::new(&name).extension().map(|e| e.to_string_lossy().to_lowercase());
+        if extension.is_some_and(|e| NESTED_ARCHIVE_EXTENSIONS.contains(&e.as_str())) {
+            problems.push(format!("{} is a nested archive", name));
+        }
+        if entry.size() >= RATIO_CHECK_MIN_BYTES && entry.size() > entry.compressed_size().max(1) * MAX_COMPRESSION_RATIO {
+            problems.push(format!("{} is compressed more than {}:1", name, MAX_COMPRESSION_RATIO));
+        }
+        total_size = total_size.saturating_add(entry.size());
+    }
+
+    if total_size > MAX_EXTRACTED_BYTES {
+        problems.push(format!(
+            "Archive extracts to {} MB, the limit is {} MB",
+            total_size / 1024 / 1024,
+            MAX_EXTRACTED_BYTES / 1024 / 1024,
+        ));
+    }
+    Ok(problems)
+}
+
+/// Extracts an archive into `target` once `inspect_archive` found nothing wrong with it.
+///
+/// The sizes in an archive's headers can lie, so the extracted data is counted as well and
+/// extraction stops once it exceeds the limit.
+///
+/// # Errors
+///
+/// `UnsafeArchive` with the problems found, IO and ZIP errors as they occur.
+pub fn extract_archive(zip_path: &Path, target: &Path) -> Result<(), MatchMakerError> {
+    let problems = inspect_archive(zip_path)?;
+    if !problems.is_empty() {
+        return Err(MatchMakerError::UnsafeArchive(problems));
+    }
+
+    let file = File::open(zip_path).map_err(MatchMakerError::IOError)?;
+    let mut archive = ZipArchive::new(file).map_err(MatchMakerError::ZippingError)?;
+    fs::create_dir_all(target).map_err(MatchMakerError::IOError)?;
+
+    let mut remaining = MAX_EXTRACTED_BYTES;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(MatchMakerError::ZippingError)?;
+        let path = match entry.enclosed_name() {
+            Some(p) => target.join(p),
+            None => return Err(MatchMakerError::UnsafeArchive(vec![format!("{} points outside of the archive", entry.name())])),
+        };
+
+        if entry.is_dir() {
+            fs::create_dir_all(&path).map_err(MatchMakerError::IOError)?;
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(MatchMakerError::IOError)?;
+        }
+
+        let mut out = File::create(&path).map_err(MatchMakerError::IOError)?;
+        let written = io::copy(&mut (&mut entry).take(remaining + 1), &mut out).map_err(MatchMakerError::IOError)?;
+        if written > remaining {
+            return Err(MatchMakerError::UnsafeArchive(vec![format!(
+                "Archive extracts to more than {} MB",
+                MAX_EXTRACTED_BYTES / 1024 / 1024,
+            )]));
+        }
+        remaining -= written;
+    }
+    Ok(())
+}
+
+/// Whether the entry name stays inside the directory it's extracted to.
+fn is_enclosed(name: &str) -> bool {
+    Path::new(name).components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, path::PathBuf};
+
+    use tempfile::TempDir;
+    use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+    use super::*;
+
+    /// Offsets into a central directory header.
+    const CENTRAL_HEADER: &[u8] = b"PK\x01\x02";
+    const UNCOMPRESSED_SIZE_OFFSET: usize = 24;
+    const EXTERNAL_ATTRIBUTES_OFFSET: usize = 38;
+
+    fn build_zip(dir: &TempDir, entries: &[(&str, Vec<u8>)]) -> PathBuf {
+        let path = dir.path().join("bot.zip");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, contents) in entries.iter() {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(contents).unwrap();
+        }
+        zip.finish().unwrap();
+        path
+    }
+
+    /// Overwrites 4 bytes of the central directory header of the `index`th entry, for
+    /// headers the zip writer won't produce.
+    fn patch_central_header(path: &Path, index: usize, offset: usize, value: u32) {
+        let mut bytes = fs::read(path).unwrap();
+        let header = bytes
+            .windows(CENTRAL_HEADER.len())
+            .enumerate()
+            .filter(|(_, w)| *w == CENTRAL_HEADER)
+            .map(|(i, _)| i)
+            .nth(index)
+            .unwrap();
+        bytes[header + offset..header + offset + 4].copy_from_slice(&value.to_le_bytes());
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn accepts_a_plain_bot() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("Bot.java", b"class Bot {}".to_vec()), ("util/Map.java", b"class Map {}".to_vec())]);
+        assert!(inspect_archive(&zip).unwrap().is_empty());
+
+        let target = dir.path().join("out");
+        extract_archive(&zip, &target).unwrap();
+        assert_eq!(fs::read_to_string(target.join("util/Map.java")).unwrap(), "class Map {}");
+    }
+
+    #[test]
+    fn rejects_entries_outside_the_archive() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("../Bot.java", vec![]), ("/etc/Bot.java", vec![]), ("src/../../Bot.java", vec![])]);
+        let problems = inspect_archive(&zip).unwrap();
+        assert_eq!(problems.len(), 3);
+        assert!(problems.iter().all(|p| p.ends_with("points outside of the archive")));
+
+        let target = dir.path().join("out");
+        assert!(matches!(extract_archive(&zip, &target), Err(MatchMakerError::UnsafeArchive(_))));
+        assert!(!dir.path().join("Bot.java").exists());
+    }
+
+    #[test]
+    fn rejects_symlinks() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("Bot.java", b"/etc/passwd".to_vec())]);
+        patch_central_header(&zip, 0, EXTERNAL_ATTRIBUTES_OFFSET, (S_IFLNK | 0o777) << 16);
+        assert_eq!(inspect_archive(&zip).unwrap(), vec!["Bot.java is a symlink".to_string()]);
+    }
+
+    #[test]
+    fn rejects_nested_archives() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("lib/Engine.JAR", vec![1, 2, 3]), ("Bot.java", vec![])]);
+        assert_eq!(inspect_archive(&zip).unwrap(), vec!["lib/Engine.JAR is a nested archive".to_string()]);
+    }
+
+    #[test]
+    fn rejects_entries_compressed_too_well() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("Bot.java", vec![0; 2 * RATIO_CHECK_MIN_BYTES as usize])]);
+        let problems = inspect_archive(&zip).unwrap();
+        assert_eq!(problems, vec![format!("Bot.java is compressed more than {}:1", MAX_COMPRESSION_RATIO)]);
+    }
+
+    #[test]
+    fn ignores_the_ratio_of_small_entries() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("Bot.java", vec![b' '; RATIO_CHECK_MIN_BYTES as usize - 1])]);
+        assert!(inspect_archive(&zip).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_too_many_entries() {
+        let dir = TempDir::new().unwrap();
+        let names = (0..=MAX_ARCHIVE_ENTRIES).map(|i| format!("{}.java", i)).collect::<Vec<String>>();
+        let entries = names.iter().map(|n| (n.as_str(), vec![])).collect::<Vec<(&str, Vec<u8>)>>();
+        let zip = build_zip(&dir, &entries);
+        let problems = inspect_archive(&zip).unwrap();
+        assert_eq!(problems, vec![format!("Archive has {} entries, the limit is {}", MAX_ARCHIVE_ENTRIES + 1, MAX_ARCHIVE_ENTRIES)]);
+    }
+
+    #[test]
+    fn stops_extracting_an_entry_larger_than_its_header_says() {
+        let dir = TempDir::new().unwrap();
+        let zip = build_zip(&dir, &[("Bot.java", vec![0; MAX_EXTRACTED_BYTES as usize + 1])]);
+        // the header claims a small file, so neither the size nor the ratio check sees it
+        patch_central_header(&zip, 0, UNCOMPRESSED_SIZE_OFFSET, 1024);
+        assert!(inspect_archive(&zip).unwrap().is_empty());
+
+        let target = dir.path().join("out");
+        assert!(matches!(extract_archive(&zip, &target), Err(MatchMakerError::UnsafeArchive(_))));
+        assert!(fs::metadata(target.join("Bot.java")).unwrap().len() <= MAX_EXTRACTED_BYTES + 1);
+    }
+
+    #[test]
+    fn encloses_only_relative_paths_without_parents() {
+        assert!(is_enclosed("Bot.java"));
+        assert!(is_enclosed("./src/Bot.java"));
+        assert!(!is_enclosed("../Bot.java"));
+        assert!(!is_enclosed("src/../../Bot.java"));
+        assert!(!is_enclosed("/Bot.java"));
+    }
+}
@@ -0,0 +1,58 @@
+use diesel::result::Error;
+
+use crate::{
+    db::operations_bot::get_bots_by_ids,
+    models::{bot::{BotMetadata, GameBot, DEFAULT_BOT_LANGUAGE}, game_2v2::Game2v2},
+};
+
+/// Longest bot name that's accepted.
+pub const MAX_BOT_NAME_LENGTH: usize = 64;
+/// Longest bot description that's accepted.
+pub const MAX_BOT_DESCRIPTION_LENGTH: usize = 2000;
+/// Longest language name that's accepted.
+pub const MAX_BOT_LANGUAGE_LENGTH: usize = 32;
+
+/// Trims a bot's metadata and checks that it's usable. An empty name is allowed, the bot is
+/// then shown under its file name; an empty language is the default one.
+pub fn validate_bot_metadata(metadata: BotMetadata) -> Result<BotMetadata, String> {
+    let name = metadata.name.trim().to_string();
+    if name.chars().count() > MAX_BOT_NAME_LENGTH {
+        return Err(format!("Bot name can have at most {} characters", MAX_BOT_NAME_LENGTH));
+    }
+    let description = metadata.description.trim().to_string();
+    if description.chars().count() > MAX_BOT_DESCRIPTION_LENGTH {
+        return Err(format!("Bot description can have at most {} characters", MAX_BOT_DESCRIPTION_LENGTH));
+    }
+    let language = match metadata.language.trim() {
+        "" => DEFAULT_BOT_LANGUAGE.to_string(),
+        l => l.to_string(),
+    };
+    if language.chars().count() > MAX_BOT_LANGUAGE_LENGTH {
+        return Err(format!("Bot language can have at most {} characters", MAX_BOT_LANGUAGE_LENGTH));
+    }
+    Ok(BotMetadata { name, description, language })
+}
+
+/// The four bots of a game with the names they are shown under, in slot order. Bots that no
+/// longer exist are listed under their id.
+pub fn game_bots(game: &Game2v2) -> Result<Vec<GameBot>, Error> {
+    let slots = [
+        ("team1bot1", &game.team1bot1_id),
+        ("team1bot2", &game.team1bot2_id),
+        ("team2bot1", &game.team2bot1_id),
+        ("team2bot2", &game.team2bot2_id),
+    ];
+    let bots = get_bots_by_ids(slots.iter().map(|(_, id)| id.to_string()).collect())?;
+    Ok(slots
+        .into_iter()
+        .map(|(slot, bot_id)| {
+            let bot = bots.iter().find(|b| &b.id == bot_id);
+            GameBot {
+                slot: slot.to_string(),
+                bot_id: bot_id.clone(),
+                name: bot.map(|b| b.public_name()).unwrap_or_else(|| bot_id.clone()),
+                language: bot.map(|b| b.language.clone()).unwrap_or_default(),
+            }
+        })
+        .collect())
+}
\ No newline at end of file
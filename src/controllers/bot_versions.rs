@@ -0,0 +1,97 @@
+use chrono::NaiveDateTime;
+use tracing::{error, info};
+
+use crate::{
+    db::{operations_bot_versions::insert_bot_version, operations_teams::set_team_bot},
+    models::{
+        bot::Bot,
+        bot_version::{BotVersion, CompileStatus, NewBotVersion},
+        errors::MatchMakerError,
+        team::{BotSelector, Team},
+    },
+};
+
+use super::file_handler::{file_sha256, local_copy};
+
+/// Makes a bot the active bot of one of the team's slots and records it as a new version
+/// of that slot.
+///
+/// The checksum of the bot's source archive is stored with the version, if it can't be
+/// computed the version is recorded without one.
+///
+pub fn activate_bot(team: &Team, slot: BotSelector, bot: &Bot) -> Result<BotVersion, MatchMakerError> {
+    set_team_bot(team, slot, bot.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+
+    let source_sha256 = match local_copy(&bot.source_path).and_then(|path| file_sha256(&path)) {
+        Ok(sha) => sha,
+        Err(e) => {
+            error!("Failed computing checksum of bot {}: {:?}", bot.id, e);
+            "".to_string()
+        }
+    };
+    insert_bot_version(NewBotVersion {
+        team_id: team.id.clone(),
+        slot,
+        bot_id: bot.id.clone(),
+        source_sha256,
+        compile_status: if bot.compile_error.is_empty() { CompileStatus::Ok } else { CompileStatus::Failed },
+        uploaded: bot.created,
+    }).map_err(MatchMakerError::DatabaseError)
+}
+
+/// Picks the version a slot is rolled back to.
+///
+/// With a `version_id` that version is used, it has to belong to the slot. Without one, the
+/// newest version of the slot running a different bot than the active one is used.
+///
+/// # Arguments
+///
+/// * `versions` - The team's versions, newest first.
+/// * `slot` - The slot to roll back.
+/// * `active_bot_id` - The bot currently active in the slot.
+/// * `version_id` - Optionally the version to roll back to.
+///
+pub fn rollback_target(versions: Vec<BotVersion>, slot: BotSelector, active_bot_id: &str, version_id: Option<String>) -> Option<BotVersion> {
+    let mut slot_versions = versions.into_iter().filter(|v| v.slot == slot);
+    match version_id {
+        Some(vid) => slot_versions.find(|v| v.id == vid),
+        None => slot_versions.find(|v| v.bot_id != active_bot_id),
+    }
+}
+
+/// The team with the bots it had active at the round's submission cutoff.
+///
+/// A slot changed after the cutoff plays the newest version activated before it. A slot
+/// without any version before the cutoff is emptied, so the team sits out the round, unless
+/// the slot has no recorded versions at all, then its current bot is kept.
+///
+/// # Arguments
+///
+/// * `team` - The team with its current bots.
+/// * `versions` - Versions of the team's slots, newest first.
+/// * `cutoff` - The round's submission cutoff.
+///
+pub fn bots_at_cutoff(mut team: Team, versions: &[BotVersion], cutoff: NaiveDateTime) -> Team {
+    for slot in [BotSelector::First, BotSelector::Second] {
+        let mut slot_versions = versions
+            .iter()
+            .filter(|v| v.team_id == team.id && v.slot == slot)
+            .peekable();
+        if slot_versions.peek().is_none() {
+            continue;
+        }
+        let bot_id = slot_versions
+            .find(|v| v.created <= cutoff)
+            .map(|v| v.bot_id.clone())
+            .unwrap_or_default();
+        let active = match slot {
+            BotSelector::First => &mut team.bot1,
+            BotSelector::Second => &mut team.bot2,
+        };
+        if *active != bot_id {
+            info!(team_id = %team.id, %slot, "Bot changed after the submission cutoff, playing {:?} instead", bot_id);
+            *active = bot_id;
+        }
+    }
+    team
+}
\ No newline at end of file
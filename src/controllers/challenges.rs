@@ -0,0 +1,102 @@
+use std::fs;
+
+use chrono::{Duration, Local};
+use diesel::result::Error;
+use tracing::{error, info, warn};
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::{
+        operations_challenges::{count_challenges_sent_since, fail_running_challenges, finish_challenge},
+        operations_competition::get_competition_by_id,
+        operations_teams::get_team_by_id,
+    },
+    models::{
+        challenge::{Challenge, ChallengeStatus},
+        errors::MatchMakerError,
+        game_2v2::NewGame2v2,
+    },
+};
+
+use super::{file_handler::{persist_replay, save_to_zip}, matchmaker_2v2::play_unranked_game, workload_gate::acquire_unranked_slot};
+
+/// Whether the team may send another challenge, teams may send
+/// `rate_limits.challenges_per_day` challenges in any 24 hours.
+pub fn may_send_challenge(team_id: String) -> Result<bool, Error> {
+    let limit = settings().rate_limits.challenges_per_day;
+    if limit == 0 {
+        return Ok(true);
+    }
+    let since = Local::now().naive_utc() - Duration::days(1);
+    Ok(count_challenges_sent_since(team_id, since)? < limit as i64)
+}
+
+/// Plays the game of an accepted challenge and stores its outcome.
+///
+/// Both teams play with the bots they have selected when the game starts. The game is unrated
+/// unranked work: it yields to ranked rounds and isn't stored as a game of the competition.
+/// Its replay is kept in `<games>/challenges/<challenge id>.zip` for the two teams.
+pub fn play_challenge(mut challenge: Challenge) {
+    let _permit = acquire_unranked_slot(None);
+    info!("Playing challenge {} of team {} against team {}", challenge.id, challenge.challenger_id, challenge.challenged_id);
+
+    match play_challenge_game(&mut challenge) {
+        Ok(_) => challenge.status = ChallengeStatus::Finished,
+        Err(e) => {
+            warn!("Challenge {} failed: {}", challenge.id, e);
+            challenge.status = ChallengeStatus::Failed;
+            challenge.error = e.to_string();
+        },
+    }
+    if let Err(e) = finish_challenge(&challenge) {
+        error!("Failed storing the outcome of challenge {}: {:?}", challenge.id, e);
+    }
+}
+
+/// Fails the challenges that were being played when the server stopped.
+pub fn fail_interrupted_challenges() {
+    match fail_running_challenges() {
+        Ok(0) => (),
+        Ok(n) => info!("Failed {} challenges interrupted by a restart", n),
+        Err(e) => error!("Failed marking interrupted challenges: {:?}", e),
+    }
+}
+
+fn play_challenge_game(challenge: &mut Challenge) -> Result<(), MatchMakerError> {
+    let challenger = get_team_by_id(challenge.challenger_id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let challenged = get_team_by_id(challenge.challenged_id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let competition = get_competition_by_id(challenge.competition_id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(&competition)?;
+
+    let mut match_game = NewGame2v2::new(
+        competition.id.clone(),
+        competition.round,
+        challenger.id.clone(),
+        challenged.id.clone(),
+        challenger.bot1.clone(),
+        challenger.bot2.clone(),
+        challenged.bot1.clone(),
+        challenged.bot2.clone(),
+    );
+    let sources = vec![
+        settings().paths.bots_workdir.join(&challenger.bot1),
+        settings().paths.bots_workdir.join(&challenger.bot2),
+        settings().paths.bots_workdir.join(&challenged.bot1),
+        settings().paths.bots_workdir.join(&challenged.bot2),
+    ];
+    let played = play_unranked_game(adapter.as_ref(), &mut match_game, sources)?;
+
+    challenge.winner_id = match_game.winner_id.clone();
+    challenge.challenger_score = match_game.team1_score;
+    challenge.challenged_score = match_game.team2_score;
+    challenge.error = match_game.additional_data.clone();
+
+    let replay_dir = settings().paths.games.join("challenges");
+    let replay_file = replay_dir.join(format!("{}.zip", challenge.id)).to_string_lossy().to_string();
+    fs::create_dir_all(&replay_dir).map_err(MatchMakerError::IOError)?;
+    save_to_zip(played.replay.join("\n"), &replay_file)?;
+    persist_replay(&replay_file)?;
+    challenge.log_file_path = replay_file;
+    Ok(())
+}
\ No newline at end of file
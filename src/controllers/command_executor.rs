@@ -1,7 +1,18 @@
-use std::fs;
+use std::{fs, thread};
 use std::io::{Error, ErrorKind, BufReader, BufRead};
+use std::os::unix::process::CommandExt;
 use std::path::Path;
-use std::process::{Command, Stdio};
+use std::process::{Command, Stdio, ExitStatus};
+use std::time::Duration;
+
+use wait_timeout::ChildExt;
+
+pub struct TimedCommandOutput {
+    /// Exit status of the command, `None` if it was killed after the timeout.
+    pub status: Option<ExitStatus>,
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+}
 
 
 pub fn execute_command(command: String, args: Vec<&str>) -> std::io::Result<Vec<String>> {
@@ -29,6 +40,52 @@ pub fn execute_command(command: String, args: Vec<&str>) -> std::io::Result<Vec<
     Ok(output_lines)
 }
 
+/// Executes a command, killing it if it doesn't finish within `timeout`.
+///
+/// Unlike `execute_command` a non-zero exit status is not treated as an error; both output
+/// streams and the exit status are returned so the caller can decide what to record.
+/// The command runs in its own process group, anything it spawned is killed once it exits or
+/// times out.
+pub fn execute_command_with_timeout(
+    command: String, 
+    args: Vec<&str>, 
+    envs: Vec<(&str, String)>, 
+    timeout: Duration
+) -> std::io::Result<TimedCommandOutput> {
+    let mut child = Command::new(command)
+        .args(&args)
+        .envs(envs)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .process_group(0)
+        .spawn()?;
+
+    let stdout = child.stdout.take().ok_or_else(|| Error::other("Could not capture standard output."))?;
+    let stderr = child.stderr.take().ok_or_else(|| Error::other("Could not capture standard error."))?;
+
+    // read both streams on their own threads so a chatty command can't block on a full pipe
+    let stdout_handle = thread::spawn(move || {
+        BufReader::new(stdout).lines().map_while(Result::ok).collect::<Vec<String>>()
+    });
+    let stderr_handle = thread::spawn(move || {
+        BufReader::new(stderr).lines().map_while(Result::ok).collect::<Vec<String>>()
+    });
+
+    let status = child.wait_timeout(timeout)?;
+    // also after a normal exit: a background process the command left behind would keep the
+    // pipes open and the readers waiting forever. A negative pid targets the whole group
+    unsafe { libc::kill(-(child.id() as i32), libc::SIGKILL); }
+    if status.is_none() {
+        let _ = child.wait();
+    }
+
+    Ok(TimedCommandOutput {
+        status,
+        stdout: stdout_handle.join().unwrap_or_default(),
+        stderr: stderr_handle.join().unwrap_or_default(),
+    })
+}
+
 
 pub fn recursive_copy(src: &Path, dest: &Path) -> std::io::Result<()> {
     if !src.is_dir() {
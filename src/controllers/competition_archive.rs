@@ -0,0 +1,95 @@
+use std::{fs::{self, File}, io::{self, Write}, path::Path};
+
+use serde::Serialize;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    config::settings,
+    controllers::{leaderboard::build_leaderboard, storage::storage},
+    db::{
+        operations_competition::set_competition_archived,
+        operations_elo_history::get_elo_history_by_competition_id,
+        operations_game2v2::get_games_by_competition_id,
+        operations_teams::get_teams_by_competition_id,
+    },
+    models::{
+        competition::{Competition, PublicCompetition},
+        elo_history::PublicEloHistory,
+        errors::MatchMakerError,
+        game_2v2::PublicGame2v2,
+        team::PublicTeam,
+    },
+};
+
+/// Bundles everything a finished competition produced into a single ZIP archive and marks
+/// the competition as archived, which makes it read-only.
+///
+/// The archive contains:
+///
+/// * `competition.json` - the competition's configuration.
+/// * `standings.json` - the final leaderboard.
+/// * `teams.json` - all registered teams.
+/// * `games.json` - the records of all games.
+/// * `elo_history.json` - the rating changes of all teams.
+/// * `replays/<round>/<file>` - the replays that are still stored, as they are on disk.
+///
+/// # Returns
+///
+/// The path of the archive, `<archives>/<competition id>.zip`.
+///
+pub fn archive_competition(competition: Competition) -> Result<String, MatchMakerError> {
+    let standings = build_leaderboard(&competition).map_err(MatchMakerError::DatabaseError)?;
+    let teams = get_teams_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let games = get_games_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let elo_history = get_elo_history_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+
+    let archive_dir = &settings().paths.archives;
+    fs::create_dir_all(archive_dir).map_err(MatchMakerError::IOError)?;
+    let archive_path = archive_dir.join(format!("{}.zip", competition.id)).to_string_lossy().to_string();
+    // written next to the final path, so an interrupted export never looks complete
+    let partial_path = format!("{}.part", archive_path);
+
+    let file = File::create(&partial_path).map_err(MatchMakerError::IOError)?;
+    let mut zip = ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let replays: Vec<(i32, String)> = games
+        .iter()
+        .map(|g| (g.round, g.log_file_path.clone()))
+        .filter(|(_, path)| storage().exists(path).unwrap_or(false))
+        .collect();
+    let public_games: Vec<PublicGame2v2> = games.into_iter().map(PublicGame2v2::from).collect();
+    let public_teams: Vec<PublicTeam> = teams.into_iter().map(PublicTeam::from).collect();
+    let public_elo_history: Vec<PublicEloHistory> = elo_history.into_iter().map(PublicEloHistory::from).collect();
+
+    let competition_id = competition.id.clone();
+    write_json(&mut zip, "competition.json", &PublicCompetition::from(competition), options)?;
+    write_json(&mut zip, "standings.json", &standings, options)?;
+    write_json(&mut zip, "teams.json", &public_teams, options)?;
+    write_json(&mut zip, "games.json", &public_games, options)?;
+    write_json(&mut zip, "elo_history.json", &public_elo_history, options)?;
+
+    // replays are compressed already
+    let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+    for (round, path) in replays.iter() {
+        let file_name = match Path::new(path).file_name() {
+            Some(n) => n.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let contents = storage().get(path)?;
+        zip.start_file(format!("replays/{}/{}", round, file_name), stored)
+            .map_err(MatchMakerError::ZippingError)?;
+        zip.write_all(&contents).map_err(MatchMakerError::IOError)?;
+    }
+
+    zip.finish().map_err(MatchMakerError::ZippingError)?;
+    fs::rename(&partial_path, &archive_path).map_err(MatchMakerError::IOError)?;
+
+    set_competition_archived(competition_id, archive_path.clone()).map_err(MatchMakerError::DatabaseError)?;
+    Ok(archive_path)
+}
+
+fn write_json<T: Serialize>(zip: &mut ZipWriter<File>, name: &str, value: &T, options: FileOptions) -> Result<(), MatchMakerError> {
+    zip.start_file(name, options).map_err(MatchMakerError::ZippingError)?;
+    serde_json::to_writer_pretty(&mut *zip, value).map_err(|e| MatchMakerError::IOError(io::Error::from(e)))
+}
\ No newline at end of file
@@ -1,20 +1,46 @@
-use crate::{models::errors::MatchMakerError, db::operations_competition::get_running_competitions};
+use crate::{
+    models::{errors::MatchMakerError, job::Job},
+    db::operations_competition::{get_running_competitions, get_competition_by_id},
+};
 
-use super::matchmaker_2v2::run_2v2_round;
+use super::{matchmaker_2v2::run_2v2_round, trace::TraceContext, job_queue::enqueue_round};
 
 
-
-pub fn run_competitions_round() -> Result<(), MatchMakerError> {
+/// Queues the next round of every running competition, the job worker runs them one after
+/// another.
+pub fn queue_competitions_round() -> Result<Vec<Job>, MatchMakerError> {
     let competitions = match get_running_competitions() {
         Ok(c) => c,
         Err(e) => return Err(MatchMakerError::DatabaseError(e)),
     };
-    
+
+    let mut jobs = vec![];
     for competition in competitions.into_iter() {
-        match competition.type_.as_str() {
-            "2v2" => run_2v2_round(competition.id)?,
-            _ => continue,
+        if !has_rounds(&competition.type_) {
+            continue;
+        }
+        match enqueue_round(competition.id, &TraceContext::new()) {
+            Ok(job) => jobs.push(job),
+            Err(e) => return Err(MatchMakerError::DatabaseError(e)),
         }
     }
-    Ok(())
+    Ok(jobs)
+}
+
+/// Runs the round the job queued, according to the competition's type.
+pub async fn run_competition_round(job: &Job, trace: &TraceContext) -> Result<(), MatchMakerError> {
+    let competition = match get_competition_by_id(competition_id) {
+        Ok(c) => c,
+        Err(e) => return Err(MatchMakerError::DatabaseError(e)),
+    };
+
+    match competition.type_.as_str() {
+        "2v2" => run_2v2_round(competition.id, trace, job).await,
+        _ => Ok(()),
+    }
+}
+
+/// Whether competitions of the given type are played in rounds by the matchmaker.
+pub fn has_rounds(competition_type: &str) -> bool {
+    competition_type == "2v2"
 }
\ No newline at end of file
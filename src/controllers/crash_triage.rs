@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    db::{operations_game2v2::get_games_by_competition_id, operations_teams::get_teams_by_competition_id},
+    models::{
+        crash_report::{CrashCounts, CrashReport, TeamCrashes},
+        errors::MatchMakerError,
+        game_player_stats::{FailureKind, GameError},
+    },
+};
+
+/// Stderr patterns of each kind of failure, matched case-insensitively in this order. The
+/// first kind with a matching line wins. Timeouts come first: a game killed for running too
+/// long, stalling or filling its disk quota dies of a SIGKILL as well, which says nothing
+/// about its memory.
+const FAILURE_PATTERNS: [(FailureKind, &[&str]); 3] = [
+    (FailureKind::BotTimeout, &[
+        "timeoutexception",
+        "timed out",
+        "took too long",
+        "did not respond",
+        "not responding",
+    ]),
+    // java.lang.OutOfMemoryError, whatever the JVM ran out of
+    (FailureKind::OutOfMemory, &[
+        "outofmemoryerror",
+    ]),
+    (FailureKind::BotException, &[
+        "exception in thread",
+        "exception:",
+    ]),
+];
+
+/// Classifies why a game crashed from the stderr of its evaluator.
+///
+/// Out of memory and timeouts are recognised with or without a bot to blame. Any other
+/// failure is a bot exception if a bot could be blamed for it and an error of the evaluator
+/// itself if none could.
+///
+/// # Arguments
+///
+/// * `errors` - The stderr lines of the game.
+/// * `bot_blamed` - Whether the id of one of the game's bots shows up in `errors`.
+///
+pub fn classify_failure(errors: &[String], bot_blamed: bool) -> FailureKind {
+    let lines = errors.iter().map(|l| l.to_lowercase()).collect::<Vec<String>>();
+    let kind = FAILURE_PATTERNS
+        .iter()
+        .find(|(_, patterns)| lines.iter().any(|line| patterns.iter().any(|p| line.contains(p))))
+        .map(|(kind, _)| *kind);
+
+    match (kind, bot_blamed) {
+        (Some(FailureKind::BotException), false) => FailureKind::EvaluatorError,
+        (Some(kind), _) => kind,
+        (None, true) => FailureKind::Unknown,
+        (None, false) => FailureKind::EvaluatorError,
+    }
+}
+
+/// Whether a game crashed because of the host instead of one of its bots: the evaluator
+/// itself failed, or the host ran out of memory with no bot to blame.
+///
+/// # Arguments
+///
+/// * `errors` - The stderr lines of the game.
+/// * `bot_ids` - Ids of the game's bots.
+///
+pub fn is_infrastructure_failure(errors: &[String], bot_ids: &[&str]) -> bool {
+    let bot_blamed = errors
+        .iter()
+        .any(|line| bot_ids.iter().any(|id| !id.is_empty() && line.contains(id)));
+    match classify_failure(errors, bot_blamed) {
+        FailureKind::EvaluatorError => true,
+        FailureKind::OutOfMemory => !bot_blamed,
+        _ => false,
+    }
+}
+
+/// Whether a game couldn't be played because of the host, like the evaluator not starting
+/// or a file it needs missing.
+pub fn is_infrastructure_error(error: &MatchMakerError) -> bool {
+    matches!(error, MatchMakerError::IOError(_) | MatchMakerError::InvalidPath(_) | MatchMakerError::GameProcessFailed)
+}
+
+/// Counts the crashed games of a competition per team and kind of failure. A crash counts
+/// for the team whose bot was blamed for it.
+pub fn crash_report(competition_id: String) -> Result<CrashReport, Error> {
+    let games = get_games_by_competition_id(competition_id.clone())?;
+
+    let mut totals = CrashCounts::default();
+    let mut per_team: HashMap<String, CrashCounts> = HashMap::new();
+    for game in games.iter().filter(|g| !g.additional_data.is_empty()) {
+        let game_error = match serde_json::from_str::<GameError>(&game.additional_data) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        totals.count(game_error.failure_kind);
+
+        let blamed_team = if [&game.team1bot1_id, &game.team1bot2_id].contains(&&game_error.blame_id) {
+            Some(&game.team1_id)
+        } else if [&game.team2bot1_id, &game.team2bot2_id].contains(&&game_error.blame_id) {
+            Some(&game.team2_id)
+        } else {
+            None
+        };
+        if let Some(team_id) = blamed_team {
+            per_team.entry(team_id.clone()).or_default().count(game_error.failure_kind);
+        }
+    }
+
+    let mut teams = get_teams_by_competition_id(competition_id.clone())?
+        .into_iter()
+        .filter_map(|team| Some(TeamCrashes {
+            crashes: per_team.remove(&team.id)?,
+            team_id: team.id,
+            team_name: team.name,
+        }))
+        .collect::<Vec<TeamCrashes>>();
+    teams.sort_by_key(|t| std::cmp::Reverse(t.crashes.total));
+
+    Ok(CrashReport { competition_id, totals, teams })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOT_ID: &str = "8f14e45f-bot";
+
+    fn lines(stderr: &[&str]) -> Vec<String> {
+        stderr.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn classifies_stderr_samples() {
+        let cases: [(&[&str], bool, FailureKind); 13] = [
+            (&["Exception in thread \"main\" java.lang.NullPointerException", "\tat Bot.main(Bot.java:12)"], true, FailureKind::BotException),
+            (&["java.lang.ArrayIndexOutOfBoundsException: Index 5 out of bounds for length 5"], true, FailureKind::BotException),
+            (&["java.util.concurrent.TimeoutException"], true, FailureKind::BotTimeout),
+            (&["Player 8f14e45f-bot timed out after 1000ms"], true, FailureKind::BotTimeout),
+            (&["Player 8f14e45f-bot TOOK TOO LONG to respond"], false, FailureKind::BotTimeout),
+            (&["Bot did not respond in time"], true, FailureKind::BotTimeout),
+            (&["Exception in thread \"main\" java.lang.OutOfMemoryError: Java heap space"], true, FailureKind::OutOfMemory),
+            (&["java.lang.OutOfMemoryError: GC overhead limit exceeded"], false, FailureKind::OutOfMemory),
+            // a game killed for running too long is a timeout, however its memory looked
+            (&["java.lang.OutOfMemoryError: Metaspace", "Player 8f14e45f-bot timed out"], true, FailureKind::BotTimeout),
+            (&["Exception in thread \"main\" java.lang.IllegalStateException: bad map"], false, FailureKind::EvaluatorError),
+            (&["Error: Unable to access jarfile Evaluator.jar"], false, FailureKind::EvaluatorError),
+            (&["Killed"], true, FailureKind::Unknown),
+            (&[], false, FailureKind::EvaluatorError),
+        ];
+        for (stderr, bot_blamed, expected) in cases {
+            assert_eq!(classify_failure(&lines(stderr), bot_blamed), expected, "{:?}", stderr);
+        }
+    }
+
+    #[test]
+    fn mentions_of_memory_alone_are_no_out_of_memory() {
+        let stderr = lines(&["Bot 8f14e45f-bot: using 12MB of memory", "Killed"]);
+        assert_eq!(classify_failure(&stderr, true), FailureKind::Unknown);
+    }
+
+    #[test]
+    fn recognises_infrastructure_failures() {
+        let cases: [(&[&str], bool); 8] = [
+            (&["Error: Unable to access jarfile Evaluator.jar"], true),
+            (&["Exception in thread \"main\" java.lang.IllegalStateException: bad map"], true),
+            (&["java.lang.OutOfMemoryError: unable to create native thread"], true),
+            (&[], true),
+            (&["java.lang.OutOfMemoryError: Java heap space", "\tat 8f14e45f-bot.Bot.plan(Bot.java:40)"], false),
+            (&["Exception in thread \"main\" java.lang.NullPointerException", "\tat 8f14e45f-bot.Bot.main(Bot.java:12)"], false),
+            (&["Player 8f14e45f-bot timed out"], false),
+            (&["Player 8f14e45f-bot exited", "Killed"], false),
+        ];
+        for (stderr, expected) in cases {
+            assert_eq!(is_infrastructure_failure(&lines(stderr), &[BOT_ID, "other-bot"]), expected, "{:?}", stderr);
+        }
+    }
+
+    #[test]
+    fn empty_bot_ids_blame_no_one() {
+        let stderr = lines(&["java.lang.OutOfMemoryError: Java heap space"]);
+        assert!(is_infrastructure_failure(&stderr, &["", ""]));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{cmp::Reverse, collections::HashMap, thread, time::Duration};
+
+use chrono::Utc;
+use serde_json::{json, Value};
+use tracing::warn;
+
+use crate::{
+    db::{
+        operations_discord_channels::get_discord_channel_by_competition_id,
+        operations_elo_history::get_elo_history_by_round,
+    },
+    models::{
+        competition::{Competition, ScoringSystem},
+        discord_channel::MAX_STANDINGS_SIZE,
+        elo_history::EloHistory,
+        errors::MatchMakerError,
+        game_2v2::Game2v2,
+        leaderboard::LeaderboardEntry,
+    },
+};
+
+use super::{command_executor::execute_command_with_timeout, leaderboard::build_leaderboard};
+
+/// Teams listed as top movers in each direction, and upsets listed.
+const HIGHLIGHT_COUNT: usize = 3;
+/// Discord rejects embed fields longer than this.
+const FIELD_VALUE_LIMIT: usize = 1024;
+const DISCORD_TIMEOUT_SECS: u64 = 10;
+/// Embed color, Batalja blue.
+const EMBED_COLOR: u32 = 0x3B82F6;
+
+/// Posts a summary of the round that was just played to the competition's Discord channel,
+/// if it has one: the teams whose ELO moved the most, the biggest upsets (the wins that
+/// earned the most ELO, so the least expected ones) and the top of the standings.
+///
+/// The round's ratings have to be stored already. The message is sent in the background,
+/// a failed delivery is only logged.
+///
+/// # Errors
+///
+/// Returns `MatchMakerError::DatabaseError` if the channel, the standings or the round's
+/// ELO changes can't be read.
+///
+pub fn post_round_summary(competition: &Competition, games: &[Game2v2]) -> Result<(), MatchMakerError> {
+    let channel = match get_discord_channel_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)? {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+
+    let standings = build_leaderboard(competition).map_err(MatchMakerError::DatabaseError)?;
+    let history = get_elo_history_by_round(competition.id.clone(), competition.round)
+        .map_err(MatchMakerError::DatabaseError)?;
+    let names: HashMap<&str, &str> = standings
+        .iter()
+        .map(|e| (e.team_id.as_str(), e.team_name.as_str()))
+        .collect();
+
+    let standings_size = channel.standings_size.clamp(1, MAX_STANDINGS_SIZE) as usize;
+    let message = json!({
+        "username": "Batalja",
+        "embeds": [{
+            "title": format!("{} - round {} played", competition.name, competition.round),
+            "description": format!("{} games played", games.len()),
+            "color": EMBED_COLOR,
+            "fields": [
+                embed_field("Top movers", top_movers(&history, &names)),
+                embed_field("Biggest upsets", biggest_upsets(games, &names)),
+                embed_field("Standings", standings_lines(&standings, &competition.scoring_system, standings_size)),
+            ],
+            "timestamp": Utc::now().to_rfc3339(),
+        }],
+    }).to_string();
+
+    let webhook_url = channel.webhook_url;
+    thread::spawn(move || {
+        let max_time = DISCORD_TIMEOUT_SECS.to_string();
+        let result = execute_command_with_timeout(
+            "curl".to_string(),
+            vec![
+                "-sS", "--fail",
+                "-X", "POST",
+                "-H", "Content-Type: application/json",
+                "--max-time", &max_time,
+                "--data-binary", &message,
+                &webhook_url,
+            ],
+            vec![],
+            // give curl the chance to report its own timeout first
+            Duration::from_secs(DISCORD_TIMEOUT_SECS + 5),
+        );
+        match result {
+            Ok(out) if out.status.map(|s| s.success()).unwrap_or(false) => (),
+            Ok(out) => warn!("Failed posting the round summary to Discord: {}", out.stderr.join("\n")),
+            Err(e) => warn!("Failed posting the round summary to Discord: {}", e),
+        }
+    });
+    Ok(())
+}
+
+fn top_movers(history: &[EloHistory], names: &HashMap<&str, &str>) -> String {
+    let mut changes: Vec<&EloHistory> = history.iter().filter(|h| h.elo_change != 0).collect();
+    changes.sort_by_key(|h| Reverse(h.elo_change));
+
+    let gains = changes.iter().take_while(|h| h.elo_change > 0).take(HIGHLIGHT_COUNT);
+    let losses = changes.iter().rev().take_while(|h| h.elo_change < 0).take(HIGHLIGHT_COUNT);
+    gains
+        .chain(losses)
+        .map(|h| format!(
+            "{} **{}** {:+} ({})",
+            if h.elo_change > 0 { "▲" } else { "▼" },
+            team_name(names, &h.team_id),
+            h.elo_change,
+            h.elo,
+        ))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn biggest_upsets(games: &[Game2v2], names: &HashMap<&str, &str>) -> String {
+    // a win earns more ELO the less likely it was
+    let mut wins: Vec<(&Game2v2, i32)> = games
+        .iter()
+        .filter(|g| !g.winner_id.is_empty() && g.team1_id != g.team2_id)
+        .map(|g| (g, if g.winner_id == g.team1_id { g.team1_elo } else { g.team2_elo }))
+        .filter(|(_, gain)| *gain > 0)
+        .collect();
+    wins.sort_by_key(|(_, gain)| Reverse(*gain));
+
+    wins.iter()
+        .take(HIGHLIGHT_COUNT)
+        .map(|(g, gain)| {
+            let (winner, loser, winner_score, loser_score) = if g.winner_id == g.team1_id {
+                (&g.team1_id, &g.team2_id, g.team1_score, g.team2_score)
+            } else {
+                (&g.team2_id, &g.team1_id, g.team2_score, g.team1_score)
+            };
+            format!(
+                "**{}** beat **{}** {}-{} ({:+})",
+                team_name(names, winner),
+                team_name(names, loser),
+                winner_score,
+                loser_score,
+                gain,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn standings_lines(standings: &[LeaderboardEntry], scoring: &ScoringSystem, size: usize) -> String {
+    standings
+        .iter()
+        .take(size)
+        .map(|e| {
+            let rating = match scoring {
+                ScoringSystem::Points => format!("{} pts", e.points),
+                _ => format!("{} ELO", e.elo),
+            };
+            format!("`{:>2}.` **{}** {} ({}W {}L {}D)", e.rank, e.team_name, rating, e.wins, e.losses, e.draws)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn team_name<'a>(names: &HashMap<&str, &'a str>, team_id: &'a str) -> &'a str {
+    names.get(team_id).copied().unwrap_or(team_id)
+}
+
+/// An embed field cut to the length Discord accepts, Discord doesn't accept empty fields either.
+fn embed_field(name: &str, value: String) -> Value {
+    let value = if value.is_empty() {
+        "-".to_string()
+    } else if value.chars().count() > FIELD_VALUE_LIMIT {
+        let cut: String = value.chars().take(FIELD_VALUE_LIMIT - 3).collect();
+        format!("{}...", cut)
+    } else {
+        value
+    };
+    json!({ "name": name, "value": value })
+}
\ No newline at end of file
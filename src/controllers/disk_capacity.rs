@@ -0,0 +1,148 @@
+use std::{collections::HashMap, fs, os::unix::fs::MetadataExt, path::Path};
+
+use tracing::{error, warn};
+
+use crate::{
+    config::settings,
+    db::{operations_game2v2::get_games_by_competition_id, operations_round_events::insert_round_event},
+    models::{competition::Competition, errors::MatchMakerError, game_2v2::ReplayState, round_event::NewRoundEvent, team::Team},
+};
+
+use super::{alert_signals::record_round_disk, disk_emergency::free_disk_space, match_workspace::{matches_dir, workspace_size}, storage::storage};
+
+/// Replay size assumed when no earlier round left replays to measure.
+const DEFAULT_REPLAY_BYTES: u64 = 2 * 1024 * 1024;
+/// Headroom on top of the estimate, in percent.
+const ESTIMATE_MARGIN_PERCENT: u64 = 25;
+
+/// Checks that there is enough free disk for a round before any of its games is played.
+///
+/// Every match copies its four bots into a folder of the matches directory, which is only
+/// cleaned up after the round, and stores a replay in the games directory. The bots are
+/// measured in the bots' work directory, replays are estimated from the average replay the
+/// competition's previous round stored, replays kept in object storage don't stay on the
+/// disk. If both directories are on the same disk their needs are added up.
+///
+/// The estimate is exported as a metric, and a round with less than twice the disk it needs
+/// is recorded as a warning in the round event log.
+///
+/// # Arguments
+///
+/// * `competition` - The competition the round belongs to.
+/// * `match_pairs` - The matches the round is about to play.
+/// * `trace_id` - Trace of the round, the warning is recorded under it.
+///
+/// # Errors
+///
+/// `MatchMakerError::InsufficientDisk` if either disk has less free space than the round is
+/// estimated to need.
+///
+pub fn check_round_capacity(competition: &Competition, match_pairs: &[(Team, Team)], trace_id: &str) -> Result<(), MatchMakerError> {
+    let games_dir = settings().paths.games.clone();
+    let matches_path = matches_dir();
+
+    let replays_needed = with_margin(replay_estimate(competition) * match_pairs.len() as u64);
+    let workspaces_needed = with_margin(workspaces_estimate(match_pairs));
+
+    let needs = if same_disk(&games_dir, &matches_path) {
+        vec![(games_dir, replays_needed + workspaces_needed)]
+    } else {
+        vec![(games_dir, replays_needed), (matches_path, workspaces_needed)]
+    };
+
+    let mut shortest: Option<(u64, u64)> = None;
+    for (path, needed) in needs.into_iter() {
+        let free = match free_disk_space(&path.to_string_lossy()) {
+            Ok(free) => free,
+            Err(e) => {
+                error!("Failed checking free disk space of {}: {}", path.display(), e);
+                continue;
+            },
+        };
+        // the disk with the least room to spare is the one reported
+        let headroom = free as i128 - needed as i128;
+        if shortest.is_none_or(|(n, f)| headroom < f as i128 - n as i128) {
+            shortest = Some((needed, free));
+        }
+    }
+
+    let (needed, free) = match shortest {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+    record_round_disk(needed, free);
+    if free < needed {
+        let message = format!("Round needs about {} MB of disk, {} MB free", needed / 1024 / 1024, free / 1024 / 1024);
+        record_capacity_event(competition, trace_id, "FAILED", message);
+        return Err(MatchMakerError::InsufficientDisk(needed, free));
+    }
+    if free < needed.saturating_mul(2) {
+        let message = format!("Low disk: round needs about {} MB, {} MB free", needed / 1024 / 1024, free / 1024 / 1024);
+        warn!("{}", message);
+        record_capacity_event(competition, trace_id, "WARNING", message);
+    }
+    Ok(())
+}
+
+/// Average size of the replays the competition's previous round stored on the local disk, in
+/// bytes. Replays uploaded to object storage are removed from the disk right away.
+fn replay_estimate(competition: &Competition) -> u64 {
+    if storage().is_remote() {
+        return 0;
+    }
+    let games = match get_games_by_competition_id(competition.id.clone()) {
+        Ok(games) => games,
+        Err(e) => {
+            error!("Failed loading games to estimate replay sizes: {:?}", e);
+            return DEFAULT_REPLAY_BYTES;
+        },
+    };
+    let sizes = games
+        .iter()
+        .filter(|g| g.round == competition.round - 1 && g.replay_state == ReplayState::Stored)
+        .filter_map(|g| fs::metadata(&g.log_file_path).ok().map(|m| m.len()))
+        .collect::<Vec<u64>>();
+    if sizes.is_empty() {
+        return DEFAULT_REPLAY_BYTES;
+    }
+    sizes.iter().sum::<u64>() / sizes.len() as u64
+}
+
+/// Size of the bot copies all match folders of the round hold together, in bytes.
+fn workspaces_estimate(match_pairs: &[(Team, Team)]) -> u64 {
+    let bots_workdir = &settings().paths.bots_workdir;
+    let mut bot_sizes: HashMap<&str, u64> = HashMap::new();
+    let mut total = 0;
+    for (team1, team2) in match_pairs.iter() {
+        for bot_id in [&team1.bot1, &team1.bot2, &team2.bot1, &team2.bot2] {
+            total += *bot_sizes
+                .entry(bot_id.as_str())
+                .or_insert_with(|| workspace_size(&bots_workdir.join(bot_id)));
+        }
+    }
+    total
+}
+
+fn with_margin(bytes: u64) -> u64 {
+    bytes + bytes * ESTIMATE_MARGIN_PERCENT / 100
+}
+
+fn same_disk(a: &Path, b: &Path) -> bool {
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        _ => true,
+    }
+}
+
+fn record_capacity_event(competition: &Competition, trace_id: &str, status: &str, message: String) {
+    if let Err(e) = insert_round_event(NewRoundEvent {
+        competition_id: competition.id.clone(),
+        round: competition.round,
+        kind: "DISK_CAPACITY".to_string(),
+        status: status.to_string(),
+        message,
+        trace_id: trace_id.to_string(),
+    }) {
+        error!("Failed recording round event: {:?}", e);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use std::{ffi::CString, fs, io::Error, path::PathBuf, sync::atomic::{AtomicBool, Ordering}};
+use tracing::error;
+
+use crate::{
+    config::settings,
+    db::operations_round_events::insert_round_event,
+    models::{competition::Competition, errors::MatchMakerError, game_2v2::{NewGame2v2, ReplayState}, round_event::NewRoundEvent},
+};
+
+use super::file_handler::{persist_replay, save_to_zip};
+
+/// Set while the server is running in low-disk emergency mode.
+static EMERGENCY_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the server is currently in low-disk emergency mode.
+pub fn in_emergency_mode() -> bool {
+    EMERGENCY_MODE.load(Ordering::SeqCst)
+}
+
+/// Stores the replay of a finished game.
+///
+/// Normally the replay is written to `<games directory>/<round>/<game id>.zip`. When the free
+/// space on that disk drops below the storage's `emergency_threshold_mb`, or a write
+/// fails because the disk is full, the server switches to emergency mode:
+///
+/// * if the `replay_offload` path is set (a mounted object store), the replay is streamed there
+///   directly and the game is flagged as `OFFLOADED`,
+/// * otherwise the turn data is dropped, only the results are kept and the game is flagged
+///   as `DROPPED`.
+///
+/// Entering and leaving emergency mode is recorded in the round event log so admins are
+/// alerted, instead of the games failing with IO errors.
+///
+/// # Arguments
+///
+/// * `competition` - The competition the game belongs to.
+/// * `contents` - The game's output.
+/// * `match_game` - The game, its `log_file_path` and `replay_state` are updated.
+///
+pub fn store_replay(competition: &Competition, contents: String, match_game: &mut NewGame2v2) -> Result<(), MatchMakerError> {
+    if !disk_is_low() {
+        leave_emergency_mode(competition, &match_game.trace_id);
+        let output_file = format!("{}/{}/{}.zip", settings().paths.games.display(), competition.round, match_game.id);
+        match save_to_zip(contents.clone(), &output_file) {
+            Ok(_) => {
+                persist_replay(&output_file)?;
+                match_game.log_file_path = output_file;
+                match_game.replay_state = ReplayState::Stored;
+                return Ok(());
+            },
+            Err(MatchMakerError::IOError(e)) if is_disk_full(&e) => {
+                let _ = fs::remove_file(&output_file);
+            },
+            Err(e) => return Err(e),
+        }
+    }
+
+    enter_emergency_mode(competition, &match_game.trace_id);
+    if let Some(offload_dir) = offload_dir() {
+        let round_dir = offload_dir.join(competition.round.to_string());
+        let output_file = round_dir.join(format!("{}.zip", match_game.id)).to_string_lossy().to_string();
+        let offloaded = fs::create_dir_all(&round_dir)
+            .map_err(MatchMakerError::IOError)
+            .and_then(|_| save_to_zip(contents, &output_file))
+            .and_then(|_| persist_replay(&output_file));
+
+        match offloaded {
+            Ok(_) => {
+                match_game.log_file_path = output_file;
+                match_game.replay_state = ReplayState::Offloaded;
+                return Ok(());
+            },
+            Err(e) => error!("Failed offloading replay of game {}: {:?}", match_game.id, e),
+        }
+    }
+
+    match_game.log_file_path = "".to_string();
+    match_game.replay_state = ReplayState::Dropped;
+    Ok(())
+}
+
+/// Returns the free space on the disk holding the game replays in bytes.
+pub fn free_disk_space(path: &str) -> Result<u64, Error> {
+    let c_path = CString::new(path).map_err(Error::other)?;
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return Err(Error::last_os_error());
+    }
+    Ok(stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+fn disk_is_low() -> bool {
+    let threshold = settings().storage.emergency_threshold_mb;
+
+    match free_disk_space(&settings().paths.games.to_string_lossy()) {
+        Ok(free) => free < threshold * 1024 * 1024,
+        Err(e) => {
+            error!("Failed checking free disk space: {}", e);
+            false
+        }
+    }
+}
+
+fn is_disk_full(e: &Error) -> bool {
+    e.raw_os_error() == Some(libc::ENOSPC)
+}
+
+fn offload_dir() -> Option<PathBuf> {
+    settings().paths.replay_offload.clone().filter(|d| !d.as_os_str().is_empty())
+}
+
+fn enter_emergency_mode(competition: &Competition, trace_id: &str) {
+    if EMERGENCY_MODE.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let message = match offload_dir() {
+        Some(dir) => format!("Low disk space, replays are offloaded to {}", dir.display()),
+        None => "Low disk space, replays are dropped and only results are kept".to_string(),
+    };
+    error!("{}", message);
+    record_disk_event(competition, trace_id, "ALERT", message);
+}
+
+fn leave_emergency_mode(competition: &Competition, trace_id: &str) {
+    if !EMERGENCY_MODE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    record_disk_event(competition, trace_id, "RECOVERED", "Disk space recovered, replays are stored locally again".to_string());
+}
+
+fn record_disk_event(competition: &Competition, trace_id: &str, status: &str, message: String) {
+    if let Err(e) = insert_round_event(NewRoundEvent {
+        competition_id: competition.id.clone(),
+        round: competition.round,
+        kind: "DISK_EMERGENCY".to_string(),
+        status: status.to_string(),
+        message,
+        trace_id: trace_id.to_string(),
+    }) {
+        error!("Failed recording round event: {:?}", e);
+    }
+}
\ No newline at end of file
@@ -1,22 +1,134 @@
+use std::collections::HashMap;
+
 use diesel::result::Error;
 
-use crate::{models::game_2v2::{Game2v2, NewGame2v2}, db::operations_teams::{get_team_by_id, add_elo_change}};
+use crate::{
+    config::settings,
+    models::{
+        game_2v2::{Game2v2, NewGame2v2},
+        team::{Team, TeamRating, DEFAULT_ELO, DEFAULT_RATING_MU, DEFAULT_RATING_SIGMA},
+        elo_history::NewEloHistory,
+        competition::Competition,
+        rating_recompute::GameRatingShadow,
+    }, 
+    db::{
+        operations_teams::get_team_by_id,
+        operations_game2v2::{count_games_by_team_id, count_games_by_team_before_round},
+        operations_elo_history::get_elo_history_by_round,
+    }
+};
+
+/// Rating changes of a round, computed up front so they can be stored together with the
+/// round's games.
+pub struct RoundRatings {
+    /// Ratings of every team that played, after all games of the round are applied.
+    pub teams: Vec<TeamRating>,
+    /// An `elo_history` entry with the team's new ELO and its total change in the round.
+    pub history: Vec<NewEloHistory>,
+}
+
+/// K-factors a game is rated with, a competition's own or the overrides of a recompute.
+#[derive(Debug, Clone)]
+pub struct RatingSettings {
+    pub k_factor: i32,
+    pub provisional_games: i32,
+    pub provisional_k_factor: i32,
+}
+
+/// Ratings of a competition recomputed from its games, see `replay_ratings`.
+pub struct ReplayedRatings {
+    /// Final ratings of every team that played.
+    pub teams: Vec<TeamRating>,
+    /// The ELO changes of each game.
+    pub games: Vec<GameRatingShadow>,
+}
+
+/// Applies the ELO and skill changes of a round's games to the teams' current ratings.
+///
+/// Nothing is written, the teams are only read to get their ratings before the round.
+pub fn calc_round_ratings(games: &[Game2v2]) -> Result<RoundRatings, Error> {
+    let mut teams: HashMap<String, Team> = HashMap::new();
+    // team id -> (competition id, round, summed elo change)
+    let mut round_changes: HashMap<String, (String, i32, i32)> = HashMap::new();
+
+    // games against ghost teams don't move any rating
+    for game in games.iter().filter(|g| !g.unrated) {
+        for team_id in [&game.team1_id, &game.team2_id] {
+            if !teams.contains_key(team_id) {
+                teams.insert(team_id.clone(), get_team_by_id(team_id.clone())?);
+            }
+        }
 
-const ELO_K_FAATOR: i32 = 16;
+        round_changes
+            .entry(game.team1_id.clone())
+            .or_insert((game.competition_id.clone(), game.round, 0))
+            .2 += game.team1_elo;
+        round_changes
+            .entry(game.team2_id.clone())
+            .or_insert((game.competition_id.clone(), game.round, 0))
+            .2 += game.team2_elo;
 
-pub fn update_team_elo(games: Vec<Game2v2>) -> Result<(), Error> {
-    for game in games.into_iter() {
-        if let Err(e) = add_elo_change(game.team1_id, game.team1_elo) {
-            return Err(e.into());
-        } 
-        if let Err(e) = add_elo_change(game.team2_id, game.team2_elo) {
-            return Err(e.into());
-        } 
+        if let Some(team) = teams.get_mut(&game.team1_id) {
+            team.elo += game.team1_elo;
+        }
+        if let Some(team) = teams.get_mut(&game.team2_id) {
+            team.elo += game.team2_elo;
+        }
+
+        // a team paired against itself can't gain or lose skill
+        if game.team1_id == game.team2_id {
+            continue;
+        }
+        let placements = if game.winner_id.is_empty() {
+            [1, 1]
+        } else if game.winner_id == game.team1_id {
+            [1, 2]
+        } else {
+            [2, 1]
+        };
+        let ratings = [
+            (teams[&game.team1_id].rating_mu, teams[&game.team1_id].rating_sigma),
+            (teams[&game.team2_id].rating_mu, teams[&game.team2_id].rating_sigma),
+        ];
+        let new_ratings = calc_skill_rating_changes(&ratings, &placements);
+        for (team_id, (mu, sigma)) in [&game.team1_id, &game.team2_id].into_iter().zip(new_ratings) {
+            if let Some(team) = teams.get_mut(team_id) {
+                team.rating_mu = mu;
+                team.rating_sigma = sigma;
+            }
+        }
     }
-    Ok(())
+
+    let history = round_changes
+        .into_iter()
+        .map(|(team_id, (competition_id, round, elo_change))| NewEloHistory {
+            elo: teams[&team_id].elo,
+            team_id,
+            competition_id,
+            round,
+            elo_change,
+        })
+        .collect::<Vec<NewEloHistory>>();
+
+    let teams = teams
+        .into_values()
+        .map(|t| TeamRating {
+            team_id: t.id,
+            elo: t.elo,
+            rating_mu: t.rating_mu,
+            rating_sigma: t.rating_sigma,
+        })
+        .collect::<Vec<TeamRating>>();
+
+    Ok(RoundRatings { teams, history })
 }
 
-pub fn calc_elo_changes(game: &mut NewGame2v2) -> Result<(), Error> {
+/// Calculates the ELO changes of both teams in a game.
+///
+/// The K-factor is configured per competition (`elo_k_factor`). A team that has played fewer
+/// than `provisional_games` games still has a provisional rating and uses the competition's
+/// `provisional_k_factor` instead, so new teams move towards their real rating faster.
+pub fn calc_elo_changes(game: &mut NewGame2v2, competition: &Competition) -> Result<(), Error> {
     let team1 = match get_team_by_id(game.team1_id.clone()) {
         Ok(t) => t,
         Err(e) => return Err(e),
@@ -26,16 +138,357 @@ pub fn calc_elo_changes(game: &mut NewGame2v2) -> Result<(), Error> {
         Err(e) => return Err(e),
     };
 
-    let result_team1 = if game.winner_id == game.team1_id { 1.0 } else { 0.0 };
-    let result_team2 = 1.0 - result_team1; // Opposite of team1's result
+    let k_team1 = k_factor_for_team(&team1.id, competition)?;
+    let k_team2 = k_factor_for_team(&team2.id, competition)?;
 
-    game.team1_elo = calculate_elo_change(team1.elo, team2.elo, result_team1);
-    game.team2_elo = calculate_elo_change(team2.elo, team1.elo, result_team2);
+    (game.team1_elo, game.team2_elo) = elo_changes(team1.elo, team2.elo, game.winner_id == game.team1_id, k_team1, k_team2);
 
     Ok(())
 }
 
-fn calculate_elo_change(player_elo: i32, opponent_elo: i32, result: f64) -> i32 {
+/// ELO changes a stored game would have had if `winner_id` had won it.
+///
+/// The game is rated like it was when it was played: with the ELO the teams had at the start
+/// of its round and the K-factors of the games they played before it. Later games aren't
+/// affected, recomputing the competition's ratings corrects those as well.
+pub fn calc_elo_changes_for_result(game: &Game2v2, winner_id: &str, competition: &Competition) -> Result<(i32, i32), Error> {
+    if game.unrated {
+        return Ok((0, 0));
+    }
+    let round_history = get_elo_history_by_round(game.competition_id.clone(), game.round)?;
+    let elo_before_round = |team_id: &String| -> Result<i32, Error> {
+        match round_history.iter().find(|h| &h.team_id == team_id) {
+            Some(h) => Ok(h.elo - h.elo_change),
+            None => Ok(get_team_by_id(team_id.clone())?.elo),
+        }
+    };
+    let rating_settings = RatingSettings::from(competition);
+    let k_factor = |team_id: &String| -> Result<i32, Error> {
+        Ok(rating_settings.k_factor(count_games_by_team_before_round(team_id.clone(), game.round)?))
+    };
+
+    Ok(elo_changes(
+        elo_before_round(&game.team1_id)?,
+        elo_before_round(&game.team2_id)?,
+        winner_id == game.team1_id,
+        k_factor(&game.team1_id)?,
+        k_factor(&game.team2_id)?,
+    ))
+}
+
+/// Calculates new skill ratings for a free-for-all game with any number of teams.
+///
+/// This is the Bradley-Terry "full pairing" update from Weng & Lin, *A Bayesian Approximation 
+/// Method for Online Ranking* (2011), a TrueSkill-like model that works directly on placements:
+/// every team is compared against every other team, a better placement counts as a win, an 
+/// equal placement as a draw. Each team's rating is a normal distribution (`mu`, `sigma`); the
+/// mean moves towards the observed result and the uncertainty shrinks with every game.
+///
+/// # Arguments
+///
+/// * `ratings` - Current `(mu, sigma)` of each team.
+/// * `placements` - Placement of each team (lower is better), in the same order as `ratings`.
+///
+/// # Returns
+///
+/// The new `(mu, sigma)` of each team, in the same order as `ratings`.
+///
+pub fn calc_skill_rating_changes(ratings: &[(f64, f64)], placements: &[i32]) -> Vec<(f64, f64)> {
+    let skill_beta = settings().elo.skill_beta;
+    let beta_sq = skill_beta * skill_beta;
+
+    ratings.iter().enumerate().map(|(i, &(mu_i, sigma_i))| {
+        let sigma_i_sq = sigma_i * sigma_i;
+        let mut omega = 0.0;
+        let mut delta = 0.0;
+
+        for (q, &(mu_q, sigma_q)) in ratings.iter().enumerate() {
+            if q == i {
+                continue;
+            }
+            let c = (sigma_i_sq + sigma_q * sigma_q + 2.0 * beta_sq).sqrt();
+            let p_i = 1.0 / (1.0 + ((mu_q - mu_i) / c).exp());
+            let score = match placements[i].cmp(&placements[q]) {
+                std::cmp::Ordering::Less => 1.0,
+                std::cmp::Ordering::Equal => 0.5,
+                std::cmp::Ordering::Greater => 0.0,
+            };
+            let gamma = sigma_i / c;
+
+            omega += sigma_i_sq / c * (score - p_i);
+            delta += gamma * sigma_i_sq / (c * c) * p_i * (1.0 - p_i);
+        }
+
+        (
+            mu_i + omega,
+            sigma_i * (1.0 - delta).max(settings().elo.skill_kappa).sqrt(),
+        )
+    }).collect()
+}
+
+/// Recomputes the ratings of a competition from scratch by replaying its games in the order
+/// they were played, every team starts with the default ratings.
+///
+/// Games are rated the way `calc_elo_changes` and `calc_round_ratings` rate a live round:
+/// ELO changes use the ELO teams had at the start of the round and the games they played
+/// before it, skill ratings are updated game by game. Unrated games keep no ELO changes.
+pub fn replay_ratings(games: &[Game2v2], rating_settings: &RatingSettings) -> ReplayedRatings {
+    let mut sorted = games.iter().collect::<Vec<&Game2v2>>();
+    sorted.sort_by(|a, b| (a.round, a.created, &a.id).cmp(&(b.round, b.created, &b.id)));
+
+    let mut ratings: HashMap<String, TeamRating> = HashMap::new();
+    let mut games_played: HashMap<String, i64> = HashMap::new();
+    let mut shadows = Vec::with_capacity(sorted.len());
+
+    for round_games in sorted.chunk_by(|a, b| a.round == b.round) {
+        let round_start_elo = ratings
+            .iter()
+            .map(|(team_id, r)| (team_id.clone(), r.elo))
+            .collect::<HashMap<String, i32>>();
+        let played_before_round = games_played.clone();
+
+        for game in round_games {
+            if game.unrated {
+                shadows.push(GameRatingShadow {
+                    game_id: game.id.clone(),
+                    competition_id: game.competition_id.clone(),
+                    team1_elo: 0,
+                    team2_elo: 0,
+                });
+                continue;
+            }
+            for team_id in [&game.team1_id, &game.team2_id] {
+                ratings.entry(team_id.clone()).or_insert_with(|| TeamRating {
+                    team_id: team_id.clone(),
+                    elo: DEFAULT_ELO,
+                    rating_mu: DEFAULT_RATING_MU,
+                    rating_sigma: DEFAULT_RATING_SIGMA,
+                });
+            }
+
+            let elo_before = |team_id: &String| round_start_elo.get(team_id).copied().unwrap_or(DEFAULT_ELO);
+            let k_factor = |team_id: &String| rating_settings.k_factor(played_before_round.get(team_id).copied().unwrap_or(0));
+            let (team1_elo, team2_elo) = elo_changes(
+                elo_before(&game.team1_id),
+                elo_before(&game.team2_id),
+                game.winner_id == game.team1_id,
+                k_factor(&game.team1_id),
+                k_factor(&game.team2_id),
+            );
+            if let Some(r) = ratings.get_mut(&game.team1_id) {
+                r.elo += team1_elo;
+            }
+            if let Some(r) = ratings.get_mut(&game.team2_id) {
+                r.elo += team2_elo;
+            }
+            shadows.push(GameRatingShadow {
+                game_id: game.id.clone(),
+                competition_id: game.competition_id.clone(),
+                team1_elo,
+                team2_elo,
+            });
+
+            *games_played.entry(game.team1_id.clone()).or_default() += 1;
+            // a team paired against itself can't gain or lose skill
+            if game.team1_id == game.team2_id {
+                continue;
+            }
+            *games_played.entry(game.team2_id.clone()).or_default() += 1;
+
+            let placements = if game.winner_id.is_empty() {
+                [1, 1]
+            } else if game.winner_id == game.team1_id {
+                [1, 2]
+            } else {
+                [2, 1]
+            };
+            let before = [
+                (ratings[&game.team1_id].rating_mu, ratings[&game.team1_id].rating_sigma),
+                (ratings[&game.team2_id].rating_mu, ratings[&game.team2_id].rating_sigma),
+            ];
+            let after = calc_skill_rating_changes(&before, &placements);
+            for (team_id, (mu, sigma)) in [&game.team1_id, &game.team2_id].into_iter().zip(after) {
+                if let Some(r) = ratings.get_mut(team_id) {
+                    r.rating_mu = mu;
+                    r.rating_sigma = sigma;
+                }
+            }
+        }
+    }
+
+    ReplayedRatings {
+        teams: ratings.into_values().collect(),
+        games: shadows,
+    }
+}
+
+/// The `elo_history` of a competition rebuilt from the ELO changes of its games (game id ->
+/// changes of both teams). Every team starts at `DEFAULT_ELO`, games without changes are
+/// skipped.
+pub fn elo_history_from_changes(games: &[Game2v2], changes: &HashMap<String, (i32, i32)>) -> Vec<NewEloHistory> {
+    let mut sorted = games.iter().collect::<Vec<&Game2v2>>();
+    sorted.sort_by_key(|g| g.round);
+
+    let mut elos: HashMap<String, i32> = HashMap::new();
+    let mut history = vec![];
+    for round_games in sorted.chunk_by(|a, b| a.round == b.round) {
+        // team id -> summed change in the round, in the order the teams first played
+        let mut round_changes: Vec<(String, i32)> = vec![];
+        for game in round_games {
+            let Some((team1_elo, team2_elo)) = changes.get(&game.id) else {
+                continue;
+            };
+            for (team_id, change) in [(&game.team1_id, *team1_elo), (&game.team2_id, *team2_elo)] {
+                match round_changes.iter_mut().find(|(id, _)| id == team_id) {
+                    Some((_, sum)) => *sum += change,
+                    None => round_changes.push((team_id.clone(), change)),
+                }
+            }
+        }
+
+        for (team_id, elo_change) in round_changes {
+            let elo = elos.entry(team_id.clone()).or_insert(DEFAULT_ELO);
+            *elo += elo_change;
+            history.push(NewEloHistory {
+                elo: *elo,
+                team_id,
+                competition_id: round_games[0].competition_id.clone(),
+                round: round_games[0].round,
+                elo_change,
+            });
+        }
+    }
+    history
+}
+
+impl From<&Competition> for RatingSettings {
+    fn from(competition: &Competition) -> Self {
+        Self {
+            k_factor: competition.elo_k_factor,
+            provisional_games: competition.provisional_games,
+            provisional_k_factor: competition.provisional_k_factor,
+        }
+    }
+}
+
+impl RatingSettings {
+    /// K-factor of a team that played `games_played` games before.
+    pub fn k_factor(&self, games_played: i64) -> i32 {
+        if games_played < self.provisional_games as i64 {
+            self.provisional_k_factor
+        } else {
+            self.k_factor
+        }
+    }
+}
+
+fn k_factor_for_team(team_id: &str, competition: &Competition) -> Result<i32, Error> {
+    let rating_settings = RatingSettings::from(competition);
+    if rating_settings.provisional_games <= 0 {
+        return Ok(rating_settings.k_factor);
+    }
+    let games_played = count_games_by_team_id(team_id.to_string())?;
+    Ok(rating_settings.k_factor(games_played))
+}
+
+/// ELO changes of both teams of a game, a game without a winner counts as won by the second
+/// team.
+fn elo_changes(team1_elo: i32, team2_elo: i32, team1_won: bool, k_team1: i32, k_team2: i32) -> (i32, i32) {
+    let result_team1 = if team1_won { 1.0 } else { 0.0 };
+    let result_team2 = 1.0 - result_team1; // Opposite of team1's result
+    (
+        calculate_elo_change(team1_elo, team2_elo, result_team1, k_team1),
+        calculate_elo_change(team2_elo, team1_elo, result_team2, k_team2),
+    )
+}
+
+/// ELO change of a player with `result` 1 for a win and 0 for a loss.
+pub fn calculate_elo_change(player_elo: i32, opponent_elo: i32, result: f64, k_factor: i32) -> i32 {
     let expected_score = 1.0 / (1.0 + 10.0_f64.powf((opponent_elo - player_elo) as f64 / 400.0));
-    (ELO_K_FAATOR as f64 * (result - expected_score)).round() as i32
-}
\ No newline at end of file
+    (k_factor as f64 * (result - expected_score)).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn rating() -> impl Strategy<Value = (f64, f64)> {
+        (0.0..50.0, 0.5..15.0)
+    }
+
+    /// 2 to 6 teams with placements from 1 to 4, so ties show up as well.
+    fn game() -> impl Strategy<Value = Vec<((f64, f64), i32)>> {
+        prop::collection::vec((rating(), 1..=4), 2..=6)
+    }
+
+    fn split(game: &[((f64, f64), i32)]) -> (Vec<(f64, f64)>, Vec<i32>) {
+        game.iter().cloned().unzip()
+    }
+
+    proptest! {
+        #[test]
+        fn sigma_never_grows(game in game()) {
+            let (ratings, placements) = split(&game);
+            let after = calc_skill_rating_changes(&ratings, &placements);
+            for ((_, sigma_before), (_, sigma_after)) in ratings.iter().zip(after.iter()) {
+                prop_assert!(*sigma_after <= *sigma_before);
+                prop_assert!(*sigma_after > 0.0);
+            }
+        }
+
+        #[test]
+        fn order_of_teams_does_not_matter((game, order) in game().prop_flat_map(|g| {
+            let order = (0..g.len()).collect::<Vec<usize>>();
+            (Just(g), Just(order).prop_shuffle())
+        })) {
+            let (ratings, placements) = split(&game);
+            let reordered = order.iter().map(|&i| game[i]).collect::<Vec<((f64, f64), i32)>>();
+            let (reordered_ratings, reordered_placements) = split(&reordered);
+            let after = calc_skill_rating_changes(&ratings, &placements);
+            let reordered_after = calc_skill_rating_changes(&reordered_ratings, &reordered_placements);
+            for (position, &i) in order.iter().enumerate() {
+                prop_assert!((after[i].0 - reordered_after[position].0).abs() < EPSILON);
+                prop_assert!((after[i].1 - reordered_after[position].1).abs() < EPSILON);
+            }
+        }
+
+        #[test]
+        fn winner_gains_and_loser_loses_skill(team1 in rating(), team2 in rating()) {
+            let after = calc_skill_rating_changes(&[team1, team2], &[1, 2]);
+            prop_assert!(after[0].0 > team1.0);
+            prop_assert!(after[1].0 < team2.0);
+        }
+
+        #[test]
+        fn winning_is_better_than_a_tie_and_a_tie_better_than_losing(team1 in rating(), team2 in rating()) {
+            let won = calc_skill_rating_changes(&[team1, team2], &[1, 2]);
+            let tied = calc_skill_rating_changes(&[team1, team2], &[1, 1]);
+            let lost = calc_skill_rating_changes(&[team1, team2], &[2, 1]);
+            prop_assert!(won[0].0 > tied[0].0);
+            prop_assert!(tied[0].0 > lost[0].0);
+        }
+
+        #[test]
+        fn tie_moves_teams_towards_each_other(team1 in rating(), team2 in rating()) {
+            let after = calc_skill_rating_changes(&[team1, team2], &[1, 1]);
+            if team1.0 > team2.0 {
+                prop_assert!(after[0].0 <= team1.0);
+                prop_assert!(after[1].0 >= team2.0);
+            } else if team1.0 < team2.0 {
+                prop_assert!(after[0].0 >= team1.0);
+                prop_assert!(after[1].0 <= team2.0);
+            }
+        }
+
+        #[test]
+        fn equal_sigmas_are_zero_sum(mu1 in 0.0..50.0, mu2 in 0.0..50.0, sigma in 0.5..15.0, team1_won: bool) {
+            let placements = if team1_won { [1, 2] } else { [2, 1] };
+            let after = calc_skill_rating_changes(&[(mu1, sigma), (mu2, sigma)], &placements);
+            prop_assert!(((after[0].0 - mu1) + (after[1].0 - mu2)).abs() < EPSILON);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::{collections::{hash_map::DefaultHasher, HashMap}, fs, hash::{Hash, Hasher}, io::Error, path::Path, sync::Mutex, time::Duration};
+
+use once_cell::sync::Lazy;
+use uuid::Uuid;
+
+use crate::{config::settings, models::errors::MatchMakerError};
+
+use super::{command_executor::execute_command_with_timeout, file_handler::file_sha256};
+
+const DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+const PROBE_TIMEOUT_SECS: u64 = 10;
+
+/// Whether each evaluator JAR that was probed can play several games per run.
+static BATCH_SUPPORT: Lazy<Mutex<HashMap<String, bool>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Resolves a competition's evaluator artifact to a local JAR.
+///
+/// * an empty `source` uses the configured `default_evaluator`,
+/// * an `http(s)://` URL is downloaded once into the `evaluator_cache` folder and reused by
+///   later rounds; with a checksum the cached file is named after it, so changing the
+///   checksum fetches the artifact again,
+/// * anything else is a path on the server.
+///
+/// If `sha256` is set, the JAR's checksum has to match it before it is used.
+///
+/// # Returns
+///
+/// The path of the JAR to pass to `java -jar`.
+///
+/// # Errors
+///
+/// `MatchMakerError::ChecksumMismatch` if the artifact doesn't match the checksum (a
+/// mismatching download is removed), `MatchMakerError::IOError` if it can't be found or
+/// downloaded.
+///
+pub fn resolve_evaluator(source: &str, sha256: &str) -> Result<String, MatchMakerError> {
+    let source = source.trim();
+    let sha256 = sha256.trim().to_lowercase();
+
+    if source.is_empty() {
+        return Ok(settings().paths.default_evaluator.to_string_lossy().to_string());
+    }
+
+    if !source.starts_with("http://") && !source.starts_with("https://") {
+        if !Path::new(source).is_file() {
+            return Err(MatchMakerError::IOError(Error::other(format!("Evaluator {} not found", source))));
+        }
+        verify_checksum(source, &sha256)?;
+        return Ok(source.to_string());
+    }
+
+    let cache_dir = &settings().paths.evaluator_cache;
+    let cached = cache_dir.join(cache_name(source, &sha256));
+    let cached_path = cached.to_string_lossy().to_string();
+    if cached.is_file() {
+        return Ok(cached_path);
+    }
+
+    fs::create_dir_all(cache_dir).map_err(MatchMakerError::IOError)?;
+    // download next to the cache and move it in once verified, concurrent rounds never see
+    // a partial file
+    let download_path = cache_dir.join(format!("{}.part", Uuid::new_v4())).to_string_lossy().to_string();
+    let result = download(source, &download_path)
+        .and_then(|_| verify_checksum(&download_path, &sha256))
+        .and_then(|_| fs::rename(&download_path, &cached).map_err(MatchMakerError::IOError));
+    if result.is_err() {
+        let _ = fs::remove_file(&download_path);
+    }
+    result.map(|_| cached_path)
+}
+
+/// Whether an evaluator JAR can play several games in a single run, which it announces by
+/// listing the `--seeds` option in its `--help`. The answer is kept for as long as the
+/// server runs, an evaluator that can't be probed is taken to play one game per run.
+pub fn supports_batches(evaluator: &str) -> bool {
+    if let Some(supported) = BATCH_SUPPORT.lock().expect("Batch support lock was poisoned").get(evaluator) {
+        return *supported;
+    }
+    let supported = execute_command_with_timeout(
+        "java".to_string(),
+        vec!["-jar", evaluator, "--help"],
+        vec![],
+        Duration::from_secs(PROBE_TIMEOUT_SECS),
+    )
+    .map(|output| output.stdout.iter().chain(output.stderr.iter()).any(|line| line.contains("--seeds")))
+    .unwrap_or(false);
+    BATCH_SUPPORT
+        .lock()
+        .expect("Batch support lock was poisoned")
+        .insert(evaluator.to_string(), supported);
+    supported
+}
+
+fn cache_name(url: &str, sha256: &str) -> String {
+    if !sha256.is_empty() {
+        return format!("{}.jar", sha256);
+    }
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    format!("url-{:x}.jar", hasher.finish())
+}
+
+fn download(url: &str, destination: &str) -> Result<(), MatchMakerError> {
+    let max_time = DOWNLOAD_TIMEOUT_SECS.to_string();
+    let output = execute_command_with_timeout(
+        "curl".to_string(),
+        vec!["-sS", "--fail", "-L", "--max-time", &max_time, "-o", destination, url],
+        vec![],
+        Duration::from_secs(DOWNLOAD_TIMEOUT_SECS + 10),
+    ).map_err(MatchMakerError::IOError)?;
+
+    match output.status {
+        Some(status) if status.success() => Ok(()),
+        _ => Err(MatchMakerError::IOError(Error::other(format!(
+            "Failed downloading evaluator {}: {}", url, output.stderr.join("\n")
+        )))),
+    }
+}
+
+fn verify_checksum(path: &str, sha256: &str) -> Result<(), MatchMakerError> {
+    if sha256.is_empty() {
+        return Ok(());
+    }
+    let actual = file_sha256(path)?;
+
+    if actual != sha256 {
+        return Err(MatchMakerError::ChecksumMismatch(format!(
+            "{} has checksum {}, expected {}", path, actual, sha256
+        )));
+    }
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    db::operations_game2v2::{get_games_by_competition_round, get_last_played_round},
+    models::{
+        competition::Competition,
+        featured_game::{FeaturedGame, FeaturedGames, FeaturedReason},
+        game_2v2::Game2v2,
+    },
+};
+
+use super::leaderboard::build_leaderboard;
+
+/// Picks the most interesting games of a round for spectators:
+///
+/// * `ClosestScore` - the decided game with the smallest score difference, the higher
+///   scoring one if several are equally close.
+/// * `BiggestEloSwing` - the game that moved a team's ELO the most.
+/// * `TopTeamsMeeting` - the game between the two teams currently leading the standings.
+///
+/// A game that qualifies for several reasons is listed once with all of them.
+///
+/// # Arguments
+///
+/// * `competition` - The competition.
+/// * `round` - The round to pick from, the latest round with games if `None`.
+///
+pub fn featured_games(competition: &Competition, round: Option<i32>) -> Result<FeaturedGames, Error> {
+    let round = match round {
+        Some(r) => Some(r),
+        None => get_last_played_round(competition.id.clone())?,
+    };
+    let round = match round {
+        Some(r) => r,
+        None => return Ok(FeaturedGames { competition_id: competition.id.clone(), round: None, games: vec![] }),
+    };
+
+    let games: Vec<Game2v2> = get_games_by_competition_round(competition.id.clone(), round)?
+        .into_iter()
+        .filter(|g| g.team1_id != g.team2_id)
+        .collect();
+
+    let standings = build_leaderboard(competition)?;
+    let names: HashMap<&str, &str> = standings.iter().map(|e| (e.team_id.as_str(), e.team_name.as_str())).collect();
+
+    let mut picks: Vec<(&Game2v2, FeaturedReason)> = vec![];
+    let closest = games
+        .iter()
+        .filter(|g| !g.winner_id.is_empty())
+        .min_by_key(|g| ((g.team1_score - g.team2_score).abs(), -(g.team1_score + g.team2_score)));
+    if let Some(g) = closest {
+        picks.push((g, FeaturedReason::ClosestScore));
+    }
+    if let Some(g) = games.iter().filter(|g| elo_swing(g) > 0).max_by_key(|g| elo_swing(g)) {
+        picks.push((g, FeaturedReason::BiggestEloSwing));
+    }
+    if let [first, second, ..] = standings.as_slice() {
+        let top_teams = |g: &&Game2v2| {
+            (g.team1_id == first.team_id && g.team2_id == second.team_id)
+                || (g.team1_id == second.team_id && g.team2_id == first.team_id)
+        };
+        picks.extend(games.iter().filter(top_teams).map(|g| (g, FeaturedReason::TopTeamsMeeting)));
+    }
+
+    let mut featured: Vec<FeaturedGame> = vec![];
+    for (game, reason) in picks {
+        if let Some(f) = featured.iter_mut().find(|f| f.game_id == game.id) {
+            f.reasons.push(reason);
+            continue;
+        }
+        let name = |id: &str| names.get(id).copied().unwrap_or(id).to_string();
+        featured.push(FeaturedGame {
+            game_id: game.id.clone(),
+            team1_id: game.team1_id.clone(),
+            team1_name: name(&game.team1_id),
+            team2_id: game.team2_id.clone(),
+            team2_name: name(&game.team2_id),
+            winner_id: game.winner_id.clone(),
+            team1_score: game.team1_score,
+            team2_score: game.team2_score,
+            elo_swing: elo_swing(game),
+            public: game.public,
+            reasons: vec![reason],
+        });
+    }
+
+    Ok(FeaturedGames {
+        competition_id: competition.id.clone(),
+        round: Some(round),
+        games: featured,
+    })
+}
+
+fn elo_swing(game: &Game2v2) -> i32 {
+    game.team1_elo.abs().max(game.team2_elo.abs())
+}
\ No newline at end of file
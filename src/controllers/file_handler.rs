@@ -1,9 +1,13 @@
-use std::{fs::File, io::Write};
+use std::{fs::{self, File}, io::{Cursor, Read, Write}, path::{Path, PathBuf}};
 
-use zip::{write::FileOptions, CompressionMethod};
+use chrono::{Datelike, Local, Timelike};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive};
 
-use crate::models::errors::MatchMakerError;
+use crate::{config::settings, models::errors::MatchMakerError};
 
+use super::{command_executor::execute_command, storage::storage};
+
+const ZSTD_COMPRESSION_LEVEL: i32 = 19;
 
 
 pub fn save_to_zip(contents: String, file_name: &str) -> Result<(), MatchMakerError> {
@@ -27,4 +31,102 @@ pub fn save_to_zip(contents: String, file_name: &str) -> Result<(), MatchMakerEr
     // Finish writing the zip file
     zip.finish().map_err(|e| MatchMakerError::ZippingError(e.into()))?;
     Ok(())
+}
+
+/// Hands a replay written to its key's local path over to the storage backend.
+///
+/// With object storage the local file is removed once it is uploaded.
+pub fn persist_replay(file_name: &str) -> Result<(), MatchMakerError> {
+    storage().put(file_name, Path::new(file_name))?;
+    if storage().is_remote() {
+        fs::remove_file(file_name).map_err(MatchMakerError::IOError)?;
+    }
+    Ok(())
+}
+
+/// Local path of a stored file, downloaded from the storage backend if it isn't cached yet.
+pub fn local_copy(key: &str) -> Result<String, MatchMakerError> {
+    if !Path::new(key).exists() {
+        storage().fetch(key, Path::new(key))?;
+    }
+    Ok(key.to_string())
+}
+
+/// Reads the text contents of a stored replay.
+///
+/// Replays are written as ZIP archives by `save_to_zip`, but the retention policy may later
+/// re-compress them into a `.zst` file. Both formats are handled here, so callers don't need
+/// to know which stage of its life a replay is in.
+pub fn read_replay(file_name: &str) -> Result<String, MatchMakerError> {
+    let file = Cursor::new(storage().get(file_name)?);
+
+    if file_name.ends_with(".zst") {
+        let bytes = zstd::decode_all(file).map_err(MatchMakerError::IOError)?;
+        return Ok(String::from_utf8_lossy(&bytes).to_string());
+    }
+
+    // the log file is the only file in the archive
+    let mut zip = ZipArchive::new(file).map_err(MatchMakerError::ZippingError)?;
+    let mut log_file = zip.by_index(0).map_err(MatchMakerError::ZippingError)?;
+    let mut contents = String::new();
+    log_file.read_to_string(&mut contents).map_err(MatchMakerError::IOError)?;
+    Ok(contents)
+}
+
+/// Re-compresses a ZIP replay with zstd and removes the original archive.
+///
+/// Returns the path of the new `.zst` file.
+pub fn recompress_replay_zstd(file_name: &str) -> Result<String, MatchMakerError> {
+    let new_file_name = Path::new(file_name).with_extension("zst").to_string_lossy().to_string();
+    // the original is deleted after writing, it must not be the file just written
+    if new_file_name == file_name {
+        return Err(MatchMakerError::IOError(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Replay {} is already compressed with zstd", file_name),
+        )));
+    }
+    let contents = read_replay(file_name)?;
+    let compressed = zstd::encode_all(contents.as_bytes(), ZSTD_COMPRESSION_LEVEL)
+        .map_err(MatchMakerError::IOError)?;
+
+    fs::write(&new_file_name, compressed).map_err(MatchMakerError::IOError)?;
+    persist_replay(&new_file_name)?;
+    storage().delete(file_name)?;
+    Ok(new_file_name)
+}
+
+/// Copies an uploaded bot archive to `<uploads directory>/<competition id>/<timestamp>/`.
+///
+/// The archive is also handed to the storage backend, the local file stays as a cache for
+/// compiling. Returns the path the archive was stored at, which is its storage key.
+pub fn store_upload(upload: &Path, competition_id: &str, file_name: &str) -> Result<PathBuf, MatchMakerError> {
+    let now = Local::now();
+    let time = format!(
+        "{:04}-{:02}-{:02}-{:02}-{:02}-{:02}", 
+        now.year(), 
+        now.month(), 
+        now.day(), 
+        now.hour(), 
+        now.minute(), 
+        now.second()
+    );
+    let save_directory = settings().paths.uploads
+        .join(competition_id)
+        .join(time);
+    fs::create_dir_all(&save_directory).map_err(MatchMakerError::IOError)?;
+
+    let save_path = save_directory.join(file_name);
+    fs::copy(upload, &save_path).map_err(MatchMakerError::IOError)?;
+    storage().put(&save_path.to_string_lossy(), &save_path)?;
+    Ok(save_path)
+}
+
+/// SHA-256 checksum of a file, in lowercase hex.
+pub fn file_sha256(path: &str) -> Result<String, MatchMakerError> {
+    let output = execute_command("sha256sum".to_string(), vec![path]).map_err(MatchMakerError::IOError)?;
+    Ok(output
+        .first()
+        .and_then(|line| line.split_whitespace().next())
+        .unwrap_or_default()
+        .to_lowercase())
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+use diesel::result::Error;
+use serde_json::json;
+
+use crate::{
+    db::{operations_game2v2::get_game_by_id, operations_game_overrides::store_game_override},
+    models::{
+        audit_log::{AuditAction, NewAuditEntry},
+        competition::Competition,
+        game_2v2::{Game2v2, PublicGame2v2},
+        game_override::{GameOverrideResult, GameResultCorrection, GameResultOverride},
+        user::User,
+    },
+};
+
+use super::{elo::calc_elo_changes_for_result, leaderboard::rebuild_team_streaks};
+
+/// What's wrong with an override of the game's result, `None` if it can be applied.
+pub fn override_invalid_reason(game: &Game2v2, result: &GameResultOverride) -> Option<String> {
+    if result.reason.trim().is_empty() {
+        return Some("A reason is required".to_string());
+    }
+    // rating a game counts a missing winner as a win of team 2, so a draw can't be stored
+    if result.winner_id != game.team1_id && result.winner_id != game.team2_id {
+        return Some("The winner has to be one of the game's teams".to_string());
+    }
+    None
+}
+
+/// Replaces the winner and survivors of a played game and corrects the ELO of both teams: the
+/// game's original ELO changes are reverted and the ones of the corrected result applied. The
+/// override is recorded in the audit log with the result before and after.
+///
+/// Skill ratings and the ELO changes of later games stay as they are, recompute the
+/// competition's ratings to correct those as well.
+pub fn override_game_result(game: Game2v2, competition: &Competition, result: GameResultOverride, admin: &User) -> Result<GameOverrideResult, Error> {
+    let (team1_elo, team2_elo) = calc_elo_changes_for_result(&game, &result.winner_id, competition)?;
+    let survived = [
+        result.team1bot1_survived.unwrap_or(game.team1bot1_survived),
+        result.team1bot2_survived.unwrap_or(game.team1bot2_survived),
+        result.team2bot1_survived.unwrap_or(game.team2bot1_survived),
+        result.team2bot2_survived.unwrap_or(game.team2bot2_survived),
+    ];
+
+    let correction = GameResultCorrection {
+        game_id: game.id.clone(),
+        competition_id: game.competition_id.clone(),
+        round: game.round,
+        team1_id: game.team1_id.clone(),
+        team2_id: game.team2_id.clone(),
+        winner_id: result.winner_id.clone(),
+        survived,
+        team1_elo,
+        team2_elo,
+    };
+
+    let audit = NewAuditEntry {
+        user_id: admin.id.clone(),
+        action: AuditAction::GameResultOverride,
+        competition_id: game.competition_id.clone(),
+        target_id: game.id.clone(),
+        details: json!({
+            "reason": result.reason,
+            "before": {
+                "winner_id": game.winner_id,
+                "survived": [game.team1bot1_survived, game.team1bot2_survived, game.team2bot1_survived, game.team2bot2_survived],
+                "team1_elo": game.team1_elo,
+                "team2_elo": game.team2_elo,
+            },
+            "after": {
+                "winner_id": result.winner_id,
+                "survived": survived,
+                "team1_elo": team1_elo,
+                "team2_elo": team2_elo,
+            },
+        }),
+    };
+
+    let (team1_elo_correction, team2_elo_correction) = store_game_override(correction, audit)?;
+    rebuild_team_streaks(game.competition_id.clone())?;
+    Ok(GameOverrideResult {
+        game: PublicGame2v2::from(get_game_by_id(game.id)?),
+        team1_elo_correction,
+        team2_elo_correction,
+    })
+}
\ No newline at end of file
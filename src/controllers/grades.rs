@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+use serde_json::json;
+
+use crate::{
+    db::{
+        operations_grade_mappings::{get_grade_mapping_by_competition_id, store_grade_mapping},
+        operations_teams::get_active_teams_by_competition_id,
+        operations_users::get_users_by_ids,
+    },
+    models::{
+        audit_log::{AuditAction, NewAuditEntry},
+        competition::Competition,
+        grade_mapping::{GradeMapping, GradePreview, PublicGradeMapping},
+        user::User,
+    },
+};
+
+use super::leaderboard::build_leaderboard;
+
+/// Stores a competition's grade mapping. The mapping before and after the change is recorded in
+/// the audit log, so it can be traced which formula produced the grades.
+pub fn update_grade_mapping(mapping: GradeMapping, admin: &User) -> Result<GradeMapping, Error> {
+    let previous = get_grade_mapping_by_competition_id(mapping.competition_id.clone())?;
+    let audit = NewAuditEntry {
+        user_id: admin.id.clone(),
+        action: AuditAction::GradeMappingUpdate,
+        competition_id: mapping.competition_id.clone(),
+        target_id: mapping.competition_id.clone(),
+        details: json!({
+            "before": previous.map(PublicGradeMapping::from),
+            "after": PublicGradeMapping::from(mapping.clone()),
+        }),
+    };
+    store_grade_mapping(mapping, audit)
+}
+
+/// The grade each student of the competition would get from its current standings.
+///
+/// Both members of a team get the team's grade. The grades are computed on request from the
+/// leaderboard and the stored mapping, they aren't stored.
+///
+/// # Returns
+///
+/// The previews ordered by rank, then by username.
+///
+pub fn grade_preview(competition: &Competition, mapping: &GradeMapping) -> Result<Vec<GradePreview>, Error> {
+    let leaderboard = build_leaderboard(competition)?;
+    let teams = get_active_teams_by_competition_id(competition.id.clone())?
+        .into_iter()
+        .map(|t| (t.id.clone(), t))
+        .collect::<HashMap<_, _>>();
+
+    let mut previews = vec![];
+    for entry in leaderboard.iter() {
+        let team = match teams.get(&entry.team_id) {
+            Some(t) => t,
+            None => continue,
+        };
+        let band = mapping.band_for(entry.rank, entry.points, leaderboard.len());
+        for member in [&team.owner, &team.partner] {
+            if member.is_empty() {
+                continue;
+            }
+            previews.push(GradePreview {
+                user_id: member.clone(),
+                username: "".to_string(),
+                team_id: team.id.clone(),
+                team_name: team.name.clone(),
+                rank: entry.rank,
+                points: entry.points,
+                grade: band.map(|b| b.grade).unwrap_or(mapping.default_grade),
+                band: band.map(|b| b.label.clone()).unwrap_or_default(),
+            });
+        }
+    }
+
+    let usernames = get_users_by_ids(previews.iter().map(|p| p.user_id.clone()).collect())?
+        .into_iter()
+        .map(|u| (u.id, u.username))
+        .collect::<HashMap<String, String>>();
+    for preview in previews.iter_mut() {
+        preview.username = usernames.get(&preview.user_id).cloned().unwrap_or_default();
+    }
+    previews.sort_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.username.cmp(&b.username)));
+    Ok(previews)
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use diesel::result::Error;
+
+use crate::{
+    db::operations_game2v2::get_games_between_teams,
+    models::{head_to_head::HeadToHead, game_2v2::PublicGame2v2},
+};
+
+/// Collects all games between two teams and their win/loss record and average score margin,
+/// seen from the perspective of `team_a`.
+pub fn build_head_to_head(team_a: String, team_b: String) -> Result<HeadToHead, Error> {
+    let games = get_games_between_teams(team_a.clone(), team_b.clone())?;
+
+    let mut wins = 0;
+    let mut losses = 0;
+    let mut draws = 0;
+    let mut score_margin = 0;
+    for game in games.iter() {
+        if game.winner_id.is_empty() {
+            draws += 1;
+        } else if game.winner_id == team_a {
+            wins += 1;
+        } else {
+            losses += 1;
+        }
+        score_margin += if game.team1_id == team_a {
+            game.team1_score - game.team2_score
+        } else {
+            game.team2_score - game.team1_score
+        };
+    }
+
+    let games_played = games.len() as i32;
+    Ok(HeadToHead {
+        team_a,
+        team_b,
+        games_played,
+        wins,
+        losses,
+        draws,
+        avg_score_margin: if games_played == 0 { 0.0 } else { score_margin as f64 / games_played as f64 },
+        games: games.into_iter().map(PublicGame2v2::from).collect(),
+    })
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use diesel::result::Error;
+use tokio::task::JoinSet;
+use tracing::{error, info, info_span, Instrument};
+
+use crate::{
+    db::{
+        operations_jobs::{insert_job, claim_next_job, append_job_log, finish_job, get_running_jobs, set_job_progress, has_queued_job},
+        operations_round_checkpoints::get_checkpointed_competition_ids,
+        operations_rounds::interrupt_running_rounds,
+    },
+    models::{job::{Job, NewJob, JobKind, JobStatus}, errors::MatchMakerError},
+    config::settings,
+};
+
+use super::{competitions::run_competition_round, trace::TraceContext, shutdown::is_shutting_down};
+
+/// How often the worker looks for queued jobs when the queue is empty.
+const JOB_POLL_SECS: u64 = 5;
+
+/// Queues a round of the competition, it runs once the worker gets to it. The round is
+/// recorded under the given trace, which should not be shared with other jobs.
+pub fn enqueue_round(competition_id: String, trace: &TraceContext) -> Result<Job, Error> {
+    insert_job(NewJob {
+        kind: JobKind::Round,
+        competition_id,
+        trace_id: trace.trace_id.clone(),
+    })
+}
+
+/// Runs the queued jobs oldest first, up to `concurrent_rounds` of them at once. A
+/// competition runs one job at a time, the games of the rounds that run together share the
+/// game slots by the competitions' priorities.
+///
+/// Jobs that were still running when the server stopped are marked as failed first. Rounds that
+/// were checkpointed on shutdown are queued again and continue where they stopped. The worker
+/// stops taking jobs once the server is shutting down and waits for the running ones.
+pub async fn run_job_worker() {
+    // the queue lives in the database, its calls are kept off the async workers
+    let recovered = tokio::task::spawn_blocking(|| {
+        fail_interrupted_jobs();
+        resume_checkpointed_rounds();
+    }).await;
+    if let Err(e) = recovered {
+        error!("Failed recovering interrupted jobs: {}", e);
+    }
+    let concurrent_rounds = settings().threads.concurrent_rounds.max(1);
+    let mut running = JoinSet::new();
+    while !is_shutting_down() {
+        if running.len() >= concurrent_rounds {
+            running.join_next().await;
+            continue;
+        }
+        match tokio::task::spawn_blocking(claim_next_job).await {
+            Ok(Ok(Some(job))) => {
+                let log_span = info_span!("job", job_id = %job.id);
+                running.spawn(run_job(job).instrument(log_span));
+                continue;
+            },
+            Ok(Ok(None)) => (),
+            Ok(Err(e)) => error!("Failed claiming the next job: {:?}", e),
+            Err(e) => error!("Failed claiming the next job: {}", e),
+        }
+        // a finished job may let a queued job of its competition run
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_secs(JOB_POLL_SECS)) => (),
+            Some(_) = running.join_next(), if !running.is_empty() => (),
+        }
+    }
+    while running.join_next().await.is_some() {}
+}
+
+/// Reports how many of a round's matches are done to the job running the round.
+pub async fn report_round_progress(job_id: &str, finished_matches: usize, total_matches: usize) {
+    if total_matches == 0 {
+        return;
+    }
+    let progress = (finished_matches * 100 / total_matches) as i32;
+    let job_id = job_id.to_string();
+    match tokio::task::spawn_blocking(move || set_job_progress(job_id, progress)).await {
+        Ok(Ok(_)) => (),
+        Ok(Err(e)) => error!("Failed updating job progress: {:?}", e),
+        Err(e) => error!("Failed updating job progress: {}", e),
+    }
+}
+
+async fn run_job(job: Job) {
+    let trace = TraceContext { trace_id: job.trace_id.clone() };
+    let started = Instant::now();
+    log_job_blocking(&job.id, format!("Running round of competition {} (trace {})", job.competition_id, trace.trace_id)).await;
+
+    let result = match job.kind {
+        // the round is scheduled for when it was queued, not for when the worker got to it
+        JobKind::Round => run_competition_round(&job, &trace).await,
+    };
+
+    let (status, message) = match result {
+        Ok(_) => (JobStatus::Done, format!("Round finished in {}s", started.elapsed().as_secs())),
+        Err(MatchMakerError::ShuttingDown) => (
+            JobStatus::Failed,
+            "Interrupted by a shutdown, the round resumes after the restart".to_string(),
+        ),
+        Err(e) => {
+            error!("Error on running round (trace {}): {:?}", trace.trace_id, e);
+            (JobStatus::Failed, format!("Round failed: {}", e))
+        },
+    };
+    let finished = tokio::task::spawn_blocking(move || {
+        log_job(&job.id, message);
+        finish_job(job.id, status)
+    }).await;
+    match finished {
+        Ok(Ok(_)) => (),
+        Ok(Err(e)) => error!("Failed finishing job: {:?}", e),
+        Err(e) => error!("Failed finishing job: {}", e),
+    }
+}
+
+fn fail_interrupted_jobs() {
+    let interrupted = match get_running_jobs() {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            error!("Failed loading interrupted jobs: {:?}", e);
+            return;
+        },
+    };
+    for job in interrupted.into_iter() {
+        log_job(&job.id, "Interrupted by a server restart".to_string());
+        if let Err(e) = finish_job(job.id, JobStatus::Failed) {
+            error!("Failed finishing interrupted job: {:?}", e);
+        }
+    }
+    // their rounds can be claimed again
+    if let Err(e) = interrupt_running_rounds() {
+        error!("Failed marking interrupted rounds: {:?}", e);
+    }
+}
+
+fn resume_checkpointed_rounds() {
+    let competition_ids = match get_checkpointed_competition_ids() {
+        Ok(ids) => ids,
+        Err(e) => {
+            error!("Failed loading checkpointed rounds: {:?}", e);
+            return;
+        },
+    };
+    for competition_id in competition_ids.into_iter() {
+        match has_queued_job(competition_id.clone()) {
+            Ok(true) => continue,
+            Ok(false) => (),
+            Err(e) => {
+                error!("Failed checking queued jobs: {:?}", e);
+                continue;
+            },
+        }
+        match enqueue_round(competition_id.clone(), &TraceContext::new()) {
+            Ok(job) => info!("Resuming interrupted round of competition {} as job {}", competition_id, job.id),
+            Err(e) => error!("Failed queueing interrupted round: {:?}", e),
+        }
+    }
+}
+
+/// `log_job` off the async workers.
+async fn log_job_blocking(job_id: &str, message: String) {
+    let job_id = job_id.to_string();
+    if let Err(e) = tokio::task::spawn_blocking(move || log_job(&job_id, message)).await {
+        error!("Failed writing job log: {}", e);
+    }
+}
+
+fn log_job(job_id: &str, message: String) {
+    let line = format!("[{}] {}", Local::now().naive_utc().format("%Y-%m-%d %H:%M:%S"), message);
+    if let Err(e) = append_job_log(job_id.to_string(), line) {
+        error!("Failed writing job log: {:?}", e);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+//! Options that cut the startup cost every game's JVM pays.
+//!
+//! The Evaluator is started fresh for every game and has no way to accept further games once
+//! it runs, so instead of keeping a JVM around each game starts from a class data sharing
+//! archive of the Evaluator's classes. The first game of an Evaluator JAR writes the archive
+//! when it exits, the games after it map the classes in instead of loading and verifying them
+//! again. The bots are started by the Evaluator itself and aren't affected.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::config::settings;
+
+/// How long the game writing an archive has before another game may try instead, a game
+/// that is killed never writes it.
+const ARCHIVE_CLAIM_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Archives being written, with when the game writing them started.
+static ARCHIVE_CLAIMS: Lazy<Mutex<HashMap<PathBuf, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Options of the JVM running a game of the Evaluator at `evaluator`, passed before `-jar`.
+///
+/// These are `jvm.options` followed, with `jvm.class_data_sharing` on, by the options that
+/// use the Evaluator's archive or have the game write it if no other game is writing it yet.
+/// JVMs too old for class data sharing ignore those options.
+pub fn jvm_options(evaluator: &str) -> Vec<String> {
+    let jvm = &settings().jvm;
+    let mut options = jvm.options.clone();
+    if jvm.class_data_sharing {
+        options.extend(class_data_sharing_options(evaluator));
+    }
+    options
+}
+
+fn class_data_sharing_options(evaluator: &str) -> Vec<String> {
+    let archive = match archive_path(evaluator) {
+        Some(a) => a,
+        None => return vec![],
+    };
+    let archive_option = if archive.is_file() {
+        format!("-XX:SharedArchiveFile={}", archive.to_string_lossy())
+    } else if claim_archive(&archive) {
+        format!("-XX:ArchiveClassesAtExit={}", archive.to_string_lossy())
+    } else {
+        return vec![];
+    };
+    vec![
+        "-XX:+IgnoreUnrecognizedVMOptions".to_string(),
+        // archive warnings would otherwise end up in the game's output
+        "-Xlog:disable".to_string(),
+        archive_option,
+    ]
+}
+
+/// Archive of an Evaluator JAR, named after the JAR and when it was last modified so a
+/// replaced JAR gets a new archive.
+fn archive_path(evaluator: &str) -> Option<PathBuf> {
+    let jar = Path::new(evaluator);
+    let modified = fs::metadata(jar)
+        .and_then(|m| m.modified())
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    let folder = settings().paths.games.join("cds");
+    fs::create_dir_all(&folder).ok()?;
+    let name = jar.file_stem()?.to_string_lossy();
+    Some(folder.join(format!("{}-{}.jsa", name, modified)))
+}
+
+/// Whether the calling game should write the archive, only one game writes it at a time.
+fn claim_archive(archive: &Path) -> bool {
+    let mut claims = ARCHIVE_CLAIMS.lock().expect("Archive claims lock was poisoned");
+    match claims.get(archive) {
+        Some(claimed) if claimed.elapsed() < ARCHIVE_CLAIM_TIMEOUT => false,
+        _ => {
+            claims.insert(archive.to_path_buf(), Instant::now());
+            true
+        },
+    }
+}
\ No newline at end of file
@@ -1,20 +1,31 @@
 use std::env;
 
+use actix_web::{HttpResponse, dev::ServiceRequest, http::{Method, header::AUTHORIZATION}};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::{Duration, Local};
 use serde::{Deserialize, Serialize};
 use jsonwebtoken::{decode, DecodingKey, Validation, Algorithm, encode, Header, EncodingKey, errors::Error};
+use tracing::{error, warn};
 
-use crate::{models::user::User, db::operations_users::get_user_by_username};
+use crate::{models::user::{User, Role}, db::operations_users::get_user_by_username};
+
+const DEFAULT_TOKEN_TTL_HOURS: i64 = 24 * 7;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
    pub sub: String,
-   pub admin: bool,
+   pub role: String,
    pub exp: usize,
 }
 
-pub fn encode_jwt(user_id: String, role: bool) -> Result<String, Error> {
-    let claims = Claims{ sub: user_id, admin: role, exp: (60 * 60 * 10000000) };
+pub fn encode_jwt(user_id: String, role: &Role) -> Result<String, Error> {
+    let ttl_hours = env::var("JWT_TTL_HOURS")
+        .ok()
+        .and_then(|h| h.parse().ok())
+        .unwrap_or(DEFAULT_TOKEN_TTL_HOURS);
+    let exp = (Local::now() + Duration::hours(ttl_hours)).timestamp() as usize;
+
+    let claims = Claims{ sub: user_id, role: role.to_string(), exp };
     let secret = env::var("JWT_SECRET").expect("Missing the JWT_SECRET environment variable.");
     encode(
         &Header::default(), 
@@ -23,16 +34,16 @@ pub fn encode_jwt(user_id: String, role: bool) -> Result<String, Error> {
     )
 }
 
-pub fn decode_jwt(token: String) -> Option<String> {
+pub fn decode_jwt(token: String) -> Option<Claims> {
     let secret = env::var("JWT_SECRET").expect("Missing the JWT_SECRET environment variable.");
     match decode::<Claims>(
         &token, 
         &DecodingKey::from_secret(secret.as_bytes()), 
         &Validation::new(Algorithm::HS256)
     ) {
-        Ok(data) => Some(data.claims.sub),
+        Ok(data) => Some(data.claims),
         Err(e) =>  {
-            println!("Error decoding JTW token: {:#?}", e.to_string());
+            warn!("Error decoding JTW token: {:#?}", e.to_string());
             None
         }
     }
@@ -40,15 +51,49 @@ pub fn decode_jwt(token: String) -> Option<String> {
 
 pub fn exchange_token_for_user(token: BearerAuth) -> Option<User> {
     let email = match decode_jwt(token.token().to_string()) {
-        Some(uid) => uid,
+        Some(claims) => claims.sub,
         None => return None,
     };
 
     match get_user_by_username(email) {
         Ok(user) => Some(user),
         Err(e) => {
-            eprintln!("[JWT exchange_token_for_user] Error finding user: {:#?}", e);
+            error!("[JWT exchange_token_for_user] Error finding user: {:#?}", e);
             None
         }
     }
+}
+
+/// Resolves the user of the token and checks that they have one of the `allowed` roles.
+/// The error is the response the route should return.
+pub fn authorize(token: BearerAuth, allowed: &[Role]) -> Result<User, HttpResponse> {
+    let user = match exchange_token_for_user(token) {
+        Some(u) => u,
+        None => return Err(HttpResponse::Unauthorized().finish()),
+    };
+
+    if !allowed.contains(&user.role) {
+        return Err(HttpResponse::Forbidden().finish());
+    }
+    Ok(user)
+}
+
+/// Spectators can only read. Any request of a spectator that could change something, except
+/// logging in again, is rejected before it reaches a route.
+///
+/// The role is taken from the token, the routes still check the user against the database.
+pub fn is_spectator_write(req: &ServiceRequest) -> bool {
+    if req.method() == Method::GET || req.method() == Method::OPTIONS || req.path().ends_with("/login") {
+        return false;
+    }
+
+    let token = match req.headers().get(AUTHORIZATION).and_then(|h| h.to_str().ok()) {
+        Some(h) => h.trim_start_matches("Bearer ").to_string(),
+        None => return false,
+    };
+
+    match decode_jwt(token) {
+        Some(claims) => Role::from(claims.role.as_str()) == Role::Spectator,
+        None => false,
+    }
 }
\ No newline at end of file
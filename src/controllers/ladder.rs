@@ -0,0 +1,196 @@
+use std::{collections::HashMap, fs, path::PathBuf, sync::atomic::{AtomicBool, Ordering}};
+
+use diesel::result::Error;
+use tracing::{error, info, warn};
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::{
+        operations_competition::get_running_competitions,
+        operations_ladder::{get_ladder_bots_by_competition_id, get_ladder_games_by_competition_id, insert_ladder_bot, insert_ladder_game},
+        operations_teams::{get_active_teams_by_competition_id, get_teams_by_competition_id},
+    },
+    models::{
+        bot::Bot,
+        competition::Competition,
+        errors::MatchMakerError,
+        game_2v2::NewGame2v2,
+        ladder::{LadderBot, LadderOverview, LadderStanding, NewLadderBot, NewLadderGame, PublicLadderBot, SqlLadderBot},
+        team::DEFAULT_ELO,
+    },
+};
+
+use super::{
+    elo::calculate_elo_change,
+    matchmaker_2v2::{compile_bot, play_unranked_game},
+    practice::{copy_compiled, has_compiled_bots},
+    safe_mode::is_safe_mode,
+    shutdown::is_shutting_down,
+    workload_gate::acquire_unranked_slot,
+};
+
+/// Set while the ladder is played, a run that is still going skips the next one.
+static LADDER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Clears `LADDER_RUNNING` when the run ends, also if it panics.
+struct LadderRunGuard;
+
+impl Drop for LadderRunGuard {
+    fn drop(&mut self) {
+        LADDER_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Adds a staff bot to a competition's ladder.
+///
+/// The bot is compiled and its compiled classes are copied to `<ladder_bots>/<ladder bot id>`,
+/// the same way practice bots are published.
+pub fn add_ladder_bot(bot: &Bot, new_ladder_bot: NewLadderBot) -> Result<LadderBot, MatchMakerError> {
+    compile_bot(bot)?;
+
+    let ladder_bot = SqlLadderBot::from(new_ladder_bot);
+    let source = settings().paths.bots_workdir.join(&bot.id);
+    let destination = ladder_bot_folder(&ladder_bot.id);
+    if let Err(e) = copy_compiled(&source, &destination) {
+        let _ = fs::remove_dir_all(&destination);
+        return Err(MatchMakerError::IOError(e));
+    }
+
+    insert_ladder_bot(ladder_bot).map_err(|e| {
+        let _ = fs::remove_dir_all(&destination);
+        MatchMakerError::DatabaseError(e)
+    })
+}
+
+/// Plays a ladder game of every active team of the running competitions that have ladder bots.
+///
+/// Each team plays the staff bot whose rating is closest to its own ladder rating, which
+/// starts at the default ELO. The staff bots' ratings don't move, so a team's ladder rating
+/// tracks how strong its bots are regardless of how the rest of the competition does. The
+/// games are unranked work: they aren't stored as games and yield to ranked rounds.
+pub fn run_ladder() {
+    if is_safe_mode() || LADDER_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let _running = LadderRunGuard;
+    match get_running_competitions() {
+        Ok(competitions) => {
+            for competition in competitions.iter() {
+                if let Err(e) = run_competition_ladder(competition) {
+                    error!("Failed playing the ladder of competition {}: {:?}", competition.id, e);
+                }
+            }
+        },
+        Err(e) => error!("Failed loading competitions for the ladder: {:?}", e),
+    }
+}
+
+fn run_competition_ladder(competition: &Competition) -> Result<(), MatchMakerError> {
+    let ladder_bots = get_ladder_bots_by_competition_id(competition.id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    if ladder_bots.is_empty() {
+        return Ok(());
+    }
+    let teams = get_active_teams_by_competition_id(competition.id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let mut ratings = ladder_ratings(competition.id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(competition)?;
+
+    info!("Playing the ladder of competition {} against {} staff bots", competition.id, ladder_bots.len());
+    for team in teams.iter().filter(|t| has_compiled_bots(t)) {
+        if is_shutting_down() {
+            break;
+        }
+        let rating = ratings.get(&team.id).copied().unwrap_or(DEFAULT_ELO);
+        let ladder_bot = match ladder_bots.iter().min_by_key(|b| (b.rating - rating).abs()) {
+            Some(b) => b,
+            None => break,
+        };
+
+        let _permit = acquire_unranked_slot(None);
+        let mut match_game = NewGame2v2::new(
+            competition.id.clone(),
+            competition.round,
+            team.id.clone(),
+            ladder_bot.id.clone(),
+            team.bot1.clone(),
+            team.bot2.clone(),
+            format!("{}-a", ladder_bot.id),
+            format!("{}-b", ladder_bot.id),
+        );
+        let sources = vec![
+            settings().paths.bots_workdir.join(&team.bot1),
+            settings().paths.bots_workdir.join(&team.bot2),
+            ladder_bot_folder(&ladder_bot.id),
+            ladder_bot_folder(&ladder_bot.id),
+        ];
+        if let Err(e) = play_unranked_game(adapter.as_ref(), &mut match_game, sources) {
+            warn!("Ladder game of team {} against {} failed: {}", team.id, ladder_bot.name, e);
+            continue;
+        }
+
+        let won = match_game.winner_id == team.id;
+        let rating_change = calculate_elo_change(rating, ladder_bot.rating, if won { 1.0 } else { 0.0 }, competition.elo_k_factor);
+        let new_rating = rating + rating_change;
+        insert_ladder_game(NewLadderGame {
+            competition_id: competition.id.clone(),
+            team_id: team.id.clone(),
+            ladder_bot_id: ladder_bot.id.clone(),
+            won,
+            team_score: match_game.team1_score,
+            bot_score: match_game.team2_score,
+            rating: new_rating,
+            rating_change,
+        }).map_err(MatchMakerError::DatabaseError)?;
+        ratings.insert(team.id.clone(), new_rating);
+    }
+    Ok(())
+}
+
+/// The staff bots of a competition's ladder and every team that played on it, best first.
+pub fn ladder_overview(competition_id: String) -> Result<LadderOverview, Error> {
+    let bots = get_ladder_bots_by_competition_id(competition_id.clone())?;
+    let games = get_ladder_games_by_competition_id(competition_id.clone())?;
+
+    let mut standings: HashMap<String, LadderStanding> = HashMap::new();
+    for game in games.into_iter() {
+        let standing = standings.entry(game.team_id.clone()).or_insert_with(|| LadderStanding {
+            team_id: game.team_id.clone(),
+            team_name: "".to_string(),
+            rating: DEFAULT_ELO,
+            games: 0,
+            wins: 0,
+        });
+        standing.rating = game.rating;
+        standing.games += 1;
+        if game.won {
+            standing.wins += 1;
+        }
+    }
+    for team in get_teams_by_competition_id(competition_id)?.into_iter() {
+        if let Some(standing) = standings.get_mut(&team.id) {
+            standing.team_name = team.name;
+        }
+    }
+
+    let mut standings = standings.into_values().collect::<Vec<LadderStanding>>();
+    standings.sort_by(|a, b| b.rating.cmp(&a.rating).then(a.team_name.cmp(&b.team_name)));
+    Ok(LadderOverview {
+        bots: bots.into_iter().map(PublicLadderBot::from).collect(),
+        standings,
+    })
+}
+
+/// The current ladder rating of every team that played on the ladder.
+fn ladder_ratings(competition_id: String) -> Result<HashMap<String, i32>, Error> {
+    Ok(get_ladder_games_by_competition_id(competition_id)?
+        .into_iter()
+        .map(|game| (game.team_id, game.rating))
+        .collect())
+}
+
+fn ladder_bot_folder(ladder_bot_id: &str) -> PathBuf {
+    settings().paths.ladder_bots.join(ladder_bot_id)
+}
\ No newline at end of file
@@ -1,21 +1,47 @@
-use ldap3::{Scope, SearchEntry, LdapConnAsync};
+use ldap3::{Scope, SearchEntry, LdapConnAsync, ldap_escape};
 use ldap3::result::Result;
 use std::env;
+use tracing::{error, warn};
+
+use crate::models::user::LdapUser;
+
+const DEFAULT_LDAP_BASE_DN: &str = "dc=upr,dc=si";
+const DEFAULT_LDAP_USER_ATTRIBUTE: &str = "uid";
+
+/// Checks the credentials against the faculty directory and returns the account's entry.
+///
+/// The account is looked up under `LDAP_BASE_DN` by the `LDAP_USER_ATTRIBUTE` attribute and
+/// the password is verified by binding as it.
+pub async fn ldap_login(username: String, password: String) -> Result<Option<LdapUser>> {
+    // an empty password would make the bind anonymous, which most servers accept
+    if username.trim().is_empty() || password.is_empty() {
+        return Ok(None);
+    }
+
+    let base_dn = env::var("LDAP_BASE_DN").unwrap_or(DEFAULT_LDAP_BASE_DN.to_string());
+    let user_attribute = env::var("LDAP_USER_ATTRIBUTE").unwrap_or(DEFAULT_LDAP_USER_ATTRIBUTE.to_string());
 
-pub async fn ldap_login(username: String, password: String) -> Result<Option<String>> {
     let (conn, mut ldap_conn) = LdapConnAsync::new(&env::var("LDAP_SERVER").expect("$LDAP_SERVER is not set")).await?;
     ldap3::drive!(conn);
 
     // Search for the user in the directory
     let (rs, _res) = ldap_conn.search(
-        "dc=upr,dc=si",
+        base_dn.as_str(),
         Scope::Subtree,
-        format!("(uid={})", username).as_str(),
-        vec!["dn", "sn", "cn"]
+        format!("({}={})", user_attribute, ldap_escape(username.as_str())).as_str(),
+        vec!["dn", "sn", "cn", "mail"]
     ).await?.success()?;
 
+    // there should only be one entry, an ambiguous username isn't trusted
+    if rs.len() != 1 {
+        if rs.len() > 1 {
+            warn!("Found {} directory entries for {}, refusing login", rs.len(), username);
+        }
+        let _ = ldap_conn.unbind().await;
+        return Ok(None);
+    }
+
     let mut user_entry = None;
-    // there should only be one entry in the array or results
     for entry in rs {
         let entry = SearchEntry::construct(entry);
         match ldap_conn.simple_bind(&entry.dn, &password).await {
@@ -29,9 +55,18 @@ pub async fn ldap_login(username: String, password: String) -> Result<Option<Str
                 errors. For example, error code 49 is used to indicate that 
                 the provided credentials (username or password) are invalid
             */
-            Ok(r) => if r.rc == 0 { user_entry = Some(entry.dn); },
-            Err(e) => println!("Error binding to ldap: {:?}", e)
+            Ok(r) => if r.rc == 0 {
+                let attribute = |name: &str| entry.attrs.get(name).and_then(|v| v.first()).cloned().unwrap_or_default();
+                user_entry = Some(LdapUser {
+                    username: username.clone(),
+                    display_name: attribute("cn"),
+                    email: attribute("mail"),
+                    ldap_dn: entry.dn,
+                });
+            },
+            Err(e) => error!("Error binding to ldap: {:?}", e)
         }
     }
+    let _ = ldap_conn.unbind().await;
     Ok(user_entry)
 }
\ No newline at end of file
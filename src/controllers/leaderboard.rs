@@ -0,0 +1,355 @@
+use std::{cmp::Ordering, collections::{HashMap, HashSet}};
+
+use diesel::result::Error;
+
+use crate::{
+    db::{
+        operations_game2v2::get_games_by_competition_id,
+        operations_teams::get_active_teams_by_competition_id,
+        operations_participation::get_participations_by_competition_id,
+        operations_round_byes::get_round_byes_by_competition_id,
+        operations_elo_history::get_elo_history_by_competition_id,
+        operations_team_streaks::{get_team_streaks_by_competition_id, replace_team_streaks},
+        operations_standings_history::{get_standings_snapshot, replace_standings_snapshot},
+    },
+    models::{
+        leaderboard::{LeaderboardEntry, LeaderboardSort},
+        competition::{Competition, ScoringSystem},
+        game_2v2::Game2v2,
+        round_bye::RoundBye,
+        team::DEFAULT_ELO,
+        team_streak::TeamStreak,
+        standings_history::{PublicStandingsEntry, StandingsEntry, StandingsSnapshot},
+    },
+};
+
+/// Computes the standings of all teams in a competition.
+///
+/// Wins, losses, draws and points are counted over all played games in round order;
+/// games a team played against itself and unrated games against ghost teams are not counted. Byes and forfeits recorded for a round
+/// count as games played, a forfeit as a lost game without a score. Rounds played before byes
+/// were recorded count a bye when the team had a working bot but wasn't paired with anyone.
+/// Streaks and upsets come from the `team_streaks` aggregates. Teams are listed under their
+/// alias if they set one, the leaderboard is public.
+///
+/// Teams are ranked according to the competition's scoring system:
+///
+/// * `Elo` - by ELO, teams with equal ELO share a rank.
+/// * `Points` - by league points. Teams with equal points are separated by the points they
+///   earned in games against each other, then by score difference; teams equal in all three
+///   share a rank.
+///
+/// # Returns
+///
+/// The leaderboard entries ordered by rank.
+///
+pub fn build_leaderboard(competition: &Competition) -> Result<Vec<LeaderboardEntry>, Error> {
+    let teams = get_active_teams_by_competition_id(competition.id.clone())?;
+    let games = rated_games(get_games_by_competition_id(competition.id.clone())?);
+    let participations = get_participations_by_competition_id(competition.id.clone())?;
+    let byes = get_round_byes_by_competition_id(competition.id.clone())?;
+
+    let mut entries: HashMap<String, LeaderboardEntry> = teams
+        .into_iter()
+        .map(|team| (team.id.clone(), LeaderboardEntry {
+            rank: 0,
+            rank_change: None,
+            team_name: team.public_name(),
+            team_id: team.id,
+            elo: team.elo,
+            points: 0,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+            byes: 0,
+            forfeits: 0,
+            games_played: 0,
+            score_difference: 0,
+            current_streak: 0,
+            longest_win_streak: 0,
+            upset_score: 0,
+            upset_team_id: "".to_string(),
+        }))
+        .collect();
+
+    // byes are counted in round order together with the games
+    let mut pending_byes = byes.iter().peekable();
+    for game in games.iter() {
+        while let Some(bye) = pending_byes.next_if(|b| b.round < game.round) {
+            count_bye(&mut entries, competition, bye);
+        }
+        if game.team1_id == game.team2_id {
+            continue;
+        }
+        for team_id in [&game.team1_id, &game.team2_id] {
+            let entry = match entries.get_mut(team_id) {
+                Some(e) => e,
+                None => continue,
+            };
+            entry.games_played += 1;
+            entry.points += game_points(competition, game, team_id);
+            entry.score_difference += if game.team1_id == *team_id {
+                game.team1_score - game.team2_score
+            } else {
+                game.team2_score - game.team1_score
+            };
+
+            if game.winner_id.is_empty() {
+                entry.draws += 1;
+            } else if game.winner_id == *team_id {
+                entry.wins += 1;
+            } else {
+                entry.losses += 1;
+            }
+        }
+    }
+
+    for bye in pending_byes {
+        count_bye(&mut entries, competition, bye);
+    }
+
+    for participation in participations.iter() {
+        if !participation.submitted_working_bot || participation.games_played > 0 {
+            continue;
+        }
+        if byes.iter().any(|b| b.team_id == participation.team_id && b.round == participation.round) {
+            continue;
+        }
+        if let Some(entry) = entries.get_mut(&participation.team_id) {
+            entry.byes += 1;
+            entry.games_played += 1;
+            entry.points += competition.points_bye;
+        }
+    }
+
+    // streaks and upsets are kept up to date as the rounds are stored, competitions that were
+    // played before they were tracked have theirs built once from their games
+    let mut streaks = get_team_streaks_by_competition_id(competition.id.clone())?;
+    if streaks.is_empty() && !games.is_empty() {
+        streaks = rebuild_team_streaks(competition.id.clone())?;
+    }
+    for streak in streaks.into_iter() {
+        if let Some(entry) = entries.get_mut(&streak.team_id) {
+            entry.current_streak = streak.current_streak;
+            entry.longest_win_streak = streak.longest_win_streak;
+            entry.upset_score = streak.upset_score;
+            entry.upset_team_id = streak.upset_team_id;
+        }
+    }
+
+    let mut entries: Vec<LeaderboardEntry> = entries.into_values().collect();
+    match competition.scoring_system {
+        ScoringSystem::Elo => rank_by_elo(&mut entries),
+        ScoringSystem::Points => rank_by_points(&mut entries, &games, competition),
+    }
+
+    // the standings before the last played round
+    let previous = previous_ranks(competition.id.clone(), competition.round - 2)?;
+    for entry in entries.iter_mut() {
+        entry.rank_change = previous.get(&entry.team_id).map(|rank| rank - entry.rank as i32);
+    }
+    Ok(entries)
+}
+
+/// Stores the leaderboard as it is at the end of the competition's current round, so rank
+/// changes between rounds can be shown without replaying all games.
+pub fn snapshot_standings(competition: &Competition) -> Result<(), Error> {
+    let entries = build_leaderboard(competition)?
+        .iter()
+        .map(|entry| StandingsEntry::from_leaderboard(competition.id.clone(), competition.round, entry))
+        .collect::<Vec<StandingsEntry>>();
+    replace_standings_snapshot(competition.id.clone(), competition.round, entries)
+}
+
+/// The standings at the end of a round with each team's rank change since the round before.
+///
+/// # Returns
+///
+/// `None` if no standings were stored for the round.
+///
+pub fn standings_snapshot(competition_id: String, round: i32) -> Result<Option<StandingsSnapshot>, Error> {
+    let entries = get_standings_snapshot(competition_id.clone(), round)?;
+    if entries.is_empty() {
+        return Ok(None);
+    }
+    let previous = previous_ranks(competition_id.clone(), round - 1)?;
+
+    Ok(Some(StandingsSnapshot {
+        competition_id,
+        round,
+        entries: entries
+            .into_iter()
+            .map(|entry| PublicStandingsEntry {
+                rank: entry.rank,
+                rank_change: previous.get(&entry.team_id).map(|rank| rank - entry.rank),
+                team_id: entry.team_id,
+                team_name: entry.team_name,
+                elo: entry.elo,
+                points: entry.points,
+                wins: entry.wins,
+                losses: entry.losses,
+                draws: entry.draws,
+                games_played: entry.games_played,
+            })
+            .collect(),
+    }))
+}
+
+/// The rank of each team at the end of the round, empty if the round has no stored standings.
+fn previous_ranks(competition_id: String, round: i32) -> Result<HashMap<String, i32>, Error> {
+    if round < 0 {
+        return Ok(HashMap::new());
+    }
+    Ok(get_standings_snapshot(competition_id, round)?
+        .into_iter()
+        .map(|entry| (entry.team_id, entry.rank))
+        .collect())
+}
+
+/// Sorts leaderboard entries, rank breaks ties of all other sort keys.
+pub fn sort_leaderboard(entries: &mut [LeaderboardEntry], sort: LeaderboardSort) {
+    entries.sort_by(|a, b| {
+        let ordering = match sort {
+            LeaderboardSort::Rank => Ordering::Equal,
+            LeaderboardSort::Elo => b.elo.cmp(&a.elo),
+            LeaderboardSort::Points => b.points.cmp(&a.points),
+            LeaderboardSort::Wins => b.wins.cmp(&a.wins),
+            LeaderboardSort::Losses => b.losses.cmp(&a.losses),
+            LeaderboardSort::GamesPlayed => b.games_played.cmp(&a.games_played),
+            LeaderboardSort::WinRate => b.win_rate().total_cmp(&a.win_rate()),
+            LeaderboardSort::Streak => b.current_streak.cmp(&a.current_streak),
+            LeaderboardSort::Upset => b.upset_score.cmp(&a.upset_score),
+            LeaderboardSort::Name => a.team_name.cmp(&b.team_name),
+        };
+        ordering.then_with(|| a.rank.cmp(&b.rank))
+    });
+}
+
+/// Builds the streak and upset aggregates of a competition from all its games and forfeits,
+/// replacing the stored ones. Used when the games' results or the teams' ELO history changed
+/// after the rounds were stored.
+pub fn rebuild_team_streaks(competition_id: String) -> Result<Vec<TeamStreak>, Error> {
+    let games = rated_games(get_games_by_competition_id(competition_id.clone())?);
+    let byes = get_round_byes_by_competition_id(competition_id.clone())?;
+    // ELO before each round, the history of a round holds the ELO after it
+    let elo_before = get_elo_history_by_competition_id(competition_id.clone())?
+        .into_iter()
+        .map(|h| ((h.team_id, h.round), h.elo - h.elo_change))
+        .collect::<HashMap<(String, i32), i32>>();
+
+    let mut streaks: HashMap<String, TeamStreak> = HashMap::new();
+    let mut pending_forfeits = byes.iter().filter(|b| b.is_forfeit()).peekable();
+    for game in games.iter() {
+        while let Some(forfeit) = pending_forfeits.next_if(|b| b.round < game.round) {
+            streaks
+                .entry(forfeit.team_id.clone())
+                .or_insert_with(|| TeamStreak::new(competition_id.clone(), forfeit.team_id.clone()))
+                .record_loss();
+        }
+        if game.team1_id == game.team2_id {
+            continue;
+        }
+        for (team_id, opponent_id) in [(&game.team1_id, &game.team2_id), (&game.team2_id, &game.team1_id)] {
+            let opponent_elo = elo_before.get(&(opponent_id.clone(), game.round)).copied().unwrap_or(DEFAULT_ELO);
+            streaks
+                .entry(team_id.clone())
+                .or_insert_with(|| TeamStreak::new(competition_id.clone(), team_id.clone()))
+                .record_game(&game.winner_id, opponent_id, opponent_elo);
+        }
+    }
+    for forfeit in pending_forfeits {
+        streaks
+            .entry(forfeit.team_id.clone())
+            .or_insert_with(|| TeamStreak::new(competition_id.clone(), forfeit.team_id.clone()))
+            .record_loss();
+    }
+
+    let streaks = streaks.into_values().collect::<Vec<TeamStreak>>();
+    replace_team_streaks(competition_id, streaks.clone())?;
+    Ok(streaks)
+}
+
+fn count_bye(entries: &mut HashMap<String, LeaderboardEntry>, competition: &Competition, bye: &RoundBye) {
+    let entry = match entries.get_mut(&bye.team_id) {
+        Some(e) => e,
+        None => return,
+    };
+    entry.games_played += 1;
+    if bye.is_forfeit() {
+        entry.forfeits += 1;
+        entry.losses += 1;
+        entry.points += competition.points_loss;
+    } else {
+        entry.byes += 1;
+        entry.points += competition.points_bye;
+    }
+}
+
+/// Games that count towards the standings, games against ghost teams don't.
+fn rated_games(games: Vec<Game2v2>) -> Vec<Game2v2> {
+    games.into_iter().filter(|g| !g.unrated).collect()
+}
+
+fn game_points(competition: &Competition, game: &Game2v2, team_id: &str) -> i32 {
+    if game.winner_id.is_empty() {
+        competition.points_draw
+    } else if game.winner_id == team_id {
+        competition.points_win
+    } else {
+        competition.points_loss
+    }
+}
+
+fn rank_by_elo(entries: &mut [LeaderboardEntry]) {
+    entries.sort_by(|a, b| b.elo.cmp(&a.elo).then_with(|| a.team_name.cmp(&b.team_name)));
+    assign_ranks(entries, |e| (e.elo, 0, 0));
+}
+
+fn rank_by_points(entries: &mut [LeaderboardEntry], games: &[Game2v2], competition: &Competition) {
+    // group teams with equal points, the head-to-head points are only counted within a group
+    let mut groups: HashMap<i32, HashSet<String>> = HashMap::new();
+    for entry in entries.iter() {
+        groups.entry(entry.points).or_default().insert(entry.team_id.clone());
+    }
+
+    let mut head_to_head: HashMap<String, i32> = HashMap::new();
+    for game in games.iter() {
+        if game.team1_id == game.team2_id {
+            continue;
+        }
+        let same_group = groups
+            .values()
+            .any(|g| g.len() > 1 && g.contains(&game.team1_id) && g.contains(&game.team2_id));
+        if !same_group {
+            continue;
+        }
+        for team_id in [&game.team1_id, &game.team2_id] {
+            *head_to_head.entry(team_id.clone()).or_insert(0) += game_points(competition, game, team_id);
+        }
+    }
+
+    let key = |e: &LeaderboardEntry| (
+        e.points,
+        head_to_head.get(&e.team_id).copied().unwrap_or(0),
+        e.score_difference,
+    );
+    entries.sort_by(|a, b| key(b).cmp(&key(a)).then_with(|| a.team_name.cmp(&b.team_name)));
+    assign_ranks(entries, key);
+}
+
+/// Assigns ranks to sorted entries, entries with an equal key share a rank.
+fn assign_ranks<F>(entries: &mut [LeaderboardEntry], key: F)
+where
+    F: Fn(&LeaderboardEntry) -> (i32, i32, i32)
+{
+    let mut previous_key = None;
+    let mut rank = 0;
+    for (i, entry) in entries.iter_mut().enumerate() {
+        let current_key = key(entry);
+        if previous_key != Some(current_key) {
+            rank = i + 1;
+            previous_key = Some(current_key);
+        }
+        entry.rank = rank;
+    }
+}
\ No newline at end of file
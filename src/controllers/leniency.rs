@@ -0,0 +1,35 @@
+use tracing::warn;
+
+use crate::{
+    db::operations_round_leniency::get_round_leniencies_by_competition_id,
+    models::{competition::Competition, round_leniency::GameLeniency},
+};
+
+/// Resolves the game timeout and retries for the competition's current round.
+///
+/// When several configured round ranges cover the round, the narrowest one wins, so a
+/// specific override (e.g. the final round) takes precedence over a broad one. Rounds not
+/// covered by any range use the competition's game timeout and the default retries.
+pub fn leniency_for_round(competition: &Competition) -> GameLeniency {
+    let competition_leniency = GameLeniency {
+        timeout_secs: competition.game_timeout_secs,
+        ..GameLeniency::default()
+    };
+    let leniencies = match get_round_leniencies_by_competition_id(competition.id.clone()) {
+        Ok(l) => l,
+        Err(e) => {
+            warn!("Failed fetching round leniency, using defaults: {:?}", e);
+            return competition_leniency;
+        }
+    };
+
+    leniencies
+        .into_iter()
+        .filter(|l| l.covers(competition.round))
+        .min_by_key(|l| match l.to_round {
+            0 => i32::MAX,
+            to_round => to_round - l.from_round,
+        })
+        .map(GameLeniency::from)
+        .unwrap_or(competition_leniency)
+}
\ No newline at end of file
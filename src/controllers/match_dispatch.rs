@@ -0,0 +1,202 @@
+use std::{collections::{HashMap, VecDeque}, env, fs, io::{Cursor, Write}, path::Path, sync::Mutex, time::{Duration, Instant}};
+
+use once_cell::sync::Lazy;
+use tokio::{sync::oneshot, time::timeout};
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+use tracing::warn;
+
+use crate::{config::settings, models::{errors::MatchMakerError, remote_game::{RemoteGame, RemoteGameResult}}};
+
+use super::shutdown::is_shutting_down;
+
+/// A worker that hasn't asked for a game for this long is considered gone.
+const WORKER_STALE_SECS: u64 = 30;
+/// Extra time a worker gets on top of the game's timeout to download the bots and report back.
+const LEASE_GRACE_SECS: u64 = 60;
+/// How often a game is handed out before the server plays it itself.
+const MAX_REMOTE_ATTEMPTS: u32 = 3;
+/// How often a waiting game checks for workers that went silent.
+const LEASE_CHECK_SECS: u64 = 5;
+/// Largest result a worker may upload, replays of long games get big.
+pub const MAX_RESULT_PAYLOAD_BYTES: usize = 256 * 1024 * 1024;
+
+struct Assignment {
+    game: RemoteGame,
+    attempts: u32,
+    result: oneshot::Sender<RemoteGameResult>,
+}
+
+struct Lease {
+    worker_id: String,
+    deadline: Instant,
+    assignment: Assignment,
+}
+
+#[derive(Default)]
+struct DispatchState {
+    queue: VecDeque<Assignment>,
+    /// assignment id -> the worker playing it
+    leases: HashMap<String, Lease>,
+    /// worker id -> when it last asked for a game or reported one
+    workers: HashMap<String, Instant>,
+}
+
+static STATE: Lazy<Mutex<DispatchState>> = Lazy::new(|| Mutex::new(DispatchState::default()));
+
+impl DispatchState {
+    fn has_live_workers(&self) -> bool {
+        let stale = Duration::from_secs(WORKER_STALE_SECS);
+        !self.leases.is_empty() || self.workers.values().any(|seen| seen.elapsed() < stale)
+    }
+
+    /// Puts games whose worker didn't report back in time back in the queue. A game that
+    /// ran out of attempts, or that no worker is left for, is handed back to the server by
+    /// dropping its sender.
+    fn requeue_expired_leases(&mut self) {
+        let now = Instant::now();
+        let expired: Vec<String> = self.leases
+            .iter()
+            .filter(|(_, lease)| lease.deadline <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for id in expired.into_iter() {
+            if let Some(lease) = self.leases.remove(&id) {
+                warn!("Worker {} didn't report game {} in time", lease.worker_id, lease.assignment.game.game_id);
+                // a worker that is still alive registers again with its next request
+                self.workers.remove(&lease.worker_id);
+                self.retry(lease.assignment);
+            }
+        }
+
+        if !self.has_live_workers() {
+            self.queue.clear();
+        }
+    }
+
+    fn retry(&mut self, mut assignment: Assignment) {
+        assignment.attempts += 1;
+        if assignment.attempts < MAX_REMOTE_ATTEMPTS {
+            self.queue.push_front(assignment);
+        }
+    }
+}
+
+/// Shared secret the match workers authenticate with, remote execution is disabled without it.
+pub fn worker_token() -> Option<String> {
+    env::var("MATCH_WORKER_TOKEN").ok().filter(|t| !t.trim().is_empty())
+}
+
+pub fn is_worker_token(token: &str) -> bool {
+    match worker_token() {
+        Some(t) => t == token,
+        None => false,
+    }
+}
+
+/// Plays a game on a remote match worker.
+///
+/// The game is queued until a worker leases it. If the worker doesn't report back within
+/// the game's timeout plus a grace period, or reports it couldn't play the game, the game is
+/// given to another worker, up to `MAX_REMOTE_ATTEMPTS` times.
+///
+/// # Returns
+///
+/// The lines the game wrote to stdout and stderr, or `None` if no worker is available or
+/// the game ran out of attempts; the caller plays it locally then.
+///
+pub async fn dispatch_remote_game(game: RemoteGame) -> Option<(Vec<String>, Vec<String>)> {
+    let mut receiver = {
+        let mut state = STATE.lock().unwrap();
+        if worker_token().is_none() || !state.has_live_workers() || is_shutting_down() {
+            return None;
+        }
+        let (sender, receiver) = oneshot::channel();
+        state.queue.push_back(Assignment { game, attempts: 0, result: sender });
+        receiver
+    };
+
+    loop {
+        match timeout(Duration::from_secs(LEASE_CHECK_SECS), &mut receiver).await {
+            Ok(Ok(result)) => return Some((result.output, result.errors)),
+            Ok(Err(_)) => return None,
+            Err(_) => STATE.lock().unwrap().requeue_expired_leases(),
+        }
+    }
+}
+
+/// Hands the next queued game to a worker. Asking for a game also marks the worker as alive.
+pub fn lease_remote_game(worker_id: &str) -> Option<RemoteGame> {
+    let mut state = STATE.lock().unwrap();
+    state.workers.insert(worker_id.to_string(), Instant::now());
+    state.requeue_expired_leases();
+
+    let assignment = state.queue.pop_front()?;
+    let game = assignment.game.clone();
+    let deadline = Instant::now() + Duration::from_secs(game.timeout_secs.max(0) as u64 + LEASE_GRACE_SECS);
+    state.leases.insert(game.id.clone(), Lease {
+        worker_id: worker_id.to_string(),
+        deadline,
+        assignment,
+    });
+    Some(game)
+}
+
+/// Takes the result of a leased game.
+///
+/// # Returns
+///
+/// `false` if the worker doesn't hold the game's lease (anymore), the result is ignored then.
+///
+pub fn complete_remote_game(result: RemoteGameResult) -> bool {
+    let mut state = STATE.lock().unwrap();
+    state.workers.insert(result.worker_id.clone(), Instant::now());
+
+    let holds_lease = state.leases
+        .get(&result.assignment_id)
+        .map(|lease| lease.worker_id == result.worker_id)
+        .unwrap_or(false);
+    if !holds_lease {
+        return false;
+    }
+
+    let lease = state.leases.remove(&result.assignment_id).expect("Lease disappeared while locked");
+    if let Some(error) = &result.error {
+        warn!("Worker {} couldn't play game {}: {}", result.worker_id, lease.assignment.game.game_id, error);
+        state.retry(lease.assignment);
+        return true;
+    }
+    // the round may have given up on the game already
+    let _ = lease.assignment.result.send(result);
+    true
+}
+
+/// Zips the compiled files of a bot for a match worker.
+pub fn zip_compiled_bot(bot_id: &str) -> Result<Vec<u8>, MatchMakerError> {
+    let bot_folder = settings().paths.bots_workdir.join(bot_id);
+    if !bot_folder.is_dir() {
+        return Err(MatchMakerError::InvalidPath(bot_folder.into()));
+    }
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    add_folder_to_zip(&mut zip, &bot_folder, "", options)?;
+    let buffer = zip.finish().map_err(MatchMakerError::ZippingError)?;
+    Ok(buffer.into_inner())
+}
+
+fn add_folder_to_zip(zip: &mut ZipWriter<Cursor<Vec<u8>>>, folder: &Path, prefix: &str, options: FileOptions) -> Result<(), MatchMakerError> {
+    for entry in fs::read_dir(folder).map_err(MatchMakerError::IOError)? {
+        let path = entry.map_err(MatchMakerError::IOError)?.path();
+        let name = format!("{}{}", prefix, path.file_name().unwrap_or_default().to_string_lossy());
+        if path.is_dir() {
+            zip.add_directory(name.clone(), options).map_err(MatchMakerError::ZippingError)?;
+            add_folder_to_zip(zip, &path, &format!("{}/", name), options)?;
+        } else {
+            let contents = fs::read(&path).map_err(MatchMakerError::IOError)?;
+            zip.start_file(name, options).map_err(MatchMakerError::ZippingError)?;
+            zip.write_all(&contents).map_err(MatchMakerError::IOError)?;
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
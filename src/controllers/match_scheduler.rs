@@ -0,0 +1,104 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use tokio::sync::oneshot;
+
+use crate::config::settings;
+
+/// Game slots shared by the rounds of all competitions, a round has to hold one for every
+/// game it plays.
+static SCHEDULER: Lazy<Mutex<SchedulerState>> = Lazy::new(|| Mutex::new(SchedulerState {
+    free_slots: settings().threads.concurrent_games(),
+    running: HashMap::new(),
+    waiting: Vec::new(),
+    next_ticket: 0,
+}));
+
+struct SchedulerState {
+    free_slots: usize,
+    /// games each competition is playing
+    running: HashMap<String, usize>,
+    waiting: Vec<Waiter>,
+    next_ticket: u64,
+}
+
+struct Waiter {
+    competition_id: String,
+    priority: i32,
+    ticket: u64,
+    granted: oneshot::Sender<MatchSlot>,
+}
+
+/// A game slot held for as long as it's alive.
+pub struct MatchSlot {
+    competition_id: String,
+}
+
+impl Drop for MatchSlot {
+    fn drop(&mut self) {
+        let mut state = SCHEDULER.lock().unwrap();
+        release(&mut state, &self.competition_id);
+        dispatch(&mut state);
+    }
+}
+
+/// Waits for a game slot for a game of the competition.
+///
+/// Free slots go to the waiting competition playing the fewest games for its priority, so
+/// competitions running rounds at the same time share the machine in proportion to their
+/// priorities. Ties go to the higher priority and then to whoever asked first.
+pub async fn acquire_match_slot(competition_id: &str, priority: i32) -> MatchSlot {
+    let (granted, slot_granted) = oneshot::channel();
+    {
+        let mut state = SCHEDULER.lock().unwrap();
+        let ticket = state.next_ticket;
+        state.next_ticket += 1;
+        state.waiting.push(Waiter {
+            competition_id: competition_id.to_string(),
+            priority: priority.max(1),
+            ticket,
+            granted,
+        });
+        dispatch(&mut state);
+    }
+    // the sender is only dropped once the slot is granted
+    slot_granted.await.expect("Match scheduler dropped a waiting game")
+}
+
+/// Hands the free slots to the waiting games in fair-share order.
+fn dispatch(state: &mut SchedulerState) {
+    while state.free_slots > 0 && !state.waiting.is_empty() {
+        let next = state.waiting
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let share = |w: &Waiter| *state.running.get(&w.competition_id).unwrap_or(&0) as f64 / w.priority as f64;
+                share(a).total_cmp(&share(b))
+                    .then(b.priority.cmp(&a.priority))
+                    .then(a.ticket.cmp(&b.ticket))
+            })
+            .map(|(i, _)| i);
+        let waiter = match next {
+            Some(i) => state.waiting.remove(i),
+            None => return,
+        };
+        state.free_slots -= 1;
+        *state.running.entry(waiter.competition_id.clone()).or_default() += 1;
+        // a round that stopped waiting doesn't get a slot, the slot it would have held can't
+        // be dropped while the scheduler is locked
+        if let Err(slot) = waiter.granted.send(MatchSlot { competition_id: waiter.competition_id }) {
+            release(state, &slot.competition_id);
+            std::mem::forget(slot);
+        }
+    }
+}
+
+fn release(state: &mut SchedulerState, competition_id: &str) {
+    state.free_slots += 1;
+    if let Some(running) = state.running.get_mut(competition_id) {
+        *running -= 1;
+        if *running == 0 {
+            state.running.remove(competition_id);
+        }
+    }
+}
\ No newline at end of file
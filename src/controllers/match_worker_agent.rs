@@ -0,0 +1,167 @@
+use std::{env, fs, io::Error, path::Path, time::Duration};
+
+use uuid::Uuid;
+use tracing::{error, info};
+
+use crate::{
+    adapters::adapter_for_game,
+    models::{errors::MatchMakerError, remote_game::{RemoteGame, RemoteGameLease, RemoteGameResult}},
+};
+
+use super::{command_executor::execute_command_with_timeout, matchmaker_2v2::execute_evaluator, match_workspace::remove_workspace, archive_safety::extract_archive};
+
+/// How long an idle worker waits before asking for the next game.
+const AGENT_POLL_SECS: u64 = 5;
+/// Upper bound for a single request to the coordinator, bot downloads and replay uploads included.
+const AGENT_REQUEST_TIMEOUT_SECS: u64 = 300;
+const AGENT_DIR: &str = "./resources/worker";
+
+/// Url of the server this instance plays games for. When `MATCH_WORKER_COORDINATOR` is set
+/// the binary runs as a match worker instead of the server.
+pub fn worker_coordinator() -> Option<String> {
+    env::var("MATCH_WORKER_COORDINATOR")
+        .ok()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
+
+/// Plays the games the coordinator hands out, one at a time, until the process is stopped.
+///
+/// The worker authenticates with `MATCH_WORKER_TOKEN` and identifies itself with
+/// `MATCH_WORKER_ID` (a random id if unset). It needs `java` and `curl`, and the
+/// evaluators of the competitions it plays: the default evaluator and evaluators referenced by
+/// a server path have to exist at the same path, downloaded evaluators are fetched the same
+/// way the server does.
+pub async fn run_worker_agent(coordinator: String) {
+    let token = env::var("MATCH_WORKER_TOKEN").expect("$MATCH_WORKER_TOKEN is not set");
+    let worker_id = env::var("MATCH_WORKER_ID").unwrap_or_else(|_| Uuid::new_v4().to_string());
+    info!("Match worker {} playing games for {}", worker_id, coordinator);
+
+    loop {
+        match lease_game(&coordinator, &token, &worker_id) {
+            Ok(Some(game)) => {
+                info!("Playing game {} (assignment {})", game.game_id, game.id);
+                let result = match play_game(&coordinator, &token, &game).await {
+                    Ok((output, errors)) => RemoteGameResult {
+                        assignment_id: game.id.clone(),
+                        worker_id: worker_id.clone(),
+                        output,
+                        errors,
+                        error: None,
+                    },
+                    Err(e) => RemoteGameResult {
+                        assignment_id: game.id.clone(),
+                        worker_id: worker_id.clone(),
+                        output: vec![],
+                        errors: vec![],
+                        error: Some(e.to_string()),
+                    },
+                };
+                if let Err(e) = report_game(&coordinator, &token, &result) {
+                    error!("Failed reporting game {}: {}", game.game_id, e);
+                }
+            },
+            Ok(None) => tokio::time::sleep(Duration::from_secs(AGENT_POLL_SECS)).await,
+            Err(e) => {
+                error!("Failed asking {} for a game: {}", coordinator, e);
+                tokio::time::sleep(Duration::from_secs(AGENT_POLL_SECS)).await;
+            },
+        }
+    }
+}
+
+fn lease_game(coordinator: &str, token: &str, worker_id: &str) -> Result<Option<RemoteGame>, MatchMakerError> {
+    let body = serde_json::to_string(&RemoteGameLease { worker_id: worker_id.to_string() })
+        .map_err(|e| MatchMakerError::IOError(Error::from(e)))?;
+    let response = coordinator_request(
+        &format!("{}/workers/lease", coordinator),
+        token,
+        &["-X", "POST", "-H", "Content-Type: application/json", "--data-binary", &body],
+    )?;
+
+    let response = response.join("\n");
+    if response.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(&response)
+        .map(Some)
+        .map_err(|e| MatchMakerError::IOError(Error::from(e)))
+}
+
+/// Downloads the bots of the game into a match folder and runs it.
+async fn play_game(coordinator: &str, token: &str, game: &RemoteGame) -> Result<(Vec<String>, Vec<String>), MatchMakerError> {
+    let match_folder = Path::new(AGENT_DIR).join("matches").join(&game.id);
+    let result = prepare_and_run(coordinator, token, game, &match_folder).await;
+    let _ = remove_workspace(&match_folder);
+    result
+}
+
+async fn prepare_and_run(coordinator: &str, token: &str, game: &RemoteGame, match_folder: &Path) -> Result<(Vec<String>, Vec<String>), MatchMakerError> {
+    let adapter = adapter_for_game(&game.game_pack, &game.evaluator_source, &game.evaluator_sha256)?;
+    if game.bot_ids.len() != adapter.bot_count() {
+        return Err(MatchMakerError::BotCountMismatch(adapter.bot_count(), game.bot_ids.len()));
+    }
+    fs::create_dir_all(match_folder).map_err(MatchMakerError::IOError)?;
+
+    let mut bot_paths = vec![];
+    for bot_id in game.bot_ids.iter() {
+        let archive = match_folder.join(format!("{}.zip", bot_id));
+        let archive_str = archive.to_string_lossy().to_string();
+        coordinator_request(
+            &format!("{}/workers/bots/{}", coordinator, bot_id),
+            token,
+            &["-o", &archive_str],
+        )?;
+
+        let bot_folder = match_folder.join(bot_id);
+        extract_archive(&archive, &bot_folder)?;
+        bot_paths.push(bot_folder.to_string_lossy().to_string());
+    }
+
+    execute_evaluator(&adapter.launch_args(bot_paths, game.map_seed), game.timeout_secs, game.stall_timeout_secs, match_folder).await
+}
+
+fn report_game(coordinator: &str, token: &str, result: &RemoteGameResult) -> Result<(), MatchMakerError> {
+    // replays are too big for the command line, the body is passed as a file
+    fs::create_dir_all(AGENT_DIR).map_err(MatchMakerError::IOError)?;
+    let body_file = format!("{}/{}.json", AGENT_DIR, result.assignment_id);
+    let body = serde_json::to_vec(result).map_err(|e| MatchMakerError::IOError(Error::from(e)))?;
+    fs::write(&body_file, body).map_err(MatchMakerError::IOError)?;
+
+    let data = format!("@{}", body_file);
+    let response = coordinator_request(
+        &format!("{}/workers/result", coordinator),
+        token,
+        &["-X", "POST", "-H", "Content-Type: application/json", "--data-binary", &data],
+    );
+    let _ = fs::remove_file(&body_file);
+    response.map(|_| ())
+}
+
+/// Sends a request to the coordinator with `curl`.
+///
+/// # Returns
+///
+/// The lines of the response body, an error if the request failed or got an error status.
+///
+fn coordinator_request(url: &str, token: &str, args: &[&str]) -> Result<Vec<String>, MatchMakerError> {
+    let authorization = format!("Authorization: Bearer {}", token);
+    let max_time = AGENT_REQUEST_TIMEOUT_SECS.to_string();
+    let mut curl_args = vec!["-sS", "--fail", "--max-time", &max_time, "-H", &authorization];
+    curl_args.extend_from_slice(args);
+    curl_args.push(url);
+
+    let output = execute_command_with_timeout(
+        "curl".to_string(),
+        curl_args,
+        vec![],
+        Duration::from_secs(AGENT_REQUEST_TIMEOUT_SECS + 10),
+    ).map_err(MatchMakerError::IOError)?;
+
+    match output.status {
+        Some(status) if status.success() => Ok(output.stdout),
+        _ => Err(MatchMakerError::IOError(Error::other(format!(
+            "Request to {} failed: {}", url, output.stderr.join("\n")
+        )))),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::{fs, io, os::unix::fs::PermissionsExt, path::{Path, PathBuf}};
+
+use crate::config::settings;
+
+/// Root of the folders games are played in, `paths.matches` of the settings.
+///
+/// Pointing it at a tmpfs mount keeps what bots write during a game off the disk the server
+/// stores its replays on.
+pub fn matches_dir() -> PathBuf {
+    settings().paths.matches.clone()
+}
+
+/// How much a single game's folder may grow to, `None` if the quota is 0.
+pub fn match_disk_quota_bytes() -> Option<u64> {
+    match settings().timeouts.match_disk_quota_mb {
+        0 => None,
+        mb => Some(mb * 1024 * 1024),
+    }
+}
+
+/// Total size of the files within the folder. Files that disappear while counting are skipped.
+pub fn workspace_size(path: &Path) -> u64 {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+    let mut size = 0;
+    for entry in entries.flatten() {
+        match entry.metadata() {
+            Ok(meta) if meta.is_dir() => size += workspace_size(&entry.path()),
+            Ok(meta) => size += meta.len(),
+            Err(_) => (),
+        }
+    }
+    size
+}
+
+/// Removes a game's folder.
+///
+/// Bots can leave read-only folders behind, whose files can't be removed. If the first attempt
+/// fails the folder is made writable all the way down and removed again.
+pub fn remove_workspace(path: &Path) -> io::Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+    if fs::remove_dir_all(path).is_ok() {
+        return Ok(());
+    }
+    make_writable(path);
+    fs::remove_dir_all(path)
+}
+
+fn make_writable(path: &Path) {
+    let meta = match fs::symlink_metadata(path) {
+        Ok(meta) => meta,
+        Err(_) => return,
+    };
+    // links are removed without being followed, their targets are left alone
+    if meta.file_type().is_symlink() {
+        return;
+    }
+    let mut permissions = meta.permissions();
+    permissions.set_mode(permissions.mode() | 0o700);
+    let _ = fs::set_permissions(path, permissions);
+    if meta.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                make_writable(&entry.path());
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,40 +1,74 @@
-use std::{path::Path, fs::{self, File}, process::{Command, Stdio, ExitStatus, Output}, time::Duration, thread, io::{BufReader, BufRead, self}, collections::HashMap, sync::{Arc, Mutex}};
-use rand::Rng;
-use rayon::prelude::{IntoParallelIterator, ParallelIterator, IntoParallelRefIterator};
-use wait_timeout::ChildExt;
-use num_cpus;
+use std::{path::{Path, PathBuf}, fs::{self, File}, os::unix::process::CommandExt, process::{Command, Stdio}, time::{Duration, Instant}, io::{BufRead, self}, collections::{HashMap, HashSet}, sync::{Arc, Mutex}};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde_json::json;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use tokio::{io::{AsyncBufReadExt, AsyncRead}, sync::Notify, task::JoinSet, time::timeout};
+use tracing::{error, field, info, info_span, warn, Instrument, Span};
 
 use crate::{
     db::{
-        operations_competition::{get_competition_by_id, set_competition_round}, 
-        operations_teams::get_teams_by_competition_id, 
-        operations_bot::{get_bot_by_id, set_bot_error}, operations_game2v2::insert_game,
+        operations_competition::get_competition_by_id, 
+        operations_teams::get_active_teams_by_competition_id, 
+        operations_bot::{get_bot_by_id, set_bot_error}, operations_game2v2::get_games_by_competition_id,
+        operations_round_events::insert_round_event,
+        operations_rounds::{complete_round, start_round, set_round_pairing, end_round},
+        operations_round_checkpoints::{get_round_checkpoints, save_round_checkpoints},
+        operations_round_byes::replace_round_byes,
+        operations_ghost_teams::get_ghost_teams_by_competition_id,
+        operations_bot_versions::get_bot_versions_by_team_ids,
     }, 
     models::{
         team::Team, 
         errors::{MatchMakerError, self}, 
         bot::Bot, 
-        game_2v2::{NewGame2v2, Game2v2, self}, 
-        competition::Competition, game_player_stats::{GamePlayerStats, GameError}
-    }, controllers::elo::update_team_elo
+        game_2v2::{NewGame2v2, Game2v2, SqlGame2v2, PendingGame2v2, ColorPair, UnrankedGameOutput, self}, 
+        competition::Competition, game_player_stats::{GamePlayerStats, GameError, NewGamePlayerStats},
+        round_event::NewRoundEvent,
+        round_leniency::GameLeniency,
+        compile_diagnostics::CompileDiagnostics,
+        remote_game::RemoteGame,
+        round_checkpoint::{NewRoundCheckpoint, RoundCheckpoint},
+        round_bye::{NewRoundBye, ByeKind},
+        pairing_constraints::PairingCosts,
+        round::{NewRound, PairingMetadata, RoundStatus},
+        webhook::WebhookEvent,
+        ghost_team::GhostTeam,
+        job::Job,
+    }, controllers::elo::calc_round_ratings,
+    parsers::{EvaluatorOutput, replay::decode_frames},
+    adapters::{GameAdapter, adapter_for_competition},
+    config::settings,
 };
 
-use super::{command_executor::{execute_command, recursive_copy}, elo::calc_elo_changes, file_handler::save_to_zip};
+use super::{command_executor::{execute_command, execute_command_with_timeout, recursive_copy}, elo::calc_elo_changes, disk_emergency::{store_replay, in_emergency_mode}, replay_retention::apply_replay_retention, round_hooks::run_post_round_hooks, participation::record_round_participation, pairing::pairing_costs, workload_gate::begin_ranked_round, leniency::leniency_for_round, trace::TraceContext, safe_mode::is_safe_mode, round_stats::record_round_stats, revalidation::exclude_expired_violations, alert_signals::{begin_round_signal, record_match_success, record_infra_failure}, static_scan::{forbidden_api_rules, scan_files}, job_queue::report_round_progress, match_dispatch::dispatch_remote_game, shutdown::{is_shutting_down, track_game_process, killed_by_shutdown}, match_workspace::{matches_dir, match_disk_quota_bytes, workspace_size, remove_workspace}, webhooks::notify_webhooks, discord::post_round_summary, archive_safety::extract_archive, crash_triage::{classify_failure, is_infrastructure_error, is_infrastructure_failure}, match_scheduler::acquire_match_slot, achievements::award_round_achievements, leaderboard::snapshot_standings, file_handler::local_copy, submission_window::{submission_cutoff, scheduled_round_start}, bot_versions::bots_at_cutoff, disk_capacity::check_round_capacity};
+
 
 /// Runs a 2v2 round for a specified competition.
 ///
 /// This function manages the execution of a single 2v2 round for a competition, which includes:
 /// 1. Fetching the competition details from the database.
-/// 2. Retrieving all the teams participating in the competition.
-/// 3. Compiling the bots for each team.
-/// 4. Creating match pairs for the round.
-/// 5. Running each match in parallel.
-/// 6. Cleaning up the match directory after all games have been executed.
-/// 7. Incrementing the competition round for the next set of matches.
+/// 2. Retrieving all the teams participating in the competition, together with the ghost
+///    teams entered into it. Games against a ghost team are unrated.
+/// 3. Compiling the bots each team had active at the round's submission cutoff, except teams
+///    that missed the deadline to replace a bot breaking the competition rules.
+/// 4. Creating match pairs for the round and checking there is enough free disk to play them.
+/// 5. Running each match in parallel, repeated matches of the same teams in a single run of
+///    the game if it can play several games per run.
+/// 6. Storing the round's games, the teams' new ratings and the incremented competition round
+///    in a single transaction, so a failure can't leave a partially recorded round.
+/// 7. Recording which teams participated in the round and rolling up the round's statistics.
+/// 8. Cleaning up the match directory after all games have been executed.
+/// 9. Applying the competition's replay retention policy to replays of older rounds.
+/// 10. Recording the finished round in the round event log and running the post-round hooks.
 ///
 /// # Arguments
 ///
 /// * `competition_id` - A string representing the ID of the competition for which the round is to be run.
+/// * `trace` - Trace the round's steps, matches, games and events are recorded under.
+/// * `job` - The job running the round. Its progress is reported to the job, and the round is
+///           scheduled for the full hour the job was queued in; the submission cutoff is taken
+///           from it. A round that was started before keeps its first scheduled start.
 ///
 /// # Returns
 ///
@@ -48,84 +82,503 @@ use super::{command_executor::{execute_command, recursive_copy}, elo::calc_elo_c
 /// - The teams for the specified competition cannot be retrieved.
 /// - There's an issue compiling the bots for any team.
 /// - There's an error running any of the matches.
+/// - There isn't enough free disk for the round (`MatchMakerError::InsufficientDisk`).
 /// - The cleanup process fails.
-/// - There's a problem updating the competition's round in the database.
+/// - The round's games, ratings or round number can't be stored.
 ///
-pub fn run_2v2_round(competition_id: String) -> Result<(), MatchMakerError> {
-    println!("Running 2v2 competition: {}", competition_id);
+pub async fn run_2v2_round(competition_id: String, trace: &TraceContext, job: &Job) -> Result<(), MatchMakerError> {
+    if is_safe_mode() {
+        return Err(MatchMakerError::ExecutionDisabled);
+    }
+    let span = trace.span(&competition_id, "ROUND");
+    // everything logged while the round runs carries its competition, round and trace
+    let log_span = info_span!("round", competition_id = %competition_id, round = field::Empty, trace_id = %trace.trace_id);
+    let mut claimed_round = None;
+    let result = execute_2v2_round(competition_id.clone(), trace, job, &mut claimed_round).instrument(log_span).await;
+    span.finish_with(&result);
+    // the round belongs to the run that is playing it
+    if let Err(MatchMakerError::RoundAlreadyRunning(round)) = &result {
+        warn!("Round {} of competition {} is already running or was played, not running it again", round, competition_id);
+        return result;
+    }
+    // only the round this run claimed is ended, it failed before claiming one otherwise
+    if let (Err(e), Some(round)) = (&result, claimed_round) {
+        let status = match e {
+            MatchMakerError::ShuttingDown => RoundStatus::Interrupted,
+            _ => RoundStatus::Failed,
+        };
+        if let Err(e) = end_round(competition_id.clone(), round, status) {
+            error!("Failed recording the end of the round: {:?}", e);
+        }
+    }
+    match &result {
+        Ok(_) => (),
+        Err(MatchMakerError::ShuttingDown) => notify_webhooks(&competition_id, WebhookEvent::RoundFinished, json!({
+            "status": "INTERRUPTED",
+            "trace_id": trace.trace_id,
+        })),
+        Err(e) => {
+            record_infra_failure();
+            notify_webhooks(&competition_id, WebhookEvent::RoundFinished, json!({
+                "status": "FAILED",
+                "error": e.to_string(),
+                "trace_id": trace.trace_id,
+            }));
+        },
+    }
+    result
+}
+
+/// Plays the round, `claimed_round` is set once the round is claimed for this run.
+async fn execute_2v2_round(competition_id: String, trace: &TraceContext, job: &Job, claimed_round: &mut Option<i32>) -> Result<(), MatchMakerError> {
+    info!("Running 2v2 competition");
+    // pause or throttle unranked workloads while the round runs
+    let _ranked_round = begin_ranked_round();
+    let _round_signal = begin_round_signal();
     let competition = match get_competition_by_id(competition_id) {
         Ok(c) => c,
         Err(e) => return Err(MatchMakerError::DatabaseError(e))
     };
 
-    let teams = match get_teams_by_competition_id(competition.id.clone()) {
+    Span::current().record("round", competition.round);
+
+    // a round that is started again keeps its seed and scheduled start, one that is already
+    // running isn't
+    let (seed, scheduled_at) = match start_round(NewRound {
+        competition_id: competition.id.clone(),
+        round: competition.round,
+        seed: rand::random(),
+        scheduled_at: scheduled_round_start(job.created),
+    }) {
+        Ok(Some(s)) => s,
+        Ok(None) => return Err(MatchMakerError::RoundAlreadyRunning(competition.round)),
+        Err(e) => return Err(MatchMakerError::DatabaseError(e))
+    };
+    *claimed_round = Some(competition.round);
+
+    let teams = match get_active_teams_by_competition_id(competition.id.clone()) {
         Ok(teams) => teams,
         Err(e) => return Err(MatchMakerError::DatabaseError(e))
     };
 
-    let compiled_teams = compile_team_bots(teams);
-    let match_pairs = create_match_pairs(competition.games_per_round, compiled_teams);
+    // archived champions are paired like any team, but they are never rated or ranked
+    let ghost_teams = match get_ghost_teams_by_competition_id(competition.id.clone()) {
+        Ok(ghosts) => ghosts.iter().map(GhostTeam::as_team).collect::<Vec<Team>>(),
+        Err(e) => return Err(MatchMakerError::DatabaseError(e))
+    };
+    let ghost_team_ids: HashSet<String> = ghost_teams.iter().map(|t| t.id.clone()).collect();
+
+    // teams that didn't replace a bot breaking the rules in time sit out the round
+    let eligible_teams = exclude_expired_violations(&competition.id, teams.clone());
+    let eligible_team_ids: Vec<String> = eligible_teams.iter().map(|t| t.id.clone()).collect();
+
+    // bots changed after the submission cutoff only play from the next round on
+    let cutoff = submission_cutoff(&competition, scheduled_at);
+    let eligible_teams = match get_bot_versions_by_team_ids(eligible_team_ids.clone()) {
+        Ok(versions) => eligible_teams
+            .into_iter()
+            .map(|t| bots_at_cutoff(t, &versions, cutoff))
+            .collect::<Vec<Team>>(),
+        Err(e) => return Err(MatchMakerError::DatabaseError(e))
+    };
 
-    
-    // Get the number of available logical cores
-    let num_cores = num_cpus::get();
+    let span = trace.span(&competition.id, "COMPILE");
+    // javac is run synchronously, keep it off the async workers
+    let round_span = Span::current();
+    let playing_teams = eligible_teams.into_iter().chain(ghost_teams).collect::<Vec<Team>>();
+    let mut compiled_teams = tokio::task::spawn_blocking(move || round_span.in_scope(|| compile_team_bots(playing_teams)))
+        .await
+        .map_err(|e| MatchMakerError::IOError(io::Error::other(e)))?;
+    // pairing depends on the order of the teams, keep it the same for the same teams
+    compiled_teams.sort_by(|a, b| a.id.cmp(&b.id));
+    let compiled_team_ids: Vec<String> = compiled_teams.iter().map(|t| t.id.clone()).collect();
+    let ranked_team_ids: Vec<String> = compiled_team_ids
+        .iter()
+        .filter(|id| !ghost_team_ids.contains(*id))
+        .cloned()
+        .collect();
+    span.finish("OK", format!(
+        "{} of {} teams compiled, {} ghost teams",
+        ranked_team_ids.len(),
+        teams.len(),
+        compiled_team_ids.len() - ranked_team_ids.len(),
+    ));
+    // a round interrupted by a shutdown continues where it stopped
+    let checkpoints = match get_round_checkpoints(competition.id.clone(), competition.round) {
+        Ok(c) => c,
+        Err(e) => return Err(MatchMakerError::DatabaseError(e))
+    };
+    let (mut pending_games, match_pairs) = if checkpoints.is_empty() {
+        let color_balance = color_balance(&competition.id);
+        let costs = pairing_costs(&competition, &compiled_teams).unwrap_or_else(|e| {
+            error!("Failed loading pairing constraints: {:?}", e);
+            PairingCosts::default()
+        });
+        let mut rng = ChaCha8Rng::seed_from_u64(seed as u64);
+        let (pairs, bye_team_ids) = create_match_pairs(competition.games_per_round, &compiled_team_ids, color_balance.clone(), costs.clone(), &mut rng);
+        let metadata = PairingMetadata {
+            games_per_round: competition.games_per_round,
+            team_ids: compiled_team_ids.clone(),
+            color_balance,
+            costs,
+            pairs: pairs.clone(),
+            byes: bye_team_ids.clone(),
+        };
+        if let Err(e) = set_round_pairing(competition.id.clone(), competition.round, &metadata) {
+            return Err(MatchMakerError::DatabaseError(e));
+        }
 
-    // Calculate the number of threads to use (one less than the number of cores)
-    let num_threads = num_cores - 1;
+        // teams sitting out the round are recorded, so standings count the round for them too
+        let forfeits = eligible_team_ids.iter().filter(|id| !compiled_team_ids.contains(id));
+        let byes = bye_team_ids
+            .iter()
+            .filter(|id| !ghost_team_ids.contains(*id))
+            .map(|id| (id, ByeKind::Bye))
+            .chain(forfeits.map(|id| (id, ByeKind::Forfeit)))
+            .map(|(team_id, kind)| NewRoundBye {
+                competition_id: competition.id.clone(),
+                round: competition.round,
+                team_id: team_id.clone(),
+                kind,
+            })
+            .collect::<Vec<NewRoundBye>>();
+        if let Err(e) = replace_round_byes(competition.id.clone(), competition.round, byes) {
+            error!("Failed recording byes: {:?}", e);
+        }
+        let teams_by_id = compiled_teams
+            .into_iter()
+            .map(|t| (t.id.clone(), t))
+            .collect::<HashMap<String, Team>>();
+        let match_pairs = pairs
+            .iter()
+            .map(|(team1_id, team2_id)| (teams_by_id[team1_id].clone(), teams_by_id[team2_id].clone()))
+            .collect::<Vec<(Team, Team)>>();
+        (Vec::new(), match_pairs)
+    } else {
+        info!("Resuming round {} from {} checkpointed matches", competition.round, checkpoints.len());
+        resume_match_pairs(checkpoints, compiled_teams)
+    };
+    // a round that can't fit on the disk is refused up front instead of failing halfway
+    check_round_capacity(&competition, &match_pairs, &trace.trace_id)?;
+    let leniency = leniency_for_round(&competition);
+    let adapter: Arc<dyn GameAdapter> = Arc::from(adapter_for_competition(&competition)?);
+    info!("Playing {} games, output {}", adapter.name(), adapter.output_version());
+    notify_webhooks(&competition.id, WebhookEvent::RoundStarted, json!({
+        "round": competition.round,
+        "matches": pending_games.len() + match_pairs.len(),
+        "teams": ranked_team_ids.len(),
+        "trace_id": trace.trace_id,
+    }));
+
+    // the game slots are shared with the rounds of other competitions
+    let competition = Arc::new(competition);
+    let leniency = Arc::new(leniency);
+
+    let match_count = pending_games.len() + match_pairs.len();
+    // identical matches are played in a single run of the game if it can play several, finding
+    // out may start the game
+    let batch_adapter = adapter.clone();
+    let max_batch_size = tokio::task::spawn_blocking(move || batch_adapter.max_batch_size()).await.unwrap_or(1);
+    let match_batches = batch_match_pairs(match_pairs, max_batch_size);
+    let mut matches = JoinSet::new();
+    for (team1, team2, games) in match_batches.into_iter() {
+        let competition = competition.clone();
+        let adapter = adapter.clone();
+        let leniency = leniency.clone();
+        let trace = trace.clone();
+        let unrated = ghost_team_ids.contains(&team1.id) || ghost_team_ids.contains(&team2.id);
+        let log_span = info_span!("match", team1_id = %team1.id, team2_id = %team2.id, game_id = field::Empty);
+        matches.spawn(async move {
+            if games == 1 {
+                return vec![play_match(&competition, adapter.as_ref(), &leniency, &trace, &team1, &team2, unrated).await];
+            }
+            let match_games = (0..games)
+                .map(|_| new_match_game(&competition, &trace, &team1, &team2, unrated))
+                .collect::<Vec<NewGame2v2>>();
+            play_match_batch(&competition, adapter.as_ref(), &leniency, &trace, &team1, &team2, match_games).await
+        }.instrument(log_span));
+    }
 
-    // Create a custom thread pool with a specified number of threads
-    let pool = rayon::ThreadPoolBuilder::new()
-        .num_threads(num_threads)
-        .build()
-        .unwrap();
+    let mut interrupted: Vec<(String, String)> = Vec::new();
+    let mut finished_matches = pending_games.len();
+    while let Some(result) = matches.join_next().await {
+        let outcomes = match result {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                finished_matches += 1;
+                report_round_progress(&job.id, finished_matches, match_count).await;
+                record_infra_failure();
+                error!("Match task failed: {}", e);
+                continue;
+            },
+        };
+        for outcome in outcomes.into_iter() {
+            finished_matches += 1;
+            match outcome {
+                MatchOutcome::Played(g) => pending_games.push(*g),
+                MatchOutcome::Interrupted(team1_id, team2_id) => interrupted.push((team1_id, team2_id)),
+                MatchOutcome::Failed => (),
+            }
+        }
+        report_round_progress(&job.id, finished_matches, match_count).await;
+    }
 
-    // Create a thread-safe vector using Arc and Mutex
-    let games: Arc<Mutex<Vec<Game2v2>>> = Arc::new(Mutex::new(Vec::new()));
+    if !interrupted.is_empty() || is_shutting_down() {
+        save_round_checkpoint(&competition, pending_games, interrupted)?;
+        return Err(MatchMakerError::ShuttingDown);
+    }
 
+    // storing the round and the work after it is blocking, keep it off the async workers
+    let round_span = Span::current();
+    let trace = trace.clone();
+    let eligible_teams = eligible_team_ids.len();
+    tokio::task::spawn_blocking(move || round_span.in_scope(|| {
+        finish_round(&competition, &trace, &teams, &ranked_team_ids, eligible_teams, pending_games, match_count)
+    }))
+        .await
+        .map_err(|e| MatchMakerError::IOError(io::Error::other(e)))?
+}
 
-    // Execute the parallel operation with the custom thread pool
-    pool.install(|| {
-        match_pairs.par_iter().for_each(|match_pair| {
-            match run_match(&competition, &match_pair.0, &match_pair.1) {
-                Ok(g) => {
-                    let mut games_lock = games.lock().unwrap();
-                    games_lock.push(g)
-                },
-                Err(e) => eprintln!("Error: {}", e),
-            }
-        });
-    });
-    
-    // Attempt to take ownership of the Mutex
-    let games_mutex = Arc::try_unwrap(games)
-        .expect("Arc::try_unwrap failed, there are multiple owners of the Arc");
+/// Stores a round whose matches are all played: its games and the new ratings, then the
+/// statistics, achievements and standings, followed by the cleanup, the replay retention,
+/// the round event, the hooks and the notifications. Blocking, run it off the async workers.
+fn finish_round(competition: &Competition, trace: &TraceContext, teams: &[Team], ranked_team_ids: &[String], eligible_teams: usize, pending_games: Vec<PendingGame2v2>, match_count: usize) -> Result<(), MatchMakerError> {
+    let games_vec = pending_games
+        .iter()
+        .map(|p| Game2v2::from(p.game.clone()))
+        .collect::<Vec<Game2v2>>();
+    let games_played = games_vec.len();
 
-    // Lock the Mutex to access the vector
-    let games_vec = games_mutex.into_inner()
-        .expect("Mutex::into_inner failed, the mutex is poisoned");
+    // store the games, the rating changes and the next round number all at once
+    let span = trace.span(&competition.id, "ELO");
+    let new_round = competition.round + 1;
+    let elo_result = calc_round_ratings(&games_vec)
+        .and_then(|ratings| complete_round(competition.id.clone(), new_round, match_count - games_played, pending_games, ratings.teams, ratings.history));
+    span.finish_with(&elo_result);
+    if let Err(e) = elo_result {
+        return Err(MatchMakerError::DatabaseError(e))
+    };
+
+    if let Err(e) = record_round_participation(competition, teams, ranked_team_ids, &games_vec) {
+        error!("Failed recording participation: {:?}", e);
+    }
+
+    let span = trace.span(&competition.id, "STATS");
+    let stats_result = record_round_stats(competition, &games_vec, match_count - games_played, eligible_teams - ranked_team_ids.len());
+    span.finish_with(&stats_result);
+    if let Err(e) = stats_result {
+        error!("Failed recording round stats: {:?}", e);
+    }
+
+    let span = trace.span(&competition.id, "ACHIEVEMENTS");
+    let achievements_result = award_round_achievements(competition, teams, &games_vec);
+    span.finish_with(&achievements_result);
+    if let Err(e) = achievements_result {
+        error!("Failed awarding achievements: {:?}", e);
+    }
+
+    let span = trace.span(&competition.id, "STANDINGS");
+    let standings_result = snapshot_standings(competition);
+    span.finish_with(&standings_result);
+    if let Err(e) = standings_result {
+        error!("Failed storing the standings: {:?}", e);
+    }
 
-    if let Err(e) = update_team_elo(games_vec) {
-        return Err(MatchMakerError::DatabaseError(e.into()))
-    }; 
-    
     // Cleanup: Remove the match directory
     cleanup_matches()?;
+
+    // compress or delete replays of older rounds
+    let span = trace.span(&competition.id, "RETENTION");
+    let retention_result = apply_replay_retention(competition);
+    span.finish_with(&retention_result);
+    if let Err(e) = retention_result {
+        error!("Failed applying replay retention: {:?}", e);
+    }
     
-    // increment competition round
-    let new_round = competition.round + 1;
-    if let Err(e) = set_competition_round(competition.id.clone(), new_round) {
-        return Err(MatchMakerError::DatabaseError(e))
-    }  
-    println!("Competition done!");
+    if let Err(e) = insert_round_event(NewRoundEvent {
+        competition_id: competition.id.clone(),
+        round: competition.round,
+        kind: "ROUND_FINISHED".to_string(),
+        status: "OK".to_string(),
+        message: format!("{} games played", games_played),
+        trace_id: trace.trace_id.clone(),
+    }) {
+        error!("Failed recording round event: {:?}", e);
+    }
+    notify_webhooks(&competition.id, WebhookEvent::RoundFinished, json!({
+        "status": "OK",
+        "round": competition.round,
+        "games_played": games_played,
+        "trace_id": trace.trace_id,
+    }));
+
+    let span = trace.span(&competition.id, "HOOKS");
+    let hooks_result = run_post_round_hooks(competition, trace);
+    span.finish_with(&hooks_result);
+    if let Err(e) = hooks_result {
+        error!("Failed running post-round hooks: {:?}", e);
+    }
+
+    let span = trace.span(&competition.id, "DISCORD");
+    let discord_result = post_round_summary(competition, &games_vec);
+    span.finish_with(&discord_result);
+    if let Err(e) = discord_result {
+        error!("Failed posting the round summary: {:?}", e);
+    }
+    info!(games_played, "Competition done!");
     Ok(())
 }
 
+/// How a single match of a round ended.
+enum MatchOutcome {
+    Played(Box<PendingGame2v2>),
+    Failed,
+    /// stopped by a shutdown, the match is played again when the round is resumed
+    Interrupted(String, String),
+}
+
+/// Plays a single match of a round and reports how it ended.
+async fn play_match(competition: &Competition, adapter: &dyn GameAdapter, leniency: &GameLeniency, trace: &TraceContext, team1: &Team, team2: &Team, unrated: bool) -> MatchOutcome {
+    let span = trace.span(&competition.id, "MATCH");
+    match run_match(competition, adapter, leniency, trace, team1, team2, unrated).await {
+        Ok(g) => {
+            announce_game(competition, &g);
+            span.finish("OK", format!("game {}: {} vs {}", g.game.id, g.game.team1_id, g.game.team2_id));
+            MatchOutcome::Played(Box::new(g))
+        },
+        Err(MatchMakerError::ShuttingDown) => {
+            span.finish("INTERRUPTED", format!("{} vs {}: server is shutting down", team1.id, team2.id));
+            MatchOutcome::Interrupted(team1.id.clone(), team2.id.clone())
+        },
+        Err(e) => {
+            record_infra_failure();
+            span.finish("FAILED", format!("{} vs {}: {}", team1.id, team2.id, e));
+            error!(error = %e, "Match failed");
+            MatchOutcome::Failed
+        },
+    }
+}
+
+/// Plays several matches of the same teams in a single run of the game. If the run crashes,
+/// fails because of the host or its output can't be split into the games, the matches are
+/// played one by one instead, so failures are retried and blamed like in any other match.
+async fn play_match_batch(competition: &Competition, adapter: &dyn GameAdapter, leniency: &GameLeniency, trace: &TraceContext, team1: &Team, team2: &Team, match_games: Vec<NewGame2v2>) -> Vec<MatchOutcome> {
+    let span = trace.span(&competition.id, "MATCH_BATCH");
+    let games = match_games.len();
+    let unrated = match_games.iter().any(|g| g.unrated);
+    match run_match_batch(competition, adapter, leniency, team1, team2, match_games).await {
+        Ok(Some(played)) => {
+            for g in played.iter() {
+                announce_game(competition, g);
+            }
+            span.finish("OK", format!("{} games: {} vs {}", played.len(), team1.id, team2.id));
+            played.into_iter().map(|g| MatchOutcome::Played(Box::new(g))).collect()
+        },
+        Ok(None) => {
+            span.finish("SPLIT", format!("{} vs {}: batch crashed, playing {} games one by one", team1.id, team2.id, games));
+            let mut outcomes = Vec::with_capacity(games);
+            for _ in 0..games {
+                outcomes.push(play_match(competition, adapter, leniency, trace, team1, team2, unrated).await);
+            }
+            outcomes
+        },
+        Err(MatchMakerError::ShuttingDown) => {
+            span.finish("INTERRUPTED", format!("{} vs {}: server is shutting down", team1.id, team2.id));
+            (0..games).map(|_| MatchOutcome::Interrupted(team1.id.clone(), team2.id.clone())).collect()
+        },
+        Err(e) => {
+            record_infra_failure();
+            span.finish("FAILED", format!("{} games of {} vs {}: {}", games, team1.id, team2.id, e));
+            error!(error = %e, "Match batch failed");
+            (0..games).map(|_| MatchOutcome::Failed).collect()
+        },
+    }
+}
+
+/// Records a played game as a successful match and tells the webhooks about it, the game
+/// itself is stored together with the rest of the round.
+fn announce_game(competition: &Competition, g: &PendingGame2v2) {
+    record_match_success();
+    notify_webhooks(&competition.id, WebhookEvent::GameFinished, json!({
+        "round": g.game.round,
+        "game_id": g.game.id,
+        "team1_id": g.game.team1_id,
+        "team2_id": g.game.team2_id,
+        "winner_id": g.game.winner_id,
+        "team1_score": g.game.team1_score,
+        "team2_score": g.game.team2_score,
+    }));
+}
+
+/// Groups the matches of the same two teams, in the same colors, into batches of at most
+/// `max_batch_size` games. The batches keep the order in which their first match came.
+fn batch_match_pairs(match_pairs: Vec<(Team, Team)>, max_batch_size: usize) -> Vec<(Team, Team, usize)> {
+    let mut batches: Vec<(Team, Team, usize)> = Vec::new();
+    for (team1, team2) in match_pairs.into_iter() {
+        let open_batch = batches
+            .iter_mut()
+            .find(|(t1, t2, games)| t1.id == team1.id && t2.id == team2.id && *games < max_batch_size);
+        match open_batch {
+            Some((_, _, games)) => *games += 1,
+            None => batches.push((team1, team2, 1)),
+        }
+    }
+    batches
+}
+
+/// Saves the games a round already played and the matches it still has to play, so the round
+/// can be resumed after a restart instead of being played from the start.
+fn save_round_checkpoint(competition: &Competition, played: Vec<PendingGame2v2>, unplayed: Vec<(String, String)>) -> Result<(), MatchMakerError> {
+    let mut checkpoints = Vec::with_capacity(played.len() + unplayed.len());
+    for (team1_id, team2_id) in unplayed.into_iter() {
+        checkpoints.push(NewRoundCheckpoint {
+            competition_id: competition.id.clone(),
+            round: competition.round,
+            team1_id,
+            team2_id,
+            game: None,
+        });
+    }
+    for game in played.into_iter() {
+        checkpoints.push(NewRoundCheckpoint {
+            competition_id: competition.id.clone(),
+            round: competition.round,
+            team1_id: game.game.team1_id.clone(),
+            team2_id: game.game.team2_id.clone(),
+            game: Some(game),
+        });
+    }
+    info!("Saving {} matches of round {} for resumption", checkpoints.len(), competition.round);
+    save_round_checkpoints(competition.id.clone(), competition.round, checkpoints)
+        .map_err(MatchMakerError::DatabaseError)
+}
+
+/// Splits the checkpoints of an interrupted round into the games that were already played and
+/// the matches that still have to be played. Matches of teams that no longer compile are dropped.
+fn resume_match_pairs(checkpoints: Vec<RoundCheckpoint>, teams: Vec<Team>) -> (Vec<PendingGame2v2>, Vec<(Team, Team)>) {
+    let teams: HashMap<String, Team> = teams.into_iter().map(|t| (t.id.clone(), t)).collect();
+    let mut played = Vec::new();
+    let mut unplayed = Vec::new();
+    for checkpoint in checkpoints.into_iter() {
+        if let Some(game) = checkpoint.game {
+            played.push(game);
+            continue;
+        }
+        match (teams.get(&checkpoint.team1_id), teams.get(&checkpoint.team2_id)) {
+            (Some(team1), Some(team2)) => unplayed.push((team1.clone(), team2.clone())),
+            _ => warn!("Dropping checkpointed match {} vs {}: team is no longer playing", checkpoint.team1_id, checkpoint.team2_id),
+        }
+    }
+    (played, unplayed)
+}
+
 /// Cleans up the matches directory by removing all sub-directories.
 ///
 /// This function is designed to remove all game-related folders that were 
-/// created during individual matches within the `./resources/matches/` directory.
+/// created during individual matches within the matches directory (`MATCHES_DIR`).
 /// It ensures the top-level `matches` directory remains intact while all its
-/// sub-directories (representing individual matches) are deleted.
+/// sub-directories (representing individual matches) are deleted, including the ones
+/// a bot made read-only.
 ///
 /// # Returns
 ///
@@ -133,13 +586,13 @@ pub fn run_2v2_round(competition_id: String) -> Result<(), MatchMakerError> {
 /// if there's an error during the cleanup process.
 ///
 fn cleanup_matches() -> Result<(), MatchMakerError> {
-    // Cleanup: Remove all sub-directories within the ./resources/matches/ directory
-    let matches_path = Path::new("./resources/matches");
+    // Cleanup: Remove all sub-directories within the matches directory
+    let matches_path = matches_dir();
     if let Ok(entries) = fs::read_dir(matches_path) {
         for entry in entries {
             if let Ok(entry) = entry {
                 if entry.path().is_dir() {
-                    if let Err(e) = fs::remove_dir_all(entry.path()) {
+                    if let Err(e) = remove_workspace(&entry.path()) {
                         return Err(MatchMakerError::IOError(e));
                     }
                 }
@@ -147,60 +600,6 @@ fn cleanup_matches() -> Result<(), MatchMakerError> {
         }
     }
 
-    if let Err(e) = kill_java_player_processes() {
-        eprintln!("Failed killing java processes: {:?}", e);
-    }
-    Ok(())
-}
-
-
-/// Kill all processes running with the command "java Player."
-fn kill_java_player_processes() -> Result<(), std::io::Error> {
-    // Get a list of all processes with "java Player" in their command line
-    let ps_output = Command::new("ps")
-        .arg("ax")
-        .output()?;
-
-    // Convert the output to a string
-    let ps_output_str = String::from_utf8_lossy(&ps_output.stdout);
-
-    // Split the output into lines
-    let process_lines: Vec<&str> = ps_output_str.lines().collect();
-
-    // Iterate through the lines and find processes with "java Player"
-    for process_line in process_lines {
-        if process_line.contains("java Player") {
-            // Extract the process ID (PID)
-            let pid_str = process_line.split_whitespace().next().unwrap_or_default();
-
-            // Parse the PID as an integer
-            if let Ok(pid) = pid_str.parse::<i32>() {
-                // Kill the process using the "kill" command
-                let kill_result = Command::new("kill")
-                    .arg("-9") // Use SIGKILL to forcefully terminate the process
-                    .arg(pid.to_string())
-                    .output();
-
-                match kill_result {
-                    Ok(Output {
-                        status,
-                        stdout,
-                        stderr,
-                    }) => {
-                        if status.success() {
-                            println!("Killed process with PID {}: {:?}", pid, String::from_utf8_lossy(&stdout));
-                        } else {
-                            eprintln!("Failed to kill process with PID {}: {:?}", pid, String::from_utf8_lossy(&stderr));
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("Error killing process with PID {}: {:?}", pid, e);
-                    }
-                }
-            }
-        }
-    }
-
     Ok(())
 }
 
@@ -211,18 +610,24 @@ fn kill_java_player_processes() -> Result<(), std::io::Error> {
 /// The steps include:
 ///
 /// 1. Initializing a new 2v2 game instance based on the teams and competition details.
-/// 2. Creating a unique directory for the match within the `./resources/matches` folder.
-/// 3. Copying the bots of both teams to the match directory.
-/// 4. Running the game using the Evaluator JAR, ensuring the game and its spawned bot processes 
-///    are grouped together for easy management.
-/// 5. Saving the game's output to a file within the `./resources/games` folder.
-/// 6. Cleaning up by terminating any lingering processes related to the game to prevent zombies.
+/// 2. Handing the game to a remote match worker if there are any.
+/// 3. Otherwise waiting for a local game slot, creating a unique directory for the match within
+///    the matches directory and copying the bots of both teams to it.
+/// 4. Running the game as launched by the game's adapter, ensuring the game and its spawned bot processes 
+///    are grouped together for easy management. Timeout and crash retries come from the
+///    round's leniency settings, both are recorded on the game together with the duration
+///    of the final attempt.
+/// 5. Saving the game's output to a file within the games directory.
+/// 7. Cleaning up by terminating any lingering processes related to the game to prevent zombies.
 /// 7. Parsing the game output to produce a structured representation of the game results.
-/// 8. Cleaning up by removing the match directory created in step 2.
+/// 8. Cleaning up by removing the match directory created in step 3.
 ///
 /// # Arguments
 ///
 /// * `competition` - A reference to the competition in which the teams are participating.
+/// * `adapter` - The game's adapter, provides the launch arguments and reads the output.
+/// * `leniency` - Timeout and crash retries that apply to the competition's current round.
+/// * `trace` - Trace of the round, the game is recorded under it.
 /// * `team1` - The first team participating in the match.
 /// * `team2` - The second team participating in the match.
 ///
@@ -244,8 +649,128 @@ fn kill_java_player_processes() -> Result<(), std::io::Error> {
 /// - This function assumes that the necessary external tools and JAR files for game evaluation are
 ///   available and correctly configured.
 /// 
-fn run_match(competition: &Competition, team1: &Team, team2: &Team) -> Result<Game2v2, MatchMakerError> {
+async fn run_match(competition: &Competition, adapter: &dyn GameAdapter, leniency: &GameLeniency, trace: &TraceContext, team1: &Team, team2: &Team, unrated: bool) -> Result<PendingGame2v2, MatchMakerError> {
+    if is_shutting_down() {
+        return Err(MatchMakerError::ShuttingDown);
+    }
     // Initialize a new 2v2 game with details from the provided teams and competition
+    let mut match_game = new_match_game(competition, trace, team1, team2, unrated);
+    Span::current().record("game_id", match_game.id.as_str());
+
+    // create a round directory (if doesn't exist) to later store game replays
+    let output_dir = format!("{}/{}", settings().paths.games.display(), competition.round);
+    if let Err(e) = fs::create_dir_all(&output_dir) {
+        return Err(MatchMakerError::IOError(e));
+    }
+
+    let bots = vec![&team1.bot1, &team1.bot2, &team2.bot1, &team2.bot2];
+    if bots.len() != adapter.bot_count() {
+        return Err(MatchMakerError::BotCountMismatch(adapter.bot_count(), bots.len()));
+    }
+
+    // Execute the game using the Evaluator JAR and collect the paths of each bot, the bots are
+    // only copied into the match directory once the game has to be played locally
+    let match_folder = matches_dir().join(match_game.id.to_string());
+    let bot_paths: Vec<String> = bots
+        .iter()
+        .map(|bot_id| match_folder
+            .join(bot_id)
+            .to_string_lossy()
+            .to_string())
+        .collect();
+    let command_args = adapter.launch_args(bot_paths, match_game.map_seed);
+
+    
+    // Run the game, a crashed game is replayed as long as the round's leniency allows it. Games
+    // failing because of the host are replayed on their own budget, so no team loses to a
+    // host hiccup; only the last attempt is kept
+    let bot_ids = bots.iter().map(|b| b.as_str()).collect::<Vec<&str>>();
+    let mut attempts = 0;
+    let mut crash_retries = 0;
+    let mut infra_retries = 0;
+    let mut workspace_ready = false;
+    let (output, errors) = loop {
+        attempts += 1;
+        let started = Instant::now();
+        // lab machines running match workers take the game if there are any, only games
+        // played here need one of this machine's game slots
+        let remote_game = RemoteGame::new(competition, &match_game, bots.iter().map(|b| b.to_string()).collect(), leniency.timeout_secs);
+        let result = match dispatch_remote_game(remote_game).await {
+            Some(result) => Ok(result),
+            None => {
+                let _slot = acquire_match_slot(&competition.id, competition.priority).await;
+                if !workspace_ready {
+                    prepare_match_folder(&match_folder, &bots)?;
+                    workspace_ready = true;
+                }
+                execute_evaluator(&command_args, leniency.timeout_secs, competition.stall_timeout_secs, &match_folder).await
+            },
+        };
+        let (output, errors) = match result {
+            Err(e) if is_infrastructure_error(&e) && infra_retries < leniency.infra_retries => {
+                infra_retries += 1;
+                warn!("Game {} failed to run ({}), retrying (infrastructure retry {} of {})", match_game.id, e, infra_retries, leniency.infra_retries);
+                continue;
+            },
+            result => result?,
+        };
+        match_game.duration_ms = started.elapsed().as_millis() as i64;
+        // always at least 1 error line because of the first "..." row
+        if errors.len() <= 1 {
+            break (output, errors);
+        }
+        if is_infrastructure_failure(&errors, &bot_ids) && infra_retries < leniency.infra_retries {
+            infra_retries += 1;
+            warn!("Game {} failed because of the host, retrying (infrastructure retry {} of {})", match_game.id, infra_retries, leniency.infra_retries);
+            continue;
+        }
+        if crash_retries >= leniency.crash_retries {
+            break (output, errors);
+        }
+        crash_retries += 1;
+        warn!("Game {} crashed, retrying (attempt {} of {})", match_game.id, crash_retries + 1, leniency.crash_retries + 1);
+    };
+    match_game.timeout_secs = leniency.timeout_secs;
+    match_game.attempts = attempts;
+
+
+    // Save the game's output, falls back to offloading or dropping the replay when low on disk
+    let output_string = output.join("\n");
+    store_replay(competition, output_string, &mut match_game)?;
+
+    // Save any errors to a separate file, skipped in emergency mode to spare the disk
+    if !errors.concat().trim().eq("...") && !in_emergency_mode() {
+        let error_string = errors.join("\n");
+        let error_file = format!("{}/{}/{}_error.txt", settings().paths.games.display(), competition.round, match_game.id);
+        if let Err(e) = fs::write(&error_file, &error_string) {
+            // Log error output to help diagnose problems
+            error!("Error output from child process: {}", error_string);
+            return Err(MatchMakerError::IOError(e));
+        }
+        match_game.stderr_path = error_file;
+    }
+
+    // Keep what each bot printed apart, so a team only sees their own bots' messages
+    store_bot_prints(competition.round, &match_game, adapter, &output, &errors);
+
+
+    // Parse the game using the provided function and return the result
+    parse_game(output, errors, match_game, competition, adapter)
+}
+
+/// Creates the directory a game is played in locally and copies each bot from the work
+/// directory into it.
+fn prepare_match_folder(match_folder: &Path, bots: &[&String]) -> Result<(), MatchMakerError> {
+    fs::create_dir_all(match_folder).map_err(MatchMakerError::IOError)?;
+    for bot_id in bots {
+        let source = settings().paths.bots_workdir.join(bot_id);
+        recursive_copy(&source, &match_folder.join(bot_id)).map_err(MatchMakerError::IOError)?;
+    }
+    Ok(())
+}
+
+/// A new game of the round between two teams, recorded under the round's trace.
+fn new_match_game(competition: &Competition, trace: &TraceContext, team1: &Team, team2: &Team, unrated: bool) -> NewGame2v2 {
     let mut match_game = NewGame2v2::new(
         competition.id.clone(),
         competition.round,
@@ -256,131 +781,299 @@ fn run_match(competition: &Competition, team1: &Team, team2: &Team) -> Result<Ga
         team2.bot1.clone(),
         team2.bot2.clone(),
     );
+    match_game.trace_id = trace.trace_id.clone();
+    match_game.unrated = unrated;
+    match_game
+}
 
-    // Create a directory to store match-related files
-    let match_folder = Path::new("./resources/matches").join(match_game.id.to_string());
-    if let Err(e) = fs::create_dir_all(&match_folder) {
-        return Err(MatchMakerError::IOError(e));
+/// Plays games of the same two teams in a single run of the game, each on its own map, to
+/// spare the startup of a game process per game. The run gets the round's timeout for every
+/// game it plays and isn't retried.
+///
+/// # Returns
+///
+/// The played games, or `None` if the run crashed, couldn't be started because of the host or
+/// its output didn't hold every game; the games are then better played one by one with
+/// `run_match`.
+///
+async fn run_match_batch(competition: &Competition, adapter: &dyn GameAdapter, leniency: &GameLeniency, team1: &Team, team2: &Team, match_games: Vec<NewGame2v2>) -> Result<Option<Vec<PendingGame2v2>>, MatchMakerError> {
+    if is_shutting_down() {
+        return Err(MatchMakerError::ShuttingDown);
     }
+    let games = match_games.len();
+    // batches are always played on this machine
+    let _slot = acquire_match_slot(&competition.id, competition.priority).await;
 
-    // create a round directory (if doesn't exist) to later store game replays
-    let output_dir = format!("./resources/games/{}", competition.round);
-    if let Err(e) = fs::create_dir_all(&output_dir) {
-        return Err(MatchMakerError::IOError(e));
-    }
+    // the batch is played in the folder of its first game
+    let match_folder = matches_dir().join(match_games[0].id.to_string());
+    let output_dir = format!("{}/{}", settings().paths.games.display(), competition.round);
+    fs::create_dir_all(&output_dir).map_err(MatchMakerError::IOError)?;
 
-    // Copy each bot from the work directory to the match directory
     let bots = vec![&team1.bot1, &team1.bot2, &team2.bot1, &team2.bot2];
-    for bot_id in &bots {
-        let source = Path::new("./resources/workdir/bots").join(bot_id);
+    if bots.len() != adapter.bot_count() {
+        return Err(MatchMakerError::BotCountMismatch(adapter.bot_count(), bots.len()));
+    }
+    prepare_match_folder(&match_folder, &bots)?;
+    let bot_paths: Vec<String> = bots
+        .iter()
+        .map(|bot_id| match_folder.join(bot_id).to_string_lossy().to_string())
+        .collect();
+    let map_seeds = match_games.iter().map(|g| g.map_seed).collect::<Vec<i64>>();
+    let command_args = adapter.batch_launch_args(bot_paths, &map_seeds);
+
+    let started = Instant::now();
+    let timeout_secs = leniency.timeout_secs.saturating_mul(games as i32);
+    let (output, errors) = match execute_evaluator(&command_args, timeout_secs, competition.stall_timeout_secs, &match_folder).await {
+        Ok(result) => result,
+        // played one by one, the games get remote workers and infrastructure retries
+        Err(e) if is_infrastructure_error(&e) => {
+            warn!("Batch of {} games of {} vs {} failed to run: {}", games, team1.id, team2.id, e);
+            return Ok(None);
+        },
+        Err(e) => return Err(e),
+    };
+    // always at least 1 error line because of the first "..." row
+    if errors.len() > 1 {
+        warn!("Batch of {} games of {} vs {} crashed", games, team1.id, team2.id);
+        return Ok(None);
+    }
+    let outputs = match adapter.split_batch_output(&output, &map_seeds) {
+        Some(o) => o,
+        None => {
+            warn!("Batch of {} games of {} vs {} didn't write one game for each map", games, team1.id, team2.id);
+            return Ok(None);
+        },
+    };
+    let duration_ms = started.elapsed().as_millis() as i64 / games as i64;
+
+    let mut played = Vec::with_capacity(games);
+    for (mut match_game, game_output) in match_games.into_iter().zip(outputs.into_iter()) {
+        match_game.duration_ms = duration_ms;
+        match_game.timeout_secs = leniency.timeout_secs;
+        match_game.attempts = 1;
+        store_replay(competition, game_output.join("\n"), &mut match_game)?;
+        store_bot_prints(competition.round, &match_game, adapter, &game_output, &errors);
+        played.push(parse_game(game_output, errors.clone(), match_game, competition, adapter)?);
+    }
+    Ok(Some(played))
+}
+
+/// Plays a game that isn't stored, used by test matches and rematches.
+///
+/// The bots are copied into a temporary folder within the matches directory, which is
+/// removed afterwards. The game runs on the map of `match_game.map_seed` with the default
+/// timeout, nothing is inserted and ELO isn't touched. The caller is expected to hold an
+/// unranked workload slot.
+///
+/// # Arguments
+///
+/// * `adapter` - The game's adapter.
+/// * `match_game` - The game, its bot ids name the bots' folders and its results are filled in.
+/// * `sources` - Folder of each bot's compiled files, in slot order.
+///
+/// # Returns
+///
+/// The parsed output of the game together with the raw replay and the game's stderr.
+///
+pub fn play_unranked_game(adapter: &dyn GameAdapter, match_game: &mut NewGame2v2, sources: Vec<PathBuf>) -> Result<UnrankedGameOutput, MatchMakerError> {
+    play_unranked_game_within(adapter, match_game, sources, GameLeniency::default().timeout_secs, None)
+}
+
+/// Plays a game that isn't stored like `play_unranked_game`, but kills it after
+/// `timeout_secs` or, if `max_turns` is given, once it played that many turns. The output
+/// written until then is parsed as if the game timed out.
+pub fn play_unranked_game_within(adapter: &dyn GameAdapter, match_game: &mut NewGame2v2, sources: Vec<PathBuf>, timeout_secs: i32, max_turns: Option<usize>) -> Result<UnrankedGameOutput, MatchMakerError> {
+    if is_safe_mode() {
+        return Err(MatchMakerError::ExecutionDisabled);
+    }
+
+    let bot_ids = [
+        match_game.team1bot1_id.clone(),
+        match_game.team1bot2_id.clone(),
+        match_game.team2bot1_id.clone(),
+        match_game.team2bot2_id.clone(),
+    ];
+    if sources.len() != adapter.bot_count() {
+        return Err(MatchMakerError::BotCountMismatch(adapter.bot_count(), sources.len()));
+    }
+
+    let match_folder = matches_dir().join(format!("unranked-{}", match_game.id));
+    let mut bot_paths = vec![];
+    for (bot_id, source) in bot_ids.iter().zip(sources.iter()) {
         let destination = match_folder.join(bot_id);
-        
-        if let Err(e) = recursive_copy(&source, &destination) {
+        if let Err(e) = recursive_copy(source, &destination) {
+            let _ = remove_workspace(&match_folder);
             return Err(MatchMakerError::IOError(e));
         }
+        bot_paths.push(destination.to_string_lossy().to_string());
     }
 
-    // Execute the game using the Evaluator JAR and collect the paths of each bot
-    let mut bot_paths: Vec<String> = bots
-        .iter()
-        .map(|bot_id| match_folder
-            .join(bot_id)
-            .to_string_lossy()
-            .to_string())
-        .collect();
-    let output_file = format!("./resources/games/{}/{}.zip", competition.round, match_game.id.to_string());
-    let mut command_args = vec![
-        "-jar".to_string(),
-        "resources/gamefiles/Evaluator.jar".to_string(),
-        "--gui=false".to_string(),
-    ];
-    command_args.append(&mut bot_paths);
+    let started = Instant::now();
+    // unranked games are played from blocking workers, outside of the async runtime
+    let result = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(MatchMakerError::IOError)
+        .and_then(|runtime| runtime.block_on(
+            run_evaluator(&adapter.launch_args(bot_paths, match_game.map_seed), timeout_secs, 0, &match_folder, max_turns)
+        ));
+    match_game.duration_ms = started.elapsed().as_millis() as i64;
+    let _ = remove_workspace(&match_folder);
+    let (output, errors) = result?;
 
-    
-    // Spawn the child process
-    let mut child = Command::new("java")
-        .args(&command_args)
+    let parsed = evaluate_game_output(output.clone(), errors.clone(), match_game, adapter);
+    Ok(UnrankedGameOutput { parsed, replay: output, errors })
+}
+
+/// Runs the Evaluator JAR with the given arguments and collects its output.
+///
+/// The game is killed if it doesn't finish within `timeout_secs`, if it writes no output for
+/// `stall_timeout_secs` (`0` turns this off), or if `workspace`, the folder holding its bots,
+/// grows past `MATCH_DISK_QUOTA_MB`. A stalled game is scored like one that timed out.
+///
+/// # Returns
+///
+/// The lines the game wrote to stdout and stderr.
+///
+pub async fn execute_evaluator(command_args: &[String], timeout_secs: i32, stall_timeout_secs: i32, workspace: &Path) -> Result<(Vec<String>, Vec<String>), MatchMakerError> {
+    run_evaluator(command_args, timeout_secs, stall_timeout_secs, workspace, None).await
+}
+
+/// Runs the Evaluator like `execute_evaluator`, the game is stopped as well once it played
+/// `max_turns` turns if that is given.
+async fn run_evaluator(command_args: &[String], timeout_secs: i32, stall_timeout_secs: i32, workspace: &Path, max_turns: Option<usize>) -> Result<(Vec<String>, Vec<String>), MatchMakerError> {
+    if is_shutting_down() {
+        return Err(MatchMakerError::ShuttingDown);
+    }
+
+    // The game runs in its own process group, so the players it starts can be killed with it
+    let mut command = Command::new("java");
+    command
+        .args(command_args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
+        .process_group(0);
+    let mut child = tokio::process::Command::from(command)
+        .kill_on_drop(true)
         .spawn()
-        .map_err(|e| MatchMakerError::IOError(e))?;
+        .map_err(MatchMakerError::IOError)?;
+    let pgid = child.id().map(|id| id as i32).ok_or_else(|| MatchMakerError::GameProcessFailed)?;
+    let _game_process = track_game_process(pgid, workspace);
 
-    // Set up asynchronous reading of stdout and stderr
     let stdout = child.stdout.take().expect("Failed to take stdout");
     let stderr = child.stderr.take().expect("Failed to take stderr");
 
-    let stdout_reader = BufReader::new(stdout);
-    let stderr_reader = BufReader::new(stderr);
+    // Wait for the process to finish or timeout, a game filling its folder past the quota is
+    // killed right away and one that stopped writing output as soon as it stalls
+    let quota = match_disk_quota_bytes();
+    let last_output = Mutex::new(Instant::now());
+    let turns_played = Notify::new();
+    let wait_for_game = async {
+        let finished = tokio::select! {
+            status = timeout(Duration::from_secs(timeout_secs as u64), child.wait()) => status,
+            _ = turns_played.notified() => {
+                unsafe { libc::kill(-pgid, libc::SIGKILL); }
+                info!("Game played its {} turns, stopped with status: {:#?}", max_turns.unwrap_or_default(), child.wait().await);
+                return Ok(());
+            },
+            _ = stalls(&last_output, stall_timeout_secs) => {
+                unsafe { libc::kill(-pgid, libc::SIGKILL); }
+                warn!("Game wrote nothing for {}s, killed and exited with status: {:#?}", stall_timeout_secs, child.wait().await);
+                return Ok(());
+            },
+            quota = exceeds_disk_quota(workspace.to_path_buf(), quota) => {
+                // negative pid targets the whole process group
+                unsafe { libc::kill(-pgid, libc::SIGKILL); }
+                warn!("Game exceeded its disk quota, killed and exited with status: {:#?}", child.wait().await);
+                return Err(MatchMakerError::DiskQuotaExceeded(quota));
+            },
+        };
+        match finished {
+            Ok(status) => status.map(|_| ()).map_err(MatchMakerError::IOError),
+            Err(_) => {
+                unsafe { libc::kill(-pgid, libc::SIGKILL); }
+                warn!("Game timed out, killed and exited with status: {:#?}", child.wait().await);
+                Ok(())
+            },
+        }
+    };
 
-    // Spawn threads to handle stdout and stderr
-    let stdout_handle = thread::spawn(move || {
-        stdout_reader
-            .lines()
-            .filter_map(Result::ok)
-            .collect::<Vec<String>>()
-    });
+    // stdout and stderr are read while the game runs, so a full pipe can't stall it
+    let turn_limit = max_turns.map(|turns| (turns, &turns_played));
+    let (finished, output, errors) = tokio::join!(wait_for_game, read_lines(stdout, &last_output, turn_limit), read_lines(stderr, &last_output, None));
+    finished?;
 
-    let stderr_handle = thread::spawn(move || {
-        stderr_reader
-            .lines()
-            .filter_map(Result::ok)
-            .collect::<Vec<String>>()
-    });
-
-    // Wait for the process to finish or timeout
-    let timeout_result: Option<ExitStatus> = child.wait_timeout(Duration::from_secs(120)).map_err(|e| MatchMakerError::IOError(e))?;
-    // Initialize flags for success and timeout
-    // let mut timeout_occurred = false;
-    // let mut success = true;
-    // Check if the process has finished
-    if let None = timeout_result {
-        // Timeout occurred
-        // timeout_occurred = true;
-        // success = false;
-        // Attempt to kill the child process
-        let _ = child.kill();
-        let st = child.wait();
-        println!("Game timed out, killed and exited with status: {:#?}", st);
-    }
-
-    // Join the threads and collect the output
-    let output: Vec<String> = stdout_handle.join().expect("Failed to join stdout thread");
-    let errors: Vec<String> = stderr_handle.join().expect("Failed to join stderr thread");
-
-    // if timeout_occurred {
-    //     // Process did not finish in time
-    //     return Err(MatchMakerError::TimeoutError);
-    // } else if !success {
-    //     // Process finished but was not successful
-    //     return Err(MatchMakerError::GameProcessFailed);
-    // }
-
-
-    // Save the game's output to the specified file
-    let output_string = output.join("\n");
-    if let Err(e) = save_to_zip(output_string, &output_file) {
-        return Err(e);
-    } else {
-        match_game.log_file_path = output_file;
+    // a game cut short by the shutdown is played again when the round resumes
+    if killed_by_shutdown(pgid) {
+        return Err(MatchMakerError::ShuttingDown);
     }
+    Ok((output, errors))
+}
 
-    // Save any errors to a separate file
-    if !errors.concat().trim().eq("...") {
-        let error_string = errors.join("\n");
-        let error_file = format!("./resources/games/{}/{}_error.txt", competition.round, match_game.id.to_string());
-        if let Err(e) = fs::write(&error_file, &error_string) {
-            // Log error output to help diagnose problems
-            log::error!("Error output from child process: {}", error_string);
-            return Err(MatchMakerError::IOError(e));
+/// Resolves with the quota once the game's folder grows past it, never if there is no quota.
+async fn exceeds_disk_quota(workspace: PathBuf, quota: Option<u64>) -> u64 {
+    let quota = match quota {
+        Some(q) => q,
+        None => return std::future::pending().await,
+    };
+    loop {
+        tokio::time::sleep(Duration::from_secs(settings().timeouts.disk_quota_check_secs)).await;
+        let folder = workspace.clone();
+        let size = tokio::task::spawn_blocking(move || workspace_size(&folder)).await.unwrap_or(0);
+        if size > quota {
+            return quota;
         }
     }
+}
 
+/// Resolves once no output was written for `stall_timeout_secs`, never if it is `0`.
+async fn stalls(last_output: &Mutex<Instant>, stall_timeout_secs: i32) {
+    if stall_timeout_secs <= 0 {
+        return std::future::pending().await;
+    }
+    let stall_timeout = Duration::from_secs(stall_timeout_secs as u64);
+    loop {
+        let last = *last_output.lock().expect("Output progress lock was poisoned");
+        if last.elapsed() >= stall_timeout {
+            return;
+        }
+        tokio::time::sleep(stall_timeout.saturating_sub(last.elapsed())).await;
+    }
+}
 
-    // Parse the game using the provided function and return the result
-    parse_game(output, errors, match_game)
+/// Collects the lines of a child process' output stream until it is closed, `last_output` is
+/// set whenever a line comes in. Bytes that aren't valid UTF-8 are replaced, a bot printing
+/// binary garbage doesn't cut off the rest of the output. With a `turn_limit` the lines are
+/// read as a replay and the notify is woken once the given number of turns was played.
+async fn read_lines<R: AsyncRead + Unpin>(stream: R, last_output: &Mutex<Instant>, turn_limit: Option<(usize, &Notify)>) -> Vec<String> {
+    let mut reader = tokio::io::BufReader::new(stream);
+    let mut collected = vec![];
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer).await {
+            Ok(0) => break,
+            Ok(_) => (),
+            Err(e) => {
+                warn!("Failed reading game output: {}", e);
+                break;
+            }
+        }
+        *last_output.lock().expect("Output progress lock was poisoned") = Instant::now();
+        let line = String::from_utf8_lossy(&buffer);
+        collected.push(line.trim_end_matches(['\n', '\r']).to_string());
+        // a turn is complete once the planets of the next one come in
+        if let Some((max_turns, turns_played)) = turn_limit {
+            if line.starts_with("P ") && decode_frames(&collected).len() > max_turns {
+                turns_played.notify_one();
+            }
+        }
+    }
+    collected
 }
 
-/// Parses game output to determine match results and constructs a `Game2v2` object.
+/// Parses game output to determine match results and constructs the game that is stored at the
+/// end of the round.
 ///
 /// This function processes the output lines from a game match to extract relevant information
 /// such as which bots survived and the scores of each bot. Based on this information, it 
@@ -395,29 +1088,116 @@ fn run_match(competition: &Competition, team1: &Team, team2: &Team) -> Result<Ga
 /// * `lines` - A vector of strings representing the game's output lines.
 /// * `match_game` - A mutable `NewGame2v2` object that contains initial game details and will be 
 ///                  updated with the parsed results.
+/// * `competition` - The competition the game belongs to, provides the ELO K-factors.
+/// * `adapter` - The game's adapter, reads the output and determines the winner.
 ///
 /// # Returns
 ///
-/// A `Result` containing a `Game2v2` object if successful, or a `MatchMakerError` if there's an error.
+/// A `Result` containing the `PendingGame2v2` if successful, or a `MatchMakerError` if there's an
+/// error. The stats of each bot are stored in the `game_player_stats` table alongside the game.
+/// Output lines the adapter doesn't recognise are kept in
+/// `<games directory>/<round>/<game id>_unknown.txt`.
 ///
-fn parse_game(lines: Vec<String>, errors: Vec<String>, mut match_game: NewGame2v2) -> Result<Game2v2, MatchMakerError> {
-    if errors.len() > 1 { // always at least 1 because of first "..." row
-        parse_bugged_game(lines, errors, &mut match_game);
-    } else {
-        parse_healthy_game(lines, errors, &mut match_game);
+fn parse_game(lines: Vec<String>, errors: Vec<String>, mut match_game: NewGame2v2, competition: &Competition, adapter: &dyn GameAdapter) -> Result<PendingGame2v2, MatchMakerError> {
+    let output = evaluate_game_output(lines, errors, &mut match_game, adapter);
+
+    // ghost teams have no rating to change
+    if !match_game.unrated {
+        if let Err(e) = calc_elo_changes(&mut match_game, competition) {
+            return Err(MatchMakerError::DatabaseError(e.into()))
+        }
     }
     
+    let game = SqlGame2v2::from(match_game);
 
-    if let Err(e) = calc_elo_changes(&mut match_game) {
-        return Err(MatchMakerError::DatabaseError(e.into()))
+    if !output.unknown_lines.is_empty() {
+        store_unknown_lines(&game, &output.unknown_lines);
     }
-    
-    match insert_game(match_game) {
-        Ok(g) => Ok(g),
-        Err(e) => Err(MatchMakerError::DatabaseError(e)),
+
+    let player_stats = player_stats_records(&game, output.player_stats);
+    Ok(PendingGame2v2 { game, player_stats })
+}
+
+/// Keeps the output lines the parser didn't recognise next to the replay, so changes of the
+/// output format can be debugged. Skipped in emergency mode to spare the disk.
+fn store_unknown_lines(game: &SqlGame2v2, unknown_lines: &[String]) {
+    warn!("Game {} had {} unrecognised output lines ({} parser)", game.id, unknown_lines.len(), game.output_version);
+    if in_emergency_mode() {
+        return;
+    }
+    let unknown_file = format!("{}/{}/{}_unknown.txt", settings().paths.games.display(), game.round, game.id);
+    if let Err(e) = fs::write(&unknown_file, unknown_lines.join("\n")) {
+        error!("Failed storing unrecognised output of game {}: {:?}", game.id, e);
+    }
+}
+
+/// Where the lines a bot printed during a game are kept, next to the game's replay.
+pub fn bot_output_file(round: i32, game_id: &str, slot: &str) -> String {
+    format!("{}/{}/{}_{}.txt", settings().paths.games.display(), round, game_id, slot)
+}
+
+/// Stores the lines each bot of a game printed in a file per bot. Skipped in emergency mode
+/// to spare the disk.
+fn store_bot_prints(round: i32, match_game: &NewGame2v2, adapter: &dyn GameAdapter, output: &[String], errors: &[String]) {
+    if in_emergency_mode() {
+        return;
+    }
+    let bot_ids = [
+        match_game.team1bot1_id.as_str(),
+        match_game.team1bot2_id.as_str(),
+        match_game.team2bot1_id.as_str(),
+        match_game.team2bot2_id.as_str(),
+    ];
+    let lines = [output, errors].concat();
+    for (slot, prints) in adapter.split_bot_output(&lines, &bot_ids) {
+        let bot_file = bot_output_file(round, &match_game.id, &slot);
+        if let Err(e) = fs::write(&bot_file, prints.join("\n")) {
+            error!("Failed storing the output of {} in game {}: {:?}", slot, match_game.id, e);
+        }
     }
 }
 
+/// Fills in the winner, survivors and scores of a game from its output without storing
+/// anything. The output is read and the winner determined by the game's adapter, the
+/// adapter's output version is recorded on the game.
+///
+/// # Returns
+///
+/// The parsed output, its stats are empty if the game crashed.
+///
+pub fn evaluate_game_output(lines: Vec<String>, errors: Vec<String>, match_game: &mut NewGame2v2, adapter: &dyn GameAdapter) -> EvaluatorOutput {
+    match_game.output_version = adapter.output_version().to_string();
+    if errors.len() > 1 { // always at least 1 because of first "..." row
+        parse_bugged_game(lines, errors, match_game);
+        EvaluatorOutput::default()
+    } else {
+        parse_healthy_game(lines, errors, match_game, adapter)
+    }
+}
+
+/// Maps the parsed stats of each bot slot (`team1bot1`, ...) to the bot and team in that slot.
+fn player_stats_records(game: &SqlGame2v2, stats: HashMap<String, GamePlayerStats>) -> Vec<NewGamePlayerStats> {
+    stats
+        .into_iter()
+        .filter_map(|(slot, stats)| {
+            let (team_id, bot_id) = match slot.as_str() {
+                "team1bot1" => (&game.team1_id, &game.team1bot1_id),
+                "team1bot2" => (&game.team1_id, &game.team1bot2_id),
+                "team2bot1" => (&game.team2_id, &game.team2bot1_id),
+                "team2bot2" => (&game.team2_id, &game.team2bot2_id),
+                _ => return None,
+            };
+            Some(NewGamePlayerStats {
+                game_id: game.id.clone(),
+                team_id: team_id.clone(),
+                bot_id: bot_id.clone(),
+                slot,
+                stats,
+            })
+        })
+        .collect()
+}
+
 fn parse_bugged_game(_lines: Vec<String>, errors: Vec<String>, match_game: &mut NewGame2v2) -> () {
     // find bot id
     let bot_ids = [
@@ -468,6 +1248,7 @@ fn parse_bugged_game(_lines: Vec<String>, errors: Vec<String>, match_game: &mut
 
     // Remove backslashes from the formatted string
     let additional_data_error = GameError {
+        failure_kind: classify_failure(&errors, bugged_bot_id_option.is_some()),
         error: trimmed_lines,
         blame_id: bugged_bot_id_option.unwrap_or(&"Unknown".to_string()).to_string()
     };
@@ -475,90 +1256,19 @@ fn parse_bugged_game(_lines: Vec<String>, errors: Vec<String>, match_game: &mut
     match_game.additional_data = serde_json::to_string(&additional_data_error).unwrap_or(String::from("{ \"error\": \"Error serializing\"}"));
 }
 
-fn parse_healthy_game(lines: Vec<String>, _errors: Vec<String>, match_game: &mut NewGame2v2) -> () {
-    let mut r_green = 0;
-    let mut r_blue = 0;
-    let mut r_yellow = 0;
-    let mut r_cyan = 0;
-    let mut current_bot: Option<String> = None;
-    let mut last_L: Option<String> = None;
-    let mut stats: HashMap<String, GamePlayerStats> = HashMap::new();
-    let mut stats_keys = vec![
-        "team2bot2",
-        "team1bot2",
-        "team2bot1",
-        "team1bot1", 
+fn parse_healthy_game(lines: Vec<String>, _errors: Vec<String>, match_game: &mut NewGame2v2, adapter: &dyn GameAdapter) -> EvaluatorOutput {
+    let bot_ids = [
+        match_game.team1bot1_id.as_str(),
+        match_game.team1bot2_id.as_str(),
+        match_game.team2bot1_id.as_str(),
+        match_game.team2bot2_id.as_str(),
     ];
+    let output = adapter.parse_output(&lines, &bot_ids);
+    let stats = &output.player_stats;
 
-
-
-    for line in lines.into_iter() {
-        // track score through the game
-        // the last score is the final score of the game
-        // needed to determine the winner (if timeout still both teams are alive
-        // and the "survived"/"winnwe" stat at the end is not enough)
-        if line.contains("R ") {
-            let parts: Vec<&str> = line.split(" ").collect();
-            if parts.len() == 3 {
-                match parts[2] {
-                    "green"     => r_green = parts[1].parse().unwrap_or(0),
-                    "blue"      => r_blue = parts[1].parse().unwrap_or(0),
-                    "yellow"    => r_yellow = parts[1].parse().unwrap_or(0),
-                    "cyan"      => r_cyan = parts[1].parse().unwrap_or(0),
-                    _ => ()
-                }
-            }
-        }
-
-        if line.contains("L ") {
-            last_L = Some(line.to_owned());
-        }
-
-        if line.contains("STAT: ") {
-            // try to extract a bot name
-            // also init a stat object for the player (untill next player id there is going to 
-            // be a sequence of stats in form of <key>: <value> for this player)
-            let next_key_option = stats_keys.pop();
-            if let Some(next_key) = next_key_option {
-                current_bot = Some(next_key.to_string());
-                stats.insert(next_key.into(), GamePlayerStats::default());
-            }
-        }
-
-
-        let parts: Vec<&str> = line.split(" ").collect();
-        
-        // if collecting player stats
-        if let Some(bot_key) = &current_bot {
-            if parts.len() == 2 {
-                
-                let stat = match stats.get_mut(bot_key) {
-                    Some(s) => s,
-                    None => continue,
-                };
-                
-                match parts[0] {
-                    "turnsPlayed:"           => stat.turns_played             = parts[1].parse().unwrap_or(0),
-                    "survive:"               => stat.survived                 = parts[1].parse().unwrap_or(false),
-                    "fleetGenerated:"        => stat.fleet_generated          = parts[1].parse().unwrap_or(0),
-                    "fleetLost:"             => stat.fleet_lost               = parts[1].parse().unwrap_or(0),
-                    "fleetReinforced:"       => stat.fleet_reinforced         = parts[1].parse().unwrap_or(0),
-                    "largestAttack:"         => stat.largest_attack           = parts[1].parse().unwrap_or(0),
-                    "largestLoss:"           => stat.largest_loss             = parts[1].parse().unwrap_or(0),
-                    "largestReinforcement:"  => stat.largest_reinforcement    = parts[1].parse().unwrap_or(0),
-                    "planetsLost:"           => stat.planets_lost             = parts[1].parse().unwrap_or(0),
-                    "planetsConquered:"      => stat.planets_conquered        = parts[1].parse().unwrap_or(0),
-                    "planetsDefended:"       => stat.planets_defended         = parts[1].parse().unwrap_or(0),
-                    "planetsAttacked:"       => stat.planets_attacked         = parts[1].parse().unwrap_or(0),
-                    "numFleetLost:"          => stat.num_fleet_lost            = parts[1].parse().unwrap_or(0),
-                    "numFleetReinforced:"    => stat.num_fleet_reinforced      = parts[1].parse().unwrap_or(0),
-                    "numFleetGenerated:"     => stat.num_fleet_generated       = parts[1].parse().unwrap_or(0),
-                    "totalTroopsGenerated:"  => stat.total_troops_generated    = parts[1].parse().unwrap_or(0),
-                    _ => ()
-                }
-            }
-        }
-    }
+    // final score of each team, used for the winner on timeout and for score difference tiebreakers
+    match_game.team1_score = output.team1_score;
+    match_game.team2_score = output.team2_score;
 
     // check if bots survived
     match_game.team1bot1_survived = if let Some(stat) = stats.get("team1bot1") {
@@ -582,37 +1292,12 @@ fn parse_healthy_game(lines: Vec<String>, _errors: Vec<String>, match_game: &mut
         false
     };
 
-    match (
-        &match_game.team1bot1_survived,
-        &match_game.team1bot2_survived,
-        &match_game.team2bot1_survived,
-        &match_game.team2bot2_survived
-    ) {
-        (true,  true,  false, false) => match_game.winner_id = match_game.team1_id.clone(),
-        (true,  false, false, false) => match_game.winner_id = match_game.team1_id.clone(),
-        (false, true,  false, false) => match_game.winner_id = match_game.team1_id.clone(),
-        (false, false, true,  true)  => match_game.winner_id = match_game.team2_id.clone(),
-        (false, false, true,  false) => match_game.winner_id = match_game.team2_id.clone(),
-        (false, false, false, true)  => match_game.winner_id = match_game.team2_id.clone(),
-        (_, _, _, _) => match_game.winner_id = "".to_string(),
-    }
-
-    // if multiple teams alive at the end (timeout) check who won by score
-    if match_game.winner_id.eq("") {
-        let t1_score = r_yellow + r_green;
-        let t2_score = r_blue + r_cyan;
-        
-        if t1_score > t2_score {
-            match_game.winner_id = match_game.team1_id.clone();
-        } else {
-            match_game.winner_id = match_game.team2_id.clone();
-        }
-    }
-    if stats.is_empty() && last_L.is_some() {
-        parse_bugged_game(vec![], vec![last_L.unwrap()], match_game)
-    } else {
-        match_game.additional_data = serde_json::to_string(&stats).unwrap_or(String::from("{ \"error\": \"Error serializing\"}"));
+    adapter.determine_winner(match_game);
+
+    if let (true, Some(last_loss)) = (stats.is_empty(), &output.last_loss) {
+        parse_bugged_game(vec![], vec![last_loss.clone()], match_game)
     }
+    output
 }
 
 
@@ -637,8 +1322,10 @@ fn parse_healthy_game(lines: Vec<String>, _errors: Vec<String>, match_game: &mut
 /// This function uses parallel processing for improved performance. Each team's bots are compiled in a separate thread.
 ///
 pub fn compile_team_bots(teams: Vec<Team>) -> Vec<Team> {
-    // Parallel processing of each team to compile associated bots
+    // Parallel processing of each team to compile associated bots, logged within the caller's span
+    let parent_span = Span::current();
     let results: Vec<Team> = teams.into_par_iter().filter_map(|team| {
+        let _entered = parent_span.enter();
         // Skip teams without both bot1 and bot2
         if team.bot1.eq("") || team.bot2.eq("") {
             return None
@@ -658,7 +1345,8 @@ pub fn compile_team_bots(teams: Vec<Team>) -> Vec<Team> {
         
         // Attempt to compile bot1
         if let Err(e) = compile_bot(&bot1) {
-            if let Err(_) = set_bot_error(bot1, e.to_string()) {
+            warn!(team_id = %team.id, bot_id = %bot1.id, error = %e, "Bot failed to compile");
+            if let Err(_) = set_bot_error(bot1, e.to_string(), &CompileDiagnostics::from_error(&e)) {
                 // return Some(Err(MatchMakerError::DatabaseError(e)));
                 return None;
             }
@@ -668,7 +1356,8 @@ pub fn compile_team_bots(teams: Vec<Team>) -> Vec<Team> {
 
         // Attempt to compile bot2
         if let Err(e) = compile_bot(&bot2) {
-            if let Err(_) = set_bot_error(bot2, e.to_string()) {
+            warn!(team_id = %team.id, bot_id = %bot2.id, error = %e, "Bot failed to compile");
+            if let Err(_) = set_bot_error(bot2, e.to_string(), &CompileDiagnostics::from_error(&e)) {
                 // return Some(Err(MatchMakerError::DatabaseError(e)));
                 return None;
             }
@@ -713,9 +1402,10 @@ fn contains_main_method(file_path: &str) -> io::Result<bool> {
 /// This function performs the following tasks:
 /// 1. Creates a working directory specific to the bot.
 /// 2. Copies the bot's ZIP file to the working directory.
-/// 3. Unzips the bot's ZIP file.
+/// 3. Unzips the bot's ZIP file, unless it's unsafe to (see `archive_safety`).
 /// 4. Finds any Java files inside the unzipped directory.
-/// 5. Compiles the Java files using the `javac` command.
+/// 5. Scans the Java files for forbidden APIs.
+/// 6. Compiles the Java files using the `javac` command.
 ///
 /// # Arguments
 ///
@@ -723,20 +1413,29 @@ fn contains_main_method(file_path: &str) -> io::Result<bool> {
 ///
 /// # Returns
 ///
-/// * `Ok(())` if the bot's source code was compiled successfully.
+/// * `Ok(output)` with the compiler's output (e.g. warnings) if the bot's source code was
+///   compiled successfully.
 /// * `Err(MatchMakerError)` if any step in the process fails.
 ///
 /// # Errors
 ///
 /// This function will return an error if:
 /// * The working directory cannot be created.
-/// * The ZIP file cannot be copied or unzipped.
+/// * The ZIP file cannot be copied or unzipped, or is a zip bomb or contains entries pointing
+///   outside of the working directory (`UnsafeArchive`).
 /// * No Java files are found in the unzipped directory.
-/// * The Java files cannot be compiled.
+/// * The sources use a forbidden API (`ForbiddenApi`), see `static_scan`.
+/// * The Java files cannot be compiled (`CompileError` with javac's output) or javac takes
+///   longer than `timeouts.compile_secs` of the settings.
 /// 
-pub fn compile_bot(bot: &Bot) -> Result<(), MatchMakerError> {
-    let workdir = Path::new("./resources/workdir/bots").join(bot.id.clone());
-    let source_path = Path::new(&bot.source_path);
+pub fn compile_bot(bot: &Bot) -> Result<Vec<String>, MatchMakerError> {
+    if is_safe_mode() {
+        return Err(MatchMakerError::ExecutionDisabled);
+    }
+    let _span = info_span!("compile", bot_id = %bot.id, team_id = %bot.team_id).entered();
+    let workdir = settings().paths.bots_workdir.join(bot.id.clone());
+    let source_path = local_copy(&bot.source_path)?;
+    let source_path = Path::new(&source_path);
 
     // Create a dedicated working directory for the bot.
     if let Err(e) = fs::create_dir_all(&workdir) {
@@ -774,17 +1473,7 @@ pub fn compile_bot(bot: &Bot) -> Result<(), MatchMakerError> {
 
     // Unzip the bot's ZIP file in the working directory.
     let unzip_target = workdir.join(file_name_str);
-    let unzip_target_str = match unzip_target.as_os_str().to_str() {
-        Some(s) => s,
-        None => return Err(MatchMakerError::InvalidPath(unzip_target.into())),
-    };
-    
-    if let Err(e) = execute_command(
-        "unzip".to_string(), 
-        vec!["-o", unzip_target_str, "-d", workdir_str]
-    ) {
-        return Err(MatchMakerError::IOError(e));
-    }
+    extract_archive(&unzip_target, &workdir)?;
 
     // Retrieve a list of Java files from the unzipped directory.
     let java_files: Vec<String> = match fs::read_dir(&workdir) {
@@ -823,57 +1512,114 @@ pub fn compile_bot(bot: &Bot) -> Result<(), MatchMakerError> {
     }
 
 
-    // Compile the Java files.
-    if let Err(e) = execute_command(
-        "javac".to_string(),
-        java_files_str
-    ) {
-        return Err(MatchMakerError::IOError(e));
+    // Reject sources using forbidden APIs before anything of the bot is compiled
+    let violations = scan_files(&java_files, &forbidden_api_rules()).map_err(MatchMakerError::IOError)?;
+    if !violations.is_empty() {
+        return Err(MatchMakerError::ForbiddenApi(violations));
     }
 
-    Ok(())
+    // Compile the Java files, javac reports errors on stderr
+    let output = execute_command_with_timeout(
+        "javac".to_string(),
+        java_files_str,
+        vec![],
+        Duration::from_secs(settings().timeouts.compile_secs),
+    ).map_err(MatchMakerError::IOError)?;
+
+    let mut compiler_output = output.stdout;
+    compiler_output.extend(output.stderr);
+    match output.status {
+        Some(status) if status.success() => Ok(compiler_output),
+        Some(_) => Err(MatchMakerError::CompileError(compiler_output)),
+        None => Err(MatchMakerError::TimeoutError),
+    }
 }
 
-/// Creates match pairs for a set of teams.
+/// Creates match pairs for a set of teams. The same teams, in the same order, with the same
+/// color balance, costs and seed always give the same pairs.
+///
+/// The first team of a pair plays yellow/green, the second blue/cyan. Of the two teams, the
+/// one that played yellow/green less often so far is put first, so colors alternate across
+/// games.
+///
+/// The first team of each pair is drawn at random, its opponent at random from the remaining
+/// teams that are cheapest to pair it with under the competition's pairing constraints.
 ///
 /// # Arguments
 ///
 /// * `match_num` - The number of matches each team should play.
-/// * `teams` - A vector containing all the teams.
+/// * `team_ids` - Ids of all the teams.
+/// * `color_balance` - Per team, games played on yellow/green minus games on blue/cyan.
+/// * `costs` - What pairing two teams costs under the competition's pairing constraints.
+/// * `rng` - Random number generator seeded with the round's seed. ChaCha8 gives the same
+///           numbers for a seed in every version of `rand`, so stored pairings stay reproducible.
 ///
 /// # Returns
 ///
-/// A vector containing tuples, where each tuple represents a match between two teams.
-///
-/// # Panics
-///
-/// The function may panic if the random number generation fails.
+/// A vector containing tuples of team ids, where each tuple represents a match between two
+/// teams, and the ids of the teams that got a bye because they were left over without an
+/// opponent.
 /// 
-fn create_match_pairs(match_num: i32, teams: Vec<Team>) -> Vec<(Team, Team)> {
+pub fn create_match_pairs(match_num: i32, team_ids: &[String], mut color_balance: HashMap<String, i32>, mut costs: PairingCosts, rng: &mut ChaCha8Rng) -> (Vec<(String, String)>, Vec<String>) {
     let mut pairs = Vec::new();
-    let games_to_play = ((teams.len() as f32 * match_num as f32) / 2.).ceil() as i32;
+    let mut byes = Vec::new();
+    let games_to_play = ((team_ids.len() as f32 * match_num as f32) / 2.).ceil() as i32;
 
-    let mut players: Vec<usize> = std::iter::repeat(0..teams.len())
-        .take(match_num as usize)
+    let mut players: Vec<usize> = std::iter::repeat_n(0..team_ids.len(), match_num as usize)
         .flatten()
         .collect();
 
     while (pairs.len() as i32) < games_to_play {
-        let random_index = rand::thread_rng().gen_range(0..players.len());
+        let random_index = rng.gen_range(0..players.len());
         let first_team_index = players.swap_remove(random_index);
     
         if players.len() < 1 {
+            byes.push(team_ids[first_team_index].clone());
             break
         }
 
-        let random_index = rand::thread_rng().gen_range(0..players.len());
+        let player_costs = players
+            .iter()
+            .map(|&p| costs.cost(&team_ids[first_team_index], &team_ids[p]))
+            .collect::<Vec<i32>>();
+        let lowest_cost = player_costs.iter().copied().min().unwrap_or(0);
+        let candidates = (0..players.len())
+            .filter(|&i| player_costs[i] == lowest_cost)
+            .collect::<Vec<usize>>();
+        let random_index = candidates[rng.gen_range(0..candidates.len())];
         let second_team_index = players.swap_remove(random_index);
-    
-        pairs.push((
-            teams[first_team_index].clone(),
-            teams[second_team_index].clone(),
-        ));
+
+        // the team that played yellow/green less often so far takes the first slot
+        let (first, second) = (&team_ids[first_team_index], &team_ids[second_team_index]);
+        let first_balance = color_balance.get(first).copied().unwrap_or(0);
+        let second_balance = color_balance.get(second).copied().unwrap_or(0);
+        let (team1, team2) = if second_balance < first_balance { (second, first) } else { (first, second) };
+        *color_balance.entry(team1.clone()).or_insert(0) += 1;
+        *color_balance.entry(team2.clone()).or_insert(0) -= 1;
+        costs.record_pairing(team1, team2);
+
+        pairs.push((team1.clone(), team2.clone()));
     }
 
-    pairs
+    (pairs, byes)
+}
+
+/// Counts how many more games each team of the competition played on yellow/green than on
+/// blue/cyan, so `create_match_pairs` can even out any positional advantage.
+fn color_balance(competition_id: &str) -> HashMap<String, i32> {
+    let mut balance = HashMap::new();
+    let games = match get_games_by_competition_id(competition_id.to_string()) {
+        Ok(g) => g,
+        Err(e) => {
+            error!("Failed loading games for color rotation: {:?}", e);
+            return balance;
+        }
+    };
+    for game in games.iter().filter(|g| g.team1_id != g.team2_id) {
+        for (team_id, colors) in [(&game.team1_id, game.team1_colors), (&game.team2_id, game.team2_colors)] {
+            let change = if colors == ColorPair::YellowGreen { 1 } else { -1 };
+            *balance.entry(team_id.clone()).or_insert(0) += change;
+        }
+    }
+    balance
 }
\ No newline at end of file
@@ -4,4 +4,60 @@ pub mod matchmaker_2v2;
 pub mod command_executor;
 pub mod competitions;
 pub mod elo;
-pub mod file_handler;
\ No newline at end of file
+pub mod file_handler;
+pub mod replay_retention;
+pub mod round_hooks;
+pub mod participation;
+pub mod workload_gate;
+pub mod disk_emergency;
+pub mod leniency;
+pub mod leaderboard;
+pub mod trace;
+pub mod head_to_head;
+pub mod safe_mode;
+pub mod practice;
+pub mod round_stats;
+pub mod revalidation;
+pub mod evaluator_artifact;
+pub mod alert_signals;
+pub mod rematch;
+pub mod upload_validation;
+pub mod reference_match;
+pub mod bot_versions;
+pub mod submission_window;
+pub mod static_scan;
+pub mod plagiarism;
+pub mod team_changes;
+pub mod registration;
+pub mod seasons;
+pub mod competition_archive;
+pub mod job_queue;
+pub mod match_dispatch;
+pub mod match_worker_agent;
+pub mod shutdown;
+pub mod match_workspace;
+pub mod webhooks;
+pub mod discord;
+pub mod rate_limit;
+pub mod archive_safety;
+pub mod replay_frames;
+pub mod featured;
+pub mod api_keys;
+pub mod rating_recompute;
+pub mod game_override;
+pub mod pairing;
+pub mod crash_triage;
+pub mod match_scheduler;
+pub mod ladder;
+pub mod practice_queue;
+pub mod challenges;
+pub mod achievements;
+pub mod grades;
+pub mod storage;
+pub mod search;
+pub mod user_data;
+pub mod bot_metadata;
+pub mod smoke_test;
+pub mod turn_times;
+pub mod jvm_warmup;
+pub mod disk_capacity;
\ No newline at end of file
@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel::result::Error;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    db::{
+        operations_game2v2::get_games_by_competition_id,
+        operations_pairing_constraints::get_pairing_constraints_by_competition_id,
+        operations_rounds::get_round,
+        operations_users::get_users_by_ids,
+    },
+    models::{
+        competition::Competition,
+        pairing_constraints::{pair_key, PairingCosts},
+        round::{PublicRound, RoundPairingAudit},
+        team::Team,
+    },
+};
+
+use super::matchmaker_2v2::create_match_pairs;
+
+/// Loads what the competition's pairing constraints need to know about its teams: the games
+/// they played in the rematch window and the lab groups of their members.
+pub fn pairing_costs(competition: &Competition, teams: &[Team]) -> Result<PairingCosts, Error> {
+    let constraints = match get_pairing_constraints_by_competition_id(competition.id.clone())? {
+        Some(c) => c,
+        None => return Ok(PairingCosts::default()),
+    };
+
+    let mut recent_games = HashMap::new();
+    if constraints.rematch_weight > 0 && constraints.rematch_window > 0 {
+        let first_round = competition.round - constraints.rematch_window;
+        let games = get_games_by_competition_id(competition.id.clone())?;
+        for game in games.iter().filter(|g| g.round >= first_round && g.team1_id != g.team2_id) {
+            *recent_games.entry(pair_key(&game.team1_id, &game.team2_id)).or_insert(0) += 1;
+        }
+    }
+
+    let mut lab_groups = HashMap::new();
+    if constraints.lab_group_weight > 0 {
+        let member_ids = teams
+            .iter()
+            .flat_map(|t| [t.owner.clone(), t.partner.clone()])
+            .filter(|id| !id.is_empty())
+            .collect::<Vec<String>>();
+        let groups = get_users_by_ids(member_ids)?
+            .into_iter()
+            .filter(|u| !u.lab_group.is_empty())
+            .map(|u| (u.id, u.lab_group))
+            .collect::<HashMap<String, String>>();
+        for team in teams.iter() {
+            let team_groups = [&team.owner, &team.partner]
+                .into_iter()
+                .filter_map(|member| groups.get(member).cloned())
+                .collect::<HashSet<String>>();
+            lab_groups.insert(team.id.clone(), team_groups);
+        }
+    }
+
+    Ok(PairingCosts {
+        // without a window there are no rematches to avoid
+        rematch_weight: if constraints.rematch_window > 0 { constraints.rematch_weight } else { 0 },
+        lab_group_weight: constraints.lab_group_weight,
+        recent_games,
+        lab_groups,
+    })
+}
+
+/// Pairs a round again from its stored seed and metadata and compares the result to the
+/// pairing the round was played with, `None` if the round's pairing wasn't stored.
+pub fn audit_round_pairing(competition_id: String, round: i32) -> Result<Option<RoundPairingAudit>, Error> {
+    let stored = match get_round(competition_id, round)? {
+        Some(r) => r,
+        None => return Ok(None),
+    };
+    let metadata = &stored.metadata;
+    let mut rng = ChaCha8Rng::seed_from_u64(stored.seed as u64);
+    let (pairs, byes) = create_match_pairs(
+        metadata.games_per_round,
+        &metadata.team_ids,
+        metadata.color_balance.clone(),
+        metadata.costs.clone(),
+        &mut rng,
+    );
+    let reproduced = pairs == metadata.pairs && byes == metadata.byes;
+    Ok(Some(RoundPairingAudit {
+        metadata: stored.metadata.clone(),
+        round: PublicRound::from(stored),
+        reproduced,
+    }))
+}
\ No newline at end of file
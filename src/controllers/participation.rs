@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel::result::Error;
+
+use crate::{
+    db::{
+        operations_participation::{insert_participations, get_participations_by_competition_id},
+        operations_users::get_users_by_ids,
+    },
+    models::{
+        competition::Competition,
+        errors::MatchMakerError,
+        game_2v2::Game2v2,
+        participation::{NewParticipation, StudentParticipation},
+        team::Team,
+    },
+};
+
+/// Records the participation of every team of the competition in the round that was just played.
+///
+/// A row is written for each team, including teams that were left out of the round because
+/// their bots didn't compile. The team's members are stored with the row, so later changes in
+/// team membership don't rewrite who participated in past rounds.
+///
+/// # Arguments
+///
+/// * `competition` - The competition whose round has just been played.
+/// * `teams` - All teams of the competition.
+/// * `compiled_team_ids` - Ids of the teams whose bots compiled and were paired for the round.
+/// * `games` - The games played in the round.
+///
+/// # Errors
+///
+/// Returns `MatchMakerError::DatabaseError` if the rows can't be inserted.
+///
+pub fn record_round_participation(
+    competition: &Competition,
+    teams: &[Team],
+    compiled_team_ids: &[String],
+    games: &[Game2v2],
+) -> Result<(), MatchMakerError> {
+    let mut games_per_team: HashMap<&str, i32> = HashMap::new();
+    for game in games.iter() {
+        *games_per_team.entry(game.team1_id.as_str()).or_insert(0) += 1;
+        if game.team2_id != game.team1_id {
+            *games_per_team.entry(game.team2_id.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let rows: Vec<NewParticipation> = teams
+        .iter()
+        .map(|team| NewParticipation {
+            competition_id: competition.id.clone(),
+            round: competition.round,
+            team_id: team.id.clone(),
+            owner: team.owner.clone(),
+            partner: team.partner.clone(),
+            submitted_working_bot: compiled_team_ids.contains(&team.id),
+            games_played: *games_per_team.get(team.id.as_str()).unwrap_or(&0),
+        })
+        .collect();
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+    insert_participations(rows).map_err(MatchMakerError::DatabaseError)
+}
+
+/// Builds per-student participation summaries for a competition.
+///
+/// `rounds_total` is the number of rounds the competition has recorded, so a student who
+/// joined late has fewer participated rounds rather than a smaller total.
+pub fn summarize_participation(competition_id: String) -> Result<Vec<StudentParticipation>, Error> {
+    let rows = get_participations_by_competition_id(competition_id)?;
+    let rounds_total = rows.iter().map(|p| p.round).collect::<HashSet<i32>>().len() as i32;
+
+    let mut summaries: HashMap<String, StudentParticipation> = HashMap::new();
+    for row in rows.iter() {
+        for member in [&row.owner, &row.partner] {
+            if member.is_empty() {
+                continue;
+            }
+            let summary = summaries.entry(member.clone()).or_insert(StudentParticipation {
+                user_id: member.clone(),
+                username: "".to_string(),
+                rounds_total,
+                rounds_with_working_bot: 0,
+                rounds_played: 0,
+                games_played: 0,
+            });
+            if row.submitted_working_bot {
+                summary.rounds_with_working_bot += 1;
+            }
+            if row.games_played > 0 {
+                summary.rounds_played += 1;
+            }
+            summary.games_played += row.games_played;
+        }
+    }
+
+    let users = get_users_by_ids(summaries.keys().cloned().collect())?;
+    for user in users.into_iter() {
+        if let Some(summary) = summaries.get_mut(&user.id) {
+            summary.username = user.username;
+        }
+    }
+
+    let mut summaries: Vec<StudentParticipation> = summaries.into_values().collect();
+    summaries.sort_by(|a, b| a.username.cmp(&b.username));
+    Ok(summaries)
+}
\ No newline at end of file
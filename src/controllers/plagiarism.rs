@@ -0,0 +1,235 @@
+use std::{collections::{hash_map::DefaultHasher, HashMap, HashSet}, fs::File, hash::{Hash, Hasher}, io::Read};
+
+use zip::ZipArchive;
+use tracing::error;
+
+use crate::{
+    db::{
+        operations_bot::get_bots_by_team,
+        operations_plagiarism::insert_plagiarism_report,
+        operations_teams::get_teams_by_competition_id,
+    },
+    models::{
+        errors::MatchMakerError,
+        plagiarism::{NewPlagiarismPair, NewPlagiarismReport, PlagiarismReport},
+    },
+};
+
+use super::file_handler::local_copy;
+
+/// Length of the token sequences that are fingerprinted.
+const KGRAM_SIZE: usize = 12;
+/// Winnowing window, a shared sequence of at least `KGRAM_SIZE + WINNOW_WINDOW - 1` tokens
+/// is always detected.
+const WINNOW_WINDOW: usize = 8;
+/// Bots with fewer fingerprints are too small to compare meaningfully.
+const MIN_FINGERPRINTS: usize = 10;
+
+const JAVA_KEYWORDS: [&str; 50] = [
+    "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "final", "finally", "float",
+    "for", "goto", "if", "implements", "import", "instanceof", "int", "interface", "long", "native",
+    "new", "package", "private", "protected", "public", "return", "short", "static", "strictfp", "super",
+    "switch", "synchronized", "this", "throw", "throws", "transient", "try", "void", "volatile", "while",
+];
+
+struct BotFingerprints {
+    team_id: String,
+    bot_id: String,
+    fingerprints: HashSet<u64>,
+}
+
+/// Compares the sources of all bots submitted to a competition and stores a report of
+/// suspiciously similar pairs.
+///
+/// Sources are tokenized with identifiers and literals normalized, so renaming variables or
+/// changing comments and formatting doesn't hide copied code. Token k-grams are hashed and
+/// winnowed into fingerprints (as done by MOSS). Fingerprints found in the bots of more than
+/// half of the teams, e.g. code of a starter kit, are ignored.
+///
+/// Every bot is compared with the bots of the other teams, bots of the same team are
+/// expected to be similar. For each pair of teams the most similar pair of bots is reported
+/// if its similarity, the share of the smaller bot's fingerprints found in the other bot,
+/// reaches `threshold`.
+///
+pub fn analyze_competition(competition_id: String, threshold: f64) -> Result<PlagiarismReport, MatchMakerError> {
+    let teams = get_teams_by_competition_id(competition_id.clone()).map_err(MatchMakerError::DatabaseError)?;
+
+    let mut bots = vec![];
+    for team in teams.iter() {
+        let team_bots = get_bots_by_team(team.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+        for bot in team_bots.into_iter() {
+            match local_copy(&bot.source_path).and_then(|path| source_tokens(&path)) {
+                Ok(tokens) => bots.push(BotFingerprints {
+                    team_id: team.id.clone(),
+                    bot_id: bot.id,
+                    fingerprints: winnow(&tokens),
+                }),
+                Err(e) => error!("Failed reading sources of bot {}: {:?}", bot.id, e),
+            }
+        }
+    }
+
+    remove_common_fingerprints(&mut bots, teams.len());
+    bots.retain(|b| b.fingerprints.len() >= MIN_FINGERPRINTS);
+
+    let mut best_pairs: HashMap<(String, String), NewPlagiarismPair> = HashMap::new();
+    for (i, a) in bots.iter().enumerate() {
+        for b in bots.iter().skip(i + 1) {
+            if a.team_id == b.team_id {
+                continue;
+            }
+            let shared = a.fingerprints.intersection(&b.fingerprints).count();
+            let similarity = shared as f64 / a.fingerprints.len().min(b.fingerprints.len()) as f64;
+            if similarity < threshold {
+                continue;
+            }
+
+            let (first, second) = if a.team_id < b.team_id { (a, b) } else { (b, a) };
+            let pair = best_pairs
+                .entry((first.team_id.clone(), second.team_id.clone()))
+                .or_insert_with(|| NewPlagiarismPair {
+                    team1_id: first.team_id.clone(),
+                    bot1_id: first.bot_id.clone(),
+                    team2_id: second.team_id.clone(),
+                    bot2_id: second.bot_id.clone(),
+                    similarity: 0.0,
+                    shared_fingerprints: 0,
+                });
+            if similarity > pair.similarity {
+                pair.bot1_id = first.bot_id.clone();
+                pair.bot2_id = second.bot_id.clone();
+                pair.similarity = similarity;
+                pair.shared_fingerprints = shared as i32;
+            }
+        }
+    }
+
+    let report = NewPlagiarismReport {
+        competition_id,
+        bots_compared: bots.len() as i32,
+        threshold,
+    };
+    insert_plagiarism_report(report, best_pairs.into_values().collect()).map_err(MatchMakerError::DatabaseError)
+}
+
+/// Tokens of all Java sources in a bot's archive, in archive order.
+fn source_tokens(source_path: &str) -> Result<Vec<String>, MatchMakerError> {
+    let file = File::open(source_path).map_err(MatchMakerError::IOError)?;
+    let mut archive = ZipArchive::new(file).map_err(MatchMakerError::ZippingError)?;
+
+    let mut tokens = vec![];
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(MatchMakerError::ZippingError)?;
+        if !entry.name().ends_with(".java") {
+            continue;
+        }
+        let mut source = String::new();
+        if entry.read_to_string(&mut source).is_ok() {
+            tokens.extend(tokenize(&source));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Splits Java source into tokens. Comments and whitespace are dropped, identifiers become
+/// `I`, numbers `N` and string or char literals `S`; keywords and operators are kept.
+fn tokenize(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            tokens.push("S".to_string());
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.' || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push("N".to_string());
+        } else if c.is_alphabetic() || c == '_' || c == '$' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if JAVA_KEYWORDS.contains(&word.as_str()) {
+                tokens.push(word);
+            } else {
+                tokens.push("I".to_string());
+            }
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Fingerprints of a token stream: of every window of `WINNOW_WINDOW` consecutive k-gram
+/// hashes the smallest one is kept.
+fn winnow(tokens: &[String]) -> HashSet<u64> {
+    let hashes: Vec<u64> = tokens
+        .windows(KGRAM_SIZE)
+        .map(|kgram| {
+            let mut hasher = DefaultHasher::new();
+            kgram.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect();
+
+    if hashes.len() < WINNOW_WINDOW {
+        return hashes.into_iter().collect();
+    }
+    hashes
+        .windows(WINNOW_WINDOW)
+        .filter_map(|window| window.iter().min().copied())
+        .collect()
+}
+
+/// Drops fingerprints that show up in the bots of more than half of the teams.
+fn remove_common_fingerprints(bots: &mut [BotFingerprints], team_count: usize) {
+    // with only a few teams everything would look common
+    if team_count < 4 {
+        return;
+    }
+
+    let mut teams_per_fingerprint: HashMap<u64, HashSet<&str>> = HashMap::new();
+    for bot in bots.iter() {
+        for fingerprint in bot.fingerprints.iter() {
+            teams_per_fingerprint.entry(*fingerprint).or_default().insert(&bot.team_id);
+        }
+    }
+    let common: HashSet<u64> = teams_per_fingerprint
+        .into_iter()
+        .filter(|(_, teams)| teams.len() * 2 > team_count)
+        .map(|(fingerprint, _)| fingerprint)
+        .collect();
+
+    for bot in bots.iter_mut() {
+        bot.fingerprints.retain(|f| !common.contains(f));
+    }
+}
\ No newline at end of file
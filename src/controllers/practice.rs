@@ -0,0 +1,125 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use crate::{
+    db::{operations_practice_bots::insert_practice_bot, operations_competition::get_competition_by_id},
+    models::{
+        bot::Bot,
+        errors::MatchMakerError,
+        game_2v2::NewGame2v2,
+        practice_bot::{NewPracticeBot, PracticeBot, PracticeMatchResult, SqlPracticeBot},
+        team::Team,
+    },
+    adapters::adapter_for_competition,
+    config::settings,
+};
+
+use super::matchmaker_2v2::{compile_bot, play_unranked_game};
+
+/// Publishes a team's bot as a practice opponent.
+///
+/// The bot is compiled and only its compiled classes are copied to
+/// `<practice_bots>/<practice bot id>`; the sources and the uploaded archive are left
+/// behind. Practice bots are never served for download, they can only be played against.
+///
+/// # Arguments
+///
+/// * `bot` - The bot to publish, has to compile.
+/// * `team` - The team publishing the bot.
+/// * `new_practice_bot` - Listing name and whether the team stays anonymous.
+///
+pub fn publish_practice_bot(bot: &Bot, team: &Team, new_practice_bot: NewPracticeBot) -> Result<PracticeBot, MatchMakerError> {
+    compile_bot(bot)?;
+
+    let practice_bot = SqlPracticeBot::new(new_practice_bot, team.id.clone(), team.competition_id.clone());
+    let source = settings().paths.bots_workdir.join(&bot.id);
+    let destination = practice_bot_folder(&practice_bot.id);
+    if let Err(e) = copy_compiled(&source, &destination) {
+        let _ = fs::remove_dir_all(&destination);
+        return Err(MatchMakerError::IOError(e));
+    }
+
+    insert_practice_bot(practice_bot).map_err(|e| {
+        let _ = fs::remove_dir_all(&destination);
+        MatchMakerError::DatabaseError(e)
+    })
+}
+
+/// Removes the compiled files of a practice bot.
+pub fn remove_practice_bot_files(practice_bot_id: &str) -> Result<(), MatchMakerError> {
+    let folder = practice_bot_folder(practice_bot_id);
+    if !folder.exists() {
+        return Ok(());
+    }
+    fs::remove_dir_all(folder).map_err(MatchMakerError::IOError)
+}
+
+/// Plays an unranked test match of a team's bots against two copies of a practice bot.
+///
+/// Nothing is stored: the game isn't inserted and ELO isn't touched. The caller is expected
+/// to hold an unranked workload slot.
+///
+pub fn run_practice_match(team: &Team, practice_bot: &PracticeBot) -> Result<PracticeMatchResult, MatchMakerError> {
+    let competition = get_competition_by_id(team.competition_id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(&competition)?;
+
+    let opponent_bot1 = format!("{}-a", practice_bot.id);
+    let opponent_bot2 = format!("{}-b", practice_bot.id);
+    let mut match_game = NewGame2v2::new(
+        team.competition_id.clone(),
+        0,
+        team.id.clone(),
+        practice_bot.id.clone(),
+        team.bot1.clone(),
+        team.bot2.clone(),
+        opponent_bot1.clone(),
+        opponent_bot2.clone(),
+    );
+
+    let sources = vec![
+        settings().paths.bots_workdir.join(&team.bot1),
+        settings().paths.bots_workdir.join(&team.bot2),
+        practice_bot_folder(&practice_bot.id),
+        practice_bot_folder(&practice_bot.id),
+    ];
+    play_unranked_game(adapter.as_ref(), &mut match_game, sources)?;
+
+    Ok(PracticeMatchResult {
+        practice_bot_id: practice_bot.id.clone(),
+        won: match_game.winner_id == team.id,
+        team_score: match_game.team1_score,
+        opponent_score: match_game.team2_score,
+        bot1_survived: match_game.team1bot1_survived,
+        bot2_survived: match_game.team1bot2_survived,
+        error: match_game.additional_data,
+    })
+}
+
+fn practice_bot_folder(practice_bot_id: &str) -> PathBuf {
+    settings().paths.practice_bots.join(practice_bot_id)
+}
+
+/// Whether both of a team's slots hold a bot that is compiled, so the team can play an
+/// unranked game without compiling first.
+pub fn has_compiled_bots(team: &Team) -> bool {
+    [&team.bot1, &team.bot2]
+        .iter()
+        .all(|bot_id| !bot_id.is_empty() && settings().paths.bots_workdir.join(bot_id).is_dir())
+}
+
+/// Copies a compiled bot, skipping its Java sources and the uploaded archive.
+pub fn copy_compiled(src: &Path, dest: &Path) -> io::Result<()> {
+    if src.is_dir() {
+        fs::create_dir_all(dest)?;
+        for entry in fs::read_dir(src)? {
+            let path = entry?.path();
+            copy_compiled(&path, &dest.join(path.file_name().unwrap()))?;
+        }
+        return Ok(());
+    }
+
+    match src.extension().and_then(|e| e.to_str()) {
+        Some("java") | Some("zip") => Ok(()),
+        _ => fs::copy(src, dest).map(|_| ()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::{collections::HashMap, sync::atomic::{AtomicBool, Ordering}};
+
+use tracing::{error, info, warn};
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::{
+        operations_competition::get_competition_by_id,
+        operations_practice_queue::{get_practice_queue, insert_practice_game_and_dequeue},
+        operations_teams::get_team_by_id,
+    },
+    models::{
+        errors::MatchMakerError,
+        game_2v2::NewGame2v2,
+        practice_queue::{NewPracticeGame, PracticeQueueEntry},
+        team::Team,
+    },
+};
+
+use super::{
+    matchmaker_2v2::play_unranked_game,
+    practice::has_compiled_bots,
+    safe_mode::is_safe_mode,
+    shutdown::is_shutting_down,
+    workload_gate::{acquire_unranked_slot, is_idle},
+};
+
+/// Set while queued practice games are played, a run that is still going skips the next one.
+static PRACTICE_QUEUE_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Clears `PRACTICE_QUEUE_RUNNING` when the run ends, also if it panics.
+struct PracticeQueueRunGuard;
+
+impl Drop for PracticeQueueRunGuard {
+    fn drop(&mut self) {
+        PRACTICE_QUEUE_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Plays practice games between the teams in the practice queue while the machine is idle.
+///
+/// Queued teams of a competition are sorted by ELO and paired with their neighbour, so teams
+/// play opponents of similar strength. A game only starts when no ranked round is running and
+/// no other unranked work is running or waiting; otherwise the remaining pairs stay queued
+/// for the next run. Both teams leave the queue once their game is played, the result is only
+/// shown to the two teams.
+pub fn run_practice_queue() {
+    if is_safe_mode() || !is_idle() || PRACTICE_QUEUE_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let _running = PracticeQueueRunGuard;
+    match get_practice_queue() {
+        Ok(queue) => {
+            for (competition_id, entries) in group_by_competition(queue).into_iter() {
+                if let Err(e) = run_competition_practice_queue(&competition_id, entries) {
+                    error!("Failed playing the practice queue of competition {}: {:?}", competition_id, e);
+                }
+            }
+        },
+        Err(e) => error!("Failed loading the practice queue: {:?}", e),
+    }
+}
+
+fn run_competition_practice_queue(competition_id: &str, entries: Vec<PracticeQueueEntry>) -> Result<(), MatchMakerError> {
+    let mut teams = entries
+        .into_iter()
+        .filter_map(|entry| get_team_by_id(entry.team_id).ok())
+        .filter(has_compiled_bots)
+        .collect::<Vec<Team>>();
+    if teams.len() < 2 {
+        return Ok(());
+    }
+    teams.sort_by_key(|t| t.elo);
+
+    let competition = get_competition_by_id(competition_id.to_string())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(&competition)?;
+
+    info!("Playing {} queued practice games of competition {}", teams.len() / 2, competition.id);
+    for pair in teams.chunks_exact(2) {
+        // practice games only fill idle time, anything else that shows up takes precedence
+        if is_shutting_down() || is_safe_mode() || !is_idle() {
+            break;
+        }
+        let (team1, team2) = (&pair[0], &pair[1]);
+
+        let _permit = acquire_unranked_slot(None);
+        let mut match_game = NewGame2v2::new(
+            competition.id.clone(),
+            competition.round,
+            team1.id.clone(),
+            team2.id.clone(),
+            team1.bot1.clone(),
+            team1.bot2.clone(),
+            team2.bot1.clone(),
+            team2.bot2.clone(),
+        );
+        let sources = vec![
+            settings().paths.bots_workdir.join(&team1.bot1),
+            settings().paths.bots_workdir.join(&team1.bot2),
+            settings().paths.bots_workdir.join(&team2.bot1),
+            settings().paths.bots_workdir.join(&team2.bot2),
+        ];
+        let error = match play_unranked_game(adapter.as_ref(), &mut match_game, sources) {
+            Ok(_) => "".to_string(),
+            Err(MatchMakerError::ExecutionDisabled) => break,
+            Err(e) => {
+                warn!("Practice game of teams {} and {} failed: {}", team1.id, team2.id, e);
+                e.to_string()
+            },
+        };
+
+        insert_practice_game_and_dequeue(NewPracticeGame {
+            competition_id: competition.id.clone(),
+            team1_id: team1.id.clone(),
+            team2_id: team2.id.clone(),
+            winner_id: match_game.winner_id.clone(),
+            team1_score: match_game.team1_score,
+            team2_score: match_game.team2_score,
+            error,
+        }).map_err(MatchMakerError::DatabaseError)?;
+    }
+    Ok(())
+}
+
+fn group_by_competition(queue: Vec<PracticeQueueEntry>) -> HashMap<String, Vec<PracticeQueueEntry>> {
+    let mut grouped: HashMap<String, Vec<PracticeQueueEntry>> = HashMap::new();
+    for entry in queue.into_iter() {
+        grouped.entry(entry.competition_id.clone()).or_default().push(entry);
+    }
+    grouped
+}
\ No newline at end of file
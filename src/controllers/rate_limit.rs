@@ -0,0 +1,279 @@
+use std::{collections::{HashMap, VecDeque}, sync::Mutex, time::{Duration, Instant}};
+
+use actix_web::{dev::ServiceRequest, http::header::AUTHORIZATION};
+use once_cell::sync::Lazy;
+use tracing::{error, warn};
+
+use crate::{
+    config::settings,
+    db::{operations_rate_limits::{get_rate_limit_by_competition_id, get_rate_limits_by_competition_ids}, operations_teams::get_team_by_student, operations_users::get_user_by_username},
+    models::{api_key::ApiKey, user::Role},
+};
+
+use super::{api_keys::{hash_api_key, API_KEY_HEADER}, jwt::decode_jwt};
+
+const UPLOAD_WINDOW: Duration = Duration::from_secs(60 * 60);
+const REQUEST_WINDOW: Duration = Duration::from_secs(60);
+/// How long a user's request limit is kept before it's looked up again, so a changed
+/// competition limit or a newly joined team takes effect without a restart.
+const USER_LIMIT_TTL: Duration = Duration::from_secs(5 * 60);
+/// Windows are swept for idle keys once there are this many.
+const SWEEP_THRESHOLD: usize = 10_000;
+/// Routes of the public API, limited by their API key once it's known to be valid.
+const PUBLIC_API_PREFIX: &str = "/api/public/";
+/// How long a resolved API key exempts its requests from the address limit.
+const API_KEY_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Default)]
+struct RateLimiterState {
+    /// key -> when the requests counted in its window were made, oldest first
+    windows: HashMap<String, VecDeque<Instant>>,
+    /// username -> when the request limit was looked up and the limit
+    user_limits: HashMap<String, (Instant, u32)>,
+    /// hash of an API key -> when a request last resolved it to an active key
+    api_keys: HashMap<String, Instant>,
+}
+
+static STATE: Lazy<Mutex<RateLimiterState>> = Lazy::new(|| Mutex::new(RateLimiterState::default()));
+
+impl RateLimiterState {
+    /// Counts a request against `key` if it's within `limit` requests per `window`. When it
+    /// isn't, the error is how many seconds until the oldest request leaves the window.
+    fn hit(&mut self, key: String, limit: u32, window: Duration) -> Result<(), u64> {
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        if self.windows.len() >= SWEEP_THRESHOLD {
+            self.windows.retain(|_, hits| hits.back().is_some_and(|last| now.duration_since(*last) < window));
+        }
+
+        let hits = self.windows.entry(key).or_default();
+        while hits.front().is_some_and(|first| now.duration_since(*first) >= window) {
+            hits.pop_front();
+        }
+
+        if hits.len() >= limit as usize {
+            let oldest = hits.front().copied().unwrap_or(now);
+            let retry_after = window.saturating_sub(now.duration_since(oldest));
+            return Err(retry_after.as_secs().max(1));
+        }
+
+        hits.push_back(now);
+        Ok(())
+    }
+}
+
+/// Counts a bot upload of the user to the competition. The competition's own limit applies
+/// if it set one, the server's default otherwise. The error is the number of seconds until
+/// the user may upload again.
+pub fn check_upload_rate(user_id: &str, competition_id: &str) -> Result<(), u64> {
+    let limit = match get_rate_limit_by_competition_id(competition_id.to_string()) {
+        Ok(Some(l)) => l.uploads_per_hour.max(0) as u32,
+        Ok(None) => settings().rate_limits.uploads_per_hour,
+        Err(e) => {
+            error!("Failed loading the rate limits of competition {}: {:?}", competition_id, e);
+            settings().rate_limits.uploads_per_hour
+        }
+    };
+
+    let key = format!("upload:{}:{}", user_id, competition_id);
+    let result = STATE.lock().unwrap().hit(key, limit, UPLOAD_WINDOW);
+    if result.is_err() {
+        warn!("User {} hit the upload limit of competition {}", user_id, competition_id);
+    }
+    result
+}
+
+/// Counts a public API request against the limit of its key. The error is the number of
+/// seconds until the key may be used again.
+pub fn check_api_key_rate(api_key: &ApiKey) -> Result<(), u64> {
+    let limit = api_key.requests_per_minute.max(0) as u32;
+    STATE.lock().unwrap().hit(format!("api_key:{}", api_key.id), limit, REQUEST_WINDOW)
+}
+
+/// Marks an API key as resolved to an active key, its public API requests are then limited
+/// by the key instead of the address for a while.
+pub fn remember_api_key(key_hash: String) {
+    let mut state = STATE.lock().unwrap();
+    if state.api_keys.len() >= SWEEP_THRESHOLD {
+        state.api_keys.retain(|_, resolved| resolved.elapsed() < API_KEY_TTL);
+    }
+    state.api_keys.insert(key_hash, Instant::now());
+}
+
+/// Counts an API request against the requester's limit, users are told apart by their
+/// token and everyone else by their address. Admins and match workers aren't limited, public
+/// API requests with a key that was recently resolved are limited by their key instead.
+/// The error is the number of seconds until the requester may try again.
+pub fn check_request_rate(req: &ServiceRequest) -> Result<(), u64> {
+    if req.path().contains("/workers/") {
+        return Ok(());
+    }
+    if req.path().starts_with(PUBLIC_API_PREFIX) && has_known_api_key(req) {
+        return Ok(());
+    }
+
+    let token = req.headers()
+        .get(AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .map(|h| h.trim_start_matches("Bearer ").to_string());
+    let claims = token.and_then(decode_jwt);
+
+    let (key, limit) = match claims {
+        Some(c) if Role::from(c.role.as_str()) == Role::Admin => return Ok(()),
+        Some(c) => {
+            let limit = user_request_limit(&c.sub);
+            (format!("user:{}", c.sub), limit)
+        },
+        None => {
+            let address = req.peer_addr().map(|a| a.ip().to_string()).unwrap_or_default();
+            (format!("address:{}", address), settings().rate_limits.requests_per_minute)
+        },
+    };
+
+    STATE.lock().unwrap().hit(key, limit, REQUEST_WINDOW)
+}
+
+/// Whether the request's API key was resolved to an active key recently. Unknown keys count
+/// against the address, so made up keys can't skip its limit.
+fn has_known_api_key(req: &ServiceRequest) -> bool {
+    let key = match req.headers().get(API_KEY_HEADER).and_then(|h| h.to_str().ok()) {
+        Some(k) if !k.trim().is_empty() => k.trim(),
+        _ => return false,
+    };
+    STATE.lock().unwrap()
+        .api_keys
+        .get(&hash_api_key(key))
+        .is_some_and(|resolved| resolved.elapsed() < API_KEY_TTL)
+}
+
+/// The request limit of a user, the most permissive one of the competitions they have a
+/// team in, so a finished competition's limit doesn't hold them back in a running one.
+fn user_request_limit(username: &str) -> u32 {
+    if let Some((looked_up, limit)) = STATE.lock().unwrap().user_limits.get(username) {
+        if looked_up.elapsed() < USER_LIMIT_TTL {
+            return *limit;
+        }
+    }
+
+    let limit = lookup_user_request_limit(username);
+    STATE.lock().unwrap().user_limits.insert(username.to_string(), (Instant::now(), limit));
+    limit
+}
+
+fn lookup_user_request_limit(username: &str) -> u32 {
+    let default = settings().rate_limits.requests_per_minute;
+    let competition_ids: Vec<String> = match get_user_by_username(username.to_string()).and_then(get_team_by_student) {
+        Ok(teams) => teams.into_iter().map(|t| t.competition_id).collect(),
+        Err(_) => return default,
+    };
+    if competition_ids.is_empty() {
+        return default;
+    }
+
+    let overrides = match get_rate_limits_by_competition_ids(competition_ids.clone()) {
+        Ok(o) => o,
+        Err(e) => {
+            error!("Failed loading rate limits: {:?}", e);
+            return default;
+        }
+    };
+
+    competition_ids
+        .iter()
+        .map(|id| match overrides.iter().find(|o| &o.competition_id == id) {
+            Some(o) => o.requests_per_minute.max(0) as u32,
+            None => default,
+        })
+        .max_by_key(|limit| if *limit == 0 { u32::MAX } else { *limit })
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::sleep;
+
+    use super::*;
+
+    const SHORT_WINDOW: Duration = Duration::from_millis(50);
+
+    #[test]
+    fn allows_requests_up_to_the_limit() {
+        let mut state = RateLimiterState::default();
+        for _ in 0..3 {
+            assert_eq!(state.hit("user:a".to_string(), 3, REQUEST_WINDOW), Ok(()));
+        }
+        assert!(state.hit("user:a".to_string(), 3, REQUEST_WINDOW).is_err());
+        // other keys have their own window
+        assert_eq!(state.hit("user:b".to_string(), 3, REQUEST_WINDOW), Ok(()));
+    }
+
+    #[test]
+    fn rejected_requests_are_not_counted() {
+        let mut state = RateLimiterState::default();
+        assert_eq!(state.hit("user:a".to_string(), 1, REQUEST_WINDOW), Ok(()));
+        for _ in 0..5 {
+            assert!(state.hit("user:a".to_string(), 1, REQUEST_WINDOW).is_err());
+        }
+        assert_eq!(state.windows["user:a"].len(), 1);
+    }
+
+    #[test]
+    fn retry_after_is_when_the_oldest_request_leaves_the_window() {
+        let mut state = RateLimiterState::default();
+        state.hit("user:a".to_string(), 1, REQUEST_WINDOW).unwrap();
+        let retry_after = state.hit("user:a".to_string(), 1, REQUEST_WINDOW).unwrap_err();
+        assert!(retry_after > 0 && retry_after <= REQUEST_WINDOW.as_secs());
+
+        // never less than a second, even right before the window frees up
+        state.hit("user:b".to_string(), 1, SHORT_WINDOW).unwrap();
+        assert_eq!(state.hit("user:b".to_string(), 1, SHORT_WINDOW), Err(1));
+    }
+
+    #[test]
+    fn requests_leave_the_window() {
+        let mut state = RateLimiterState::default();
+        state.hit("user:a".to_string(), 2, SHORT_WINDOW).unwrap();
+        state.hit("user:a".to_string(), 2, SHORT_WINDOW).unwrap();
+        assert!(state.hit("user:a".to_string(), 2, SHORT_WINDOW).is_err());
+
+        sleep(SHORT_WINDOW);
+        assert_eq!(state.hit("user:a".to_string(), 2, SHORT_WINDOW), Ok(()));
+        assert_eq!(state.windows["user:a"].len(), 1);
+    }
+
+    #[test]
+    fn a_limit_of_zero_is_unlimited() {
+        let mut state = RateLimiterState::default();
+        for _ in 0..1000 {
+            assert_eq!(state.hit("user:a".to_string(), 0, REQUEST_WINDOW), Ok(()));
+        }
+        assert!(state.windows.is_empty());
+    }
+
+    #[test]
+    fn idle_windows_are_swept() {
+        let mut state = RateLimiterState::default();
+        for i in 0..SWEEP_THRESHOLD {
+            state.hit(format!("address:{}", i), 10, SHORT_WINDOW).unwrap();
+        }
+        assert_eq!(state.windows.len(), SWEEP_THRESHOLD);
+
+        sleep(SHORT_WINDOW);
+        state.hit("address:fresh".to_string(), 10, SHORT_WINDOW).unwrap();
+        assert_eq!(state.windows.len(), 1);
+        assert!(state.windows.contains_key("address:fresh"));
+    }
+
+    #[test]
+    fn windows_still_in_use_survive_the_sweep() {
+        let mut state = RateLimiterState::default();
+        for i in 0..SWEEP_THRESHOLD {
+            state.hit(format!("address:{}", i), 10, REQUEST_WINDOW).unwrap();
+        }
+        state.hit("address:fresh".to_string(), 10, REQUEST_WINDOW).unwrap();
+        assert_eq!(state.windows.len(), SWEEP_THRESHOLD + 1);
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    db::{
+        operations_game2v2::get_games_by_competition_id,
+        operations_jobs::get_running_jobs,
+        operations_rating_shadows::{apply_rating_shadows, get_game_rating_shadows, get_team_rating_shadows, store_rating_shadows},
+        operations_teams::get_teams_by_competition_id,
+    },
+    models::{
+        competition::Competition,
+        rating_recompute::{RatingRecomputeOptions, RatingRecomputeReport, TeamRatingDiff},
+        team::{TeamRating, DEFAULT_ELO, DEFAULT_RATING_MU, DEFAULT_RATING_SIGMA},
+    },
+};
+
+use super::{elo::{elo_history_from_changes, replay_ratings, RatingSettings}, leaderboard::rebuild_team_streaks};
+
+/// Replays all games of the competition and stores the recomputed ratings next to the
+/// current ones, nothing the competition shows changes until they are applied.
+///
+/// # Returns
+///
+/// The current ratings compared to the recomputed ones.
+pub fn recompute_ratings(competition: &Competition, options: RatingRecomputeOptions) -> Result<RatingRecomputeReport, Error> {
+    let defaults = RatingSettings::from(competition);
+    let rating_settings = RatingSettings {
+        k_factor: options.k_factor.unwrap_or(defaults.k_factor),
+        provisional_games: options.provisional_games.unwrap_or(defaults.provisional_games),
+        provisional_k_factor: options.provisional_k_factor.unwrap_or(defaults.provisional_k_factor),
+    };
+
+    let games = get_games_by_competition_id(competition.id.clone())?;
+    let replayed = replay_ratings(&games, &rating_settings);
+
+    // teams without games are back at the default ratings
+    let mut ratings = replayed.teams;
+    for team in get_teams_by_competition_id(competition.id.clone())? {
+        if !ratings.iter().any(|r| r.team_id == team.id) {
+            ratings.push(TeamRating {
+                team_id: team.id,
+                elo: DEFAULT_ELO,
+                rating_mu: DEFAULT_RATING_MU,
+                rating_sigma: DEFAULT_RATING_SIGMA,
+            });
+        }
+    }
+
+    store_rating_shadows(competition.id.clone(), ratings, replayed.games)?;
+    Ok(rating_report(competition)?.unwrap_or_else(|| empty_report(competition)))
+}
+
+/// Compares the recomputed ratings of the competition to the current ones, `None` if there is
+/// no recompute to apply.
+pub fn rating_report(competition: &Competition) -> Result<Option<RatingRecomputeReport>, Error> {
+    let shadows = get_team_rating_shadows(competition.id.clone())?;
+    if shadows.is_empty() {
+        return Ok(None);
+    }
+    let games_replayed = get_game_rating_shadows(competition.id.clone())?.len();
+
+    let mut teams = get_teams_by_competition_id(competition.id.clone())?
+        .into_iter()
+        .filter_map(|team| {
+            let shadow = shadows.iter().find(|s| s.team_id == team.id)?;
+            Some(TeamRatingDiff {
+                elo_difference: shadow.elo - team.elo,
+                recomputed_elo: shadow.elo,
+                recomputed_rating_mu: shadow.rating_mu,
+                recomputed_rating_sigma: shadow.rating_sigma,
+                team_id: team.id,
+                team_name: team.name,
+                elo: team.elo,
+                rating_mu: team.rating_mu,
+                rating_sigma: team.rating_sigma,
+            })
+        })
+        .collect::<Vec<TeamRatingDiff>>();
+    teams.sort_by_key(|t| std::cmp::Reverse(t.elo_difference.abs()));
+
+    Ok(Some(RatingRecomputeReport {
+        competition_id: competition.id.clone(),
+        games_replayed,
+        teams_changed: teams.iter().filter(|t| t.elo_difference != 0).count(),
+        max_elo_difference: teams.first().map(|t| t.elo_difference.abs()).unwrap_or(0),
+        teams,
+    }))
+}
+
+/// Why the recomputed ratings of the competition can't be applied right now, `None` if they can.
+pub fn apply_blocked_reason(competition: &Competition) -> Result<Option<String>, Error> {
+    if get_running_jobs()?.iter().any(|j| j.competition_id == competition.id) {
+        return Ok(Some("A round of the competition is running".to_string()));
+    }
+    if get_team_rating_shadows(competition.id.clone())?.is_empty() {
+        return Ok(Some("Ratings of the competition weren't recomputed".to_string()));
+    }
+
+    let shadows = get_game_rating_shadows(competition.id.clone())?;
+    let games = get_games_by_competition_id(competition.id.clone())?;
+    if games.iter().any(|g| !shadows.iter().any(|s| s.game_id == g.id)) {
+        return Ok(Some("Games were played since the recompute, recompute the ratings again".to_string()));
+    }
+    Ok(None)
+}
+
+/// Replaces the competition's ratings, the ELO changes of its games and its ELO history with
+/// the recomputed ones. Check `apply_blocked_reason` first.
+pub fn apply_recomputed_ratings(competition: &Competition) -> Result<(), Error> {
+    let shadows = get_game_rating_shadows(competition.id.clone())?;
+    let games = get_games_by_competition_id(competition.id.clone())?;
+
+    let changes = shadows
+        .iter()
+        .map(|s| (s.game_id.clone(), (s.team1_elo, s.team2_elo)))
+        .collect::<HashMap<String, (i32, i32)>>();
+    let history = elo_history_from_changes(&games, &changes);
+
+    apply_rating_shadows(competition.id.clone(), shadows, history)?;
+    // upsets are measured against the ELO history that was just replaced
+    rebuild_team_streaks(competition.id.clone())?;
+    Ok(())
+}
+
+fn empty_report(competition: &Competition) -> RatingRecomputeReport {
+    RatingRecomputeReport {
+        competition_id: competition.id.clone(),
+        games_replayed: 0,
+        teams_changed: 0,
+        max_elo_difference: 0,
+        teams: vec![],
+    }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::{io, path::PathBuf};
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::operations_competition::get_competition_by_id,
+    models::{bot::Bot, errors::MatchMakerError, game_2v2::NewGame2v2, reference_match::{ReferenceMatchResult, REFERENCE_TEAM_ID}, team::Team},
+};
+
+use super::matchmaker_2v2::{compile_bot, play_unranked_game};
+
+/// Plays a quick test match of a bot against the competition's reference bot.
+///
+/// The reference bot is read from `<reference_bots>/<competition id>`, falling back to
+/// `<reference_bots>/default`; both hold the reference bot's compiled files. The game
+/// is played through `play_unranked_game`, so it isn't stored or rated. The bot is compiled
+/// first if it hasn't been yet, the caller is expected to hold an unranked workload slot.
+///
+/// # Arguments
+///
+/// * `team` - The team the bot belongs to.
+/// * `bot` - The bot to test, plays both of team 1's slots.
+///
+pub fn run_reference_match(team: &Team, bot: &Bot) -> Result<ReferenceMatchResult, MatchMakerError> {
+    let competition = get_competition_by_id(team.competition_id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(&competition)?;
+    let reference = reference_bot_folder(&competition.id)?;
+
+    let bot_folder = settings().paths.bots_workdir.join(&bot.id);
+    if !bot_folder.is_dir() {
+        compile_bot(bot)?;
+    }
+
+    let mut match_game = NewGame2v2::new(
+        competition.id.clone(),
+        competition.round,
+        team.id.clone(),
+        REFERENCE_TEAM_ID.to_string(),
+        bot.id.clone(),
+        bot.id.clone(),
+        REFERENCE_TEAM_ID.to_string(),
+        REFERENCE_TEAM_ID.to_string(),
+    );
+    let sources = vec![bot_folder.clone(), bot_folder, reference.clone(), reference];
+    let played = play_unranked_game(adapter.as_ref(), &mut match_game, sources)?;
+
+    Ok(ReferenceMatchResult {
+        bot_id: bot.id.clone(),
+        won: match_game.winner_id == team.id,
+        bot_score: match_game.team1_score,
+        reference_score: match_game.team2_score,
+        bot1_survived: match_game.team1bot1_survived,
+        bot2_survived: match_game.team1bot2_survived,
+        map_seed: match_game.map_seed,
+        duration_ms: match_game.duration_ms,
+        error: match_game.additional_data,
+        stats: played.parsed.player_stats,
+        replay: played.replay.join("\n"),
+    })
+}
+
+fn reference_bot_folder(competition_id: &str) -> Result<PathBuf, MatchMakerError> {
+    [competition_id, "default"]
+        .iter()
+        .map(|name| settings().paths.reference_bots.join(name))
+        .find(|folder| folder.is_dir())
+        .ok_or_else(|| MatchMakerError::IOError(io::Error::new(io::ErrorKind::NotFound, "No reference bot installed")))
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use diesel::result::Error;
+
+use crate::{
+    db::operations_teams::{get_active_teams_by_competition_id, get_waitlisted_teams_by_competition_id, set_team_waitlisted},
+    models::{competition::Competition, team::Team},
+};
+
+/// Returns why no teams can be registered for a competition at `now`, `None` if they can.
+pub fn registration_closed_reason(competition: &Competition, now: NaiveDateTime) -> Option<String> {
+    if competition.archived {
+        return Some(format!("{} is archived", competition.name));
+    }
+    if now < competition.registration_open {
+        return Some(format!("Registration opens at {}", competition.registration_open));
+    }
+    if now > competition.registration_close {
+        return Some(format!("Registration closed at {}", competition.registration_close));
+    }
+    None
+}
+
+/// Moves waitlisted teams into the competition, in the order they registered, while there
+/// are free spots.
+///
+/// # Returns
+///
+/// The teams that got a spot.
+///
+pub fn promote_waitlisted_teams(competition: &Competition) -> Result<Vec<Team>, Error> {
+    let waitlist = get_waitlisted_teams_by_competition_id(competition.id.clone())?;
+    if waitlist.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let free_spots = if competition.max_teams <= 0 {
+        waitlist.len()
+    } else {
+        let active_teams = get_active_teams_by_competition_id(competition.id.clone())?;
+        (competition.max_teams as usize).saturating_sub(active_teams.len())
+    };
+
+    let mut promoted = vec![];
+    for mut team in waitlist.into_iter().take(free_spots) {
+        set_team_waitlisted(team.id.clone(), false)?;
+        team.waitlisted = false;
+        promoted.push(team);
+    }
+    Ok(promoted)
+}
\ No newline at end of file
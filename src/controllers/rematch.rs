@@ -0,0 +1,60 @@
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::{operations_bot::get_bot_by_id, operations_competition::get_competition_by_id},
+    models::{errors::MatchMakerError, game_2v2::{Game2v2, NewGame2v2}, rematch::RematchResult},
+};
+
+use super::matchmaker_2v2::{compile_bot, play_unranked_game};
+
+
+/// Replays a game with the same bots on the same map, to reproduce reported bot bugs.
+///
+/// Bots that are no longer compiled are compiled again first. The rematch is unranked:
+/// nothing is stored and the caller is expected to hold an unranked workload slot.
+///
+pub fn run_rematch(game: &Game2v2) -> Result<RematchResult, MatchMakerError> {
+    let competition = get_competition_by_id(game.competition_id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let adapter = adapter_for_competition(&competition)?;
+
+    let bot_ids = [&game.team1bot1_id, &game.team1bot2_id, &game.team2bot1_id, &game.team2bot2_id];
+    for bot_id in bot_ids.iter() {
+        if !settings().paths.bots_workdir.join(bot_id).is_dir() {
+            let bot = get_bot_by_id(bot_id.to_string()).map_err(MatchMakerError::DatabaseError)?;
+            compile_bot(&bot)?;
+        }
+    }
+
+    let mut match_game = NewGame2v2::new(
+        game.competition_id.clone(),
+        game.round,
+        game.team1_id.clone(),
+        game.team2_id.clone(),
+        game.team1bot1_id.clone(),
+        game.team1bot2_id.clone(),
+        game.team2bot1_id.clone(),
+        game.team2bot2_id.clone(),
+    );
+    match_game.map_seed = game.map_seed;
+
+    let sources = bot_ids.iter().map(|id| settings().paths.bots_workdir.join(id)).collect();
+    let played = play_unranked_game(adapter.as_ref(), &mut match_game, sources)?;
+
+    Ok(RematchResult {
+        game_id: game.id.clone(),
+        map_seed: match_game.map_seed,
+        original_winner_id: game.winner_id.clone(),
+        same_winner: match_game.winner_id == game.winner_id,
+        winner_id: match_game.winner_id,
+        team1_score: match_game.team1_score,
+        team2_score: match_game.team2_score,
+        team1bot1_survived: match_game.team1bot1_survived,
+        team1bot2_survived: match_game.team1bot2_survived,
+        team2bot1_survived: match_game.team2bot1_survived,
+        team2bot2_survived: match_game.team2bot2_survived,
+        error: match_game.additional_data,
+        stderr: played.errors,
+    })
+}
\ No newline at end of file
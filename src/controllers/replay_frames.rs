@@ -0,0 +1,68 @@
+use std::{fs, path::PathBuf};
+
+use tracing::warn;
+
+use crate::{
+    config::settings,
+    models::{bot::GameBot, errors::MatchMakerError, game_2v2::Game2v2, replay_frame::{ReplayFrames, REPLAY_FRAMES_VERSION}},
+    parsers::replay::decode_frames,
+};
+
+use super::{bot_metadata::game_bots, file_handler::read_replay};
+
+/// Decoded frames are cached next to the replay as `<games directory>/<round>/<game id>_frames.json`.
+pub fn frames_cache_path(game: &Game2v2) -> PathBuf {
+    settings().paths.games.join(game.round.to_string()).join(format!("{}_frames.json", game.id))
+}
+
+/// Decodes a game's replay into frames for the visualizer.
+///
+/// The frames are decoded once and cached on disk, the cache is ignored if it was written
+/// by another version of the decoder. A cache that can't be written only costs decoding the
+/// replay again on the next request.
+///
+/// # Errors
+///
+/// Returns a `MatchMakerError` if the replay can't be read.
+///
+pub fn replay_frames(game: &Game2v2) -> Result<ReplayFrames, MatchMakerError> {
+    let cache_path = frames_cache_path(game);
+    let cached = fs::read(&cache_path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<ReplayFrames>(&bytes).ok())
+        .filter(|frames| frames.version == REPLAY_FRAMES_VERSION);
+    if let Some(mut frames) = cached {
+        frames.players = players(game);
+        return Ok(frames);
+    }
+
+    let replay = read_replay(&game.log_file_path)?;
+    let lines: Vec<String> = replay.lines().map(str::to_string).collect();
+    let mut frames = ReplayFrames {
+        version: REPLAY_FRAMES_VERSION,
+        game_id: game.id.clone(),
+        output_version: game.output_version.clone(),
+        players: vec![],
+        frames: decode_frames(&lines),
+    };
+
+    match serde_json::to_vec(&frames) {
+        Ok(bytes) => {
+            if let Err(e) = fs::write(&cache_path, bytes) {
+                warn!("Failed caching the frames of game {}: {}", game.id, e);
+            }
+        },
+        Err(e) => warn!("Failed serializing the frames of game {}: {}", game.id, e),
+    }
+    // bots can be renamed after the game, so their names aren't cached
+    frames.players = players(game);
+    Ok(frames)
+}
+
+/// The game's bots for the frames, a replay is still served if they can't be looked up.
+fn players(game: &Game2v2) -> Vec<GameBot> {
+    game_bots(game).unwrap_or_else(|e| {
+        warn!("Failed looking up the bots of game {}: {}", game.id, e);
+        vec![]
+    })
+}
\ No newline at end of file
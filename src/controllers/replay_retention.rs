@@ -0,0 +1,82 @@
+use std::{fs, path::Path};
+
+use crate::{
+    config::settings,
+    db::operations_game2v2::{get_games_with_replay_state_until_round, set_game_replay},
+    models::{competition::Competition, errors::MatchMakerError, game_2v2::ReplayState},
+    parsers::BOT_SLOTS,
+};
+
+use super::{file_handler::recompress_replay_zstd, matchmaker_2v2::bot_output_file, replay_frames::frames_cache_path, storage::storage};
+
+/// Applies the competition's replay retention policy after a round has been played.
+///
+/// The policy is configured per competition with two thresholds, both counted in rounds
+/// relative to the round that was just played (`0` disables the step):
+///
+/// * `replay_delete_after` - replays of games older than this many rounds are deleted from
+///   the replay storage, and their error output and decoded frames from the games directory.
+/// * `replay_compress_after` - replays of games older than this many rounds are re-compressed
+///   with zstd, which is considerably smaller than the deflated ZIP written during the match.
+///
+/// Every change is written back to the game record (`log_file_path` and `replay_state`), so
+/// a game never points to a replay that no longer exists.
+///
+/// # Arguments
+///
+/// * `competition` - The competition whose round has just finished.
+///
+/// # Errors
+///
+/// Returns a `MatchMakerError` if the games can't be fetched or updated, or if a replay
+/// can't be compressed or removed.
+///
+pub fn apply_replay_retention(competition: &Competition) -> Result<(), MatchMakerError> {
+    if competition.replay_delete_after > 0 {
+        let last_round = competition.round - competition.replay_delete_after;
+        let games = get_games_with_replay_state_until_round(
+            competition.id.clone(),
+            vec![ReplayState::Stored, ReplayState::Compressed],
+            last_round,
+        ).map_err(MatchMakerError::DatabaseError)?;
+
+        for game in games.into_iter() {
+            storage().delete(&game.log_file_path)?;
+            let error_file = format!("{}/{}/{}_error.txt", settings().paths.games.display(), game.round, game.id);
+            remove_if_exists(&error_file)?;
+            for slot in BOT_SLOTS {
+                remove_if_exists(&bot_output_file(game.round, &game.id, slot))?;
+            }
+            remove_if_exists(&frames_cache_path(&game).to_string_lossy())?;
+            set_game_replay(game.id, "".to_string(), ReplayState::Deleted)
+                .map_err(MatchMakerError::DatabaseError)?;
+        }
+    }
+
+    if competition.replay_compress_after > 0 {
+        let last_round = competition.round - competition.replay_compress_after;
+        let games = get_games_with_replay_state_until_round(
+            competition.id.clone(),
+            vec![ReplayState::Stored],
+            last_round,
+        ).map_err(MatchMakerError::DatabaseError)?;
+
+        for game in games.into_iter() {
+            if !storage().exists(&game.log_file_path)? {
+                continue;
+            }
+            let compressed_path = recompress_replay_zstd(&game.log_file_path)?;
+            set_game_replay(game.id, compressed_path, ReplayState::Compressed)
+                .map_err(MatchMakerError::DatabaseError)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn remove_if_exists(file_name: &str) -> Result<(), MatchMakerError> {
+    if file_name.is_empty() || !Path::new(file_name).exists() {
+        return Ok(());
+    }
+    fs::remove_file(file_name).map_err(MatchMakerError::IOError)
+}
\ No newline at end of file
@@ -0,0 +1,223 @@
+use std::{collections::{HashMap, HashSet}, fs::{self, File}, io::Read, path::Path};
+
+use chrono::{Duration, Local};
+use zip::ZipArchive;
+use tracing::error;
+
+use crate::{
+    db::{
+        operations_bot::get_bot_by_id,
+        operations_bot_violations::{delete_bot_violation, get_bot_violations_by_competition_id, insert_bot_violation},
+        operations_competition::get_competition_by_id,
+        operations_round_events::insert_round_event,
+        operations_teams::get_teams_by_competition_id,
+        operations_validation_rules::get_validation_rules_by_competition_id,
+    },
+    models::{
+        bot::Bot,
+        bot_violation::{NewBotViolation, RevalidationSummary},
+        errors::MatchMakerError,
+        round_event::NewRoundEvent,
+        team::Team,
+        validation_rules::ValidationRules,
+    },
+};
+
+use super::{file_handler::local_copy, trace::TraceContext};
+
+/// Re-checks the active bots of all teams in a competition against its validation rules.
+///
+/// Run after an admin changes the rules mid-competition. Bots that newly break the rules
+/// are flagged with a deadline `grace_hours` from now and their team is notified through
+/// the round event log; flags of bots that comply again (or are no longer active) are
+/// cleared. Bots that were already flagged keep their original deadline. A bot that can't
+/// be checked is logged and skipped, its flag is left as it was.
+///
+/// # Arguments
+///
+/// * `competition_id` - The competition whose bots are re-checked.
+/// * `trace` - Trace the notifications are recorded under.
+///
+pub fn revalidate_competition(competition_id: String, trace: &TraceContext) -> Result<RevalidationSummary, MatchMakerError> {
+    let competition = get_competition_by_id(competition_id).map_err(MatchMakerError::DatabaseError)?;
+    let rules = get_validation_rules_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let teams = get_teams_by_competition_id(competition.id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let mut flagged: HashMap<String, String> = get_bot_violations_by_competition_id(competition.id.clone())
+        .map_err(MatchMakerError::DatabaseError)?
+        .into_iter()
+        .map(|v| (v.bot_id, v.id))
+        .collect();
+
+    let mut summary = RevalidationSummary::default();
+    let mut active_bots = HashSet::new();
+    let notify = |status: &str, message: String| {
+        if let Err(e) = insert_round_event(NewRoundEvent {
+            competition_id: competition.id.clone(),
+            round: competition.round,
+            kind: "REVALIDATION".to_string(),
+            status: status.to_string(),
+            message,
+            trace_id: trace.trace_id.clone(),
+        }) {
+            error!("Failed recording round event: {:?}", e);
+        }
+    };
+
+    for team in teams.iter() {
+        for bot_id in [&team.bot1, &team.bot2] {
+            if bot_id.is_empty() || !active_bots.insert(bot_id.clone()) {
+                continue;
+            }
+            // a bot that can't be checked keeps its flag as it is and doesn't stop the others
+            let bot = match get_bot_by_id(bot_id.clone()) {
+                Ok(b) => b,
+                Err(e) => {
+                    error!("Failed loading bot {} for revalidation: {:?}", bot_id, e);
+                    flagged.remove(bot_id);
+                    continue;
+                },
+            };
+            let violations = match &rules {
+                Some(r) => match validate_bot(&bot, r) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error!("Failed revalidating bot {}: {:?}", bot.id, e);
+                        flagged.remove(bot_id);
+                        continue;
+                    },
+                },
+                None => vec![],
+            };
+            summary.checked += 1;
+
+            match (violations.is_empty(), flagged.remove(bot_id)) {
+                (true, Some(violation_id)) => {
+                    if let Err(e) = delete_bot_violation(violation_id) {
+                        error!("Failed clearing the flag of bot {}: {:?}", bot.id, e);
+                        continue;
+                    }
+                    summary.cleared += 1;
+                },
+                (false, None) => {
+                    let grace_hours = rules.as_ref().map(|r| r.grace_hours).unwrap_or(0);
+                    let violation = match insert_bot_violation(NewBotViolation {
+                        bot_id: bot.id.clone(),
+                        team_id: team.id.clone(),
+                        competition_id: competition.id.clone(),
+                        reason: violations.join("\n"),
+                        deadline: Local::now().naive_utc() + Duration::hours(grace_hours as i64),
+                    }) {
+                        Ok(v) => v,
+                        Err(e) => {
+                            error!("Failed flagging bot {}: {:?}", bot.id, e);
+                            continue;
+                        },
+                    };
+                    notify("FLAGGED", format!(
+                        "bot {} of team {} breaks the competition rules, replace it before {}:\n{}",
+                        bot.id, team.id, violation.deadline, violation.reason
+                    ));
+                    summary.flagged += 1;
+                },
+                _ => (),
+            }
+        }
+    }
+
+    // flags of bots the teams have replaced in the meantime
+    for (_, violation_id) in flagged.into_iter() {
+        delete_bot_violation(violation_id).map_err(MatchMakerError::DatabaseError)?;
+        summary.cleared += 1;
+    }
+
+    notify("OK", format!(
+        "{} bots checked, {} flagged, {} cleared",
+        summary.checked, summary.flagged, summary.cleared
+    ));
+    Ok(summary)
+}
+
+/// Checks a bot's source archive against the validation rules.
+///
+/// # Returns
+///
+/// A description of every broken rule, empty if the bot complies.
+///
+pub fn validate_bot(bot: &Bot, rules: &ValidationRules) -> Result<Vec<String>, MatchMakerError> {
+    validate_archive(Path::new(&local_copy(&bot.source_path)?), rules)
+}
+
+/// Checks a source archive against the validation rules, see `validate_bot`.
+pub fn validate_archive(source_path: &Path, rules: &ValidationRules) -> Result<Vec<String>, MatchMakerError> {
+    let mut violations = vec![];
+
+    let size = fs::metadata(source_path).map_err(MatchMakerError::IOError)?.len();
+    if rules.max_size_kb > 0 && size > rules.max_size_kb as u64 * 1024 {
+        violations.push(format!("archive is {} KB, the limit is {} KB", size / 1024, rules.max_size_kb));
+    }
+
+    let file = File::open(source_path).map_err(MatchMakerError::IOError)?;
+    let mut archive = ZipArchive::new(file).map_err(MatchMakerError::ZippingError)?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(MatchMakerError::ZippingError)?;
+        let name = entry.name().to_string();
+        let language = match source_language(&name) {
+            Some(l) => l,
+            None => continue,
+        };
+
+        if !rules.allowed_languages.is_empty() && !rules.allowed_languages.iter().any(|l| l == language) {
+            violations.push(format!("{} is written in {}, which isn't allowed", name, language));
+        }
+
+        let mut source = String::new();
+        if entry.read_to_string(&mut source).is_err() {
+            continue;
+        }
+        for api in rules.forbidden_apis.iter() {
+            if source.contains(api.as_str()) {
+                violations.push(format!("{} uses the forbidden API {}", name, api));
+            }
+        }
+    }
+    Ok(violations)
+}
+
+/// Removes teams whose active bot is flagged and past its deadline, they sit out the round
+/// until they replace the bot.
+pub fn exclude_expired_violations(competition: &str, teams: Vec<Team>) -> Vec<Team> {
+    let now = Local::now().naive_utc();
+    let expired: HashSet<String> = match get_bot_violations_by_competition_id(competition.to_string()) {
+        Ok(violations) => violations
+            .into_iter()
+            .filter(|v| v.deadline < now)
+            .map(|v| v.bot_id)
+            .collect(),
+        Err(e) => {
+            error!("Failed fetching bot violations: {:?}", e);
+            return teams;
+        }
+    };
+
+    teams
+        .into_iter()
+        .filter(|t| !expired.contains(&t.bot1) && !expired.contains(&t.bot2))
+        .collect()
+}
+
+/// Language of a source file, `None` for files that aren't source code.
+fn source_language(file_name: &str) -> Option<&'static str> {
+    let extension = Path::new(file_name).extension()?.to_str()?;
+    match extension.to_lowercase().as_str() {
+        "java" => Some("java"),
+        "kt" | "kts" => Some("kotlin"),
+        "py" => Some("python"),
+        "c" | "h" => Some("c"),
+        "cpp" | "cc" | "hpp" => Some("cpp"),
+        "cs" => Some("csharp"),
+        "js" => Some("javascript"),
+        "rs" => Some("rust"),
+        "go" => Some("go"),
+        _ => None,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::{
+    db::{
+        operations_round_hooks::get_round_hooks_by_competition_id,
+        operations_round_events::insert_round_event,
+    },
+    models::{
+        competition::Competition,
+        errors::MatchMakerError,
+        round_hook::{RoundHook, HookKind},
+        round_event::NewRoundEvent,
+    },
+};
+
+use super::{command_executor::{execute_command_with_timeout, TimedCommandOutput}, trace::TraceContext};
+
+/// Maximum number of characters of hook output kept in the round event log.
+const HOOK_OUTPUT_LIMIT: usize = 8192;
+
+/// Runs all post-round hooks configured for a competition.
+///
+/// Hooks are executed one after another in the order they were created. Each hook is
+/// either a shell command (run with `sh -c`, with `BATALJA_COMPETITION_ID` and `BATALJA_ROUND`
+/// set in its environment) or an HTTP endpoint that receives a JSON `POST` with the same
+/// information. Every run is bounded by the hook's timeout, and its status and captured
+/// output are written to the round event log.
+///
+/// A failing hook doesn't stop the remaining hooks.
+///
+/// # Arguments
+///
+/// * `competition` - The competition whose round has just finished.
+/// * `trace` - Trace of the round, the hook results are recorded under it.
+///
+/// # Errors
+///
+/// Returns `MatchMakerError::DatabaseError` if the hooks can't be fetched or an event
+/// can't be recorded.
+///
+pub fn run_post_round_hooks(competition: &Competition, trace: &TraceContext) -> Result<(), MatchMakerError> {
+    let hooks = get_round_hooks_by_competition_id(competition.id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+
+    for hook in hooks.iter() {
+        let (status, output) = match run_hook(competition, hook) {
+            Ok(out) => (hook_status(&out), format_output(&out)),
+            Err(e) => ("FAILED".to_string(), e.to_string()),
+        };
+
+        insert_round_event(NewRoundEvent {
+            competition_id: competition.id.clone(),
+            round: competition.round,
+            kind: "HOOK".to_string(),
+            status,
+            message: format!("hook {} ({})\n{}", hook.id, hook.target, truncate_output(output)),
+            trace_id: trace.trace_id.clone(),
+        }).map_err(MatchMakerError::DatabaseError)?;
+    }
+    Ok(())
+}
+
+fn run_hook(competition: &Competition, hook: &RoundHook) -> std::io::Result<TimedCommandOutput> {
+    let timeout = Duration::from_secs(hook.timeout_secs.max(1) as u64);
+    let round = competition.round.to_string();
+
+    match hook.kind {
+        HookKind::Command => execute_command_with_timeout(
+            "sh".to_string(),
+            vec!["-c", &hook.target],
+            vec![
+                ("BATALJA_COMPETITION_ID", competition.id.clone()),
+                ("BATALJA_ROUND", round),
+            ],
+            timeout,
+        ),
+        HookKind::Http => {
+            let payload = json!({
+                "competition_id": competition.id,
+                "round": competition.round,
+            }).to_string();
+            let max_time = hook.timeout_secs.max(1).to_string();
+            execute_command_with_timeout(
+                "curl".to_string(),
+                vec![
+                    "-sS", "--fail",
+                    "-X", "POST",
+                    "-H", "Content-Type: application/json",
+                    "--max-time", &max_time,
+                    "-d", &payload,
+                    &hook.target,
+                ],
+                vec![],
+                // give curl the chance to report its own timeout first
+                timeout + Duration::from_secs(5),
+            )
+        },
+    }
+}
+
+fn hook_status(output: &TimedCommandOutput) -> String {
+    match output.status {
+        Some(status) if status.success() => "OK".to_string(),
+        Some(_) => "FAILED".to_string(),
+        None => "TIMEOUT".to_string(),
+    }
+}
+
+fn format_output(output: &TimedCommandOutput) -> String {
+    let exit = match output.status {
+        Some(status) => status.to_string(),
+        None => "killed after timeout".to_string(),
+    };
+    format!(
+        "{}\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        exit,
+        output.stdout.join("\n"),
+        output.stderr.join("\n"),
+    )
+}
+
+/// Keeps the tail of the output, which is usually where the interesting part is.
+fn truncate_output(output: String) -> String {
+    let char_count = output.chars().count();
+    if char_count <= HOOK_OUTPUT_LIMIT {
+        return output;
+    }
+    let tail: String = output.chars().skip(char_count - HOOK_OUTPUT_LIMIT).collect();
+    format!("...\n{}", tail)
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use diesel::result::Error;
+
+use crate::{
+    db::operations_round_stats::upsert_round_stats,
+    models::{competition::Competition, game_2v2::Game2v2, round_stats::{NewRoundStats, RoundStats}},
+};
+
+/// Rolls up the games of a finished round into the `round_stats` table, so dashboards don't
+/// have to aggregate all games of a competition.
+///
+/// # Arguments
+///
+/// * `competition` - The competition, its current round is the one that finished.
+/// * `games` - The games played in the round.
+/// * `failed_matches` - Matches of the round that failed before producing a game.
+/// * `compile_failures` - Teams that sat out the round because their bots didn't compile.
+///
+pub fn record_round_stats(competition: &Competition, games: &[Game2v2], failed_matches: usize, compile_failures: usize) -> Result<RoundStats, Error> {
+    let games_played = games.len();
+    let average = |total: f64| if games_played == 0 { 0.0 } else { total / games_played as f64 };
+
+    // ELO each team gained or lost in each game, games against itself don't move ELO
+    let elo_changes = games
+        .iter()
+        .filter(|g| g.team1_id != g.team2_id)
+        .flat_map(|g| [g.team1_elo, g.team2_elo])
+        .collect::<Vec<i32>>();
+    let avg_elo_change = if elo_changes.is_empty() {
+        0.0
+    } else {
+        elo_changes.iter().map(|c| c.abs() as f64).sum::<f64>() / elo_changes.len() as f64
+    };
+
+    upsert_round_stats(NewRoundStats {
+        competition_id: competition.id.clone(),
+        round: competition.round,
+        games_played: games_played as i32,
+        failed_matches: failed_matches as i32,
+        // crashed games carry the error of the bot that was blamed
+        crashed_games: games.iter().filter(|g| !g.additional_data.is_empty()).count() as i32,
+        avg_duration_ms: average(games.iter().map(|g| g.duration_ms as f64).sum()) as i64,
+        avg_score: average(games.iter().map(|g| (g.team1_score + g.team2_score) as f64 / 2.0).sum()),
+        biggest_elo_swing: games
+            .iter()
+            .map(|g| g.team1_elo.abs().max(g.team2_elo.abs()))
+            .max()
+            .unwrap_or(0),
+        // a game that runs into its timeout is killed and scored as it stands
+        timeouts: games
+            .iter()
+            .filter(|g| g.timeout_secs > 0 && g.duration_ms >= g.timeout_secs as i64 * 1000)
+            .count() as i32,
+        compile_failures: compile_failures as i32,
+        avg_elo_change,
+        biggest_elo_gain: elo_changes.iter().copied().max().unwrap_or(0).max(0),
+        biggest_elo_loss: -elo_changes.iter().copied().min().unwrap_or(0).min(0),
+    })
+}
\ No newline at end of file
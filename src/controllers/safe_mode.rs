@@ -0,0 +1,23 @@
+use std::{env, sync::atomic::{AtomicBool, Ordering}};
+
+use crate::config::settings;
+
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Reads the safe mode setting at startup, from the `--safe-mode` flag or the server's
+/// `safe_mode` setting (`SAFE_MODE=1` or `true` in the environment).
+///
+/// In safe mode the API (results, standings, admin) stays available, but the round
+/// scheduler doesn't start and no games are played or bots compiled, so operators can
+/// inspect and repair state after an incident without new matches interfering.
+pub fn init_safe_mode() -> bool {
+    let from_flag = env::args().any(|a| a == "--safe-mode");
+    let safe_mode = from_flag || settings().server.safe_mode;
+    SAFE_MODE.store(safe_mode, Ordering::SeqCst);
+    safe_mode
+}
+
+/// Returns whether game and compile execution is disabled.
+pub fn is_safe_mode() -> bool {
+    SAFE_MODE.load(Ordering::SeqCst)
+}
\ No newline at end of file
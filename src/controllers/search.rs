@@ -0,0 +1,90 @@
+use diesel::result::Error;
+
+use crate::{
+    db::operations_search::{search_competitions, search_teams, search_users},
+    models::{
+        competition::PublicCompetition,
+        search::{CompetitionSearchHit, SearchRank, SearchResults, TeamSearchHit, UserSearchHit},
+        team::PublicTeam,
+        user::PublicUser,
+    },
+};
+
+/// Matches loaded of each kind before ranking, so an exact match isn't cut off by prefix
+/// matches that sort before it alphabetically.
+const SEARCH_CANDIDATES: i64 = 200;
+
+/// Searches teams, users and competitions for the search bar.
+///
+/// Names are matched on their start, ignoring case. Exact matches rank above prefix matches,
+/// ties go to the shorter name and then alphabetically. Teams are also found through their
+/// owner or partner, those rank below teams matched by name.
+///
+/// # Arguments
+///
+/// * `term` - What was typed in the search bar.
+/// * `competition_id` - Only search the teams of this competition.
+/// * `limit` - Results of each kind.
+///
+pub fn search(term: &str, competition_id: Option<String>, limit: i64) -> Result<SearchResults, Error> {
+    let term = term.trim().to_lowercase();
+
+    let mut users = search_users(&term, SEARCH_CANDIDATES)?
+        .into_iter()
+        .map(|u| {
+            let rank = if u.username.to_lowercase() == term || u.display_name.to_lowercase() == term {
+                SearchRank::Exact
+            } else {
+                SearchRank::Prefix
+            };
+            (rank, u)
+        })
+        .collect::<Vec<_>>();
+    let member_ids = users.iter().map(|(_, u)| u.id.clone()).collect::<Vec<String>>();
+    users.sort_by(|(r1, u1), (r2, u2)| r1.cmp(r2).then(by_name(&u1.username, &u2.username)));
+
+    let mut teams = search_teams(&term, member_ids, competition_id, SEARCH_CANDIDATES)?
+        .into_iter()
+        .map(|t| (name_rank(&t.name, &term).unwrap_or(SearchRank::Member), t))
+        .collect::<Vec<_>>();
+    teams.sort_by(|(r1, t1), (r2, t2)| r1.cmp(r2).then(by_name(&t1.name, &t2.name)));
+
+    let mut competitions = search_competitions(&term, SEARCH_CANDIDATES)?
+        .into_iter()
+        .map(|c| (name_rank(&c.name, &term).unwrap_or(SearchRank::Prefix), c))
+        .collect::<Vec<_>>();
+    competitions.sort_by(|(r1, c1), (r2, c2)| r1.cmp(r2).then(by_name(&c1.name, &c2.name)));
+
+    Ok(SearchResults {
+        teams: teams
+            .into_iter()
+            .take(limit as usize)
+            .map(|(rank, t)| TeamSearchHit { team: PublicTeam::from(t), rank })
+            .collect(),
+        users: users
+            .into_iter()
+            .take(limit as usize)
+            .map(|(rank, u)| UserSearchHit { user: PublicUser::from(u), rank })
+            .collect(),
+        competitions: competitions
+            .into_iter()
+            .take(limit as usize)
+            .map(|(rank, c)| CompetitionSearchHit { competition: PublicCompetition::from(c), rank })
+            .collect(),
+    })
+}
+
+fn name_rank(name: &str, term: &str) -> Option<SearchRank> {
+    let name = name.to_lowercase();
+    if name == term {
+        Some(SearchRank::Exact)
+    } else if name.starts_with(term) {
+        Some(SearchRank::Prefix)
+    } else {
+        None
+    }
+}
+
+fn by_name(a: &str, b: &str) -> std::cmp::Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    controllers::leaderboard::build_leaderboard,
+    db::{
+        operations_competition::get_competitions_by_ids,
+        operations_seasons::get_season_competition_ids,
+        operations_teams::get_active_teams_by_competition_id,
+        operations_users::get_users_by_ids,
+    },
+    models::{
+        season::{Season, SeasonPlacement, SeasonStanding, SeasonStandings, PublicSeason, SEASON_PLACEMENT_POINTS},
+        team::Team,
+    },
+};
+
+/// Computes the standings of a season.
+///
+/// Every competition of the season is ranked by its own leaderboard, the placement of a team
+/// is worth `SEASON_PLACEMENT_POINTS` and both members of the team get them. Students are
+/// ranked by the sum of their points; students with equal points share a rank.
+///
+pub fn build_season_standings(season: Season) -> Result<SeasonStandings, Error> {
+    let competition_ids = get_season_competition_ids(season.id.clone())?;
+    let competitions = get_competitions_by_ids(competition_ids.clone())?;
+
+    let mut placements: HashMap<String, Vec<SeasonPlacement>> = HashMap::new();
+    for competition in competitions.iter() {
+        let teams: HashMap<String, Team> = get_active_teams_by_competition_id(competition.id.clone())?
+            .into_iter()
+            .map(|t| (t.id.clone(), t))
+            .collect();
+
+        for entry in build_leaderboard(competition)?.into_iter() {
+            let team = match teams.get(&entry.team_id) {
+                Some(t) => t,
+                None => continue,
+            };
+            let placement = SeasonPlacement {
+                competition_id: competition.id.clone(),
+                team_id: entry.team_id,
+                team_name: entry.team_name,
+                rank: entry.rank,
+                points: SEASON_PLACEMENT_POINTS.get(entry.rank.saturating_sub(1)).copied().unwrap_or(0),
+            };
+            for member in [&team.owner, &team.partner] {
+                if !member.is_empty() {
+                    placements.entry(member.clone()).or_default().push(placement.clone());
+                }
+            }
+        }
+    }
+
+    let users = get_users_by_ids(placements.keys().cloned().collect())?;
+    let mut standings: Vec<SeasonStanding> = users
+        .into_iter()
+        .map(|u| {
+            let user_placements = placements.remove(&u.id).unwrap_or_default();
+            SeasonStanding {
+                rank: 0,
+                points: user_placements.iter().map(|p| p.points).sum(),
+                user_id: u.id,
+                username: u.username,
+                display_name: u.display_name,
+                placements: user_placements,
+            }
+        })
+        .collect();
+
+    standings.sort_by(|a, b| b.points.cmp(&a.points).then_with(|| a.username.cmp(&b.username)));
+    for i in 0..standings.len() {
+        standings[i].rank = if i > 0 && standings[i].points == standings[i - 1].points {
+            standings[i - 1].rank
+        } else {
+            i + 1
+        };
+    }
+
+    Ok(SeasonStandings {
+        season: PublicSeason::new(season, competition_ids),
+        standings,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, fs, path::Path, sync::{Mutex, atomic::{AtomicBool, Ordering}}, time::{Duration, Instant}};
+
+use once_cell::sync::Lazy;
+use tracing::{error, info, warn};
+
+use crate::config::settings;
+
+use super::workload_gate::workload_status;
+
+/// How long interrupted rounds get to store their progress once their games are stopped.
+const CHECKPOINT_WAIT_SECS: u64 = 30;
+
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Process groups of the running Evaluators.
+static GAME_PROCESSES: Lazy<Mutex<HashMap<i32, GameProcess>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct GameProcess {
+    /// folder holding the game's bots, the Evaluator is started with paths inside it
+    workspace: String,
+    /// whether shutdown killed the game
+    killed: bool,
+}
+
+/// Keeps a game's process group tracked while the game runs. Dropping it kills whatever is
+/// left of the group, the players an Evaluator started don't always exit with it.
+pub struct GameProcessGuard {
+    pgid: i32,
+}
+
+impl Drop for GameProcessGuard {
+    fn drop(&mut self) {
+        let mut processes = GAME_PROCESSES.lock().unwrap();
+        processes.remove(&self.pgid);
+        write_game_processes(&processes);
+        // negative pid targets the whole process group
+        unsafe { libc::kill(-self.pgid, libc::SIGKILL); }
+    }
+}
+
+/// Returns whether the server is shutting down, no new games start then.
+pub fn is_shutting_down() -> bool {
+    SHUTTING_DOWN.load(Ordering::SeqCst)
+}
+
+/// Tracks a game started in its own process group, the group id is the Evaluator's pid and
+/// `workspace` the folder its bots were copied to.
+pub fn track_game_process(pgid: i32, workspace: &Path) -> GameProcessGuard {
+    let mut processes = GAME_PROCESSES.lock().unwrap();
+    processes.insert(pgid, GameProcess { workspace: workspace.to_string_lossy().to_string(), killed: false });
+    write_game_processes(&processes);
+    GameProcessGuard { pgid }
+}
+
+/// Kills the games a crashed server left running, using the process groups it recorded.
+///
+/// A group is only killed if its leader is still the recorded game, a Java process started
+/// with bots from the recorded workspace; the id may have been reused by an unrelated process
+/// since.
+pub fn kill_orphaned_games() {
+    let recorded = match fs::read_to_string(&settings().paths.game_processes) {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+    for (pgid, workspace) in recorded.lines().filter_map(parse_game_process) {
+        let cmdline = fs::read(format!("/proc/{}/cmdline", pgid)).unwrap_or_default();
+        let cmdline = String::from_utf8_lossy(&cmdline);
+        if cmdline.starts_with("java") && cmdline.contains(workspace) {
+            warn!("[SETUP] Killing game process group {} left by the previous run", pgid);
+            unsafe { libc::kill(-pgid, libc::SIGKILL); }
+        }
+    }
+    if let Err(e) = fs::remove_file(&settings().paths.game_processes) {
+        error!("Failed removing the recorded game processes: {:?}", e);
+    }
+}
+
+/// Reads a `<pgid> <workspace>` line of the recorded game processes.
+fn parse_game_process(line: &str) -> Option<(i32, &str)> {
+    let (pgid, workspace) = line.trim().split_once(' ')?;
+    let workspace = workspace.trim();
+    if workspace.is_empty() {
+        return None;
+    }
+    Some((pgid.parse().ok()?, workspace))
+}
+
+fn write_game_processes(processes: &HashMap<i32, GameProcess>) {
+    let recorded = processes
+        .iter()
+        .map(|(pgid, process)| format!("{} {}", pgid, process.workspace))
+        .collect::<Vec<String>>()
+        .join("\n");
+    if let Err(e) = fs::write(&settings().paths.game_processes, recorded) {
+        error!("Failed recording the running game processes: {:?}", e);
+    }
+}
+
+/// Whether the game of the given process group was killed because the server shut down.
+pub fn killed_by_shutdown(pgid: i32) -> bool {
+    GAME_PROCESSES.lock().unwrap().get(&pgid).map(|p| p.killed).unwrap_or(false)
+}
+
+/// Stops the matchmaker after the HTTP server stopped.
+///
+/// New games are refused right away, running games get `shutdown_drain_secs` to finish and
+/// are killed after that. Rounds cut short store the games they finished and the matches
+/// they still have to play, the next run of the round resumes from there.
+pub async fn drain_matches() {
+    SHUTTING_DOWN.store(true, Ordering::SeqCst);
+
+    let drain_secs = settings().timeouts.shutdown_drain_secs;
+    let running = GAME_PROCESSES.lock().unwrap().len();
+    info!("[SHUTDOWN] Waiting up to {}s for {} running games", drain_secs, running);
+
+    let deadline = Instant::now() + Duration::from_secs(drain_secs);
+    while !GAME_PROCESSES.lock().unwrap().is_empty() && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    {
+        let mut processes = GAME_PROCESSES.lock().unwrap();
+        if !processes.is_empty() {
+            warn!("[SHUTDOWN] Killing {} games that didn't finish in time", processes.len());
+        }
+        for (pgid, process) in processes.iter_mut() {
+            process.killed = true;
+            unsafe { libc::kill(-*pgid, libc::SIGKILL); }
+        }
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(CHECKPOINT_WAIT_SECS);
+    while workload_status().ranked_rounds_running > 0 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    info!("[SHUTDOWN] Matchmaker stopped");
+}
\ No newline at end of file
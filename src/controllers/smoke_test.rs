@@ -0,0 +1,138 @@
+use std::io;
+
+use tracing::warn;
+
+use crate::{
+    adapters::adapter_for_competition,
+    config::settings,
+    db::{operations_bot::set_bot_smoke_test, operations_competition::get_competition_by_id, operations_teams::get_team_by_id},
+    models::{
+        bot::Bot,
+        errors::MatchMakerError,
+        game_2v2::{NewGame2v2, UnrankedGameOutput},
+        game_player_stats::{FailureKind, GameError},
+        smoke_test::SmokeTestStatus,
+    },
+    parsers::replay::decode_frames,
+};
+
+use super::matchmaker_2v2::{compile_bot, play_unranked_game_within};
+
+/// Team id the idle bots play under in smoke tests.
+const IDLE_TEAM_ID: &str = "idle";
+/// Turns the bot has to get through against the idle bots.
+const SMOKE_TEST_TURNS: usize = 20;
+/// The test game is killed after this long, a bot that didn't play its turns by then timed out.
+const SMOKE_TEST_TIMEOUT_SECS: i32 = 60;
+/// Colors of team 1, which the tested bot plays.
+const BOT_COLORS: [&str; 2] = ["yellow", "green"];
+/// Longest error kept on the bot record.
+const MAX_MESSAGE_LEN: usize = 2000;
+
+/// Plays the first turns of a game of a freshly uploaded bot against idle bots and stores
+/// the verdict on the bot, so a bot that crashes or runs out of time right away is caught
+/// before it is put into a slot.
+///
+/// The bot plays both of team 1's slots, the configured `idle_bot` both of team
+/// 2's. The game isn't stored and is stopped once it played `SMOKE_TEST_TURNS` turns, or
+/// killed after `SMOKE_TEST_TIMEOUT_SECS` if it doesn't get that far. If the test
+/// can't run (no idle bot installed, safe mode, the bot doesn't compile) the verdict is
+/// `Skipped`. The caller is expected to hold an unranked workload slot.
+///
+/// # Returns
+///
+/// The verdict, as it was stored.
+///
+pub fn smoke_test_bot(bot: &Bot) -> (SmokeTestStatus, String) {
+    let (status, message) = match play_smoke_test(bot) {
+        Ok(verdict) => verdict,
+        Err(e) => (SmokeTestStatus::Skipped, format!("Smoke test couldn't run: {}", e.to_string().trim())),
+    };
+    if let Err(e) = set_bot_smoke_test(bot.id.clone(), status, message.clone()) {
+        warn!("Failed to store the smoke test verdict of bot {}: {}", bot.id, e);
+    }
+    (status, message)
+}
+
+/// Why a bot can't be put into a slot because of its smoke test, `None` if it can.
+pub fn smoke_test_blocks_slot_reason(bot: &Bot) -> Option<String> {
+    if !bot.smoke_test.blocks_slot() {
+        return None;
+    }
+    match bot.smoke_test {
+        SmokeTestStatus::Pending => Some("Bot's smoke test hasn't finished yet".to_string()),
+        _ => Some(format!("Bot failed its smoke test: {}", bot.smoke_test_message)),
+    }
+}
+
+fn play_smoke_test(bot: &Bot) -> Result<(SmokeTestStatus, String), MatchMakerError> {
+    let team = get_team_by_id(bot.team_id.clone()).map_err(MatchMakerError::DatabaseError)?;
+    let competition = get_competition_by_id(team.competition_id.clone())
+        .map_err(MatchMakerError::DatabaseError)?;
+    let idle_bot = settings().paths.idle_bot.as_path();
+    if !idle_bot.is_dir() {
+        return Err(MatchMakerError::IOError(io::Error::new(io::ErrorKind::NotFound, "No idle bot installed")));
+    }
+    let adapter = adapter_for_competition(&competition)?;
+
+    let bot_folder = settings().paths.bots_workdir.join(&bot.id);
+    if !bot_folder.is_dir() {
+        compile_bot(bot)?;
+    }
+
+    let mut match_game = NewGame2v2::new(
+        competition.id.clone(),
+        competition.round,
+        team.id.clone(),
+        IDLE_TEAM_ID.to_string(),
+        bot.id.clone(),
+        bot.id.clone(),
+        IDLE_TEAM_ID.to_string(),
+        IDLE_TEAM_ID.to_string(),
+    );
+    let sources = vec![bot_folder.clone(), bot_folder, idle_bot.to_path_buf(), idle_bot.to_path_buf()];
+    let played = play_unranked_game_within(adapter.as_ref(), &mut match_game, sources, SMOKE_TEST_TIMEOUT_SECS, Some(SMOKE_TEST_TURNS))?;
+    Ok(verdict(bot, &match_game, &played))
+}
+
+/// Judges the first `SMOKE_TEST_TURNS` turns of the test game.
+fn verdict(bot: &Bot, match_game: &NewGame2v2, played: &UnrankedGameOutput) -> (SmokeTestStatus, String) {
+    // a crashed game has its error recorded the way ranked games do
+    if played.errors.len() > 1 {
+        let error: GameError = serde_json::from_str(&match_game.additional_data).unwrap_or_default();
+        if error.blame_id != bot.id {
+            return (SmokeTestStatus::Skipped, "Test game failed without the bot to blame".to_string());
+        }
+        let status = match error.failure_kind {
+            FailureKind::BotTimeout => SmokeTestStatus::TimedOut,
+            _ => SmokeTestStatus::Crashed,
+        };
+        return (status, truncate(error.error));
+    }
+
+    let frames = decode_frames(&played.replay);
+    let eliminated = frames
+        .iter()
+        .take(SMOKE_TEST_TURNS)
+        .position(|frame| frame.eliminated.iter().any(|color| BOT_COLORS.contains(&color.as_str())));
+    if let Some(turn) = eliminated {
+        // idle bots don't attack, the Evaluator only eliminates a bot that broke down
+        return (SmokeTestStatus::Crashed, format!("Bot was eliminated on turn {} against idle bots", turn + 1));
+    }
+
+    // a game that ends early because the bot beat the idle bots is fine
+    if frames.len() < SMOKE_TEST_TURNS && match_game.winner_id != bot.team_id {
+        return (
+            SmokeTestStatus::TimedOut,
+            format!("Bot played {} of {} turns within {}s", frames.len(), SMOKE_TEST_TURNS, SMOKE_TEST_TIMEOUT_SECS),
+        );
+    }
+    (SmokeTestStatus::Passed, format!("Played {} turns without errors", frames.len().min(SMOKE_TEST_TURNS)))
+}
+
+fn truncate(message: String) -> String {
+    match message.char_indices().nth(MAX_MESSAGE_LEN) {
+        Some((end, _)) => message[..end].to_string(),
+        None => message,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::{fs, path::Path};
+
+use crate::config::settings;
+
+/// Java APIs bots can't use: running processes, networking, reflection and file I/O.
+/// Bots talk to the game over stdin/stdout only, so none of these are needed to play.
+pub const DEFAULT_FORBIDDEN_APIS: [&str; 19] = [
+    "Runtime.getRuntime",
+    "ProcessBuilder",
+    "ProcessHandle",
+    "java.net.",
+    "java.nio.channels",
+    "java.lang.reflect",
+    "Class.forName",
+    "getDeclaredMethod",
+    "getDeclaredField",
+    "setAccessible",
+    "java.lang.invoke",
+    "new File(",
+    "FileInputStream",
+    "FileOutputStream",
+    "FileReader",
+    "FileWriter",
+    "RandomAccessFile",
+    "java.nio.file",
+    "System.exit",
+];
+
+/// Patterns bot sources are scanned for before they are compiled.
+///
+/// These apply to every competition and reject a bot outright, unlike a competition's own
+/// `forbidden_apis` validation rule which flags active bots with a grace period.
+///
+/// The configured `forbidden_apis` file replaces the default list when present, one pattern
+/// per line, `#` starts a comment.
+pub fn forbidden_api_rules() -> Vec<String> {
+    match fs::read_to_string(&settings().paths.forbidden_apis) {
+        Ok(contents) => contents
+            .lines()
+            .map(|l| l.split('#').next().unwrap_or_default().trim())
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(_) => DEFAULT_FORBIDDEN_APIS.iter().map(|a| a.to_string()).collect(),
+    }
+}
+
+/// Scans a single source file for forbidden APIs, lines that are comments are skipped.
+///
+/// # Returns
+///
+/// A `<file>:<line> uses <api>` entry for every hit.
+///
+pub fn scan_source(file_name: &str, source: &str, rules: &[String]) -> Vec<String> {
+    let mut violations = vec![];
+    for (number, line) in source.lines().enumerate() {
+        let code = line.trim_start();
+        if code.starts_with("//") || code.starts_with('*') || code.starts_with("/*") {
+            continue;
+        }
+        for api in rules.iter() {
+            if code.contains(api.as_str()) {
+                violations.push(format!("{}:{} uses the forbidden API {}", file_name, number + 1, api));
+            }
+        }
+    }
+    violations
+}
+
+/// Scans source files on disk, see `scan_source`.
+pub fn scan_files(files: &[String], rules: &[String]) -> std::io::Result<Vec<String>> {
+    let mut violations = vec![];
+    for file in files.iter() {
+        let source = fs::read_to_string(file)?;
+        let file_name = Path::new(file)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.clone());
+        violations.extend(scan_source(&file_name, &source, rules));
+    }
+    Ok(violations)
+}
\ No newline at end of file
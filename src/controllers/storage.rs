@@ -0,0 +1,279 @@
+use std::{env, fs, path::Path, time::Duration};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{config::{settings, StorageBackend, StorageSettings}, models::errors::MatchMakerError};
+
+use super::command_executor::execute_command_with_timeout;
+
+/// How long a single upload or download to the object store may take.
+const S3_TIMEOUT_SECS: u64 = 300;
+
+/// Where replays and bot archives are kept.
+///
+/// Files are addressed by a key, which is the path the file has (or would have) on the local
+/// disk, e.g. a game's `log_file_path` or a bot's `source_path`. Everything that needs the
+/// file as an actual file (javac, unzip, sha256sum) works on a local copy at that same path.
+pub trait Storage: Send + Sync {
+    /// Stores the local file under the key.
+    fn put(&self, key: &str, local: &Path) -> Result<(), MatchMakerError>;
+    /// Contents of the file stored under the key.
+    fn get(&self, key: &str) -> Result<Vec<u8>, MatchMakerError>;
+    /// Writes the file stored under the key to a local path.
+    fn fetch(&self, key: &str, local: &Path) -> Result<(), MatchMakerError>;
+    /// Removes the file, a missing file is not an error.
+    fn delete(&self, key: &str) -> Result<(), MatchMakerError>;
+    fn exists(&self, key: &str) -> Result<bool, MatchMakerError>;
+    /// A link clients can download the file from directly, `None` if they have to go through
+    /// the server.
+    fn presigned_url(&self, key: &str) -> Option<String>;
+    /// Whether the files live somewhere else than the local disk.
+    fn is_remote(&self) -> bool;
+}
+
+static STORAGE: Lazy<Box<dyn Storage>> = Lazy::new(|| {
+    let config = &settings().storage;
+    match config.backend {
+        StorageBackend::Local => Box::new(LocalStorage),
+        StorageBackend::S3 => Box::new(S3Storage::new(config)),
+    }
+});
+
+/// The configured storage backend.
+pub fn storage() -> &'static dyn Storage {
+    STORAGE.as_ref()
+}
+
+/// Keeps files where their key says, on the local disk.
+pub struct LocalStorage;
+
+impl Storage for LocalStorage {
+    fn put(&self, key: &str, local: &Path) -> Result<(), MatchMakerError> {
+        if Path::new(key) != local {
+            fs::copy(local, key).map_err(MatchMakerError::IOError)?;
+        }
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Vec<u8>, MatchMakerError> {
+        fs::read(key).map_err(MatchMakerError::IOError)
+    }
+
+    fn fetch(&self, key: &str, local: &Path) -> Result<(), MatchMakerError> {
+        if Path::new(key) != local {
+            fs::copy(key, local).map_err(MatchMakerError::IOError)?;
+        }
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), MatchMakerError> {
+        if key.is_empty() || !Path::new(key).exists() {
+            return Ok(());
+        }
+        fs::remove_file(key).map_err(MatchMakerError::IOError)
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, MatchMakerError> {
+        Ok(!key.is_empty() && Path::new(key).exists())
+    }
+
+    fn presigned_url(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_remote(&self) -> bool {
+        false
+    }
+}
+
+/// Keeps files in a bucket of an S3-compatible object store (AWS, MinIO, Garage, ...).
+///
+/// The transfers are made by curl like the webhook deliveries, the requests are signed here
+/// with AWS Signature V4 so the secret key never shows up in a process list.
+pub struct S3Storage {
+    /// scheme and host, without a trailing slash
+    endpoint: String,
+    host: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+    presign_secs: u64,
+}
+
+impl S3Storage {
+    pub fn new(config: &StorageSettings) -> Self {
+        let endpoint = config.endpoint.trim_end_matches('/').to_string();
+        let host = endpoint
+            .split_once("://")
+            .map(|(_, host)| host)
+            .unwrap_or(&endpoint)
+            .to_string();
+        Self {
+            endpoint,
+            host,
+            bucket: config.bucket.clone(),
+            region: config.region.clone(),
+            access_key: config.access_key.clone(),
+            secret_key: config.secret_key.clone(),
+            presign_secs: config.presign_secs,
+        }
+    }
+
+    /// Path of the object, relative keys like `./resources/games/3/x.zip` lose the `./`.
+    fn object_path(&self, key: &str) -> String {
+        let key = key.trim_start_matches("./").trim_start_matches('/');
+        format!("/{}/{}", uri_encode(&self.bucket, true), uri_encode(key, false))
+    }
+
+    fn scope(&self, now: &DateTime<Utc>) -> String {
+        format!("{}/{}/s3/aws4_request", now.format("%Y%m%d"), self.region)
+    }
+
+    fn signature(&self, now: &DateTime<Utc>, canonical_request: &str) -> String {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            now.format("%Y%m%dT%H%M%SZ"),
+            self.scope(now),
+            hex::encode(Sha256::digest(canonical_request.as_bytes())),
+        );
+        let date_key = hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), &now.format("%Y%m%d").to_string());
+        let region_key = hmac_sha256(&date_key, &self.region);
+        let service_key = hmac_sha256(&region_key, "s3");
+        let signing_key = hmac_sha256(&service_key, "aws4_request");
+        hex::encode(hmac_sha256(&signing_key, &string_to_sign))
+    }
+
+    /// Runs curl against the object with signed headers, the extra arguments come before the URL.
+    fn request(&self, method: &str, key: &str, extra_args: Vec<&str>) -> Result<Vec<String>, MatchMakerError> {
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let path = self.object_path(key);
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:UNSIGNED-PAYLOAD\nx-amz-date:{}\n\nhost;x-amz-content-sha256;x-amz-date\nUNSIGNED-PAYLOAD",
+            method, path, self.host, amz_date,
+        );
+        let authorization = format!(
+            "Authorization: AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature={}",
+            self.access_key,
+            self.scope(&now),
+            self.signature(&now, &canonical_request),
+        );
+        let date_header = format!("x-amz-date: {}", amz_date);
+        let url = format!("{}{}", self.endpoint, path);
+        let max_time = S3_TIMEOUT_SECS.to_string();
+
+        let mut args = vec![
+            "-sS",
+            "-X", method,
+            "-H", &authorization,
+            "-H", "x-amz-content-sha256: UNSIGNED-PAYLOAD",
+            "-H", &date_header,
+            "--max-time", &max_time,
+        ];
+        args.extend(extra_args);
+        args.push(&url);
+
+        let out = execute_command_with_timeout(
+            "curl".to_string(),
+            args,
+            vec![],
+            // give curl the chance to report its own timeout first
+            Duration::from_secs(S3_TIMEOUT_SECS + 5),
+        ).map_err(MatchMakerError::IOError)?;
+        if !out.status.map(|s| s.success()).unwrap_or(false) {
+            return Err(MatchMakerError::StorageError(format!("{} {} failed: {}", method, key, out.stderr.join("\n"))));
+        }
+        Ok(out.stdout)
+    }
+}
+
+impl Storage for S3Storage {
+    fn put(&self, key: &str, local: &Path) -> Result<(), MatchMakerError> {
+        let local = local.to_string_lossy();
+        self.request("PUT", key, vec!["--fail", "-T", &local])?;
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Vec<u8>, MatchMakerError> {
+        let download = env::temp_dir().join(format!("batalja-{}", Uuid::new_v4()));
+        let result = self.fetch(key, &download).and_then(|_| fs::read(&download).map_err(MatchMakerError::IOError));
+        let _ = fs::remove_file(&download);
+        result
+    }
+
+    fn fetch(&self, key: &str, local: &Path) -> Result<(), MatchMakerError> {
+        if let Some(parent) = local.parent() {
+            fs::create_dir_all(parent).map_err(MatchMakerError::IOError)?;
+        }
+        let local = local.to_string_lossy();
+        self.request("GET", key, vec!["--fail", "-o", &local])?;
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), MatchMakerError> {
+        if key.is_empty() {
+            return Ok(());
+        }
+        // S3 answers 204 whether the object existed or not
+        self.request("DELETE", key, vec!["--fail"])?;
+        Ok(())
+    }
+
+    fn exists(&self, key: &str) -> Result<bool, MatchMakerError> {
+        if key.is_empty() {
+            return Ok(false);
+        }
+        let status = self.request("HEAD", key, vec!["-I", "-o", "/dev/null", "-w", "%{http_code}"])?;
+        match status.first().map(|s| s.trim()) {
+            Some("200") => Ok(true),
+            Some("404") => Ok(false),
+            other => Err(MatchMakerError::StorageError(format!("HEAD {} answered {:?}", key, other))),
+        }
+    }
+
+    fn presigned_url(&self, key: &str) -> Option<String> {
+        let now = Utc::now();
+        let path = self.object_path(key);
+        let credential = format!("{}/{}", self.access_key, self.scope(&now));
+        // the parameters have to be in this (alphabetical) order for the signature
+        let query = format!(
+            "X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential={}&X-Amz-Date={}&X-Amz-Expires={}&X-Amz-SignedHeaders=host",
+            uri_encode(&credential, true),
+            now.format("%Y%m%dT%H%M%SZ"),
+            self.presign_secs,
+        );
+        let canonical_request = format!(
+            "GET\n{}\n{}\nhost:{}\n\nhost\nUNSIGNED-PAYLOAD",
+            path, query, self.host,
+        );
+        Some(format!(
+            "{}{}?{}&X-Amz-Signature={}",
+            self.endpoint, path, query, self.signature(&now, &canonical_request),
+        ))
+    }
+
+    fn is_remote(&self) -> bool {
+        true
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent-encoding as SigV4 wants it, everything but the unreserved characters, `/` is kept
+/// in object paths.
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    value.bytes().map(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+        b'/' if !encode_slash => "/".to_string(),
+        _ => format!("%{:02X}", b),
+    }).collect()
+}
\ No newline at end of file
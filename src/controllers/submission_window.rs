@@ -0,0 +1,122 @@
+use chrono::{Duration, Local, NaiveDateTime, Timelike};
+use tracing::{error, info};
+
+use crate::{
+    db::operations_competition::{get_all_competitions, set_competition_allowed_submissions},
+    models::{competition::Competition, submission_lock::SubmissionLock},
+};
+
+use super::competitions::has_rounds;
+
+/// Returns why a competition doesn't accept submissions at `now`, `None` if it does.
+///
+/// Submissions are accepted between the competition's `start` and `end`, except during the
+/// competition's freeze window: the `submission_freeze_minutes` before each round. Rounds
+/// are played at every full hour, see the scheduler in `main.rs`.
+///
+pub fn submissions_closed_reason(competition: &Competition, now: NaiveDateTime) -> Option<String> {
+    if competition.archived {
+        return Some(format!("{} is archived", competition.name));
+    }
+    if now < competition.start {
+        return Some(format!("Submissions open at {}", competition.start));
+    }
+    if now > competition.end {
+        return Some(format!("Competition ended at {}", competition.end));
+    }
+
+    let next_round = next_round_start(now);
+    if competition.submission_freeze_minutes > 0 && now >= submission_cutoff(competition, next_round) {
+        return Some(format!("Submissions are frozen until the round at {}", next_round));
+    }
+    None
+}
+
+/// Returns why the bots in a team's slots can't be changed at `now`, `None` if they can.
+///
+/// Slots can be changed while the competition accepts submissions, so a slot change can't
+/// sneak a bot into a round after its cutoff. An admin closing submissions closes them too.
+pub fn slot_changes_closed_reason(competition: &Competition, now: NaiveDateTime) -> Option<String> {
+    submissions_closed_reason(competition, now).or_else(|| {
+        (!competition.allowed_submissions).then(|| format!("{} doesn't accept submissions", competition.name))
+    })
+}
+
+/// Sets `allowed_submissions` of every competition to whether it currently accepts
+/// submissions, so the flag the frontend reads follows the start, end and freeze window.
+pub fn sync_submission_windows() {
+    let competitions = match get_all_competitions() {
+        Ok(c) => c,
+        Err(e) => {
+            error!("Failed fetching competitions for the submission windows: {:?}", e);
+            return;
+        }
+    };
+
+    let now = Local::now().naive_utc();
+    for competition in competitions.iter() {
+        let allowed = submissions_closed_reason(competition, now).is_none();
+        if allowed == competition.allowed_submissions {
+            continue;
+        }
+        match set_competition_allowed_submissions(competition.id.clone(), allowed) {
+            Ok(_) => info!("Submissions for {} are now {}", competition.name, if allowed { "open" } else { "closed" }),
+            Err(e) => error!("Failed toggling submissions for {}: {:?}", competition.id, e),
+        }
+    }
+}
+
+/// The competition's next round and its submission cutoff as seen at `now`, for countdowns.
+pub fn submission_lock(competition: &Competition, now: NaiveDateTime) -> SubmissionLock {
+    let next_round = upcoming_round(competition, now);
+    let cutoff = next_round.map(|r| submission_cutoff(competition, r));
+    let closed_reason = submissions_closed_reason(competition, now);
+    SubmissionLock {
+        competition_id: competition.id.clone(),
+        next_round,
+        submission_cutoff: cutoff,
+        seconds_until_round: next_round.map(|r| (r - now).num_seconds()),
+        seconds_until_cutoff: cutoff.map(|c| (c - now).num_seconds().max(0)),
+        submissions_open: closed_reason.is_none(),
+        closed_reason: closed_reason.unwrap_or_default(),
+        server_time: now,
+    }
+}
+
+/// Last moment a bot change counts for the round scheduled at `round_start`: the start of
+/// the competition's freeze window, or the round itself without one.
+pub fn submission_cutoff(competition: &Competition, round_start: NaiveDateTime) -> NaiveDateTime {
+    round_start - Duration::minutes(competition.submission_freeze_minutes as i64)
+}
+
+/// The full hour a round running at `now` was scheduled for. A round the job worker picks up
+/// late still plays the bots of its scheduled time.
+pub fn scheduled_round_start(now: NaiveDateTime) -> NaiveDateTime {
+    now
+        .with_minute(0)
+        .and_then(|t| t.with_second(0))
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(now)
+}
+
+fn next_round_start(now: NaiveDateTime) -> NaiveDateTime {
+    scheduled_round_start(now) + Duration::hours(1)
+}
+
+/// The next round the scheduler queues for the competition, `None` if it won't play another.
+fn upcoming_round(competition: &Competition, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    if competition.archived || !has_rounds(&competition.type_) {
+        return None;
+    }
+    // the first round is the first full hour the competition is running at
+    let next_round = if now < competition.start {
+        let start_hour = scheduled_round_start(competition.start);
+        if start_hour == competition.start { start_hour } else { start_hour + Duration::hours(1) }
+    } else {
+        next_round_start(now)
+    };
+    if next_round > competition.end {
+        return None;
+    }
+    Some(next_round)
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::{db::operations_competition::get_competition_by_id, models::competition::Competition};
+
+/// Longest team name that's accepted when creating or renaming a team.
+pub const MAX_TEAM_NAME_LENGTH: usize = 32;
+
+/// Returns why the teams of a competition can't be changed anymore, `None` if they can.
+///
+/// Teams can be renamed, joined, left, kicked from and disbanded until the first round of
+/// the competition has been played. Afterwards the roster and name stay as they were ranked.
+pub fn team_changes_closed_reason(competition: &Competition) -> Option<String> {
+    if competition.archived {
+        return Some(format!("{} is archived", competition.name));
+    }
+    if competition.round >= 1 {
+        return Some(format!("Teams of {} are locked since the first round was played", competition.name));
+    }
+    None
+}
+
+/// Same as `team_changes_closed_reason`, looking the competition up by id.
+pub fn team_changes_closed_reason_by_id(competition_id: String) -> Result<Option<String>, diesel::result::Error> {
+    let competition = get_competition_by_id(competition_id)?;
+    Ok(team_changes_closed_reason(&competition))
+}
+
+/// Trims a team name and checks that it's usable.
+pub fn validate_team_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Team name can't be empty".to_string());
+    }
+    if name.chars().count() > MAX_TEAM_NAME_LENGTH {
+        return Err(format!("Team name can have at most {} characters", MAX_TEAM_NAME_LENGTH));
+    }
+    Ok(name.to_string())
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use chrono::{Local, NaiveDateTime};
+use uuid::Uuid;
+use tracing::error;
+
+use crate::{
+    db::{
+        operations_trace_spans::{insert_trace_span, get_trace_spans_by_trace_id},
+        operations_round_events::get_round_events_by_trace_id,
+        operations_game2v2::get_games_by_trace_id,
+    },
+    models::{
+        trace_span::{NewTraceSpan, PublicTraceSpan, TraceTimeline},
+        round_event::PublicRoundEvent,
+        game_2v2::PublicGame2v2,
+    },
+};
+
+/// Header used to pass a trace id into a request and to return it to the caller.
+pub const TRACE_HEADER: &str = "X-Trace-Id";
+
+/// Identifies everything that happens as part of one round run: the spans of its steps and
+/// matches, the games it wrote and the round events it emitted.
+#[derive(Debug, Clone)]
+pub struct TraceContext {
+    pub trace_id: String,
+}
+
+/// A timed step of a trace, recorded when finished.
+pub struct Span {
+    trace_id: String,
+    competition_id: String,
+    name: String,
+    started: NaiveDateTime,
+}
+
+impl TraceContext {
+    pub fn new() -> Self {
+        Self { trace_id: Uuid::new_v4().to_string() }
+    }
+
+    /// Continues the trace id given by the caller, or starts a new trace if there is none.
+    pub fn from_header(trace_id: Option<&str>) -> Self {
+        match trace_id.map(str::trim) {
+            Some(t) if !t.is_empty() && t.len() <= 255 => Self { trace_id: t.to_string() },
+            _ => Self::new(),
+        }
+    }
+
+    pub fn span(&self, competition_id: &str, name: &str) -> Span {
+        Span {
+            trace_id: self.trace_id.clone(),
+            competition_id: competition_id.to_string(),
+            name: name.to_string(),
+            started: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Span {
+    /// Records the span with the given status (`OK`, `FAILED`, ...) and details.
+    pub fn finish(self, status: &str, detail: String) {
+        if let Err(e) = insert_trace_span(NewTraceSpan {
+            trace_id: self.trace_id,
+            competition_id: self.competition_id,
+            name: self.name,
+            status: status.to_string(),
+            detail,
+            started: self.started,
+            finished: Local::now().naive_utc(),
+        }) {
+            error!("Failed recording trace span: {:?}", e);
+        }
+    }
+
+    /// Records the span as `OK` or `FAILED` depending on the result of the traced step.
+    pub fn finish_with<T, E: std::fmt::Debug>(self, result: &Result<T, E>) {
+        match result {
+            Ok(_) => self.finish("OK", "".to_string()),
+            Err(e) => self.finish("FAILED", format!("{:?}", e)),
+        }
+    }
+}
+
+/// Collects the spans, round events and games recorded under a trace id.
+pub fn get_trace_timeline(trace_id: String) -> Result<TraceTimeline, diesel::result::Error> {
+    let spans = get_trace_spans_by_trace_id(trace_id.clone())?;
+    let events = get_round_events_by_trace_id(trace_id.clone())?;
+    let games = get_games_by_trace_id(trace_id.clone())?;
+
+    Ok(TraceTimeline {
+        trace_id,
+        spans: spans.into_iter().map(PublicTraceSpan::from).collect(),
+        events: events.into_iter().map(PublicRoundEvent::from).collect(),
+        games: games.into_iter().map(PublicGame2v2::from).collect(),
+    })
+}
\ No newline at end of file
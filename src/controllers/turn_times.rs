@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+
+use crate::{
+    config::settings,
+    db::{
+        operations_bot::get_bots_by_ids,
+        operations_game2v2::get_games_by_competition_id,
+        operations_game_player_stats::get_game_player_stats_by_game_ids,
+        operations_teams::get_teams_by_competition_id,
+    },
+    models::{
+        game_player_stats::GamePlayerStatsRecord,
+        turn_time_report::{BotTurnTimes, TurnTimeReport},
+    },
+};
+
+/// A turn is close to the limit from this share of it on, in percent.
+const NEAR_LIMIT_PERCENT: u64 = 80;
+/// A bot is flagged once at least this share of its games came close to the limit.
+const ROUTINE_SHARE: f64 = 0.5;
+/// Games a bot needs before it can be flagged, so a single slow game doesn't.
+const MIN_GAMES: i32 = 3;
+
+/// Sums up the turn times of every bot that played in a competition and flags the bots whose
+/// 95th percentile turn routinely comes close to `timeouts.turn_limit_ms`.
+///
+/// Only games the game pack reported turn times for are counted.
+pub fn turn_time_report(competition_id: String) -> Result<TurnTimeReport, Error> {
+    let turn_limit_ms = settings().timeouts.turn_limit_ms;
+    let near_limit_ms = (turn_limit_ms * NEAR_LIMIT_PERCENT / 100) as i32;
+
+    let game_ids = get_games_by_competition_id(competition_id.clone())?
+        .into_iter()
+        .map(|g| g.id)
+        .collect::<Vec<String>>();
+    let mut per_bot: HashMap<String, Vec<GamePlayerStatsRecord>> = HashMap::new();
+    for stats in get_game_player_stats_by_game_ids(game_ids)?.into_iter().filter(|s| s.turns_timed > 0) {
+        per_bot.entry(stats.bot_id.clone()).or_default().push(stats);
+    }
+
+    let team_names = get_teams_by_competition_id(competition_id.clone())?
+        .into_iter()
+        .map(|t| (t.id, t.name))
+        .collect::<HashMap<String, String>>();
+    let bot_names = get_bots_by_ids(per_bot.keys().cloned().collect())?
+        .into_iter()
+        .map(|b| (b.id.clone(), b.public_name()))
+        .collect::<HashMap<String, String>>();
+
+    let mut bots = per_bot
+        .into_iter()
+        .map(|(bot_id, games)| {
+            let games_near_limit = games.iter().filter(|g| g.turn_time_p95_ms >= near_limit_ms).count() as i32;
+            let played = games.len() as i32;
+            let team_id = games[0].team_id.clone();
+            BotTurnTimes {
+                bot_name: bot_names.get(&bot_id).cloned().unwrap_or_default(),
+                team_name: team_names.get(&team_id).cloned().unwrap_or_default(),
+                typical_p50_ms: median(games.iter().map(|g| g.turn_time_p50_ms).collect()),
+                typical_p95_ms: median(games.iter().map(|g| g.turn_time_p95_ms).collect()),
+                max_ms: games.iter().map(|g| g.turn_time_max_ms).max().unwrap_or(0),
+                flagged: played >= MIN_GAMES && games_near_limit as f64 >= played as f64 * ROUTINE_SHARE,
+                games: played,
+                games_near_limit,
+                bot_id,
+                team_id,
+            }
+        })
+        .collect::<Vec<BotTurnTimes>>();
+    bots.sort_by_key(|b| (std::cmp::Reverse(b.flagged), std::cmp::Reverse(b.typical_p95_ms)));
+
+    Ok(TurnTimeReport {
+        competition_id,
+        turn_limit_ms: turn_limit_ms as i32,
+        near_limit_ms,
+        bots,
+    })
+}
+
+fn median(mut values: Vec<i32>) -> i32 {
+    if values.is_empty() {
+        return 0;
+    }
+    values.sort_unstable();
+    values[values.len() / 2]
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use std::{fs::{self, File}, io::Read, path::Path, time::Duration};
+
+use uuid::Uuid;
+use zip::ZipArchive;
+use tracing::error;
+
+use crate::{
+    config::settings,
+    db::operations_validation_rules::get_validation_rules_by_competition_id,
+    models::{errors::MatchMakerError, upload_validation::{UploadValidation, UploadValidationError, ValidationStage, DEFAULT_MAX_UPLOAD_KB}},
+};
+
+use super::{archive_safety::{extract_archive, inspect_archive}, command_executor::execute_command_with_timeout, revalidation::validate_archive, static_scan::{forbidden_api_rules, scan_source}};
+
+const SANDBOX_COMPILE_TIMEOUT_SECS: u64 = 60;
+
+/// Validates an uploaded bot archive before it is stored.
+///
+/// The pipeline stops at the first stage that fails:
+/// 1. The file has to be a readable ZIP archive that is safe to extract (see `archive_safety`).
+/// 2. It can't exceed the competition's `max_size_kb` rule (default `DEFAULT_MAX_UPLOAD_KB`).
+/// 3. It has to contain a `Player.java` with a main method or a manifest naming a
+///    `Main-Class`, at the root of the archive.
+/// 4. It can't use a forbidden API (see `static_scan`) and has to follow the competition's
+///    validation rules.
+/// 5. If `compile` is set, the sources are compiled in a throwaway sandbox directory.
+///
+/// # Arguments
+///
+/// * `zip_path` - Path of the uploaded archive.
+/// * `competition_id` - Competition the bot is uploaded to.
+/// * `compile` - Whether to run the compile stage.
+///
+pub fn validate_upload(zip_path: &Path, competition_id: &str, compile: bool) -> UploadValidation {
+    let error = |stage: ValidationStage, message: String| UploadValidationError { stage, message };
+
+    let mut archive = match File::open(zip_path).map(ZipArchive::new) {
+        Ok(Ok(a)) => a,
+        _ => return UploadValidation::new(false, vec![error(ValidationStage::Archive, "Uploaded file is not a valid ZIP file".to_string())]),
+    };
+
+    let rules = match get_validation_rules_by_competition_id(competition_id.to_string()) {
+        Ok(r) => r,
+        Err(e) => {
+            error!("Failed fetching validation rules: {:?}", e);
+            None
+        }
+    };
+
+    let max_size_kb = rules.as_ref().map(|r| r.max_size_kb).filter(|kb| *kb > 0).unwrap_or(DEFAULT_MAX_UPLOAD_KB);
+    let size = fs::metadata(zip_path).map(|m| m.len()).unwrap_or(0);
+    if size > max_size_kb as u64 * 1024 {
+        return UploadValidation::new(false, vec![error(
+            ValidationStage::Size,
+            format!("Archive is {} KB, the limit is {} KB", size / 1024, max_size_kb),
+        )]);
+    }
+
+    match inspect_archive(zip_path) {
+        Ok(problems) if !problems.is_empty() => {
+            let errors = problems.into_iter().map(|m| error(ValidationStage::Archive, m)).collect();
+            return UploadValidation::new(false, errors);
+        },
+        Ok(_) => {},
+        Err(e) => return UploadValidation::new(false, vec![error(ValidationStage::Archive, format!("Archive can't be read: {}", e))]),
+    }
+
+    let structure_errors = check_structure(&mut archive);
+    if !structure_errors.is_empty() {
+        let errors = structure_errors.into_iter().map(|m| error(ValidationStage::Structure, m)).collect();
+        return UploadValidation::new(false, errors);
+    }
+
+    let mut violations = match scan_archive(&mut archive) {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Failed scanning upload for forbidden APIs: {:?}", e);
+            vec![]
+        }
+    };
+    if let Some(rules) = rules.as_ref() {
+        match validate_archive(zip_path, rules) {
+            Ok(v) => violations.extend(v),
+            Err(e) => error!("Failed checking validation rules: {:?}", e),
+        }
+    }
+    if !violations.is_empty() {
+        let errors = violations.into_iter().map(|m| error(ValidationStage::Rules, m)).collect();
+        return UploadValidation::new(false, errors);
+    }
+
+    if !compile {
+        return UploadValidation::new(false, vec![]);
+    }
+    let errors = compile_in_sandbox(zip_path)
+        .into_iter()
+        .map(|m| error(ValidationStage::Compile, m))
+        .collect();
+    UploadValidation::new(true, errors)
+}
+
+/// Checks the archive has an entry point, returns what is missing.
+fn check_structure(archive: &mut ZipArchive<File>) -> Vec<String> {
+    let mut read_entry = |name: &str| -> Option<String> {
+        let mut entry = archive.by_name(name).ok()?;
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).ok()?;
+        Some(contents)
+    };
+
+    if let Some(player) = read_entry("Player.java") {
+        if player.contains("public static void main(") {
+            return vec![];
+        }
+        return vec!["Player.java has no main method".to_string()];
+    }
+
+    match read_entry("META-INF/MANIFEST.MF") {
+        Some(manifest) if manifest.lines().any(|l| l.starts_with("Main-Class:")) => vec![],
+        Some(_) => vec!["Manifest doesn't name a Main-Class".to_string()],
+        None => vec!["Archive contains neither Player.java nor a manifest at its root".to_string()],
+    }
+}
+
+/// Scans the Java sources in the archive for forbidden APIs.
+fn scan_archive(archive: &mut ZipArchive<File>) -> Result<Vec<String>, MatchMakerError> {
+    let rules = forbidden_api_rules();
+    let mut violations = vec![];
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).map_err(MatchMakerError::ZippingError)?;
+        if !entry.name().ends_with(".java") {
+            continue;
+        }
+        let name = entry.name().to_string();
+        let mut source = String::new();
+        if entry.read_to_string(&mut source).is_err() {
+            continue;
+        }
+        violations.extend(scan_source(&name, &source, &rules));
+    }
+    Ok(violations)
+}
+
+/// Unzips and compiles the archive in its own sandbox directory, which is removed afterwards.
+///
+/// # Returns
+///
+/// The compiler errors, empty if the bot compiled.
+fn compile_in_sandbox(zip_path: &Path) -> Vec<String> {
+    let sandbox = settings().paths.sandbox.join(Uuid::new_v4().to_string());
+    let errors = match compile_sources(zip_path, &sandbox) {
+        Ok(errors) => errors,
+        Err(e) => vec![format!("Compilation failed: {}", e)],
+    };
+    if let Err(e) = fs::remove_dir_all(&sandbox) {
+        error!("Failed removing sandbox {}: {}", sandbox.display(), e);
+    }
+    errors
+}
+
+fn compile_sources(zip_path: &Path, sandbox: &Path) -> std::io::Result<Vec<String>> {
+    fs::create_dir_all(sandbox)?;
+    let sandbox_str = sandbox.to_string_lossy().to_string();
+    extract_archive(zip_path, sandbox).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let java_files: Vec<String> = fs::read_dir(sandbox)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension() == Some(std::ffi::OsStr::new("java")))
+        .map(|entry| entry.path().display().to_string())
+        .collect();
+    if java_files.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let output = execute_command_with_timeout(
+        "javac".to_string(),
+        java_files.iter().map(AsRef::as_ref).collect(),
+        vec![],
+        Duration::from_secs(SANDBOX_COMPILE_TIMEOUT_SECS),
+    )?;
+
+    match output.status {
+        Some(status) if status.success() => Ok(vec![]),
+        Some(_) => {
+            // report errors relative to the archive, not the sandbox
+            let prefix = format!("{}/", sandbox_str);
+            let errors: Vec<String> = output.stderr
+                .iter()
+                .filter(|l| l.contains("error:"))
+                .map(|l| l.replace(&prefix, ""))
+                .collect();
+            if errors.is_empty() {
+                Ok(vec!["Compilation failed".to_string()])
+            } else {
+                Ok(errors)
+            }
+        },
+        None => Ok(vec![format!("Compilation took longer than {} seconds", SANDBOX_COMPILE_TIMEOUT_SECS)]),
+    }
+}
\ No newline at end of file
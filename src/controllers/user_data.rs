@@ -0,0 +1,80 @@
+use chrono::Local;
+use diesel::result::Error;
+
+use crate::{
+    db::{
+        operations_achievements::get_achievements_by_user_id,
+        operations_api_keys::get_api_keys_by_user_id,
+        operations_audit_log::get_audit_entries_by_user_id,
+        operations_bot::get_all_bots_by_team,
+        operations_bot_versions::get_bot_versions_by_team_id,
+        operations_game2v2::get_rounds_for_competition,
+        operations_participation::get_participations_by_user_id,
+        operations_teams::get_all_teams_by_student_id,
+    },
+    models::{
+        achievement::PublicAchievement,
+        api_key::PublicApiKey,
+        audit_log::PublicAuditEntry,
+        bot::PublicBot,
+        bot_version::PublicBotVersion,
+        game_2v2::PublicGame2v2,
+        participation::PublicParticipation,
+        team::{BotSelector, PublicTeam},
+        user::{User, UserProfile},
+        user_data::UserDataExport,
+    },
+};
+
+/// Collects all data tied to a user: their profile, every team they were in and what those
+/// teams submitted and played, and the user's own achievements, API keys and audited actions.
+pub fn export_user_data(user: User) -> Result<UserDataExport, Error> {
+    let teams = get_all_teams_by_student_id(user.id.clone())?;
+
+    let mut bots = vec![];
+    let mut bot_versions = vec![];
+    let mut games = vec![];
+    for team in teams.iter() {
+        bots.extend(get_all_bots_by_team(team.id.clone())?.into_iter().map(PublicBot::from));
+
+        // versions are newest first, only the newest one of a slot can be the active bot
+        let mut seen_slots = vec![];
+        for version in get_bot_versions_by_team_id(team.id.clone())? {
+            let active_bot = if version.slot == BotSelector::First { &team.bot1 } else { &team.bot2 };
+            let active = !seen_slots.contains(&version.slot) && version.bot_id == *active_bot;
+            seen_slots.push(version.slot);
+            bot_versions.push(PublicBotVersion::new(version, active));
+        }
+
+        games.extend(
+            get_rounds_for_competition(team.id.clone(), team.competition_id.clone())?
+                .into_iter()
+                .map(PublicGame2v2::from)
+        );
+    }
+
+    Ok(UserDataExport {
+        exported: Local::now().naive_utc(),
+        participations: get_participations_by_user_id(user.id.clone())?
+            .into_iter()
+            .map(PublicParticipation::from)
+            .collect(),
+        achievements: get_achievements_by_user_id(user.id.clone())?
+            .into_iter()
+            .map(PublicAchievement::from)
+            .collect(),
+        api_keys: get_api_keys_by_user_id(user.id.clone())?
+            .into_iter()
+            .map(PublicApiKey::from)
+            .collect(),
+        audit_log: get_audit_entries_by_user_id(user.id.clone())?
+            .into_iter()
+            .map(PublicAuditEntry::from)
+            .collect(),
+        teams: teams.into_iter().map(PublicTeam::from).collect(),
+        bots,
+        bot_versions,
+        games,
+        profile: UserProfile::from(user),
+    })
+}
@@ -0,0 +1,106 @@
+use std::{thread, time::Duration};
+
+use chrono::Local;
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::{
+    db::operations_webhooks::get_webhooks_by_competition_id,
+    models::webhook::{Webhook, WebhookEvent},
+};
+
+use super::command_executor::execute_command_with_timeout;
+
+/// How long a receiver gets to answer a single delivery.
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+/// Deliveries that fail are retried, waiting a bit longer before every attempt.
+const WEBHOOK_ATTEMPTS: u64 = 3;
+
+/// Notifies the competition's webhooks that subscribed to the event.
+///
+/// Every webhook receives a JSON `POST` of the form
+/// `{"event": ..., "competition_id": ..., "sent": ..., "data": {...}}` with the headers
+/// `X-Batalja-Event`, `X-Batalja-Delivery` (unique per notification) and `X-Batalja-Signature`,
+/// `sha256=` followed by the hex HMAC-SHA256 of the body keyed with the webhook's secret.
+///
+/// Deliveries run in the background and never hold up the round, failures are only logged.
+pub fn notify_webhooks(competition_id: &str, event: WebhookEvent, data: Value) {
+    let hooks = match get_webhooks_by_competition_id(competition_id.to_string()) {
+        Ok(hooks) => hooks,
+        Err(e) => {
+            error!("Failed fetching webhooks: {:?}", e);
+            return;
+        },
+    };
+    let hooks: Vec<Webhook> = hooks.into_iter().filter(|h| h.receives(event)).collect();
+    if hooks.is_empty() {
+        return;
+    }
+
+    let delivery_id = Uuid::new_v4().to_string();
+    let payload = json!({
+        "event": event.to_string(),
+        "competition_id": competition_id,
+        "sent": Local::now().naive_utc(),
+        "data": data,
+    }).to_string();
+
+    for hook in hooks.into_iter() {
+        let payload = payload.clone();
+        let delivery_id = delivery_id.clone();
+        thread::spawn(move || deliver(&hook, event, &delivery_id, &payload));
+    }
+}
+
+fn deliver(hook: &Webhook, event: WebhookEvent, delivery_id: &str, payload: &str) {
+    let signature = match sign(&hook.secret, payload) {
+        Some(s) => s,
+        None => {
+            error!("Failed signing the payload of webhook {}", hook.id);
+            return;
+        },
+    };
+    let event_header = format!("X-Batalja-Event: {}", event);
+    let delivery_header = format!("X-Batalja-Delivery: {}", delivery_id);
+    let signature_header = format!("X-Batalja-Signature: sha256={}", signature);
+    let max_time = WEBHOOK_TIMEOUT_SECS.to_string();
+
+    for attempt in 1..=WEBHOOK_ATTEMPTS {
+        let result = execute_command_with_timeout(
+            "curl".to_string(),
+            vec![
+                "-sS", "--fail",
+                "-X", "POST",
+                "-H", "Content-Type: application/json",
+                "-H", &event_header,
+                "-H", &delivery_header,
+                "-H", &signature_header,
+                "--max-time", &max_time,
+                "--data-binary", payload,
+                &hook.url,
+            ],
+            vec![],
+            // give curl the chance to report its own timeout first
+            Duration::from_secs(WEBHOOK_TIMEOUT_SECS + 5),
+        );
+        let failure = match result {
+            Ok(out) if out.status.map(|s| s.success()).unwrap_or(false) => return,
+            Ok(out) => out.stderr.join("\n"),
+            Err(e) => e.to_string(),
+        };
+        warn!("Webhook {} failed delivering {} (attempt {} of {}): {}", hook.id, event, attempt, WEBHOOK_ATTEMPTS, failure);
+        if attempt < WEBHOOK_ATTEMPTS {
+            thread::sleep(Duration::from_secs(attempt * 5));
+        }
+    }
+}
+
+/// Hex HMAC-SHA256 of the payload.
+fn sign(secret: &str, payload: &str) -> Option<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(payload.as_bytes());
+    Some(hex::encode(mac.finalize().into_bytes()))
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::{sync::{Mutex, Condvar}, time::Duration};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::config::{settings, UnrankedMode};
+
+use super::safe_mode::is_safe_mode;
+
+/// How unranked workloads (test matches, smoke tests, upload-time compilation, ...) are
+/// treated while a ranked round is executing. Configured with `workloads.unranked_policy`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnrankedPolicy {
+    /// no unranked work starts until all ranked rounds are done
+    Pause,
+    /// at most the given number of unranked workloads run at once
+    Throttle(usize),
+    /// ranked rounds don't affect unranked work
+    Unrestricted,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub enum WorkloadMode {
+    Normal,
+    Throttled,
+    Paused,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkloadStatus {
+    pub mode: WorkloadMode,
+    pub ranked_rounds_running: usize,
+    pub unranked_running: usize,
+    pub unranked_waiting: usize,
+    pub safe_mode: bool,
+}
+
+#[derive(Default)]
+struct WorkloadState {
+    ranked_rounds: usize,
+    unranked_running: usize,
+    unranked_waiting: usize,
+}
+
+static STATE: Lazy<(Mutex<WorkloadState>, Condvar)> = Lazy::new(|| {
+    (Mutex::new(WorkloadState::default()), Condvar::new())
+});
+
+/// Marks a ranked round as running for as long as it is alive.
+pub struct RankedRoundGuard;
+
+/// Allows a single unranked workload to run for as long as it is alive.
+pub struct UnrankedPermit;
+
+impl Drop for RankedRoundGuard {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*STATE;
+        let mut state = lock.lock().unwrap();
+        state.ranked_rounds -= 1;
+        cvar.notify_all();
+    }
+}
+
+impl Drop for UnrankedPermit {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*STATE;
+        let mut state = lock.lock().unwrap();
+        state.unranked_running -= 1;
+        cvar.notify_all();
+    }
+}
+
+/// Reads the unranked workload policy from the settings.
+///
+/// `unranked_policy` is one of `pause`, `throttle` (default) or `unrestricted`; in throttle
+/// mode `unranked_throttle_limit` (default 1) sets the number of concurrent unranked workloads.
+pub fn unranked_policy() -> UnrankedPolicy {
+    let workloads = &settings().workloads;
+    match workloads.unranked_policy {
+        UnrankedMode::Pause => UnrankedPolicy::Pause,
+        UnrankedMode::Unrestricted => UnrankedPolicy::Unrestricted,
+        UnrankedMode::Throttle => UnrankedPolicy::Throttle(workloads.unranked_throttle_limit),
+    }
+}
+
+/// Registers a running ranked round. Unranked workloads are paused or throttled according
+/// to the configured policy until the returned guard is dropped.
+pub fn begin_ranked_round() -> RankedRoundGuard {
+    let (lock, _) = &*STATE;
+    lock.lock().unwrap().ranked_rounds += 1;
+    RankedRoundGuard
+}
+
+/// Waits until an unranked workload may start.
+///
+/// # Arguments
+///
+/// * `timeout` - How long to wait for a slot, `None` waits until one frees up.
+///
+/// # Returns
+///
+/// A permit that has to be kept alive for the duration of the workload, or `None` if no
+/// slot became available within `timeout`.
+///
+pub fn acquire_unranked_slot(timeout: Option<Duration>) -> Option<UnrankedPermit> {
+    let policy = unranked_policy();
+    let (lock, cvar) = &*STATE;
+    let mut state = lock.lock().unwrap();
+
+    state.unranked_waiting += 1;
+    let blocked = |s: &mut WorkloadState| !may_start_unranked(s, &policy);
+    let (mut state, timed_out) = match timeout {
+        Some(t) => {
+            let (state, result) = cvar.wait_timeout_while(state, t, blocked).unwrap();
+            (state, result.timed_out())
+        },
+        None => (cvar.wait_while(state, blocked).unwrap(), false),
+    };
+    state.unranked_waiting -= 1;
+
+    if timed_out {
+        return None;
+    }
+    state.unranked_running += 1;
+    Some(UnrankedPermit)
+}
+
+/// Returns the current mode of the unranked workloads together with the queue counters.
+pub fn workload_status() -> WorkloadStatus {
+    let (lock, _) = &*STATE;
+    let state = lock.lock().unwrap();
+    let mode = match (state.ranked_rounds, unranked_policy()) {
+        (0, _) | (_, UnrankedPolicy::Unrestricted) => WorkloadMode::Normal,
+        (_, UnrankedPolicy::Throttle(_)) => WorkloadMode::Throttled,
+        (_, UnrankedPolicy::Pause) => WorkloadMode::Paused,
+    };
+
+    WorkloadStatus {
+        mode,
+        ranked_rounds_running: state.ranked_rounds,
+        unranked_running: state.unranked_running,
+        unranked_waiting: state.unranked_waiting,
+        safe_mode: is_safe_mode(),
+    }
+}
+
+/// Whether the machine is idle: no ranked round is running and no unranked workload is
+/// running or waiting for a slot.
+pub fn is_idle() -> bool {
+    let (lock, _) = &*STATE;
+    let state = lock.lock().unwrap();
+    state.ranked_rounds == 0 && state.unranked_running == 0 && state.unranked_waiting == 0
+}
+
+fn may_start_unranked(state: &WorkloadState, policy: &UnrankedPolicy) -> bool {
+    if state.ranked_rounds == 0 {
+        return true;
+    }
+    match policy {
+        UnrankedPolicy::Pause => false,
+        UnrankedPolicy::Throttle(limit) => state.unranked_running < *limit,
+        UnrankedPolicy::Unrestricted => true,
+    }
+}
\ No newline at end of file
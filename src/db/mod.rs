@@ -1,7 +1,51 @@
+// the schema diesel prints for the backend the server is built for, see migration.sh
+#[cfg(feature = "mysql")]
+#[path = "schema_mysql.rs"]
+pub mod schema;
+#[cfg(feature = "postgres")]
+#[path = "schema_postgres.rs"]
 pub mod schema;
 pub mod operations_db;
 pub mod operations_users;
 pub mod operations_teams;
 pub mod operations_competition;
 pub mod operations_bot;
-pub mod operations_game2v2;
\ No newline at end of file
+pub mod operations_game2v2;
+pub mod operations_round_hooks;
+pub mod operations_round_events;
+pub mod operations_participation;
+pub mod operations_elo_history;
+pub mod operations_round_leniency;
+pub mod operations_team_stats;
+pub mod operations_trace_spans;
+pub mod operations_game_player_stats;
+pub mod operations_practice_bots;
+pub mod operations_round_stats;
+pub mod operations_validation_rules;
+pub mod operations_bot_violations;
+pub mod operations_scouting;
+pub mod operations_bot_versions;
+pub mod operations_plagiarism;
+pub mod operations_seasons;
+pub mod operations_rounds;
+pub mod operations_jobs;
+pub mod operations_round_checkpoints;
+pub mod operations_webhooks;
+pub mod operations_discord_channels;
+pub mod operations_rate_limits;
+pub mod operations_api_keys;
+pub mod operations_rating_shadows;
+pub mod operations_audit_log;
+pub mod operations_game_overrides;
+pub mod operations_round_byes;
+pub mod operations_pairing_constraints;
+pub mod operations_ladder;
+pub mod operations_practice_queue;
+pub mod operations_challenges;
+pub mod operations_achievements;
+pub mod operations_team_streaks;
+pub mod operations_standings_history;
+pub mod operations_grade_mappings;
+pub mod operations_search;
+pub mod operations_published_bots;
+pub mod operations_ghost_teams;
\ No newline at end of file
@@ -0,0 +1,59 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::achievements;
+use crate::models::achievement::{Achievement, NewAchievement, SqlAchievement};
+use super::operations_db::establish_connection;
+
+
+/// Stores earned achievements, ones the member already has are skipped.
+///
+/// # Returns
+///
+/// The number of achievements that were new.
+///
+pub fn insert_achievements(new_achievements: Vec<NewAchievement>) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let team_ids = new_achievements.iter().map(|a| a.team_id.clone()).collect::<Vec<String>>();
+    conn.transaction(|conn| {
+        let mut earned = achievements::table
+            .filter(achievements::team_id.eq_any(&team_ids))
+            .select((achievements::team_id, achievements::user_id, achievements::kind))
+            .load::<(String, String, String)>(conn)?;
+        let rows = new_achievements
+            .into_iter()
+            .map(SqlAchievement::from)
+            .filter(|a| {
+                let key = (a.team_id.clone(), a.user_id.clone(), a.kind.clone());
+                let new = !earned.contains(&key);
+                earned.push(key);
+                new
+            })
+            .collect::<Vec<SqlAchievement>>();
+        if rows.is_empty() {
+            return Ok(0);
+        }
+        insert_into(achievements::table)
+            .values(&rows)
+            .execute(conn)
+    })
+}
+
+/// Achievements of a team's members, oldest first.
+pub fn get_achievements_by_team_id(tid: String) -> Result<Vec<Achievement>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let found = achievements::table
+        .filter(achievements::team_id.eq(tid))
+        .order(achievements::created.asc())
+        .load::<SqlAchievement>(&mut conn)?;
+    Ok(found.into_iter().map(Achievement::from).collect::<Vec<Achievement>>())
+}
+
+/// Achievements a user earned in any of their teams, oldest first.
+pub fn get_achievements_by_user_id(uid: String) -> Result<Vec<Achievement>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let found = achievements::table
+        .filter(achievements::user_id.eq(uid))
+        .order(achievements::created.asc())
+        .load::<SqlAchievement>(&mut conn)?;
+    Ok(found.into_iter().map(Achievement::from).collect::<Vec<Achievement>>())
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use chrono::Local;
+use diesel::result::Error;
+use diesel::prelude::*;
+use crate::db::schema::api_keys::dsl::*;
+use crate::models::api_key::{SqlApiKey, ApiKey};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_api_key(new_key: SqlApiKey) -> Result<ApiKey, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = diesel::insert_into(api_keys)
+        .values(&new_key)
+        .execute(&mut conn)?;
+    Ok(ApiKey::from(new_key))
+}
+
+pub fn get_api_key_by_id(kid: String) -> Result<ApiKey, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let key = api_keys
+        .filter(id.eq(kid))
+        .first::<SqlApiKey>(&mut conn)?;
+    Ok(ApiKey::from(key))
+}
+
+/// The key with the hash, unless it was revoked.
+pub fn get_active_api_key_by_hash(hash: String) -> Result<Option<ApiKey>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let key = api_keys
+        .filter(key_hash.eq(hash))
+        .filter(revoked.eq(false))
+        .first::<SqlApiKey>(&mut conn)
+        .optional()?;
+    Ok(key.map(ApiKey::from))
+}
+
+pub fn get_api_keys_by_user_id(uid: String) -> Result<Vec<ApiKey>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let keys = api_keys
+        .filter(user_id.eq(uid))
+        .order(created.desc())
+        .load::<SqlApiKey>(&mut conn)?;
+    Ok(keys.into_iter().map(ApiKey::from).collect())
+}
+
+pub fn revoke_api_key(kid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(api_keys.filter(id.eq(kid)))
+        .set(revoked.eq(true))
+        .execute(&mut conn)
+}
+
+pub fn touch_api_key(kid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(api_keys.filter(id.eq(kid)))
+        .set(last_used.eq(Some(Local::now().naive_utc())))
+        .execute(&mut conn)
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use diesel::result::Error;
+use diesel::prelude::*;
+use crate::db::schema::audit_log::dsl::*;
+use crate::models::audit_log::{SqlAuditEntry, AuditEntry};
+use super::operations_db::establish_connection;
+
+
+/// Audit entries of a competition, newest first.
+pub fn get_audit_entries_by_competition_id(com_id: String) -> Result<Vec<AuditEntry>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = audit_log
+        .filter(competition_id.eq(com_id))
+        .order(created.desc())
+        .load::<SqlAuditEntry>(&mut conn)?;
+    Ok(entries.into_iter().map(AuditEntry::from).collect::<Vec<AuditEntry>>())
+}
+
+/// Audit entries of the actions a user took, newest first.
+pub fn get_audit_entries_by_user_id(uid: String) -> Result<Vec<AuditEntry>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = audit_log
+        .filter(user_id.eq(uid))
+        .order(created.desc())
+        .load::<SqlAuditEntry>(&mut conn)?;
+    Ok(entries.into_iter().map(AuditEntry::from).collect::<Vec<AuditEntry>>())
+}
\ No newline at end of file
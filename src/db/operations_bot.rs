@@ -1,8 +1,9 @@
+use chrono::{Local, NaiveDateTime};
 use diesel::result::Error;
 use diesel::{prelude::*, insert_into};
 use crate::db::schema::bots::dsl::*;
-use crate::models::bot::{SqlBot, Bot, NewBot};
-use super::operations_db::establish_connection;
+use crate::models::{bot::{SqlBot, Bot, NewBot, BotMetadata}, bot_version::CompileStatus, compile_diagnostics::CompileDiagnostics, smoke_test::SmokeTestStatus};
+use super::{operations_db::establish_connection, operations_bot_versions::set_bot_versions_compile_status};
 
 
 pub fn insert_bot(bot: NewBot) ->  Result<Bot, Error> {
@@ -28,6 +29,7 @@ pub fn get_bot_by_id_and_team(bot_id: String, tid: String) -> Result<Bot, Error>
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match bots
         .filter(id.eq(bot_id).and(team_id.eq(tid)))
+        .filter(deleted_at.is_null())
         .first::<SqlBot>(&mut conn) {
             Ok(u) => Ok(Bot::from(u)),
             Err(e) => Err(e)
@@ -39,21 +41,67 @@ pub fn get_bots_by_team(tid: String) -> Result<Vec<Bot>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match bots
         .filter(team_id.eq(tid))
+        .filter(deleted_at.is_null())
         .load::<SqlBot>(&mut conn) {
             Ok(u) => Ok(u.into_iter().map(Bot::from).collect::<Vec<Bot>>()),
             Err(e) => Err(e)
     }
 }
 
-pub fn set_bot_error(bot: Bot, error: String) -> Result<(), Error> {
+/// Bots the team uploaded, including deleted ones, oldest first.
+pub fn get_all_bots_by_team(tid: String) -> Result<Vec<Bot>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
-    diesel::update(bots.filter(id.eq(bot.id)))
-        .set(compile_error.eq(error))
+    let rows = bots
+        .filter(team_id.eq(tid))
+        .order(created.asc())
+        .load::<SqlBot>(&mut conn)?;
+    Ok(rows.into_iter().map(Bot::from).collect::<Vec<Bot>>())
+}
+
+pub fn set_bot_error(bot: Bot, error: String, diagnostics: &CompileDiagnostics) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let status = if error.is_empty() { CompileStatus::Ok } else { CompileStatus::Failed };
+    diesel::update(bots.filter(id.eq(bot.id.clone())))
+        .set((compile_error.eq(error), compile_diagnostics.eq(diagnostics.to_json())))
         .execute(&mut conn)?;
+    set_bot_versions_compile_status(bot.id, status)?;
     Ok(())
 }
 
 
+pub fn set_bot_metadata(bid: String, metadata: BotMetadata) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(bots.filter(id.eq(bid)))
+        .set((
+            display_name.eq(metadata.name),
+            description.eq(metadata.description),
+            language.eq(metadata.language),
+        ))
+        .execute(&mut conn)
+}
+
+pub fn set_bot_smoke_test(bid: String, status: SmokeTestStatus, message: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(bots.filter(id.eq(bid)))
+        .set((smoke_test.eq(status.to_string()), smoke_test_message.eq(message)))
+        .execute(&mut conn)
+}
+
+
+pub fn soft_delete_bot(bid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(bots.filter(id.eq(bid)).filter(deleted_at.is_null()))
+        .set(deleted_at.eq(Local::now().naive_utc()))
+        .execute(&mut conn)
+}
+
+pub fn restore_bot(bid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(bots.filter(id.eq(bid)).filter(deleted_at.is_not_null()))
+        .set(deleted_at.eq(None::<NaiveDateTime>))
+        .execute(&mut conn)
+}
+
 pub fn get_bots_by_ids(ids: Vec<String>) -> Result<Vec<Bot>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     let sql_bots = bots
@@ -0,0 +1,42 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::bot_versions::dsl::*;
+use crate::models::bot_version::{SqlBotVersion, BotVersion, NewBotVersion, CompileStatus};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_bot_version(version: NewBotVersion) -> Result<BotVersion, Error> {
+    let new_version = SqlBotVersion::from(version);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(bot_versions)
+        .values(&new_version)
+        .execute(&mut conn)?;
+    Ok(BotVersion::from(new_version))
+}
+
+/// Versions of all slots of a team, newest first.
+pub fn get_bot_versions_by_team_id(tid: String) -> Result<Vec<BotVersion>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let versions = bot_versions
+        .filter(team_id.eq(tid))
+        .order(created.desc())
+        .load::<SqlBotVersion>(&mut conn)?;
+    Ok(versions.into_iter().map(BotVersion::from).collect::<Vec<BotVersion>>())
+}
+
+/// Versions of all slots of the teams, newest first.
+pub fn get_bot_versions_by_team_ids(tids: Vec<String>) -> Result<Vec<BotVersion>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let versions = bot_versions
+        .filter(team_id.eq_any(tids))
+        .order(created.desc())
+        .load::<SqlBotVersion>(&mut conn)?;
+    Ok(versions.into_iter().map(BotVersion::from).collect::<Vec<BotVersion>>())
+}
+
+pub fn set_bot_versions_compile_status(bid: String, status: CompileStatus) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(bot_versions.filter(bot_id.eq(bid)))
+        .set(compile_status.eq(status.to_string()))
+        .execute(&mut conn)
+}
\ No newline at end of file
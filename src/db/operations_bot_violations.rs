@@ -0,0 +1,39 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::bot_violations::dsl::*;
+use crate::models::bot_violation::{SqlBotViolation, BotViolation, NewBotViolation};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_bot_violation(violation: NewBotViolation) -> Result<BotViolation, Error> {
+    let new_violation = SqlBotViolation::from(violation);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(bot_violations)
+        .values(&new_violation)
+        .execute(&mut conn)?;
+    Ok(BotViolation::from(new_violation))
+}
+
+pub fn get_bot_violations_by_competition_id(com_id: String) -> Result<Vec<BotViolation>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let violations = bot_violations
+        .filter(competition_id.eq(com_id))
+        .order(created.asc())
+        .load::<SqlBotViolation>(&mut conn)?;
+    Ok(violations.into_iter().map(BotViolation::from).collect::<Vec<BotViolation>>())
+}
+
+pub fn get_bot_violations_by_team_id(tid: String) -> Result<Vec<BotViolation>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let violations = bot_violations
+        .filter(team_id.eq(tid))
+        .order(created.asc())
+        .load::<SqlBotViolation>(&mut conn)?;
+    Ok(violations.into_iter().map(BotViolation::from).collect::<Vec<BotViolation>>())
+}
+
+pub fn delete_bot_violation(vid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(bot_violations.filter(id.eq(vid)))
+        .execute(&mut conn)
+}
\ No newline at end of file
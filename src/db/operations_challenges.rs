@@ -0,0 +1,102 @@
+use chrono::{Local, NaiveDateTime};
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into, update};
+use crate::db::schema::challenges;
+use crate::models::challenge::{Challenge, ChallengeStatus, SqlChallenge};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_challenge(challenge: Challenge) -> Result<Challenge, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(challenges::table)
+        .values(&SqlChallenge::from(challenge.clone()))
+        .execute(&mut conn)?;
+    Ok(challenge)
+}
+
+pub fn get_challenge_by_id(cid: String) -> Result<Challenge, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let challenge = challenges::table
+        .find(cid)
+        .first::<SqlChallenge>(&mut conn)?;
+    Ok(Challenge::from(challenge))
+}
+
+/// Challenges a team sent or received, newest first.
+pub fn get_challenges_by_team_id(tid: String) -> Result<Vec<Challenge>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let found = challenges::table
+        .filter(challenges::challenger_id.eq(&tid).or(challenges::challenged_id.eq(&tid)))
+        .order(challenges::created.desc())
+        .load::<SqlChallenge>(&mut conn)?;
+    Ok(found.into_iter().map(Challenge::from).collect::<Vec<Challenge>>())
+}
+
+/// Number of challenges a team sent since the given time.
+pub fn count_challenges_sent_since(tid: String, since: NaiveDateTime) -> Result<i64, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    challenges::table
+        .filter(challenges::challenger_id.eq(tid))
+        .filter(challenges::created.ge(since))
+        .count()
+        .get_result(&mut conn)
+}
+
+/// Whether one of the two teams already has an unanswered challenge for the other.
+pub fn has_pending_challenge_between(team1_id: String, team2_id: String) -> Result<bool, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let pending = challenges::table
+        .filter(challenges::status.eq(ChallengeStatus::Pending.to_string()))
+        .filter(
+            challenges::challenger_id.eq(&team1_id).and(challenges::challenged_id.eq(&team2_id))
+                .or(challenges::challenger_id.eq(&team2_id).and(challenges::challenged_id.eq(&team1_id)))
+        )
+        .count()
+        .get_result::<i64>(&mut conn)?;
+    Ok(pending > 0)
+}
+
+/// Moves a challenge from one status to another.
+///
+/// # Returns
+///
+/// `false` if the challenge wasn't in the `from` status anymore, e.g. because it was answered
+/// at the same time from another session.
+///
+pub fn transition_challenge(cid: String, from: ChallengeStatus, to: ChallengeStatus) -> Result<bool, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let updated_rows = update(challenges::table.find(cid))
+        .filter(challenges::status.eq(from.to_string()))
+        .set((challenges::status.eq(to.to_string()), challenges::updated.eq(Local::now().naive_utc())))
+        .execute(&mut conn)?;
+    Ok(updated_rows > 0)
+}
+
+/// Stores the outcome of a challenge's game.
+pub fn finish_challenge(challenge: &Challenge) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    update(challenges::table.find(&challenge.id))
+        .set((
+            challenges::status.eq(challenge.status.to_string()),
+            challenges::winner_id.eq(&challenge.winner_id),
+            challenges::challenger_score.eq(challenge.challenger_score),
+            challenges::challenged_score.eq(challenge.challenged_score),
+            challenges::log_file_path.eq(&challenge.log_file_path),
+            challenges::error.eq(&challenge.error),
+            challenges::updated.eq(Local::now().naive_utc()),
+        ))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Fails challenges whose game was cut off by a server restart.
+pub fn fail_running_challenges() -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    update(challenges::table.filter(challenges::status.eq(ChallengeStatus::Running.to_string())))
+        .set((
+            challenges::status.eq(ChallengeStatus::Failed.to_string()),
+            challenges::error.eq("Interrupted by a server restart"),
+            challenges::updated.eq(Local::now().naive_utc()),
+        ))
+        .execute(&mut conn)
+}
\ No newline at end of file
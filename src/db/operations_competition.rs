@@ -1,8 +1,8 @@
-use chrono::Local;
+use chrono::{Local, NaiveDateTime};
 use diesel::result::Error;
 use diesel::{prelude::*, insert_into};
 use crate::db::schema::competitions::dsl::*;
-use crate::models::competition::{SqlCompetition, Competition, NewCompetition};
+use crate::models::competition::{SqlCompetition, Competition, NewCompetition, CompetitionClone, ScoringSystem};
 use super::operations_db::establish_connection;
 
 
@@ -15,9 +15,19 @@ pub fn insert_competition(competition: NewCompetition) ->  Result<Competition, E
     Ok(Competition::from(new_competition))
 }
 
+pub fn insert_competition_from_template(template: Competition, clone: CompetitionClone) -> Result<Competition, Error> {
+    let new_competition = SqlCompetition::from_template(template, clone);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(competitions)
+        .values(&new_competition)
+        .execute(&mut conn)?;
+    Ok(Competition::from(new_competition))
+}
+
 pub fn get_competition_by_id(uid: String) -> Result<Competition, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match competitions
+        .filter(deleted_at.is_null())
         .filter(id.eq(uid))
         .first::<SqlCompetition>(&mut conn) {
             Ok(u) => Ok(Competition::from(u)),
@@ -29,6 +39,7 @@ pub fn get_competition_by_id(uid: String) -> Result<Competition, Error> {
 pub fn get_competitions_by_ids(ids: Vec<String>) -> Result<Vec<Competition>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     let sql_competitions = competitions
+        .filter(deleted_at.is_null())
         .filter(id.eq_any(ids))
         .load::<SqlCompetition>(&mut conn)?;
     let converted_competitions: Vec<Competition> = sql_competitions.into_iter()
@@ -43,6 +54,7 @@ pub fn get_running_competitions() -> Result<Vec<Competition>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     let current_time = Local::now().naive_utc();
     let sql_competitions = competitions
+        .filter(deleted_at.is_null())
         .filter(start.le(current_time).and(end.ge(current_time)))
         .load::<SqlCompetition>(&mut conn)?;
     let converted_competitions: Vec<Competition> = sql_competitions.into_iter()
@@ -51,10 +63,131 @@ pub fn get_running_competitions() -> Result<Vec<Competition>, Error> {
     Ok(converted_competitions)
 }
 
-pub fn set_competition_round(cid: String, new_round: i32) -> Result<(), Error> {
+pub fn set_competition_replay_retention(cid: String, compress_after: i32, delete_after: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            replay_compress_after.eq(compress_after),
+            replay_delete_after.eq(delete_after),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn set_competition_game_timeouts(cid: String, timeout_secs: i32, stall_secs: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            game_timeout_secs.eq(timeout_secs),
+            stall_timeout_secs.eq(stall_secs),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn set_competition_priority(cid: String, new_priority: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set(priority.eq(new_priority))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn set_competition_rating_settings(cid: String, k_factor: i32, prov_games: i32, prov_k_factor: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            elo_k_factor.eq(k_factor),
+            provisional_games.eq(prov_games),
+            provisional_k_factor.eq(prov_k_factor),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn set_competition_scoring(cid: String, system: ScoringSystem, win: i32, draw: i32, loss: i32, bye: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            scoring_system.eq(system.to_string()),
+            points_win.eq(win),
+            points_draw.eq(draw),
+            points_loss.eq(loss),
+            points_bye.eq(bye),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn set_competition_evaluator(cid: String, source: String, sha256: String) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            evaluator_source.eq(source),
+            evaluator_sha256.eq(sha256),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+pub fn get_all_competitions() -> Result<Vec<Competition>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let sql_competitions = competitions
+        .filter(deleted_at.is_null())
+        .load::<SqlCompetition>(&mut conn)?;
+    Ok(sql_competitions.into_iter().map(Competition::from).collect::<Vec<Competition>>())
+}
+
+pub fn set_competition_allowed_submissions(cid: String, allowed: bool) -> Result<(), Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     diesel::update(competitions.filter(id.eq(cid)))
-        .set(round.eq(new_round.to_string()))
+        .set(allowed_submissions.eq(allowed))
         .execute(&mut conn)?;
     Ok(())
+}
+
+pub fn set_competition_registration(cid: String, open: NaiveDateTime, close: NaiveDateTime, team_cap: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            registration_open.eq(open),
+            registration_close.eq(close),
+            max_teams.eq(team_cap),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+/// Marks a competition as archived, its export is stored at `path`.
+pub fn set_competition_archived(cid: String, path: String) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set((
+            archived.eq(true),
+            archive_path.eq(path),
+            allowed_submissions.eq(false),
+        ))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
+}
+
+pub fn soft_delete_competition(cid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid)).filter(deleted_at.is_null()))
+        .set(deleted_at.eq(Local::now().naive_utc()))
+        .execute(&mut conn)
+}
+
+pub fn restore_competition(cid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid)).filter(deleted_at.is_not_null()))
+        .set(deleted_at.eq(None::<NaiveDateTime>))
+        .execute(&mut conn)
+}
+
+pub fn set_competition_submission_freeze(cid: String, freeze_minutes: i32) -> Result<Competition, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(competitions.filter(id.eq(cid.clone())))
+        .set(submission_freeze_minutes.eq(freeze_minutes))
+        .execute(&mut conn)?;
+    get_competition_by_id(cid)
 }
\ No newline at end of file
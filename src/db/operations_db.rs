@@ -1,22 +1,43 @@
 use r2d2::{self, Pool, PooledConnection, Error as R2D2Error};
 use diesel::r2d2::ConnectionManager;
-use diesel::MysqlConnection;
-use std::env;
-use std::sync::Mutex;
+use std::time::Duration;
 use once_cell::sync::Lazy;
+use crate::config::settings;
 
-type DbConn = PooledConnection<ConnectionManager<MysqlConnection>>;
+#[cfg(all(feature = "mysql", feature = "postgres"))]
+compile_error!("the `mysql` and `postgres` features can't be enabled at the same time, build with `--no-default-features --features postgres` for PostgreSQL");
+#[cfg(not(any(feature = "mysql", feature = "postgres")))]
+compile_error!("one of the `mysql` or `postgres` features has to be enabled");
 
-static POOL: Lazy<Mutex<Pool<ConnectionManager<MysqlConnection>>>> = Lazy::new(|| {
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let manager = ConnectionManager::<MysqlConnection>::new(database_url);
-    let pool = Pool::builder()
+/// The connection of the database backend the server is built for.
+#[cfg(feature = "mysql")]
+pub type DbConnection = diesel::MysqlConnection;
+#[cfg(feature = "postgres")]
+pub type DbConnection = diesel::PgConnection;
+
+pub type DbPool = Pool<ConnectionManager<DbConnection>>;
+type DbConn = PooledConnection<ConnectionManager<DbConnection>>;
+
+// r2d2 pools are thread safe, handing out connections doesn't need a lock around the pool
+static POOL: Lazy<DbPool> = Lazy::new(|| {
+    let database = &settings().database;
+    if database.url.is_empty() {
+        panic!("DATABASE_URL must be set");
+    }
+
+    let manager = ConnectionManager::<DbConnection>::new(database.url.clone());
+    Pool::builder()
+        .max_size(database.pool_size)
+        .connection_timeout(Duration::from_secs(database.pool_timeout_secs))
         .build(manager)
-        .expect("Failed to create pool.");
-    Mutex::new(pool)
+        .expect("Failed to create pool.")
 });
 
+/// The shared connection pool. Clones refer to the same pool.
+pub fn db_pool() -> DbPool {
+    POOL.clone()
+}
+
 pub fn establish_connection() -> Result<DbConn, R2D2Error> {
-    let pool = POOL.lock().unwrap();
-    pool.get()
+    POOL.get()
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::discord_channels::dsl::*;
+use crate::models::discord_channel::{SqlDiscordChannel, DiscordChannel, NewDiscordChannel};
+use super::operations_db::establish_connection;
+
+
+/// Stores the Discord channel of a competition, replacing its previous channel.
+pub fn upsert_discord_channel(channel: NewDiscordChannel) -> Result<DiscordChannel, Error> {
+    let new_channel = SqlDiscordChannel::from(channel);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(discord_channels.filter(competition_id.eq(&new_channel.competition_id))).execute(conn)?;
+        insert_into(discord_channels).values(&new_channel).execute(conn)
+    })?;
+    Ok(DiscordChannel::from(new_channel))
+}
+
+pub fn get_discord_channel_by_competition_id(com_id: String) -> Result<Option<DiscordChannel>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let channel = discord_channels
+        .filter(competition_id.eq(com_id))
+        .first::<SqlDiscordChannel>(&mut conn)
+        .optional()?;
+    Ok(channel.map(DiscordChannel::from))
+}
+
+pub fn delete_discord_channel(com_id: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(discord_channels.filter(competition_id.eq(com_id)))
+        .execute(&mut conn)
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use diesel::result::Error;
+use diesel::prelude::*;
+use crate::db::schema::elo_history::dsl::*;
+use crate::models::elo_history::{SqlEloHistory, EloHistory};
+use super::operations_db::establish_connection;
+
+
+pub fn get_elo_history_by_competition_id(com_id: String) -> Result<Vec<EloHistory>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = elo_history
+        .filter(competition_id.eq(com_id))
+        .order(round.asc())
+        .load::<SqlEloHistory>(&mut conn)?;
+    Ok(entries.into_iter().map(EloHistory::from).collect::<Vec<EloHistory>>())
+}
+
+pub fn get_elo_history_by_team_id(tid: String) -> Result<Vec<EloHistory>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = elo_history
+        .filter(team_id.eq(tid))
+        .order(round.asc())
+        .load::<SqlEloHistory>(&mut conn)?;
+    Ok(entries.into_iter().map(EloHistory::from).collect::<Vec<EloHistory>>())
+}
+
+/// ELO changes of all teams of a competition in the given round.
+pub fn get_elo_history_by_round(com_id: String, r: i32) -> Result<Vec<EloHistory>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = elo_history
+        .filter(competition_id.eq(com_id))
+        .filter(round.eq(r))
+        .load::<SqlEloHistory>(&mut conn)?;
+    Ok(entries.into_iter().map(EloHistory::from).collect::<Vec<EloHistory>>())
+}
\ No newline at end of file
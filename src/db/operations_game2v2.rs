@@ -1,19 +1,11 @@
 use diesel::result::Error;
-use diesel::{prelude::*, insert_into};
+use diesel::prelude::*;
 use crate::db::schema::games_2v2::dsl::*;
-use crate::models::game_2v2::{SqlGame2v2, Game2v2, NewGame2v2};
+use crate::models::game_2v2::{SqlGame2v2, Game2v2, ReplayState};
+use crate::models::game_listing::{CursorKey, GameCursor, GameListFilter, GameSort};
 use super::operations_db::establish_connection;
 
 
-pub fn insert_game(game: NewGame2v2) ->  Result<Game2v2, Error> {
-    let new_bot = SqlGame2v2::from(game);
-    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
-    let _ = insert_into(games_2v2)
-        .values(&new_bot)
-        .execute(&mut conn)?;
-    Ok(Game2v2::from(new_bot))
-}
-
 pub fn get_game_by_id(uid: String) -> Result<Game2v2, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match games_2v2
@@ -66,4 +58,148 @@ pub fn get_public_games() -> Result<Vec<Game2v2>, Error> {
         .filter(public.eq(true))
         .load::<SqlGame2v2>(&mut conn)?;
     Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+pub fn get_games_with_replay_state_until_round(com_id: String, states: Vec<ReplayState>, last_round: i32) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let state_names: Vec<String> = states.iter().map(ReplayState::to_string).collect();
+    let games = games_2v2
+        .filter(competition_id.eq(com_id))
+        .filter(replay_state.eq_any(state_names))
+        .filter(round.le(last_round))
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+pub fn set_game_replay(game_id: String, path: String, state: ReplayState) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(games_2v2.filter(id.eq(game_id)))
+        .set((log_file_path.eq(path), replay_state.eq(state.to_string())))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Rated games the team played, games against ghost teams aren't counted.
+pub fn count_games_by_team_id(tid: String) -> Result<i64, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    games_2v2
+        .filter(team1_id.eq(tid.clone()).or(team2_id.eq(tid)))
+        .filter(unrated.eq(false))
+        .count()
+        .get_result(&mut conn)
+}
+
+/// Rated games the team played in rounds before `r`.
+pub fn count_games_by_team_before_round(tid: String, r: i32) -> Result<i64, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    games_2v2
+        .filter(team1_id.eq(tid.clone()).or(team2_id.eq(tid)))
+        .filter(round.lt(r))
+        .filter(unrated.eq(false))
+        .count()
+        .get_result(&mut conn)
+}
+
+pub fn get_games_by_competition_id(com_id: String) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = games_2v2
+        .filter(competition_id.eq(com_id))
+        .order((round.asc(), created.asc()))
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+pub fn get_games_by_trace_id(tid: String) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = games_2v2
+        .filter(trace_id.eq(tid))
+        .order(created.asc())
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+pub fn get_games_between_teams(team_a: String, team_b: String) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = games_2v2
+        .filter(
+            team1_id.eq(team_a.clone()).and(team2_id.eq(team_b.clone()))
+                .or(team1_id.eq(team_b).and(team2_id.eq(team_a)))
+        )
+        .order((round.asc(), created.asc()))
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+pub fn get_games_by_competition_round(com_id: String, r: i32) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = games_2v2
+        .filter(competition_id.eq(com_id))
+        .filter(round.eq(r))
+        .order(created.asc())
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
+}
+
+/// The latest round the competition has games of, `None` before its first round.
+pub fn get_last_played_round(com_id: String) -> Result<Option<i32>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    games_2v2
+        .filter(competition_id.eq(com_id))
+        .select(diesel::dsl::max(round))
+        .first::<Option<i32>>(&mut conn)
+}
+
+/// One page of a competition's games, see `GameListFilter`. Loads one game more than
+/// `filter.limit` so the caller can tell whether there is a next page.
+pub fn get_games_page(filter: GameListFilter) -> Result<Vec<Game2v2>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let mut query = games_2v2
+        .filter(competition_id.eq(filter.competition_id))
+        .into_boxed();
+
+    if let Some(r) = filter.round {
+        query = query.filter(round.eq(r));
+    }
+    if let Some(tid) = filter.team_id {
+        query = query.filter(team1_id.eq(tid.clone()).or(team2_id.eq(tid)));
+    }
+    if let Some(wid) = filter.winner_id {
+        query = query.filter(winner_id.eq(wid));
+    }
+    if let Some(team_ids) = filter.visible_to_teams {
+        query = query.filter(
+            public.eq(true)
+                .or(team1_id.eq_any(team_ids.clone()))
+                .or(team2_id.eq_any(team_ids))
+        );
+    }
+
+    // keyset pagination: everything past (key, id) of the cursor, ids break ties. Cursors
+    // are decoded for the sort, their key always has its variant
+    macro_rules! sort_by {
+        ($column:expr, $variant:path) => {
+            if filter.descending {
+                if let Some(GameCursor { key: $variant(key), id: after_id }) = &filter.after {
+                    query = query.filter($column.lt(*key).or($column.eq(*key).and(id.lt(after_id.clone()))));
+                }
+                query.order(($column.desc(), id.desc()))
+            } else {
+                if let Some(GameCursor { key: $variant(key), id: after_id }) = &filter.after {
+                    query = query.filter($column.gt(*key).or($column.eq(*key).and(id.gt(after_id.clone()))));
+                }
+                query.order(($column.asc(), id.asc()))
+            }
+        };
+    }
+
+    let query = match filter.sort {
+        GameSort::Created => sort_by!(created, CursorKey::Created),
+        GameSort::Round => sort_by!(round, CursorKey::Round),
+        GameSort::Duration => sort_by!(duration_ms, CursorKey::Duration),
+    };
+
+    let games = query
+        .limit(filter.limit + 1)
+        .load::<SqlGame2v2>(&mut conn)?;
+    Ok(games.into_iter().map(Game2v2::from).collect::<Vec<Game2v2>>())
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{audit_log, elo_history, game_player_stats, games_2v2, teams};
+use crate::models::audit_log::{NewAuditEntry, SqlAuditEntry};
+use crate::models::game_override::GameResultCorrection;
+use super::operations_db::establish_connection;
+
+const SLOTS: [&str; 4] = ["team1bot1", "team1bot2", "team2bot1", "team2bot2"];
+
+
+/// Stores the corrected result of a game together with its audit entry in a single
+/// transaction. The ELO correction, the new ELO changes minus the ones stored on the game, is
+/// added to the teams' ELO and to their ELO history from the game's round on. The game is
+/// locked while its ELO changes are read, so concurrent overrides correct it one after the
+/// other.
+///
+/// # Returns
+///
+/// How much the ELO of team 1 and team 2 changed.
+///
+pub fn store_game_override(correction: GameResultCorrection, audit: NewAuditEntry) -> Result<(i32, i32), Error> {
+    let audit_entry = SqlAuditEntry::from(audit);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        let (stored_team1_elo, stored_team2_elo) = games_2v2::table
+            .find(&correction.game_id)
+            .select((games_2v2::team1_elo, games_2v2::team2_elo))
+            .for_update()
+            .first::<(i32, i32)>(conn)?;
+        let team1_elo_correction = correction.team1_elo - stored_team1_elo;
+        let team2_elo_correction = correction.team2_elo - stored_team2_elo;
+
+        diesel::update(games_2v2::table.find(&correction.game_id))
+            .set((
+                games_2v2::winner_id.eq(&correction.winner_id),
+                games_2v2::team1bot1_survived.eq(correction.survived[0]),
+                games_2v2::team1bot2_survived.eq(correction.survived[1]),
+                games_2v2::team2bot1_survived.eq(correction.survived[2]),
+                games_2v2::team2bot2_survived.eq(correction.survived[3]),
+                games_2v2::team1_elo.eq(correction.team1_elo),
+                games_2v2::team2_elo.eq(correction.team2_elo),
+            ))
+            .execute(conn)?;
+
+        for (slot, survived) in SLOTS.iter().zip(correction.survived) {
+            diesel::update(game_player_stats::table
+                .filter(game_player_stats::game_id.eq(&correction.game_id))
+                .filter(game_player_stats::slot.eq(slot)))
+                .set(game_player_stats::survived.eq(survived))
+                .execute(conn)?;
+        }
+
+        for (team_id, elo_correction) in [
+            (&correction.team1_id, team1_elo_correction),
+            (&correction.team2_id, team2_elo_correction),
+        ] {
+            if elo_correction == 0 {
+                continue;
+            }
+            diesel::update(teams::table.find(team_id))
+                .set(teams::elo.eq(teams::elo + elo_correction))
+                .execute(conn)?;
+            diesel::update(elo_history::table
+                .filter(elo_history::team_id.eq(team_id))
+                .filter(elo_history::competition_id.eq(&correction.competition_id))
+                .filter(elo_history::round.eq(correction.round)))
+                .set(elo_history::elo_change.eq(elo_history::elo_change + elo_correction))
+                .execute(conn)?;
+            diesel::update(elo_history::table
+                .filter(elo_history::team_id.eq(team_id))
+                .filter(elo_history::competition_id.eq(&correction.competition_id))
+                .filter(elo_history::round.ge(correction.round)))
+                .set(elo_history::elo.eq(elo_history::elo + elo_correction))
+                .execute(conn)?;
+        }
+
+        insert_into(audit_log::table)
+            .values(&audit_entry)
+            .execute(conn)?;
+        Ok((team1_elo_correction, team2_elo_correction))
+    })
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use diesel::result::Error;
+use diesel::prelude::*;
+use crate::db::schema::game_player_stats::dsl::*;
+use crate::models::game_player_stats::{SqlGamePlayerStats, GamePlayerStatsRecord};
+use super::operations_db::establish_connection;
+
+
+pub fn get_game_player_stats_by_game_id(gid: String) -> Result<Vec<GamePlayerStatsRecord>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let stats = game_player_stats
+        .filter(game_id.eq(gid))
+        .order(slot.asc())
+        .load::<SqlGamePlayerStats>(&mut conn)?;
+    Ok(stats.into_iter().map(GamePlayerStatsRecord::from).collect::<Vec<GamePlayerStatsRecord>>())
+}
+pub fn get_game_player_stats_by_game_ids(ids: Vec<String>) -> Result<Vec<GamePlayerStatsRecord>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let stats = game_player_stats
+        .filter(game_id.eq_any(ids))
+        .load::<SqlGamePlayerStats>(&mut conn)?;
+    Ok(stats.into_iter().map(GamePlayerStatsRecord::from).collect::<Vec<GamePlayerStatsRecord>>())
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::ghost_teams::dsl::*;
+use crate::models::ghost_team::{SqlGhostTeam, GhostTeam};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_ghost_team(ghost_team: SqlGhostTeam) -> Result<GhostTeam, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(ghost_teams)
+        .values(&ghost_team)
+        .execute(&mut conn)?;
+    Ok(GhostTeam::from(ghost_team))
+}
+
+pub fn get_ghost_team_by_id(gid: String) -> Result<GhostTeam, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let ghost_team = ghost_teams
+        .filter(id.eq(gid))
+        .first::<SqlGhostTeam>(&mut conn)?;
+    Ok(GhostTeam::from(ghost_team))
+}
+
+pub fn get_ghost_teams_by_competition_id(cid: String) -> Result<Vec<GhostTeam>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let teams = ghost_teams
+        .filter(competition_id.eq(cid))
+        .order(created.asc())
+        .load::<SqlGhostTeam>(&mut conn)?;
+    Ok(teams.into_iter().map(GhostTeam::from).collect::<Vec<GhostTeam>>())
+}
+
+pub fn is_ghost_team_entered(cid: String, pid: String) -> Result<bool, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let count: i64 = ghost_teams
+        .filter(competition_id.eq(cid))
+        .filter(published_bot_id.eq(pid))
+        .count()
+        .get_result(&mut conn)?;
+    Ok(count > 0)
+}
+
+pub fn delete_ghost_team(gid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(ghost_teams.filter(id.eq(gid)))
+        .execute(&mut conn)
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::{audit_log, grade_mappings};
+use crate::models::audit_log::{NewAuditEntry, SqlAuditEntry};
+use crate::models::grade_mapping::{GradeMapping, SqlGradeMapping};
+use super::operations_db::establish_connection;
+
+
+pub fn get_grade_mapping_by_competition_id(cid: String) -> Result<Option<GradeMapping>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let mapping = grade_mappings::table
+        .find(cid)
+        .first::<SqlGradeMapping>(&mut conn)
+        .optional()?;
+    Ok(mapping.map(GradeMapping::from))
+}
+
+/// Stores a competition's grade mapping together with its audit entry in a single transaction.
+pub fn store_grade_mapping(mapping: GradeMapping, audit: NewAuditEntry) -> Result<GradeMapping, Error> {
+    let audit_entry = SqlAuditEntry::from(audit);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(grade_mappings::table.find(&mapping.competition_id)).execute(conn)?;
+        insert_into(grade_mappings::table)
+            .values(&SqlGradeMapping::from(mapping.clone()))
+            .execute(conn)?;
+        insert_into(audit_log::table)
+            .values(&audit_entry)
+            .execute(conn)?;
+        Ok(mapping)
+    })
+}
\ No newline at end of file
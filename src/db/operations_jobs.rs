@@ -0,0 +1,112 @@
+use chrono::Local;
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into, sql_types::Text};
+use crate::db::schema::jobs::dsl::*;
+use crate::models::job::{SqlJob, Job, NewJob, JobStatus};
+use super::operations_db::establish_connection;
+
+// `||` is a logical OR in MySQL, CONCAT appends in both databases
+sql_function!(fn concat(a: Text, b: Text) -> Text);
+
+pub fn insert_job(job: NewJob) -> Result<Job, Error> {
+    let new_job = SqlJob::from(job);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(jobs)
+        .values(&new_job)
+        .execute(&mut conn)?;
+    Ok(Job::from(new_job))
+}
+
+pub fn get_job_by_id(jid: String) -> Result<Job, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let job = jobs
+        .find(jid)
+        .first::<SqlJob>(&mut conn)?;
+    Ok(Job::from(job))
+}
+
+/// Takes the oldest queued job of a competition that isn't running one and marks it as running.
+///
+/// Jobs another worker is claiming at the same time are skipped rather than waited for, so
+/// no job is claimed twice.
+pub fn claim_next_job() -> Result<Option<Job>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        // a competition runs one job at a time
+        let busy_competitions = jobs
+            .filter(status.eq(JobStatus::Running.to_string()))
+            .select(competition_id)
+            .load::<String>(conn)?;
+        let next = jobs
+            .filter(status.eq(JobStatus::Queued.to_string()))
+            .filter(competition_id.ne_all(busy_competitions))
+            .order(created.asc())
+            .for_update()
+            .skip_locked()
+            .first::<SqlJob>(conn)
+            .optional()?;
+        let mut job = match next {
+            Some(j) => j,
+            None => return Ok(None),
+        };
+
+        let now = Local::now().naive_utc();
+        diesel::update(jobs.find(job.id.clone()))
+            .set((status.eq(JobStatus::Running.to_string()), started.eq(Some(now))))
+            .execute(conn)?;
+        job.status = JobStatus::Running.to_string();
+        job.started = Some(now);
+        Ok(Some(Job::from(job)))
+    })
+}
+
+pub fn set_job_progress(jid: String, new_progress: i32) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(jobs.find(jid))
+        .set(progress.eq(new_progress))
+        .execute(&mut conn)
+}
+
+/// Appends a line to a job's log in a single statement, concurrent appends don't lose lines.
+pub fn append_job_log(jid: String, line: String) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(jobs.find(jid))
+        .set(log.eq(concat(log, format!("{}\n", line))))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Marks a job as done or failed, a done job is at full progress.
+pub fn finish_job(jid: String, new_status: JobStatus) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let now = Some(Local::now().naive_utc());
+    let builder = diesel::update(jobs.find(jid));
+    match new_status {
+        JobStatus::Done => builder
+            .set((status.eq(new_status.to_string()), progress.eq(100), finished.eq(now)))
+            .execute(&mut conn)?,
+        _ => builder
+            .set((status.eq(new_status.to_string()), finished.eq(now)))
+            .execute(&mut conn)?,
+    };
+    Ok(())
+}
+
+/// Jobs that were running when the server stopped.
+pub fn get_running_jobs() -> Result<Vec<Job>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let running = jobs
+        .filter(status.eq(JobStatus::Running.to_string()))
+        .load::<SqlJob>(&mut conn)?;
+    Ok(running.into_iter().map(Job::from).collect::<Vec<Job>>())
+}
+
+pub fn has_queued_job(cid: String) -> Result<bool, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let queued = jobs
+        .filter(competition_id.eq(cid))
+        .filter(status.eq(JobStatus::Queued.to_string()))
+        .count()
+        .get_result::<i64>(&mut conn)?;
+    Ok(queued > 0)
+}
\ No newline at end of file
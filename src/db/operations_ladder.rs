@@ -0,0 +1,41 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{ladder_bots, ladder_games};
+use crate::models::ladder::{LadderBot, LadderGame, NewLadderGame, SqlLadderBot, SqlLadderGame};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_ladder_bot(ladder_bot: SqlLadderBot) -> Result<LadderBot, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(ladder_bots::table)
+        .values(&ladder_bot)
+        .execute(&mut conn)?;
+    Ok(LadderBot::from(ladder_bot))
+}
+
+pub fn get_ladder_bots_by_competition_id(cid: String) -> Result<Vec<LadderBot>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let bots = ladder_bots::table
+        .filter(ladder_bots::competition_id.eq(cid))
+        .order(ladder_bots::rating.asc())
+        .load::<SqlLadderBot>(&mut conn)?;
+    Ok(bots.into_iter().map(LadderBot::from).collect::<Vec<LadderBot>>())
+}
+
+pub fn insert_ladder_game(new_game: NewLadderGame) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    insert_into(ladder_games::table)
+        .values(&SqlLadderGame::from(new_game))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Ladder games of a competition, oldest first.
+pub fn get_ladder_games_by_competition_id(cid: String) -> Result<Vec<LadderGame>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = ladder_games::table
+        .filter(ladder_games::competition_id.eq(cid))
+        .order(ladder_games::created.asc())
+        .load::<SqlLadderGame>(&mut conn)?;
+    Ok(games.into_iter().map(LadderGame::from).collect::<Vec<LadderGame>>())
+}
\ No newline at end of file
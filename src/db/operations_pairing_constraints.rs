@@ -0,0 +1,26 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::pairing_constraints::dsl::*;
+use crate::models::pairing_constraints::{SqlPairingConstraints, PairingConstraints, NewPairingConstraints};
+use super::operations_db::establish_connection;
+
+
+/// Stores the pairing constraints of a competition, replacing its previous constraints.
+pub fn upsert_pairing_constraints(constraints: NewPairingConstraints) -> Result<PairingConstraints, Error> {
+    let new_constraints = SqlPairingConstraints::from(constraints);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(pairing_constraints.filter(competition_id.eq(&new_constraints.competition_id))).execute(conn)?;
+        insert_into(pairing_constraints).values(&new_constraints).execute(conn)
+    })?;
+    Ok(PairingConstraints::from(new_constraints))
+}
+
+pub fn get_pairing_constraints_by_competition_id(com_id: String) -> Result<Option<PairingConstraints>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let constraints = pairing_constraints
+        .filter(competition_id.eq(com_id))
+        .first::<SqlPairingConstraints>(&mut conn)
+        .optional()?;
+    Ok(constraints.map(PairingConstraints::from))
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::participations::dsl::*;
+use crate::models::participation::{SqlParticipation, Participation, NewParticipation};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_participations(new_participations: Vec<NewParticipation>) -> Result<(), Error> {
+    let rows: Vec<SqlParticipation> = new_participations
+        .into_iter()
+        .map(SqlParticipation::from)
+        .collect();
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    insert_into(participations)
+        .values(&rows)
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+pub fn get_participations_by_competition_id(com_id: String) -> Result<Vec<Participation>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rows = participations
+        .filter(competition_id.eq(com_id))
+        .load::<SqlParticipation>(&mut conn)?;
+    Ok(rows.into_iter().map(Participation::from).collect::<Vec<Participation>>())
+}
+
+pub fn get_participations_by_team_id(tid: String) -> Result<Vec<Participation>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rows = participations
+        .filter(team_id.eq(tid))
+        .order(round.asc())
+        .load::<SqlParticipation>(&mut conn)?;
+    Ok(rows.into_iter().map(Participation::from).collect::<Vec<Participation>>())
+}
+
+/// Participations the user had as owner or partner of any team, oldest first.
+pub fn get_participations_by_user_id(uid: String) -> Result<Vec<Participation>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rows = participations
+        .filter(owner.eq(uid.clone()).or(partner.eq(uid)))
+        .order(created.asc())
+        .load::<SqlParticipation>(&mut conn)?;
+    Ok(rows.into_iter().map(Participation::from).collect::<Vec<Participation>>())
+}
\ No newline at end of file
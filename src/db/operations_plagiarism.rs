@@ -0,0 +1,48 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{plagiarism_pairs, plagiarism_reports};
+use crate::models::plagiarism::{
+    NewPlagiarismPair, NewPlagiarismReport, PlagiarismPair, PlagiarismReport, SqlPlagiarismPair, SqlPlagiarismReport,
+};
+use super::operations_db::establish_connection;
+
+
+/// Stores a report together with its flagged pairs.
+pub fn insert_plagiarism_report(report: NewPlagiarismReport, pairs: Vec<NewPlagiarismPair>) -> Result<PlagiarismReport, Error> {
+    let new_report = SqlPlagiarismReport::new(report, pairs.len() as i32);
+    let new_pairs = pairs
+        .into_iter()
+        .map(|p| SqlPlagiarismPair::new(p, new_report.id.clone()))
+        .collect::<Vec<SqlPlagiarismPair>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        insert_into(plagiarism_reports::table)
+            .values(&new_report)
+            .execute(conn)?;
+        insert_into(plagiarism_pairs::table)
+            .values(&new_pairs)
+            .execute(conn)
+    })?;
+    Ok(PlagiarismReport::from(new_report))
+}
+
+pub fn get_latest_plagiarism_report(com_id: String) -> Result<Option<PlagiarismReport>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let report = plagiarism_reports::table
+        .filter(plagiarism_reports::competition_id.eq(com_id))
+        .order(plagiarism_reports::created.desc())
+        .first::<SqlPlagiarismReport>(&mut conn)
+        .optional()?;
+    Ok(report.map(PlagiarismReport::from))
+}
+
+/// Flagged pairs of a report, most similar first.
+pub fn get_plagiarism_pairs_by_report_id(rid: String) -> Result<Vec<PlagiarismPair>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let pairs = plagiarism_pairs::table
+        .filter(plagiarism_pairs::report_id.eq(rid))
+        .order(plagiarism_pairs::similarity.desc())
+        .load::<SqlPlagiarismPair>(&mut conn)?;
+    Ok(pairs.into_iter().map(PlagiarismPair::from).collect::<Vec<PlagiarismPair>>())
+}
\ No newline at end of file
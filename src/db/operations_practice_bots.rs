@@ -0,0 +1,37 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::practice_bots::dsl::*;
+use crate::models::practice_bot::{SqlPracticeBot, PracticeBot};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_practice_bot(practice_bot: SqlPracticeBot) -> Result<PracticeBot, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(practice_bots)
+        .values(&practice_bot)
+        .execute(&mut conn)?;
+    Ok(PracticeBot::from(practice_bot))
+}
+
+pub fn get_practice_bot_by_id(pid: String) -> Result<PracticeBot, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let practice_bot = practice_bots
+        .filter(id.eq(pid))
+        .first::<SqlPracticeBot>(&mut conn)?;
+    Ok(PracticeBot::from(practice_bot))
+}
+
+pub fn get_practice_bots_by_competition_id(com_id: String) -> Result<Vec<PracticeBot>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let bots = practice_bots
+        .filter(competition_id.eq(com_id))
+        .order(created.desc())
+        .load::<SqlPracticeBot>(&mut conn)?;
+    Ok(bots.into_iter().map(PracticeBot::from).collect::<Vec<PracticeBot>>())
+}
+
+pub fn delete_practice_bot(pid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(practice_bots.filter(id.eq(pid)))
+        .execute(&mut conn)
+}
\ No newline at end of file
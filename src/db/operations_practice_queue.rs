@@ -0,0 +1,61 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into, delete};
+use crate::db::schema::{practice_games, practice_queue};
+use crate::models::practice_queue::{NewPracticeGame, PracticeGame, PracticeQueueEntry, SqlPracticeGame, SqlPracticeQueueEntry};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_practice_queue_entry(entry: PracticeQueueEntry) -> Result<PracticeQueueEntry, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(practice_queue::table)
+        .values(&SqlPracticeQueueEntry::from(entry.clone()))
+        .execute(&mut conn)?;
+    Ok(entry)
+}
+
+pub fn get_practice_queue_entry_by_team_id(tid: String) -> Result<PracticeQueueEntry, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entry = practice_queue::table
+        .filter(practice_queue::team_id.eq(tid))
+        .first::<SqlPracticeQueueEntry>(&mut conn)?;
+    Ok(PracticeQueueEntry::from(entry))
+}
+
+/// Every queued team, longest waiting first.
+pub fn get_practice_queue() -> Result<Vec<PracticeQueueEntry>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = practice_queue::table
+        .order(practice_queue::created.asc())
+        .load::<SqlPracticeQueueEntry>(&mut conn)?;
+    Ok(entries.into_iter().map(PracticeQueueEntry::from).collect::<Vec<PracticeQueueEntry>>())
+}
+
+pub fn delete_practice_queue_entry_by_team_id(tid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    delete(practice_queue::table.filter(practice_queue::team_id.eq(tid)))
+        .execute(&mut conn)
+}
+
+/// Stores the result of a practice game and takes both teams out of the queue.
+pub fn insert_practice_game_and_dequeue(new_game: NewPracticeGame) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(practice_queue::table.filter(
+            practice_queue::team_id.eq(&new_game.team1_id).or(practice_queue::team_id.eq(&new_game.team2_id))
+        )).execute(conn)?;
+        insert_into(practice_games::table)
+            .values(&SqlPracticeGame::from(new_game))
+            .execute(conn)?;
+        Ok(())
+    })
+}
+
+/// Practice games a team played in, newest first.
+pub fn get_practice_games_by_team_id(tid: String) -> Result<Vec<PracticeGame>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let games = practice_games::table
+        .filter(practice_games::team1_id.eq(&tid).or(practice_games::team2_id.eq(&tid)))
+        .order(practice_games::created.desc())
+        .load::<SqlPracticeGame>(&mut conn)?;
+    Ok(games.into_iter().map(PracticeGame::from).collect::<Vec<PracticeGame>>())
+}
\ No newline at end of file
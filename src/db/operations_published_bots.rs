@@ -0,0 +1,50 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::published_bots::dsl::*;
+use crate::models::published_bot::{SqlPublishedBot, PublishedBot};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_published_bot(published_bot: SqlPublishedBot) -> Result<PublishedBot, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(published_bots)
+        .values(&published_bot)
+        .execute(&mut conn)?;
+    Ok(PublishedBot::from(published_bot))
+}
+
+pub fn get_published_bot_by_id(pid: String) -> Result<PublishedBot, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let published_bot = published_bots
+        .filter(id.eq(pid))
+        .first::<SqlPublishedBot>(&mut conn)?;
+    Ok(PublishedBot::from(published_bot))
+}
+
+pub fn is_bot_published(bid: String) -> Result<bool, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let count: i64 = published_bots
+        .filter(bot_id.eq(bid))
+        .count()
+        .get_result(&mut conn)?;
+    Ok(count > 0)
+}
+
+/// Published bots, of a single competition if `com_id` is given, newest first.
+pub fn get_published_bots(com_id: Option<String>) -> Result<Vec<PublishedBot>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let mut query = published_bots.into_boxed();
+    if let Some(c) = com_id {
+        query = query.filter(competition_id.eq(c));
+    }
+    let bots = query
+        .order(created.desc())
+        .load::<SqlPublishedBot>(&mut conn)?;
+    Ok(bots.into_iter().map(PublishedBot::from).collect::<Vec<PublishedBot>>())
+}
+
+pub fn delete_published_bot(pid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(published_bots.filter(id.eq(pid)))
+        .execute(&mut conn)
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::rate_limits::dsl::*;
+use crate::models::rate_limit::{SqlRateLimit, RateLimit, NewRateLimit};
+use super::operations_db::establish_connection;
+
+
+/// Stores the rate limits of a competition, replacing its previous limits.
+pub fn upsert_rate_limit(limit: NewRateLimit) -> Result<RateLimit, Error> {
+    let new_limit = SqlRateLimit::from(limit);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(rate_limits.filter(competition_id.eq(&new_limit.competition_id))).execute(conn)?;
+        insert_into(rate_limits).values(&new_limit).execute(conn)
+    })?;
+    Ok(RateLimit::from(new_limit))
+}
+
+pub fn get_rate_limit_by_competition_id(com_id: String) -> Result<Option<RateLimit>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let limit = rate_limits
+        .filter(competition_id.eq(com_id))
+        .first::<SqlRateLimit>(&mut conn)
+        .optional()?;
+    Ok(limit.map(RateLimit::from))
+}
+
+pub fn get_rate_limits_by_competition_ids(com_ids: Vec<String>) -> Result<Vec<RateLimit>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let limits = rate_limits
+        .filter(competition_id.eq_any(com_ids))
+        .load::<SqlRateLimit>(&mut conn)?;
+    Ok(limits.into_iter().map(RateLimit::from).collect())
+}
\ No newline at end of file
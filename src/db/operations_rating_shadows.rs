@@ -0,0 +1,121 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{elo_history, game_rating_shadows, games_2v2, teams};
+use crate::models::elo_history::{NewEloHistory, SqlEloHistory};
+use crate::models::rating_recompute::{GameRatingShadow, SqlGameRatingShadow};
+use crate::models::team::TeamRating;
+use super::operations_db::{establish_connection, DbConnection};
+
+
+/// Stores the result of a recompute next to the competition's ratings, replacing the
+/// result of an earlier recompute.
+pub fn store_rating_shadows(cid: String, ratings: Vec<TeamRating>, games: Vec<GameRatingShadow>) -> Result<(), Error> {
+    let new_shadows = games
+        .into_iter()
+        .map(SqlGameRatingShadow::from)
+        .collect::<Vec<SqlGameRatingShadow>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        clear_rating_shadows(conn, &cid)?;
+        if !new_shadows.is_empty() {
+            insert_into(game_rating_shadows::table)
+                .values(&new_shadows)
+                .execute(conn)?;
+        }
+        for rating in ratings.iter() {
+            diesel::update(teams::table.find(&rating.team_id))
+                .set((
+                    teams::shadow_elo.eq(rating.elo),
+                    teams::shadow_rating_mu.eq(rating.rating_mu),
+                    teams::shadow_rating_sigma.eq(rating.rating_sigma),
+                ))
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+/// Recomputed ratings of the competition's teams, empty if there is no recompute to apply.
+pub fn get_team_rating_shadows(cid: String) -> Result<Vec<TeamRating>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let shadows = teams::table
+        .filter(teams::competition_id.eq(cid))
+        .filter(teams::deleted_at.is_null())
+        .filter(teams::shadow_elo.is_not_null())
+        .select((
+            teams::id,
+            teams::shadow_elo.assume_not_null(),
+            teams::shadow_rating_mu.assume_not_null(),
+            teams::shadow_rating_sigma.assume_not_null(),
+        ))
+        .load::<(String, i32, f64, f64)>(&mut conn)?;
+    Ok(shadows
+        .into_iter()
+        .map(|(team_id, elo, rating_mu, rating_sigma)| TeamRating { team_id, elo, rating_mu, rating_sigma })
+        .collect())
+}
+
+pub fn get_game_rating_shadows(cid: String) -> Result<Vec<GameRatingShadow>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let shadows = game_rating_shadows::table
+        .filter(game_rating_shadows::competition_id.eq(cid))
+        .load::<SqlGameRatingShadow>(&mut conn)?;
+    Ok(shadows.into_iter().map(GameRatingShadow::from).collect())
+}
+
+/// Replaces the competition's ratings, the ELO changes of its games and its ELO history with
+/// the recomputed ones in a single transaction and removes the recompute.
+pub fn apply_rating_shadows(cid: String, games: Vec<GameRatingShadow>, history: Vec<NewEloHistory>) -> Result<(), Error> {
+    let new_history = history
+        .into_iter()
+        .map(SqlEloHistory::from)
+        .collect::<Vec<SqlEloHistory>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        diesel::update(teams::table
+            .filter(teams::competition_id.eq(&cid))
+            .filter(teams::shadow_elo.is_not_null()))
+            .set((
+                teams::elo.eq(teams::shadow_elo.assume_not_null()),
+                teams::rating_mu.eq(teams::shadow_rating_mu.assume_not_null()),
+                teams::rating_sigma.eq(teams::shadow_rating_sigma.assume_not_null()),
+            ))
+            .execute(conn)?;
+        for game in games.iter() {
+            diesel::update(games_2v2::table.find(&game.game_id))
+                .set((
+                    games_2v2::team1_elo.eq(game.team1_elo),
+                    games_2v2::team2_elo.eq(game.team2_elo),
+                ))
+                .execute(conn)?;
+        }
+        diesel::delete(elo_history::table.filter(elo_history::competition_id.eq(&cid)))
+            .execute(conn)?;
+        if !new_history.is_empty() {
+            insert_into(elo_history::table)
+                .values(&new_history)
+                .execute(conn)?;
+        }
+        clear_rating_shadows(conn, &cid)
+    })
+}
+
+pub fn discard_rating_shadows(cid: String) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| clear_rating_shadows(conn, &cid))
+}
+
+fn clear_rating_shadows(conn: &mut DbConnection, cid: &str) -> Result<(), Error> {
+    diesel::delete(game_rating_shadows::table.filter(game_rating_shadows::competition_id.eq(cid)))
+        .execute(conn)?;
+    diesel::update(teams::table.filter(teams::competition_id.eq(cid)))
+        .set((
+            teams::shadow_elo.eq(None::<i32>),
+            teams::shadow_rating_mu.eq(None::<f64>),
+            teams::shadow_rating_sigma.eq(None::<f64>),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
\ No newline at end of file
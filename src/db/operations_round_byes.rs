@@ -0,0 +1,38 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::round_byes;
+use crate::models::round_bye::{NewRoundBye, RoundBye, SqlRoundBye};
+use super::operations_db::establish_connection;
+
+
+/// Replaces the byes and forfeits recorded for a round, so a round that is started again
+/// doesn't count them twice.
+pub fn replace_round_byes(cid: String, round: i32, byes: Vec<NewRoundBye>) -> Result<(), Error> {
+    let rows = byes
+        .into_iter()
+        .map(SqlRoundBye::from)
+        .collect::<Vec<SqlRoundBye>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        diesel::delete(round_byes::table
+            .filter(round_byes::competition_id.eq(&cid))
+            .filter(round_byes::round.eq(round)))
+            .execute(conn)?;
+        if !rows.is_empty() {
+            insert_into(round_byes::table)
+                .values(&rows)
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn get_round_byes_by_competition_id(cid: String) -> Result<Vec<RoundBye>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let byes = round_byes::table
+        .filter(round_byes::competition_id.eq(cid))
+        .order((round_byes::round.asc(), round_byes::created.asc()))
+        .load::<SqlRoundBye>(&mut conn)?;
+    Ok(byes.into_iter().map(RoundBye::from).collect())
+}
\ No newline at end of file
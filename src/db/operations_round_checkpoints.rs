@@ -0,0 +1,45 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::round_checkpoints::dsl::*;
+use crate::models::round_checkpoint::{NewRoundCheckpoint, RoundCheckpoint, SqlRoundCheckpoint};
+use super::operations_db::establish_connection;
+
+
+/// Replaces the saved progress of a competition's round with the given matches.
+pub fn save_round_checkpoints(cid: String, r: i32, checkpoints: Vec<NewRoundCheckpoint>) -> Result<(), Error> {
+    let new_checkpoints = checkpoints
+        .into_iter()
+        .map(SqlRoundCheckpoint::from)
+        .collect::<Vec<SqlRoundCheckpoint>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        diesel::delete(round_checkpoints.filter(competition_id.eq(&cid)).filter(round.eq(r)))
+            .execute(conn)?;
+        if !new_checkpoints.is_empty() {
+            insert_into(round_checkpoints)
+                .values(&new_checkpoints)
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn get_round_checkpoints(cid: String, r: i32) -> Result<Vec<RoundCheckpoint>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let checkpoints = round_checkpoints
+        .filter(competition_id.eq(cid))
+        .filter(round.eq(r))
+        .order(created.asc())
+        .load::<SqlRoundCheckpoint>(&mut conn)?;
+    Ok(checkpoints.into_iter().map(RoundCheckpoint::from).collect::<Vec<RoundCheckpoint>>())
+}
+
+/// Ids of the competitions that have an interrupted round waiting to be resumed.
+pub fn get_checkpointed_competition_ids() -> Result<Vec<String>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    round_checkpoints
+        .select(competition_id)
+        .distinct()
+        .load::<String>(&mut conn)
+}
\ No newline at end of file
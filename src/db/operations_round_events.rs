@@ -0,0 +1,33 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::round_events::dsl::*;
+use crate::models::round_event::{SqlRoundEvent, RoundEvent, NewRoundEvent};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_round_event(event: NewRoundEvent) -> Result<RoundEvent, Error> {
+    let new_event = SqlRoundEvent::from(event);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(round_events)
+        .values(&new_event)
+        .execute(&mut conn)?;
+    Ok(RoundEvent::from(new_event))
+}
+
+pub fn get_round_events_by_competition_id(com_id: String) -> Result<Vec<RoundEvent>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let events = round_events
+        .filter(competition_id.eq(com_id))
+        .order(created.asc())
+        .load::<SqlRoundEvent>(&mut conn)?;
+    Ok(events.into_iter().map(RoundEvent::from).collect::<Vec<RoundEvent>>())
+}
+
+pub fn get_round_events_by_trace_id(tid: String) -> Result<Vec<RoundEvent>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let events = round_events
+        .filter(trace_id.eq(tid))
+        .order(created.asc())
+        .load::<SqlRoundEvent>(&mut conn)?;
+    Ok(events.into_iter().map(RoundEvent::from).collect::<Vec<RoundEvent>>())
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::round_hooks::dsl::*;
+use crate::models::round_hook::{SqlRoundHook, RoundHook, NewRoundHook};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_round_hook(hook: NewRoundHook) -> Result<RoundHook, Error> {
+    let new_hook = SqlRoundHook::from(hook);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(round_hooks)
+        .values(&new_hook)
+        .execute(&mut conn)?;
+    Ok(RoundHook::from(new_hook))
+}
+
+pub fn get_round_hooks_by_competition_id(com_id: String) -> Result<Vec<RoundHook>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let hooks = round_hooks
+        .filter(competition_id.eq(com_id))
+        .order(created.asc())
+        .load::<SqlRoundHook>(&mut conn)?;
+    Ok(hooks.into_iter().map(RoundHook::from).collect::<Vec<RoundHook>>())
+}
+
+pub fn delete_round_hook(hook_id: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(round_hooks.filter(id.eq(hook_id)))
+        .execute(&mut conn)
+}
\ No newline at end of file
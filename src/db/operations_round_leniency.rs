@@ -0,0 +1,30 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::round_leniencies::dsl::*;
+use crate::models::round_leniency::{SqlRoundLeniency, RoundLeniency, NewRoundLeniency};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_round_leniency(leniency: NewRoundLeniency) -> Result<RoundLeniency, Error> {
+    let new_leniency = SqlRoundLeniency::from(leniency);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(round_leniencies)
+        .values(&new_leniency)
+        .execute(&mut conn)?;
+    Ok(RoundLeniency::from(new_leniency))
+}
+
+pub fn get_round_leniencies_by_competition_id(com_id: String) -> Result<Vec<RoundLeniency>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let leniencies = round_leniencies
+        .filter(competition_id.eq(com_id))
+        .order(from_round.asc())
+        .load::<SqlRoundLeniency>(&mut conn)?;
+    Ok(leniencies.into_iter().map(RoundLeniency::from).collect::<Vec<RoundLeniency>>())
+}
+
+pub fn delete_round_leniency(leniency_id: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(round_leniencies.filter(id.eq(leniency_id)))
+        .execute(&mut conn)
+}
\ No newline at end of file
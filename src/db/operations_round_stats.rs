@@ -0,0 +1,27 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::round_stats::dsl::*;
+use crate::models::round_stats::{SqlRoundStats, RoundStats, NewRoundStats};
+use super::operations_db::establish_connection;
+
+
+/// Stores the rollup of a round, replacing an earlier rollup of the same round.
+pub fn upsert_round_stats(stats: NewRoundStats) -> Result<RoundStats, Error> {
+    let new_stats = SqlRoundStats::from(stats);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(round_stats.filter(competition_id.eq(&new_stats.competition_id)).filter(round.eq(new_stats.round)))
+            .execute(conn)?;
+        insert_into(round_stats).values(&new_stats).execute(conn)
+    })?;
+    Ok(RoundStats::from(new_stats))
+}
+
+pub fn get_round_stats_by_competition_id(com_id: String) -> Result<Vec<RoundStats>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let stats = round_stats
+        .filter(competition_id.eq(com_id))
+        .order(round.asc())
+        .load::<SqlRoundStats>(&mut conn)?;
+    Ok(stats.into_iter().map(RoundStats::from).collect::<Vec<RoundStats>>())
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+use diesel::result::{DatabaseErrorKind, Error};
+use chrono::{Local, NaiveDateTime};
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{competitions, elo_history, game_player_stats, games_2v2, round_checkpoints, rounds, teams};
+use crate::models::elo_history::{NewEloHistory, SqlEloHistory};
+use crate::models::game_2v2::PendingGame2v2;
+use crate::models::game_player_stats::SqlGamePlayerStats;
+use crate::models::round::{NewRound, PairingMetadata, Round, RoundStatus, SqlRound};
+use crate::models::team::TeamRating;
+use super::operations_db::establish_connection;
+use super::operations_team_streaks::apply_round_to_team_streaks;
+
+
+/// Stores the outcome of a round in a single transaction: its games with their player stats,
+/// the teams' streak aggregates, the teams' new ratings with their ELO history and the
+/// competition's next round number.
+/// The round's checkpoints are removed with it and the round is marked as finished. If any of
+/// it fails, or the competition already moved past the round, nothing is stored.
+pub fn complete_round(
+    cid: String,
+    new_round: i32,
+    failed_matches: usize,
+    games: Vec<PendingGame2v2>,
+    ratings: Vec<TeamRating>,
+    history: Vec<NewEloHistory>,
+) -> Result<(), Error> {
+    let mut new_games = Vec::with_capacity(games.len());
+    let mut new_stats = Vec::new();
+    for pending in games.into_iter() {
+        new_games.push(pending.game);
+        new_stats.extend(pending.player_stats.into_iter().map(SqlGamePlayerStats::from));
+    }
+    let new_history = history
+        .into_iter()
+        .map(SqlEloHistory::from)
+        .collect::<Vec<SqlEloHistory>>();
+    let games_played = new_games.len() as i32;
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        if !new_games.is_empty() {
+            insert_into(games_2v2::table)
+                .values(&new_games)
+                .execute(conn)?;
+        }
+        if !new_stats.is_empty() {
+            insert_into(game_player_stats::table)
+                .values(&new_stats)
+                .execute(conn)?;
+        }
+        // before the ratings change, upsets are measured against the ELO before the round
+        apply_round_to_team_streaks(conn, &cid, new_round - 1, &new_games)?;
+        for rating in ratings.iter() {
+            diesel::update(teams::table.find(&rating.team_id))
+                .set((
+                    teams::elo.eq(rating.elo),
+                    teams::rating_mu.eq(rating.rating_mu),
+                    teams::rating_sigma.eq(rating.rating_sigma),
+                ))
+                .execute(conn)?;
+        }
+        if !new_history.is_empty() {
+            insert_into(elo_history::table)
+                .values(&new_history)
+                .execute(conn)?;
+        }
+        diesel::delete(round_checkpoints::table
+            .filter(round_checkpoints::competition_id.eq(&cid))
+            .filter(round_checkpoints::round.lt(new_round)))
+            .execute(conn)?;
+        diesel::update(rounds::table
+            .filter(rounds::competition_id.eq(&cid))
+            .filter(rounds::round.eq(new_round - 1)))
+            .set((
+                rounds::status.eq(RoundStatus::Finished.to_string()),
+                rounds::finished_at.eq(Local::now().naive_utc()),
+                rounds::games_played.eq(games_played),
+                rounds::failed_matches.eq(failed_matches as i32),
+            ))
+            .execute(conn)?;
+        // only the run that played the competition's current round moves it on
+        let advanced = diesel::update(competitions::table
+            .filter(competitions::id.eq(cid))
+            .filter(competitions::round.eq(new_round - 1)))
+            .set(competitions::round.eq(new_round))
+            .execute(conn)?;
+        if advanced == 0 {
+            return Err(Error::RollbackTransaction);
+        }
+        Ok(())
+    })
+}
+
+/// Claims a round for the run that is about to play it. A round that failed or was
+/// interrupted before is claimed again and keeps its seed and scheduled start; a round that is
+/// running or was played can't be claimed, so overlapping runs of a competition can't play it
+/// twice.
+///
+/// # Returns
+///
+/// The seed the round is paired from and the start it was scheduled for, or `None` if the
+/// round couldn't be claimed.
+///
+pub fn start_round(new_round: NewRound) -> Result<Option<(i64, NaiveDateTime)>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let claimed = conn.transaction(|conn| {
+        let existing = rounds::table
+            .filter(rounds::competition_id.eq(&new_round.competition_id))
+            .filter(rounds::round.eq(new_round.round))
+            .for_update()
+            .first::<SqlRound>(conn)
+            .optional()?;
+        match existing {
+            Some(r) => {
+                let status = RoundStatus::from(r.status.as_str());
+                if status == RoundStatus::Running || status == RoundStatus::Finished {
+                    return Ok(None);
+                }
+                diesel::update(rounds::table.find(&r.id))
+                    .set((
+                        rounds::status.eq(RoundStatus::Running.to_string()),
+                        rounds::started_at.eq(Local::now().naive_utc()),
+                        rounds::finished_at.eq(None::<chrono::NaiveDateTime>),
+                    ))
+                    .execute(conn)?;
+                Ok(Some((r.seed, r.scheduled_at)))
+            },
+            None => {
+                let claimed = (new_round.seed, new_round.scheduled_at);
+                insert_into(rounds::table)
+                    .values(&SqlRound::from(new_round))
+                    .execute(conn)?;
+                Ok(Some(claimed))
+            },
+        }
+    });
+    match claimed {
+        // another run inserted the round first
+        Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => Ok(None),
+        claimed => claimed,
+    }
+}
+
+/// Stores what the pairing of a round was based on and what it came up with.
+pub fn set_round_pairing(cid: String, round: i32, metadata: &PairingMetadata) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(rounds::table
+        .filter(rounds::competition_id.eq(cid))
+        .filter(rounds::round.eq(round)))
+        .set(rounds::metadata.eq(serde_json::to_string(metadata).unwrap_or_default()))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Ends a running round of a competition that didn't finish, other rounds of the competition
+/// are left alone.
+pub fn end_round(cid: String, round_number: i32, status: RoundStatus) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(rounds::table
+        .filter(rounds::competition_id.eq(cid))
+        .filter(rounds::round.eq(round_number))
+        .filter(rounds::status.eq(RoundStatus::Running.to_string())))
+        .set((
+            rounds::status.eq(status.to_string()),
+            rounds::finished_at.eq(Local::now().naive_utc()),
+        ))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+/// Marks the rounds of every competition that are still running as interrupted, used on
+/// startup when no round can be running anymore.
+pub fn interrupt_running_rounds() -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(rounds::table.filter(rounds::status.eq(RoundStatus::Running.to_string())))
+        .set((
+            rounds::status.eq(RoundStatus::Interrupted.to_string()),
+            rounds::finished_at.eq(Local::now().naive_utc()),
+        ))
+        .execute(&mut conn)
+}
+
+pub fn get_round(cid: String, round: i32) -> Result<Option<Round>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let stored = rounds::table
+        .filter(rounds::competition_id.eq(cid))
+        .filter(rounds::round.eq(round))
+        .first::<SqlRound>(&mut conn)
+        .optional()?;
+    Ok(stored.map(Round::from))
+}
+
+pub fn get_rounds_by_competition_id(cid: String) -> Result<Vec<Round>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let stored = rounds::table
+        .filter(rounds::competition_id.eq(cid))
+        .order(rounds::round.asc())
+        .load::<SqlRound>(&mut conn)?;
+    Ok(stored.into_iter().map(Round::from).collect())
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use diesel::result::Error;
+use diesel::{prelude::*, sql_query, sql_types::{BigInt, Varchar}};
+use crate::models::scouting::SqlScoutingStats;
+use super::operations_db::establish_connection;
+
+/// Aggregates the stats of the team's bots over the team's most recent ranked games.
+#[cfg(feature = "mysql")]
+const SCOUTING_QUERY: &str = "
+SELECT
+    COUNT(DISTINCT s.game_id) AS games,
+    COUNT(*) AS bot_games,
+    CAST(COALESCE(AVG(s.turns_played), 0) AS DOUBLE) AS avg_turns_played,
+    CAST(COALESCE(MIN(s.turns_played), 0) AS SIGNED) AS min_turns_played,
+    CAST(COALESCE(MAX(s.turns_played), 0) AS SIGNED) AS max_turns_played,
+    CAST(COALESCE(SUM(CASE WHEN s.survived THEN 1 ELSE 0 END), 0) AS SIGNED) AS times_survived,
+    CAST(COALESCE(SUM(s.turns_played), 0) AS SIGNED) AS total_turns_played,
+    CAST(COALESCE(SUM(s.planets_attacked), 0) AS SIGNED) AS total_planets_attacked,
+    CAST(COALESCE(SUM(s.planets_conquered), 0) AS SIGNED) AS total_planets_conquered,
+    CAST(COALESCE(SUM(s.planets_lost), 0) AS SIGNED) AS total_planets_lost,
+    CAST(COALESCE(SUM(s.fleet_generated), 0) AS SIGNED) AS total_fleet_generated,
+    CAST(COALESCE(SUM(s.fleet_reinforced), 0) AS SIGNED) AS total_fleet_reinforced,
+    CAST(COALESCE(MAX(s.largest_attack), 0) AS SIGNED) AS largest_attack
+FROM game_player_stats s
+JOIN (
+    SELECT id FROM games_2v2
+    WHERE (team1_id = ? OR team2_id = ?) AND team1_id <> team2_id
+    ORDER BY created DESC
+    LIMIT ?
+) recent ON recent.id = s.game_id
+WHERE s.team_id = ?";
+
+/// The same query in PostgreSQL's dialect, which numbers its placeholders and has no SIGNED.
+#[cfg(feature = "postgres")]
+const SCOUTING_QUERY: &str = "
+SELECT
+    COUNT(DISTINCT s.game_id) AS games,
+    COUNT(*) AS bot_games,
+    CAST(COALESCE(AVG(s.turns_played), 0) AS DOUBLE PRECISION) AS avg_turns_played,
+    CAST(COALESCE(MIN(s.turns_played), 0) AS BIGINT) AS min_turns_played,
+    CAST(COALESCE(MAX(s.turns_played), 0) AS BIGINT) AS max_turns_played,
+    CAST(COALESCE(SUM(CASE WHEN s.survived THEN 1 ELSE 0 END), 0) AS BIGINT) AS times_survived,
+    CAST(COALESCE(SUM(s.turns_played), 0) AS BIGINT) AS total_turns_played,
+    CAST(COALESCE(SUM(s.planets_attacked), 0) AS BIGINT) AS total_planets_attacked,
+    CAST(COALESCE(SUM(s.planets_conquered), 0) AS BIGINT) AS total_planets_conquered,
+    CAST(COALESCE(SUM(s.planets_lost), 0) AS BIGINT) AS total_planets_lost,
+    CAST(COALESCE(SUM(s.fleet_generated), 0) AS BIGINT) AS total_fleet_generated,
+    CAST(COALESCE(SUM(s.fleet_reinforced), 0) AS BIGINT) AS total_fleet_reinforced,
+    CAST(COALESCE(MAX(s.largest_attack), 0) AS BIGINT) AS largest_attack
+FROM game_player_stats s
+JOIN (
+    SELECT id FROM games_2v2
+    WHERE (team1_id = $1 OR team2_id = $2) AND team1_id <> team2_id
+    ORDER BY created DESC
+    LIMIT $3
+) recent ON recent.id = s.game_id
+WHERE s.team_id = $4";
+
+pub fn get_scouting_stats(team_id: String, games: i64) -> Result<SqlScoutingStats, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    sql_query(SCOUTING_QUERY)
+        .bind::<Varchar, _>(team_id.clone())
+        .bind::<Varchar, _>(team_id.clone())
+        .bind::<BigInt, _>(games)
+        .bind::<Varchar, _>(team_id)
+        .get_result::<SqlScoutingStats>(&mut conn)
+}
\ No newline at end of file
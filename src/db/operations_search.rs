@@ -0,0 +1,80 @@
+use diesel::result::Error;
+use diesel::prelude::*;
+use crate::db::schema::{competitions, teams, users};
+use crate::models::competition::{Competition, SqlCompetition};
+use crate::models::team::{SqlTeam, Team};
+use crate::models::user::{SqlUser, User};
+use super::operations_db::establish_connection;
+
+#[cfg(feature = "postgres")]
+sql_function!(fn lower(x: diesel::sql_types::Text) -> diesel::sql_types::Text);
+
+// MySQL's default collation compares case insensitively and keeps the plain index usable,
+// PostgreSQL compares the lowercase value its migration indexes
+#[cfg(feature = "mysql")]
+macro_rules! search_key {
+    ($column:expr) => { $column };
+}
+#[cfg(feature = "postgres")]
+macro_rules! search_key {
+    ($column:expr) => { lower($column) };
+}
+
+/// LIKE pattern matching values that start with the lowercase `term`.
+fn prefix_pattern(term: &str) -> String {
+    let escaped = term
+        .to_lowercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{}%", escaped)
+}
+
+/// Users whose username or display name starts with `term`, at most `max` of them in
+/// alphabetical order.
+pub fn search_users(term: &str, max: i64) -> Result<Vec<User>, Error> {
+    let pattern = prefix_pattern(term);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let found = users::table
+        .filter(search_key!(users::username).like(&pattern).or(search_key!(users::display_name).like(&pattern)))
+        .order(users::username.asc())
+        .limit(max)
+        .load::<SqlUser>(&mut conn)?;
+    Ok(found.into_iter().map(User::from).collect())
+}
+
+/// Teams whose name starts with `term` or that one of `member_ids` owns or is a partner of,
+/// at most `max` of them in alphabetical order.
+pub fn search_teams(term: &str, member_ids: Vec<String>, cid: Option<String>, max: i64) -> Result<Vec<Team>, Error> {
+    let pattern = prefix_pattern(term);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let mut query = teams::table
+        .filter(teams::deleted_at.is_null())
+        .filter(
+            search_key!(teams::name).like(pattern)
+                .or(teams::owner.eq_any(member_ids.clone()))
+                .or(teams::partner.eq_any(member_ids))
+        )
+        .into_boxed();
+    if let Some(c) = cid {
+        query = query.filter(teams::competition_id.eq(c));
+    }
+    let found = query
+        .order(teams::name.asc())
+        .limit(max)
+        .load::<SqlTeam>(&mut conn)?;
+    Ok(found.into_iter().map(Team::from).collect())
+}
+
+/// Competitions whose name starts with `term`, at most `max` of them in alphabetical order.
+pub fn search_competitions(term: &str, max: i64) -> Result<Vec<Competition>, Error> {
+    let pattern = prefix_pattern(term);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let found = competitions::table
+        .filter(competitions::deleted_at.is_null())
+        .filter(search_key!(competitions::name).like(pattern))
+        .order(competitions::name.asc())
+        .limit(max)
+        .load::<SqlCompetition>(&mut conn)?;
+    Ok(found.into_iter().map(Competition::from).collect())
+}
@@ -0,0 +1,52 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::{season_competitions, seasons};
+use crate::models::season::{NewSeason, Season, SqlSeason, SqlSeasonCompetition};
+use super::operations_db::establish_connection;
+
+
+/// Stores a season together with the competitions it groups.
+pub fn insert_season(season: NewSeason) -> Result<Season, Error> {
+    let competition_ids = season.competition_ids.clone();
+    let new_season = SqlSeason::from(season);
+    let links = competition_ids
+        .into_iter()
+        .map(|cid| SqlSeasonCompetition { season_id: new_season.id.clone(), competition_id: cid })
+        .collect::<Vec<SqlSeasonCompetition>>();
+
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        insert_into(seasons::table)
+            .values(&new_season)
+            .execute(conn)?;
+        insert_into(season_competitions::table)
+            .values(&links)
+            .execute(conn)
+    })?;
+    Ok(Season::from(new_season))
+}
+
+pub fn get_season_by_id(sid: String) -> Result<Season, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let season = seasons::table
+        .filter(seasons::id.eq(sid))
+        .first::<SqlSeason>(&mut conn)?;
+    Ok(Season::from(season))
+}
+
+/// All seasons, newest first.
+pub fn get_all_seasons() -> Result<Vec<Season>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let all_seasons = seasons::table
+        .order(seasons::created.desc())
+        .load::<SqlSeason>(&mut conn)?;
+    Ok(all_seasons.into_iter().map(Season::from).collect::<Vec<Season>>())
+}
+
+pub fn get_season_competition_ids(sid: String) -> Result<Vec<String>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    season_competitions::table
+        .filter(season_competitions::season_id.eq(sid))
+        .select(season_competitions::competition_id)
+        .load::<String>(&mut conn)
+}
\ No newline at end of file
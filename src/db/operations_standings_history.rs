@@ -0,0 +1,35 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::standings_history;
+use crate::models::standings_history::{SqlStandingsEntry, StandingsEntry};
+use super::operations_db::establish_connection;
+
+
+/// Stores the standings at the end of a round, replacing an earlier snapshot of the round.
+pub fn replace_standings_snapshot(cid: String, r: i32, entries: Vec<StandingsEntry>) -> Result<(), Error> {
+    let rows = entries.into_iter().map(SqlStandingsEntry::from).collect::<Vec<SqlStandingsEntry>>();
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        diesel::delete(standings_history::table
+            .filter(standings_history::competition_id.eq(&cid))
+            .filter(standings_history::round.eq(r)))
+            .execute(conn)?;
+        if !rows.is_empty() {
+            insert_into(standings_history::table)
+                .values(&rows)
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+/// The standings at the end of a round, best first.
+pub fn get_standings_snapshot(cid: String, r: i32) -> Result<Vec<StandingsEntry>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let entries = standings_history::table
+        .filter(standings_history::competition_id.eq(cid))
+        .filter(standings_history::round.eq(r))
+        .order((standings_history::position.asc(), standings_history::team_name.asc()))
+        .load::<SqlStandingsEntry>(&mut conn)?;
+    Ok(entries.into_iter().map(StandingsEntry::from).collect::<Vec<StandingsEntry>>())
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use diesel::result::Error;
+use diesel::{prelude::*, sql_query, sql_types::Varchar};
+use crate::models::team_stats::SqlTeamStats;
+use super::operations_db::establish_connection;
+
+/// Aggregates the stats of all bots that played for the team.
+#[cfg(feature = "mysql")]
+const TEAM_STATS_QUERY: &str = "
+SELECT
+    COUNT(*) AS bot_games,
+    CAST(COALESCE(AVG(turns_played), 0) AS DOUBLE) AS avg_turns_played,
+    CAST(COALESCE(SUM(CASE WHEN survived THEN 1 ELSE 0 END), 0) AS SIGNED) AS times_survived,
+    CAST(COALESCE(SUM(fleet_generated), 0) AS SIGNED) AS total_fleet_generated,
+    CAST(COALESCE(SUM(fleet_lost), 0) AS SIGNED) AS total_fleet_lost,
+    CAST(COALESCE(SUM(fleet_reinforced), 0) AS SIGNED) AS total_fleet_reinforced,
+    CAST(COALESCE(MAX(largest_attack), 0) AS SIGNED) AS largest_attack,
+    CAST(COALESCE(MAX(largest_loss), 0) AS SIGNED) AS largest_loss,
+    CAST(COALESCE(MAX(largest_reinforcement), 0) AS SIGNED) AS largest_reinforcement,
+    CAST(COALESCE(SUM(planets_lost), 0) AS SIGNED) AS total_planets_lost,
+    CAST(COALESCE(SUM(planets_conquered), 0) AS SIGNED) AS total_planets_conquered,
+    CAST(COALESCE(SUM(planets_defended), 0) AS SIGNED) AS total_planets_defended,
+    CAST(COALESCE(SUM(planets_attacked), 0) AS SIGNED) AS total_planets_attacked,
+    CAST(COALESCE(SUM(total_troops_generated), 0) AS SIGNED) AS total_troops_generated
+FROM game_player_stats
+WHERE team_id = ?";
+
+/// The same query in PostgreSQL's dialect, which numbers its placeholders and has no SIGNED.
+#[cfg(feature = "postgres")]
+const TEAM_STATS_QUERY: &str = "
+SELECT
+    COUNT(*) AS bot_games,
+    CAST(COALESCE(AVG(turns_played), 0) AS DOUBLE PRECISION) AS avg_turns_played,
+    CAST(COALESCE(SUM(CASE WHEN survived THEN 1 ELSE 0 END), 0) AS BIGINT) AS times_survived,
+    CAST(COALESCE(SUM(fleet_generated), 0) AS BIGINT) AS total_fleet_generated,
+    CAST(COALESCE(SUM(fleet_lost), 0) AS BIGINT) AS total_fleet_lost,
+    CAST(COALESCE(SUM(fleet_reinforced), 0) AS BIGINT) AS total_fleet_reinforced,
+    CAST(COALESCE(MAX(largest_attack), 0) AS BIGINT) AS largest_attack,
+    CAST(COALESCE(MAX(largest_loss), 0) AS BIGINT) AS largest_loss,
+    CAST(COALESCE(MAX(largest_reinforcement), 0) AS BIGINT) AS largest_reinforcement,
+    CAST(COALESCE(SUM(planets_lost), 0) AS BIGINT) AS total_planets_lost,
+    CAST(COALESCE(SUM(planets_conquered), 0) AS BIGINT) AS total_planets_conquered,
+    CAST(COALESCE(SUM(planets_defended), 0) AS BIGINT) AS total_planets_defended,
+    CAST(COALESCE(SUM(planets_attacked), 0) AS BIGINT) AS total_planets_attacked,
+    CAST(COALESCE(SUM(total_troops_generated), 0) AS BIGINT) AS total_troops_generated
+FROM game_player_stats
+WHERE team_id = $1";
+
+pub fn get_team_stats(team_id: String) -> Result<SqlTeamStats, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    sql_query(TEAM_STATS_QUERY)
+        .bind::<Varchar, _>(team_id)
+        .get_result::<SqlTeamStats>(&mut conn)
+}
\ No newline at end of file
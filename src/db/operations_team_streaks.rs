@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::{round_byes, team_streaks, teams};
+use crate::models::game_2v2::SqlGame2v2;
+use crate::models::round_bye::ByeKind;
+use crate::models::team::DEFAULT_ELO;
+use crate::models::team_streak::{SqlTeamStreak, TeamStreak};
+use super::operations_db::{establish_connection, DbConnection};
+
+
+pub fn get_team_streaks_by_competition_id(cid: String) -> Result<Vec<TeamStreak>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let streaks = team_streaks::table
+        .filter(team_streaks::competition_id.eq(cid))
+        .load::<SqlTeamStreak>(&mut conn)?;
+    Ok(streaks.into_iter().map(TeamStreak::from).collect::<Vec<TeamStreak>>())
+}
+
+/// Replaces all aggregates of a competition, e.g. after they were rebuilt from its games.
+pub fn replace_team_streaks(cid: String, streaks: Vec<TeamStreak>) -> Result<(), Error> {
+    let rows = streaks.into_iter().map(SqlTeamStreak::from).collect::<Vec<SqlTeamStreak>>();
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(team_streaks::table.filter(team_streaks::competition_id.eq(&cid)))
+            .execute(conn)?;
+        if !rows.is_empty() {
+            insert_into(team_streaks::table)
+                .values(&rows)
+                .execute(conn)?;
+        }
+        Ok(())
+    })
+}
+
+/// Counts the games and forfeits of a round in the teams' aggregates. Runs in the transaction
+/// that stores the round, before the teams' ratings are updated, so the teams' ELO is still
+/// the one they had before the round.
+pub fn apply_round_to_team_streaks(conn: &mut DbConnection, cid: &str, round: i32, games: &[SqlGame2v2]) -> Result<(), Error> {
+    let mut games = games.iter().filter(|g| g.team1_id != g.team2_id && !g.unrated).collect::<Vec<&SqlGame2v2>>();
+    games.sort_by_key(|g| g.created);
+    let forfeits = round_byes::table
+        .filter(round_byes::competition_id.eq(cid))
+        .filter(round_byes::round.eq(round))
+        .filter(round_byes::kind.eq(ByeKind::Forfeit.to_string()))
+        .select(round_byes::team_id)
+        .load::<String>(conn)?;
+
+    let mut team_ids = forfeits.clone();
+    team_ids.extend(games.iter().flat_map(|g| [g.team1_id.clone(), g.team2_id.clone()]));
+    if team_ids.is_empty() {
+        return Ok(());
+    }
+
+    let elo_before = teams::table
+        .filter(teams::id.eq_any(&team_ids))
+        .select((teams::id, teams::elo))
+        .load::<(String, i32)>(conn)?
+        .into_iter()
+        .collect::<HashMap<String, i32>>();
+    let mut streaks = team_streaks::table
+        .filter(team_streaks::team_id.eq_any(&team_ids))
+        .load::<SqlTeamStreak>(conn)?
+        .into_iter()
+        .map(|s| (s.team_id.clone(), TeamStreak::from(s)))
+        .collect::<HashMap<String, TeamStreak>>();
+
+    for game in games.iter() {
+        for (team_id, opponent_id) in [(&game.team1_id, &game.team2_id), (&game.team2_id, &game.team1_id)] {
+            let opponent_elo = elo_before.get(opponent_id).copied().unwrap_or(DEFAULT_ELO);
+            streaks
+                .entry(team_id.clone())
+                .or_insert_with(|| TeamStreak::new(cid.to_string(), team_id.clone()))
+                .record_game(&game.winner_id, opponent_id, opponent_elo);
+        }
+    }
+    for team_id in forfeits.into_iter() {
+        streaks
+            .entry(team_id.clone())
+            .or_insert_with(|| TeamStreak::new(cid.to_string(), team_id))
+            .record_loss();
+    }
+
+    let rows = streaks.into_values().map(SqlTeamStreak::from).collect::<Vec<SqlTeamStreak>>();
+    let replaced = rows.iter().map(|r| r.team_id.clone()).collect::<Vec<String>>();
+    delete(team_streaks::table.filter(team_streaks::team_id.eq_any(&replaced))).execute(conn)?;
+    insert_into(team_streaks::table)
+        .values(&rows)
+        .execute(conn)?;
+    Ok(())
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+use chrono::{Local, NaiveDateTime};
 use diesel::result::Error;
 use diesel::{prelude::*, insert_into};
 use crate::db::schema::teams::dsl::*;
@@ -6,12 +7,35 @@ use crate::models::team::{SqlTeam, Team, NewTeam, BotSelector};
 use super::operations_db::establish_connection;
 
 
-pub fn create_team(team: NewTeam) ->  Result<Team, Error> {
-    let new_team = SqlTeam::from(team);
+/// Registers a team, past the competition's `max_teams` (0 for no limit) it is put on the
+/// waitlist.
+///
+/// The competition row is locked while the active teams are counted, so teams registering
+/// at the same time can't both take the last spot.
+pub fn create_team(team: NewTeam, max_teams: i32) ->  Result<Team, Error> {
+    use crate::db::schema::competitions;
+
+    let mut new_team = SqlTeam::from(team);
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
-    let _ = insert_into(teams)
-        .values(&new_team)
-        .execute(&mut conn)?;
+    conn.transaction(|conn| {
+        competitions::table
+            .find(&new_team.competition_id)
+            .select(competitions::id)
+            .for_update()
+            .first::<String>(conn)?;
+        if max_teams > 0 {
+            let active_teams: i64 = teams
+                .filter(deleted_at.is_null())
+                .filter(competition_id.eq(&new_team.competition_id))
+                .filter(waitlisted.eq(false))
+                .count()
+                .get_result(conn)?;
+            new_team.waitlisted = active_teams >= max_teams as i64;
+        }
+        insert_into(teams)
+            .values(&new_team)
+            .execute(conn)
+    })?;
     Ok(Team::from(new_team))
 }
 
@@ -26,6 +50,7 @@ pub fn join_team(team: Team, user: User) -> Result<(), Error> {
 pub fn get_team_by_id(uid: String) -> Result<Team, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(id.eq(uid))
         .first::<SqlTeam>(&mut conn) {
             Ok(t) => Ok(Team::from(t)),
@@ -36,6 +61,7 @@ pub fn get_team_by_id(uid: String) -> Result<Team, Error> {
 pub fn get_team_by_student_for_competition(user: User, comp_id: String) -> Result<Team, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(competition_id.eq(comp_id))
         .filter(owner.eq(user.id.clone()).or(partner.eq(user.id.clone())))
         .first::<SqlTeam>(&mut conn) {
@@ -47,6 +73,7 @@ pub fn get_team_by_student_for_competition(user: User, comp_id: String) -> Resul
 pub fn get_team_by_student(user: User) -> Result<Vec<Team>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(owner.eq(user.id.clone()).or(partner.eq(user.id.clone())))
         .load::<SqlTeam>(&mut conn) {
             Ok(t) => Ok(t.into_iter().map(Team::from).collect::<Vec<Team>>()),
@@ -54,9 +81,29 @@ pub fn get_team_by_student(user: User) -> Result<Vec<Team>, Error> {
     }
 }
 
+/// Teams the user owns or is a partner of, including deleted ones.
+pub fn get_all_teams_by_student_id(uid: String) -> Result<Vec<Team>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rows = teams
+        .filter(owner.eq(uid.clone()).or(partner.eq(uid)))
+        .order(created.asc())
+        .load::<SqlTeam>(&mut conn)?;
+    Ok(rows.into_iter().map(Team::from).collect::<Vec<Team>>())
+}
+
+pub fn get_teams_by_ids(ids: Vec<String>) -> Result<Vec<Team>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rows = teams
+        .filter(deleted_at.is_null())
+        .filter(id.eq_any(ids))
+        .load::<SqlTeam>(&mut conn)?;
+    Ok(rows.into_iter().map(Team::from).collect::<Vec<Team>>())
+}
+
 pub fn get_teams_by_competition_id(com_id: String) -> Result<Vec<Team>, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(competition_id.eq(com_id))
         .load::<SqlTeam>(&mut conn) {
             Ok(t) => Ok(t.into_iter().map(Team::from).collect::<Vec<Team>>()),
@@ -64,12 +111,43 @@ pub fn get_teams_by_competition_id(com_id: String) -> Result<Vec<Team>, Error> {
     }
 }
 
+/// Teams of a competition that are taking part, i.e. aren't waiting for a free spot.
+pub fn get_active_teams_by_competition_id(com_id: String) -> Result<Vec<Team>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let t = teams
+        .filter(deleted_at.is_null())
+        .filter(competition_id.eq(com_id))
+        .filter(waitlisted.eq(false))
+        .load::<SqlTeam>(&mut conn)?;
+    Ok(t.into_iter().map(Team::from).collect::<Vec<Team>>())
+}
+
+/// Waitlisted teams of a competition, longest waiting first.
+pub fn get_waitlisted_teams_by_competition_id(com_id: String) -> Result<Vec<Team>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let t = teams
+        .filter(deleted_at.is_null())
+        .filter(competition_id.eq(com_id))
+        .filter(waitlisted.eq(true))
+        .order(created.asc())
+        .load::<SqlTeam>(&mut conn)?;
+    Ok(t.into_iter().map(Team::from).collect::<Vec<Team>>())
+}
+
+pub fn set_team_waitlisted(tid: String, on_waitlist: bool) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(teams.filter(id.eq(tid)))
+        .set(waitlisted.eq(on_waitlist))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
 pub fn leave_team(team: Team, user: User) -> Result<(), Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     diesel::update(teams.filter(
             id.eq(team.id).and(partner.eq(user.id))
         ))
-        .set(partner.eq(""))
+        .set(partner.eq(None::<String>))
         .execute(&mut conn)?;
     Ok(())
 }
@@ -79,24 +157,50 @@ pub fn kick_partner(team: Team, user: User) -> Result<(), Error> {
     diesel::update(teams.filter(
             id.eq(team.id).and(owner.eq(user.id))
         ))
-        .set(partner.eq(""))
+        .set(partner.eq(None::<String>))
         .execute(&mut conn)?;
     Ok(())
 }
 
 
+/// Soft deletes the team, its games keep referring to it and an admin can restore it.
 pub fn disband_team(team: Team, user: User) -> Result<(), Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
-    diesel::delete(teams.filter(
+    diesel::update(teams.filter(
             id.eq(team.id).and(owner.eq(user.id))
         ))
+        .set(deleted_at.eq(Local::now().naive_utc()))
         .execute(&mut conn)?;
     Ok(())
 }
 
+pub fn soft_delete_team(tid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(teams.filter(id.eq(tid)).filter(deleted_at.is_null()))
+        .set(deleted_at.eq(Local::now().naive_utc()))
+        .execute(&mut conn)
+}
+
+pub fn get_deleted_team_by_id(tid: String) -> Result<Team, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let team = teams
+        .filter(id.eq(tid))
+        .filter(deleted_at.is_not_null())
+        .first::<SqlTeam>(&mut conn)?;
+    Ok(Team::from(team))
+}
+
+pub fn restore_team(tid: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(teams.filter(id.eq(tid)).filter(deleted_at.is_not_null()))
+        .set(deleted_at.eq(None::<NaiveDateTime>))
+        .execute(&mut conn)
+}
+
 pub fn is_member_of_a_team(user: User) -> bool {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(owner.eq(user.id.clone()).or(partner.eq(user.id.clone())))
         .first::<SqlTeam>(&mut conn) {
             Ok(_) => true,
@@ -107,6 +211,7 @@ pub fn is_member_of_a_team(user: User) -> bool {
 pub fn is_member_of_a_team_on_competition(user: User, comp_id: String) -> bool {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match teams
+        .filter(deleted_at.is_null())
         .filter(competition_id.eq(comp_id))
         .filter(owner.eq(user.id.clone()).or(partner.eq(user.id.clone())))
         .first::<SqlTeam>(&mut conn) {
@@ -123,6 +228,14 @@ pub fn set_team_name(team: &Team, new_name: String) -> Result<Team, Error> {
     get_team_by_id(team.id.clone())
 }
 
+pub fn set_team_privacy(team: &Team, new_alias: String, hide: bool) -> Result<Team, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = diesel::update(teams.filter(id.eq(team.id.clone())))
+        .set((alias.eq(new_alias), hide_members.eq(hide)))
+        .execute(&mut conn)?;
+    get_team_by_id(team.id.clone())
+}
+
 pub fn set_team_bot(team: &Team, bot: BotSelector, bot_id: String) -> Result<(), Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     let builder = diesel::update(teams.filter(id.eq(team.id.clone())));
@@ -131,18 +244,4 @@ pub fn set_team_bot(team: &Team, bot: BotSelector, bot_id: String) -> Result<(),
         BotSelector::Second => builder.set(bot2.eq(bot_id)).execute(&mut conn)?,
     };
     Ok(())
-}
-
-pub fn add_elo_change(tid: String, elo_change: i32) -> Result<(), Error> {
-    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
-    let team: SqlTeam = teams
-        .find(tid.clone())
-        .first(&mut conn)?;
-    // Calculate the new ELO
-    let new_elo = team.elo + elo_change;
-    // Update the team's ELO in the database
-    diesel::update(teams.find(tid))
-        .set(elo.eq(new_elo))
-        .execute(&mut conn)?;
-    Ok(())
 }
\ No newline at end of file
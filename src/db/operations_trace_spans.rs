@@ -0,0 +1,24 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::trace_spans::dsl::*;
+use crate::models::trace_span::{SqlTraceSpan, TraceSpan, NewTraceSpan};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_trace_span(span: NewTraceSpan) -> Result<TraceSpan, Error> {
+    let new_span = SqlTraceSpan::from(span);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(trace_spans)
+        .values(&new_span)
+        .execute(&mut conn)?;
+    Ok(TraceSpan::from(new_span))
+}
+
+pub fn get_trace_spans_by_trace_id(tid: String) -> Result<Vec<TraceSpan>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let spans = trace_spans
+        .filter(trace_id.eq(tid))
+        .order(started.asc())
+        .load::<SqlTraceSpan>(&mut conn)?;
+    Ok(spans.into_iter().map(TraceSpan::from).collect::<Vec<TraceSpan>>())
+}
\ No newline at end of file
@@ -1,7 +1,8 @@
 use diesel::result::Error;
 use diesel::{prelude::*, insert_into};
 use crate::db::schema::users::dsl::*;
-use crate::models::user::{SqlUser, User, NewUser};
+use crate::db::schema::{achievements, api_keys, audit_log, participations, teams};
+use crate::models::user::{SqlUser, User, NewUser, Role, UserErasure, ERASED_USERNAME_PREFIX};
 use super::operations_db::establish_connection;
 
 
@@ -34,6 +35,81 @@ pub fn get_user_by_username(un: String) -> Result<User, Error> {
     }
 }
 
+pub fn set_user_role(uid: String, new_role: &Role) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(users.filter(id.eq(uid)))
+        .set(role.eq(new_role.to_string()))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+pub fn set_user_ldap_dn(uid: String, dn: String) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(users.filter(id.eq(uid)))
+        .set(ldap_dn.eq(dn))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+pub fn set_user_lab_group(uid: String, group: String) -> Result<(), Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(users.filter(id.eq(uid)))
+        .set(lab_group.eq(group))
+        .execute(&mut conn)?;
+    Ok(())
+}
+
+pub fn update_user_profile(uid: String, name: String, mail: String) -> Result<User, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::update(users.filter(id.eq(uid.clone())))
+        .set((display_name.eq(name), email.eq(mail)))
+        .execute(&mut conn)?;
+    let user = users
+        .filter(id.eq(uid))
+        .first::<SqlUser>(&mut conn)?;
+    Ok(User::from(user))
+}
+
+/// Removes a user's personal data. Their achievements and API keys are deleted. A user that
+/// was ever part of a team, or took actions that were audited, is anonymized instead of being
+/// deleted, so the teams, participations and audit entries of past rounds keep pointing at an
+/// existing user and competition results stay intact.
+pub fn erase_user(uid: String) -> Result<UserErasure, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        diesel::delete(achievements::table.filter(achievements::user_id.eq(&uid))).execute(conn)?;
+        diesel::delete(api_keys::table.filter(api_keys::user_id.eq(&uid))).execute(conn)?;
+
+        let teams_of_user: i64 = teams::table
+            .filter(teams::owner.eq(&uid).or(teams::partner.eq(&uid)))
+            .count()
+            .get_result(conn)?;
+        let participations_of_user: i64 = participations::table
+            .filter(participations::owner.eq(&uid).or(participations::partner.eq(&uid)))
+            .count()
+            .get_result(conn)?;
+        let audited_actions: i64 = audit_log::table
+            .filter(audit_log::user_id.eq(&uid))
+            .count()
+            .get_result(conn)?;
+
+        if teams_of_user + participations_of_user + audited_actions == 0 {
+            diesel::delete(users.filter(id.eq(&uid))).execute(conn)?;
+            return Ok(UserErasure::Deleted);
+        }
+        diesel::update(users.filter(id.eq(&uid)))
+            .set((
+                username.eq(format!("{}{}", ERASED_USERNAME_PREFIX, uid)),
+                ldap_dn.eq(""),
+                display_name.eq(""),
+                email.eq(""),
+                lab_group.eq(""),
+            ))
+            .execute(conn)?;
+        Ok(UserErasure::Anonymized)
+    })
+}
+
 pub fn get_user_by_studnet_number(num: String) -> Result<User, Error> {
     let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
     match users
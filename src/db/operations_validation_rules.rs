@@ -0,0 +1,26 @@
+use diesel::result::Error;
+use diesel::{prelude::*, delete, insert_into};
+use crate::db::schema::validation_rules::dsl::*;
+use crate::models::validation_rules::{SqlValidationRules, ValidationRules, NewValidationRules};
+use super::operations_db::establish_connection;
+
+
+/// Stores the validation rules of a competition, replacing its previous rules.
+pub fn upsert_validation_rules(rules: NewValidationRules) -> Result<ValidationRules, Error> {
+    let new_rules = SqlValidationRules::from(rules);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    conn.transaction(|conn| {
+        delete(validation_rules.filter(competition_id.eq(&new_rules.competition_id))).execute(conn)?;
+        insert_into(validation_rules).values(&new_rules).execute(conn)
+    })?;
+    Ok(ValidationRules::from(new_rules))
+}
+
+pub fn get_validation_rules_by_competition_id(com_id: String) -> Result<Option<ValidationRules>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let rules = validation_rules
+        .filter(competition_id.eq(com_id))
+        .first::<SqlValidationRules>(&mut conn)
+        .optional()?;
+    Ok(rules.map(ValidationRules::from))
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use diesel::result::Error;
+use diesel::{prelude::*, insert_into};
+use crate::db::schema::webhooks::dsl::*;
+use crate::models::webhook::{SqlWebhook, Webhook, NewWebhook};
+use super::operations_db::establish_connection;
+
+
+pub fn insert_webhook(webhook: NewWebhook) -> Result<Webhook, Error> {
+    let new_webhook = SqlWebhook::from(webhook);
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let _ = insert_into(webhooks)
+        .values(&new_webhook)
+        .execute(&mut conn)?;
+    Ok(Webhook::from(new_webhook))
+}
+
+pub fn get_webhooks_by_competition_id(com_id: String) -> Result<Vec<Webhook>, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    let hooks = webhooks
+        .filter(competition_id.eq(com_id))
+        .order(created.asc())
+        .load::<SqlWebhook>(&mut conn)?;
+    Ok(hooks.into_iter().map(Webhook::from).collect::<Vec<Webhook>>())
+}
+
+pub fn delete_webhook(webhook_id: String) -> Result<usize, Error> {
+    let mut conn = establish_connection().expect("Failed to get a DB connection from the pool");
+    diesel::delete(webhooks.filter(id.eq(webhook_id)))
+        .execute(&mut conn)
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+use once_cell::sync::Lazy;
+use tracing::error;
+
+use crate::models::{game_2v2::Game2v2, team::Team, user::Role};
+
+use self::query::QueryRoot;
+
+pub mod objects;
+pub mod query;
+
+/// Nesting is what the endpoint is for (team -> games -> opponents -> ELO history), but
+/// every level can be a query per item, so how deep and wide a query goes is capped.
+const MAX_QUERY_DEPTH: usize = 8;
+const MAX_QUERY_COMPLEXITY: usize = 500;
+
+pub type DashboardSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+static SCHEMA: Lazy<DashboardSchema> = Lazy::new(|| {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+});
+
+pub fn schema() -> &'static DashboardSchema {
+    &SCHEMA
+}
+
+/// The user a query is resolved for, passed to the resolvers as context data.
+pub struct Viewer {
+    pub role: Role,
+    /// teams the user is a member of, in any competition
+    pub teams: Vec<Team>,
+}
+
+impl Viewer {
+    /// Same rules as `/game/{game_id}`, private games are only visible to admins and the
+    /// teams that played them.
+    pub fn can_view_game(&self, game: &Game2v2) -> bool {
+        game.public
+            || self.role == Role::Admin
+            || self.teams.iter().any(|t| t.id == game.team1_id || t.id == game.team2_id)
+    }
+}
+
+/// Not found becomes `null`, other database errors are logged and reported without details.
+fn optional<T>(result: Result<T, diesel::result::Error>) -> async_graphql::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(diesel::result::Error::NotFound) => Ok(None),
+        Err(e) => Err(db_error(e)),
+    }
+}
+
+fn db_error(e: diesel::result::Error) -> async_graphql::Error {
+    error!("GraphQL query failed on the database: {:?}", e);
+    async_graphql::Error::new("Database error")
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use async_graphql::{ComplexObject, Context, Result};
+
+use crate::{
+    db::{
+        operations_competition::get_competition_by_id,
+        operations_elo_history::{get_elo_history_by_competition_id, get_elo_history_by_team_id},
+        operations_game2v2::{get_games_by_competition_id, get_games_by_competition_round, get_rounds_for_competition},
+        operations_team_stats::get_team_stats,
+        operations_teams::{get_team_by_id, get_teams_by_competition_id},
+    },
+    models::{
+        competition::PublicCompetition,
+        elo_history::PublicEloHistory,
+        game_2v2::{Game2v2, PublicGame2v2},
+        team::PublicTeam,
+        team_stats::PublicTeamStats,
+    },
+};
+
+use super::{db_error, optional, Viewer};
+
+#[ComplexObject]
+impl PublicCompetition {
+    async fn teams(&self) -> Result<Vec<PublicTeam>> {
+        let teams = get_teams_by_competition_id(self.id.clone()).map_err(db_error)?;
+        Ok(teams.into_iter().map(PublicTeam::from).collect())
+    }
+
+    /// Games the viewer may see, only those of `round` if it's given.
+    async fn games(&self, ctx: &Context<'_>, round: Option<i32>) -> Result<Vec<PublicGame2v2>> {
+        let games = match round {
+            Some(r) => get_games_by_competition_round(self.id.clone(), r),
+            None => get_games_by_competition_id(self.id.clone()),
+        }.map_err(db_error)?;
+        Ok(visible_games(ctx, games))
+    }
+
+    async fn elo_history(&self) -> Result<Vec<PublicEloHistory>> {
+        let history = get_elo_history_by_competition_id(self.id.clone()).map_err(db_error)?;
+        Ok(history.into_iter().map(PublicEloHistory::from).collect())
+    }
+}
+
+#[ComplexObject]
+impl PublicTeam {
+    async fn competition(&self) -> Result<Option<PublicCompetition>> {
+        Ok(optional(get_competition_by_id(self.competition_id.clone()))?.map(PublicCompetition::from))
+    }
+
+    /// Games of the team the viewer may see.
+    async fn games(&self, ctx: &Context<'_>) -> Result<Vec<PublicGame2v2>> {
+        let games = get_rounds_for_competition(self.id.clone(), self.competition_id.clone()).map_err(db_error)?;
+        Ok(visible_games(ctx, games))
+    }
+
+    async fn elo_history(&self) -> Result<Vec<PublicEloHistory>> {
+        let history = get_elo_history_by_team_id(self.id.clone()).map_err(db_error)?;
+        Ok(history.into_iter().map(PublicEloHistory::from).collect())
+    }
+
+    async fn stats(&self) -> Result<PublicTeamStats> {
+        let stats = get_team_stats(self.id.clone()).map_err(db_error)?;
+        Ok(PublicTeamStats::new(self.id.clone(), stats))
+    }
+}
+
+#[ComplexObject]
+impl PublicGame2v2 {
+    async fn competition(&self) -> Result<Option<PublicCompetition>> {
+        Ok(optional(get_competition_by_id(self.competition_id.clone()))?.map(PublicCompetition::from))
+    }
+
+    async fn team1(&self) -> Result<Option<PublicTeam>> {
+        Ok(optional(get_team_by_id(self.team1_id.clone()))?.map(PublicTeam::from))
+    }
+
+    async fn team2(&self) -> Result<Option<PublicTeam>> {
+        Ok(optional(get_team_by_id(self.team2_id.clone()))?.map(PublicTeam::from))
+    }
+
+    /// The team that played against `team_id`, `null` if that team didn't play the game.
+    async fn opponent(&self, team_id: String) -> Result<Option<PublicTeam>> {
+        let opponent_id = if team_id == self.team1_id {
+            self.team2_id.clone()
+        } else if team_id == self.team2_id {
+            self.team1_id.clone()
+        } else {
+            return Ok(None);
+        };
+        Ok(optional(get_team_by_id(opponent_id))?.map(PublicTeam::from))
+    }
+}
+
+fn visible_games(ctx: &Context<'_>, games: Vec<Game2v2>) -> Vec<PublicGame2v2> {
+    let viewer = ctx.data_unchecked::<Viewer>();
+    games
+        .into_iter()
+        .filter(|g| viewer.can_view_game(g))
+        .map(PublicGame2v2::from)
+        .collect()
+}
\ No newline at end of file
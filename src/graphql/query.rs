@@ -0,0 +1,44 @@
+use async_graphql::{Context, Object, Result};
+
+use crate::{
+    db::{
+        operations_competition::{get_all_competitions, get_competition_by_id},
+        operations_game2v2::get_game_by_id,
+        operations_teams::get_team_by_id,
+    },
+    models::{competition::PublicCompetition, game_2v2::PublicGame2v2, team::PublicTeam},
+};
+
+use super::{db_error, optional, Viewer};
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn competitions(&self) -> Result<Vec<PublicCompetition>> {
+        let competitions = get_all_competitions().map_err(db_error)?;
+        Ok(competitions.into_iter().map(PublicCompetition::from).collect())
+    }
+
+    async fn competition(&self, id: String) -> Result<Option<PublicCompetition>> {
+        Ok(optional(get_competition_by_id(id))?.map(PublicCompetition::from))
+    }
+
+    async fn team(&self, id: String) -> Result<Option<PublicTeam>> {
+        Ok(optional(get_team_by_id(id))?.map(PublicTeam::from))
+    }
+
+    /// Teams of the viewer, one per competition they take part in.
+    async fn my_teams(&self, ctx: &Context<'_>) -> Vec<PublicTeam> {
+        let viewer = ctx.data_unchecked::<Viewer>();
+        viewer.teams.iter().cloned().map(PublicTeam::from).collect()
+    }
+
+    /// `null` if the game doesn't exist or the viewer may not see it.
+    async fn game(&self, ctx: &Context<'_>, id: String) -> Result<Option<PublicGame2v2>> {
+        let viewer = ctx.data_unchecked::<Viewer>();
+        Ok(optional(get_game_by_id(id))?
+            .filter(|g| viewer.can_view_game(g))
+            .map(PublicGame2v2::from))
+    }
+}
\ No newline at end of file
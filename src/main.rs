@@ -1,34 +1,62 @@
-use std::{env, thread};
+use std::env;
 
 use actix_cors::Cors;
 use actix_web::HttpServer;
 use actix_web_httpauth::extractors::bearer::Config;
-use controllers::competitions::run_competitions_round;
+use controllers::{competitions::queue_competitions_round, job_queue::run_job_worker, trace::TRACE_HEADER, safe_mode::init_safe_mode, alert_signals::set_scheduler_running, submission_window::sync_submission_windows, ladder::run_ladder, practice_queue::run_practice_queue, challenges::fail_interrupted_challenges, jwt::is_spectator_write, rate_limit::check_request_rate, api_keys::API_KEY_HEADER, match_dispatch::MAX_RESULT_PAYLOAD_BYTES, match_worker_agent::{worker_coordinator, run_worker_agent}, shutdown::{drain_matches, kill_orphaned_games}};
+use db::operations_db::db_pool;
 use dotenv::dotenv;
-use actix_web::{App, web, http, middleware::Logger};
+use actix_web::{App, HttpResponse, web, http, middleware::Logger, dev::Service};
 use tokio_cron_scheduler::{JobScheduler, Job};
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
+use config::{settings, LogFormat};
 
 use crate::routes::{
     login::login, 
     competition_create::competition_create, 
+    competition_clone::competition_clone,
+    competition_archive::competition_archive,
+    competition_archive_get::competition_archive_get,
+    competition_delete::competition_delete,
+    competition_restore::competition_restore,
     team_create::team_create, 
     team_join::team_join, 
     team_leave::team_leave, 
     team_kick::team_kick, 
     bot_upload::bot_upload, 
+    bot_test_match::bot_test_match,
+    bot_recompile::bot_recompile,
+    bot_delete::bot_delete,
+    bot_restore::bot_restore,
+    bot_metadata::bot_metadata,
     competition_running::competition_running, 
     user_me::user_me, 
+    user_role::user_role,
+    user_lab_group::user_lab_group,
+    user_update::user_update,
+    user_delete::user_delete,
+    user_export::user_export,
     team_get::team_get, 
     team_bots::team_bots, 
     competition_attended::competition_attended,
     competition_id::competition_id, 
     user_id::user_id, 
     team_disband::team_disband, 
+    team_delete::team_delete,
+    team_restore::team_restore,
     team_bot_change::team_bot_change, 
+    team_bot_versions::team_bot_versions,
+    team_bot_rollback::team_bot_rollback,
+    team_slot_assign::team_slot_assign,
+    team_slots::team_slots,
     matchmaking_test::mmt, 
     bot_win_rates::bots_win_rate, 
     competition_rounds::competition_rounds, 
     game_log::game_log, 
+    game_replay_download::game_replay_download,
+    game_errors::game_errors,
+    game_bot_output::game_bot_output,
     game_toggle_public::game_toggle_public, 
     competition_team_count::competition_team_count, 
     game_id::game_id, 
@@ -36,22 +64,150 @@ use crate::routes::{
     team_get_all::team_get_all, 
     game_get_public::game_get_public, 
     team_rename::team_name_change, 
+    team_privacy::team_privacy,
     team_id::team_id,
+    competition_retention::competition_retention,
+    competition_timeouts::competition_timeouts,
+    competition_priority::competition_priority,
+    competition_ladder::competition_ladder,
+    competition_standings::competition_standings,
+    competition_grade_mapping::competition_grade_mapping,
+    competition_grade_mapping_get::competition_grade_mapping_get,
+    competition_grades::competition_grades,
+    competition_ladder_bot::competition_ladder_bot,
+    competition_ghost_create::competition_ghost_create,
+    competition_ghosts::competition_ghosts,
+    competition_ghost_delete::competition_ghost_delete,
+    competition_rating::competition_rating,
+    competition_scoring::competition_scoring,
+    competition_submission_freeze::competition_submission_freeze,
+    competition_submission_lock::competition_submission_lock,
+    competition_registration::competition_registration,
+    season_create::season_create,
+    season_get_all::season_get_all,
+    season_standings::season_standings,
+    competition_leaderboard::competition_leaderboard,
+    competition_events::competition_events,
+    competition_participation::competition_participation,
+    team_participation::team_participation,
+    team_elo_history::team_elo_history,
+    team_stats::team_stats,
+    team_scouting::team_scouting,
+    team_head_to_head::team_head_to_head,
+    hook_create::hook_create,
+    hook_get_all::hook_get_all,
+    hook_delete::hook_delete,
+    webhook_create::webhook_create,
+    webhook_get_all::webhook_get_all,
+    webhook_delete::webhook_delete,
+    competition_discord::competition_discord,
+    competition_discord_get::competition_discord_get,
+    competition_discord_delete::competition_discord_delete,
+    competition_rate_limit::competition_rate_limit,
+    competition_rate_limit_get::competition_rate_limit_get,
+    game_frames::game_frames,
+    competition_featured::competition_featured,
+    api_key_create::api_key_create,
+    api_key_get_all::api_key_get_all,
+    api_key_revoke::api_key_revoke,
+    public_standings::public_standings,
+    public_games::public_games,
+    public_replay::public_replay,
+    openapi_spec::openapi_spec,
+    api_docs::api_docs,
+    graphql_query::graphql_query,
+    competition_games::competition_games,
+    competition_ratings_recompute::competition_ratings_recompute,
+    competition_ratings_report::competition_ratings_report,
+    competition_ratings_apply::competition_ratings_apply,
+    competition_ratings_discard::competition_ratings_discard,
+    game_result_override::game_result_override,
+    competition_audit_log::competition_audit_log,
+    leniency_create::leniency_create,
+    leniency_get_all::leniency_get_all,
+    leniency_delete::leniency_delete,
+    queue_status::queue_status,
+    trace_timeline::trace_timeline,
+    competition_round_run::competition_round_run,
+    job_get::job_get,
+    worker_lease::worker_lease,
+    worker_bot::worker_bot,
+    worker_result::worker_result,
+    practice_publish::practice_publish,
+    practice_get_all::practice_get_all,
+    practice_delete::practice_delete,
+    practice_match::practice_match,
+    gallery_publish::gallery_publish,
+    gallery_get_all::gallery_get_all,
+    gallery_download::gallery_download,
+    gallery_delete::gallery_delete,
+    achievements_team::achievements_team,
+    achievements_user::achievements_user,
+    challenge_create::challenge_create,
+    challenge_accept::challenge_accept,
+    challenge_decline::challenge_decline,
+    challenges_team::challenges_team,
+    challenge_replay::challenge_replay,
+    practice_queue_join::practice_queue_join,
+    practice_queue_leave::practice_queue_leave,
+    practice_queue_games::practice_queue_games,
+    competition_round_stats::competition_round_stats,
+    competition_validation::competition_validation,
+    competition_plagiarism::competition_plagiarism,
+    competition_plagiarism_get::competition_plagiarism_get,
+    competition_validation_get::competition_validation_get,
+    competition_pairing::competition_pairing,
+    competition_pairing_get::competition_pairing_get,
+    competition_round_pairing::competition_round_pairing,
+    competition_round_list::competition_round_list,
+    competition_round_get::competition_round_get,
+    competition_round_report::competition_round_report,
+    competition_crashes::competition_crashes,
+    competition_turn_times::competition_turn_times,
+    team_violations::team_violations,
+    competition_evaluator::competition_evaluator,
+    metrics::metrics,
+    game_rematch::game_rematch,
+    search::search,
 };
 
 mod routes;
 mod controllers;
 mod db;
 mod models;
+mod parsers;
+mod adapters;
+mod config;
+mod openapi;
+mod graphql;
 
-#[actix_web::main]
+// the server, the round scheduler and the games it runs share this runtime
+#[tokio::main]
 async fn main() -> std::io::Result<()>  {
-    println!("[SETUP] Setting up environment.");
+    dotenv().ok();
+    init_logging();
+    if let Some(coordinator) = worker_coordinator() {
+        info!("[SETUP] Running as a match worker, the server isn't started.");
+        kill_orphaned_games();
+        run_worker_agent(coordinator).await;
+        return Ok(());
+    }
+
+    info!("[SETUP] Setting up environment.");
     let (port, url) = setup_env();
+
+    // connect before serving, so a wrong DATABASE_URL fails the start instead of a request
+    let pool = db_pool();
+    info!("[SETUP] Database pool ready with up to {} connections.", pool.max_size());
    
-    thread::spawn(|| {
-        run_cron();
-    });
+    if init_safe_mode() {
+        info!("[SETUP] Safe mode: game execution, the round scheduler and the job worker are disabled.");
+    } else {
+        kill_orphaned_games();
+        fail_interrupted_challenges();
+        tokio::spawn(run_job_worker());
+        tokio::spawn(run_cron());
+    }
 
     // setup Http server
     let mut server = HttpServer::new(move || {
@@ -63,6 +219,9 @@ async fn main() -> std::io::Result<()>  {
             .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE"])
             .allowed_headers(vec![http::header::AUTHORIZATION, http::header::ACCEPT])
             .allowed_header(http::header::CONTENT_TYPE)
+            .allowed_header(TRACE_HEADER)
+            .allowed_header(API_KEY_HEADER)
+            .expose_headers(vec![TRACE_HEADER])
             .max_age(3600),
             None => Cors::permissive(),   
         };
@@ -70,59 +229,226 @@ async fn main() -> std::io::Result<()>  {
         App::new()
             // .wrap(Logger::default())
             .wrap(Logger::new("TIME: %T s | FROM: %a | RESP: %s | %r %{User-Agent}i (msg size in byted: %b)"))
+            .wrap_fn(|req, srv| {
+                // spectators are read only
+                let rejection = if is_spectator_write(&req) {
+                    Some(HttpResponse::Forbidden().finish())
+                } else if let Err(retry_after) = check_request_rate(&req) {
+                    Some(HttpResponse::TooManyRequests()
+                        .insert_header((http::header::RETRY_AFTER, retry_after.to_string()))
+                        .finish())
+                } else {
+                    None
+                };
+                let response = match rejection {
+                    Some(r) => Err(req.into_response(r)),
+                    None => Ok(srv.call(req)),
+                };
+                async move {
+                    match response {
+                        Ok(fut) => fut.await.map(|r| r.map_into_left_body()),
+                        Err(r) => Ok(r.map_into_right_body()),
+                    }
+                }
+            })
             .wrap(cors)
             .app_data(Config::default())
+            // only raw bodies are affected, match workers upload whole replays
+            .app_data(web::PayloadConfig::new(MAX_RESULT_PAYLOAD_BYTES))
             .service(
                 web::scope("/api")
                 .service(user_me)
                 .service(user_id)
+                .service(user_role)
+                .service(user_lab_group)
+                .service(user_update)
+                .service(user_delete)
+                .service(user_export)
                 .service(login)
                 .service(team_id)
                 .service(team_name_change)
+                .service(team_privacy)
                 .service(team_create)
                 .service(team_disband)
+                .service(team_delete)
+                .service(team_restore)
                 .service(team_join)
                 .service(team_leave)
                 .service(team_kick)
                 .service(team_bots)
                 .service(team_bot_change)
+                .service(team_bot_versions)
+                .service(team_bot_rollback)
+                .service(team_slot_assign)
+                .service(team_slots)
                 .service(team_get)
                 .service(team_get_all)
+                .service(team_participation)
+                .service(team_elo_history)
+                .service(team_stats)
+                .service(team_scouting)
+                .service(team_head_to_head)
                 .service(bot_upload)
+                .service(bot_test_match)
+                .service(bot_recompile)
+                .service(bot_delete)
+                .service(bot_restore)
+                .service(bot_metadata)
                 .service(bots_win_rate)
                 .service(competition_create)
+                .service(competition_clone)
+                .service(competition_archive)
+                .service(competition_archive_get)
+                .service(competition_delete)
+                .service(competition_restore)
                 .service(competition_pack)
+                .service(competition_retention)
+                .service(competition_timeouts)
+                .service(competition_priority)
+                .service(competition_ladder)
+                .service(competition_standings)
+                .service(competition_grade_mapping)
+                .service(competition_grade_mapping_get)
+                .service(competition_grades)
+                .service(competition_ladder_bot)
+                .service(competition_ghost_create)
+                .service(competition_ghosts)
+                .service(competition_ghost_delete)
+                .service(competition_rating)
+                .service(competition_scoring)
+                .service(competition_submission_freeze)
+                .service(competition_submission_lock)
+                .service(competition_registration)
+                .service(competition_leaderboard)
+                .service(competition_events)
+                .service(competition_participation)
                 .service(competition_team_count)
                 .service(competition_running)
                 .service(competition_attended)
                 .service(competition_id)
                 .service(competition_rounds)
+                .service(season_create)
+                .service(season_get_all)
+                .service(season_standings)
                 .service(game_log)
+                .service(game_replay_download)
+                .service(game_errors)
+                .service(game_bot_output)
                 .service(game_toggle_public)
                 .service(game_get_public)
                 .service(game_id)
+                .service(hook_create)
+                .service(hook_get_all)
+                .service(hook_delete)
+                .service(webhook_create)
+                .service(webhook_get_all)
+                .service(webhook_delete)
+                .service(competition_discord)
+                .service(competition_discord_get)
+                .service(competition_discord_delete)
+                .service(competition_rate_limit)
+                .service(competition_rate_limit_get)
+                .service(game_frames)
+                .service(competition_featured)
+                .service(api_key_create)
+                .service(api_key_get_all)
+                .service(api_key_revoke)
+                .service(public_standings)
+                .service(public_games)
+                .service(public_replay)
+                .service(openapi_spec)
+                .service(api_docs)
+                .service(graphql_query)
+                .service(competition_games)
+                .service(competition_ratings_recompute)
+                .service(competition_ratings_report)
+                .service(competition_ratings_apply)
+                .service(competition_ratings_discard)
+                .service(game_result_override)
+                .service(competition_audit_log)
+                .service(leniency_create)
+                .service(leniency_get_all)
+                .service(leniency_delete)
+                .service(queue_status)
+                .service(trace_timeline)
+                .service(competition_round_run)
+                .service(job_get)
+                .service(worker_lease)
+                .service(worker_bot)
+                .service(worker_result)
+                .service(practice_publish)
+                .service(practice_get_all)
+                .service(practice_delete)
+                .service(practice_match)
+                .service(gallery_publish)
+                .service(gallery_get_all)
+                .service(gallery_download)
+                .service(gallery_delete)
+                .service(practice_queue_join)
+                .service(practice_queue_leave)
+                .service(practice_queue_games)
+                .service(challenge_create)
+                .service(challenge_accept)
+                .service(challenge_decline)
+                .service(challenges_team)
+                .service(challenge_replay)
+                .service(achievements_team)
+                .service(achievements_user)
+                .service(competition_round_stats)
+                .service(competition_validation)
+                .service(competition_validation_get)
+                .service(competition_pairing)
+                .service(competition_pairing_get)
+                .service(competition_round_pairing)
+                .service(competition_round_list)
+                .service(competition_round_get)
+                .service(competition_round_report)
+                .service(competition_crashes)
+                .service(competition_turn_times)
+                .service(competition_plagiarism)
+                .service(competition_plagiarism_get)
+                .service(team_violations)
+                .service(competition_evaluator)
+                .service(metrics)
+                .service(game_rematch)
+                .service(search)
                 .service(mmt)
             )
             
     });
-    println!("[SETUP] Running server on 0.0.0.0:{}", port);
+    info!("[SETUP] Running server on 0.0.0.0:{}", port);
     server = server.bind(("0.0.0.0", port)).unwrap();
-    server.run().await
+    // actix stops the server on SIGINT/SIGTERM, the games still running get to finish after that
+    let result = server.run().await;
+    drain_matches().await;
+    result
+}
+
+/// Sends the logs of the server, including actix's `log` records, to stdout as text or JSON lines.
+/// Spans of rounds, matches and compilations are part of every line logged within them.
+fn init_logging() {
+    let logging = &settings().logging;
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&logging.filter));
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    match logging.format {
+        LogFormat::Json => subscriber.json().init(),
+        LogFormat::Text => subscriber.init(),
+    }
 }
 
 fn setup_env() -> (u16, Option<String>) {
     dotenv().ok();
-    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
     let port = env::var("PORT").expect("$PORT is not set").parse::<u16>().unwrap();
     let url = env::var("URL").ok();
     (port, url)
 }
 
-/// Schedules and runs a cron job to execute the `run_competitions_round` function every hour.
+/// Schedules and runs a cron job to queue the rounds of the running competitions every hour.
 ///
 /// This function sets up a cron job using the `JobScheduler` library. The cron job is scheduled to
-/// run at the start of every hour, every day, and it calls the `run_competitions_round` function.
-/// If there's any error while running the `run_competitions_round` function, the error is printed to the console.
+/// run at the start of every hour, every day, and it calls the `queue_competitions_round` function,
+/// the job worker then runs the queued rounds. If there's any error while queueing the rounds, the
+/// error is printed to the console.
 ///
 /// Additionally, a shutdown handler is set up for the scheduler. This handler prints a shutdown message
 /// when the scheduler is shutting down.
@@ -131,30 +457,54 @@ fn setup_env() -> (u16, Option<String>) {
 ///
 /// This function will panic if there's an error setting up the cron job or starting the scheduler.
 ///
-#[tokio::main]
 async fn run_cron() {
     let mut sched = JobScheduler::new();
-    match sched.add(Job::new_async("0 0 * * * * *", move |_, _|  Box::pin(async { 
-        if let Err(e) = run_competitions_round() {
-            println!("Error on running round: {:?}", e)
+    match sched.add(Job::new("0 0 * * * * *", |_, _| {
+        if let Err(e) = queue_competitions_round() {
+            error!("Error on queueing rounds: {:?}", e)
         }
-    })).unwrap()) {
-        Ok(c) => println!("Started cron!: {:?}", c),
-        Err(e) => println!("Something went wrong scheduling CRON: {:?}", e)
+    }).unwrap()) {
+        Ok(c) => info!("Started cron!: {:?}", c),
+        Err(e) => error!("Something went wrong scheduling CRON: {:?}", e)
+    };
+
+    // open and close submissions according to each competition's window
+    match sched.add(Job::new("0 * * * * * *", |_, _| sync_submission_windows()).unwrap()) {
+        Ok(c) => info!("Started submission window cron!: {:?}", c),
+        Err(e) => error!("Something went wrong scheduling submission window CRON: {:?}", e)
+    };
+
+    // staff bot ladder, half past so it doesn't start together with the rounds
+    match sched.add(Job::new("0 30 * * * * *", |_, _| {
+        // ladder games block until they are played
+        std::thread::spawn(run_ladder);
+    }).unwrap()) {
+        Ok(c) => info!("Started ladder cron!: {:?}", c),
+        Err(e) => error!("Something went wrong scheduling ladder CRON: {:?}", e)
+    };
+
+    // queued practice games, only played while the machine is idle
+    match sched.add(Job::new("30 * * * * * *", |_, _| {
+        std::thread::spawn(run_practice_queue);
+    }).unwrap()) {
+        Ok(c) => info!("Started practice queue cron!: {:?}", c),
+        Err(e) => error!("Something went wrong scheduling practice queue CRON: {:?}", e)
     };
 
     // set shudown handler
     match sched.set_shutdown_handler(Box::new(|| {
         Box::pin(async move {
-          println!("Shut down done");
+          info!("Shut down done");
         })
     })) {
-        Ok(c) => println!("Shutdown handler set for cron!: {:?}", c),
-        Err(e) => println!("Something went wrong setting shutdown handler for CRON: {:?}", e)
+        Ok(c) => info!("Shutdown handler set for cron!: {:?}", c),
+        Err(e) => error!("Something went wrong setting shutdown handler for CRON: {:?}", e)
     };
 
     // start cron
+    set_scheduler_running(true);
     if let Err(e) = sched.start().await {
-        println!("Error on scheduler {:?}", e);
+        set_scheduler_running(false);
+        error!("Error on scheduler {:?}", e);
     }
 }
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::achievements;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum AchievementKind {
+    /// won a ranked game for the first time
+    FirstWin,
+    /// won five ranked games in a row
+    WinStreak,
+    /// won every game of a round without losing a bot
+    FlawlessRound,
+    /// beat a team rated far above it
+    GiantKiller,
+}
+
+/// An achievement a team earned, it is stored once for each of the team's members.
+#[derive(Debug)]
+pub struct NewAchievement {
+    pub competition_id: String,
+    pub team_id: String,
+    pub user_id: String,
+    pub kind: AchievementKind,
+    pub round: i32,
+    pub game_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Achievement {
+    pub competition_id: String,
+    pub team_id: String,
+    pub kind: AchievementKind,
+    pub round: i32,
+    pub game_id: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = achievements)]
+pub struct SqlAchievement {
+    pub id: String,
+    pub competition_id: String,
+    pub team_id: String,
+    pub user_id: String,
+    pub kind: String,
+    pub round: i32,
+    pub game_id: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicAchievement {
+    pub competition_id: String,
+    pub team_id: String,
+    pub kind: AchievementKind,
+    /// the round it was earned in
+    pub round: i32,
+    /// the game that earned it, empty for achievements of a whole round
+    pub game_id: String,
+    pub created: NaiveDateTime,
+}
+
+impl fmt::Display for AchievementKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AchievementKind::FirstWin => write!(f, "FIRST_WIN"),
+            AchievementKind::WinStreak => write!(f, "WIN_STREAK"),
+            AchievementKind::FlawlessRound => write!(f, "FLAWLESS_ROUND"),
+            AchievementKind::GiantKiller => write!(f, "GIANT_KILLER"),
+        }
+    }
+}
+
+impl From<&str> for AchievementKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "WIN_STREAK" => AchievementKind::WinStreak,
+            "FLAWLESS_ROUND" => AchievementKind::FlawlessRound,
+            "GIANT_KILLER" => AchievementKind::GiantKiller,
+            _ => AchievementKind::FirstWin,
+        }
+    }
+}
+
+impl From<NewAchievement> for SqlAchievement {
+    fn from(new_achievement: NewAchievement) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_achievement.competition_id,
+            team_id: new_achievement.team_id,
+            user_id: new_achievement.user_id,
+            kind: new_achievement.kind.to_string(),
+            round: new_achievement.round,
+            game_id: new_achievement.game_id,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlAchievement> for Achievement {
+    fn from(sql_achievement: SqlAchievement) -> Self {
+        Self {
+            competition_id: sql_achievement.competition_id,
+            team_id: sql_achievement.team_id,
+            kind: AchievementKind::from(sql_achievement.kind.as_str()),
+            round: sql_achievement.round,
+            game_id: sql_achievement.game_id,
+            created: sql_achievement.created,
+        }
+    }
+}
+
+impl From<Achievement> for PublicAchievement {
+    fn from(achievement: Achievement) -> Self {
+        Self {
+            competition_id: achievement.competition_id,
+            team_id: achievement.team_id,
+            kind: achievement.kind,
+            round: achievement.round,
+            game_id: achievement.game_id,
+            created: achievement.created,
+        }
+    }
+}
\ No newline at end of file
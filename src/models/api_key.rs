@@ -0,0 +1,92 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::NaiveDateTime;
+use crate::db::schema::api_keys::{self};
+
+/// Keys a single user may have that aren't revoked.
+pub const MAX_API_KEYS_PER_USER: usize = 5;
+
+/// A key of the read-only public API. `requests_per_minute` can only be set by admins,
+/// everyone else gets the server's default.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewApiKey {
+    pub name: String,
+    pub requests_per_minute: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub requests_per_minute: i32,
+    pub revoked: bool,
+    pub created: NaiveDateTime,
+    pub last_used: Option<NaiveDateTime>,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = api_keys)]
+pub struct SqlApiKey {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub key_hash: String,
+    pub key_prefix: String,
+    pub requests_per_minute: i32,
+    pub revoked: bool,
+    pub created: NaiveDateTime,
+    pub last_used: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicApiKey {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub requests_per_minute: i32,
+    pub revoked: bool,
+    pub created: NaiveDateTime,
+    pub last_used: Option<NaiveDateTime>,
+}
+
+/// A newly created key, the only time the key itself is shown.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKey {
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: PublicApiKey,
+}
+
+impl From<SqlApiKey> for ApiKey {
+    fn from(sql_key: SqlApiKey) -> Self {
+        Self {
+            id: sql_key.id,
+            user_id: sql_key.user_id,
+            name: sql_key.name,
+            key_prefix: sql_key.key_prefix,
+            requests_per_minute: sql_key.requests_per_minute,
+            revoked: sql_key.revoked,
+            created: sql_key.created,
+            last_used: sql_key.last_used,
+        }
+    }
+}
+
+impl From<ApiKey> for PublicApiKey {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            id: key.id,
+            user_id: key.user_id,
+            name: key.name,
+            key_prefix: key.key_prefix,
+            requests_per_minute: key.requests_per_minute,
+            revoked: key.revoked,
+            created: key.created,
+            last_used: key.last_used,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::audit_log::{self};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum AuditAction {
+    /// the winner or survivors of a game were corrected
+    GameResultOverride,
+    /// the mapping of final standings to grades was set or changed
+    GradeMappingUpdate,
+}
+
+#[derive(Debug)]
+pub struct NewAuditEntry {
+    pub user_id: String,
+    pub action: AuditAction,
+    pub competition_id: String,
+    pub target_id: String,
+    /// what changed, e.g. the values before and after
+    pub details: serde_json::Value,
+}
+
+#[derive(Debug)]
+pub struct AuditEntry {
+    pub id: String,
+    pub user_id: String,
+    pub action: String,
+    pub competition_id: String,
+    pub target_id: String,
+    pub details: serde_json::Value,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct SqlAuditEntry {
+    pub id: String,
+    pub user_id: String,
+    pub action: String,
+    pub competition_id: String,
+    pub target_id: String,
+    pub details: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicAuditEntry {
+    pub id: String,
+    pub user_id: String,
+    pub action: String,
+    pub competition_id: String,
+    pub target_id: String,
+    #[schema(value_type = Object)]
+    pub details: serde_json::Value,
+    pub created: NaiveDateTime,
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditAction::GameResultOverride => write!(f, "GAME_RESULT_OVERRIDE"),
+            AuditAction::GradeMappingUpdate => write!(f, "GRADE_MAPPING_UPDATE"),
+        }
+    }
+}
+
+impl From<SqlAuditEntry> for AuditEntry {
+    fn from(sql_entry: SqlAuditEntry) -> Self {
+        Self {
+            id: sql_entry.id,
+            user_id: sql_entry.user_id,
+            action: sql_entry.action,
+            competition_id: sql_entry.competition_id,
+            target_id: sql_entry.target_id,
+            details: serde_json::from_str(&sql_entry.details).unwrap_or_default(),
+            created: sql_entry.created,
+        }
+    }
+}
+
+impl From<AuditEntry> for PublicAuditEntry {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            user_id: entry.user_id,
+            action: entry.action,
+            competition_id: entry.competition_id,
+            target_id: entry.target_id,
+            details: entry.details,
+            created: entry.created,
+        }
+    }
+}
+
+impl From<NewAuditEntry> for SqlAuditEntry {
+    fn from(new_entry: NewAuditEntry) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id: new_entry.user_id,
+            action: new_entry.action.to_string(),
+            competition_id: new_entry.competition_id,
+            target_id: new_entry.target_id,
+            details: new_entry.details.to_string(),
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
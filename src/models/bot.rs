@@ -2,14 +2,42 @@ use std::path::Path;
 
 use diesel::prelude::{Insertable, Queryable};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 use chrono::{NaiveDateTime, Local};
 use uuid::Uuid;
 use crate::db::schema::bots::{self};
+use super::{compile_diagnostics::CompileDiagnostics, smoke_test::SmokeTestStatus};
 
-#[derive(Debug, Deserialize)]
+/// Language a bot is written in when the team doesn't say.
+pub const DEFAULT_BOT_LANGUAGE: &str = "Java";
+
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct NewBot {
     pub team_id: String,
     pub source_path: String,
+    pub metadata: BotMetadata,
+}
+
+/// What a team tells about a bot. The name is shown instead of the bot's id wherever the
+/// bot appears, without one the file name of the upload is used.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct BotMetadata {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub language: String,
+}
+
+/// A bot as it played in a game, so results and replays can name it.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GameBot {
+    /// `team1bot1`, `team1bot2`, `team2bot1` or `team2bot2`
+    pub slot: String,
+    pub bot_id: String,
+    pub name: String,
+    pub language: String,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +48,12 @@ pub struct Bot {
     pub source_path: String,
     pub compile_error: String,
     pub created: NaiveDateTime,
+    pub compile_diagnostics: CompileDiagnostics,
+    pub display_name: String,
+    pub description: String,
+    pub language: String,
+    pub smoke_test: SmokeTestStatus,
+    pub smoke_test_message: String,
 }   
 
 #[derive(Queryable, Debug, Insertable)]
@@ -31,15 +65,41 @@ pub struct SqlBot {
     pub source_path: String,
     pub compile_error: String,
     pub created: NaiveDateTime,
+    pub compile_diagnostics: String,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub display_name: String,
+    pub description: String,
+    pub language: String,
+    pub smoke_test: String,
+    pub smoke_test_message: String,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema)]
 pub struct PublicBot {
     pub id: String,
     pub team_id: String,
     pub bot_name: String,
     pub compile_error: String,
     pub created: NaiveDateTime,
+    pub compile_diagnostics: CompileDiagnostics,
+    /// the name to show for the bot, its file name if the team didn't name it
+    pub name: String,
+    pub description: String,
+    pub language: String,
+    /// verdict of the test game against idle bots, the bot can't be slotted unless it passed
+    /// or was skipped
+    pub smoke_test: SmokeTestStatus,
+    pub smoke_test_message: String,
+}
+
+/// Result of recompiling a bot on demand.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompileReport {
+    pub bot_id: String,
+    pub success: bool,
+    pub error: String,
+    pub compiler_output: Vec<String>,
+    pub diagnostics: CompileDiagnostics,
 }
 
 impl From<SqlBot> for Bot {
@@ -51,6 +111,23 @@ impl From<SqlBot> for Bot {
             source_path: sql_bot.source_path,
             compile_error: sql_bot.compile_error,
             created: sql_bot.created,
+            compile_diagnostics: CompileDiagnostics::from_json(&sql_bot.compile_diagnostics),
+            display_name: sql_bot.display_name,
+            description: sql_bot.description,
+            language: sql_bot.language,
+            smoke_test: SmokeTestStatus::from(sql_bot.smoke_test.as_str()),
+            smoke_test_message: sql_bot.smoke_test_message,
+        }
+    }
+}
+
+impl Bot {
+    /// The name the bot is shown under, the file name of its upload if the team didn't name it.
+    pub fn public_name(&self) -> String {
+        if self.display_name.is_empty() {
+            self.bot_name.clone()
+        } else {
+            self.display_name.clone()
         }
     }
 }
@@ -58,11 +135,17 @@ impl From<SqlBot> for Bot {
 impl From<Bot> for PublicBot {
     fn from(bot: Bot) -> Self {
         Self { 
+            name: bot.public_name(),
             id: bot.id,
             team_id: bot.team_id,
             bot_name: bot.bot_name,
             compile_error: bot.compile_error,
             created: bot.created,
+            compile_diagnostics: bot.compile_diagnostics,
+            description: bot.description,
+            language: bot.language,
+            smoke_test: bot.smoke_test,
+            smoke_test_message: bot.smoke_test_message,
         }
     }
 }
@@ -82,6 +165,17 @@ impl From<NewBot> for SqlBot {
             source_path: new_bot.source_path,
             compile_error: "".to_string(),
             created: Local::now().naive_utc(),
+            compile_diagnostics: CompileDiagnostics::default().to_json(),
+            deleted_at: None,
+            display_name: new_bot.metadata.name,
+            description: new_bot.metadata.description,
+            language: if new_bot.metadata.language.is_empty() {
+                DEFAULT_BOT_LANGUAGE.to_string()
+            } else {
+                new_bot.metadata.language
+            },
+            smoke_test: SmokeTestStatus::Pending.to_string(),
+            smoke_test_message: "".to_string(),
         }
     }
 }
\ No newline at end of file
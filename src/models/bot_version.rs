@@ -0,0 +1,136 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::bot_versions::{self};
+use super::{bot::PublicBot, team::BotSelector};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum CompileStatus {
+    Ok,
+    Failed,
+}
+
+/// A bot that was active in one of a team's slots. A new version is recorded every time a
+/// slot's bot changes, rollbacks included, so the history is never rewritten.
+#[derive(Debug)]
+pub struct NewBotVersion {
+    pub team_id: String,
+    pub slot: BotSelector,
+    pub bot_id: String,
+    pub source_sha256: String,
+    pub compile_status: CompileStatus,
+    pub uploaded: NaiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct BotVersion {
+    pub id: String,
+    pub team_id: String,
+    pub slot: BotSelector,
+    pub bot_id: String,
+    pub source_sha256: String,
+    pub compile_status: CompileStatus,
+    pub uploaded: NaiveDateTime,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = bot_versions)]
+pub struct SqlBotVersion {
+    pub id: String,
+    pub team_id: String,
+    pub slot: String,
+    pub bot_id: String,
+    pub source_sha256: String,
+    pub compile_status: String,
+    pub uploaded: NaiveDateTime,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicBotVersion {
+    pub id: String,
+    pub team_id: String,
+    pub slot: BotSelector,
+    pub bot_id: String,
+    pub source_sha256: String,
+    pub compile_status: CompileStatus,
+    pub uploaded: NaiveDateTime,
+    pub created: NaiveDateTime,
+    pub active: bool,
+}
+
+/// The bot assigned to one of a team's slots.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BotSlot {
+    pub slot: BotSelector,
+    /// `None` if the slot is empty
+    pub bot: Option<PublicBot>,
+    /// when the bot was assigned, `None` if it was assigned before versions were recorded
+    pub assigned: Option<NaiveDateTime>,
+}
+
+impl PublicBotVersion {
+    pub fn new(version: BotVersion, active: bool) -> Self {
+        Self {
+            id: version.id,
+            team_id: version.team_id,
+            slot: version.slot,
+            bot_id: version.bot_id,
+            source_sha256: version.source_sha256,
+            compile_status: version.compile_status,
+            uploaded: version.uploaded,
+            created: version.created,
+            active,
+        }
+    }
+}
+
+impl From<NewBotVersion> for SqlBotVersion {
+    fn from(new_version: NewBotVersion) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            team_id: new_version.team_id,
+            slot: new_version.slot.to_string(),
+            bot_id: new_version.bot_id,
+            source_sha256: new_version.source_sha256,
+            compile_status: new_version.compile_status.to_string(),
+            uploaded: new_version.uploaded,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlBotVersion> for BotVersion {
+    fn from(sql_version: SqlBotVersion) -> Self {
+        Self {
+            id: sql_version.id,
+            team_id: sql_version.team_id,
+            slot: match sql_version.slot.as_str() {
+                "BOT2" => BotSelector::Second,
+                _ => BotSelector::First,
+            },
+            bot_id: sql_version.bot_id,
+            source_sha256: sql_version.source_sha256,
+            compile_status: match sql_version.compile_status.as_str() {
+                "FAILED" => CompileStatus::Failed,
+                _ => CompileStatus::Ok,
+            },
+            uploaded: sql_version.uploaded,
+            created: sql_version.created,
+        }
+    }
+}
+
+impl fmt::Display for CompileStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompileStatus::Ok => write!(f, "OK"),
+            CompileStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::bot_violations::{self};
+
+/// An active bot that broke the competition's validation rules. The team has to replace
+/// the bot before the deadline, afterwards the team sits out the rounds.
+#[derive(Debug)]
+pub struct NewBotViolation {
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub reason: String,
+    pub deadline: NaiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct BotViolation {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub reason: String,
+    pub deadline: NaiveDateTime,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = bot_violations)]
+pub struct SqlBotViolation {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub reason: String,
+    pub deadline: NaiveDateTime,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicBotViolation {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub reason: String,
+    pub deadline: NaiveDateTime,
+    pub created: NaiveDateTime,
+}
+
+/// Outcome of re-checking the active bots of a competition.
+#[derive(Debug, Serialize, Default, ToSchema)]
+pub struct RevalidationSummary {
+    pub checked: usize,
+    pub flagged: usize,
+    pub cleared: usize,
+}
+
+impl From<NewBotViolation> for SqlBotViolation {
+    fn from(new_violation: NewBotViolation) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            bot_id: new_violation.bot_id,
+            team_id: new_violation.team_id,
+            competition_id: new_violation.competition_id,
+            reason: new_violation.reason,
+            deadline: new_violation.deadline,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlBotViolation> for BotViolation {
+    fn from(sql_violation: SqlBotViolation) -> Self {
+        Self {
+            id: sql_violation.id,
+            bot_id: sql_violation.bot_id,
+            team_id: sql_violation.team_id,
+            competition_id: sql_violation.competition_id,
+            reason: sql_violation.reason,
+            deadline: sql_violation.deadline,
+            created: sql_violation.created,
+        }
+    }
+}
+
+impl From<BotViolation> for PublicBotViolation {
+    fn from(violation: BotViolation) -> Self {
+        Self {
+            id: violation.id,
+            bot_id: violation.bot_id,
+            team_id: violation.team_id,
+            competition_id: violation.competition_id,
+            reason: violation.reason,
+            deadline: violation.deadline,
+            created: violation.created,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::challenges;
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub enum ChallengeStatus {
+    /// waiting for the challenged team to answer
+    Pending,
+    /// accepted, the game is being played
+    Running,
+    Declined,
+    Finished,
+    /// the game couldn't be played, see `error`
+    Failed,
+}
+
+/// A team challenging another team of its competition to a friendly, unrated game.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewChallenge {
+    pub challenger_id: String,
+    pub challenged_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Challenge {
+    pub id: String,
+    pub competition_id: String,
+    pub challenger_id: String,
+    pub challenged_id: String,
+    pub status: ChallengeStatus,
+    pub winner_id: String,
+    pub challenger_score: i32,
+    pub challenged_score: i32,
+    pub log_file_path: String,
+    pub error: String,
+    pub created: NaiveDateTime,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = challenges)]
+pub struct SqlChallenge {
+    pub id: String,
+    pub competition_id: String,
+    pub challenger_id: String,
+    pub challenged_id: String,
+    pub status: String,
+    pub winner_id: String,
+    pub challenger_score: i32,
+    pub challenged_score: i32,
+    pub log_file_path: String,
+    pub error: String,
+    pub created: NaiveDateTime,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicChallenge {
+    pub id: String,
+    pub competition_id: String,
+    pub challenger_id: String,
+    pub challenged_id: String,
+    pub status: ChallengeStatus,
+    pub winner_id: String,
+    pub challenger_score: i32,
+    pub challenged_score: i32,
+    /// whether the replay can be downloaded from `/challenge/replay/{id}`
+    pub has_replay: bool,
+    pub error: String,
+    pub created: NaiveDateTime,
+    pub updated: NaiveDateTime,
+}
+
+impl Challenge {
+    pub fn new(competition_id: String, new_challenge: NewChallenge) -> Self {
+        let now = Local::now().naive_utc();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id,
+            challenger_id: new_challenge.challenger_id,
+            challenged_id: new_challenge.challenged_id,
+            status: ChallengeStatus::Pending,
+            winner_id: "".to_string(),
+            challenger_score: 0,
+            challenged_score: 0,
+            log_file_path: "".to_string(),
+            error: "".to_string(),
+            created: now,
+            updated: now,
+        }
+    }
+}
+
+impl fmt::Display for ChallengeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChallengeStatus::Pending => write!(f, "PENDING"),
+            ChallengeStatus::Running => write!(f, "RUNNING"),
+            ChallengeStatus::Declined => write!(f, "DECLINED"),
+            ChallengeStatus::Finished => write!(f, "FINISHED"),
+            ChallengeStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+impl From<&str> for ChallengeStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "RUNNING" => ChallengeStatus::Running,
+            "DECLINED" => ChallengeStatus::Declined,
+            "FINISHED" => ChallengeStatus::Finished,
+            "FAILED" => ChallengeStatus::Failed,
+            _ => ChallengeStatus::Pending,
+        }
+    }
+}
+
+impl From<SqlChallenge> for Challenge {
+    fn from(sql_challenge: SqlChallenge) -> Self {
+        Self {
+            id: sql_challenge.id,
+            competition_id: sql_challenge.competition_id,
+            challenger_id: sql_challenge.challenger_id,
+            challenged_id: sql_challenge.challenged_id,
+            status: ChallengeStatus::from(sql_challenge.status.as_str()),
+            winner_id: sql_challenge.winner_id,
+            challenger_score: sql_challenge.challenger_score,
+            challenged_score: sql_challenge.challenged_score,
+            log_file_path: sql_challenge.log_file_path,
+            error: sql_challenge.error,
+            created: sql_challenge.created,
+            updated: sql_challenge.updated,
+        }
+    }
+}
+
+impl From<Challenge> for SqlChallenge {
+    fn from(challenge: Challenge) -> Self {
+        Self {
+            id: challenge.id,
+            competition_id: challenge.competition_id,
+            challenger_id: challenge.challenger_id,
+            challenged_id: challenge.challenged_id,
+            status: challenge.status.to_string(),
+            winner_id: challenge.winner_id,
+            challenger_score: challenge.challenger_score,
+            challenged_score: challenge.challenged_score,
+            log_file_path: challenge.log_file_path,
+            error: challenge.error,
+            created: challenge.created,
+            updated: challenge.updated,
+        }
+    }
+}
+
+impl From<Challenge> for PublicChallenge {
+    fn from(challenge: Challenge) -> Self {
+        Self {
+            id: challenge.id,
+            competition_id: challenge.competition_id,
+            challenger_id: challenge.challenger_id,
+            challenged_id: challenge.challenged_id,
+            status: challenge.status,
+            winner_id: challenge.winner_id,
+            challenger_score: challenge.challenger_score,
+            challenged_score: challenge.challenged_score,
+            has_replay: !challenge.log_file_path.is_empty(),
+            error: challenge.error,
+            created: challenge.created,
+            updated: challenge.updated,
+        }
+    }
+}
\ No newline at end of file
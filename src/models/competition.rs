@@ -1,15 +1,61 @@
+use std::fmt;
+
 use diesel::prelude::{Insertable, Queryable};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use async_graphql::{Enum, SimpleObject};
 use chrono::{NaiveDateTime, Local};
 use uuid::Uuid;
+use crate::config::settings;
+use crate::models::round_leniency::DEFAULT_GAME_TIMEOUT_SECS;
 use crate::db::schema::competitions::{self};
 
-#[derive(Debug, Deserialize)]
+/// How the standings of a competition are determined.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, Enum)]
+pub enum ScoringSystem {
+    /// teams are ranked by their ELO
+    Elo,
+    /// teams are ranked by league points, ties are broken by head-to-head results and then
+    /// by score difference
+    Points,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct NewCompetition {
     name: String,
     start: NaiveDateTime,
     end: NaiveDateTime,
     type_: String,
+    replay_compress_after: Option<i32>,
+    replay_delete_after: Option<i32>,
+    elo_k_factor: Option<i32>,
+    provisional_games: Option<i32>,
+    provisional_k_factor: Option<i32>,
+    scoring_system: Option<ScoringSystem>,
+    points_win: Option<i32>,
+    points_draw: Option<i32>,
+    points_loss: Option<i32>,
+    points_bye: Option<i32>,
+    evaluator_source: Option<String>,
+    evaluator_sha256: Option<String>,
+    submission_freeze_minutes: Option<i32>,
+    registration_open: Option<NaiveDateTime>,
+    registration_close: Option<NaiveDateTime>,
+    max_teams: Option<i32>,
+    game_timeout_secs: Option<i32>,
+    stall_timeout_secs: Option<i32>,
+    priority: Option<i32>,
+}
+
+/// A new competition that takes its configuration from an existing one.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CompetitionClone {
+    pub competition_id: String,
+    pub name: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub registration_open: Option<NaiveDateTime>,
+    pub registration_close: Option<NaiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -24,6 +70,29 @@ pub struct Competition {
     pub games_per_round: i32,
     pub game_pack: String,
     pub created: NaiveDateTime,
+    pub replay_compress_after: i32,
+    pub replay_delete_after: i32,
+    pub elo_k_factor: i32,
+    pub provisional_games: i32,
+    pub provisional_k_factor: i32,
+    pub scoring_system: ScoringSystem,
+    pub points_win: i32,
+    pub points_draw: i32,
+    pub points_loss: i32,
+    pub points_bye: i32,
+    pub evaluator_source: String,
+    pub evaluator_sha256: String,
+    pub submission_freeze_minutes: i32,
+    pub registration_open: NaiveDateTime,
+    pub registration_close: NaiveDateTime,
+    pub max_teams: i32,
+    pub archived: bool,
+    pub archive_path: String,
+    pub game_timeout_secs: i32,
+    /// games printing nothing for this long are killed, `0` waits for the full timeout
+    pub stall_timeout_secs: i32,
+    /// share of the game slots while other competitions play rounds at the same time
+    pub priority: i32,
 }   
 
 #[derive(Queryable, Debug, Insertable)]
@@ -33,15 +102,38 @@ pub struct SqlCompetition {
     pub name: String,
     pub start: NaiveDateTime,
     pub end: NaiveDateTime,
-    pub allowed_submissions: String,
-    pub round: String,
+    pub allowed_submissions: bool,
+    pub round: i32,
     pub type_: String,
     pub games_per_round: i32,
     pub game_pack: String,
     pub created: NaiveDateTime,
+    pub replay_compress_after: i32,
+    pub replay_delete_after: i32,
+    pub elo_k_factor: i32,
+    pub provisional_games: i32,
+    pub provisional_k_factor: i32,
+    pub scoring_system: String,
+    pub points_win: i32,
+    pub points_draw: i32,
+    pub points_loss: i32,
+    pub points_bye: i32,
+    pub evaluator_source: String,
+    pub evaluator_sha256: String,
+    pub submission_freeze_minutes: i32,
+    pub registration_open: NaiveDateTime,
+    pub registration_close: NaiveDateTime,
+    pub max_teams: i32,
+    pub archived: bool,
+    pub archive_path: String,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub game_timeout_secs: i32,
+    pub stall_timeout_secs: i32,
+    pub priority: i32,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema, SimpleObject)]
+#[graphql(name = "Competition", complex)]
 pub struct PublicCompetition {
     pub id: String,
     pub name: String,
@@ -51,6 +143,26 @@ pub struct PublicCompetition {
     pub round: i32,
     pub type_: String,
     created: NaiveDateTime,
+    pub replay_compress_after: i32,
+    pub replay_delete_after: i32,
+    pub elo_k_factor: i32,
+    pub provisional_games: i32,
+    pub provisional_k_factor: i32,
+    pub scoring_system: ScoringSystem,
+    pub points_win: i32,
+    pub points_draw: i32,
+    pub points_loss: i32,
+    pub points_bye: i32,
+    pub evaluator_source: String,
+    pub evaluator_sha256: String,
+    pub submission_freeze_minutes: i32,
+    pub registration_open: NaiveDateTime,
+    pub registration_close: NaiveDateTime,
+    pub max_teams: i32,
+    pub archived: bool,
+    pub game_timeout_secs: i32,
+    pub stall_timeout_secs: i32,
+    pub priority: i32,
 }
 
 impl From<SqlCompetition> for Competition {
@@ -60,12 +172,36 @@ impl From<SqlCompetition> for Competition {
             name: sql_competition.name,
             start: sql_competition.start.into(),
             end: sql_competition.end.into(),
-            allowed_submissions: sql_competition.allowed_submissions.parse().unwrap(),
-            round: sql_competition.round.parse().unwrap(),
+            allowed_submissions: sql_competition.allowed_submissions,
+            round: sql_competition.round,
             type_: sql_competition.type_,
             games_per_round: sql_competition.games_per_round,
             game_pack: sql_competition.game_pack,
             created: sql_competition.created,
+            replay_compress_after: sql_competition.replay_compress_after,
+            replay_delete_after: sql_competition.replay_delete_after,
+            elo_k_factor: sql_competition.elo_k_factor,
+            provisional_games: sql_competition.provisional_games,
+            provisional_k_factor: sql_competition.provisional_k_factor,
+            scoring_system: match sql_competition.scoring_system.as_str() {
+                "POINTS" => ScoringSystem::Points,
+                _ => ScoringSystem::Elo,
+            },
+            points_win: sql_competition.points_win,
+            points_draw: sql_competition.points_draw,
+            points_loss: sql_competition.points_loss,
+            points_bye: sql_competition.points_bye,
+            evaluator_source: sql_competition.evaluator_source,
+            evaluator_sha256: sql_competition.evaluator_sha256,
+            submission_freeze_minutes: sql_competition.submission_freeze_minutes,
+            registration_open: sql_competition.registration_open,
+            registration_close: sql_competition.registration_close,
+            max_teams: sql_competition.max_teams,
+            archived: sql_competition.archived,
+            archive_path: sql_competition.archive_path,
+            game_timeout_secs: sql_competition.game_timeout_secs,
+            stall_timeout_secs: sql_competition.stall_timeout_secs,
+            priority: sql_competition.priority,
         }
     }
 }
@@ -81,23 +217,118 @@ impl From<Competition> for PublicCompetition {
             round: competition.round,
             type_: competition.type_,
             created: competition.created,
+            replay_compress_after: competition.replay_compress_after,
+            replay_delete_after: competition.replay_delete_after,
+            elo_k_factor: competition.elo_k_factor,
+            provisional_games: competition.provisional_games,
+            provisional_k_factor: competition.provisional_k_factor,
+            scoring_system: competition.scoring_system,
+            points_win: competition.points_win,
+            points_draw: competition.points_draw,
+            points_loss: competition.points_loss,
+            points_bye: competition.points_bye,
+            evaluator_source: competition.evaluator_source,
+            evaluator_sha256: competition.evaluator_sha256,
+            submission_freeze_minutes: competition.submission_freeze_minutes,
+            registration_open: competition.registration_open,
+            registration_close: competition.registration_close,
+            max_teams: competition.max_teams,
+            archived: competition.archived,
+            game_timeout_secs: competition.game_timeout_secs,
+            stall_timeout_secs: competition.stall_timeout_secs,
+            priority: competition.priority,
         }
     }
 }
 
 impl From<NewCompetition> for SqlCompetition {
     fn from(new_competition: NewCompetition) -> Self {
+        let created = Local::now().naive_utc();
         Self {
             id: Uuid::new_v4().to_string(),
             name: new_competition.name,
             start: new_competition.start,
             end: new_competition.end,
-            allowed_submissions: true.to_string(),
-            round: 0.to_string(),
+            allowed_submissions: true,
+            round: 0,
             type_: new_competition.type_.clone(),
             games_per_round: 6,
             game_pack: format!("./resources/packs/Batalja{}Pack.zip", new_competition.type_),
-            created: Local::now().naive_utc(),
+            created,
+            replay_compress_after: new_competition.replay_compress_after.unwrap_or(0),
+            replay_delete_after: new_competition.replay_delete_after.unwrap_or(0),
+            elo_k_factor: new_competition.elo_k_factor.unwrap_or(settings().elo.k_factor),
+            provisional_games: new_competition.provisional_games.unwrap_or(0),
+            provisional_k_factor: new_competition.provisional_k_factor.unwrap_or(settings().elo.provisional_k_factor),
+            scoring_system: new_competition.scoring_system.unwrap_or(ScoringSystem::Elo).to_string(),
+            points_win: new_competition.points_win.unwrap_or(3),
+            points_draw: new_competition.points_draw.unwrap_or(1),
+            points_loss: new_competition.points_loss.unwrap_or(0),
+            points_bye: new_competition.points_bye.unwrap_or(3),
+            evaluator_source: new_competition.evaluator_source.unwrap_or_default(),
+            evaluator_sha256: new_competition.evaluator_sha256.unwrap_or_default().to_lowercase(),
+            submission_freeze_minutes: new_competition.submission_freeze_minutes.unwrap_or(0),
+            registration_open: new_competition.registration_open.unwrap_or(created),
+            registration_close: new_competition.registration_close.unwrap_or(new_competition.end),
+            max_teams: new_competition.max_teams.unwrap_or(0).max(0),
+            archived: false,
+            archive_path: "".to_string(),
+            deleted_at: None,
+            game_timeout_secs: new_competition.game_timeout_secs.unwrap_or(DEFAULT_GAME_TIMEOUT_SECS),
+            stall_timeout_secs: new_competition.stall_timeout_secs.unwrap_or(0),
+            priority: new_competition.priority.unwrap_or(1).max(1),
+        }
+    }
+}
+
+impl SqlCompetition {
+    /// Copies the game, timeout, rating, scoring, submission and registration settings of
+    /// `template`.
+    /// Progress of the template, its round and submission state, isn't copied.
+    pub fn from_template(template: Competition, clone: CompetitionClone) -> Self {
+        let created = Local::now().naive_utc();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: clone.name,
+            start: clone.start,
+            end: clone.end,
+            allowed_submissions: true,
+            round: 0,
+            type_: template.type_,
+            games_per_round: template.games_per_round,
+            game_pack: template.game_pack,
+            created,
+            replay_compress_after: template.replay_compress_after,
+            replay_delete_after: template.replay_delete_after,
+            elo_k_factor: template.elo_k_factor,
+            provisional_games: template.provisional_games,
+            provisional_k_factor: template.provisional_k_factor,
+            scoring_system: template.scoring_system.to_string(),
+            points_win: template.points_win,
+            points_draw: template.points_draw,
+            points_loss: template.points_loss,
+            points_bye: template.points_bye,
+            evaluator_source: template.evaluator_source,
+            evaluator_sha256: template.evaluator_sha256,
+            submission_freeze_minutes: template.submission_freeze_minutes,
+            registration_open: clone.registration_open.unwrap_or(created),
+            registration_close: clone.registration_close.unwrap_or(clone.end),
+            max_teams: template.max_teams,
+            archived: false,
+            archive_path: "".to_string(),
+            deleted_at: None,
+            game_timeout_secs: template.game_timeout_secs,
+            stall_timeout_secs: template.stall_timeout_secs,
+            priority: template.priority,
+        }
+    }
+}
+
+impl fmt::Display for ScoringSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScoringSystem::Elo => write!(f, "ELO"),
+            ScoringSystem::Points => write!(f, "POINTS"),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use super::errors::MatchMakerError;
+
+/// A single message javac reported for a bot's source.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CompileDiagnostic {
+    pub file: String,
+    pub line: u32,
+    /// 1-based column the compiler pointed at, if it did
+    pub column: Option<u32>,
+    pub severity: String,
+    pub message: String,
+    /// the offending line of source as javac quoted it
+    pub source_line: String,
+}
+
+/// The parsed compiler output of a bot, stored with the bot so the team can see what
+/// javac complained about. Output that isn't tied to a file ends up in `other`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct CompileDiagnostics {
+    pub errors: usize,
+    pub warnings: usize,
+    pub diagnostics: Vec<CompileDiagnostic>,
+    pub other: Vec<String>,
+}
+
+impl CompileDiagnostics {
+    /// Parses javac's output.
+    ///
+    /// A diagnostic starts with a `<path>:<line>: <severity>: <message>` line, optionally
+    /// followed by the quoted source line and a caret marking the column. Paths are reduced
+    /// to the file name so the server's directory layout isn't exposed.
+    pub fn parse(output: &[String]) -> Self {
+        let mut result = CompileDiagnostics::default();
+
+        for line in output.iter() {
+            if let Some(diagnostic) = parse_header(line) {
+                match diagnostic.severity.as_str() {
+                    "error" => result.errors += 1,
+                    "warning" => result.warnings += 1,
+                    _ => (),
+                }
+                result.diagnostics.push(diagnostic);
+                continue;
+            }
+
+            if line.trim().is_empty() || is_summary(line) {
+                continue;
+            }
+
+            let current = match result.diagnostics.last_mut() {
+                Some(d) if !line.starts_with("Note: ") => d,
+                _ => {
+                    result.other.push(line.to_owned());
+                    continue;
+                }
+            };
+
+            if current.column.is_none() && line.trim() == "^" {
+                current.column = line.find('^').map(|c| c as u32 + 1);
+            } else if current.source_line.is_empty() && current.column.is_none() {
+                current.source_line = line.to_owned();
+            } else {
+                // javac continues long messages (e.g. "symbol: ...") on the following lines
+                current.message = format!("{}\n{}", current.message, line.trim());
+            }
+        }
+        result
+    }
+
+    /// Diagnostics of a failed compilation, empty for errors that didn't come from javac.
+    pub fn from_error(error: &MatchMakerError) -> Self {
+        match error {
+            MatchMakerError::CompileError(output) => Self::parse(output),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    pub fn from_json(json: &str) -> Self {
+        serde_json::from_str(json).unwrap_or_default()
+    }
+}
+
+fn parse_header(line: &str) -> Option<CompileDiagnostic> {
+    let (location, rest) = line.split_once(".java:")?;
+    let (line_number, rest) = rest.split_once(": ")?;
+    let (severity, message) = rest.split_once(": ")?;
+    if !["error", "warning", "note"].contains(&severity) {
+        return None;
+    }
+
+    let file = location.rsplit('/').next().unwrap_or(location);
+    Some(CompileDiagnostic {
+        file: format!("{}.java", file),
+        line: line_number.parse().ok()?,
+        column: None,
+        severity: severity.to_string(),
+        message: message.to_string(),
+        source_line: "".to_string(),
+    })
+}
+
+/// The "1 error" / "2 warnings" lines javac ends with.
+fn is_summary(line: &str) -> bool {
+    let mut parts = line.split_whitespace();
+    matches!(
+        (parts.next().map(|n| n.parse::<u32>().is_ok()), parts.next(), parts.next()),
+        (Some(true), Some("error" | "errors" | "warning" | "warnings"), None)
+    )
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::game_player_stats::FailureKind;
+
+/// Crashed games a team was blamed for, by why they crashed.
+#[derive(Debug, Serialize, Clone, Default, ToSchema)]
+pub struct CrashCounts {
+    pub total: i32,
+    pub bot_exceptions: i32,
+    pub bot_timeouts: i32,
+    pub out_of_memory: i32,
+    pub evaluator_errors: i32,
+    pub unknown: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamCrashes {
+    pub team_id: String,
+    pub team_name: String,
+    pub crashes: CrashCounts,
+}
+
+/// Crashes of a competition's games, the teams that crashed the most first. Crashes no bot
+/// was blamed for only count towards `totals`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CrashReport {
+    pub competition_id: String,
+    pub totals: CrashCounts,
+    pub teams: Vec<TeamCrashes>,
+}
+
+impl CrashCounts {
+    pub fn count(&mut self, kind: FailureKind) {
+        self.total += 1;
+        match kind {
+            FailureKind::BotException => self.bot_exceptions += 1,
+            FailureKind::BotTimeout => self.bot_timeouts += 1,
+            FailureKind::OutOfMemory => self.out_of_memory += 1,
+            FailureKind::EvaluatorError => self.evaluator_errors += 1,
+            FailureKind::Unknown => self.unknown += 1,
+        }
+    }
+}
\ No newline at end of file
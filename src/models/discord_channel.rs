@@ -0,0 +1,83 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::discord_channels::{self};
+
+pub const DEFAULT_STANDINGS_SIZE: i32 = 10;
+/// Discord allows up to 25 fields per embed, one line per team keeps well below its limits.
+pub const MAX_STANDINGS_SIZE: i32 = 25;
+
+/// The Discord channel a competition's round summaries are posted to. `webhook_url` is the
+/// channel's webhook, created in the channel's integration settings.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewDiscordChannel {
+    pub competition_id: String,
+    pub webhook_url: String,
+    pub standings_size: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiscordChannel {
+    pub id: String,
+    pub competition_id: String,
+    pub webhook_url: String,
+    pub standings_size: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = discord_channels)]
+pub struct SqlDiscordChannel {
+    pub id: String,
+    pub competition_id: String,
+    pub webhook_url: String,
+    pub standings_size: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicDiscordChannel {
+    pub id: String,
+    pub competition_id: String,
+    pub webhook_url: String,
+    pub standings_size: i32,
+    pub updated: NaiveDateTime,
+}
+
+impl From<SqlDiscordChannel> for DiscordChannel {
+    fn from(sql_channel: SqlDiscordChannel) -> Self {
+        Self {
+            id: sql_channel.id,
+            competition_id: sql_channel.competition_id,
+            webhook_url: sql_channel.webhook_url,
+            standings_size: sql_channel.standings_size,
+            updated: sql_channel.updated,
+        }
+    }
+}
+
+impl From<DiscordChannel> for PublicDiscordChannel {
+    fn from(channel: DiscordChannel) -> Self {
+        Self {
+            id: channel.id,
+            competition_id: channel.competition_id,
+            webhook_url: channel.webhook_url,
+            standings_size: channel.standings_size,
+            updated: channel.updated,
+        }
+    }
+}
+
+impl From<NewDiscordChannel> for SqlDiscordChannel {
+    fn from(new_channel: NewDiscordChannel) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_channel.competition_id,
+            webhook_url: new_channel.webhook_url.trim().to_string(),
+            standings_size: new_channel.standings_size.unwrap_or(DEFAULT_STANDINGS_SIZE),
+            updated: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use async_graphql::SimpleObject;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::elo_history::{self};
+
+#[derive(Debug)]
+pub struct NewEloHistory {
+    pub team_id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub elo: i32,
+    pub elo_change: i32,
+}
+
+#[derive(Debug)]
+pub struct EloHistory {
+    pub id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub elo: i32,
+    pub elo_change: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = elo_history)]
+pub struct SqlEloHistory {
+    pub id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub elo: i32,
+    pub elo_change: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema, SimpleObject)]
+#[graphql(name = "EloHistory")]
+pub struct PublicEloHistory {
+    pub id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub elo: i32,
+    pub elo_change: i32,
+    pub created: NaiveDateTime,
+}
+
+impl From<SqlEloHistory> for EloHistory {
+    fn from(sql_elo_history: SqlEloHistory) -> Self {
+        Self {
+            id: sql_elo_history.id,
+            team_id: sql_elo_history.team_id,
+            competition_id: sql_elo_history.competition_id,
+            round: sql_elo_history.round,
+            elo: sql_elo_history.elo,
+            elo_change: sql_elo_history.elo_change,
+            created: sql_elo_history.created,
+        }
+    }
+}
+
+impl From<EloHistory> for PublicEloHistory {
+    fn from(elo_history: EloHistory) -> Self {
+        Self {
+            id: elo_history.id,
+            team_id: elo_history.team_id,
+            competition_id: elo_history.competition_id,
+            round: elo_history.round,
+            elo: elo_history.elo,
+            elo_change: elo_history.elo_change,
+            created: elo_history.created,
+        }
+    }
+}
+
+impl From<NewEloHistory> for SqlEloHistory {
+    fn from(new_elo_history: NewEloHistory) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            team_id: new_elo_history.team_id,
+            competition_id: new_elo_history.competition_id,
+            round: new_elo_history.round,
+            elo: new_elo_history.elo,
+            elo_change: new_elo_history.elo_change,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -13,6 +13,20 @@ pub enum MatchMakerError {
     ZippingError(ZipError),
     PlayerFileMissing,
     MainMethodNotInPlayerFile,
+    ExecutionDisabled,
+    BotCountMismatch(usize, usize),
+    ChecksumMismatch(String),
+    CompileError(Vec<String>),
+    ForbiddenApi(Vec<String>),
+    ShuttingDown,
+    DiskQuotaExceeded(u64),
+    UnsafeArchive(Vec<String>),
+    /// the round was claimed by another run of the same competition
+    RoundAlreadyRunning(i32),
+    /// a transfer to or from the object store failed
+    StorageError(String),
+    /// not enough free disk for the round to start, bytes it needs and bytes that are free
+    InsufficientDisk(u64, u64),
 }
 
 // Implement std::fmt::Display for MatchMakerError
@@ -27,6 +41,17 @@ impl fmt::Display for MatchMakerError {
             MatchMakerError::ZippingError(err) => writeln!(f, "ZippingError: {}", err),
             MatchMakerError::PlayerFileMissing => writeln!(f, "PlayerFileMissing Error"),
             MatchMakerError::MainMethodNotInPlayerFile => writeln!(f, "MainMethodNotInPlayerFile Error"),
+            MatchMakerError::ExecutionDisabled => writeln!(f, "ExecutionDisabled Error: server is running in safe mode"),
+            MatchMakerError::BotCountMismatch(expected, actual) => writeln!(f, "BotCountMismatch Error: game needs {} bots, got {}", expected, actual),
+            MatchMakerError::ChecksumMismatch(details) => writeln!(f, "ChecksumMismatch Error: {}", details),
+            MatchMakerError::CompileError(output) => writeln!(f, "CompileError: {}", output.join("\n")),
+            MatchMakerError::ForbiddenApi(violations) => writeln!(f, "ForbiddenApi Error: {}", violations.join("\n")),
+            MatchMakerError::ShuttingDown => writeln!(f, "ShuttingDown Error: server is shutting down"),
+            MatchMakerError::DiskQuotaExceeded(quota) => writeln!(f, "DiskQuotaExceeded Error: game wrote more than {} bytes", quota),
+            MatchMakerError::UnsafeArchive(problems) => writeln!(f, "UnsafeArchive Error: {}", problems.join("\n")),
+            MatchMakerError::RoundAlreadyRunning(round) => writeln!(f, "RoundAlreadyRunning Error: round {} is already running or was played", round),
+            MatchMakerError::StorageError(details) => writeln!(f, "StorageError: {}", details),
+            MatchMakerError::InsufficientDisk(needed, free) => writeln!(f, "InsufficientDisk Error: round needs about {} MB, {} MB free", needed / 1024 / 1024, free / 1024 / 1024),
         }
     }
 }
@@ -43,6 +68,17 @@ impl fmt::Debug for MatchMakerError {
             MatchMakerError::ZippingError(err) => writeln!(f, "MatchMakerError::ZippingError: {:?}", err),
             MatchMakerError::PlayerFileMissing => writeln!(f, "MatchMakerError::PlayerFileMissing"),
             MatchMakerError::MainMethodNotInPlayerFile => writeln!(f, "MatchMakerError::MainMethodNotInPlayerFile"),
+            MatchMakerError::ExecutionDisabled => writeln!(f, "MatchMakerError::ExecutionDisabled"),
+            MatchMakerError::BotCountMismatch(expected, actual) => writeln!(f, "MatchMakerError::BotCountMismatch: expected {}, got {}", expected, actual),
+            MatchMakerError::ChecksumMismatch(details) => writeln!(f, "MatchMakerError::ChecksumMismatch: {}", details),
+            MatchMakerError::CompileError(output) => writeln!(f, "MatchMakerError::CompileError: {:?}", output),
+            MatchMakerError::ForbiddenApi(violations) => writeln!(f, "MatchMakerError::ForbiddenApi: {:?}", violations),
+            MatchMakerError::ShuttingDown => writeln!(f, "MatchMakerError::ShuttingDown"),
+            MatchMakerError::DiskQuotaExceeded(quota) => writeln!(f, "MatchMakerError::DiskQuotaExceeded: {}", quota),
+            MatchMakerError::UnsafeArchive(problems) => writeln!(f, "MatchMakerError::UnsafeArchive: {:?}", problems),
+            MatchMakerError::RoundAlreadyRunning(round) => writeln!(f, "MatchMakerError::RoundAlreadyRunning: {}", round),
+            MatchMakerError::StorageError(details) => writeln!(f, "MatchMakerError::StorageError: {}", details),
+            MatchMakerError::InsufficientDisk(needed, free) => writeln!(f, "MatchMakerError::InsufficientDisk: needs {} bytes, {} free", needed, free),
         }
     }
 }
@@ -59,6 +95,16 @@ impl Error for MatchMakerError {
             MatchMakerError::ZippingError(err) => Some(err),
             MatchMakerError::PlayerFileMissing => None,
             MatchMakerError::MainMethodNotInPlayerFile => None,
+            MatchMakerError::ExecutionDisabled => None,
+            MatchMakerError::BotCountMismatch(_, _) => None,
+            MatchMakerError::ChecksumMismatch(_) => None,
+            MatchMakerError::CompileError(_) => None,
+            MatchMakerError::ForbiddenApi(_) => None,
+            MatchMakerError::ShuttingDown => None,
+            MatchMakerError::DiskQuotaExceeded(_) => None,
+            MatchMakerError::UnsafeArchive(_) => None,
+            MatchMakerError::RoundAlreadyRunning(_) => None,
+            MatchMakerError::StorageError(_) => None,
         }
     }
 }
@@ -0,0 +1,43 @@
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Why a game was featured.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FeaturedReason {
+    /// smallest score difference of the round
+    ClosestScore,
+    /// largest ELO change of the round
+    BiggestEloSwing,
+    /// the two teams at the top of the standings played each other
+    TopTeamsMeeting,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FeaturedQuery {
+    /// defaults to the latest round with games
+    pub round: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeaturedGame {
+    pub game_id: String,
+    pub team1_id: String,
+    pub team1_name: String,
+    pub team2_id: String,
+    pub team2_name: String,
+    pub winner_id: String,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub elo_swing: i32,
+    pub public: bool,
+    pub reasons: Vec<FeaturedReason>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeaturedGames {
+    pub competition_id: String,
+    pub round: Option<i32>,
+    pub games: Vec<FeaturedGame>,
+}
\ No newline at end of file
@@ -1,10 +1,65 @@
+use std::fmt;
+
 use diesel::prelude::{Insertable, Queryable};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use async_graphql::{Enum, SimpleObject};
 use chrono::{NaiveDateTime, Local};
+use rand::Rng;
 use uuid::Uuid;
 use crate::db::schema::games_2v2::{self};
+use crate::models::round_leniency::DEFAULT_GAME_TIMEOUT_SECS;
+use crate::models::game_player_stats::NewGamePlayerStats;
+use crate::parsers::{EvaluatorOutput, EvaluatorVersion};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Default, ToSchema, Enum)]
+pub enum ReplayState {
+    #[default]
+    Stored,
+    Compressed,
+    Deleted,
+    /// written straight to the offload store while the server was low on disk space
+    Offloaded,
+    /// turn data was discarded while the server was low on disk space, only results are kept
+    Dropped,
+}
+
+/// Outcome of a game that isn't stored, see `play_unranked_game`.
+#[derive(Debug)]
+pub struct UnrankedGameOutput {
+    pub parsed: EvaluatorOutput,
+    /// the game's stdout, the same contents a stored replay has
+    pub replay: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Colors the Evaluator gives a team's bots, the first team passed to it plays yellow and
+/// green, the second blue and cyan.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, Enum)]
+pub enum ColorPair {
+    YellowGreen,
+    BlueCyan,
+}
+
+impl fmt::Display for ColorPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColorPair::YellowGreen => write!(f, "YELLOW_GREEN"),
+            ColorPair::BlueCyan => write!(f, "BLUE_CYAN"),
+        }
+    }
+}
 
-#[derive(Debug, Deserialize)]
+impl From<&str> for ColorPair {
+    fn from(colors: &str) -> Self {
+        match colors {
+            "BLUE_CYAN" => ColorPair::BlueCyan,
+            _ => ColorPair::YellowGreen,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct NewGame2v2 {
     pub id: String,
     pub competition_id: String,
@@ -25,6 +80,23 @@ pub struct NewGame2v2 {
     pub team1_elo: i32,
     pub team2_elo: i32,
     pub additional_data: String,
+    #[serde(skip)]
+    pub replay_state: ReplayState,
+    pub timeout_secs: i32,
+    pub attempts: i32,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub trace_id: String,
+    pub output_version: String,
+    pub duration_ms: i64,
+    pub map_seed: i64,
+    pub team1_colors: ColorPair,
+    pub team2_colors: ColorPair,
+    #[serde(default)]
+    pub stderr_path: String,
+    /// played against a ghost team, changes no ratings
+    #[serde(default)]
+    pub unrated: bool,
 }
 
 #[derive(Debug)]
@@ -49,9 +121,30 @@ pub struct Game2v2 {
     pub team1_elo: i32,
     pub team2_elo: i32,
     pub created: NaiveDateTime,
+    pub replay_state: ReplayState,
+    pub timeout_secs: i32,
+    pub attempts: i32,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub trace_id: String,
+    pub output_version: String,
+    pub duration_ms: i64,
+    pub map_seed: i64,
+    pub team1_colors: ColorPair,
+    pub team2_colors: ColorPair,
+    pub stderr_path: String,
+    pub unrated: bool,
 }   
 
-#[derive(Queryable, Debug, Insertable)]
+/// A ranked game that was played but isn't stored yet. It's stored together with the rest of
+/// its round, so a failed round leaves no games behind.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PendingGame2v2 {
+    pub game: SqlGame2v2,
+    pub player_stats: Vec<NewGamePlayerStats>,
+}
+
+#[derive(Queryable, Debug, Clone, Insertable, Serialize, Deserialize, ToSchema)]
 #[diesel(table_name = games_2v2)]
 pub struct SqlGame2v2 {
     pub id: String,
@@ -74,9 +167,26 @@ pub struct SqlGame2v2 {
     pub team1_elo: i32,
     pub team2_elo: i32,
     pub created: NaiveDateTime,
+    pub replay_state: String,
+    pub timeout_secs: i32,
+    pub attempts: i32,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub trace_id: String,
+    pub output_version: String,
+    pub duration_ms: i64,
+    pub map_seed: i64,
+    pub team1_colors: String,
+    pub team2_colors: String,
+    /// error output of the bots, empty if they printed none
+    #[serde(default)]
+    pub stderr_path: String,
+    #[serde(default)]
+    pub unrated: bool,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema, SimpleObject)]
+#[graphql(name = "Game", complex)]
 pub struct PublicGame2v2 {
     pub id: String,
     pub competition_id: String,
@@ -97,6 +207,19 @@ pub struct PublicGame2v2 {
     pub team1_elo: i32,
     pub team2_elo: i32,
     pub created: NaiveDateTime,
+    pub replay_state: ReplayState,
+    pub timeout_secs: i32,
+    pub attempts: i32,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub trace_id: String,
+    pub output_version: String,
+    pub duration_ms: i64,
+    pub map_seed: i64,
+    pub team1_colors: ColorPair,
+    pub team2_colors: ColorPair,
+    /// played against a ghost team, changes no ratings
+    pub unrated: bool,
 }
 
 impl From<SqlGame2v2> for Game2v2 {
@@ -122,6 +245,25 @@ impl From<SqlGame2v2> for Game2v2 {
             team1_elo: sql_game_2v2.team1_elo,
             team2_elo: sql_game_2v2.team2_elo,
             created: sql_game_2v2.created,
+            replay_state: match sql_game_2v2.replay_state.as_str() {
+                "COMPRESSED" => ReplayState::Compressed,
+                "DELETED" => ReplayState::Deleted,
+                "OFFLOADED" => ReplayState::Offloaded,
+                "DROPPED" => ReplayState::Dropped,
+                _ => ReplayState::Stored,
+            },
+            timeout_secs: sql_game_2v2.timeout_secs,
+            attempts: sql_game_2v2.attempts,
+            team1_score: sql_game_2v2.team1_score,
+            team2_score: sql_game_2v2.team2_score,
+            trace_id: sql_game_2v2.trace_id,
+            output_version: sql_game_2v2.output_version,
+            duration_ms: sql_game_2v2.duration_ms,
+            map_seed: sql_game_2v2.map_seed,
+            team1_colors: ColorPair::from(sql_game_2v2.team1_colors.as_str()),
+            team2_colors: ColorPair::from(sql_game_2v2.team2_colors.as_str()),
+            stderr_path: sql_game_2v2.stderr_path,
+            unrated: sql_game_2v2.unrated,
         }
     }
 }
@@ -148,6 +290,18 @@ impl From<Game2v2> for PublicGame2v2 {
             team1_elo: game_2v2.team1_elo,
             team2_elo: game_2v2.team2_elo,
             created: game_2v2.created,
+            replay_state: game_2v2.replay_state,
+            timeout_secs: game_2v2.timeout_secs,
+            attempts: game_2v2.attempts,
+            team1_score: game_2v2.team1_score,
+            team2_score: game_2v2.team2_score,
+            trace_id: game_2v2.trace_id,
+            output_version: game_2v2.output_version,
+            duration_ms: game_2v2.duration_ms,
+            map_seed: game_2v2.map_seed,
+            team1_colors: game_2v2.team1_colors,
+            team2_colors: game_2v2.team2_colors,
+            unrated: game_2v2.unrated,
         }
     }
 }
@@ -175,6 +329,19 @@ impl From<NewGame2v2> for SqlGame2v2 {
             team1_elo: new_game_2v2.team1_elo,
             team2_elo: new_game_2v2.team2_elo,
             created: Local::now().naive_utc(),
+            replay_state: new_game_2v2.replay_state.to_string(),
+            timeout_secs: new_game_2v2.timeout_secs,
+            attempts: new_game_2v2.attempts,
+            team1_score: new_game_2v2.team1_score,
+            team2_score: new_game_2v2.team2_score,
+            trace_id: new_game_2v2.trace_id,
+            output_version: new_game_2v2.output_version,
+            duration_ms: new_game_2v2.duration_ms,
+            map_seed: new_game_2v2.map_seed,
+            team1_colors: new_game_2v2.team1_colors.to_string(),
+            team2_colors: new_game_2v2.team2_colors.to_string(),
+            stderr_path: new_game_2v2.stderr_path,
+            unrated: new_game_2v2.unrated,
         }
     }
 }
@@ -211,6 +378,36 @@ impl NewGame2v2 {
             team1_elo: 0,
             team2_elo: 0,
             additional_data: "".to_string(),
+            replay_state: ReplayState::Stored,
+            timeout_secs: DEFAULT_GAME_TIMEOUT_SECS,
+            attempts: 1,
+            team1_score: 0,
+            team2_score: 0,
+            trace_id: "".to_string(),
+            output_version: EvaluatorVersion::default().to_string(),
+            duration_ms: 0,
+            map_seed: new_map_seed(),
+            team1_colors: ColorPair::YellowGreen,
+            team2_colors: ColorPair::BlueCyan,
+            stderr_path: "".to_string(),
+            unrated: false,
+        }
+    }
+}
+
+/// Seed the Evaluator generates the map from, replaying a seed replays the same map.
+pub fn new_map_seed() -> i64 {
+    rand::thread_rng().gen_range(1..=i64::from(u32::MAX))
+}
+
+impl fmt::Display for ReplayState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayState::Stored => write!(f, "STORED"),
+            ReplayState::Compressed => write!(f, "COMPRESSED"),
+            ReplayState::Deleted => write!(f, "DELETED"),
+            ReplayState::Offloaded => write!(f, "OFFLOADED"),
+            ReplayState::Dropped => write!(f, "DROPPED"),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,198 @@
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::game_2v2::{Game2v2, PublicGame2v2};
+
+pub const DEFAULT_GAMES_PER_PAGE: i64 = 50;
+pub const MAX_GAMES_PER_PAGE: i64 = 200;
+const CURSOR_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameSort {
+    Created,
+    Round,
+    Duration,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GameListQuery {
+    pub round: Option<i32>,
+    /// games the team played in
+    pub team_id: Option<String>,
+    pub winner_id: Option<String>,
+    /// `created` (default), `round` or `duration`
+    pub sort: Option<String>,
+    /// `asc` or `desc` (default)
+    pub order: Option<String>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// Position after the last game of a page, the sort key of that game and its id to break
+/// ties. Sent to clients hex encoded, they shouldn't depend on what's inside.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameCursor {
+    pub key: CursorKey,
+    pub id: String,
+}
+
+/// Value a game is sorted by, one variant per `GameSort`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CursorKey {
+    Created(chrono::NaiveDateTime),
+    Round(i32),
+    Duration(i64),
+}
+
+#[derive(Debug)]
+pub struct GameListFilter {
+    pub competition_id: String,
+    pub round: Option<i32>,
+    pub team_id: Option<String>,
+    pub winner_id: Option<String>,
+    /// private games are only listed if one of these teams played them, `None` lists all
+    pub visible_to_teams: Option<Vec<String>>,
+    pub sort: GameSort,
+    pub descending: bool,
+    pub after: Option<GameCursor>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GamePage {
+    pub games: Vec<PublicGame2v2>,
+    /// `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl TryFrom<&str> for GameSort {
+    type Error = String;
+
+    fn try_from(sort: &str) -> Result<Self, Self::Error> {
+        match sort {
+            "created" => Ok(GameSort::Created),
+            "round" => Ok(GameSort::Round),
+            "duration" => Ok(GameSort::Duration),
+            _ => Err(format!("Unknown sort: {}", sort)),
+        }
+    }
+}
+
+impl GameSort {
+    /// The value the game is sorted by.
+    pub fn key(&self, game: &Game2v2) -> CursorKey {
+        match self {
+            GameSort::Created => CursorKey::Created(game.created),
+            GameSort::Round => CursorKey::Round(game.round),
+            GameSort::Duration => CursorKey::Duration(game.duration_ms),
+        }
+    }
+
+    /// Reads a key this sort put in a cursor, `None` if it couldn't have.
+    fn parse_key(&self, key: &str) -> Option<CursorKey> {
+        match self {
+            GameSort::Created => chrono::NaiveDateTime::parse_from_str(key, CURSOR_DATETIME_FORMAT).ok().map(CursorKey::Created),
+            GameSort::Round => key.parse::<i32>().ok().map(CursorKey::Round),
+            GameSort::Duration => key.parse::<i64>().ok().map(CursorKey::Duration),
+        }
+    }
+}
+
+impl GameCursor {
+    pub fn after(sort: GameSort, game: &Game2v2) -> Self {
+        Self { key: sort.key(game), id: game.id.clone() }
+    }
+
+    pub fn encode(&self) -> String {
+        let key = match &self.key {
+            CursorKey::Created(created) => created.format(CURSOR_DATETIME_FORMAT).to_string(),
+            CursorKey::Round(round) => round.to_string(),
+            CursorKey::Duration(duration_ms) => duration_ms.to_string(),
+        };
+        hex::encode(format!("{}|{}", key, self.id))
+    }
+
+    /// Fails on cursors that weren't created for `sort`, e.g. when the sort changed
+    /// between pages.
+    pub fn decode(cursor: &str, sort: GameSort) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+        let decoded = hex::decode(cursor).map_err(|_| invalid())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+        let (key, id) = decoded.split_once('|').ok_or_else(invalid)?;
+        let key = sort.parse_key(key).ok_or_else(invalid)?;
+        Ok(Self { key, id: id.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::game_2v2::{NewGame2v2, SqlGame2v2};
+
+    const SORTS: [GameSort; 3] = [GameSort::Created, GameSort::Round, GameSort::Duration];
+
+    fn game() -> Game2v2 {
+        let mut game = Game2v2::from(SqlGame2v2::from(NewGame2v2::new(
+            "competition".to_string(),
+            7,
+            "team1".to_string(),
+            "team2".to_string(),
+            "bot1".to_string(),
+            "bot2".to_string(),
+            "bot3".to_string(),
+            "bot4".to_string(),
+        )));
+        game.created = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap().and_hms_micro_opt(13, 5, 9, 123_456).unwrap();
+        game.duration_ms = 98_765;
+        game
+    }
+
+    #[test]
+    fn cursors_round_trip_for_every_sort() {
+        let game = game();
+        for sort in SORTS {
+            let cursor = GameCursor::after(sort, &game);
+            assert_eq!(GameCursor::decode(&cursor.encode(), sort), Ok(cursor));
+        }
+    }
+
+    #[test]
+    fn cursors_keep_the_sort_key_and_the_id() {
+        let game = game();
+        let decode = |sort| GameCursor::decode(&GameCursor::after(sort, &game).encode(), sort).unwrap();
+        assert_eq!(decode(GameSort::Created).key, CursorKey::Created(game.created));
+        assert_eq!(decode(GameSort::Round).key, CursorKey::Round(7));
+        assert_eq!(decode(GameSort::Duration).key, CursorKey::Duration(98_765));
+        assert_eq!(decode(GameSort::Round).id, game.id);
+    }
+
+    #[test]
+    fn rejects_a_cursor_of_another_sort() {
+        let created = GameCursor::after(GameSort::Created, &game()).encode();
+        assert!(GameCursor::decode(&created, GameSort::Round).is_err());
+        assert!(GameCursor::decode(&created, GameSort::Duration).is_err());
+        let round = GameCursor::after(GameSort::Round, &game()).encode();
+        assert!(GameCursor::decode(&round, GameSort::Created).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_hex() {
+        for sort in SORTS {
+            assert!(GameCursor::decode("not hex", sort).is_err());
+            assert!(GameCursor::decode("abc", sort).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_a_cursor_without_separator() {
+        for sort in SORTS {
+            assert!(GameCursor::decode(&hex::encode("7"), sort).is_err());
+        }
+        // not valid UTF-8 either
+        assert!(GameCursor::decode(&hex::encode([0xff, b'|', b'a']), GameSort::Round).is_err());
+    }
+}
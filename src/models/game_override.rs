@@ -0,0 +1,40 @@
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use super::game_2v2::PublicGame2v2;
+
+/// Corrected result of a game, survivors that aren't given stay as they are.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GameResultOverride {
+    pub game_id: String,
+    /// id of one of the game's teams, a game can't be overridden to a draw
+    pub winner_id: String,
+    pub team1bot1_survived: Option<bool>,
+    pub team1bot2_survived: Option<bool>,
+    pub team2bot1_survived: Option<bool>,
+    pub team2bot2_survived: Option<bool>,
+    /// why the result was overridden, kept in the audit log
+    pub reason: String,
+}
+
+/// A game's corrected result and the ELO changes it's rated with, ready to be stored.
+#[derive(Debug)]
+pub struct GameResultCorrection {
+    pub game_id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub winner_id: String,
+    /// survivors in slot order, `team1bot1` first
+    pub survived: [bool; 4],
+    pub team1_elo: i32,
+    pub team2_elo: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GameOverrideResult {
+    pub game: PublicGame2v2,
+    pub team1_elo_correction: i32,
+    pub team2_elo_correction: i32,
+}
\ No newline at end of file
@@ -1,6 +1,11 @@
+use diesel::prelude::{Insertable, Queryable};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::game_player_stats::{self};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GamePlayerStats {
     pub turns_played: i32,
     pub survived: bool,
@@ -18,23 +23,250 @@ pub struct GamePlayerStats {
     pub num_fleet_reinforced: i32,
     pub num_fleet_generated: i32,
     pub total_troops_generated: i32,
+    /// turns the game pack reported a decision time for, the turn times are 0 without any
+    #[serde(default)]
+    pub turns_timed: i32,
+    #[serde(default)]
+    pub turn_time_p50_ms: i32,
+    #[serde(default)]
+    pub turn_time_p95_ms: i32,
+    #[serde(default)]
+    pub turn_time_max_ms: i32,
 }
 
 impl Default for GamePlayerStats {
     fn default() -> Self {
-        Self { turns_played: Default::default(), survived: Default::default(), fleet_generated: Default::default(), fleet_lost: Default::default(), fleet_reinforced: Default::default(), largest_attack: Default::default(), largest_loss: Default::default(), largest_reinforcement: Default::default(), planets_lost: Default::default(), planets_conquered: Default::default(), planets_defended: Default::default(), planets_attacked: Default::default(), num_fleet_lost: Default::default(), num_fleet_reinforced: Default::default(), num_fleet_generated: Default::default(), total_troops_generated: Default::default() }
+        Self { turns_played: Default::default(), survived: Default::default(), fleet_generated: Default::default(), fleet_lost: Default::default(), fleet_reinforced: Default::default(), largest_attack: Default::default(), largest_loss: Default::default(), largest_reinforcement: Default::default(), planets_lost: Default::default(), planets_conquered: Default::default(), planets_defended: Default::default(), planets_attacked: Default::default(), num_fleet_lost: Default::default(), num_fleet_reinforced: Default::default(), num_fleet_generated: Default::default(), total_troops_generated: Default::default(), turns_timed: Default::default(), turn_time_p50_ms: Default::default(), turn_time_p95_ms: Default::default(), turn_time_max_ms: Default::default() }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Why a game crashed, as classified from its stderr.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum FailureKind {
+    /// a bot threw an exception
+    BotException,
+    /// a bot didn't answer in time
+    BotTimeout,
+    /// a bot or the evaluator ran out of memory or was killed for it
+    OutOfMemory,
+    /// the evaluator failed without any bot to blame
+    EvaluatorError,
+    /// crashed games recorded before they were classified, or not matching any pattern
+    #[default]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GameError {
     pub error: String,
     pub blame_id: String,
+    #[serde(default)]
+    pub failure_kind: FailureKind,
 }
 
 
 impl Default for GameError {
     fn default() -> Self {
-        Self { error: Default::default(), blame_id: Default::default() }
+        Self { error: Default::default(), blame_id: Default::default(), failure_kind: Default::default() }
+    }
+}
+
+/// Stats of a single bot in a single game, as stored in the `game_player_stats` table.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NewGamePlayerStats {
+    pub game_id: String,
+    pub team_id: String,
+    pub bot_id: String,
+    pub slot: String,
+    pub stats: GamePlayerStats,
+}
+
+#[derive(Debug)]
+pub struct GamePlayerStatsRecord {
+    pub id: String,
+    pub game_id: String,
+    pub team_id: String,
+    pub bot_id: String,
+    pub slot: String,
+    pub turns_played: i32,
+    pub survived: bool,
+    pub fleet_generated: i32,
+    pub fleet_lost: i32,
+    pub fleet_reinforced: i32,
+    pub largest_attack: i32,
+    pub largest_loss: i32,
+    pub largest_reinforcement: i32,
+    pub planets_lost: i32,
+    pub planets_conquered: i32,
+    pub planets_defended: i32,
+    pub planets_attacked: i32,
+    pub num_fleet_lost: i32,
+    pub num_fleet_reinforced: i32,
+    pub num_fleet_generated: i32,
+    pub total_troops_generated: i32,
+    pub turns_timed: i32,
+    pub turn_time_p50_ms: i32,
+    pub turn_time_p95_ms: i32,
+    pub turn_time_max_ms: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = game_player_stats)]
+pub struct SqlGamePlayerStats {
+    pub id: String,
+    pub game_id: String,
+    pub team_id: String,
+    pub bot_id: String,
+    pub slot: String,
+    pub turns_played: i32,
+    pub survived: bool,
+    pub fleet_generated: i32,
+    pub fleet_lost: i32,
+    pub fleet_reinforced: i32,
+    pub largest_attack: i32,
+    pub largest_loss: i32,
+    pub largest_reinforcement: i32,
+    pub planets_lost: i32,
+    pub planets_conquered: i32,
+    pub planets_defended: i32,
+    pub planets_attacked: i32,
+    pub num_fleet_lost: i32,
+    pub num_fleet_reinforced: i32,
+    pub num_fleet_generated: i32,
+    pub total_troops_generated: i32,
+    pub turns_timed: i32,
+    pub turn_time_p50_ms: i32,
+    pub turn_time_p95_ms: i32,
+    pub turn_time_max_ms: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicGamePlayerStats {
+    pub id: String,
+    pub game_id: String,
+    pub team_id: String,
+    pub bot_id: String,
+    pub slot: String,
+    pub turns_played: i32,
+    pub survived: bool,
+    pub fleet_generated: i32,
+    pub fleet_lost: i32,
+    pub fleet_reinforced: i32,
+    pub largest_attack: i32,
+    pub largest_loss: i32,
+    pub largest_reinforcement: i32,
+    pub planets_lost: i32,
+    pub planets_conquered: i32,
+    pub planets_defended: i32,
+    pub planets_attacked: i32,
+    pub num_fleet_lost: i32,
+    pub num_fleet_reinforced: i32,
+    pub num_fleet_generated: i32,
+    pub total_troops_generated: i32,
+    pub turns_timed: i32,
+    pub turn_time_p50_ms: i32,
+    pub turn_time_p95_ms: i32,
+    pub turn_time_max_ms: i32,
+    pub created: NaiveDateTime,
+}
+
+impl From<SqlGamePlayerStats> for GamePlayerStatsRecord {
+    fn from(sql_stats: SqlGamePlayerStats) -> Self {
+        Self {
+            id: sql_stats.id,
+            game_id: sql_stats.game_id,
+            team_id: sql_stats.team_id,
+            bot_id: sql_stats.bot_id,
+            slot: sql_stats.slot,
+            turns_played: sql_stats.turns_played,
+            survived: sql_stats.survived,
+            fleet_generated: sql_stats.fleet_generated,
+            fleet_lost: sql_stats.fleet_lost,
+            fleet_reinforced: sql_stats.fleet_reinforced,
+            largest_attack: sql_stats.largest_attack,
+            largest_loss: sql_stats.largest_loss,
+            largest_reinforcement: sql_stats.largest_reinforcement,
+            planets_lost: sql_stats.planets_lost,
+            planets_conquered: sql_stats.planets_conquered,
+            planets_defended: sql_stats.planets_defended,
+            planets_attacked: sql_stats.planets_attacked,
+            num_fleet_lost: sql_stats.num_fleet_lost,
+            num_fleet_reinforced: sql_stats.num_fleet_reinforced,
+            num_fleet_generated: sql_stats.num_fleet_generated,
+            total_troops_generated: sql_stats.total_troops_generated,
+            turns_timed: sql_stats.turns_timed,
+            turn_time_p50_ms: sql_stats.turn_time_p50_ms,
+            turn_time_p95_ms: sql_stats.turn_time_p95_ms,
+            turn_time_max_ms: sql_stats.turn_time_max_ms,
+            created: sql_stats.created,
+        }
+    }
+}
+
+impl From<GamePlayerStatsRecord> for PublicGamePlayerStats {
+    fn from(record: GamePlayerStatsRecord) -> Self {
+        Self {
+            id: record.id,
+            game_id: record.game_id,
+            team_id: record.team_id,
+            bot_id: record.bot_id,
+            slot: record.slot,
+            turns_played: record.turns_played,
+            survived: record.survived,
+            fleet_generated: record.fleet_generated,
+            fleet_lost: record.fleet_lost,
+            fleet_reinforced: record.fleet_reinforced,
+            largest_attack: record.largest_attack,
+            largest_loss: record.largest_loss,
+            largest_reinforcement: record.largest_reinforcement,
+            planets_lost: record.planets_lost,
+            planets_conquered: record.planets_conquered,
+            planets_defended: record.planets_defended,
+            planets_attacked: record.planets_attacked,
+            num_fleet_lost: record.num_fleet_lost,
+            num_fleet_reinforced: record.num_fleet_reinforced,
+            num_fleet_generated: record.num_fleet_generated,
+            total_troops_generated: record.total_troops_generated,
+            turns_timed: record.turns_timed,
+            turn_time_p50_ms: record.turn_time_p50_ms,
+            turn_time_p95_ms: record.turn_time_p95_ms,
+            turn_time_max_ms: record.turn_time_max_ms,
+            created: record.created,
+        }
+    }
+}
+
+impl From<NewGamePlayerStats> for SqlGamePlayerStats {
+    fn from(new_stats: NewGamePlayerStats) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            game_id: new_stats.game_id,
+            team_id: new_stats.team_id,
+            bot_id: new_stats.bot_id,
+            slot: new_stats.slot,
+            turns_played: new_stats.stats.turns_played,
+            survived: new_stats.stats.survived,
+            fleet_generated: new_stats.stats.fleet_generated,
+            fleet_lost: new_stats.stats.fleet_lost,
+            fleet_reinforced: new_stats.stats.fleet_reinforced,
+            largest_attack: new_stats.stats.largest_attack,
+            largest_loss: new_stats.stats.largest_loss,
+            largest_reinforcement: new_stats.stats.largest_reinforcement,
+            planets_lost: new_stats.stats.planets_lost,
+            planets_conquered: new_stats.stats.planets_conquered,
+            planets_defended: new_stats.stats.planets_defended,
+            planets_attacked: new_stats.stats.planets_attacked,
+            num_fleet_lost: new_stats.stats.num_fleet_lost,
+            num_fleet_reinforced: new_stats.stats.num_fleet_reinforced,
+            num_fleet_generated: new_stats.stats.num_fleet_generated,
+            total_troops_generated: new_stats.stats.total_troops_generated,
+            turns_timed: new_stats.stats.turns_timed,
+            turn_time_p50_ms: new_stats.stats.turn_time_p50_ms,
+            turn_time_p95_ms: new_stats.stats.turn_time_p95_ms,
+            turn_time_max_ms: new_stats.stats.turn_time_max_ms,
+            created: Local::now().naive_utc(),
+        }
     }
 }
\ No newline at end of file
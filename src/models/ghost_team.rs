@@ -0,0 +1,120 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::ghost_teams::{self};
+use super::{published_bot::PublishedBot, team::{Team, DEFAULT_RATING_MU, DEFAULT_RATING_SIGMA}};
+
+/// A bot from the gallery entered into a competition's rounds. It plays like any team, but
+/// its games are unrated and it isn't ranked. Without a name it goes by the published bot's.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewGhostTeam {
+    pub competition_id: String,
+    pub published_bot_id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct GhostTeam {
+    pub id: String,
+    pub competition_id: String,
+    pub published_bot_id: String,
+    pub bot_id: String,
+    pub name: String,
+    pub elo: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = ghost_teams)]
+pub struct SqlGhostTeam {
+    pub id: String,
+    pub competition_id: String,
+    pub published_bot_id: String,
+    pub bot_id: String,
+    pub name: String,
+    pub elo: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicGhostTeam {
+    pub id: String,
+    pub competition_id: String,
+    pub published_bot_id: String,
+    pub name: String,
+    /// ELO the bot finished its own competition with, it never changes
+    pub elo: i32,
+    pub created: NaiveDateTime,
+}
+
+impl GhostTeam {
+    /// The ghost as a team the matchmaker can pair, with the published bot in both slots and
+    /// no members.
+    pub fn as_team(&self) -> Team {
+        Team {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            owner: "".to_string(),
+            partner: "".to_string(),
+            competition_id: self.competition_id.clone(),
+            bot1: self.bot_id.clone(),
+            bot2: self.bot_id.clone(),
+            elo: self.elo,
+            created: self.created,
+            rating_mu: DEFAULT_RATING_MU,
+            rating_sigma: DEFAULT_RATING_SIGMA,
+            waitlisted: false,
+            alias: "".to_string(),
+            hide_members: false,
+        }
+    }
+}
+
+impl SqlGhostTeam {
+    pub fn new(new_ghost_team: NewGhostTeam, published_bot: &PublishedBot) -> Self {
+        let name = if new_ghost_team.name.trim().is_empty() {
+            published_bot.name.clone()
+        } else {
+            new_ghost_team.name
+        };
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_ghost_team.competition_id,
+            published_bot_id: published_bot.id.clone(),
+            bot_id: published_bot.bot_id.clone(),
+            name,
+            elo: published_bot.final_elo,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlGhostTeam> for GhostTeam {
+    fn from(sql_ghost_team: SqlGhostTeam) -> Self {
+        Self {
+            id: sql_ghost_team.id,
+            competition_id: sql_ghost_team.competition_id,
+            published_bot_id: sql_ghost_team.published_bot_id,
+            bot_id: sql_ghost_team.bot_id,
+            name: sql_ghost_team.name,
+            elo: sql_ghost_team.elo,
+            created: sql_ghost_team.created,
+        }
+    }
+}
+
+impl From<GhostTeam> for PublicGhostTeam {
+    fn from(ghost_team: GhostTeam) -> Self {
+        Self {
+            id: ghost_team.id,
+            competition_id: ghost_team.competition_id,
+            published_bot_id: ghost_team.published_bot_id,
+            name: ghost_team.name,
+            elo: ghost_team.elo,
+            created: ghost_team.created,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use crate::db::schema::grade_mappings;
+
+/// What a team's final standing is measured by when mapping it to a grade.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum GradeBasis {
+    /// a band applies to teams ranked at `threshold` or better
+    Rank,
+    /// a band applies to teams in the top `threshold` percent of the leaderboard
+    Percentile,
+    /// a band applies to teams with at least `threshold` league points
+    Points,
+}
+
+/// A single step of a grade mapping.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GradeBand {
+    pub threshold: f64,
+    pub grade: f64,
+    /// shown to students next to their grade, e.g. "top 10%"
+    #[serde(default)]
+    pub label: String,
+}
+
+/// How the final standings of a competition translate into a course grade component. The
+/// bands are checked in order and the first one the team's standing falls into gives the
+/// grade; teams outside all bands get `default_grade`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewGradeMapping {
+    pub competition_id: String,
+    pub basis: GradeBasis,
+    pub bands: Vec<GradeBand>,
+    #[serde(default)]
+    pub default_grade: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct GradeMapping {
+    pub competition_id: String,
+    pub basis: GradeBasis,
+    pub bands: Vec<GradeBand>,
+    pub default_grade: f64,
+    pub updated_by: String,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = grade_mappings)]
+pub struct SqlGradeMapping {
+    pub competition_id: String,
+    pub basis: String,
+    pub bands: String,
+    pub default_grade: f64,
+    pub updated_by: String,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicGradeMapping {
+    pub competition_id: String,
+    pub basis: GradeBasis,
+    pub bands: Vec<GradeBand>,
+    pub default_grade: f64,
+    pub updated_by: String,
+    pub updated: NaiveDateTime,
+}
+
+/// The grade a student would get from the competition's current standings.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradePreview {
+    pub user_id: String,
+    pub username: String,
+    pub team_id: String,
+    pub team_name: String,
+    pub rank: usize,
+    pub points: i32,
+    pub grade: f64,
+    /// label of the band that gave the grade, empty for the default grade
+    pub band: String,
+}
+
+impl GradeMapping {
+    pub fn new(new_mapping: NewGradeMapping, updated_by: String) -> Self {
+        Self {
+            competition_id: new_mapping.competition_id,
+            basis: new_mapping.basis,
+            bands: new_mapping.bands,
+            default_grade: new_mapping.default_grade,
+            updated_by,
+            updated: Local::now().naive_utc(),
+        }
+    }
+
+    /// The band a standing falls into, `None` if it gets the default grade.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The team's rank, starting at 1.
+    /// * `points` - The team's league points.
+    /// * `team_count` - Number of ranked teams, for percentiles.
+    ///
+    pub fn band_for(&self, rank: usize, points: i32, team_count: usize) -> Option<&GradeBand> {
+        let percentile = if team_count == 0 { 100.0 } else { rank as f64 * 100.0 / team_count as f64 };
+        self.bands.iter().find(|band| match self.basis {
+            GradeBasis::Rank => rank as f64 <= band.threshold,
+            GradeBasis::Percentile => percentile <= band.threshold,
+            GradeBasis::Points => points as f64 >= band.threshold,
+        })
+    }
+
+    /// What's wrong with the mapping, `None` if it can be stored.
+    pub fn invalid_reason(&self) -> Option<String> {
+        if self.bands.is_empty() {
+            return Some("A grade mapping needs at least one band".to_string());
+        }
+        if self.bands.iter().any(|b| !b.threshold.is_finite() || !b.grade.is_finite()) || !self.default_grade.is_finite() {
+            return Some("Thresholds and grades have to be numbers".to_string());
+        }
+        match self.basis {
+            GradeBasis::Rank if self.bands.iter().any(|b| b.threshold < 1.0) => {
+                Some("Rank thresholds start at 1".to_string())
+            },
+            GradeBasis::Percentile if self.bands.iter().any(|b| b.threshold <= 0.0 || b.threshold > 100.0) => {
+                Some("Percentile thresholds are between 0 and 100".to_string())
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GradeBasis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GradeBasis::Rank => write!(f, "RANK"),
+            GradeBasis::Percentile => write!(f, "PERCENTILE"),
+            GradeBasis::Points => write!(f, "POINTS"),
+        }
+    }
+}
+
+impl From<&str> for GradeBasis {
+    fn from(basis: &str) -> Self {
+        match basis {
+            "PERCENTILE" => GradeBasis::Percentile,
+            "POINTS" => GradeBasis::Points,
+            _ => GradeBasis::Rank,
+        }
+    }
+}
+
+impl From<SqlGradeMapping> for GradeMapping {
+    fn from(sql_mapping: SqlGradeMapping) -> Self {
+        Self {
+            competition_id: sql_mapping.competition_id,
+            basis: GradeBasis::from(sql_mapping.basis.as_str()),
+            bands: serde_json::from_str(&sql_mapping.bands).unwrap_or_default(),
+            default_grade: sql_mapping.default_grade,
+            updated_by: sql_mapping.updated_by,
+            updated: sql_mapping.updated,
+        }
+    }
+}
+
+impl From<GradeMapping> for SqlGradeMapping {
+    fn from(mapping: GradeMapping) -> Self {
+        Self {
+            competition_id: mapping.competition_id,
+            basis: mapping.basis.to_string(),
+            bands: serde_json::to_string(&mapping.bands).unwrap_or_else(|_| "[]".to_string()),
+            default_grade: mapping.default_grade,
+            updated_by: mapping.updated_by,
+            updated: mapping.updated,
+        }
+    }
+}
+
+impl From<GradeMapping> for PublicGradeMapping {
+    fn from(mapping: GradeMapping) -> Self {
+        Self {
+            competition_id: mapping.competition_id,
+            basis: mapping.basis,
+            bands: mapping.bands,
+            default_grade: mapping.default_grade,
+            updated_by: mapping.updated_by,
+            updated: mapping.updated,
+        }
+    }
+}
\ No newline at end of file
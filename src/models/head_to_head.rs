@@ -0,0 +1,17 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::game_2v2::PublicGame2v2;
+
+/// Record of team `a` against team `b`, all numbers are from the perspective of team `a`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HeadToHead {
+    pub team_a: String,
+    pub team_b: String,
+    pub games_played: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub avg_score_margin: f64,
+    pub games: Vec<PublicGame2v2>,
+}
\ No newline at end of file
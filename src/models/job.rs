@@ -0,0 +1,162 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::jobs::{self};
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub enum JobKind {
+    /// runs the next round of a competition
+    Round,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Done,
+    Failed,
+}
+
+#[derive(Debug)]
+pub struct NewJob {
+    pub kind: JobKind,
+    pub competition_id: String,
+    pub trace_id: String,
+}
+
+#[derive(Debug)]
+pub struct Job {
+    pub id: String,
+    pub kind: JobKind,
+    pub competition_id: String,
+    pub status: JobStatus,
+    pub progress: i32,
+    pub log: String,
+    pub trace_id: String,
+    pub created: NaiveDateTime,
+    pub started: Option<NaiveDateTime>,
+    pub finished: Option<NaiveDateTime>,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = jobs)]
+pub struct SqlJob {
+    pub id: String,
+    pub kind: String,
+    pub competition_id: String,
+    pub status: String,
+    pub progress: i32,
+    pub log: String,
+    pub trace_id: String,
+    pub created: NaiveDateTime,
+    pub started: Option<NaiveDateTime>,
+    pub finished: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicJob {
+    pub id: String,
+    pub kind: JobKind,
+    pub competition_id: String,
+    pub status: JobStatus,
+    /// percentage of the job's work that is done
+    pub progress: i32,
+    pub log: Vec<String>,
+    /// the trace the job's round is recorded under, see `/trace/{trace_id}`
+    pub trace_id: String,
+    pub created: NaiveDateTime,
+    pub started: Option<NaiveDateTime>,
+    pub finished: Option<NaiveDateTime>,
+}
+
+impl fmt::Display for JobKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JobKind::Round => write!(f, "ROUND"),
+        }
+    }
+}
+
+impl From<&str> for JobKind {
+    fn from(_kind: &str) -> Self {
+        // rounds are the only kind of job so far
+        JobKind::Round
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JobStatus::Queued => write!(f, "QUEUED"),
+            JobStatus::Running => write!(f, "RUNNING"),
+            JobStatus::Done => write!(f, "DONE"),
+            JobStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+impl From<&str> for JobStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "RUNNING" => JobStatus::Running,
+            "DONE" => JobStatus::Done,
+            "FAILED" => JobStatus::Failed,
+            _ => JobStatus::Queued,
+        }
+    }
+}
+
+impl From<SqlJob> for Job {
+    fn from(sql_job: SqlJob) -> Self {
+        Self {
+            id: sql_job.id,
+            kind: JobKind::from(sql_job.kind.as_str()),
+            competition_id: sql_job.competition_id,
+            status: JobStatus::from(sql_job.status.as_str()),
+            progress: sql_job.progress,
+            log: sql_job.log,
+            trace_id: sql_job.trace_id,
+            created: sql_job.created,
+            started: sql_job.started,
+            finished: sql_job.finished,
+        }
+    }
+}
+
+impl From<Job> for PublicJob {
+    fn from(job: Job) -> Self {
+        Self {
+            id: job.id,
+            kind: job.kind,
+            competition_id: job.competition_id,
+            status: job.status,
+            progress: job.progress,
+            log: job.log.lines().map(str::to_string).collect(),
+            trace_id: job.trace_id,
+            created: job.created,
+            started: job.started,
+            finished: job.finished,
+        }
+    }
+}
+
+impl From<NewJob> for SqlJob {
+    fn from(new_job: NewJob) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            kind: new_job.kind.to_string(),
+            competition_id: new_job.competition_id,
+            status: JobStatus::Queued.to_string(),
+            progress: 0,
+            log: "".to_string(),
+            trace_id: new_job.trace_id,
+            created: Local::now().naive_utc(),
+            started: None,
+            finished: None,
+        }
+    }
+}
\ No newline at end of file
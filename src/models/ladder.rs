@@ -0,0 +1,159 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::{ladder_bots, ladder_games};
+
+/// A staff bot of the competition's ladder, made from an existing bot. `rating` is fixed, it
+/// anchors the ladder ratings of the teams that play against it.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewLadderBot {
+    pub competition_id: String,
+    pub bot_id: String,
+    pub name: String,
+    pub rating: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LadderBot {
+    pub id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub rating: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = ladder_bots)]
+pub struct SqlLadderBot {
+    pub id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub rating: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicLadderBot {
+    pub id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub rating: i32,
+    pub created: NaiveDateTime,
+}
+
+/// A ladder game of a team against a staff bot, `rating` is the team's ladder rating after it.
+#[derive(Debug)]
+pub struct NewLadderGame {
+    pub competition_id: String,
+    pub team_id: String,
+    pub ladder_bot_id: String,
+    pub won: bool,
+    pub team_score: i32,
+    pub bot_score: i32,
+    pub rating: i32,
+    pub rating_change: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LadderGame {
+    pub team_id: String,
+    pub won: bool,
+    pub rating: i32,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = ladder_games)]
+pub struct SqlLadderGame {
+    pub id: String,
+    pub competition_id: String,
+    pub team_id: String,
+    pub ladder_bot_id: String,
+    pub won: bool,
+    pub team_score: i32,
+    pub bot_score: i32,
+    pub rating: i32,
+    pub rating_change: i32,
+    pub created: NaiveDateTime,
+}
+
+/// A team's place on the ladder.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LadderStanding {
+    pub team_id: String,
+    pub team_name: String,
+    pub rating: i32,
+    pub games: i32,
+    pub wins: i32,
+}
+
+/// The staff bots of a competition's ladder and the teams ranked by their ladder rating.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LadderOverview {
+    pub bots: Vec<PublicLadderBot>,
+    pub standings: Vec<LadderStanding>,
+}
+
+impl From<SqlLadderBot> for LadderBot {
+    fn from(sql_ladder_bot: SqlLadderBot) -> Self {
+        Self {
+            id: sql_ladder_bot.id,
+            competition_id: sql_ladder_bot.competition_id,
+            name: sql_ladder_bot.name,
+            rating: sql_ladder_bot.rating,
+            created: sql_ladder_bot.created,
+        }
+    }
+}
+
+impl From<LadderBot> for PublicLadderBot {
+    fn from(ladder_bot: LadderBot) -> Self {
+        Self {
+            id: ladder_bot.id,
+            competition_id: ladder_bot.competition_id,
+            name: ladder_bot.name,
+            rating: ladder_bot.rating,
+            created: ladder_bot.created,
+        }
+    }
+}
+
+impl From<NewLadderBot> for SqlLadderBot {
+    fn from(new_ladder_bot: NewLadderBot) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_ladder_bot.competition_id,
+            name: new_ladder_bot.name,
+            rating: new_ladder_bot.rating,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlLadderGame> for LadderGame {
+    fn from(sql_ladder_game: SqlLadderGame) -> Self {
+        Self {
+            team_id: sql_ladder_game.team_id,
+            won: sql_ladder_game.won,
+            rating: sql_ladder_game.rating,
+        }
+    }
+}
+
+impl From<NewLadderGame> for SqlLadderGame {
+    fn from(new_ladder_game: NewLadderGame) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_ladder_game.competition_id,
+            team_id: new_ladder_game.team_id,
+            ladder_bot_id: new_ladder_game.ladder_bot_id,
+            won: new_ladder_game.won,
+            team_score: new_ladder_game.team_score,
+            bot_score: new_ladder_game.bot_score,
+            rating: new_ladder_game.rating,
+            rating_change: new_ladder_game.rating_change,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
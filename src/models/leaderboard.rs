@@ -0,0 +1,98 @@
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::competition::ScoringSystem;
+
+pub const DEFAULT_LEADERBOARD_PER_PAGE: usize = 25;
+pub const MAX_LEADERBOARD_PER_PAGE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LeaderboardSort {
+    Rank,
+    Elo,
+    Points,
+    Wins,
+    Losses,
+    GamesPlayed,
+    WinRate,
+    Streak,
+    Upset,
+    Name,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LeaderboardQuery {
+    pub sort: Option<String>,
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+}
+
+/// Standing of a single team. `current_streak` is positive for consecutive wins and
+/// negative for consecutive losses, a draw ends any streak. `points` are the league points
+/// according to the competition's point settings, also when it is ranked by ELO.
+/// `games_played` includes byes and forfeits, a forfeit also counts as a loss.
+/// `upset_score` is the ELO the strongest team it beat had before that round, `upset_team_id`
+/// that team. `rank_change` is how many places the team moved up compared to the standings
+/// before the last round, `null` if it wasn't ranked then.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub rank_change: Option<i32>,
+    pub team_id: String,
+    pub team_name: String,
+    pub elo: i32,
+    pub points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub byes: i32,
+    pub forfeits: i32,
+    pub games_played: i32,
+    pub score_difference: i32,
+    pub current_streak: i32,
+    pub longest_win_streak: i32,
+    pub upset_score: i32,
+    pub upset_team_id: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaderboardPage {
+    pub competition_id: String,
+    pub scoring_system: ScoringSystem,
+    pub page: usize,
+    pub per_page: usize,
+    pub total_teams: usize,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl LeaderboardEntry {
+    /// Share of games won, byes don't count as games here.
+    pub fn win_rate(&self) -> f64 {
+        let games = self.games_played - self.byes;
+        if games == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / games as f64
+    }
+}
+
+impl TryFrom<&str> for LeaderboardSort {
+    type Error = String;
+
+    fn try_from(sort: &str) -> Result<Self, Self::Error> {
+        match sort {
+            "rank" => Ok(LeaderboardSort::Rank),
+            "elo" => Ok(LeaderboardSort::Elo),
+            "points" => Ok(LeaderboardSort::Points),
+            "wins" => Ok(LeaderboardSort::Wins),
+            "losses" => Ok(LeaderboardSort::Losses),
+            "games_played" => Ok(LeaderboardSort::GamesPlayed),
+            "win_rate" => Ok(LeaderboardSort::WinRate),
+            "streak" => Ok(LeaderboardSort::Streak),
+            "upset" => Ok(LeaderboardSort::Upset),
+            "name" => Ok(LeaderboardSort::Name),
+            _ => Err(format!("Unknown sort: {}", sort)),
+        }
+    }
+}
\ No newline at end of file
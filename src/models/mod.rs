@@ -4,4 +4,56 @@ pub mod team;
 pub mod competition;
 pub mod bot;
 pub mod game_2v2;
-pub mod game_player_stats;
\ No newline at end of file
+pub mod game_player_stats;
+pub mod round_hook;
+pub mod round_event;
+pub mod participation;
+pub mod elo_history;
+pub mod round_leniency;
+pub mod leaderboard;
+pub mod team_stats;
+pub mod trace_span;
+pub mod head_to_head;
+pub mod practice_bot;
+pub mod round_stats;
+pub mod validation_rules;
+pub mod bot_violation;
+pub mod rematch;
+pub mod scouting;
+pub mod upload_validation;
+pub mod reference_match;
+pub mod bot_version;
+pub mod compile_diagnostics;
+pub mod plagiarism;
+pub mod season;
+pub mod job;
+pub mod remote_game;
+pub mod round_checkpoint;
+pub mod webhook;
+pub mod discord_channel;
+pub mod rate_limit;
+pub mod replay_frame;
+pub mod featured_game;
+pub mod api_key;
+pub mod game_listing;
+pub mod rating_recompute;
+pub mod audit_log;
+pub mod game_override;
+pub mod round_bye;
+pub mod pairing_constraints;
+pub mod round;
+pub mod crash_report;
+pub mod ladder;
+pub mod practice_queue;
+pub mod challenge;
+pub mod achievement;
+pub mod team_streak;
+pub mod standings_history;
+pub mod grade_mapping;
+pub mod search;
+pub mod user_data;
+pub mod published_bot;
+pub mod ghost_team;
+pub mod submission_lock;
+pub mod smoke_test;
+pub mod turn_time_report;
\ No newline at end of file
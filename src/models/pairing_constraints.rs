@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::pairing_constraints::{self};
+
+/// How strongly the pairing of a competition's rounds avoids certain matches. Each pair of
+/// teams costs `rematch_weight` for every game they played against each other in the last
+/// `rematch_window` rounds, including the current one, and `lab_group_weight` if members of
+/// both teams share a lab group. Opponents with the lowest cost are preferred, a weight of
+/// `0` turns the constraint off.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewPairingConstraints {
+    pub competition_id: String,
+    pub rematch_window: Option<i32>,
+    pub rematch_weight: Option<i32>,
+    pub lab_group_weight: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PairingConstraints {
+    pub id: String,
+    pub competition_id: String,
+    pub rematch_window: i32,
+    pub rematch_weight: i32,
+    pub lab_group_weight: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = pairing_constraints)]
+pub struct SqlPairingConstraints {
+    pub id: String,
+    pub competition_id: String,
+    pub rematch_window: i32,
+    pub rematch_weight: i32,
+    pub lab_group_weight: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicPairingConstraints {
+    pub id: String,
+    pub competition_id: String,
+    pub rematch_window: i32,
+    pub rematch_weight: i32,
+    pub lab_group_weight: i32,
+    pub updated: NaiveDateTime,
+}
+
+/// What pairing two teams costs under a competition's pairing constraints, see
+/// `PairingConstraints`. Without constraints every pairing costs nothing. Stored with each
+/// round, so its pairing can be reproduced. Pairing a team against itself costs `i32::MAX`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PairingCosts {
+    pub rematch_weight: i32,
+    pub lab_group_weight: i32,
+    /// games per pair of teams in the rematch window, keyed by both team ids, the smaller first
+    pub recent_games: HashMap<String, i32>,
+    /// lab groups of each team's members
+    pub lab_groups: HashMap<String, HashSet<String>>,
+}
+
+impl PairingCosts {
+    pub fn cost(&self, team1_id: &str, team2_id: &str) -> i32 {
+        // a team only plays itself if no other opponent is left
+        if team1_id == team2_id {
+            return i32::MAX;
+        }
+        let mut cost = self.rematch_weight * self.recent_games.get(&pair_key(team1_id, team2_id)).copied().unwrap_or(0);
+        if let (Some(groups1), Some(groups2)) = (self.lab_groups.get(team1_id), self.lab_groups.get(team2_id)) {
+            if !groups1.is_disjoint(groups2) {
+                cost += self.lab_group_weight;
+            }
+        }
+        cost
+    }
+
+    /// Counts a pairing made for the current round, so it isn't repeated within the round.
+    pub fn record_pairing(&mut self, team1_id: &str, team2_id: &str) {
+        if self.rematch_weight > 0 {
+            *self.recent_games.entry(pair_key(team1_id, team2_id)).or_insert(0) += 1;
+        }
+    }
+}
+
+impl From<NewPairingConstraints> for SqlPairingConstraints {
+    fn from(new_constraints: NewPairingConstraints) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_constraints.competition_id,
+            rematch_window: new_constraints.rematch_window.unwrap_or(0),
+            rematch_weight: new_constraints.rematch_weight.unwrap_or(0),
+            lab_group_weight: new_constraints.lab_group_weight.unwrap_or(0),
+            updated: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlPairingConstraints> for PairingConstraints {
+    fn from(sql_constraints: SqlPairingConstraints) -> Self {
+        Self {
+            id: sql_constraints.id,
+            competition_id: sql_constraints.competition_id,
+            rematch_window: sql_constraints.rematch_window,
+            rematch_weight: sql_constraints.rematch_weight,
+            lab_group_weight: sql_constraints.lab_group_weight,
+            updated: sql_constraints.updated,
+        }
+    }
+}
+
+impl From<PairingConstraints> for PublicPairingConstraints {
+    fn from(constraints: PairingConstraints) -> Self {
+        Self {
+            id: constraints.id,
+            competition_id: constraints.competition_id,
+            rematch_window: constraints.rematch_window,
+            rematch_weight: constraints.rematch_weight,
+            lab_group_weight: constraints.lab_group_weight,
+            updated: constraints.updated,
+        }
+    }
+}
+
+/// Key of a pair of teams, the same for both orders.
+pub fn pair_key(team1_id: &str, team2_id: &str) -> String {
+    if team1_id <= team2_id {
+        format!("{}|{}", team1_id, team2_id)
+    } else {
+        format!("{}|{}", team2_id, team1_id)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::participations::{self};
+
+#[derive(Debug)]
+pub struct NewParticipation {
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub owner: String,
+    pub partner: String,
+    pub submitted_working_bot: bool,
+    pub games_played: i32,
+}
+
+#[derive(Debug)]
+pub struct Participation {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub owner: String,
+    pub partner: String,
+    pub submitted_working_bot: bool,
+    pub games_played: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = participations)]
+pub struct SqlParticipation {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub owner: String,
+    pub partner: String,
+    pub submitted_working_bot: bool,
+    pub games_played: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicParticipation {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub submitted_working_bot: bool,
+    pub games_played: i32,
+    pub created: NaiveDateTime,
+}
+
+/// Participation of a single student over all played rounds of a competition.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct StudentParticipation {
+    pub user_id: String,
+    pub username: String,
+    pub rounds_total: i32,
+    pub rounds_with_working_bot: i32,
+    pub rounds_played: i32,
+    pub games_played: i32,
+}
+
+impl From<SqlParticipation> for Participation {
+    fn from(sql_participation: SqlParticipation) -> Self {
+        Self {
+            id: sql_participation.id,
+            competition_id: sql_participation.competition_id,
+            round: sql_participation.round,
+            team_id: sql_participation.team_id,
+            owner: sql_participation.owner,
+            partner: sql_participation.partner,
+            submitted_working_bot: sql_participation.submitted_working_bot,
+            games_played: sql_participation.games_played,
+            created: sql_participation.created,
+        }
+    }
+}
+
+impl From<Participation> for PublicParticipation {
+    fn from(participation: Participation) -> Self {
+        Self {
+            id: participation.id,
+            competition_id: participation.competition_id,
+            round: participation.round,
+            team_id: participation.team_id,
+            submitted_working_bot: participation.submitted_working_bot,
+            games_played: participation.games_played,
+            created: participation.created,
+        }
+    }
+}
+
+impl From<NewParticipation> for SqlParticipation {
+    fn from(new_participation: NewParticipation) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_participation.competition_id,
+            round: new_participation.round,
+            team_id: new_participation.team_id,
+            owner: new_participation.owner,
+            partner: new_participation.partner,
+            submitted_working_bot: new_participation.submitted_working_bot,
+            games_played: new_participation.games_played,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
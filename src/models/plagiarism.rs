@@ -0,0 +1,187 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::{plagiarism_pairs, plagiarism_reports};
+
+/// Share of fingerprints two bots have to have in common to be reported.
+pub const DEFAULT_PLAGIARISM_THRESHOLD: f64 = 0.6;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PlagiarismRequest {
+    pub competition_id: String,
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct NewPlagiarismReport {
+    pub competition_id: String,
+    pub bots_compared: i32,
+    pub threshold: f64,
+}
+
+#[derive(Debug)]
+pub struct PlagiarismReport {
+    pub id: String,
+    pub competition_id: String,
+    pub bots_compared: i32,
+    pub pairs_flagged: i32,
+    pub threshold: f64,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = plagiarism_reports)]
+pub struct SqlPlagiarismReport {
+    pub id: String,
+    pub competition_id: String,
+    pub bots_compared: i32,
+    pub pairs_flagged: i32,
+    pub threshold: f64,
+    pub created: NaiveDateTime,
+}
+
+/// The most similar pair of bots of two different teams.
+#[derive(Debug, Clone)]
+pub struct NewPlagiarismPair {
+    pub team1_id: String,
+    pub bot1_id: String,
+    pub team2_id: String,
+    pub bot2_id: String,
+    pub similarity: f64,
+    pub shared_fingerprints: i32,
+}
+
+#[derive(Debug)]
+pub struct PlagiarismPair {
+    pub id: String,
+    pub report_id: String,
+    pub team1_id: String,
+    pub bot1_id: String,
+    pub team2_id: String,
+    pub bot2_id: String,
+    pub similarity: f64,
+    pub shared_fingerprints: i32,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = plagiarism_pairs)]
+pub struct SqlPlagiarismPair {
+    pub id: String,
+    pub report_id: String,
+    pub team1_id: String,
+    pub bot1_id: String,
+    pub team2_id: String,
+    pub bot2_id: String,
+    pub similarity: f64,
+    pub shared_fingerprints: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicPlagiarismPair {
+    pub team1_id: String,
+    pub team1_name: String,
+    pub bot1_id: String,
+    pub team2_id: String,
+    pub team2_name: String,
+    pub bot2_id: String,
+    pub similarity: f64,
+    pub shared_fingerprints: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicPlagiarismReport {
+    pub id: String,
+    pub competition_id: String,
+    pub bots_compared: i32,
+    pub pairs_flagged: i32,
+    pub threshold: f64,
+    pub created: NaiveDateTime,
+    pub pairs: Vec<PublicPlagiarismPair>,
+}
+
+impl PublicPlagiarismPair {
+    pub fn new(pair: PlagiarismPair, team1_name: String, team2_name: String) -> Self {
+        Self {
+            team1_id: pair.team1_id,
+            team1_name,
+            bot1_id: pair.bot1_id,
+            team2_id: pair.team2_id,
+            team2_name,
+            bot2_id: pair.bot2_id,
+            similarity: pair.similarity,
+            shared_fingerprints: pair.shared_fingerprints,
+        }
+    }
+}
+
+impl PublicPlagiarismReport {
+    pub fn new(report: PlagiarismReport, pairs: Vec<PublicPlagiarismPair>) -> Self {
+        Self {
+            id: report.id,
+            competition_id: report.competition_id,
+            bots_compared: report.bots_compared,
+            pairs_flagged: report.pairs_flagged,
+            threshold: report.threshold,
+            created: report.created,
+            pairs,
+        }
+    }
+}
+
+impl SqlPlagiarismReport {
+    pub fn new(report: NewPlagiarismReport, pairs_flagged: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: report.competition_id,
+            bots_compared: report.bots_compared,
+            pairs_flagged,
+            threshold: report.threshold,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl SqlPlagiarismPair {
+    pub fn new(pair: NewPlagiarismPair, report_id: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            report_id,
+            team1_id: pair.team1_id,
+            bot1_id: pair.bot1_id,
+            team2_id: pair.team2_id,
+            bot2_id: pair.bot2_id,
+            similarity: pair.similarity,
+            shared_fingerprints: pair.shared_fingerprints,
+        }
+    }
+}
+
+impl From<SqlPlagiarismReport> for PlagiarismReport {
+    fn from(sql_report: SqlPlagiarismReport) -> Self {
+        Self {
+            id: sql_report.id,
+            competition_id: sql_report.competition_id,
+            bots_compared: sql_report.bots_compared,
+            pairs_flagged: sql_report.pairs_flagged,
+            threshold: sql_report.threshold,
+            created: sql_report.created,
+        }
+    }
+}
+
+impl From<SqlPlagiarismPair> for PlagiarismPair {
+    fn from(sql_pair: SqlPlagiarismPair) -> Self {
+        Self {
+            id: sql_pair.id,
+            report_id: sql_pair.report_id,
+            team1_id: sql_pair.team1_id,
+            bot1_id: sql_pair.bot1_id,
+            team2_id: sql_pair.team2_id,
+            bot2_id: sql_pair.bot2_id,
+            similarity: sql_pair.similarity,
+            shared_fingerprints: sql_pair.shared_fingerprints,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::practice_bots::{self};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewPracticeBot {
+    pub bot_id: String,
+    pub name: String,
+    pub anonymous: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PracticeBot {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub anonymous: bool,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = practice_bots)]
+pub struct SqlPracticeBot {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub anonymous: bool,
+    pub created: NaiveDateTime,
+}
+
+/// Listing entry of a practice bot. Neither the published bot nor its files are exposed,
+/// and the publishing team is only named if it chose attribution.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicPracticeBot {
+    pub id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub created: NaiveDateTime,
+}
+
+/// Outcome of an unranked test match of a team against a practice bot.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PracticeMatchResult {
+    pub practice_bot_id: String,
+    pub won: bool,
+    pub team_score: i32,
+    pub opponent_score: i32,
+    pub bot1_survived: bool,
+    pub bot2_survived: bool,
+    pub error: String,
+}
+
+impl From<SqlPracticeBot> for PracticeBot {
+    fn from(sql_practice_bot: SqlPracticeBot) -> Self {
+        Self {
+            id: sql_practice_bot.id,
+            bot_id: sql_practice_bot.bot_id,
+            team_id: sql_practice_bot.team_id,
+            competition_id: sql_practice_bot.competition_id,
+            name: sql_practice_bot.name,
+            anonymous: sql_practice_bot.anonymous,
+            created: sql_practice_bot.created,
+        }
+    }
+}
+
+impl PublicPracticeBot {
+    pub fn new(practice_bot: PracticeBot, team_name: Option<String>) -> Self {
+        Self {
+            id: practice_bot.id,
+            competition_id: practice_bot.competition_id,
+            name: practice_bot.name,
+            author: if practice_bot.anonymous { None } else { team_name },
+            created: practice_bot.created,
+        }
+    }
+}
+
+impl SqlPracticeBot {
+    pub fn new(new_practice_bot: NewPracticeBot, team_id: String, competition_id: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            bot_id: new_practice_bot.bot_id,
+            team_id,
+            competition_id,
+            name: new_practice_bot.name,
+            anonymous: new_practice_bot.anonymous,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
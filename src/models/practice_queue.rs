@@ -0,0 +1,182 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::{practice_games, practice_queue};
+
+/// A team waiting for an unranked practice game against another queued team.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewPracticeQueueEntry {
+    pub team_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PracticeQueueEntry {
+    pub id: String,
+    pub competition_id: String,
+    pub team_id: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = practice_queue)]
+pub struct SqlPracticeQueueEntry {
+    pub id: String,
+    pub competition_id: String,
+    pub team_id: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicPracticeQueueEntry {
+    pub id: String,
+    pub competition_id: String,
+    pub team_id: String,
+    pub created: NaiveDateTime,
+}
+
+/// The result of a practice game between two queued teams. `error` is empty unless the game
+/// didn't produce a result.
+#[derive(Debug)]
+pub struct NewPracticeGame {
+    pub competition_id: String,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub winner_id: String,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub error: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PracticeGame {
+    pub id: String,
+    pub competition_id: String,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub winner_id: String,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub error: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = practice_games)]
+pub struct SqlPracticeGame {
+    pub id: String,
+    pub competition_id: String,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub winner_id: String,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub error: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicPracticeGame {
+    pub id: String,
+    pub competition_id: String,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub winner_id: String,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub error: String,
+    pub created: NaiveDateTime,
+}
+
+impl PracticeQueueEntry {
+    pub fn new(competition_id: String, team_id: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id,
+            team_id,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlPracticeQueueEntry> for PracticeQueueEntry {
+    fn from(sql_entry: SqlPracticeQueueEntry) -> Self {
+        Self {
+            id: sql_entry.id,
+            competition_id: sql_entry.competition_id,
+            team_id: sql_entry.team_id,
+            created: sql_entry.created,
+        }
+    }
+}
+
+impl From<PracticeQueueEntry> for SqlPracticeQueueEntry {
+    fn from(entry: PracticeQueueEntry) -> Self {
+        Self {
+            id: entry.id,
+            competition_id: entry.competition_id,
+            team_id: entry.team_id,
+            created: entry.created,
+        }
+    }
+}
+
+impl From<PracticeQueueEntry> for PublicPracticeQueueEntry {
+    fn from(entry: PracticeQueueEntry) -> Self {
+        Self {
+            id: entry.id,
+            competition_id: entry.competition_id,
+            team_id: entry.team_id,
+            created: entry.created,
+        }
+    }
+}
+
+impl From<NewPracticeGame> for SqlPracticeGame {
+    fn from(new_game: NewPracticeGame) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_game.competition_id,
+            team1_id: new_game.team1_id,
+            team2_id: new_game.team2_id,
+            winner_id: new_game.winner_id,
+            team1_score: new_game.team1_score,
+            team2_score: new_game.team2_score,
+            error: new_game.error,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlPracticeGame> for PracticeGame {
+    fn from(sql_game: SqlPracticeGame) -> Self {
+        Self {
+            id: sql_game.id,
+            competition_id: sql_game.competition_id,
+            team1_id: sql_game.team1_id,
+            team2_id: sql_game.team2_id,
+            winner_id: sql_game.winner_id,
+            team1_score: sql_game.team1_score,
+            team2_score: sql_game.team2_score,
+            error: sql_game.error,
+            created: sql_game.created,
+        }
+    }
+}
+
+impl From<PracticeGame> for PublicPracticeGame {
+    fn from(game: PracticeGame) -> Self {
+        Self {
+            id: game.id,
+            competition_id: game.competition_id,
+            team1_id: game.team1_id,
+            team2_id: game.team2_id,
+            winner_id: game.winner_id,
+            team1_score: game.team1_score,
+            team2_score: game.team2_score,
+            error: game.error,
+            created: game.created,
+        }
+    }
+}
\ No newline at end of file
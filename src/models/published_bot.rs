@@ -0,0 +1,107 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::published_bots::{self};
+use super::{bot::Bot, team::Team};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewPublishedBot {
+    pub bot_id: String,
+    pub name: String,
+    pub description: String,
+    /// leave the team's name out of the gallery
+    pub anonymous: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PublishedBot {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub description: String,
+    pub anonymous: bool,
+    pub archive_path: String,
+    pub final_elo: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = published_bots)]
+pub struct SqlPublishedBot {
+    pub id: String,
+    pub bot_id: String,
+    pub team_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub description: String,
+    pub anonymous: bool,
+    pub archive_path: String,
+    pub final_elo: i32,
+    pub created: NaiveDateTime,
+}
+
+/// Gallery entry of a published bot. The team is only named if it chose attribution, the
+/// archive is downloaded separately.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicPublishedBot {
+    pub id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub description: String,
+    pub author: Option<String>,
+    /// ELO of the team when the bot was published
+    pub final_elo: i32,
+    pub created: NaiveDateTime,
+}
+
+impl From<SqlPublishedBot> for PublishedBot {
+    fn from(sql_published_bot: SqlPublishedBot) -> Self {
+        Self {
+            id: sql_published_bot.id,
+            bot_id: sql_published_bot.bot_id,
+            team_id: sql_published_bot.team_id,
+            competition_id: sql_published_bot.competition_id,
+            name: sql_published_bot.name,
+            description: sql_published_bot.description,
+            anonymous: sql_published_bot.anonymous,
+            archive_path: sql_published_bot.archive_path,
+            final_elo: sql_published_bot.final_elo,
+            created: sql_published_bot.created,
+        }
+    }
+}
+
+impl PublicPublishedBot {
+    pub fn new(published_bot: PublishedBot, team_name: Option<String>) -> Self {
+        Self {
+            id: published_bot.id,
+            competition_id: published_bot.competition_id,
+            name: published_bot.name,
+            description: published_bot.description,
+            author: if published_bot.anonymous { None } else { team_name },
+            final_elo: published_bot.final_elo,
+            created: published_bot.created,
+        }
+    }
+}
+
+impl SqlPublishedBot {
+    pub fn new(new_published_bot: NewPublishedBot, bot: &Bot, team: &Team) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            bot_id: bot.id.clone(),
+            team_id: team.id.clone(),
+            competition_id: team.competition_id.clone(),
+            name: new_published_bot.name.trim().to_string(),
+            description: new_published_bot.description.trim().to_string(),
+            anonymous: new_published_bot.anonymous,
+            archive_path: bot.source_path.clone(),
+            final_elo: team.elo,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
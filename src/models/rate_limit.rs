@@ -0,0 +1,81 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::config::settings;
+use crate::db::schema::rate_limits::{self};
+
+/// Rate limits of a competition's participants, replacing the server's defaults. A limit
+/// of `0` disables it, a missing one keeps the server's default.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewRateLimit {
+    pub competition_id: String,
+    pub uploads_per_hour: Option<i32>,
+    pub requests_per_minute: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub id: String,
+    pub competition_id: String,
+    pub uploads_per_hour: i32,
+    pub requests_per_minute: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = rate_limits)]
+pub struct SqlRateLimit {
+    pub id: String,
+    pub competition_id: String,
+    pub uploads_per_hour: i32,
+    pub requests_per_minute: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicRateLimit {
+    pub id: String,
+    pub competition_id: String,
+    pub uploads_per_hour: i32,
+    pub requests_per_minute: i32,
+    pub updated: NaiveDateTime,
+}
+
+impl From<SqlRateLimit> for RateLimit {
+    fn from(sql_limit: SqlRateLimit) -> Self {
+        Self {
+            id: sql_limit.id,
+            competition_id: sql_limit.competition_id,
+            uploads_per_hour: sql_limit.uploads_per_hour,
+            requests_per_minute: sql_limit.requests_per_minute,
+            updated: sql_limit.updated,
+        }
+    }
+}
+
+impl From<RateLimit> for PublicRateLimit {
+    fn from(limit: RateLimit) -> Self {
+        Self {
+            id: limit.id,
+            competition_id: limit.competition_id,
+            uploads_per_hour: limit.uploads_per_hour,
+            requests_per_minute: limit.requests_per_minute,
+            updated: limit.updated,
+        }
+    }
+}
+
+impl From<NewRateLimit> for SqlRateLimit {
+    fn from(new_limit: NewRateLimit) -> Self {
+        let defaults = &settings().rate_limits;
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_limit.competition_id,
+            uploads_per_hour: new_limit.uploads_per_hour.unwrap_or(defaults.uploads_per_hour as i32),
+            requests_per_minute: new_limit.requests_per_minute.unwrap_or(defaults.requests_per_minute as i32),
+            updated: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
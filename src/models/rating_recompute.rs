@@ -0,0 +1,82 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use crate::db::schema::game_rating_shadows::{self};
+
+/// Recomputes a competition's ratings, the competition's own rating settings are used for
+/// anything that isn't given.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RatingRecomputeOptions {
+    pub competition_id: String,
+    pub k_factor: Option<i32>,
+    pub provisional_games: Option<i32>,
+    pub provisional_k_factor: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RatingRecomputeTarget {
+    pub competition_id: String,
+}
+
+/// ELO changes a recompute gave a game.
+#[derive(Debug, Clone)]
+pub struct GameRatingShadow {
+    pub game_id: String,
+    pub competition_id: String,
+    pub team1_elo: i32,
+    pub team2_elo: i32,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = game_rating_shadows)]
+pub struct SqlGameRatingShadow {
+    pub game_id: String,
+    pub competition_id: String,
+    pub team1_elo: i32,
+    pub team2_elo: i32,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct TeamRatingDiff {
+    pub team_id: String,
+    pub team_name: String,
+    pub elo: i32,
+    pub recomputed_elo: i32,
+    pub elo_difference: i32,
+    pub rating_mu: f64,
+    pub recomputed_rating_mu: f64,
+    pub rating_sigma: f64,
+    pub recomputed_rating_sigma: f64,
+}
+
+/// Current ratings next to the recomputed ones, the teams that changed the most first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RatingRecomputeReport {
+    pub competition_id: String,
+    pub games_replayed: usize,
+    pub teams_changed: usize,
+    pub max_elo_difference: i32,
+    pub teams: Vec<TeamRatingDiff>,
+}
+
+impl From<SqlGameRatingShadow> for GameRatingShadow {
+    fn from(sql_shadow: SqlGameRatingShadow) -> Self {
+        Self {
+            game_id: sql_shadow.game_id,
+            competition_id: sql_shadow.competition_id,
+            team1_elo: sql_shadow.team1_elo,
+            team2_elo: sql_shadow.team2_elo,
+        }
+    }
+}
+
+impl From<GameRatingShadow> for SqlGameRatingShadow {
+    fn from(shadow: GameRatingShadow) -> Self {
+        Self {
+            game_id: shadow.game_id,
+            competition_id: shadow.competition_id,
+            team1_elo: shadow.team1_elo,
+            team2_elo: shadow.team2_elo,
+        }
+    }
+}
\ No newline at end of file
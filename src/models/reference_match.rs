@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::game_player_stats::GamePlayerStats;
+
+/// Team id the reference bot plays under in test matches.
+pub const REFERENCE_TEAM_ID: &str = "reference";
+
+/// Result of a test match of a bot against the reference bot. The bot plays both slots of
+/// team 1 (yellow/green), the reference bot both slots of team 2.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReferenceMatchResult {
+    pub bot_id: String,
+    pub won: bool,
+    pub bot_score: i32,
+    pub reference_score: i32,
+    pub bot1_survived: bool,
+    pub bot2_survived: bool,
+    pub map_seed: i64,
+    pub duration_ms: i64,
+    pub error: String,
+    /// stats per slot (`team1bot1`, ...)
+    pub stats: HashMap<String, GamePlayerStats>,
+    pub replay: String,
+}
\ No newline at end of file
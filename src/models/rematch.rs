@@ -0,0 +1,21 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Outcome of replaying a game with the same bots on the same map.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RematchResult {
+    pub game_id: String,
+    pub map_seed: i64,
+    pub original_winner_id: String,
+    pub winner_id: String,
+    pub same_winner: bool,
+    pub team1_score: i32,
+    pub team2_score: i32,
+    pub team1bot1_survived: bool,
+    pub team1bot2_survived: bool,
+    pub team2bot1_survived: bool,
+    pub team2bot2_survived: bool,
+    pub error: String,
+    /// everything the game wrote to stderr, the bots' own error output included
+    pub stderr: Vec<String>,
+}
\ No newline at end of file
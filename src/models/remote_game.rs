@@ -0,0 +1,55 @@
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::models::{competition::Competition, game_2v2::NewGame2v2};
+
+/// A game handed to a match worker on another machine. The worker downloads the bots,
+/// resolves the evaluator the same way the server does and plays the game.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RemoteGame {
+    /// id of this attempt, a game that is reassigned keeps its id
+    pub id: String,
+    pub game_id: String,
+    pub game_pack: String,
+    pub evaluator_source: String,
+    pub evaluator_sha256: String,
+    /// bots in slot order (`team1bot1`, ...)
+    pub bot_ids: Vec<String>,
+    pub map_seed: i64,
+    pub timeout_secs: i32,
+    /// the game is killed once it prints nothing for this long, `0` waits for the timeout
+    #[serde(default)]
+    pub stall_timeout_secs: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RemoteGameLease {
+    pub worker_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RemoteGameResult {
+    pub assignment_id: String,
+    pub worker_id: String,
+    pub output: Vec<String>,
+    pub errors: Vec<String>,
+    /// set if the worker couldn't play the game, the game is given to another worker
+    pub error: Option<String>,
+}
+
+impl RemoteGame {
+    pub fn new(competition: &Competition, match_game: &NewGame2v2, bot_ids: Vec<String>, timeout_secs: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            game_id: match_game.id.clone(),
+            game_pack: competition.game_pack.clone(),
+            evaluator_source: competition.evaluator_source.clone(),
+            evaluator_sha256: competition.evaluator_sha256.clone(),
+            bot_ids,
+            map_seed: match_game.map_seed,
+            timeout_secs,
+            stall_timeout_secs: competition.stall_timeout_secs,
+        }
+    }
+}
\ No newline at end of file
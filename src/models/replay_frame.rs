@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+
+use super::bot::GameBot;
+
+/// Version of the decoded layout, cached frames of another version are decoded again.
+pub const REPLAY_FRAMES_VERSION: i32 = 1;
+
+/// A replay decoded for the visualizer, one frame per turn.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReplayFrames {
+    pub version: i32,
+    pub game_id: String,
+    /// output format the replay was written in, `v1` or `v2`
+    pub output_version: String,
+    /// the game's bots with their names, filled in when the frames are served
+    #[serde(default)]
+    pub players: Vec<GameBot>,
+    pub frames: Vec<ReplayFrame>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ReplayFrame {
+    pub turn: usize,
+    pub planets: Vec<PlanetState>,
+    pub fleets: Vec<FleetMovement>,
+    /// scores reported during the turn, keyed by color
+    pub scores: HashMap<String, i32>,
+    /// colors eliminated during the turn
+    pub eliminated: Vec<String>,
+}
+
+/// A planet at the end of a turn, `id` is its position in the turn's planet records.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlanetState {
+    pub id: usize,
+    pub x: f64,
+    pub y: f64,
+    pub size: f64,
+    pub fleet_size: i64,
+    pub color: String,
+}
+
+/// A fleet on its way, `source` and `destination` are planet ids when the game pack
+/// reports them.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FleetMovement {
+    pub size: i64,
+    pub x: f64,
+    pub y: f64,
+    pub source: Option<usize>,
+    pub destination: Option<usize>,
+    pub color: String,
+}
\ No newline at end of file
@@ -0,0 +1,194 @@
+use std::{collections::HashMap, fmt};
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::rounds::{self};
+
+use super::{pairing_constraints::PairingCosts, round_stats::PublicRoundStats};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum RoundStatus {
+    Running,
+    /// the round's games and ratings were stored
+    Finished,
+    Failed,
+    /// stopped by a shutdown, the round continues when it's run again
+    Interrupted,
+}
+
+/// Everything the pairing of a round was based on and what it came up with.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct PairingMetadata {
+    pub games_per_round: i32,
+    /// teams in the order they were paired from
+    pub team_ids: Vec<String>,
+    pub color_balance: HashMap<String, i32>,
+    #[schema(value_type = Object)]
+    pub costs: PairingCosts,
+    #[schema(value_type = Vec<Vec<String>>)]
+    pub pairs: Vec<(String, String)>,
+    pub byes: Vec<String>,
+}
+
+/// A round that is starting, it's paired from `seed`. Bots count for it as they were at the
+/// submission cutoff of `scheduled_at`, the full hour it was scheduled for.
+#[derive(Debug)]
+pub struct NewRound {
+    pub competition_id: String,
+    pub round: i32,
+    pub seed: i64,
+    pub scheduled_at: NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub seed: i64,
+    pub metadata: PairingMetadata,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub status: RoundStatus,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub scheduled_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = rounds)]
+pub struct SqlRound {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub seed: i64,
+    pub metadata: String,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub status: String,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub scheduled_at: NaiveDateTime,
+}
+
+/// When a round ran and how it ended. `games_played` and `failed_matches` are counted when the
+/// round finishes.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicRound {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub seed: i64,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub status: RoundStatus,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub scheduled_at: NaiveDateTime,
+}
+
+/// A round together with the statistics of its games, if it finished.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoundDetails {
+    pub round: PublicRound,
+    pub stats: Option<PublicRoundStats>,
+}
+
+/// Summary of a finished round for instructors. `round` is missing for rounds played before
+/// rounds were recorded.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoundReport {
+    pub round: Option<PublicRound>,
+    pub stats: PublicRoundStats,
+    /// teams that sat out the round because their bots didn't compile
+    pub forfeited_team_ids: Vec<String>,
+    /// teams that were left over without an opponent
+    pub bye_team_ids: Vec<String>,
+}
+
+/// A round's stored pairing, `reproduced` if pairing it again from its seed and metadata
+/// gives the same pairs and byes.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoundPairingAudit {
+    pub round: PublicRound,
+    pub metadata: PairingMetadata,
+    pub reproduced: bool,
+}
+
+impl fmt::Display for RoundStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoundStatus::Running => write!(f, "RUNNING"),
+            RoundStatus::Finished => write!(f, "FINISHED"),
+            RoundStatus::Failed => write!(f, "FAILED"),
+            RoundStatus::Interrupted => write!(f, "INTERRUPTED"),
+        }
+    }
+}
+
+impl From<&str> for RoundStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "FINISHED" => RoundStatus::Finished,
+            "FAILED" => RoundStatus::Failed,
+            "INTERRUPTED" => RoundStatus::Interrupted,
+            _ => RoundStatus::Running,
+        }
+    }
+}
+
+impl From<SqlRound> for Round {
+    fn from(sql_round: SqlRound) -> Self {
+        Self {
+            id: sql_round.id,
+            competition_id: sql_round.competition_id,
+            round: sql_round.round,
+            seed: sql_round.seed,
+            metadata: serde_json::from_str(&sql_round.metadata).unwrap_or_default(),
+            started_at: sql_round.started_at,
+            finished_at: sql_round.finished_at,
+            status: RoundStatus::from(sql_round.status.as_str()),
+            games_played: sql_round.games_played,
+            failed_matches: sql_round.failed_matches,
+            scheduled_at: sql_round.scheduled_at,
+        }
+    }
+}
+
+impl From<Round> for PublicRound {
+    fn from(round: Round) -> Self {
+        Self {
+            id: round.id,
+            competition_id: round.competition_id,
+            round: round.round,
+            seed: round.seed,
+            started_at: round.started_at,
+            finished_at: round.finished_at,
+            status: round.status,
+            games_played: round.games_played,
+            failed_matches: round.failed_matches,
+            scheduled_at: round.scheduled_at,
+        }
+    }
+}
+
+impl From<NewRound> for SqlRound {
+    fn from(new_round: NewRound) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_round.competition_id,
+            round: new_round.round,
+            seed: new_round.seed,
+            metadata: serde_json::to_string(&PairingMetadata::default()).unwrap_or_default(),
+            started_at: Local::now().naive_utc(),
+            finished_at: None,
+            status: RoundStatus::Running.to_string(),
+            games_played: 0,
+            failed_matches: 0,
+            scheduled_at: new_round.scheduled_at,
+        }
+    }
+}
\ No newline at end of file
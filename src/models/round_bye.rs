@@ -0,0 +1,79 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_byes::{self};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ByeKind {
+    /// the team was left over when pairing the round
+    Bye,
+    /// none of the team's bots compiled
+    Forfeit,
+}
+
+#[derive(Debug)]
+pub struct NewRoundBye {
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub kind: ByeKind,
+}
+
+/// A round a team sat out instead of playing a game.
+#[derive(Debug)]
+pub struct RoundBye {
+    pub round: i32,
+    pub team_id: String,
+    pub kind: String,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_byes)]
+pub struct SqlRoundBye {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub kind: String,
+    pub created: NaiveDateTime,
+}
+
+impl fmt::Display for ByeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ByeKind::Bye => write!(f, "BYE"),
+            ByeKind::Forfeit => write!(f, "FORFEIT"),
+        }
+    }
+}
+
+impl RoundBye {
+    pub fn is_forfeit(&self) -> bool {
+        self.kind == ByeKind::Forfeit.to_string()
+    }
+}
+
+impl From<SqlRoundBye> for RoundBye {
+    fn from(sql_bye: SqlRoundBye) -> Self {
+        Self {
+            round: sql_bye.round,
+            team_id: sql_bye.team_id,
+            kind: sql_bye.kind,
+        }
+    }
+}
+
+impl From<NewRoundBye> for SqlRoundBye {
+    fn from(new_bye: NewRoundBye) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_bye.competition_id,
+            round: new_bye.round,
+            team_id: new_bye.team_id,
+            kind: new_bye.kind.to_string(),
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use diesel::prelude::{Insertable, Queryable};
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_checkpoints::{self};
+use crate::models::game_2v2::PendingGame2v2;
+
+/// A match of a round that was interrupted by a shutdown. `game` is set if the match was
+/// already played, otherwise the match still has to be played when the round is resumed.
+#[derive(Debug)]
+pub struct NewRoundCheckpoint {
+    pub competition_id: String,
+    pub round: i32,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub game: Option<PendingGame2v2>,
+}
+
+#[derive(Debug)]
+pub struct RoundCheckpoint {
+    pub team1_id: String,
+    pub team2_id: String,
+    pub game: Option<PendingGame2v2>,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_checkpoints)]
+pub struct SqlRoundCheckpoint {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team1_id: String,
+    pub team2_id: String,
+    pub game: String,
+    pub created: NaiveDateTime,
+}
+
+impl From<SqlRoundCheckpoint> for RoundCheckpoint {
+    fn from(sql_checkpoint: SqlRoundCheckpoint) -> Self {
+        // a game that can't be read back is played again
+        let game = match sql_checkpoint.game.as_str() {
+            "" => None,
+            json => serde_json::from_str::<PendingGame2v2>(json).ok(),
+        };
+        Self {
+            team1_id: sql_checkpoint.team1_id,
+            team2_id: sql_checkpoint.team2_id,
+            game,
+        }
+    }
+}
+
+impl From<NewRoundCheckpoint> for SqlRoundCheckpoint {
+    fn from(new_checkpoint: NewRoundCheckpoint) -> Self {
+        let game = match &new_checkpoint.game {
+            Some(g) => serde_json::to_string(g).unwrap_or_default(),
+            None => "".to_string(),
+        };
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_checkpoint.competition_id,
+            round: new_checkpoint.round,
+            team1_id: new_checkpoint.team1_id,
+            team2_id: new_checkpoint.team2_id,
+            game,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_events::{self};
+
+#[derive(Debug)]
+pub struct NewRoundEvent {
+    pub competition_id: String,
+    pub round: i32,
+    pub kind: String,
+    pub status: String,
+    pub message: String,
+    pub trace_id: String,
+}
+
+#[derive(Debug)]
+pub struct RoundEvent {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub kind: String,
+    pub status: String,
+    pub message: String,
+    pub created: NaiveDateTime,
+    pub trace_id: String,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_events)]
+pub struct SqlRoundEvent {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub kind: String,
+    pub status: String,
+    pub message: String,
+    pub created: NaiveDateTime,
+    pub trace_id: String,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicRoundEvent {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub kind: String,
+    pub status: String,
+    pub message: String,
+    pub created: NaiveDateTime,
+    pub trace_id: String,
+}
+
+impl From<SqlRoundEvent> for RoundEvent {
+    fn from(sql_round_event: SqlRoundEvent) -> Self {
+        Self {
+            id: sql_round_event.id,
+            competition_id: sql_round_event.competition_id,
+            round: sql_round_event.round,
+            kind: sql_round_event.kind,
+            status: sql_round_event.status,
+            message: sql_round_event.message,
+            created: sql_round_event.created,
+            trace_id: sql_round_event.trace_id,
+        }
+    }
+}
+
+impl From<RoundEvent> for PublicRoundEvent {
+    fn from(round_event: RoundEvent) -> Self {
+        Self {
+            id: round_event.id,
+            competition_id: round_event.competition_id,
+            round: round_event.round,
+            kind: round_event.kind,
+            status: round_event.status,
+            message: round_event.message,
+            created: round_event.created,
+            trace_id: round_event.trace_id,
+        }
+    }
+}
+
+impl From<NewRoundEvent> for SqlRoundEvent {
+    fn from(new_round_event: NewRoundEvent) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_round_event.competition_id,
+            round: new_round_event.round,
+            kind: new_round_event.kind,
+            status: new_round_event.status,
+            message: new_round_event.message,
+            created: Local::now().naive_utc(),
+            trace_id: new_round_event.trace_id,
+        }
+    }
+}
\ No newline at end of file
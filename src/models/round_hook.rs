@@ -0,0 +1,96 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_hooks::{self};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum HookKind {
+    Command,
+    Http,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewRoundHook {
+    pub competition_id: String,
+    pub kind: HookKind,
+    pub target: String,
+    pub timeout_secs: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundHook {
+    pub id: String,
+    pub competition_id: String,
+    pub kind: HookKind,
+    pub target: String,
+    pub timeout_secs: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_hooks)]
+pub struct SqlRoundHook {
+    pub id: String,
+    pub competition_id: String,
+    pub kind: String,
+    pub target: String,
+    pub timeout_secs: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicRoundHook {
+    pub id: String,
+    pub competition_id: String,
+    pub kind: HookKind,
+    pub target: String,
+    pub timeout_secs: i32,
+    pub created: NaiveDateTime,
+}
+
+impl From<SqlRoundHook> for RoundHook {
+    fn from(sql_round_hook: SqlRoundHook) -> Self {
+        Self {
+            id: sql_round_hook.id,
+            competition_id: sql_round_hook.competition_id,
+            kind: match sql_round_hook.kind.as_str() {
+                "HTTP" => HookKind::Http,
+                _ => HookKind::Command,
+            },
+            target: sql_round_hook.target,
+            timeout_secs: sql_round_hook.timeout_secs,
+            created: sql_round_hook.created,
+        }
+    }
+}
+
+impl From<RoundHook> for PublicRoundHook {
+    fn from(round_hook: RoundHook) -> Self {
+        Self {
+            id: round_hook.id,
+            competition_id: round_hook.competition_id,
+            kind: round_hook.kind,
+            target: round_hook.target,
+            timeout_secs: round_hook.timeout_secs,
+            created: round_hook.created,
+        }
+    }
+}
+
+impl From<NewRoundHook> for SqlRoundHook {
+    fn from(new_round_hook: NewRoundHook) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_round_hook.competition_id,
+            kind: match new_round_hook.kind {
+                HookKind::Command => "COMMAND".to_string(),
+                HookKind::Http => "HTTP".to_string(),
+            },
+            target: new_round_hook.target,
+            timeout_secs: new_round_hook.timeout_secs.unwrap_or(60),
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
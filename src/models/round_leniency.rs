@@ -0,0 +1,139 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_leniencies::{self};
+
+pub const DEFAULT_GAME_TIMEOUT_SECS: i32 = 120;
+pub const DEFAULT_CRASH_RETRIES: i32 = 0;
+pub const DEFAULT_INFRA_RETRIES: i32 = 2;
+
+/// Overrides the game timeout, crash retries and infrastructure retries for a range of rounds
+/// of a competition. `to_round` of `0` leaves the range open ended.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewRoundLeniency {
+    pub competition_id: String,
+    pub from_round: i32,
+    pub to_round: Option<i32>,
+    pub timeout_secs: Option<i32>,
+    pub crash_retries: Option<i32>,
+    /// games failing because of the host instead of a bot are played again up to this many times
+    pub infra_retries: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundLeniency {
+    pub id: String,
+    pub competition_id: String,
+    pub from_round: i32,
+    pub to_round: i32,
+    pub timeout_secs: i32,
+    pub crash_retries: i32,
+    pub created: NaiveDateTime,
+    pub infra_retries: i32,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_leniencies)]
+pub struct SqlRoundLeniency {
+    pub id: String,
+    pub competition_id: String,
+    pub from_round: i32,
+    pub to_round: i32,
+    pub timeout_secs: i32,
+    pub crash_retries: i32,
+    pub created: NaiveDateTime,
+    pub infra_retries: i32,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicRoundLeniency {
+    pub id: String,
+    pub competition_id: String,
+    pub from_round: i32,
+    pub to_round: i32,
+    pub timeout_secs: i32,
+    pub crash_retries: i32,
+    pub created: NaiveDateTime,
+    pub infra_retries: i32,
+}
+
+/// Timeout and retries that apply to the games of a single round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameLeniency {
+    pub timeout_secs: i32,
+    pub crash_retries: i32,
+    pub infra_retries: i32,
+}
+
+impl Default for GameLeniency {
+    fn default() -> Self {
+        Self {
+            timeout_secs: DEFAULT_GAME_TIMEOUT_SECS,
+            crash_retries: DEFAULT_CRASH_RETRIES,
+            infra_retries: DEFAULT_INFRA_RETRIES,
+        }
+    }
+}
+
+impl RoundLeniency {
+    pub fn covers(&self, round: i32) -> bool {
+        round >= self.from_round && (self.to_round == 0 || round <= self.to_round)
+    }
+}
+
+impl From<SqlRoundLeniency> for RoundLeniency {
+    fn from(sql_round_leniency: SqlRoundLeniency) -> Self {
+        Self {
+            id: sql_round_leniency.id,
+            competition_id: sql_round_leniency.competition_id,
+            from_round: sql_round_leniency.from_round,
+            to_round: sql_round_leniency.to_round,
+            timeout_secs: sql_round_leniency.timeout_secs,
+            crash_retries: sql_round_leniency.crash_retries,
+            created: sql_round_leniency.created,
+            infra_retries: sql_round_leniency.infra_retries,
+        }
+    }
+}
+
+impl From<RoundLeniency> for PublicRoundLeniency {
+    fn from(round_leniency: RoundLeniency) -> Self {
+        Self {
+            id: round_leniency.id,
+            competition_id: round_leniency.competition_id,
+            from_round: round_leniency.from_round,
+            to_round: round_leniency.to_round,
+            timeout_secs: round_leniency.timeout_secs,
+            crash_retries: round_leniency.crash_retries,
+            created: round_leniency.created,
+            infra_retries: round_leniency.infra_retries,
+        }
+    }
+}
+
+impl From<RoundLeniency> for GameLeniency {
+    fn from(round_leniency: RoundLeniency) -> Self {
+        Self {
+            timeout_secs: round_leniency.timeout_secs,
+            crash_retries: round_leniency.crash_retries,
+            infra_retries: round_leniency.infra_retries,
+        }
+    }
+}
+
+impl From<NewRoundLeniency> for SqlRoundLeniency {
+    fn from(new_round_leniency: NewRoundLeniency) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_round_leniency.competition_id,
+            from_round: new_round_leniency.from_round,
+            to_round: new_round_leniency.to_round.unwrap_or(0),
+            timeout_secs: new_round_leniency.timeout_secs.unwrap_or(DEFAULT_GAME_TIMEOUT_SECS),
+            crash_retries: new_round_leniency.crash_retries.unwrap_or(DEFAULT_CRASH_RETRIES),
+            created: Local::now().naive_utc(),
+            infra_retries: new_round_leniency.infra_retries.unwrap_or(DEFAULT_INFRA_RETRIES),
+        }
+    }
+}
\ No newline at end of file
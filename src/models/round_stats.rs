@@ -0,0 +1,149 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::round_stats::{self};
+
+#[derive(Debug)]
+pub struct NewRoundStats {
+    pub competition_id: String,
+    pub round: i32,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub crashed_games: i32,
+    pub avg_duration_ms: i64,
+    pub avg_score: f64,
+    pub biggest_elo_swing: i32,
+    pub timeouts: i32,
+    pub compile_failures: i32,
+    pub avg_elo_change: f64,
+    pub biggest_elo_gain: i32,
+    pub biggest_elo_loss: i32,
+}
+
+#[derive(Debug)]
+pub struct RoundStats {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub crashed_games: i32,
+    pub avg_duration_ms: i64,
+    pub avg_score: f64,
+    pub biggest_elo_swing: i32,
+    pub timeouts: i32,
+    pub compile_failures: i32,
+    pub avg_elo_change: f64,
+    pub biggest_elo_gain: i32,
+    pub biggest_elo_loss: i32,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = round_stats)]
+pub struct SqlRoundStats {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub crashed_games: i32,
+    pub avg_duration_ms: i64,
+    pub avg_score: f64,
+    pub biggest_elo_swing: i32,
+    pub created: NaiveDateTime,
+    pub timeouts: i32,
+    pub compile_failures: i32,
+    pub avg_elo_change: f64,
+    pub biggest_elo_gain: i32,
+    pub biggest_elo_loss: i32,
+}
+
+/// Summary of a finished round. `avg_elo_change` is the average ELO a team gained or lost per
+/// game, `biggest_elo_gain` and `biggest_elo_loss` the most a team gained or lost in one game.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicRoundStats {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub games_played: i32,
+    pub failed_matches: i32,
+    pub crashed_games: i32,
+    pub avg_duration_ms: i64,
+    pub avg_score: f64,
+    pub biggest_elo_swing: i32,
+    pub timeouts: i32,
+    pub compile_failures: i32,
+    pub avg_elo_change: f64,
+    pub biggest_elo_gain: i32,
+    pub biggest_elo_loss: i32,
+    pub created: NaiveDateTime,
+}
+
+impl From<NewRoundStats> for SqlRoundStats {
+    fn from(new_round_stats: NewRoundStats) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_round_stats.competition_id,
+            round: new_round_stats.round,
+            games_played: new_round_stats.games_played,
+            failed_matches: new_round_stats.failed_matches,
+            crashed_games: new_round_stats.crashed_games,
+            avg_duration_ms: new_round_stats.avg_duration_ms,
+            avg_score: new_round_stats.avg_score,
+            biggest_elo_swing: new_round_stats.biggest_elo_swing,
+            timeouts: new_round_stats.timeouts,
+            compile_failures: new_round_stats.compile_failures,
+            avg_elo_change: new_round_stats.avg_elo_change,
+            biggest_elo_gain: new_round_stats.biggest_elo_gain,
+            biggest_elo_loss: new_round_stats.biggest_elo_loss,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlRoundStats> for RoundStats {
+    fn from(sql_round_stats: SqlRoundStats) -> Self {
+        Self {
+            id: sql_round_stats.id,
+            competition_id: sql_round_stats.competition_id,
+            round: sql_round_stats.round,
+            games_played: sql_round_stats.games_played,
+            failed_matches: sql_round_stats.failed_matches,
+            crashed_games: sql_round_stats.crashed_games,
+            avg_duration_ms: sql_round_stats.avg_duration_ms,
+            avg_score: sql_round_stats.avg_score,
+            biggest_elo_swing: sql_round_stats.biggest_elo_swing,
+            timeouts: sql_round_stats.timeouts,
+            compile_failures: sql_round_stats.compile_failures,
+            avg_elo_change: sql_round_stats.avg_elo_change,
+            biggest_elo_gain: sql_round_stats.biggest_elo_gain,
+            biggest_elo_loss: sql_round_stats.biggest_elo_loss,
+            created: sql_round_stats.created,
+        }
+    }
+}
+
+impl From<RoundStats> for PublicRoundStats {
+    fn from(round_stats: RoundStats) -> Self {
+        Self {
+            id: round_stats.id,
+            competition_id: round_stats.competition_id,
+            round: round_stats.round,
+            games_played: round_stats.games_played,
+            failed_matches: round_stats.failed_matches,
+            crashed_games: round_stats.crashed_games,
+            avg_duration_ms: round_stats.avg_duration_ms,
+            avg_score: round_stats.avg_score,
+            biggest_elo_swing: round_stats.biggest_elo_swing,
+            timeouts: round_stats.timeouts,
+            compile_failures: round_stats.compile_failures,
+            avg_elo_change: round_stats.avg_elo_change,
+            biggest_elo_gain: round_stats.biggest_elo_gain,
+            biggest_elo_loss: round_stats.biggest_elo_loss,
+            created: round_stats.created,
+        }
+    }
+}
\ No newline at end of file
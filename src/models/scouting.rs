@@ -0,0 +1,86 @@
+use diesel::{QueryableByName, sql_types::{BigInt, Double}};
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+
+pub const DEFAULT_SCOUTING_GAMES: i64 = 20;
+pub const MAX_SCOUTING_GAMES: i64 = 100;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ScoutingQuery {
+    pub games: Option<i64>,
+}
+
+/// `game_player_stats` of a team's bots aggregated over the team's most recent games.
+#[derive(QueryableByName, Debug)]
+pub struct SqlScoutingStats {
+    #[diesel(sql_type = BigInt)]
+    pub games: i64,
+    #[diesel(sql_type = BigInt)]
+    pub bot_games: i64,
+    #[diesel(sql_type = Double)]
+    pub avg_turns_played: f64,
+    #[diesel(sql_type = BigInt)]
+    pub min_turns_played: i64,
+    #[diesel(sql_type = BigInt)]
+    pub max_turns_played: i64,
+    #[diesel(sql_type = BigInt)]
+    pub times_survived: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_turns_played: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_attacked: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_conquered: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_lost: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_fleet_generated: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_fleet_reinforced: i64,
+    #[diesel(sql_type = BigInt)]
+    pub largest_attack: i64,
+}
+
+/// Public behaviour profile of a team, built only from game stats so no source is exposed.
+///
+/// * `aggression_index` - share of the generated fleet that was not used to reinforce own
+///   planets, between 0 (turtling) and 1 (all in).
+/// * `expansion_speed` - planets conquered per 100 turns played.
+/// * `avg_survival_turns` - turns a bot of the team typically lasts in a game.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicScoutingReport {
+    pub team_id: String,
+    pub team_name: String,
+    pub games_analyzed: i64,
+    pub aggression_index: f64,
+    pub expansion_speed: f64,
+    pub attacks_per_100_turns: f64,
+    pub planets_lost_per_100_turns: f64,
+    pub largest_attack: i64,
+    pub avg_survival_turns: f64,
+    pub min_survival_turns: i64,
+    pub max_survival_turns: i64,
+    pub survival_rate: f64,
+}
+
+impl PublicScoutingReport {
+    pub fn new(team_id: String, team_name: String, stats: SqlScoutingStats) -> Self {
+        let ratio = |part: i64, total: i64| if total == 0 { 0.0 } else { part as f64 / total as f64 };
+        let aggression = ratio(stats.total_fleet_generated - stats.total_fleet_reinforced, stats.total_fleet_generated);
+        Self {
+            team_id,
+            team_name,
+            games_analyzed: stats.games,
+            aggression_index: aggression.clamp(0.0, 1.0),
+            expansion_speed: 100.0 * ratio(stats.total_planets_conquered, stats.total_turns_played),
+            attacks_per_100_turns: 100.0 * ratio(stats.total_planets_attacked, stats.total_turns_played),
+            planets_lost_per_100_turns: 100.0 * ratio(stats.total_planets_lost, stats.total_turns_played),
+            largest_attack: stats.largest_attack,
+            avg_survival_turns: stats.avg_turns_played,
+            min_survival_turns: stats.min_turns_played,
+            max_survival_turns: stats.max_turns_played,
+            survival_rate: ratio(stats.times_survived, stats.bot_games),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use serde::{Serialize, Deserialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::{competition::PublicCompetition, team::PublicTeam, user::PublicUser};
+
+pub const DEFAULT_SEARCH_RESULTS: i64 = 10;
+pub const MAX_SEARCH_RESULTS: i64 = 50;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    /// start of a team name, username, display name or competition name, case insensitive
+    pub q: String,
+    /// only list teams of this competition
+    pub competition_id: Option<String>,
+    /// results of each kind, 10 by default
+    pub limit: Option<i64>,
+}
+
+/// How a result matched the search, better matches are listed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+pub enum SearchRank {
+    /// the whole name is the search term
+    Exact,
+    /// the name starts with the search term
+    Prefix,
+    /// a team whose owner or partner matched, not the team's name
+    Member,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamSearchHit {
+    pub team: PublicTeam,
+    pub rank: SearchRank,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserSearchHit {
+    pub user: PublicUser,
+    pub rank: SearchRank,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompetitionSearchHit {
+    pub competition: PublicCompetition,
+    pub rank: SearchRank,
+}
+
+/// Results of a search, best match first in each list.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchResults {
+    pub teams: Vec<TeamSearchHit>,
+    pub users: Vec<UserSearchHit>,
+    pub competitions: Vec<CompetitionSearchHit>,
+}
@@ -0,0 +1,104 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::{season_competitions, seasons};
+
+/// Points for the first, second, ... placement in a competition of a season. Placements
+/// past the end of the table are worth nothing.
+pub const SEASON_PLACEMENT_POINTS: [i32; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewSeason {
+    pub name: String,
+    pub competition_ids: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Season {
+    pub id: String,
+    pub name: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = seasons)]
+pub struct SqlSeason {
+    pub id: String,
+    pub name: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = season_competitions)]
+pub struct SqlSeasonCompetition {
+    pub season_id: String,
+    pub competition_id: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicSeason {
+    pub id: String,
+    pub name: String,
+    pub created: NaiveDateTime,
+    pub competition_ids: Vec<String>,
+}
+
+/// Placement of a student's team in one competition of the season.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct SeasonPlacement {
+    pub competition_id: String,
+    pub team_id: String,
+    pub team_name: String,
+    pub rank: usize,
+    pub points: i32,
+}
+
+/// Season standing of a single student, students score with every team they were part of.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SeasonStanding {
+    pub rank: usize,
+    pub user_id: String,
+    pub username: String,
+    pub display_name: String,
+    pub points: i32,
+    pub placements: Vec<SeasonPlacement>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SeasonStandings {
+    pub season: PublicSeason,
+    pub standings: Vec<SeasonStanding>,
+}
+
+impl PublicSeason {
+    pub fn new(season: Season, competition_ids: Vec<String>) -> Self {
+        Self {
+            id: season.id,
+            name: season.name,
+            created: season.created,
+            competition_ids,
+        }
+    }
+}
+
+impl From<NewSeason> for SqlSeason {
+    fn from(new_season: NewSeason) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: new_season.name,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlSeason> for Season {
+    fn from(sql_season: SqlSeason) -> Self {
+        Self {
+            id: sql_season.id,
+            name: sql_season.name,
+            created: sql_season.created,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Verdict of the short test game an uploaded bot plays against idle bots before it can be
+/// put into one of the team's slots.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum SmokeTestStatus {
+    /// the test hasn't run yet
+    Pending,
+    Passed,
+    /// the bot threw or was eliminated by the Evaluator during the test
+    Crashed,
+    /// the bot exceeded its time limit or didn't get through the test turns in time
+    TimedOut,
+    /// the test couldn't be run, the bot is treated as if it passed
+    Skipped,
+}
+
+impl SmokeTestStatus {
+    /// Whether a bot with this verdict can't be put into a slot.
+    pub fn blocks_slot(&self) -> bool {
+        matches!(self, SmokeTestStatus::Pending | SmokeTestStatus::Crashed | SmokeTestStatus::TimedOut)
+    }
+}
+
+impl From<&str> for SmokeTestStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "PENDING" => SmokeTestStatus::Pending,
+            "PASSED" => SmokeTestStatus::Passed,
+            "CRASHED" => SmokeTestStatus::Crashed,
+            "TIMED_OUT" => SmokeTestStatus::TimedOut,
+            _ => SmokeTestStatus::Skipped,
+        }
+    }
+}
+
+impl fmt::Display for SmokeTestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SmokeTestStatus::Pending => write!(f, "PENDING"),
+            SmokeTestStatus::Passed => write!(f, "PASSED"),
+            SmokeTestStatus::Crashed => write!(f, "CRASHED"),
+            SmokeTestStatus::TimedOut => write!(f, "TIMED_OUT"),
+            SmokeTestStatus::Skipped => write!(f, "SKIPPED"),
+        }
+    }
+}
\ No newline at end of file
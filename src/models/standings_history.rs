@@ -0,0 +1,122 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::standings_history;
+
+use super::leaderboard::LeaderboardEntry;
+
+/// A team's place on the leaderboard at the end of a round.
+#[derive(Debug, Clone)]
+pub struct StandingsEntry {
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub team_name: String,
+    pub rank: i32,
+    pub elo: i32,
+    pub points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub games_played: i32,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = standings_history)]
+pub struct SqlStandingsEntry {
+    pub id: String,
+    pub competition_id: String,
+    pub round: i32,
+    pub team_id: String,
+    pub team_name: String,
+    pub position: i32,
+    pub elo: i32,
+    pub points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub games_played: i32,
+    pub created: NaiveDateTime,
+}
+
+/// A team's standing after a round. `rank_change` is how many places the team moved up since
+/// the previous round, negative if it dropped, and `null` if it wasn't ranked then.
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicStandingsEntry {
+    pub rank: i32,
+    pub rank_change: Option<i32>,
+    pub team_id: String,
+    pub team_name: String,
+    pub elo: i32,
+    pub points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub draws: i32,
+    pub games_played: i32,
+}
+
+/// The leaderboard as it was at the end of a round.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StandingsSnapshot {
+    pub competition_id: String,
+    pub round: i32,
+    pub entries: Vec<PublicStandingsEntry>,
+}
+
+impl StandingsEntry {
+    pub fn from_leaderboard(competition_id: String, round: i32, entry: &LeaderboardEntry) -> Self {
+        Self {
+            competition_id,
+            round,
+            team_id: entry.team_id.clone(),
+            team_name: entry.team_name.clone(),
+            rank: entry.rank as i32,
+            elo: entry.elo,
+            points: entry.points,
+            wins: entry.wins,
+            losses: entry.losses,
+            draws: entry.draws,
+            games_played: entry.games_played,
+        }
+    }
+}
+
+impl From<SqlStandingsEntry> for StandingsEntry {
+    fn from(sql_entry: SqlStandingsEntry) -> Self {
+        Self {
+            competition_id: sql_entry.competition_id,
+            round: sql_entry.round,
+            team_id: sql_entry.team_id,
+            team_name: sql_entry.team_name,
+            rank: sql_entry.position,
+            elo: sql_entry.elo,
+            points: sql_entry.points,
+            wins: sql_entry.wins,
+            losses: sql_entry.losses,
+            draws: sql_entry.draws,
+            games_played: sql_entry.games_played,
+        }
+    }
+}
+
+impl From<StandingsEntry> for SqlStandingsEntry {
+    fn from(entry: StandingsEntry) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: entry.competition_id,
+            round: entry.round,
+            team_id: entry.team_id,
+            team_name: entry.team_name,
+            position: entry.rank,
+            elo: entry.elo,
+            points: entry.points,
+            wins: entry.wins,
+            losses: entry.losses,
+            draws: entry.draws,
+            games_played: entry.games_played,
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// When a competition's next round is played and until when bots can still be changed for
+/// it. Rounds play the bots teams had active at the cutoff, later changes count from the
+/// round after.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmissionLock {
+    pub competition_id: String,
+    /// `None` if the competition has no further rounds
+    pub next_round: Option<NaiveDateTime>,
+    pub submission_cutoff: Option<NaiveDateTime>,
+    pub seconds_until_round: Option<i64>,
+    pub seconds_until_cutoff: Option<i64>,
+    pub submissions_open: bool,
+    /// why submissions are closed, empty while they are open
+    pub closed_reason: String,
+    pub server_time: NaiveDateTime,
+}
\ No newline at end of file
@@ -1,16 +1,36 @@
+use std::fmt;
+
 use diesel::prelude::{Insertable, Queryable};
 use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use async_graphql::SimpleObject;
 use chrono::{NaiveDateTime, Local};
 use uuid::Uuid;
 use crate::db::schema::teams::{self};
 
-#[derive(Debug, Deserialize)]
+/// ELO every team starts with.
+pub const DEFAULT_ELO: i32 = 1000;
+/// Initial mean of a team's skill rating.
+pub const DEFAULT_RATING_MU: f64 = 25.0;
+/// Initial uncertainty of a team's skill rating.
+pub const DEFAULT_RATING_SIGMA: f64 = DEFAULT_RATING_MU / 3.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum BotSelector {
     First,
     Second
 }
 
-#[derive(Debug, Deserialize)]
+impl fmt::Display for BotSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BotSelector::First => write!(f, "BOT1"),
+            BotSelector::Second => write!(f, "BOT2"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct NewTeam {
     pub name: String,
     pub owner: String,  
@@ -28,23 +48,49 @@ pub struct Team {
     pub bot2: String,
     pub elo: i32,
     pub created: NaiveDateTime,
+    pub rating_mu: f64,
+    pub rating_sigma: f64,
+    pub waitlisted: bool,
+    /// shown instead of `name` on public pages when not empty
+    pub alias: String,
+    /// hides the owner and partner from requests without a login
+    pub hide_members: bool,
 }   
 
+/// Ratings of a team after a round.
+#[derive(Debug, Clone)]
+pub struct TeamRating {
+    pub team_id: String,
+    pub elo: i32,
+    pub rating_mu: f64,
+    pub rating_sigma: f64,
+}
+
 #[derive(Queryable, Debug, Insertable)]
 #[diesel(table_name = teams)]
 pub struct SqlTeam {
     pub id: String,
     pub name: String,
     pub owner: String,
-    pub partner: String,
+    pub partner: Option<String>,
     pub competition_id: String,
     pub bot1: String,
     pub bot2: String,
     pub elo: i32,
     pub created: NaiveDateTime,
+    pub rating_mu: f64,
+    pub rating_sigma: f64,
+    pub waitlisted: bool,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub shadow_elo: Option<i32>,
+    pub shadow_rating_mu: Option<f64>,
+    pub shadow_rating_sigma: Option<f64>,
+    pub alias: String,
+    pub hide_members: bool,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema, SimpleObject)]
+#[graphql(name = "Team", complex)]
 pub struct PublicTeam {
     pub id: String,
     pub name: String,
@@ -55,6 +101,22 @@ pub struct PublicTeam {
     pub bot2: String,
     pub elo: i32,
     pub created: NaiveDateTime,
+    pub rating_mu: f64,
+    pub rating_sigma: f64,
+    pub waitlisted: bool,
+    pub alias: String,
+    pub hide_members: bool,
+}
+
+impl Team {
+    /// The name the team goes by on leaderboards and other public pages.
+    pub fn public_name(&self) -> String {
+        if self.alias.is_empty() {
+            self.name.clone()
+        } else {
+            self.alias.clone()
+        }
+    }
 }
 
 impl From<SqlTeam> for Team {
@@ -63,12 +125,17 @@ impl From<SqlTeam> for Team {
             id: sql_team.id,
             name: sql_team.name,
             owner: sql_team.owner,
-            partner: sql_team.partner,
+            partner: sql_team.partner.unwrap_or_default(),
             competition_id: sql_team.competition_id,
             bot1: sql_team.bot1,
             bot2: sql_team.bot2,
             elo: sql_team.elo,
             created: sql_team.created,
+            rating_mu: sql_team.rating_mu,
+            rating_sigma: sql_team.rating_sigma,
+            waitlisted: sql_team.waitlisted,
+            alias: sql_team.alias,
+            hide_members: sql_team.hide_members,
         }
     }
 }
@@ -85,7 +152,28 @@ impl From<Team> for PublicTeam {
             bot2: team.bot2,
             elo: team.elo,
             created: team.created,
+            rating_mu: team.rating_mu,
+            rating_sigma: team.rating_sigma,
+            waitlisted: team.waitlisted,
+            alias: team.alias,
+            hide_members: team.hide_members,
+        }
+    }
+}
+
+impl PublicTeam {
+    /// The team as it's shown to requests without a login, under its alias and without its
+    /// members if it hides them.
+    pub fn anonymized(team: Team) -> Self {
+        let name = team.public_name();
+        let hide_members = team.hide_members;
+        let mut public = Self::from(team);
+        public.name = name;
+        if hide_members {
+            public.owner = "".to_string();
+            public.partner = "".to_string();
         }
+        public
     }
 }
 
@@ -95,12 +183,21 @@ impl From<NewTeam> for SqlTeam {
             id: Uuid::new_v4().to_string(),
             name: new_team.name,
             owner: new_team.owner,
-            partner: "".to_string(),
+            partner: None,
             competition_id: new_team.competition_id,
             bot1: "".to_string(),
             bot2: "".to_string(),
-            elo: 1000,
+            elo: DEFAULT_ELO,
             created: Local::now().naive_utc(),
+            rating_mu: DEFAULT_RATING_MU,
+            rating_sigma: DEFAULT_RATING_SIGMA,
+            waitlisted: false,
+            deleted_at: None,
+            shadow_elo: None,
+            shadow_rating_mu: None,
+            shadow_rating_sigma: None,
+            alias: "".to_string(),
+            hide_members: false,
         }
     }
 }
\ No newline at end of file
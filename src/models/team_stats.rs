@@ -0,0 +1,85 @@
+use diesel::{QueryableByName, sql_types::{BigInt, Double}};
+use serde::Serialize;
+use utoipa::ToSchema;
+use async_graphql::SimpleObject;
+
+/// `game_player_stats` of both bots of a team aggregated over all of the team's games.
+/// Games without player stats (e.g. when a bot crashed) are not counted.
+#[derive(QueryableByName, Debug)]
+pub struct SqlTeamStats {
+    #[diesel(sql_type = BigInt)]
+    pub bot_games: i64,
+    #[diesel(sql_type = Double)]
+    pub avg_turns_played: f64,
+    #[diesel(sql_type = BigInt)]
+    pub times_survived: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_fleet_generated: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_fleet_lost: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_fleet_reinforced: i64,
+    #[diesel(sql_type = BigInt)]
+    pub largest_attack: i64,
+    #[diesel(sql_type = BigInt)]
+    pub largest_loss: i64,
+    #[diesel(sql_type = BigInt)]
+    pub largest_reinforcement: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_lost: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_conquered: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_defended: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_planets_attacked: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_troops_generated: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema, SimpleObject)]
+#[graphql(name = "TeamStats")]
+pub struct PublicTeamStats {
+    pub team_id: String,
+    pub bot_games: i64,
+    pub avg_turns_played: f64,
+    pub times_survived: i64,
+    pub survival_rate: f64,
+    pub total_fleet_generated: i64,
+    pub total_fleet_lost: i64,
+    pub total_fleet_reinforced: i64,
+    pub largest_attack: i64,
+    pub largest_loss: i64,
+    pub largest_reinforcement: i64,
+    pub total_planets_lost: i64,
+    pub total_planets_conquered: i64,
+    pub avg_planets_conquered: f64,
+    pub total_planets_defended: i64,
+    pub total_planets_attacked: i64,
+    pub total_troops_generated: i64,
+}
+
+impl PublicTeamStats {
+    pub fn new(team_id: String, stats: SqlTeamStats) -> Self {
+        let per_game = |total: i64| if stats.bot_games == 0 { 0.0 } else { total as f64 / stats.bot_games as f64 };
+        Self {
+            team_id,
+            bot_games: stats.bot_games,
+            avg_turns_played: stats.avg_turns_played,
+            times_survived: stats.times_survived,
+            survival_rate: per_game(stats.times_survived),
+            total_fleet_generated: stats.total_fleet_generated,
+            total_fleet_lost: stats.total_fleet_lost,
+            total_fleet_reinforced: stats.total_fleet_reinforced,
+            largest_attack: stats.largest_attack,
+            largest_loss: stats.largest_loss,
+            largest_reinforcement: stats.largest_reinforcement,
+            total_planets_lost: stats.total_planets_lost,
+            total_planets_conquered: stats.total_planets_conquered,
+            avg_planets_conquered: per_game(stats.total_planets_conquered),
+            total_planets_defended: stats.total_planets_defended,
+            total_planets_attacked: stats.total_planets_attacked,
+            total_troops_generated: stats.total_troops_generated,
+        }
+    }
+}
\ No newline at end of file
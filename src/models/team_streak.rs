@@ -0,0 +1,97 @@
+use diesel::prelude::{Insertable, Queryable};
+use chrono::{NaiveDateTime, Local};
+use crate::db::schema::team_streaks;
+
+/// Running per-team aggregates of a competition's standings, updated as each round's games are
+/// stored. `current_streak` is positive for consecutive wins and negative for consecutive
+/// losses, a draw ends any streak and a forfeit counts as a loss. `upset_score` is the ELO,
+/// before the round, of the strongest team the team beat.
+#[derive(Debug, Clone)]
+pub struct TeamStreak {
+    pub team_id: String,
+    pub competition_id: String,
+    pub current_streak: i32,
+    pub longest_win_streak: i32,
+    pub upset_score: i32,
+    pub upset_team_id: String,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = team_streaks)]
+pub struct SqlTeamStreak {
+    pub team_id: String,
+    pub competition_id: String,
+    pub current_streak: i32,
+    pub longest_win_streak: i32,
+    pub upset_score: i32,
+    pub upset_team_id: String,
+    pub updated: NaiveDateTime,
+}
+
+impl TeamStreak {
+    pub fn new(competition_id: String, team_id: String) -> Self {
+        Self {
+            team_id,
+            competition_id,
+            current_streak: 0,
+            longest_win_streak: 0,
+            upset_score: 0,
+            upset_team_id: "".to_string(),
+        }
+    }
+
+    /// Counts a game of the team.
+    ///
+    /// # Arguments
+    ///
+    /// * `winner_id` - The game's winner, empty for a draw.
+    /// * `opponent_id` - The team it played against.
+    /// * `opponent_elo` - The opponent's ELO before the round.
+    ///
+    pub fn record_game(&mut self, winner_id: &str, opponent_id: &str, opponent_elo: i32) {
+        if winner_id.is_empty() {
+            self.current_streak = 0;
+        } else if winner_id == self.team_id {
+            self.current_streak = self.current_streak.max(0) + 1;
+            self.longest_win_streak = self.longest_win_streak.max(self.current_streak);
+            if opponent_elo > self.upset_score {
+                self.upset_score = opponent_elo;
+                self.upset_team_id = opponent_id.to_string();
+            }
+        } else {
+            self.record_loss();
+        }
+    }
+
+    /// Counts a lost game or a forfeited round.
+    pub fn record_loss(&mut self) {
+        self.current_streak = self.current_streak.min(0) - 1;
+    }
+}
+
+impl From<SqlTeamStreak> for TeamStreak {
+    fn from(sql_streak: SqlTeamStreak) -> Self {
+        Self {
+            team_id: sql_streak.team_id,
+            competition_id: sql_streak.competition_id,
+            current_streak: sql_streak.current_streak,
+            longest_win_streak: sql_streak.longest_win_streak,
+            upset_score: sql_streak.upset_score,
+            upset_team_id: sql_streak.upset_team_id,
+        }
+    }
+}
+
+impl From<TeamStreak> for SqlTeamStreak {
+    fn from(streak: TeamStreak) -> Self {
+        Self {
+            team_id: streak.team_id,
+            competition_id: streak.competition_id,
+            current_streak: streak.current_streak,
+            longest_win_streak: streak.longest_win_streak,
+            upset_score: streak.upset_score,
+            upset_team_id: streak.upset_team_id,
+            updated: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::Serialize;
+use utoipa::ToSchema;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+use crate::db::schema::trace_spans::{self};
+
+use super::{round_event::PublicRoundEvent, game_2v2::PublicGame2v2};
+
+#[derive(Debug)]
+pub struct NewTraceSpan {
+    pub trace_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub status: String,
+    pub detail: String,
+    pub started: NaiveDateTime,
+    pub finished: NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct TraceSpan {
+    pub id: String,
+    pub trace_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub status: String,
+    pub detail: String,
+    pub started: NaiveDateTime,
+    pub finished: NaiveDateTime,
+    pub duration_ms: i64,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = trace_spans)]
+pub struct SqlTraceSpan {
+    pub id: String,
+    pub trace_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub status: String,
+    pub detail: String,
+    pub started: NaiveDateTime,
+    pub finished: NaiveDateTime,
+    pub duration_ms: i64,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicTraceSpan {
+    pub id: String,
+    pub trace_id: String,
+    pub competition_id: String,
+    pub name: String,
+    pub status: String,
+    pub detail: String,
+    pub started: NaiveDateTime,
+    pub finished: NaiveDateTime,
+    pub duration_ms: i64,
+}
+
+/// Everything recorded under a single trace id, ordered by time.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TraceTimeline {
+    pub trace_id: String,
+    pub spans: Vec<PublicTraceSpan>,
+    pub events: Vec<PublicRoundEvent>,
+    pub games: Vec<PublicGame2v2>,
+}
+
+impl From<SqlTraceSpan> for TraceSpan {
+    fn from(sql_trace_span: SqlTraceSpan) -> Self {
+        Self {
+            id: sql_trace_span.id,
+            trace_id: sql_trace_span.trace_id,
+            competition_id: sql_trace_span.competition_id,
+            name: sql_trace_span.name,
+            status: sql_trace_span.status,
+            detail: sql_trace_span.detail,
+            started: sql_trace_span.started,
+            finished: sql_trace_span.finished,
+            duration_ms: sql_trace_span.duration_ms,
+        }
+    }
+}
+
+impl From<TraceSpan> for PublicTraceSpan {
+    fn from(trace_span: TraceSpan) -> Self {
+        Self {
+            id: trace_span.id,
+            trace_id: trace_span.trace_id,
+            competition_id: trace_span.competition_id,
+            name: trace_span.name,
+            status: trace_span.status,
+            detail: trace_span.detail,
+            started: trace_span.started,
+            finished: trace_span.finished,
+            duration_ms: trace_span.duration_ms,
+        }
+    }
+}
+
+impl From<NewTraceSpan> for SqlTraceSpan {
+    fn from(new_trace_span: NewTraceSpan) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            duration_ms: (new_trace_span.finished - new_trace_span.started).num_milliseconds(),
+            trace_id: new_trace_span.trace_id,
+            competition_id: new_trace_span.competition_id,
+            name: new_trace_span.name,
+            status: new_trace_span.status,
+            detail: new_trace_span.detail,
+            started: new_trace_span.started,
+            finished: new_trace_span.finished,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// How long a bot takes for its turns over the games it played in a competition.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BotTurnTimes {
+    pub bot_id: String,
+    pub bot_name: String,
+    pub team_id: String,
+    pub team_name: String,
+    /// games the game pack reported turn times for
+    pub games: i32,
+    /// games in which the bot's 95th percentile turn came close to the limit
+    pub games_near_limit: i32,
+    /// median of the bot's per-game 50th percentiles
+    pub typical_p50_ms: i32,
+    /// median of the bot's per-game 95th percentiles
+    pub typical_p95_ms: i32,
+    pub max_ms: i32,
+    /// the bot routinely comes close to the limit
+    pub flagged: bool,
+}
+
+/// Turn times of a competition's bots, flagged bots first and then the slowest.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TurnTimeReport {
+    pub competition_id: String,
+    pub turn_limit_ms: i32,
+    /// a turn counts as close to the limit from this many milliseconds on
+    pub near_limit_ms: i32,
+    pub bots: Vec<BotTurnTimes>,
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Upload limit for competitions without a `max_size_kb` validation rule.
+pub const DEFAULT_MAX_UPLOAD_KB: i32 = 10 * 1024;
+
+/// Step of the upload validation pipeline an error was found in.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationStage {
+    Archive,
+    Size,
+    Structure,
+    Rules,
+    Compile,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UploadValidationError {
+    pub stage: ValidationStage,
+    pub message: String,
+}
+
+/// Result of validating an uploaded bot. `compiled` is `false` when compilation was skipped,
+/// e.g. in safe mode or while a ranked round kept the compiler busy.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UploadValidation {
+    pub valid: bool,
+    pub compiled: bool,
+    pub errors: Vec<UploadValidationError>,
+}
+
+impl UploadValidation {
+    pub fn new(compiled: bool, errors: Vec<UploadValidationError>) -> Self {
+        Self {
+            valid: errors.is_empty(),
+            compiled,
+            errors,
+        }
+    }
+}
\ No newline at end of file
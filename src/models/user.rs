@@ -1,20 +1,37 @@
+use std::fmt;
+
 use diesel::prelude::{Insertable, Queryable};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
 use chrono::{NaiveDateTime, Local};
 use uuid::Uuid;
 use crate::db::schema::users::{self};
 
+/// Start of the username of an anonymized user, followed by the user's id.
+pub const ERASED_USERNAME_PREFIX: &str = "erased-";
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
 pub enum Role {
     Student,
-    Admin
+    Admin,
+    Spectator,
+}
+
+/// What was done with a user whose data was erased.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum UserErasure {
+    /// the user was removed
+    Deleted,
+    /// the user was part of past rounds and is kept without any personal data
+    Anonymized,
 }
 
 #[derive(Debug)]
 pub struct LdapUser {
     pub username: String,
     pub ldap_dn: String,
+    pub display_name: String,
+    pub email: String,
 }
 
 #[derive(Debug)]
@@ -24,6 +41,9 @@ pub struct NewUser {
     ldap_dn: String,
     role: Role,
     created: NaiveDateTime,
+    display_name: String,
+    email: String,
+    lab_group: String,
 }
 
 #[derive(Debug)]
@@ -33,6 +53,9 @@ pub struct User {
     pub ldap_dn: String,
     pub role: Role,
     pub created: NaiveDateTime,
+    pub display_name: String,
+    pub email: String,
+    pub lab_group: String,
 }   
 
 #[derive(Queryable, Debug, Insertable)]
@@ -43,13 +66,48 @@ pub struct SqlUser {
     ldap_dn: String,
     role: String,
     created: NaiveDateTime,
+    display_name: String,
+    email: String,
+    lab_group: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UserRoleChange {
+    pub user_id: String,
+    pub role: String,
+}
+
+/// An empty `lab_group` removes the user from their lab group.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UserLabGroupChange {
+    pub user_id: String,
+    pub lab_group: String,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, ToSchema)]
 pub struct PublicUser {
     id: String,
     username: String,
+    display_name: String,
     role: Role,
+    lab_group: String,
+}
+
+/// A user's own profile, unlike `PublicUser` it includes the contact details.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserProfile {
+    id: String,
+    username: String,
+    display_name: String,
+    email: String,
+    role: Role,
+    created: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UserProfileUpdate {
+    pub display_name: Option<String>,
+    pub email: Option<String>,
 }
 
 impl From<SqlUser> for User {
@@ -58,11 +116,11 @@ impl From<SqlUser> for User {
             id: sql_user.id,
             username: sql_user.username.parse().unwrap(),
             ldap_dn: sql_user.ldap_dn,
-            role: match sql_user.role.as_str() {
-                "ADMIN" => Role::Admin,
-                _ => Role::Student,
-            },
+            role: Role::from(sql_user.role.as_str()),
             created: sql_user.created,
+            display_name: sql_user.display_name,
+            email: sql_user.email,
+            lab_group: sql_user.lab_group,
         }
     }
 }
@@ -72,7 +130,22 @@ impl From<User> for PublicUser {
         Self { 
             id: user.id, 
             username: user.username.to_string(),
+            display_name: user.display_name,
             role: user.role,
+            lab_group: user.lab_group,
+        }
+    }
+}
+
+impl From<User> for UserProfile {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            display_name: user.display_name,
+            email: user.email,
+            role: user.role,
+            created: user.created,
         }
     }
 }
@@ -85,6 +158,9 @@ impl From<LdapUser> for NewUser {
             ldap_dn: ldap_user.ldap_dn,
             created: Local::now().naive_utc(),
             role: Role::Student,
+            display_name: ldap_user.display_name,
+            email: ldap_user.email,
+            lab_group: String::new(),
         }
     }
 }
@@ -96,10 +172,30 @@ impl From<NewUser> for SqlUser {
             username: new_user.username.to_string(),
             ldap_dn: new_user.ldap_dn,
             created: new_user.created,
-            role: match new_user.role {
-                Role::Student => "STUDENT".to_string(),
-                Role::Admin => "ADMIN".to_string(),
-            },
+            role: new_user.role.to_string(),
+            display_name: new_user.display_name,
+            email: new_user.email,
+            lab_group: new_user.lab_group,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Role::Student => write!(f, "STUDENT"),
+            Role::Admin => write!(f, "ADMIN"),
+            Role::Spectator => write!(f, "SPECTATOR"),
+        }
+    }
+}
+
+impl From<&str> for Role {
+    fn from(role: &str) -> Self {
+        match role {
+            "ADMIN" => Role::Admin,
+            "SPECTATOR" => Role::Spectator,
+            _ => Role::Student,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::{
+    achievement::PublicAchievement,
+    api_key::PublicApiKey,
+    audit_log::PublicAuditEntry,
+    bot::PublicBot,
+    bot_version::PublicBotVersion,
+    game_2v2::PublicGame2v2,
+    participation::PublicParticipation,
+    team::PublicTeam,
+    user::UserProfile,
+};
+
+/// Everything stored about a user, for data access requests. Teams include deleted ones and
+/// the games, bots and bot versions are those of the user's teams.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserDataExport {
+    pub exported: NaiveDateTime,
+    pub profile: UserProfile,
+    pub teams: Vec<PublicTeam>,
+    pub participations: Vec<PublicParticipation>,
+    pub bots: Vec<PublicBot>,
+    pub bot_versions: Vec<PublicBotVersion>,
+    pub games: Vec<PublicGame2v2>,
+    pub achievements: Vec<PublicAchievement>,
+    pub api_keys: Vec<PublicApiKey>,
+    pub audit_log: Vec<PublicAuditEntry>,
+}
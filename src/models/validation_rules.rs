@@ -0,0 +1,113 @@
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use uuid::Uuid;
+use crate::db::schema::validation_rules::{self};
+
+pub const DEFAULT_GRACE_HOURS: i32 = 48;
+
+/// Rules the active bots of a competition have to follow. An empty list of allowed
+/// languages allows all of them, a `max_size_kb` of `0` disables the size limit.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewValidationRules {
+    pub competition_id: String,
+    pub forbidden_apis: Vec<String>,
+    pub allowed_languages: Vec<String>,
+    pub max_size_kb: Option<i32>,
+    pub grace_hours: Option<i32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidationRules {
+    pub id: String,
+    pub competition_id: String,
+    pub forbidden_apis: Vec<String>,
+    pub allowed_languages: Vec<String>,
+    pub max_size_kb: i32,
+    pub grace_hours: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = validation_rules)]
+pub struct SqlValidationRules {
+    pub id: String,
+    pub competition_id: String,
+    pub forbidden_apis: String,
+    pub allowed_languages: String,
+    pub max_size_kb: i32,
+    pub grace_hours: i32,
+    pub updated: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicValidationRules {
+    pub id: String,
+    pub competition_id: String,
+    pub forbidden_apis: Vec<String>,
+    pub allowed_languages: Vec<String>,
+    pub max_size_kb: i32,
+    pub grace_hours: i32,
+    pub updated: NaiveDateTime,
+}
+
+impl From<NewValidationRules> for SqlValidationRules {
+    fn from(new_rules: NewValidationRules) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_rules.competition_id,
+            forbidden_apis: join_list(new_rules.forbidden_apis),
+            allowed_languages: join_list(new_rules.allowed_languages.into_iter().map(|l| l.to_lowercase()).collect()),
+            max_size_kb: new_rules.max_size_kb.unwrap_or(0),
+            grace_hours: new_rules.grace_hours.unwrap_or(DEFAULT_GRACE_HOURS),
+            updated: Local::now().naive_utc(),
+        }
+    }
+}
+
+impl From<SqlValidationRules> for ValidationRules {
+    fn from(sql_rules: SqlValidationRules) -> Self {
+        Self {
+            id: sql_rules.id,
+            competition_id: sql_rules.competition_id,
+            forbidden_apis: split_list(&sql_rules.forbidden_apis),
+            allowed_languages: split_list(&sql_rules.allowed_languages),
+            max_size_kb: sql_rules.max_size_kb,
+            grace_hours: sql_rules.grace_hours,
+            updated: sql_rules.updated,
+        }
+    }
+}
+
+impl From<ValidationRules> for PublicValidationRules {
+    fn from(rules: ValidationRules) -> Self {
+        Self {
+            id: rules.id,
+            competition_id: rules.competition_id,
+            forbidden_apis: rules.forbidden_apis,
+            allowed_languages: rules.allowed_languages,
+            max_size_kb: rules.max_size_kb,
+            grace_hours: rules.grace_hours,
+            updated: rules.updated,
+        }
+    }
+}
+
+/// Lists are stored one entry per line.
+fn join_list(entries: Vec<String>) -> String {
+    entries
+        .iter()
+        .map(|e| e.trim())
+        .filter(|e| !e.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+fn split_list(entries: &str) -> Vec<String> {
+    entries
+        .lines()
+        .filter(|e| !e.is_empty())
+        .map(str::to_string)
+        .collect()
+}
\ No newline at end of file
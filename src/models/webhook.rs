@@ -0,0 +1,134 @@
+use std::fmt;
+
+use diesel::prelude::{Insertable, Queryable};
+use serde::{Serialize, Deserialize};
+use utoipa::ToSchema;
+use chrono::{NaiveDateTime, Local};
+use rand::RngCore;
+use uuid::Uuid;
+use crate::db::schema::webhooks::{self};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, ToSchema)]
+pub enum WebhookEvent {
+    RoundStarted,
+    RoundFinished,
+    GameFinished,
+}
+
+pub const ALL_WEBHOOK_EVENTS: [WebhookEvent; 3] = [
+    WebhookEvent::RoundStarted,
+    WebhookEvent::RoundFinished,
+    WebhookEvent::GameFinished,
+];
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NewWebhook {
+    pub competition_id: String,
+    pub url: String,
+    /// events the webhook receives, all of them if not given
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: String,
+    pub competition_id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Insertable)]
+#[diesel(table_name = webhooks)]
+pub struct SqlWebhook {
+    pub id: String,
+    pub competition_id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: String,
+    pub created: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct PublicWebhook {
+    pub id: String,
+    pub competition_id: String,
+    pub url: String,
+    /// key of the `X-Batalja-Signature` HMAC, receivers verify payloads with it
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub created: NaiveDateTime,
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebhookEvent::RoundStarted => write!(f, "ROUND_STARTED"),
+            WebhookEvent::RoundFinished => write!(f, "ROUND_FINISHED"),
+            WebhookEvent::GameFinished => write!(f, "GAME_FINISHED"),
+        }
+    }
+}
+
+impl WebhookEvent {
+    fn parse(event: &str) -> Option<Self> {
+        match event {
+            "ROUND_STARTED" => Some(WebhookEvent::RoundStarted),
+            "ROUND_FINISHED" => Some(WebhookEvent::RoundFinished),
+            "GAME_FINISHED" => Some(WebhookEvent::GameFinished),
+            _ => None,
+        }
+    }
+}
+
+impl Webhook {
+    pub fn receives(&self, event: WebhookEvent) -> bool {
+        self.events.contains(&event)
+    }
+}
+
+impl From<SqlWebhook> for Webhook {
+    fn from(sql_webhook: SqlWebhook) -> Self {
+        Self {
+            id: sql_webhook.id,
+            competition_id: sql_webhook.competition_id,
+            url: sql_webhook.url,
+            secret: sql_webhook.secret,
+            events: sql_webhook.events.split(',').filter_map(WebhookEvent::parse).collect(),
+            created: sql_webhook.created,
+        }
+    }
+}
+
+impl From<Webhook> for PublicWebhook {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            competition_id: webhook.competition_id,
+            url: webhook.url,
+            secret: webhook.secret,
+            events: webhook.events,
+            created: webhook.created,
+        }
+    }
+}
+
+impl From<NewWebhook> for SqlWebhook {
+    fn from(new_webhook: NewWebhook) -> Self {
+        let mut secret = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut secret);
+        let events = match new_webhook.events {
+            Some(events) if !events.is_empty() => events,
+            _ => ALL_WEBHOOK_EVENTS.to_vec(),
+        };
+        Self {
+            id: Uuid::new_v4().to_string(),
+            competition_id: new_webhook.competition_id,
+            url: new_webhook.url,
+            secret: hex::encode(secret),
+            events: events.iter().map(|e| e.to_string()).collect::<Vec<String>>().join(","),
+            created: Local::now().naive_utc(),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,381 @@
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::controllers::api_keys::API_KEY_HEADER;
+
+/// The OpenAPI document of every route, served at `/api/openapi.json` and rendered by the
+/// Swagger UI at `/api/docs`.
+///
+/// New routes have to be listed in `paths` and the types they return or accept in
+/// `components`, utoipa doesn't pick them up on its own.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Batalja Competition Dashboard API"),
+    servers((url = "/api")),
+    paths(
+        crate::routes::achievements_team::achievements_team,
+        crate::routes::achievements_user::achievements_user,
+        crate::routes::api_key_create::api_key_create,
+        crate::routes::api_key_get_all::api_key_get_all,
+        crate::routes::api_key_revoke::api_key_revoke,
+        crate::routes::bot_delete::bot_delete,
+        crate::routes::bot_recompile::bot_recompile,
+        crate::routes::bot_restore::bot_restore,
+        crate::routes::bot_metadata::bot_metadata,
+        crate::routes::bot_test_match::bot_test_match,
+        crate::routes::bot_upload::bot_upload,
+        crate::routes::bot_win_rates::bots_win_rate,
+        crate::routes::challenge_accept::challenge_accept,
+        crate::routes::challenge_create::challenge_create,
+        crate::routes::challenge_decline::challenge_decline,
+        crate::routes::challenge_replay::challenge_replay,
+        crate::routes::challenges_team::challenges_team,
+        crate::routes::competition_archive::competition_archive,
+        crate::routes::competition_archive_get::competition_archive_get,
+        crate::routes::competition_attended::competition_attended,
+        crate::routes::competition_clone::competition_clone,
+        crate::routes::competition_crashes::competition_crashes,
+        crate::routes::competition_turn_times::competition_turn_times,
+        crate::routes::competition_create::competition_create,
+        crate::routes::competition_delete::competition_delete,
+        crate::routes::competition_discord::competition_discord,
+        crate::routes::competition_discord_delete::competition_discord_delete,
+        crate::routes::competition_discord_get::competition_discord_get,
+        crate::routes::competition_evaluator::competition_evaluator,
+        crate::routes::competition_events::competition_events,
+        crate::routes::competition_featured::competition_featured,
+        crate::routes::competition_games::competition_games,
+        crate::routes::competition_grade_mapping::competition_grade_mapping,
+        crate::routes::competition_grade_mapping_get::competition_grade_mapping_get,
+        crate::routes::competition_grades::competition_grades,
+        crate::routes::competition_id::competition_id,
+        crate::routes::competition_ladder::competition_ladder,
+        crate::routes::competition_ladder_bot::competition_ladder_bot,
+        crate::routes::competition_ghost_create::competition_ghost_create,
+        crate::routes::competition_ghosts::competition_ghosts,
+        crate::routes::competition_ghost_delete::competition_ghost_delete,
+        crate::routes::competition_leaderboard::competition_leaderboard,
+        crate::routes::competition_pack::competition_pack,
+        crate::routes::competition_pairing::competition_pairing,
+        crate::routes::competition_pairing_get::competition_pairing_get,
+        crate::routes::competition_participation::competition_participation,
+        crate::routes::competition_plagiarism::competition_plagiarism,
+        crate::routes::competition_plagiarism_get::competition_plagiarism_get,
+        crate::routes::competition_rate_limit::competition_rate_limit,
+        crate::routes::competition_rate_limit_get::competition_rate_limit_get,
+        crate::routes::competition_ratings_recompute::competition_ratings_recompute,
+        crate::routes::competition_ratings_report::competition_ratings_report,
+        crate::routes::competition_ratings_apply::competition_ratings_apply,
+        crate::routes::competition_ratings_discard::competition_ratings_discard,
+        crate::routes::competition_audit_log::competition_audit_log,
+        crate::routes::competition_priority::competition_priority,
+        crate::routes::competition_rating::competition_rating,
+        crate::routes::competition_registration::competition_registration,
+        crate::routes::competition_restore::competition_restore,
+        crate::routes::competition_retention::competition_retention,
+        crate::routes::competition_round_get::competition_round_get,
+        crate::routes::competition_round_list::competition_round_list,
+        crate::routes::competition_round_pairing::competition_round_pairing,
+        crate::routes::competition_round_report::competition_round_report,
+        crate::routes::competition_round_run::competition_round_run,
+        crate::routes::competition_round_stats::competition_round_stats,
+        crate::routes::competition_rounds::competition_rounds,
+        crate::routes::competition_running::competition_running,
+        crate::routes::competition_scoring::competition_scoring,
+        crate::routes::competition_standings::competition_standings,
+        crate::routes::competition_submission_freeze::competition_submission_freeze,
+        crate::routes::competition_submission_lock::competition_submission_lock,
+        crate::routes::competition_team_count::competition_team_count,
+        crate::routes::competition_timeouts::competition_timeouts,
+        crate::routes::competition_validation::competition_validation,
+        crate::routes::competition_validation_get::competition_validation_get,
+        crate::routes::gallery_delete::gallery_delete,
+        crate::routes::gallery_download::gallery_download,
+        crate::routes::gallery_get_all::gallery_get_all,
+        crate::routes::gallery_publish::gallery_publish,
+        crate::routes::game_bot_output::game_bot_output,
+        crate::routes::game_errors::game_errors,
+        crate::routes::game_frames::game_frames,
+        crate::routes::game_get_public::game_get_public,
+        crate::routes::game_id::game_id,
+        crate::routes::game_log::game_log,
+        crate::routes::game_rematch::game_rematch,
+        crate::routes::game_replay_download::game_replay_download,
+        crate::routes::game_result_override::game_result_override,
+        crate::routes::graphql_query::graphql_query,
+        crate::routes::game_toggle_public::game_toggle_public,
+        crate::routes::hook_create::hook_create,
+        crate::routes::hook_delete::hook_delete,
+        crate::routes::hook_get_all::hook_get_all,
+        crate::routes::job_get::job_get,
+        crate::routes::leniency_create::leniency_create,
+        crate::routes::leniency_delete::leniency_delete,
+        crate::routes::leniency_get_all::leniency_get_all,
+        crate::routes::login::login,
+        crate::routes::matchmaking_test::mmt,
+        crate::routes::metrics::metrics,
+        crate::routes::practice_delete::practice_delete,
+        crate::routes::practice_get_all::practice_get_all,
+        crate::routes::practice_match::practice_match,
+        crate::routes::practice_publish::practice_publish,
+        crate::routes::practice_queue_games::practice_queue_games,
+        crate::routes::practice_queue_join::practice_queue_join,
+        crate::routes::practice_queue_leave::practice_queue_leave,
+        crate::routes::public_games::public_games,
+        crate::routes::public_replay::public_replay,
+        crate::routes::public_standings::public_standings,
+        crate::routes::queue_status::queue_status,
+        crate::routes::search::search,
+        crate::routes::season_create::season_create,
+        crate::routes::season_get_all::season_get_all,
+        crate::routes::season_standings::season_standings,
+        crate::routes::team_bot_change::team_bot_change,
+        crate::routes::team_bot_rollback::team_bot_rollback,
+        crate::routes::team_slot_assign::team_slot_assign,
+        crate::routes::team_slots::team_slots,
+        crate::routes::team_bot_versions::team_bot_versions,
+        crate::routes::team_bots::team_bots,
+        crate::routes::team_create::team_create,
+        crate::routes::team_delete::team_delete,
+        crate::routes::team_disband::team_disband,
+        crate::routes::team_elo_history::team_elo_history,
+        crate::routes::team_get::team_get,
+        crate::routes::team_get_all::team_get_all,
+        crate::routes::team_head_to_head::team_head_to_head,
+        crate::routes::team_id::team_id,
+        crate::routes::team_join::team_join,
+        crate::routes::team_kick::team_kick,
+        crate::routes::team_leave::team_leave,
+        crate::routes::team_participation::team_participation,
+        crate::routes::team_privacy::team_privacy,
+        crate::routes::team_rename::team_name_change,
+        crate::routes::team_restore::team_restore,
+        crate::routes::team_scouting::team_scouting,
+        crate::routes::team_stats::team_stats,
+        crate::routes::team_violations::team_violations,
+        crate::routes::trace_timeline::trace_timeline,
+        crate::routes::user_delete::user_delete,
+        crate::routes::user_export::user_export,
+        crate::routes::user_id::user_id,
+        crate::routes::user_lab_group::user_lab_group,
+        crate::routes::user_me::user_me,
+        crate::routes::user_role::user_role,
+        crate::routes::user_update::user_update,
+        crate::routes::webhook_create::webhook_create,
+        crate::routes::webhook_delete::webhook_delete,
+        crate::routes::webhook_get_all::webhook_get_all,
+        crate::routes::worker_bot::worker_bot,
+        crate::routes::worker_lease::worker_lease,
+        crate::routes::worker_result::worker_result,
+    ),
+    components(schemas(
+        crate::controllers::workload_gate::WorkloadMode,
+        crate::controllers::workload_gate::WorkloadStatus,
+        crate::models::achievement::AchievementKind,
+        crate::models::achievement::PublicAchievement,
+        crate::models::api_key::NewApiKey,
+        crate::models::api_key::PublicApiKey,
+        crate::models::api_key::CreatedApiKey,
+        crate::models::bot::NewBot,
+        crate::models::bot::PublicBot,
+        crate::models::bot::CompileReport,
+        crate::models::bot::BotMetadata,
+        crate::models::bot::GameBot,
+        crate::models::bot_version::CompileStatus,
+        crate::models::smoke_test::SmokeTestStatus,
+        crate::models::bot_version::PublicBotVersion,
+        crate::models::bot_version::BotSlot,
+        crate::models::bot_violation::PublicBotViolation,
+        crate::models::bot_violation::RevalidationSummary,
+        crate::models::challenge::ChallengeStatus,
+        crate::models::challenge::NewChallenge,
+        crate::models::challenge::PublicChallenge,
+        crate::models::competition::ScoringSystem,
+        crate::models::competition::NewCompetition,
+        crate::models::competition::CompetitionClone,
+        crate::models::competition::PublicCompetition,
+        crate::models::compile_diagnostics::CompileDiagnostic,
+        crate::models::compile_diagnostics::CompileDiagnostics,
+        crate::models::crash_report::CrashCounts,
+        crate::models::crash_report::CrashReport,
+        crate::models::crash_report::TeamCrashes,
+        crate::models::turn_time_report::TurnTimeReport,
+        crate::models::turn_time_report::BotTurnTimes,
+        crate::models::discord_channel::NewDiscordChannel,
+        crate::models::discord_channel::PublicDiscordChannel,
+        crate::models::elo_history::PublicEloHistory,
+        crate::models::featured_game::FeaturedReason,
+        crate::models::featured_game::FeaturedGame,
+        crate::models::featured_game::FeaturedGames,
+        crate::models::game_2v2::ReplayState,
+        crate::models::game_2v2::ColorPair,
+        crate::models::game_2v2::NewGame2v2,
+        crate::models::game_2v2::PendingGame2v2,
+        crate::models::game_2v2::SqlGame2v2,
+        crate::models::game_2v2::PublicGame2v2,
+        crate::models::game_listing::GamePage,
+        crate::models::game_player_stats::GamePlayerStats,
+        crate::models::game_player_stats::FailureKind,
+        crate::models::game_player_stats::GameError,
+        crate::models::game_player_stats::NewGamePlayerStats,
+        crate::models::game_player_stats::PublicGamePlayerStats,
+        crate::models::grade_mapping::GradeBasis,
+        crate::models::grade_mapping::GradeBand,
+        crate::models::grade_mapping::NewGradeMapping,
+        crate::models::grade_mapping::PublicGradeMapping,
+        crate::models::grade_mapping::GradePreview,
+        crate::models::head_to_head::HeadToHead,
+        crate::models::job::JobKind,
+        crate::models::job::JobStatus,
+        crate::models::job::PublicJob,
+        crate::models::ghost_team::NewGhostTeam,
+        crate::models::ghost_team::PublicGhostTeam,
+        crate::models::ladder::LadderOverview,
+        crate::models::ladder::LadderStanding,
+        crate::models::ladder::NewLadderBot,
+        crate::models::ladder::PublicLadderBot,
+        crate::models::leaderboard::LeaderboardEntry,
+        crate::models::leaderboard::LeaderboardPage,
+        crate::models::pairing_constraints::NewPairingConstraints,
+        crate::models::pairing_constraints::PublicPairingConstraints,
+        crate::models::participation::PublicParticipation,
+        crate::models::participation::StudentParticipation,
+        crate::models::plagiarism::PlagiarismRequest,
+        crate::models::plagiarism::PublicPlagiarismPair,
+        crate::models::plagiarism::PublicPlagiarismReport,
+        crate::models::practice_bot::NewPracticeBot,
+        crate::models::practice_bot::PublicPracticeBot,
+        crate::models::practice_bot::PracticeMatchResult,
+        crate::models::practice_queue::NewPracticeQueueEntry,
+        crate::models::practice_queue::PublicPracticeQueueEntry,
+        crate::models::practice_queue::PublicPracticeGame,
+        crate::models::published_bot::NewPublishedBot,
+        crate::models::published_bot::PublicPublishedBot,
+        crate::models::rate_limit::NewRateLimit,
+        crate::models::rate_limit::PublicRateLimit,
+        crate::models::rating_recompute::RatingRecomputeOptions,
+        crate::models::rating_recompute::RatingRecomputeTarget,
+        crate::models::rating_recompute::TeamRatingDiff,
+        crate::models::rating_recompute::RatingRecomputeReport,
+        crate::models::audit_log::PublicAuditEntry,
+        crate::models::game_override::GameResultOverride,
+        crate::models::game_override::GameOverrideResult,
+        crate::models::reference_match::ReferenceMatchResult,
+        crate::models::rematch::RematchResult,
+        crate::models::remote_game::RemoteGame,
+        crate::models::remote_game::RemoteGameLease,
+        crate::models::remote_game::RemoteGameResult,
+        crate::models::replay_frame::ReplayFrames,
+        crate::models::replay_frame::ReplayFrame,
+        crate::models::replay_frame::PlanetState,
+        crate::models::replay_frame::FleetMovement,
+        crate::models::round::PairingMetadata,
+        crate::models::round::PublicRound,
+        crate::models::round::RoundDetails,
+        crate::models::round::RoundPairingAudit,
+        crate::models::round::RoundReport,
+        crate::models::round::RoundStatus,
+        crate::models::round_event::PublicRoundEvent,
+        crate::models::round_hook::HookKind,
+        crate::models::round_hook::NewRoundHook,
+        crate::models::round_hook::PublicRoundHook,
+        crate::models::round_leniency::NewRoundLeniency,
+        crate::models::round_leniency::PublicRoundLeniency,
+        crate::models::round_stats::PublicRoundStats,
+        crate::models::scouting::PublicScoutingReport,
+        crate::models::search::SearchRank,
+        crate::models::search::TeamSearchHit,
+        crate::models::search::UserSearchHit,
+        crate::models::search::CompetitionSearchHit,
+        crate::models::search::SearchResults,
+        crate::models::season::NewSeason,
+        crate::models::season::PublicSeason,
+        crate::models::season::SeasonPlacement,
+        crate::models::season::SeasonStanding,
+        crate::models::season::SeasonStandings,
+        crate::models::standings_history::PublicStandingsEntry,
+        crate::models::standings_history::StandingsSnapshot,
+        crate::models::submission_lock::SubmissionLock,
+        crate::models::team::BotSelector,
+        crate::models::team::NewTeam,
+        crate::models::team::PublicTeam,
+        crate::models::team_stats::PublicTeamStats,
+        crate::models::trace_span::PublicTraceSpan,
+        crate::models::trace_span::TraceTimeline,
+        crate::models::upload_validation::ValidationStage,
+        crate::models::upload_validation::UploadValidationError,
+        crate::models::upload_validation::UploadValidation,
+        crate::models::user::Role,
+        crate::models::user::UserRoleChange,
+        crate::models::user::UserLabGroupChange,
+        crate::models::user::PublicUser,
+        crate::models::user::UserProfile,
+        crate::models::user::UserProfileUpdate,
+        crate::models::user::UserErasure,
+        crate::models::user_data::UserDataExport,
+        crate::models::validation_rules::NewValidationRules,
+        crate::models::validation_rules::PublicValidationRules,
+        crate::models::webhook::WebhookEvent,
+        crate::models::webhook::NewWebhook,
+        crate::models::webhook::PublicWebhook,
+        crate::routes::bot_upload::BotUploadData,
+        crate::routes::competition_evaluator::EvaluatorData,
+        crate::routes::competition_priority::PriorityData,
+        crate::routes::competition_rating::RatingSettingsData,
+        crate::routes::competition_registration::RegistrationData,
+        crate::routes::competition_retention::ReplayRetentionData,
+        crate::routes::competition_scoring::ScoringData,
+        crate::routes::competition_submission_freeze::SubmissionFreezeData,
+        crate::routes::competition_timeouts::GameTimeoutsData,
+        crate::routes::game_id::GameDetails,
+        crate::routes::bot_metadata::BotMetadataData,
+        crate::routes::login::AuthPost,
+        crate::routes::practice_match::PracticeMatchRequest,
+        crate::routes::team_bot_change::ChangeBotData,
+        crate::routes::team_bot_rollback::RollbackData,
+        crate::routes::team_slot_assign::SlotAssignmentData,
+        crate::routes::team_disband::LeaveTeamData,
+        crate::routes::team_join::JoinTeamData,
+        crate::routes::team_kick::KickPartnerData,
+        crate::routes::team_privacy::TeamPrivacyData,
+        crate::routes::team_rename::ChangeNameData,
+    )),
+    tags(
+        (name = "users", description = "Accounts and login"),
+        (name = "teams", description = "Teams and invites"),
+        (name = "bots", description = "Uploading and managing bots"),
+        (name = "competitions", description = "Competition setup and administration"),
+        (name = "games", description = "Games, replays and stats"),
+        (name = "spectators", description = "Leaderboards and featured games, no login needed"),
+        (name = "seasons", description = "Seasons spanning several competitions"),
+        (name = "practice", description = "Practice bots and test matches"),
+        (name = "challenges", description = "Friendly matches between teams"),
+        (name = "jobs", description = "Background jobs"),
+        (name = "operations", description = "Health, metrics and maintenance"),
+        (name = "workers", description = "Remote match workers"),
+        (name = "api keys", description = "Keys for the public API"),
+        (name = "public api", description = "Read-only API authenticated with an API key"),
+    ),
+    modifiers(&SecurityAddon),
+)]
+pub struct ApiDoc;
+
+/// Registers the two ways of authenticating, the JWT from `/login` and the API keys of the
+/// public API.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build()),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+    }
+}
\ No newline at end of file
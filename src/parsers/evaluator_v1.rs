@@ -0,0 +1,61 @@
+//! Output of the original Batalja game packs.
+//!
+//! Scores are reported as `R <score> <color>` throughout the game, the last one is final.
+//! After the game a `STAT:` header is written for each bot followed by `<key>: <value>`
+//! lines; the headers come in the order team1bot1, team2bot1, team1bot2, team2bot2.
+
+use std::collections::HashMap;
+
+use super::{apply_score, apply_stat, apply_turn_time, apply_turn_times, is_turn_record, team_scores, EvaluatorOutput};
+
+const STAT_ORDER: [&str; 4] = ["team1bot1", "team2bot1", "team1bot2", "team2bot2"];
+
+pub fn parse(lines: &[String]) -> EvaluatorOutput {
+    let mut output = EvaluatorOutput::default();
+    let mut scores: HashMap<String, i32> = HashMap::new();
+    let mut turn_times: HashMap<String, Vec<i32>> = HashMap::new();
+    let mut stat_slots = STAT_ORDER.iter();
+    let mut current_bot: Option<String> = None;
+
+    for line in lines.iter() {
+        let parts: Vec<&str> = line.split(' ').collect();
+
+        if apply_turn_time(&mut turn_times, &parts) {
+            continue;
+        }
+
+        if line.contains("STAT: ") {
+            // the stats that follow belong to the next bot
+            current_bot = stat_slots.next().map(|slot| slot.to_string());
+            if let Some(slot) = &current_bot {
+                output.player_stats.insert(slot.clone(), Default::default());
+            }
+            continue;
+        }
+
+        if line.contains("R ") && apply_score(&mut scores, &parts) {
+            continue;
+        }
+
+        if line.contains("L ") {
+            output.last_loss = Some(line.to_owned());
+            continue;
+        }
+
+        if let (Some(slot), [key, value]) = (&current_bot, parts.as_slice()) {
+            let stat = output.player_stats.entry(slot.clone()).or_default();
+            if apply_stat(stat, key.trim_end_matches(':'), value) {
+                continue;
+            }
+        }
+
+        if line.trim().is_empty() || is_turn_record(&parts) {
+            continue;
+        }
+        output.unknown_lines.push(line.to_owned());
+    }
+
+    (output.team1_score, output.team2_score) = team_scores(&scores);
+    apply_turn_times(&mut output.player_stats, turn_times);
+    output
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+//! Output of the FTP game packs.
+//!
+//! Same records as v1, but each `STAT:` header names the folder of the bot its stats belong
+//! to instead of relying on the order of the headers, and stats may be written either as
+//! `<key>: <value>` or `<key>=<value>`.
+
+use std::collections::HashMap;
+
+use super::{apply_score, apply_stat, apply_turn_time, apply_turn_times, is_turn_record, team_scores, EvaluatorOutput, BOT_SLOTS};
+
+pub fn parse(lines: &[String], bot_ids: &[&str]) -> EvaluatorOutput {
+    let mut output = EvaluatorOutput::default();
+    let mut scores: HashMap<String, i32> = HashMap::new();
+    let mut turn_times: HashMap<String, Vec<i32>> = HashMap::new();
+    let mut unlabelled_slots = BOT_SLOTS.iter();
+    let mut current_bot: Option<String> = None;
+
+    for line in lines.iter() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+
+        if apply_turn_time(&mut turn_times, &parts) {
+            continue;
+        }
+
+        if let Some(label) = line.trim().strip_prefix("STAT:") {
+            // headers without a known bot fall back to the slot order
+            current_bot = bot_ids
+                .iter()
+                .position(|id| !id.is_empty() && label.contains(id))
+                .and_then(|i| BOT_SLOTS.get(i).copied())
+                .or_else(|| unlabelled_slots.next().copied())
+                .map(|slot| slot.to_string());
+            if let Some(slot) = &current_bot {
+                output.player_stats.insert(slot.clone(), Default::default());
+            }
+            continue;
+        }
+
+        if parts.first() == Some(&"R") && apply_score(&mut scores, &parts) {
+            continue;
+        }
+
+        if parts.first() == Some(&"L") {
+            output.last_loss = Some(line.to_owned());
+            continue;
+        }
+
+        if let Some(slot) = &current_bot {
+            let key_value = match parts.as_slice() {
+                [key, value] => Some((key.trim_end_matches(':'), *value)),
+                [pair] => pair.split_once('='),
+                _ => None,
+            };
+            if let Some((key, value)) = key_value {
+                let stat = output.player_stats.entry(slot.clone()).or_default();
+                if apply_stat(stat, key, value) {
+                    continue;
+                }
+            }
+        }
+
+        if parts.is_empty() || is_turn_record(&parts) {
+            continue;
+        }
+        output.unknown_lines.push(line.to_owned());
+    }
+
+    (output.team1_score, output.team2_score) = team_scores(&scores);
+    apply_turn_times(&mut output.player_stats, turn_times);
+    output
+}
\ No newline at end of file
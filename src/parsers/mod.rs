@@ -0,0 +1,197 @@
+//! Parsers of the Evaluator's game output.
+//!
+//! Each game pack writes its own output format, the parser is picked by the competition's
+//! `game_pack` and the version is stored on every game so replays of old rounds are always
+//! read with the parser they were written for.
+//!
+//! Game packs that time their bots write a `T <milliseconds> <color>` record for every turn
+//! a bot took, in either version; packs that don't simply leave the turn times at zero.
+
+use std::{collections::HashMap, fmt};
+
+use crate::models::game_player_stats::GamePlayerStats;
+
+pub mod evaluator_v1;
+pub mod evaluator_v2;
+pub mod replay;
+
+/// Bot slots in the order they are passed to the Evaluator.
+pub const BOT_SLOTS: [&str; 4] = ["team1bot1", "team1bot2", "team2bot1", "team2bot2"];
+/// Color of each bot slot, in the order of `BOT_SLOTS`.
+const SLOT_COLORS: [&str; 4] = ["yellow", "green", "blue", "cyan"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EvaluatorVersion {
+    /// output of the original Batalja game packs
+    #[default]
+    V1,
+    /// output of the FTP game packs, stats are labelled with the bot they belong to
+    V2,
+}
+
+/// Everything the matchmaker needs from a game's output.
+#[derive(Debug, Default)]
+pub struct EvaluatorOutput {
+    pub team1_score: i32,
+    pub team2_score: i32,
+    /// stats of each bot, keyed by slot (`team1bot1`, ...)
+    pub player_stats: HashMap<String, GamePlayerStats>,
+    /// last `L` line, names the bot that was eliminated last
+    pub last_loss: Option<String>,
+    /// lines the parser didn't recognise, kept for debugging
+    pub unknown_lines: Vec<String>,
+}
+
+impl EvaluatorVersion {
+    /// Picks the output format of a competition's game pack.
+    pub fn for_game_pack(game_pack: &str) -> Self {
+        let pack_name = game_pack.rsplit('/').next().unwrap_or(game_pack);
+        if pack_name.to_lowercase().contains("ftp") {
+            EvaluatorVersion::V2
+        } else {
+            EvaluatorVersion::V1
+        }
+    }
+
+    /// Parses the game's stdout.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines the Evaluator wrote to stdout.
+    /// * `bot_ids` - Ids of the bots in the order of `BOT_SLOTS`.
+    ///
+    pub fn parse(&self, lines: &[String], bot_ids: &[&str]) -> EvaluatorOutput {
+        match self {
+            EvaluatorVersion::V1 => evaluator_v1::parse(lines),
+            EvaluatorVersion::V2 => evaluator_v2::parse(lines, bot_ids),
+        }
+    }
+}
+
+impl fmt::Display for EvaluatorVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvaluatorVersion::V1 => write!(f, "v1"),
+            EvaluatorVersion::V2 => write!(f, "v2"),
+        }
+    }
+}
+
+impl TryFrom<&str> for EvaluatorVersion {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "v1" => Ok(EvaluatorVersion::V1),
+            "v2" => Ok(EvaluatorVersion::V2),
+            _ => Err(format!("Unknown evaluator output version: {}", value)),
+        }
+    }
+}
+
+/// Splits the lines a game wrote by the bot they were printed by, keyed by slot
+/// (`team1bot1`, ...). The Evaluator labels what a bot prints with the bot's folder, which is
+/// named after its id; `STAT:` headers are its own and are skipped.
+///
+/// # Arguments
+///
+/// * `lines` - The lines the Evaluator wrote to stdout and stderr.
+/// * `bot_ids` - Ids of the bots in the order of `BOT_SLOTS`.
+///
+pub fn bot_prints(lines: &[String], bot_ids: &[&str]) -> HashMap<String, Vec<String>> {
+    let mut prints: HashMap<String, Vec<String>> = HashMap::new();
+    for line in lines.iter().filter(|line| !line.trim().starts_with("STAT:")) {
+        let slot = bot_ids
+            .iter()
+            .position(|id| !id.is_empty() && line.contains(id))
+            .and_then(|i| BOT_SLOTS.get(i));
+        if let Some(slot) = slot {
+            prints.entry(slot.to_string()).or_default().push(line.to_owned());
+        }
+    }
+    prints
+}
+
+/// Adds a team's score from an `R <score> <color>` line, yellow and green play for team 1,
+/// blue and cyan for team 2.
+fn apply_score(scores: &mut HashMap<String, i32>, parts: &[&str]) -> bool {
+    match parts {
+        [_, score, color] if ["yellow", "green", "blue", "cyan"].contains(color) => {
+            scores.insert(color.to_string(), score.parse().unwrap_or(0));
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Records how long a bot took for a turn from a `T <milliseconds> <color>` line.
+fn apply_turn_time(turn_times: &mut HashMap<String, Vec<i32>>, parts: &[&str]) -> bool {
+    match parts {
+        ["T", millis, color] if SLOT_COLORS.contains(color) => {
+            if let Ok(millis) = millis.parse() {
+                turn_times.entry(color.to_string()).or_default().push(millis);
+            }
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Sums up the turn times of each bot into its stats, bots without stats are skipped.
+fn apply_turn_times(stats: &mut HashMap<String, GamePlayerStats>, mut turn_times: HashMap<String, Vec<i32>>) {
+    for (slot, color) in BOT_SLOTS.iter().zip(SLOT_COLORS.iter()) {
+        let (stat, mut times) = match (stats.get_mut(*slot), turn_times.remove(*color)) {
+            (Some(stat), Some(times)) if !times.is_empty() => (stat, times),
+            _ => continue,
+        };
+        times.sort_unstable();
+        stat.turns_timed = times.len() as i32;
+        stat.turn_time_p50_ms = percentile(&times, 50);
+        stat.turn_time_p95_ms = percentile(&times, 95);
+        stat.turn_time_max_ms = times[times.len() - 1];
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty samples.
+fn percentile(sorted: &[i32], percent: usize) -> i32 {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn team_scores(scores: &HashMap<String, i32>) -> (i32, i32) {
+    let score = |color: &str| scores.get(color).copied().unwrap_or(0);
+    (score("yellow") + score("green"), score("blue") + score("cyan"))
+}
+
+/// Sets a single stat of a bot, returns `false` for unknown stats.
+fn apply_stat(stat: &mut GamePlayerStats, key: &str, value: &str) -> bool {
+    match key {
+        "turnsPlayed"           => stat.turns_played             = value.parse().unwrap_or(0),
+        "survive"               => stat.survived                 = value.parse().unwrap_or(false),
+        "fleetGenerated"        => stat.fleet_generated          = value.parse().unwrap_or(0),
+        "fleetLost"             => stat.fleet_lost               = value.parse().unwrap_or(0),
+        "fleetReinforced"       => stat.fleet_reinforced         = value.parse().unwrap_or(0),
+        "largestAttack"         => stat.largest_attack           = value.parse().unwrap_or(0),
+        "largestLoss"           => stat.largest_loss             = value.parse().unwrap_or(0),
+        "largestReinforcement"  => stat.largest_reinforcement    = value.parse().unwrap_or(0),
+        "planetsLost"           => stat.planets_lost             = value.parse().unwrap_or(0),
+        "planetsConquered"      => stat.planets_conquered        = value.parse().unwrap_or(0),
+        "planetsDefended"       => stat.planets_defended         = value.parse().unwrap_or(0),
+        "planetsAttacked"       => stat.planets_attacked         = value.parse().unwrap_or(0),
+        "numFleetLost"          => stat.num_fleet_lost           = value.parse().unwrap_or(0),
+        "numFleetReinforced"    => stat.num_fleet_reinforced     = value.parse().unwrap_or(0),
+        "numFleetGenerated"     => stat.num_fleet_generated      = value.parse().unwrap_or(0),
+        "totalTroopsGenerated"  => stat.total_troops_generated   = value.parse().unwrap_or(0),
+        _ => return false,
+    }
+    true
+}
+
+/// Whether a line is a turn record of the replay (`P ...`, `F ...`), those are read by the
+/// frontend and not by the matchmaker.
+fn is_turn_record(parts: &[&str]) -> bool {
+    match parts.first() {
+        Some(tag) => tag.len() == 1 && tag.chars().all(|c| c.is_ascii_uppercase()),
+        None => false,
+    }
+}
\ No newline at end of file
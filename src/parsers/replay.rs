@@ -0,0 +1,77 @@
+//! Turn records of a replay, decoded into frames for the visualizer.
+//!
+//! Both output versions write the same records every turn:
+//!
+//! * `P <x> <y> <size> <fleet size> <color>` for every planet,
+//! * `F <size> <x> <y> [<source planet> <destination planet>] <color>` for every fleet in flight,
+//! * `R <score> <color>` when a score changes and `L ... <color>` when a bot is eliminated.
+//!
+//! A turn starts with its planet records, so a planet record after the turn's fleets or
+//! after as many planets as the map has starts the next frame.
+
+use crate::models::replay_frame::{FleetMovement, PlanetState, ReplayFrame};
+
+pub fn decode_frames(lines: &[String]) -> Vec<ReplayFrame> {
+    let mut frames = vec![];
+    let mut frame = ReplayFrame::default();
+    let mut planets_per_turn: Option<usize> = None;
+
+    for line in lines.iter() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let (tag, fields) = match parts.split_first() {
+            Some((tag, fields)) if !fields.is_empty() => (*tag, fields),
+            _ => continue,
+        };
+        let color = fields[fields.len() - 1].to_string();
+        let numbers: Vec<f64> = fields[..fields.len() - 1].iter().filter_map(|f| f.parse().ok()).collect();
+
+        match tag {
+            "P" => {
+                let turn_complete = !frame.fleets.is_empty()
+                    || !frame.scores.is_empty()
+                    || !frame.eliminated.is_empty()
+                    || planets_per_turn.is_some_and(|n| frame.planets.len() >= n);
+                if turn_complete {
+                    planets_per_turn.get_or_insert(frame.planets.len());
+                    let turn = frames.len() + 1;
+                    frames.push(std::mem::take(&mut frame));
+                    frame.turn = turn;
+                }
+                if let [x, y, size, fleet_size, ..] = numbers.as_slice() {
+                    frame.planets.push(PlanetState {
+                        id: frame.planets.len(),
+                        x: *x,
+                        y: *y,
+                        size: *size,
+                        fleet_size: *fleet_size as i64,
+                        color,
+                    });
+                }
+            },
+            "F" => {
+                if let [size, x, y, rest @ ..] = numbers.as_slice() {
+                    frame.fleets.push(FleetMovement {
+                        size: *size as i64,
+                        x: *x,
+                        y: *y,
+                        source: rest.first().map(|p| *p as usize),
+                        destination: rest.get(1).map(|p| *p as usize),
+                        color,
+                    });
+                }
+            },
+            "R" => {
+                if let [score] = numbers.as_slice() {
+                    frame.scores.insert(color, *score as i32);
+                }
+            },
+            "L" => frame.eliminated.push(color),
+            _ => continue,
+        }
+    }
+
+    if !frame.planets.is_empty() || !frame.fleets.is_empty() {
+        frames.push(frame);
+    }
+    frames
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_achievements::get_achievements_by_team_id,
+    models::achievement::PublicAchievement,
+};
+
+/// The achievements a team earned, in the order they were earned.
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::achievement::PublicAchievement]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/achievements/team/{team_id}")]
+pub async fn achievements_team(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let achievements = match get_achievements_by_team_id(team_id.into_inner()) {
+        Ok(a) => a,
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    };
+
+    // stored once for each member, the team earned each kind once
+    let mut seen = HashSet::new();
+    let achievements = achievements
+        .into_iter()
+        .filter(|a| seen.insert(a.kind))
+        .map(PublicAchievement::from)
+        .collect::<Vec<PublicAchievement>>();
+    HttpResponse::Ok().json(achievements)
+}
\ No newline at end of file
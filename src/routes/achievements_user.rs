@@ -0,0 +1,30 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_achievements::get_achievements_by_user_id,
+    models::achievement::PublicAchievement,
+};
+
+/// The achievements a user earned with any of their teams, for their profile page.
+#[utoipa::path(
+    tag = "users",
+    params(("user_id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::achievement::PublicAchievement]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/achievements/user/{user_id}")]
+pub async fn achievements_user(auth: BearerAuth, user_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_achievements_by_user_id(user_id.into_inner()) {
+        Ok(achievements) => HttpResponse::Ok().json(achievements.into_iter().map(PublicAchievement::from).collect::<Vec<PublicAchievement>>()),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use actix_web::{HttpResponse, get};
+
+/// Swagger UI is loaded from a CDN so it doesn't have to be bundled with the server.
+const SWAGGER_UI_PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8" />
+    <title>Batalja Competition Dashboard API</title>
+    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script>
+        window.ui = SwaggerUIBundle({ url: "openapi.json", dom_id: "#swagger-ui" });
+    </script>
+</body>
+</html>"##;
+
+#[get("/docs")]
+pub async fn api_docs() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(SWAGGER_UI_PAGE)
+}
\ No newline at end of file
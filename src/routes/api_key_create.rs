@@ -0,0 +1,50 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, api_keys::create_api_key},
+    db::operations_api_keys::get_api_keys_by_user_id,
+    models::api_key::{NewApiKey, MAX_API_KEYS_PER_USER},
+};
+
+/// Creates a key of the read-only public API for the requesting user. The key is only
+/// part of this response.
+#[utoipa::path(
+    tag = "api keys",
+    request_body = crate::models::api_key::NewApiKey,
+    responses(
+        (status = 200, description = "Success", body = crate::models::api_key::CreatedApiKey),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/api_key")]
+pub async fn api_key_create(auth: BearerAuth, body: web::Json<NewApiKey>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let new_key = body.into_inner();
+    if new_key.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("API key needs a name");
+    }
+    if new_key.requests_per_minute.unwrap_or(0) < 0 {
+        return HttpResponse::BadRequest().body("Rate limit can't be negative");
+    }
+
+    let active_keys = match get_api_keys_by_user_id(requesting_user.id.clone()) {
+        Ok(keys) => keys.into_iter().filter(|k| !k.revoked).count(),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if active_keys >= MAX_API_KEYS_PER_USER {
+        return HttpResponse::Conflict().body(format!("Revoke a key first, you can have up to {} keys", MAX_API_KEYS_PER_USER));
+    }
+
+    match create_api_key(&requesting_user, new_key) {
+        Ok(created) => HttpResponse::Ok().json(created),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use actix_web::{HttpResponse, get};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_api_keys::get_api_keys_by_user_id,
+    models::api_key::PublicApiKey,
+};
+
+#[utoipa::path(
+    tag = "api keys",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::api_key::PublicApiKey]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/api_key/all")]
+pub async fn api_key_get_all(auth: BearerAuth) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    match get_api_keys_by_user_id(requesting_user.id) {
+        Ok(keys) => HttpResponse::Ok().json(
+            keys
+                .into_iter()
+                .map(PublicApiKey::from)
+                .collect::<Vec<PublicApiKey>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
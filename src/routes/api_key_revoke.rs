@@ -0,0 +1,40 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_api_keys::{get_api_key_by_id, revoke_api_key};
+use crate::models::user::Role;
+
+/// Revokes a key of the public API, keys can be revoked by their owner and by admins.
+#[utoipa::path(
+    tag = "api keys",
+    params(("key_id" = String, Path, description = "API key id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/api_key/{key_id}")]
+pub async fn api_key_revoke(auth: BearerAuth, key_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let api_key = match get_api_key_by_id(key_id.into_inner()) {
+        Ok(k) => k,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if api_key.user_id != requesting_user.id && requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match revoke_api_key(api_key.id) {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_bot::{get_bot_by_id, soft_delete_bot};
+use crate::db::operations_teams::get_team_by_id;
+use crate::models::user::Role;
+
+/// Hides a bot from its team. Games it played keep referring to it and an admin can restore
+/// it. A bot that's selected in one of the team's slots can't be deleted.
+#[utoipa::path(
+    tag = "bots",
+    params(("bot_id" = String, Path, description = "Bot id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/bot/{bot_id}")]
+pub async fn bot_delete(auth: BearerAuth, bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let bot = match get_bot_by_id(bot_id.into_inner()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let team = match get_team_by_id(bot.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner && requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    if team.bot1 == bot.id || team.bot2 == bot.id {
+        return HttpResponse::Conflict().body("Bot is selected by the team");
+    }
+
+    match soft_delete_bot(bot.id) {
+        Ok(0) => HttpResponse::NotFound().finish(),
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, bot_metadata::validate_bot_metadata};
+use crate::db::operations_bot::{get_bot_by_id, set_bot_metadata};
+use crate::db::operations_teams::get_team_by_id;
+use crate::models::bot::{BotMetadata, PublicBot};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BotMetadataData {
+    pub bot_id: String,
+    #[serde(flatten)]
+    pub metadata: BotMetadata,
+}
+
+/// Names and describes one of the team's bots. The name is shown in game results and replays
+/// instead of the bot's id, an empty name shows the file name of the upload again.
+#[utoipa::path(
+    tag = "bots",
+    request_body = BotMetadataData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot::PublicBot),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/bot/metadata")]
+pub async fn bot_metadata(auth: BearerAuth, body: web::Json<BotMetadataData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+    let data = body.into_inner();
+
+    let bot = match get_bot_by_id(data.bot_id) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let team = match get_team_by_id(bot.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let metadata = match validate_bot_metadata(data.metadata) {
+        Ok(m) => m,
+        Err(e) => return HttpResponse::BadRequest().body(e),
+    };
+
+    if let Err(_) = set_bot_metadata(bot.id.clone(), metadata) {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    match get_bot_by_id(bot.id) {
+        Ok(b) => HttpResponse::Ok().json(PublicBot::from(b)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
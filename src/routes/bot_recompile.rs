@@ -0,0 +1,76 @@
+use std::fs;
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, matchmaker_2v2::compile_bot, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    config::settings,
+    db::operations_bot::{get_bot_by_id, set_bot_error},
+    models::{bot::CompileReport, compile_diagnostics::CompileDiagnostics, errors::MatchMakerError, user::Role},
+};
+
+#[utoipa::path(
+    tag = "bots",
+    params(("bot_id" = String, Path, description = "Bot id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot::CompileReport),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/bot/recompile/{bot_id}")]
+pub async fn bot_recompile(auth: BearerAuth, bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Compilation is disabled in safe mode");
+    }
+
+    let bot = match get_bot_by_id(bot_id.into_inner()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    // compiling is unranked work, it yields to a running ranked round
+    let result = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+
+        // start from a clean working directory, leftovers of an earlier compile could hide errors
+        let workdir = settings().paths.bots_workdir.join(&bot.id);
+        if workdir.exists() {
+            fs::remove_dir_all(&workdir).map_err(MatchMakerError::IOError)?;
+        }
+
+        let (error, compiler_output) = match compile_bot(&bot) {
+            Ok(output) => ("".to_string(), output),
+            Err(MatchMakerError::CompileError(output)) => ("Compilation failed".to_string(), output),
+            Err(e) => (e.to_string(), vec![]),
+        };
+        let diagnostics = CompileDiagnostics::parse(&compiler_output);
+        let report = CompileReport {
+            bot_id: bot.id.clone(),
+            success: error.is_empty(),
+            error: error.clone(),
+            compiler_output,
+            diagnostics: diagnostics.clone(),
+        };
+        set_bot_error(bot, error, &diagnostics).map_err(MatchMakerError::DatabaseError)?;
+        Ok::<CompileReport, MatchMakerError>(report)
+    }).await;
+
+    match result {
+        Ok(Ok(r)) => HttpResponse::Ok().json(r),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_bot::{get_bot_by_id, restore_bot};
+use crate::models::bot::PublicBot;
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "bots",
+    params(("bot_id" = String, Path, description = "Bot id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot::PublicBot),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/bot/restore/{bot_id}")]
+pub async fn bot_restore(auth: BearerAuth, bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let bot_id = bot_id.into_inner();
+    match restore_bot(bot_id.clone()) {
+        Ok(0) => return HttpResponse::NotFound().finish(),
+        Ok(_) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    match get_bot_by_id(bot_id) {
+        Ok(b) => HttpResponse::Ok().json(PublicBot::from(b)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, reference_match::run_reference_match, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    db::{operations_bot::get_bot_by_id, operations_teams::get_team_by_id},
+    models::errors::MatchMakerError,
+};
+
+#[utoipa::path(
+    tag = "bots",
+    params(("bot_id" = String, Path, description = "Bot id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::reference_match::ReferenceMatchResult),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/bot/test/{bot_id}")]
+pub async fn bot_test_match(auth: BearerAuth, bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let bot = match get_bot_by_id(bot_id.into_inner()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let team = match get_team_by_id(bot.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if !bot.compile_error.is_empty() {
+        return HttpResponse::BadRequest().body("Bot doesn't compile");
+    }
+
+    // test matches are unranked work, they yield to a running ranked round
+    let result = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+        run_reference_match(&team, &bot)
+    }).await;
+
+    match result {
+        Ok(Ok(r)) => HttpResponse::Ok().json(r),
+        Ok(Err(MatchMakerError::ExecutionDisabled)) => HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
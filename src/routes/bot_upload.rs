@@ -1,17 +1,46 @@
-use std::{path::Path, fs};
+use std::time::Duration;
+use chrono::Local;
 use actix_multipart::form::{tempfile::TempFile, MultipartForm, text::Text};
-use actix_web::{HttpResponse, post};
+use actix_web::{HttpResponse, post, web, http::header::RETRY_AFTER};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
-use chrono::{Local, Timelike, Datelike};
-use zip::ZipArchive;
-use crate::{controllers::{jwt::exchange_token_for_user, matchmaker_2v2::{compile_team_bots, compile_bot}}, models::{bot::{NewBot, PublicBot}, team::BotSelector}, db::{operations_teams::{get_team_by_id, set_team_bot}, operations_bot::{insert_bot, set_bot_error, get_bot_by_id}}};
+use utoipa::ToSchema;
+use crate::{controllers::{jwt::exchange_token_for_user, matchmaker_2v2::{compile_team_bots, compile_bot}, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode, upload_validation::validate_upload, file_handler::store_upload, bot_versions::activate_bot, submission_window::submissions_closed_reason, rate_limit::check_upload_rate, bot_metadata::validate_bot_metadata, smoke_test::{smoke_test_bot, smoke_test_blocks_slot_reason}}, models::{bot::{NewBot, PublicBot, BotMetadata}, team::BotSelector, compile_diagnostics::CompileDiagnostics, smoke_test::SmokeTestStatus}, db::{operations_teams::get_team_by_id, operations_competition::get_competition_by_id, operations_bot::{insert_bot, set_bot_error, get_bot_by_id, set_bot_smoke_test}}};
 
-#[derive(MultipartForm)]
+/// How long an upload waits for the compiler while a ranked round is running. If no slot
+/// frees up in time, the bot is compiled with the next round instead.
+const UPLOAD_COMPILE_WAIT_SECS: u64 = 30;
+
+#[derive(MultipartForm, ToSchema)]
 pub struct BotUploadData {
+    #[schema(value_type = String)]
     team_id: Text<String>,
+    /// ZIP archive with the bot's sources
+    #[schema(value_type = Option<String>, format = Binary)]
     file: Option<TempFile>,
+    /// name the bot is shown under, the archive's file name if not set
+    #[schema(value_type = Option<String>)]
+    name: Option<Text<String>>,
+    #[schema(value_type = Option<String>)]
+    description: Option<Text<String>>,
+    /// `Java` if not set
+    #[schema(value_type = Option<String>)]
+    language: Option<Text<String>>,
 }
 
+#[utoipa::path(
+    tag = "bots",
+    request_body(content = BotUploadData, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot::PublicBot),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 422, description = "Failed validation"),
+        (status = 429, description = "Rate limit reached, see `Retry-After`"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/bot/upload")]
 pub async fn bot_upload(auth: BearerAuth, payload: MultipartForm<BotUploadData>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -32,47 +61,71 @@ pub async fn bot_upload(auth: BearerAuth, payload: MultipartForm<BotUploadData>)
         return HttpResponse::Forbidden().finish();
     }
 
+    if let Err(retry_after) = check_upload_rate(&requesting_user.id, &team.competition_id) {
+        return HttpResponse::TooManyRequests()
+            .insert_header((RETRY_AFTER, retry_after.to_string()))
+            .body("Upload limit reached, try again later");
+    }
+
+    // is the competition accepting submissions
+    let competition = match get_competition_by_id(team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if let Some(reason) = submissions_closed_reason(&competition, Local::now().naive_utc()) {
+        return HttpResponse::Forbidden().body(reason);
+    }
+
+    let metadata = validate_bot_metadata(BotMetadata {
+        name: bot_file_data.name.map(|t| t.0).unwrap_or_default(),
+        description: bot_file_data.description.map(|t| t.0).unwrap_or_default(),
+        language: bot_file_data.language.map(|t| t.0).unwrap_or_default(),
+    });
+    let metadata = match metadata {
+        Ok(m) => m,
+        Err(e) => return HttpResponse::BadRequest().body(e),
+    };
+
     // zip correctly uploaded?
     let bot_file = match bot_file_data.file {
         Some(f) => f,
         None => return HttpResponse::BadRequest().body("Can't extract zip file."),
     };
 
-    // is it a zip?
-    if ZipArchive::new(&bot_file.file).is_err() {
-        return HttpResponse::BadRequest().body("Uploaded file is not a valid ZIP file");
-    }
-
-    
     let filename = match &bot_file.file_name {
         Some(name) => name.to_string(),
         None => "EpicBot.zip".to_string(), // Default name if filename is not provided
     };
 
-
-    let now = Local::now();
-    let time = format!(
-        "{:04}-{:02}-{:02}-{:02}-{:02}-{:02}", 
-        now.year(), 
-        now.month(), 
-        now.day(), 
-        now.hour(), 
-        now.minute(), 
-        now.second()
-    );
-    let save_directory = Path::new("./resources/uploads")
-        .join(team.competition_id.clone())
-        .join(time);
-
-    if let Err(_) = fs::create_dir_all(&save_directory) {
-        return HttpResponse::InternalServerError().body("Failed to create directory");
+    // validate before storing anything, compiling is unranked work and yields to a running
+    // ranked round; if no slot frees up in time the bot is compiled with the next round
+    let upload_path = bot_file.file.path().to_path_buf();
+    let competition_id = team.competition_id.clone();
+    let validation = web::block(move || {
+        let permit = if is_safe_mode() {
+            None
+        } else {
+            acquire_unranked_slot(Some(Duration::from_secs(UPLOAD_COMPILE_WAIT_SECS)))
+        };
+        validate_upload(&upload_path, &competition_id, permit.is_some())
+    }).await;
+    let validation = match validation {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if !validation.valid {
+        return HttpResponse::UnprocessableEntity().json(validation);
     }
 
-    let save_path = save_directory.join(filename);
-    
+    let save_path = match store_upload(bot_file.file.path(), &team.competition_id, &filename) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::InternalServerError().body("Failed to save file"),
+    };
+
     let bot = NewBot { 
         team_id: team.id.clone(),
         source_path: save_path.to_string_lossy().to_string(), 
+        metadata,
     };
 
     let bot = match insert_bot(bot) {
@@ -80,33 +133,59 @@ pub async fn bot_upload(auth: BearerAuth, payload: MultipartForm<BotUploadData>)
         Err(_) => return HttpResponse::InternalServerError().finish(),
     };
 
-    // if team's first bot, set as default bot
-    if team.bot1.eq("") {
-        if let Err(_) = set_team_bot(&team, BotSelector::First, bot.id.clone()) {
-            return HttpResponse::InternalServerError().finish();
-        } 
-    }
-
-    if team.bot2.eq("") {
-        if let Err(_) = set_team_bot(&team, BotSelector::Second, bot.id.clone()) {
-            return HttpResponse::InternalServerError().finish();
-        } 
+    if is_safe_mode() {
+        // in safe mode the bot is only stored, it gets compiled with the next round
+        let _ = set_bot_smoke_test(bot.id.clone(), SmokeTestStatus::Skipped, "Server is in safe mode".to_string());
+    } else {
+        // try if bot compiles and survives its first turns against idle bots (unranked work,
+        // yields to a running ranked round)
+        let bot_to_compile = bot.clone();
+        let compile_result = web::block(move || {
+            let _permit = match acquire_unranked_slot(Some(Duration::from_secs(UPLOAD_COMPILE_WAIT_SECS))) {
+                Some(p) => p,
+                None => {
+                    let _ = set_bot_smoke_test(bot_to_compile.id.clone(), SmokeTestStatus::Skipped, "Server was busy with a round".to_string());
+                    return None;
+                },
+            };
+            let compiled = compile_bot(&bot_to_compile);
+            if compiled.is_ok() {
+                smoke_test_bot(&bot_to_compile);
+            }
+            Some(compiled)
+        }).await;
+        match compile_result {
+            Ok(Some(Err(e))) => {
+                let _ = set_bot_error(bot.clone(), e.to_string(), &CompileDiagnostics::from_error(&e));
+                let _ = set_bot_smoke_test(bot.id.clone(), SmokeTestStatus::Skipped, "Bot doesn't compile".to_string());
+            },
+            Err(_) => {
+                let _ = set_bot_smoke_test(bot.id.clone(), SmokeTestStatus::Skipped, "Smoke test couldn't run".to_string());
+            },
+            _ => (),
+        }
     }
 
-    if let Err(_) = bot_file.file.persist(save_path) {
-        return HttpResponse::InternalServerError().body("Failed to save file")
-    }
-
-    // try if bot compiles
-    if let Err(e) = compile_bot(&bot) {
-        let _ = set_bot_error(bot.clone(), e.to_string());
-    }
-
-    // refetch the bot (fetch potential compilation errors)
+    // refetch the bot (fetch potential compilation errors and the smoke test verdict)
     let bot = match get_bot_by_id(bot.id) {
         Ok(b) => b,
         Err(_) => return HttpResponse::InternalServerError().finish(),
     };
+
+    // if team's first bot, set as default bot, unless it failed its smoke test
+    if smoke_test_blocks_slot_reason(&bot).is_none() {
+        if team.bot1.eq("") {
+            if let Err(_) = activate_bot(&team, BotSelector::First, &bot) {
+                return HttpResponse::InternalServerError().finish();
+            } 
+        }
+
+        if team.bot2.eq("") {
+            if let Err(_) = activate_bot(&team, BotSelector::Second, &bot) {
+                return HttpResponse::InternalServerError().finish();
+            } 
+        }
+    }
     
     HttpResponse::Ok().json(PublicBot::from(bot))
 }
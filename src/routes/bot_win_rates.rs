@@ -10,6 +10,17 @@ use crate::{
     }, models::{game_2v2::Game2v2, user::Role},
 };
 
+#[utoipa::path(
+    tag = "bots",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = Object),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/bots/wr/{team_id}")]
 pub async fn bots_win_rate(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -0,0 +1,59 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, challenges::play_challenge, safe_mode::is_safe_mode},
+    db::{operations_challenges::{get_challenge_by_id, transition_challenge}, operations_teams::get_team_by_id},
+    models::challenge::{ChallengeStatus, PublicChallenge},
+};
+
+/// Accepts a challenge on behalf of the challenged team. The game is played in the background,
+/// its outcome and replay show up on the challenge once it's done.
+#[utoipa::path(
+    tag = "challenges",
+    params(("id" = String, Path, description = "Challenge id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::challenge::PublicChallenge),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Challenge was already answered"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/challenge/accept/{id}")]
+pub async fn challenge_accept(auth: BearerAuth, id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let mut challenge = match get_challenge_by_id(id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_challenged = match get_team_by_id(challenge.challenged_id.clone()) {
+        Ok(t) => requesting_user.id == t.owner || requesting_user.id == t.partner,
+        Err(_) => false,
+    };
+    if !is_challenged {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match transition_challenge(challenge.id.clone(), ChallengeStatus::Pending, ChallengeStatus::Running) {
+        Ok(true) => (),
+        Ok(false) => return HttpResponse::Conflict().body("Challenge was already answered"),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    }
+
+    challenge.status = ChallengeStatus::Running;
+    let played = challenge.clone();
+    // the game blocks until it's played, which may wait for a running ranked round
+    std::thread::spawn(move || play_challenge(played));
+    HttpResponse::Ok().json(PublicChallenge::from(challenge))
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, challenges::may_send_challenge, safe_mode::is_safe_mode},
+    db::{operations_challenges::{has_pending_challenge_between, insert_challenge}, operations_teams::get_team_by_id},
+    models::challenge::{Challenge, NewChallenge, PublicChallenge},
+};
+
+/// Challenges another team of the competition to a friendly, unrated game. The game is played
+/// once the challenged team accepts.
+#[utoipa::path(
+    tag = "challenges",
+    request_body = crate::models::challenge::NewChallenge,
+    responses(
+        (status = 200, description = "Success", body = crate::models::challenge::PublicChallenge),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "A challenge between the teams is already pending"),
+        (status = 429, description = "The team sent too many challenges today"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/challenge")]
+pub async fn challenge_create(auth: BearerAuth, body: web::Json<NewChallenge>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let new_challenge = body.into_inner();
+    let challenger = match get_team_by_id(new_challenge.challenger_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+    let challenged = match get_team_by_id(new_challenge.challenged_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != challenger.owner && requesting_user.id != challenger.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if challenger.id == challenged.id {
+        return HttpResponse::BadRequest().body("A team can't challenge itself");
+    }
+
+    if challenger.competition_id != challenged.competition_id {
+        return HttpResponse::BadRequest().body("Teams are from different competitions");
+    }
+
+    if challenger.bot1.is_empty() || challenger.bot2.is_empty() {
+        return HttpResponse::BadRequest().body("Team has no bots selected");
+    }
+
+    match has_pending_challenge_between(challenger.id.clone(), challenged.id.clone()) {
+        Ok(true) => return HttpResponse::Conflict().body("A challenge between the teams is already pending"),
+        Ok(false) => (),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    }
+
+    match may_send_challenge(challenger.id.clone()) {
+        Ok(true) => (),
+        Ok(false) => return HttpResponse::TooManyRequests().body("Team sent too many challenges today"),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    }
+
+    match insert_challenge(Challenge::new(challenger.competition_id, new_challenge)) {
+        Ok(challenge) => HttpResponse::Ok().json(PublicChallenge::from(challenge)),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
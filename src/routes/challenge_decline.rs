@@ -0,0 +1,49 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_challenges::{get_challenge_by_id, transition_challenge}, operations_teams::get_team_by_id},
+    models::challenge::ChallengeStatus,
+};
+
+/// Declines a pending challenge. The challenged team declines it, the challenger withdraws it.
+#[utoipa::path(
+    tag = "challenges",
+    params(("id" = String, Path, description = "Challenge id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Challenge was already answered"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/challenge/decline/{id}")]
+pub async fn challenge_decline(auth: BearerAuth, id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let challenge = match get_challenge_by_id(id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = [&challenge.challenger_id, &challenge.challenged_id].iter().any(|team_id| {
+        match get_team_by_id(team_id.to_string()) {
+            Ok(t) => requesting_user.id == t.owner || requesting_user.id == t.partner,
+            Err(_) => false,
+        }
+    });
+    if !is_member {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match transition_challenge(challenge.id, ChallengeStatus::Pending, ChallengeStatus::Declined) {
+        Ok(true) => HttpResponse::Ok().finish(),
+        Ok(false) => HttpResponse::Conflict().body("Challenge was already answered"),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
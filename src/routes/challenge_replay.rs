@@ -0,0 +1,53 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, file_handler::read_replay},
+    db::{operations_challenges::get_challenge_by_id, operations_teams::get_team_by_id},
+    models::user::Role,
+};
+
+/// The replay of a challenge's game, only available to the two teams.
+#[utoipa::path(
+    tag = "challenges",
+    params(("id" = String, Path, description = "Challenge id")),
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/challenge/replay/{id}")]
+pub async fn challenge_replay(auth: BearerAuth, id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let challenge = match get_challenge_by_id(id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = [&challenge.challenger_id, &challenge.challenged_id].iter().any(|team_id| {
+        match get_team_by_id(team_id.to_string()) {
+            Ok(t) => requesting_user.id == t.owner || requesting_user.id == t.partner,
+            Err(_) => false,
+        }
+    });
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if challenge.log_file_path.is_empty() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match read_replay(&challenge.log_file_path) {
+        Ok(contents) => HttpResponse::Ok()
+            .content_type("application/text; charset=utf-8")
+            .body(contents),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
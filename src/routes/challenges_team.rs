@@ -0,0 +1,42 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_challenges::get_challenges_by_team_id, operations_teams::get_team_by_id},
+    models::{challenge::PublicChallenge, user::Role},
+};
+
+/// The challenges a team sent and received, newest first.
+#[utoipa::path(
+    tag = "challenges",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::challenge::PublicChallenge]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/challenges/{team_id}")]
+pub async fn challenges_team(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = requesting_user.id == team.owner || requesting_user.id == team.partner;
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_challenges_by_team_id(team.id) {
+        Ok(challenges) => HttpResponse::Ok().json(challenges.into_iter().map(PublicChallenge::from).collect::<Vec<PublicChallenge>>()),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
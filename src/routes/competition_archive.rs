@@ -0,0 +1,58 @@
+use std::thread;
+
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::Local;
+use tracing::error;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, competition_archive::archive_competition},
+    db::operations_competition::get_competition_by_id,
+    models::user::Role,
+};
+
+/// Starts exporting a finished competition in the background. Once the export is written
+/// the competition is read-only and the archive can be downloaded.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 202, description = "Accepted, runs in the background"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/archive/{comp_id}")]
+pub async fn competition_archive(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if competition.archived {
+        return HttpResponse::Conflict().body("Competition is already archived");
+    }
+    if competition.end > Local::now().naive_utc() {
+        return HttpResponse::BadRequest().body("Only finished competitions can be archived");
+    }
+
+    thread::spawn(move || {
+        if let Err(e) = archive_competition(competition) {
+            error!("Failed archiving competition: {:?}", e);
+        }
+    });
+
+    HttpResponse::Accepted().finish()
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::{fs::File, io::Read};
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_competition::get_competition_by_id,
+    models::user::Role,
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "The archive", content_type = "application/zip"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/archive/{comp_id}")]
+pub async fn competition_archive_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    // archiving runs in the background, the path is only set once it's done
+    if !competition.archived || competition.archive_path.is_empty() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let mut file = match File::open(&competition.archive_path) {
+        Ok(file) => file,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let mut buffer = Vec::new();
+    if file.read_to_end(&mut buffer).is_err() {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header(("Content-Disposition", format!("attachment; filename=\"{}.zip\"", competition.id)))
+        .body(buffer)
+}
\ No newline at end of file
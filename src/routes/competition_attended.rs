@@ -5,6 +5,16 @@ use crate::db::operations_competition::get_competitions_by_ids;
 use crate::db::operations_teams::get_team_by_student;
 use crate::models::competition::PublicCompetition;
 
+#[utoipa::path(
+    tag = "competitions",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::competition::PublicCompetition]),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/competition/attended")]
 pub async fn competition_attended(auth: BearerAuth) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -0,0 +1,41 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_audit_log::get_audit_entries_by_competition_id,
+    models::{audit_log::PublicAuditEntry, user::Role},
+};
+
+/// Changes admins made to the competition's results, newest first.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::audit_log::PublicAuditEntry]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/audit/{comp_id}")]
+pub async fn competition_audit_log(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_audit_entries_by_competition_id(comp_id.into_inner()) {
+        Ok(entries) => HttpResponse::Ok().json(
+            entries
+                .into_iter()
+                .map(PublicAuditEntry::from)
+                .collect::<Vec<PublicAuditEntry>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
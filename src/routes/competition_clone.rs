@@ -0,0 +1,97 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::{get_competition_by_id, insert_competition_from_template};
+use crate::db::operations_validation_rules::{get_validation_rules_by_competition_id, upsert_validation_rules};
+use crate::db::operations_pairing_constraints::{get_pairing_constraints_by_competition_id, upsert_pairing_constraints};
+use crate::models::competition::{CompetitionClone, PublicCompetition};
+use crate::models::user::Role;
+use crate::models::validation_rules::NewValidationRules;
+use crate::models::pairing_constraints::NewPairingConstraints;
+
+/// Creates a competition with the configuration of an existing one, including its
+/// validation rules and pairing constraints, and new dates.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::competition::CompetitionClone,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/clone")]
+pub async fn competition_clone(auth: BearerAuth, body: web::Json<CompetitionClone>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let clone = body.into_inner();
+    if clone.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Competition name can't be empty");
+    }
+    if clone.end <= clone.start {
+        return HttpResponse::BadRequest().body("Competition has to end after it starts");
+    }
+    if let (Some(open), Some(close)) = (clone.registration_open, clone.registration_close) {
+        if close < open {
+            return HttpResponse::BadRequest().body("Registration can't close before it opens");
+        }
+    }
+
+    let template = match get_competition_by_id(clone.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let rules = match get_validation_rules_by_competition_id(template.id.clone()) {
+        Ok(r) => r,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let constraints = match get_pairing_constraints_by_competition_id(template.id.clone()) {
+        Ok(c) => c,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let competition = match insert_competition_from_template(template, clone) {
+        Ok(c) => c,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    if let Some(rules) = rules {
+        let copied_rules = NewValidationRules {
+            competition_id: competition.id.clone(),
+            forbidden_apis: rules.forbidden_apis,
+            allowed_languages: rules.allowed_languages,
+            max_size_kb: Some(rules.max_size_kb),
+            grace_hours: Some(rules.grace_hours),
+        };
+        if let Err(e) = upsert_validation_rules(copied_rules) {
+            return HttpResponse::InternalServerError().json(e.to_string());
+        }
+    }
+
+    if let Some(constraints) = constraints {
+        let copied_constraints = NewPairingConstraints {
+            competition_id: competition.id.clone(),
+            rematch_window: Some(constraints.rematch_window),
+            rematch_weight: Some(constraints.rematch_weight),
+            lab_group_weight: Some(constraints.lab_group_weight),
+        };
+        if let Err(e) = upsert_pairing_constraints(copied_constraints) {
+            return HttpResponse::InternalServerError().json(e.to_string());
+        }
+    }
+
+    HttpResponse::Ok().json(PublicCompetition::from(competition))
+}
\ No newline at end of file
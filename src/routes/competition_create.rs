@@ -5,6 +5,17 @@ use crate::db::operations_competition::insert_competition;
 use crate::models::competition::{NewCompetition, PublicCompetition};
 use crate::models::user::Role;
 
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::competition::NewCompetition,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/competition")]
 pub async fn competition_create(auth: BearerAuth, body: web::Json<NewCompetition>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -0,0 +1,36 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::soft_delete_competition;
+use crate::models::user::Role;
+
+/// Hides a competition, its teams and games are kept and it can be restored.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/competition/{comp_id}")]
+pub async fn competition_delete(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match soft_delete_competition(comp_id.into_inner()) {
+        Ok(0) => HttpResponse::NotFound().finish(),
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_discord_channels::upsert_discord_channel, operations_competition::get_competition_by_id},
+    models::{discord_channel::{NewDiscordChannel, PublicDiscordChannel, MAX_STANDINGS_SIZE}, user::Role},
+};
+
+/// Sets the Discord channel the competition's round summaries are posted to.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::discord_channel::NewDiscordChannel,
+    responses(
+        (status = 200, description = "Success", body = crate::models::discord_channel::PublicDiscordChannel),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/discord")]
+pub async fn competition_discord(auth: BearerAuth, body: web::Json<NewDiscordChannel>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let channel = body.into_inner();
+    if !channel.webhook_url.trim().starts_with("https://") {
+        return HttpResponse::BadRequest().body("Webhook url must be an https url");
+    }
+    if let Some(size) = channel.standings_size {
+        if !(1..=MAX_STANDINGS_SIZE).contains(&size) {
+            return HttpResponse::BadRequest().body(format!("Standings size must be between 1 and {}", MAX_STANDINGS_SIZE));
+        }
+    }
+
+    if get_competition_by_id(channel.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match upsert_discord_channel(channel) {
+        Ok(c) => HttpResponse::Ok().json(PublicDiscordChannel::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_discord_channels::get_discord_channel_by_competition_id,
+    models::{discord_channel::PublicDiscordChannel, user::Role},
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::discord_channel::PublicDiscordChannel),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/discord/{comp_id}")]
+pub async fn competition_discord_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    // anyone with the webhook url can post to the channel
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_discord_channel_by_competition_id(comp_id.into_inner()) {
+        Ok(Some(channel)) => HttpResponse::Ok().json(PublicDiscordChannel::from(channel)),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
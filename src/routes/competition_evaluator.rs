@@ -0,0 +1,61 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, evaluator_artifact::resolve_evaluator};
+use crate::db::operations_competition::set_competition_evaluator;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EvaluatorData {
+    pub competition_id: String,
+    /// path or URL of the JAR, empty for the default evaluator
+    pub source: String,
+    pub sha256: Option<String>,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = EvaluatorData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/evaluator")]
+pub async fn competition_evaluator(auth: BearerAuth, body: web::Json<EvaluatorData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let data = body.into_inner();
+    let source = data.source.trim().to_string();
+    let sha256 = data.sha256.unwrap_or_default().trim().to_lowercase();
+
+    if !sha256.is_empty() && (sha256.len() != 64 || !sha256.chars().all(|c| c.is_ascii_hexdigit())) {
+        return HttpResponse::BadRequest().body("sha256 has to be 64 hex characters");
+    }
+
+    // fetch and verify the artifact now, so a broken reference doesn't fail the next round
+    let (check_source, check_sha256) = (source.clone(), sha256.clone());
+    match web::block(move || resolve_evaluator(&check_source, &check_sha256)).await {
+        Ok(Ok(_)) => (),
+        Ok(Err(e)) => return HttpResponse::BadRequest().body(e.to_string()),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    match set_competition_evaluator(data.competition_id, source, sha256) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
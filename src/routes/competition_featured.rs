@@ -0,0 +1,32 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    controllers::featured::featured_games,
+    db::operations_competition::get_competition_by_id,
+    models::featured_game::FeaturedQuery,
+};
+
+/// The most interesting games of a round, for the projector in the lecture hall.
+#[utoipa::path(
+    tag = "spectators",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        crate::models::featured_game::FeaturedQuery,
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::featured_game::FeaturedGames),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/featured")]
+pub async fn competition_featured(comp_id: web::Path<String>, query: web::Query<FeaturedQuery>) -> HttpResponse {
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match featured_games(&competition, query.round) {
+        Ok(featured) => HttpResponse::Ok().json(featured),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_competition::get_competition_by_id, operations_game2v2::get_games_page, operations_teams::get_team_by_student},
+    models::{
+        game_2v2::PublicGame2v2,
+        game_listing::{GameCursor, GameListFilter, GameListQuery, GamePage, GameSort, DEFAULT_GAMES_PER_PAGE, MAX_GAMES_PER_PAGE},
+        user::Role,
+    },
+};
+
+/// Games of a competition a page at a time. Private games are only listed for admins and
+/// the teams that played them.
+#[utoipa::path(
+    tag = "games",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        crate::models::game_listing::GameListQuery,
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::game_listing::GamePage),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security((), ("bearer" = [])),
+)]
+#[get("/competitions/{comp_id}/games")]
+pub async fn competition_games(auth: Option<BearerAuth>, comp_id: web::Path<String>, query: web::Query<GameListQuery>) -> HttpResponse {
+    let query = query.into_inner();
+
+    let sort = match GameSort::try_from(query.sort.as_deref().unwrap_or("created")) {
+        Ok(s) => s,
+        Err(e) => return HttpResponse::BadRequest().body(e),
+    };
+    let descending = match query.order.as_deref().unwrap_or("desc") {
+        "desc" => true,
+        "asc" => false,
+        o => return HttpResponse::BadRequest().body(format!("Unknown order: {}", o)),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_GAMES_PER_PAGE);
+    if !(1..=MAX_GAMES_PER_PAGE).contains(&limit) {
+        return HttpResponse::BadRequest().body(format!("limit has to be between 1 and {}", MAX_GAMES_PER_PAGE));
+    }
+    let after = match query.cursor.as_deref().map(|c| GameCursor::decode(c, sort)) {
+        Some(Ok(c)) => Some(c),
+        Some(Err(e)) => return HttpResponse::BadRequest().body(e),
+        None => None,
+    };
+
+    let visible_to_teams = match auth {
+        None => Some(vec![]),
+        Some(token) => match exchange_token_for_user(token) {
+            None => return HttpResponse::Unauthorized().finish(),
+            Some(u) if u.role == Role::Admin => None,
+            Some(u) => match get_team_by_student(u) {
+                Ok(teams) => Some(teams.into_iter().map(|t| t.id).collect()),
+                Err(_) => return HttpResponse::InternalServerError().finish(),
+            },
+        },
+    };
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let mut games = match get_games_page(GameListFilter {
+        competition_id: competition.id,
+        round: query.round,
+        team_id: query.team_id,
+        winner_id: query.winner_id,
+        visible_to_teams,
+        sort,
+        descending,
+        after,
+        limit,
+    }) {
+        Ok(g) => g,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let next_cursor = if games.len() as i64 > limit {
+        games.truncate(limit as usize);
+        games.last().map(|g| GameCursor::after(sort, g).encode())
+    } else {
+        None
+    };
+
+    HttpResponse::Ok().json(GamePage {
+        games: games.into_iter().map(PublicGame2v2::from).collect(),
+        next_cursor,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{
+        operations_bot::get_bot_by_id,
+        operations_competition::get_competition_by_id,
+        operations_ghost_teams::{insert_ghost_team, is_ghost_team_entered},
+        operations_published_bots::get_published_bot_by_id,
+    },
+    models::{ghost_team::{NewGhostTeam, PublicGhostTeam, SqlGhostTeam}, user::Role},
+};
+
+/// Enters a bot from the gallery into a competition as a ghost team. It's paired in the
+/// competition's rounds like any team, but its games are unrated and it isn't ranked.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::ghost_team::NewGhostTeam,
+    responses(
+        (status = 200, description = "Success", body = crate::models::ghost_team::PublicGhostTeam),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/ghost")]
+pub async fn competition_ghost_create(auth: BearerAuth, body: web::Json<NewGhostTeam>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let new_ghost_team = body.into_inner();
+    if get_competition_by_id(new_ghost_team.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let published_bot = match get_published_bot_by_id(new_ghost_team.published_bot_id.clone()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+    if published_bot.competition_id == new_ghost_team.competition_id {
+        return HttpResponse::BadRequest().body("Bot was published from this competition");
+    }
+    // the ghost plays with the bot the team uploaded, it has to still be there
+    if get_bot_by_id(published_bot.bot_id.clone()).is_err() {
+        return HttpResponse::NotFound().body("Published bot's files are gone");
+    }
+
+    match is_ghost_team_entered(new_ghost_team.competition_id.clone(), published_bot.id.clone()) {
+        Ok(true) => return HttpResponse::Conflict().body("Bot already plays in this competition"),
+        Ok(false) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    match insert_ghost_team(SqlGhostTeam::new(new_ghost_team, &published_bot)) {
+        Ok(ghost_team) => HttpResponse::Ok().json(PublicGhostTeam::from(ghost_team)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
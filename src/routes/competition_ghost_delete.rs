@@ -0,0 +1,36 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_ghost_teams::delete_ghost_team;
+use crate::models::user::Role;
+
+/// Takes a ghost team out of the competition's next rounds, its games stay.
+#[utoipa::path(
+    tag = "competitions",
+    params(("ghost_id" = String, Path, description = "Ghost team id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/competition/ghost/{ghost_id}")]
+pub async fn competition_ghost_delete(auth: BearerAuth, ghost_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match delete_ghost_team(ghost_id.into_inner()) {
+        Ok(0) => HttpResponse::NotFound().finish(),
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    db::operations_ghost_teams::get_ghost_teams_by_competition_id,
+    models::ghost_team::PublicGhostTeam,
+};
+
+/// Ghost teams playing a competition's rounds, so their games can be told apart from ranked
+/// opponents.
+#[utoipa::path(
+    tag = "spectators",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::ghost_team::PublicGhostTeam]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/ghosts")]
+pub async fn competition_ghosts(comp_id: web::Path<String>) -> HttpResponse {
+    match get_ghost_teams_by_competition_id(comp_id.into_inner()) {
+        Ok(ghost_teams) => HttpResponse::Ok().json(
+            ghost_teams
+                .into_iter()
+                .map(PublicGhostTeam::from)
+                .collect::<Vec<PublicGhostTeam>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, grades::update_grade_mapping},
+    db::operations_competition::get_competition_by_id,
+    models::{grade_mapping::{GradeMapping, NewGradeMapping, PublicGradeMapping}, user::Role},
+};
+
+/// Sets how the final standings of a competition map to a course grade component. Every change
+/// is recorded in the audit log.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::grade_mapping::NewGradeMapping,
+    responses(
+        (status = 200, description = "Success", body = crate::models::grade_mapping::PublicGradeMapping),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/grades/mapping")]
+pub async fn competition_grade_mapping(auth: BearerAuth, body: web::Json<NewGradeMapping>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let mapping = GradeMapping::new(body.into_inner(), requesting_user.id.clone());
+    if let Some(reason) = mapping.invalid_reason() {
+        return HttpResponse::BadRequest().body(reason);
+    }
+
+    if get_competition_by_id(mapping.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match update_grade_mapping(mapping, &requesting_user) {
+        Ok(mapping) => HttpResponse::Ok().json(PublicGradeMapping::from(mapping)),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_grade_mappings::get_grade_mapping_by_competition_id,
+    models::{grade_mapping::PublicGradeMapping, user::Role},
+};
+
+/// The grade mapping of a competition.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::grade_mapping::PublicGradeMapping),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/grades/mapping/{comp_id}")]
+pub async fn competition_grade_mapping_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_grade_mapping_by_competition_id(comp_id.into_inner()) {
+        Ok(Some(mapping)) => HttpResponse::Ok().json(PublicGradeMapping::from(mapping)),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, grades::grade_preview},
+    db::{operations_competition::get_competition_by_id, operations_grade_mappings::get_grade_mapping_by_competition_id},
+    models::{grade_mapping::GradePreview, user::Role},
+};
+
+/// Previews the grades the competition's current standings would give. Admins see every
+/// student, students only see their own grade.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::grade_mapping::GradePreview]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found, or no grade mapping set"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/grades/{comp_id}")]
+pub async fn competition_grades(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let mapping = match get_grade_mapping_by_competition_id(competition.id.clone()) {
+        Ok(Some(m)) => m,
+        Ok(None) => return HttpResponse::NotFound().body("No grade mapping set"),
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    };
+
+    let previews = match grade_preview(&competition, &mapping) {
+        Ok(p) => p,
+        Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+    };
+
+    let previews = previews
+        .into_iter()
+        .filter(|p| requesting_user.role == Role::Admin || p.user_id == requesting_user.id)
+        .collect::<Vec<GradePreview>>();
+    HttpResponse::Ok().json(previews)
+}
\ No newline at end of file
@@ -2,6 +2,14 @@ use actix_web::{HttpResponse, get, web};
 use crate::db::operations_competition::get_competition_by_id;
 use crate::models::competition::PublicCompetition;
 
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 500, description = "Server error"),
+    ),
+)]
 #[get("/competition/{comp_id}")]
 pub async fn competition_id(comp_id: web::Path<String>) -> HttpResponse {
     match get_competition_by_id(comp_id.into_inner()) {
@@ -0,0 +1,19 @@
+use actix_web::{HttpResponse, get, web};
+use crate::controllers::ladder::ladder_overview;
+
+/// The staff bots of a competition's ladder and the teams ranked by their ladder rating.
+#[utoipa::path(
+    tag = "spectators",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::ladder::LadderOverview),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/ladder")]
+pub async fn competition_ladder(comp_id: web::Path<String>) -> HttpResponse {
+    match ladder_overview(comp_id.into_inner()) {
+        Ok(overview) => HttpResponse::Ok().json(overview),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
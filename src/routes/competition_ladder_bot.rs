@@ -0,0 +1,66 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, ladder::add_ladder_bot, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    db::{operations_bot::get_bot_by_id, operations_competition::get_competition_by_id},
+    models::{ladder::{NewLadderBot, PublicLadderBot}, errors::MatchMakerError, user::Role},
+};
+
+/// Adds a staff bot to a competition's ladder, made from an already uploaded bot.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::ladder::NewLadderBot,
+    responses(
+        (status = 200, description = "Success", body = crate::models::ladder::PublicLadderBot),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/ladder/bot")]
+pub async fn competition_ladder_bot(auth: BearerAuth, body: web::Json<NewLadderBot>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let new_ladder_bot = body.into_inner();
+    if new_ladder_bot.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Ladder bot needs a name");
+    }
+
+    if get_competition_by_id(new_ladder_bot.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let bot = match get_bot_by_id(new_ladder_bot.bot_id.clone()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    // compiling is unranked work, yields to a running ranked round
+    let added = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+        add_ladder_bot(&bot, new_ladder_bot)
+    }).await;
+
+    match added {
+        Ok(Ok(ladder_bot)) => HttpResponse::Ok().json(PublicLadderBot::from(ladder_bot)),
+        Ok(Err(MatchMakerError::ExecutionDisabled)) => HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode"),
+        Ok(Err(MatchMakerError::CompileError(_))) => HttpResponse::BadRequest().body("Bot doesn't compile"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    controllers::leaderboard::{build_leaderboard, sort_leaderboard},
+    db::operations_competition::get_competition_by_id,
+    models::leaderboard::{
+        LeaderboardQuery, LeaderboardSort, LeaderboardPage,
+        DEFAULT_LEADERBOARD_PER_PAGE, MAX_LEADERBOARD_PER_PAGE,
+    },
+};
+
+#[utoipa::path(
+    tag = "spectators",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        crate::models::leaderboard::LeaderboardQuery,
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::leaderboard::LeaderboardPage),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/leaderboard")]
+pub async fn competition_leaderboard(
+    comp_id: web::Path<String>,
+    query: Result<web::Query<LeaderboardQuery>, actix_web::Error>,
+) -> HttpResponse {
+    let competition_id = comp_id.into_inner();
+    // a negative or non-numeric page is rejected here instead of by the default handler
+    let query = match query {
+        Ok(q) => q.into_inner(),
+        Err(e) => return HttpResponse::BadRequest().body(e.to_string()),
+    };
+
+    let sort = match LeaderboardSort::try_from(query.sort.as_deref().unwrap_or("rank")) {
+        Ok(s) => s,
+        Err(e) => return HttpResponse::BadRequest().body(e),
+    };
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_LEADERBOARD_PER_PAGE);
+    if page == 0 || per_page == 0 || per_page > MAX_LEADERBOARD_PER_PAGE {
+        return HttpResponse::BadRequest().body(format!(
+            "page has to be at least 1 and per_page between 1 and {}", 
+            MAX_LEADERBOARD_PER_PAGE
+        ));
+    }
+
+    let competition = match get_competition_by_id(competition_id) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let mut entries = match build_leaderboard(&competition) {
+        Ok(e) => e,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    sort_leaderboard(&mut entries, sort);
+
+    let total_teams = entries.len();
+    let entries = entries
+        .into_iter()
+        .skip((page - 1).saturating_mul(per_page))
+        .take(per_page)
+        .collect();
+
+    HttpResponse::Ok().json(LeaderboardPage {
+        competition_id: competition.id,
+        scoring_system: competition.scoring_system,
+        page,
+        per_page,
+        total_teams,
+        entries,
+    })
+}
\ No newline at end of file
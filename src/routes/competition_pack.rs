@@ -3,6 +3,15 @@ use std::{fs::File, io::Read};
 use actix_web::{HttpResponse, get, web};
 use crate::db::operations_competition::get_competition_by_id;
 
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "The archive", content_type = "application/zip"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
 #[get("/competition/pack/{comp_id}")]
 pub async fn competition_pack(comp_id: web::Path<String>) -> HttpResponse {
     let competition = match get_competition_by_id(comp_id.into_inner()) {
@@ -0,0 +1,48 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_pairing_constraints::upsert_pairing_constraints, operations_competition::get_competition_by_id},
+    models::{pairing_constraints::{NewPairingConstraints, PublicPairingConstraints}, user::Role},
+};
+
+/// Replaces the pairing constraints of a competition, they apply from the next round on.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::pairing_constraints::NewPairingConstraints,
+    responses(
+        (status = 200, description = "Success", body = crate::models::pairing_constraints::PublicPairingConstraints),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/pairing")]
+pub async fn competition_pairing(auth: BearerAuth, body: web::Json<NewPairingConstraints>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let constraints = body.into_inner();
+    let values = [constraints.rematch_window, constraints.rematch_weight, constraints.lab_group_weight];
+    if values.iter().any(|v| v.unwrap_or(0) < 0) {
+        return HttpResponse::BadRequest().body("Rematch window and weights can't be negative");
+    }
+
+    if get_competition_by_id(constraints.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match upsert_pairing_constraints(constraints) {
+        Ok(c) => HttpResponse::Ok().json(PublicPairingConstraints::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_pairing_constraints::get_pairing_constraints_by_competition_id,
+    models::pairing_constraints::PublicPairingConstraints,
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::pairing_constraints::PublicPairingConstraints),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/pairing/{comp_id}")]
+pub async fn competition_pairing_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_pairing_constraints_by_competition_id(comp_id.into_inner()) {
+        Ok(Some(constraints)) => HttpResponse::Ok().json(PublicPairingConstraints::from(constraints)),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
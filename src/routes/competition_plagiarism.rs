@@ -0,0 +1,54 @@
+use std::thread;
+
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use tracing::error;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, plagiarism::analyze_competition},
+    db::operations_competition::get_competition_by_id,
+    models::{plagiarism::{PlagiarismRequest, DEFAULT_PLAGIARISM_THRESHOLD}, user::Role},
+};
+
+/// Starts comparing the sources of all bots of a competition in the background. The report
+/// can be fetched once the analysis is done.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::plagiarism::PlagiarismRequest,
+    responses(
+        (status = 202, description = "Accepted, runs in the background"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/plagiarism")]
+pub async fn competition_plagiarism(auth: BearerAuth, body: web::Json<PlagiarismRequest>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let request = body.into_inner();
+    let threshold = request.threshold.unwrap_or(DEFAULT_PLAGIARISM_THRESHOLD);
+    if !(0.0..=1.0).contains(&threshold) {
+        return HttpResponse::BadRequest().body("Threshold has to be between 0 and 1");
+    }
+
+    if get_competition_by_id(request.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    thread::spawn(move || {
+        if let Err(e) = analyze_competition(request.competition_id, threshold) {
+            error!("Failed analyzing bots for plagiarism: {:?}", e);
+        }
+    });
+
+    HttpResponse::Accepted().finish()
+}
\ No newline at end of file
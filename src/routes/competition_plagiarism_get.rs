@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{
+        operations_plagiarism::{get_latest_plagiarism_report, get_plagiarism_pairs_by_report_id},
+        operations_teams::get_teams_by_competition_id,
+    },
+    models::{plagiarism::{PublicPlagiarismPair, PublicPlagiarismReport}, user::Role},
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("competition_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::plagiarism::PublicPlagiarismReport),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/plagiarism/{competition_id}")]
+pub async fn competition_plagiarism_get(auth: BearerAuth, competition_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition_id = competition_id.into_inner();
+    let report = match get_latest_plagiarism_report(competition_id.clone()) {
+        Ok(Some(r)) => r,
+        Ok(None) => return HttpResponse::NotFound().finish(),
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let pairs = match get_plagiarism_pairs_by_report_id(report.id.clone()) {
+        Ok(p) => p,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let team_names: HashMap<String, String> = match get_teams_by_competition_id(competition_id) {
+        Ok(teams) => teams.into_iter().map(|t| (t.id, t.name)).collect(),
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let pairs = pairs
+        .into_iter()
+        .map(|p| {
+            let team1_name = team_names.get(&p.team1_id).cloned().unwrap_or_default();
+            let team2_name = team_names.get(&p.team2_id).cloned().unwrap_or_default();
+            PublicPlagiarismPair::new(p, team1_name, team2_name)
+        })
+        .collect();
+
+    HttpResponse::Ok().json(PublicPlagiarismReport::new(report, pairs))
+}
\ No newline at end of file
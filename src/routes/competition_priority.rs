@@ -0,0 +1,51 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::set_competition_priority;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PriorityData {
+    pub competition_id: String,
+    /// a competition with priority 2 gets twice the games of one with priority 1 while their
+    /// rounds run at the same time
+    pub priority: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = PriorityData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/priority")]
+pub async fn competition_priority(auth: BearerAuth, body: web::Json<PriorityData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let data = body.into_inner();
+
+    if data.priority < 1 {
+        return HttpResponse::BadRequest().body("Priority has to be at least 1");
+    }
+
+    match set_competition_priority(data.competition_id, data.priority) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
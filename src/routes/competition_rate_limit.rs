@@ -0,0 +1,47 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_rate_limits::upsert_rate_limit, operations_competition::get_competition_by_id},
+    models::{rate_limit::{NewRateLimit, PublicRateLimit}, user::Role},
+};
+
+/// Replaces the upload and request limits of a competition's participants.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::rate_limit::NewRateLimit,
+    responses(
+        (status = 200, description = "Success", body = crate::models::rate_limit::PublicRateLimit),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/rate_limit")]
+pub async fn competition_rate_limit(auth: BearerAuth, body: web::Json<NewRateLimit>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let limit = body.into_inner();
+    if limit.uploads_per_hour.unwrap_or(0) < 0 || limit.requests_per_minute.unwrap_or(0) < 0 {
+        return HttpResponse::BadRequest().body("Limits can't be negative");
+    }
+
+    if get_competition_by_id(limit.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match upsert_rate_limit(limit) {
+        Ok(l) => HttpResponse::Ok().json(PublicRateLimit::from(l)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_rate_limits::get_rate_limit_by_competition_id,
+    models::rate_limit::PublicRateLimit,
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::rate_limit::PublicRateLimit),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/rate_limit/{comp_id}")]
+pub async fn competition_rate_limit_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_rate_limit_by_competition_id(comp_id.into_inner()) {
+        Ok(Some(limit)) => HttpResponse::Ok().json(PublicRateLimit::from(limit)),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
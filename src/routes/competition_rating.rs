@@ -0,0 +1,59 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::set_competition_rating_settings;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RatingSettingsData {
+    pub competition_id: String,
+    pub elo_k_factor: i32,
+    pub provisional_games: i32,
+    pub provisional_k_factor: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = RatingSettingsData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/rating")]
+pub async fn competition_rating(auth: BearerAuth, body: web::Json<RatingSettingsData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let settings = body.into_inner();
+
+    if settings.elo_k_factor <= 0 || settings.provisional_k_factor <= 0 {
+        return HttpResponse::BadRequest().body("K-factors have to be positive");
+    }
+    if settings.provisional_games < 0 {
+        return HttpResponse::BadRequest().body("Number of provisional games can't be negative");
+    }
+
+    match set_competition_rating_settings(
+        settings.competition_id, 
+        settings.elo_k_factor, 
+        settings.provisional_games,
+        settings.provisional_k_factor
+    ) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use tracing::info;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, rating_recompute::{apply_blocked_reason, apply_recomputed_ratings}},
+    db::operations_competition::get_competition_by_id,
+    models::{rating_recompute::RatingRecomputeTarget, user::Role},
+};
+
+/// Replaces the competition's ratings, game ELO changes and ELO history with the ones of its
+/// last recompute.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::rating_recompute::RatingRecomputeTarget,
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/ratings/apply")]
+pub async fn competition_ratings_apply(auth: BearerAuth, body: web::Json<RatingRecomputeTarget>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(body.into_inner().competition_id) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match apply_blocked_reason(&competition) {
+        Ok(Some(reason)) => return HttpResponse::Conflict().body(reason),
+        Ok(None) => {},
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    match apply_recomputed_ratings(&competition) {
+        Ok(_) => {
+            info!("{} applied recomputed ratings to competition {}", requesting_user.username, competition.id);
+            HttpResponse::Ok().finish()
+        },
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
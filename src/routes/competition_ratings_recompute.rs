@@ -0,0 +1,50 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, rating_recompute::recompute_ratings},
+    db::operations_competition::get_competition_by_id,
+    models::{rating_recompute::RatingRecomputeOptions, user::Role},
+};
+
+/// Replays the competition's games and recomputes all ratings from scratch, optionally with
+/// different K-factors. The result is only stored next to the current ratings until it's
+/// applied with `/competition/ratings/apply`.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::rating_recompute::RatingRecomputeOptions,
+    responses(
+        (status = 200, description = "Success", body = crate::models::rating_recompute::RatingRecomputeReport),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/ratings/recompute")]
+pub async fn competition_ratings_recompute(auth: BearerAuth, body: web::Json<RatingRecomputeOptions>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let options = body.into_inner();
+    if [options.k_factor, options.provisional_games, options.provisional_k_factor].iter().any(|v| v.unwrap_or(0) < 0) {
+        return HttpResponse::BadRequest().body("K-factors and provisional games can't be negative");
+    }
+
+    let competition = match get_competition_by_id(options.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match recompute_ratings(&competition, options) {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::controllers::registration::promote_waitlisted_teams;
+use crate::db::operations_competition::set_competition_registration;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegistrationData {
+    pub competition_id: String,
+    pub registration_open: NaiveDateTime,
+    pub registration_close: NaiveDateTime,
+    pub max_teams: i32,
+}
+
+/// Sets when teams can register for a competition and how many can take part. Raising the
+/// cap lets teams from the waitlist in right away.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = RegistrationData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/registration")]
+pub async fn competition_registration(auth: BearerAuth, body: web::Json<RegistrationData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let registration = body.into_inner();
+    if registration.registration_close < registration.registration_open {
+        return HttpResponse::BadRequest().body("Registration can't close before it opens");
+    }
+    if registration.max_teams < 0 {
+        return HttpResponse::BadRequest().body("max_teams can't be negative");
+    }
+
+    let competition = match set_competition_registration(
+        registration.competition_id,
+        registration.registration_open,
+        registration.registration_close,
+        registration.max_teams,
+    ) {
+        Ok(c) => c,
+        Err(diesel::result::Error::NotFound) => return HttpResponse::NotFound().finish(),
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    if let Err(e) = promote_waitlisted_teams(&competition) {
+        return HttpResponse::InternalServerError().json(e.to_string());
+    }
+
+    HttpResponse::Ok().json(PublicCompetition::from(competition))
+}
\ No newline at end of file
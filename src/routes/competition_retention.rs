@@ -0,0 +1,54 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::set_competition_replay_retention;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReplayRetentionData {
+    pub competition_id: String,
+    pub replay_compress_after: i32,
+    pub replay_delete_after: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = ReplayRetentionData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/retention")]
+pub async fn competition_retention(auth: BearerAuth, body: web::Json<ReplayRetentionData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let retention = body.into_inner();
+
+    if retention.replay_compress_after < 0 || retention.replay_delete_after < 0 {
+        return HttpResponse::BadRequest().body("Retention thresholds can't be negative");
+    }
+
+    match set_competition_replay_retention(
+        retention.competition_id, 
+        retention.replay_compress_after, 
+        retention.replay_delete_after
+    ) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
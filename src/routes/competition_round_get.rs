@@ -0,0 +1,38 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    db::{operations_rounds::get_round, operations_round_stats::get_round_stats_by_competition_id},
+    models::{round::{PublicRound, RoundDetails}, round_stats::PublicRoundStats},
+};
+
+#[utoipa::path(
+    tag = "spectators",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        ("round" = i32, Path, description = "Round number"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::round::RoundDetails),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/rounds/{round}")]
+pub async fn competition_round_get(path: web::Path<(String, i32)>) -> HttpResponse {
+    let (comp_id, round) = path.into_inner();
+
+    let stored = match get_round(comp_id.clone(), round) {
+        Ok(Some(r)) => r,
+        Ok(None) => return HttpResponse::NotFound().finish(),
+        Err(_) => return HttpResponse::InternalServerError().finish()
+    };
+
+    let stats = match get_round_stats_by_competition_id(comp_id) {
+        Ok(s) => s.into_iter().find(|s| s.round == round).map(PublicRoundStats::from),
+        Err(_) => return HttpResponse::InternalServerError().finish()
+    };
+
+    HttpResponse::Ok().json(RoundDetails {
+        round: PublicRound::from(stored),
+        stats,
+    })
+}
\ No newline at end of file
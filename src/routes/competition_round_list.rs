@@ -0,0 +1,27 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    db::operations_rounds::get_rounds_by_competition_id,
+    models::round::PublicRound,
+};
+
+/// Rounds of a competition with when they ran and how they ended, in round order.
+#[utoipa::path(
+    tag = "spectators",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::round::PublicRound]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/rounds")]
+pub async fn competition_round_list(comp_id: web::Path<String>) -> HttpResponse {
+    match get_rounds_by_competition_id(comp_id.into_inner()) {
+        Ok(rounds) => HttpResponse::Ok().json(
+            rounds
+                .into_iter()
+                .map(PublicRound::from)
+                .collect::<Vec<PublicRound>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, pairing::audit_round_pairing},
+    models::user::Role,
+};
+
+/// The seed and pairing a round was played with, paired again from the seed to check it
+/// comes out the same.
+#[utoipa::path(
+    tag = "competitions",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        ("round" = i32, Path, description = "Round number"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::round::RoundPairingAudit),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competitions/{comp_id}/rounds/{round}/pairing")]
+pub async fn competition_round_pairing(auth: BearerAuth, path: web::Path<(String, i32)>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let (comp_id, round) = path.into_inner();
+    match audit_round_pairing(comp_id, round) {
+        Ok(Some(audit)) => HttpResponse::Ok().json(audit),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{
+        operations_rounds::get_round,
+        operations_round_byes::get_round_byes_by_competition_id,
+        operations_round_stats::get_round_stats_by_competition_id,
+    },
+    models::{round::{PublicRound, RoundReport}, round_stats::PublicRoundStats, user::Role},
+};
+
+/// Summary of a finished round: its games, timeouts, crashes, failed compiles and how ELO
+/// moved, with the teams that sat out.
+#[utoipa::path(
+    tag = "competitions",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        ("round" = i32, Path, description = "Round number"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::round::RoundReport),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competitions/{comp_id}/rounds/{round}/report")]
+pub async fn competition_round_report(auth: BearerAuth, path: web::Path<(String, i32)>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let (comp_id, round) = path.into_inner();
+
+    let stats = match get_round_stats_by_competition_id(comp_id.clone()) {
+        Ok(s) => match s.into_iter().find(|s| s.round == round) {
+            Some(s) => s,
+            None => return HttpResponse::NotFound().finish(),
+        },
+        Err(_) => return HttpResponse::InternalServerError().finish()
+    };
+
+    let stored = match get_round(comp_id.clone(), round) {
+        Ok(r) => r,
+        Err(_) => return HttpResponse::InternalServerError().finish()
+    };
+
+    let byes = match get_round_byes_by_competition_id(comp_id) {
+        Ok(b) => b.into_iter().filter(|b| b.round == round).collect::<Vec<_>>(),
+        Err(_) => return HttpResponse::InternalServerError().finish()
+    };
+
+    HttpResponse::Ok().json(RoundReport {
+        round: stored.map(PublicRound::from),
+        stats: PublicRoundStats::from(stats),
+        forfeited_team_ids: byes.iter().filter(|b| b.is_forfeit()).map(|b| b.team_id.clone()).collect(),
+        bye_team_ids: byes.iter().filter(|b| !b.is_forfeit()).map(|b| b.team_id.clone()).collect(),
+    })
+}
\ No newline at end of file
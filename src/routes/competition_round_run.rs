@@ -0,0 +1,62 @@
+use actix_web::{HttpRequest, HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, job_queue::enqueue_round, competitions::has_rounds, safe_mode::is_safe_mode, trace::{TraceContext, TRACE_HEADER}},
+    db::operations_competition::get_competition_by_id,
+    models::{job::PublicJob, user::Role},
+};
+
+/// Queues the next round of a competition. Returns the job right away, its status, progress
+/// and log can be polled at `/jobs/{job_id}`.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 202, description = "Accepted, runs in the background", body = crate::models::job::PublicJob),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/round/{comp_id}")]
+pub async fn competition_round_run(auth: BearerAuth, req: HttpRequest, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if competition.archived {
+        return HttpResponse::Conflict().body("Competition is archived");
+    }
+    if !has_rounds(&competition.type_) {
+        return HttpResponse::BadRequest().body("Competition isn't played in rounds");
+    }
+
+    let trace = TraceContext::from_header(
+        req.headers().get(TRACE_HEADER).and_then(|h| h.to_str().ok())
+    );
+    match enqueue_round(competition.id, &trace) {
+        Ok(job) => HttpResponse::Accepted()
+            .insert_header((TRACE_HEADER, trace.trace_id))
+            .json(PublicJob::from(job)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
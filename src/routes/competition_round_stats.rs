@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    db::operations_round_stats::get_round_stats_by_competition_id,
+    models::round_stats::PublicRoundStats,
+};
+
+#[utoipa::path(
+    tag = "spectators",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::round_stats::PublicRoundStats]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/round-stats")]
+pub async fn competition_round_stats(comp_id: web::Path<String>) -> HttpResponse {
+    match get_round_stats_by_competition_id(comp_id.into_inner()) {
+        Ok(stats) => HttpResponse::Ok().json(
+            stats
+                .into_iter()
+                .map(PublicRoundStats::from)
+                .collect::<Vec<PublicRoundStats>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
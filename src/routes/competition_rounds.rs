@@ -19,6 +19,17 @@ type RoundData = (
     Vec<String>     // vec of ids of matches
 );
 
+#[utoipa::path(
+    tag = "competitions",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = Object),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/competition/rounds/{team_id}")]
 pub async fn competition_rounds(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -2,6 +2,13 @@ use actix_web::{HttpResponse, get};
 use crate::db::operations_competition::get_running_competitions;
 use crate::models::competition::PublicCompetition;
 
+#[utoipa::path(
+    tag = "competitions",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::competition::PublicCompetition]),
+        (status = 500, description = "Server error"),
+    ),
+)]
 #[get("/competition/running")]
 pub async fn competition_running() -> HttpResponse {
     match get_running_competitions() {
@@ -0,0 +1,55 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::set_competition_scoring;
+use crate::models::competition::{PublicCompetition, ScoringSystem};
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ScoringData {
+    pub competition_id: String,
+    pub scoring_system: ScoringSystem,
+    pub points_win: i32,
+    pub points_draw: i32,
+    pub points_loss: i32,
+    pub points_bye: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = ScoringData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/scoring")]
+pub async fn competition_scoring(auth: BearerAuth, body: web::Json<ScoringData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let scoring = body.into_inner();
+
+    match set_competition_scoring(
+        scoring.competition_id, 
+        scoring.scoring_system,
+        scoring.points_win,
+        scoring.points_draw,
+        scoring.points_loss,
+        scoring.points_bye
+    ) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
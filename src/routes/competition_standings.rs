@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, get, web};
+use crate::controllers::leaderboard::standings_snapshot;
+
+/// The leaderboard as it was at the end of a round, with each team's rank change since the
+/// round before.
+#[utoipa::path(
+    tag = "spectators",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        ("round" = i32, Path, description = "Round"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::standings_history::StandingsSnapshot),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/competitions/{comp_id}/standings/{round}")]
+pub async fn competition_standings(path: web::Path<(String, i32)>) -> HttpResponse {
+    let (competition_id, round) = path.into_inner();
+    match standings_snapshot(competition_id, round) {
+        Ok(Some(snapshot)) => HttpResponse::Ok().json(snapshot),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
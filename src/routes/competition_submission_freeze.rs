@@ -0,0 +1,57 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::controllers::submission_window::sync_submission_windows;
+use crate::db::operations_competition::{get_competition_by_id, set_competition_submission_freeze};
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SubmissionFreezeData {
+    pub competition_id: String,
+    pub freeze_minutes: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = SubmissionFreezeData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/submission-freeze")]
+pub async fn competition_submission_freeze(auth: BearerAuth, body: web::Json<SubmissionFreezeData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let freeze = body.into_inner();
+
+    // rounds run every hour, a longer freeze would never open submissions
+    if !(0..60).contains(&freeze.freeze_minutes) {
+        return HttpResponse::BadRequest().body("freeze_minutes has to be between 0 and 59");
+    }
+
+    if let Err(e) = set_competition_submission_freeze(freeze.competition_id.clone(), freeze.freeze_minutes) {
+        return HttpResponse::InternalServerError().json(e.to_string());
+    }
+
+    // apply the new window right away instead of with the next sync
+    sync_submission_windows();
+    match get_competition_by_id(freeze.competition_id) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
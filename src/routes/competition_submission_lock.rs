@@ -0,0 +1,24 @@
+use actix_web::{HttpResponse, get, web};
+use chrono::Local;
+use crate::{
+    controllers::submission_window::submission_lock,
+    db::operations_competition::get_competition_by_id,
+};
+
+/// When the competition's next round is played and when submissions for it close, with the
+/// seconds left until both for countdowns.
+#[utoipa::path(
+    tag = "spectators",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::submission_lock::SubmissionLock),
+        (status = 404, description = "Not found"),
+    ),
+)]
+#[get("/competitions/{comp_id}/submission-lock")]
+pub async fn competition_submission_lock(comp_id: web::Path<String>) -> HttpResponse {
+    match get_competition_by_id(comp_id.into_inner()) {
+        Ok(competition) => HttpResponse::Ok().json(submission_lock(&competition, Local::now().naive_utc())),
+        Err(_) => HttpResponse::NotFound().finish(),
+    }
+}
\ No newline at end of file
@@ -2,8 +2,15 @@ use std::collections::HashMap;
 
 use actix_web::{HttpResponse, get};
 use crate::db::operations_competition::get_running_competitions;
-use crate::db::operations_teams::get_teams_by_competition_id;
+use crate::db::operations_teams::get_active_teams_by_competition_id;
 
+#[utoipa::path(
+    tag = "competitions",
+    responses(
+        (status = 200, description = "Success", body = Object),
+        (status = 500, description = "Server error"),
+    ),
+)]
 #[get("/competition/team/count")]
 pub async fn competition_team_count() -> HttpResponse {
     let competitions = match get_running_competitions() {
@@ -15,7 +22,7 @@ pub async fn competition_team_count() -> HttpResponse {
 
     for competition in competitions.into_iter() {
         let id = competition.id.clone();
-        let teams = match get_teams_by_competition_id(id) {
+        let teams = match get_active_teams_by_competition_id(id) {
             Ok(v) => v,
             Err(_) => continue,
         };
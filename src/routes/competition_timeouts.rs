@@ -0,0 +1,59 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::set_competition_game_timeouts;
+use crate::models::competition::PublicCompetition;
+use crate::models::user::Role;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GameTimeoutsData {
+    pub competition_id: String,
+    /// used by rounds no leniency range covers
+    pub game_timeout_secs: i32,
+    /// games writing no output for this long are killed, `0` turns the watchdog off
+    pub stall_timeout_secs: i32,
+}
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = GameTimeoutsData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::competition::PublicCompetition),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/timeouts")]
+pub async fn competition_timeouts(auth: BearerAuth, body: web::Json<GameTimeoutsData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let timeouts = body.into_inner();
+
+    if timeouts.game_timeout_secs <= 0 {
+        return HttpResponse::BadRequest().body("Timeout has to be positive");
+    }
+    if timeouts.stall_timeout_secs < 0 {
+        return HttpResponse::BadRequest().body("Stall timeout can't be negative");
+    }
+
+    match set_competition_game_timeouts(
+        timeouts.competition_id,
+        timeouts.game_timeout_secs,
+        timeouts.stall_timeout_secs
+    ) {
+        Ok(c) => HttpResponse::Ok().json(PublicCompetition::from(c)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
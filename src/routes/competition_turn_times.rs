@@ -0,0 +1,43 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, turn_times::turn_time_report},
+    db::operations_competition::get_competition_by_id,
+    models::user::Role,
+};
+
+/// How long the competition's bots take for their turns, bots that routinely come close to
+/// the per-turn limit are flagged.
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::turn_time_report::TurnTimeReport),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competitions/{comp_id}/turn-times")]
+pub async fn competition_turn_times(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match turn_time_report(competition.id) {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
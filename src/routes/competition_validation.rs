@@ -0,0 +1,64 @@
+use std::thread;
+
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use tracing::error;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, revalidation::revalidate_competition, trace::{TraceContext, TRACE_HEADER}},
+    db::{operations_validation_rules::upsert_validation_rules, operations_competition::get_competition_by_id},
+    models::{validation_rules::{NewValidationRules, PublicValidationRules}, user::Role},
+};
+
+/// Replaces the validation rules of a competition and starts re-checking all active bots
+/// against them in the background. The revalidation is recorded under the returned trace id.
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::validation_rules::NewValidationRules,
+    responses(
+        (status = 202, description = "Accepted, runs in the background", body = crate::models::validation_rules::PublicValidationRules),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/competition/validation")]
+pub async fn competition_validation(auth: BearerAuth, body: web::Json<NewValidationRules>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let rules = body.into_inner();
+    if rules.max_size_kb.unwrap_or(0) < 0 || rules.grace_hours.unwrap_or(0) < 0 {
+        return HttpResponse::BadRequest().body("Size limit and grace period can't be negative");
+    }
+
+    if get_competition_by_id(rules.competition_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let rules = match upsert_validation_rules(rules) {
+        Ok(r) => r,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string())
+    };
+
+    let trace = TraceContext::new();
+    let competition_id = rules.competition_id.clone();
+    let revalidation_trace = trace.clone();
+    thread::spawn(move || {
+        if let Err(e) = revalidate_competition(competition_id, &revalidation_trace) {
+            error!("Failed revalidating bots: {:?}", e);
+        }
+    });
+
+    HttpResponse::Accepted()
+        .insert_header((TRACE_HEADER, trace.trace_id))
+        .json(PublicValidationRules::from(rules))
+}
\ No newline at end of file
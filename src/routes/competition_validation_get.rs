@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_validation_rules::get_validation_rules_by_competition_id,
+    models::validation_rules::PublicValidationRules,
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::validation_rules::PublicValidationRules),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/competition/validation/{comp_id}")]
+pub async fn competition_validation_get(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_validation_rules_by_competition_id(comp_id.into_inner()) {
+        Ok(Some(rules)) => HttpResponse::Ok().json(PublicValidationRules::from(rules)),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_published_bots::{get_published_bot_by_id, delete_published_bot}, operations_teams::get_team_by_id},
+    models::user::Role,
+};
+
+/// Takes a bot out of the gallery again.
+#[utoipa::path(
+    tag = "practice",
+    params(("published_bot_id" = String, Path, description = "Published bot id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/gallery/{published_bot_id}")]
+pub async fn gallery_delete(auth: BearerAuth, published_bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let published_bot = match get_published_bot_by_id(published_bot_id.into_inner()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = match get_team_by_id(published_bot.team_id.clone()) {
+        Ok(t) => requesting_user.id == t.owner || requesting_user.id == t.partner,
+        Err(_) => false,
+    };
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match delete_published_bot(published_bot.id) {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use actix_files::NamedFile;
+use actix_web::{HttpRequest, HttpResponse, get, http::header, web};
+use crate::{
+    controllers::{file_handler::local_copy, storage::storage},
+    db::operations_published_bots::get_published_bot_by_id,
+};
+
+/// Downloads the archive of a published bot, as its team uploaded it. No login needed.
+///
+/// With object storage the client is redirected to a presigned link, otherwise the file is
+/// streamed from disk.
+#[utoipa::path(
+    tag = "practice",
+    params(("published_bot_id" = String, Path, description = "Published bot id")),
+    responses(
+        (status = 200, description = "Success", content_type = "application/octet-stream"),
+        (status = 307, description = "Redirect to a presigned download link"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/gallery/{published_bot_id}/download")]
+pub async fn gallery_download(req: HttpRequest, published_bot_id: web::Path<String>) -> HttpResponse {
+    let published_bot = match get_published_bot_by_id(published_bot_id.into_inner()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if let Some(url) = storage().presigned_url(&published_bot.archive_path) {
+        return HttpResponse::TemporaryRedirect()
+            .insert_header((header::LOCATION, url))
+            .finish();
+    }
+
+    let path = match web::block(move || local_copy(&published_bot.archive_path)).await {
+        Ok(Ok(p)) => p,
+        _ => return HttpResponse::NotFound().finish(),
+    };
+    match NamedFile::open(path) {
+        Ok(file) => file.into_response(&req),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use actix_web::{HttpResponse, get, web};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use crate::{
+    db::{operations_published_bots::get_published_bots, operations_teams::get_teams_by_ids},
+    models::published_bot::PublicPublishedBot,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GalleryQuery {
+    /// only bots of this competition
+    pub competition_id: Option<String>,
+}
+
+/// The gallery of bots teams published after their competition ended, no login needed.
+#[utoipa::path(
+    tag = "practice",
+    params(GalleryQuery),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::published_bot::PublicPublishedBot]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/gallery")]
+pub async fn gallery_get_all(query: web::Query<GalleryQuery>) -> HttpResponse {
+    let published_bots = match get_published_bots(query.into_inner().competition_id) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let team_ids = published_bots.iter().map(|p| p.team_id.clone()).collect::<Vec<String>>();
+    let team_names: HashMap<String, String> = match get_teams_by_ids(team_ids) {
+        Ok(teams) => teams.into_iter().map(|t| (t.id.clone(), t.public_name())).collect(),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    HttpResponse::Ok().json(
+        published_bots
+            .into_iter()
+            .map(|p| {
+                let team_name = team_names.get(&p.team_id).cloned();
+                PublicPublishedBot::new(p, team_name)
+            })
+            .collect::<Vec<PublicPublishedBot>>()
+    )
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::Local;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{
+        operations_bot::get_bot_by_id,
+        operations_competition::get_competition_by_id,
+        operations_published_bots::{insert_published_bot, is_bot_published},
+        operations_teams::get_team_by_id,
+    },
+    models::published_bot::{NewPublishedBot, PublicPublishedBot, SqlPublishedBot},
+};
+
+/// Publishes one of the team's bots to the public gallery, so students of later competitions
+/// can download it and practice against it. Only possible once the competition has ended.
+#[utoipa::path(
+    tag = "practice",
+    request_body = crate::models::published_bot::NewPublishedBot,
+    responses(
+        (status = 200, description = "Success", body = crate::models::published_bot::PublicPublishedBot),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/gallery/publish")]
+pub async fn gallery_publish(auth: BearerAuth, body: web::Json<NewPublishedBot>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let new_published_bot = body.into_inner();
+    if new_published_bot.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Published bot needs a name");
+    }
+
+    let bot = match get_bot_by_id(new_published_bot.bot_id.clone()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let team = match get_team_by_id(bot.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let competition = match get_competition_by_id(team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+    if competition.end > Local::now().naive_utc() {
+        return HttpResponse::Forbidden().body("Bots can be published once the competition has ended");
+    }
+
+    if !bot.compile_error.is_empty() {
+        return HttpResponse::BadRequest().body("Bot doesn't compile");
+    }
+
+    match is_bot_published(bot.id.clone()) {
+        Ok(true) => return HttpResponse::Conflict().body("Bot is already published"),
+        Ok(false) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    let team_name = team.public_name();
+    match insert_published_bot(SqlPublishedBot::new(new_published_bot, &bot, &team)) {
+        Ok(published_bot) => HttpResponse::Ok().json(PublicPublishedBot::new(published_bot, Some(team_name))),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
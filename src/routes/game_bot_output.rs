@@ -0,0 +1,75 @@
+use std::fs;
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    db::{
+        operations_game2v2::get_game_by_id,
+        operations_teams::get_team_by_student_for_competition
+    },
+    controllers::{jwt::exchange_token_for_user, matchmaker_2v2::bot_output_file},
+    models::{user::Role, game_2v2::ReplayState}
+};
+
+/// What a bot printed during a game, only available to the team the bot plays for.
+#[utoipa::path(
+    tag = "games",
+    params(
+        ("id" = String, Path, description = "Game id"),
+        ("bot_id" = String, Path, description = "Bot id"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found or the bot printed nothing"),
+        (status = 410, description = "No longer available"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/game/bot_output/{id}/{bot_id}")]
+pub async fn game_bot_output(auth: BearerAuth, path: web::Path<(String, String)>) -> HttpResponse {
+    let (id, bot_id) = path.into_inner();
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+
+    let game = match get_game_by_id(id) {
+        Ok(game) => game,
+        Err(_) => return HttpResponse::NotFound().finish()
+    };
+
+    let (slot, team_id) = if bot_id == game.team1bot1_id {
+        ("team1bot1", &game.team1_id)
+    } else if bot_id == game.team1bot2_id {
+        ("team1bot2", &game.team1_id)
+    } else if bot_id == game.team2bot1_id {
+        ("team2bot1", &game.team2_id)
+    } else if bot_id == game.team2bot2_id {
+        ("team2bot2", &game.team2_id)
+    } else {
+        return HttpResponse::NotFound().finish();
+    };
+
+    if requesting_user.role != Role::Admin {
+        let team = match get_team_by_student_for_competition(requesting_user, game.competition_id.clone()) {
+            Ok(t) => t,
+            Err(_) => return HttpResponse::Forbidden().finish(),
+        };
+        if !team.id.eq(team_id) {
+            return HttpResponse::Forbidden().finish();
+        }
+    }
+
+    if game.replay_state == ReplayState::Deleted {
+        return HttpResponse::Gone().body("Bot output was removed by the retention policy");
+    }
+
+    match fs::read_to_string(bot_output_file(game.round, &game.id, slot)) {
+        Ok(prints) => HttpResponse::Ok()
+            .content_type("text/plain; charset=utf-8")
+            .body(prints),
+        Err(_) => HttpResponse::NotFound().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use std::fs;
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    db::{
+        operations_game2v2::get_game_by_id,
+        operations_teams::get_team_by_student_for_competition
+    },
+    controllers::jwt::exchange_token_for_user,
+    models::{user::Role, game_2v2::ReplayState}
+};
+
+/// Error output the bots printed during a game. Students only get it for games their team
+/// played, without the lines about the opponent's bots.
+#[utoipa::path(
+    tag = "games",
+    params(("id" = String, Path, description = "Id")),
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found or the bots printed no errors"),
+        (status = 410, description = "No longer available"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/game/errors/{id}")]
+pub async fn game_errors(auth: BearerAuth, id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+
+    let game = match get_game_by_id(id.clone()) {
+        Ok(game) => game,
+        Err(_) => return HttpResponse::NotFound().finish()
+    };
+
+    let mut opponent_bot_ids = vec![];
+    if requesting_user.role != Role::Admin {
+        let team = match get_team_by_student_for_competition(requesting_user, game.competition_id.clone()) {
+            Ok(t) => t,
+            Err(_) => return HttpResponse::Forbidden().finish(),
+        };
+        if team.id.eq(&game.team1_id) {
+            opponent_bot_ids = vec![game.team2bot1_id.clone(), game.team2bot2_id.clone()];
+        } else if team.id.eq(&game.team2_id) {
+            opponent_bot_ids = vec![game.team1bot1_id.clone(), game.team1bot2_id.clone()];
+        } else {
+            return HttpResponse::Forbidden().finish();
+        }
+    }
+
+    if game.replay_state == ReplayState::Deleted {
+        return HttpResponse::Gone().body("Error output was removed by the retention policy");
+    }
+    if game.stderr_path.is_empty() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let errors = match fs::read_to_string(&game.stderr_path) {
+        Ok(contents) => contents,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let own_errors = errors
+        .lines()
+        .filter(|line| !opponent_bot_ids.iter().any(|bot_id| line.contains(bot_id.as_str())))
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(own_errors)
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    db::{
+        operations_game2v2::get_game_by_id,
+        operations_teams::get_team_by_student_for_competition
+    },
+    controllers::{jwt::exchange_token_for_user, replay_frames::replay_frames},
+    models::{user::Role, game_2v2::ReplayState}
+};
+
+/// The game's replay decoded into one frame per turn, for the visualizer. Same access rules
+/// as the raw log: public games for everyone, others for their teams and admins.
+#[utoipa::path(
+    tag = "games",
+    params(("id" = String, Path, description = "Id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::replay_frame::ReplayFrames),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 410, description = "No longer available"),
+        (status = 500, description = "Server error"),
+    ),
+    security((), ("bearer" = [])),
+)]
+#[get("/game/frames/{id}")]
+pub async fn game_frames(auth: Option<BearerAuth>, id: web::Path<String>) -> HttpResponse {
+    let game = match get_game_by_id(id.clone()) {
+        Ok(game) => game,
+        Err(_) => return HttpResponse::NotFound().finish()
+    };
+
+    if !game.public {
+        let auth_token = match auth {
+            Some(token) => token,
+            None => return HttpResponse::Forbidden().finish(),
+        };
+        let requesting_user = match exchange_token_for_user(auth_token) {
+            Some(u) => u,
+            None => return HttpResponse::Forbidden().finish(),
+        };
+
+        if requesting_user.role != Role::Admin {
+            let team = match get_team_by_student_for_competition(requesting_user, game.competition_id.clone()) {
+                Ok(t) => t,
+                Err(_) => return HttpResponse::Unauthorized().finish(),
+            };
+            if !team.id.eq(&game.team1_id) && !team.id.eq(&game.team2_id) {
+                return HttpResponse::Forbidden().finish();
+            }
+        }
+    }
+
+    if game.replay_state == ReplayState::Deleted {
+        return HttpResponse::Gone().body("Replay was removed by the retention policy");
+    }
+    if game.replay_state == ReplayState::Dropped {
+        return HttpResponse::Gone().body("Replay was not stored because the server was low on disk space");
+    }
+
+    // decoding a long replay takes a while, keep it off the worker thread
+    match web::block(move || replay_frames(&game)).await {
+        Ok(Ok(frames)) => HttpResponse::Ok().json(frames),
+        _ => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
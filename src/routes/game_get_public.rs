@@ -1,6 +1,13 @@
 use actix_web::{HttpResponse, get};
 use crate::{models::game_2v2::PublicGame2v2, db::operations_game2v2::get_public_games};
 
+#[utoipa::path(
+    tag = "games",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::game_2v2::PublicGame2v2]),
+        (status = 500, description = "Server error"),
+    ),
+)]
 #[get("/game/public")]
 pub async fn game_get_public() -> HttpResponse {
     let games = match get_public_games() {
@@ -1,7 +1,29 @@
 use actix_web::{HttpResponse, get, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
-use crate::{models::{game_2v2::PublicGame2v2, user::Role}, db::{operations_game2v2::get_game_by_id, operations_teams::get_team_by_student_for_competition}, controllers::jwt::exchange_token_for_user};
+use serde::Serialize;
+use utoipa::ToSchema;
+use crate::{models::{bot::GameBot, game_2v2::PublicGame2v2, game_player_stats::PublicGamePlayerStats, user::Role}, db::{operations_game2v2::get_game_by_id, operations_teams::get_team_by_student_for_competition, operations_game_player_stats::get_game_player_stats_by_game_id}, controllers::{jwt::exchange_token_for_user, bot_metadata::game_bots}};
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GameDetails {
+    #[serde(flatten)]
+    pub game: PublicGame2v2,
+    pub player_stats: Vec<PublicGamePlayerStats>,
+    /// names of the game's bots, in slot order
+    pub bots: Vec<GameBot>,
+}
+
+#[utoipa::path(
+    tag = "games",
+    params(("game_id" = String, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Success", body = GameDetails),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security((), ("bearer" = [])),
+)]
 #[get("/game/{game_id}")]
 pub async fn game_id(auth: Option<BearerAuth>, game_id: web::Path<String>) -> HttpResponse {
     let game = match get_game_by_id(game_id.into_inner()) {
@@ -32,5 +54,19 @@ pub async fn game_id(auth: Option<BearerAuth>, game_id: web::Path<String>) -> Ht
 
     }
 
-    HttpResponse::Ok().json(PublicGame2v2::from(game))
+    let player_stats = match get_game_player_stats_by_game_id(game.id.clone()) {
+        Ok(stats) => stats.into_iter().map(PublicGamePlayerStats::from).collect(),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let bots = match game_bots(&game) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    HttpResponse::Ok().json(GameDetails {
+        game: PublicGame2v2::from(game),
+        player_stats,
+        bots,
+    })
 }
\ No newline at end of file
@@ -1,15 +1,13 @@
-use std::{fs::{self, File}, io::Read};
-
 use actix_web::{HttpResponse, get, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use serde::Serialize;
-use zip::ZipArchive;
 use crate::{
     db::{
         operations_game2v2::get_game_by_id, 
         operations_teams::get_team_by_student_for_competition
     }, 
-    controllers::jwt::exchange_token_for_user, models::user::Role
+    controllers::{jwt::exchange_token_for_user, file_handler::read_replay}, 
+    models::{user::Role, game_2v2::ReplayState}
 };
 
 #[derive(Debug, Serialize)]
@@ -17,6 +15,19 @@ struct GameLogResponse {
     log_file_contents: String,
 }
 
+#[utoipa::path(
+    tag = "games",
+    params(("id" = String, Path, description = "Id")),
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 410, description = "No longer available"),
+        (status = 500, description = "Server error"),
+    ),
+    security((), ("bearer" = [])),
+)]
 #[get("/game/log/{id}")]
 pub async fn game_log(auth: Option<BearerAuth>, id: web::Path<String>) -> HttpResponse {
     let game = match get_game_by_id(id.clone()) {
@@ -48,33 +59,18 @@ pub async fn game_log(auth: Option<BearerAuth>, id: web::Path<String>) -> HttpRe
         }
     }
 
-    let log_file_path = game.log_file_path;
-
-        
-    // Open the ZIP file
-    let file = match File::open(&log_file_path) {
-        Ok(file) => file,
-        Err(_) => return HttpResponse::InternalServerError().finish(),
-    };
-
-    // Create a new ZIP archive from the file
-    let mut zip = match ZipArchive::new(file) {
-        Ok(zip) => zip,
-        Err(_) => return HttpResponse::InternalServerError().finish(),
-    };
+    if game.replay_state == ReplayState::Deleted {
+        return HttpResponse::Gone().body("Replay was removed by the retention policy");
+    }
+    if game.replay_state == ReplayState::Dropped {
+        return HttpResponse::Gone().body("Replay was not stored because the server was low on disk space");
+    }
 
-    // Assuming the log file is the first file in the archive
-    let mut log_file = match zip.by_index(0) {
-        Ok(file) => file,
+    let log_file_contents = match read_replay(&game.log_file_path) {
+        Ok(contents) => contents,
         Err(_) => return HttpResponse::InternalServerError().finish(),
     };
 
-    // Read the contents of the log file
-    let mut log_file_contents = String::new();
-    if let Err(_) = log_file.read_to_string(&mut log_file_contents) {
-        return HttpResponse::InternalServerError().finish();
-    }
-
     // Return the JSON response with a 200 OK status
     HttpResponse::Ok()
         .content_type("application/text; charset=utf-8")
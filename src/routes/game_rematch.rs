@@ -0,0 +1,62 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, rematch::run_rematch, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    db::{operations_game2v2::get_game_by_id, operations_teams::get_team_by_id},
+    models::{errors::MatchMakerError, user::Role},
+};
+
+#[utoipa::path(
+    tag = "games",
+    params(("game_id" = String, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::rematch::RematchResult),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/game/rematch/{game_id}")]
+pub async fn game_rematch(auth: BearerAuth, game_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let game = match get_game_by_id(game_id.into_inner()) {
+        Ok(g) => g,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = [&game.team1_id, &game.team2_id]
+        .iter()
+        .filter_map(|id| get_team_by_id(id.to_string()).ok())
+        .any(|t| requesting_user.id == t.owner || requesting_user.id == t.partner);
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if game.map_seed == 0 {
+        return HttpResponse::BadRequest().body("Game was played before map seeds were recorded");
+    }
+
+    // rematches are unranked work, they yield to a running ranked round
+    let result = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+        run_rematch(&game)
+    }).await;
+
+    match result {
+        Ok(Ok(r)) => HttpResponse::Ok().json(r),
+        Ok(Err(MatchMakerError::ExecutionDisabled)) => HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
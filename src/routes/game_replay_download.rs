@@ -0,0 +1,76 @@
+use actix_files::NamedFile;
+use actix_web::{HttpRequest, HttpResponse, get, http::header, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    db::{
+        operations_game2v2::get_game_by_id, 
+        operations_teams::get_team_by_student_for_competition
+    }, 
+    controllers::{jwt::exchange_token_for_user, storage::storage}, 
+    models::{user::Role, game_2v2::ReplayState}
+};
+
+/// Downloads the stored replay file of a game (`.zip`, or `.zst` once compressed).
+///
+/// With object storage the client is redirected to a presigned link, otherwise the file is
+/// streamed from disk.
+#[utoipa::path(
+    tag = "games",
+    params(("id" = String, Path, description = "Id")),
+    responses(
+        (status = 200, description = "Success", content_type = "application/octet-stream"),
+        (status = 307, description = "Redirect to a presigned download link"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 410, description = "No longer available"),
+        (status = 500, description = "Server error"),
+    ),
+    security((), ("bearer" = [])),
+)]
+#[get("/game/replay/{id}")]
+pub async fn game_replay_download(req: HttpRequest, auth: Option<BearerAuth>, id: web::Path<String>) -> HttpResponse {
+    let game = match get_game_by_id(id.clone()) {
+        Ok(game) => game,
+        Err(_) => return HttpResponse::NotFound().finish()
+    };
+
+    if !game.public {
+        let auth_token = match auth {
+            Some(token) => token,
+            None => return HttpResponse::Forbidden().finish(),
+        };
+        let requesting_user = match exchange_token_for_user(auth_token) {
+            Some(u) => u,
+            None => return HttpResponse::Forbidden().finish(),
+        };
+
+        if requesting_user.role != Role::Admin {
+            let team = match get_team_by_student_for_competition(requesting_user, game.competition_id.clone()) {
+                Ok(t) => t,
+                Err(_) => return HttpResponse::Unauthorized().finish(),
+            };
+            if !team.id.eq(&game.team1_id) && !team.id.eq(&game.team2_id) {
+                return HttpResponse::Forbidden().finish();
+            }
+        }
+    }
+
+    if game.replay_state == ReplayState::Deleted {
+        return HttpResponse::Gone().body("Replay was removed by the retention policy");
+    }
+    if game.replay_state == ReplayState::Dropped {
+        return HttpResponse::Gone().body("Replay was not stored because the server was low on disk space");
+    }
+
+    if let Some(url) = storage().presigned_url(&game.log_file_path) {
+        return HttpResponse::TemporaryRedirect()
+            .insert_header((header::LOCATION, url))
+            .finish();
+    }
+
+    match NamedFile::open(&game.log_file_path) {
+        Ok(file) => file.into_response(&req),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use tracing::info;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, game_override::{override_game_result, override_invalid_reason}},
+    db::{operations_competition::get_competition_by_id, operations_game2v2::get_game_by_id},
+    models::{game_override::GameResultOverride, user::Role},
+};
+
+/// Corrects the winner and survivors of a game, e.g. after a confirmed Evaluator bug, and
+/// the ELO both teams got for it.
+#[utoipa::path(
+    tag = "games",
+    request_body = crate::models::game_override::GameResultOverride,
+    responses(
+        (status = 200, description = "Success", body = crate::models::game_override::GameOverrideResult),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/game/result")]
+pub async fn game_result_override(auth: BearerAuth, body: web::Json<GameResultOverride>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let result = body.into_inner();
+    let game = match get_game_by_id(result.game_id.clone()) {
+        Ok(g) => g,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+    if let Some(reason) = override_invalid_reason(&game, &result) {
+        return HttpResponse::BadRequest().body(reason);
+    }
+
+    let competition = match get_competition_by_id(game.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match override_game_result(game, &competition, result, &requesting_user) {
+        Ok(overridden) => {
+            info!("{} overrode the result of game {}", requesting_user.username, overridden.game.id);
+            HttpResponse::Ok().json(overridden)
+        },
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
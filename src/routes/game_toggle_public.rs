@@ -6,6 +6,18 @@ use crate::db::operations_teams::get_team_by_student_for_competition;
 use crate::models::user::Role;
 
 
+#[utoipa::path(
+    tag = "games",
+    params(("game_id" = String, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/game/public/{game_id}")]
 pub async fn game_toggle_public(auth: BearerAuth, game_id: web::Path<String>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
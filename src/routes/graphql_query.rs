@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, post};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use async_graphql_actix_web::GraphQLRequest;
+use crate::{controllers::jwt::exchange_token_for_user, db::operations_teams::get_team_by_student, graphql::{schema, Viewer}};
+
+#[utoipa::path(
+    tag = "games",
+    request_body(content = Object, description = "GraphQL request with `query` and optionally `variables` and `operationName`"),
+    responses(
+        (status = 200, description = "GraphQL response, errors are reported in its `errors`", body = Object),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/graphql")]
+pub async fn graphql_query(auth: BearerAuth, request: GraphQLRequest) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+
+    let role = requesting_user.role.clone();
+    let teams = match get_team_by_student(requesting_user) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let response = schema().execute(request.into_inner().data(Viewer { role, teams })).await;
+    HttpResponse::Ok().json(response)
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::get_competition_by_id;
+use crate::db::operations_round_hooks::insert_round_hook;
+use crate::models::round_hook::{NewRoundHook, PublicRoundHook};
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::round_hook::NewRoundHook,
+    responses(
+        (status = 200, description = "Success", body = crate::models::round_hook::PublicRoundHook),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/hook")]
+pub async fn hook_create(auth: BearerAuth, body: web::Json<NewRoundHook>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let new_hook = body.into_inner();
+
+    // does competition exist
+    if get_competition_by_id(new_hook.competition_id.clone()).is_err() {
+        return HttpResponse::BadRequest().finish();
+    }
+
+    if new_hook.target.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Hook target can't be empty");
+    }
+
+    match insert_round_hook(new_hook) {
+        Ok(h) => HttpResponse::Ok().json(PublicRoundHook::from(h)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::get_competition_by_id;
+use crate::db::operations_round_leniency::insert_round_leniency;
+use crate::models::round_leniency::{NewRoundLeniency, PublicRoundLeniency};
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::round_leniency::NewRoundLeniency,
+    responses(
+        (status = 200, description = "Success", body = crate::models::round_leniency::PublicRoundLeniency),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/leniency")]
+pub async fn leniency_create(auth: BearerAuth, body: web::Json<NewRoundLeniency>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let new_leniency = body.into_inner();
+
+    // does competition exist
+    if get_competition_by_id(new_leniency.competition_id.clone()).is_err() {
+        return HttpResponse::BadRequest().finish();
+    }
+
+    if new_leniency.from_round < 0 {
+        return HttpResponse::BadRequest().body("Round range can't start before round 0");
+    }
+    if let Some(to_round) = new_leniency.to_round {
+        if to_round != 0 && to_round < new_leniency.from_round {
+            return HttpResponse::BadRequest().body("Round range ends before it starts");
+        }
+    }
+    if new_leniency.timeout_secs.is_some_and(|t| t <= 0) {
+        return HttpResponse::BadRequest().body("Timeout has to be positive");
+    }
+    if new_leniency.crash_retries.is_some_and(|r| r < 0) {
+        return HttpResponse::BadRequest().body("Crash retries can't be negative");
+    }
+    if new_leniency.infra_retries.is_some_and(|r| r < 0) {
+        return HttpResponse::BadRequest().body("Infrastructure retries can't be negative");
+    }
+
+    match insert_round_leniency(new_leniency) {
+        Ok(l) => HttpResponse::Ok().json(PublicRoundLeniency::from(l)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
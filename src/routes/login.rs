@@ -1,17 +1,29 @@
 use actix_web::{HttpResponse, post, web};
 use serde::Deserialize;
+use utoipa::ToSchema;
+use tracing::error;
 use crate::controllers::ldap::ldap_login;
 use crate::controllers::jwt::encode_jwt;
-use crate::db::operations_users::{get_user_by_studnet_number, insert_user};
-use crate::models::user::{NewUser, LdapUser, Role};
+use crate::db::operations_users::{get_user_by_studnet_number, insert_user, set_user_ldap_dn};
+use crate::models::user::{NewUser, Role};
 use std::env;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 pub struct AuthPost {
     pub username: Option<String>,
     pub password: Option<String>,
 }
 
+#[utoipa::path(
+    tag = "users",
+    request_body = AuthPost,
+    responses(
+        (status = 200, description = "JWT to send as the bearer token", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+)]
 #[post("/login")]
 pub async fn login(body: web::Json<AuthPost>) -> HttpResponse {
     let credentials: AuthPost = body.into_inner();
@@ -30,20 +42,26 @@ pub async fn login(body: web::Json<AuthPost>) -> HttpResponse {
     if username.eq("admin") {
         let admin_pw = env::var("ADMIN_PASSWORD").expect("ADMIN_PASSWORD must be set");
         if password == admin_pw {
-            return match encode_jwt("admin".to_string(), true) {
+            return match encode_jwt("admin".to_string(), &Role::Admin) {
                 Ok(token) => HttpResponse::Ok().body(token),
                 Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
             };
         }
     }
 
-    let ldap_dn_option = match ldap_login(username.clone(), password).await {
+    // directory logins are case insensitive, keep a single user record per account
+    let username = username.trim().to_lowercase();
+
+    let ldap_user_option = match ldap_login(username.clone(), password).await {
         Ok(b) => b,
-        Err(e) => { println!("ERROR: {:#?}", e); None },
+        Err(e) => {
+            error!("Failed reaching the LDAP server: {:#?}", e);
+            return HttpResponse::ServiceUnavailable().body("Login service is currently unavailable");
+        },
     };
 
-    let ldap_dn = match ldap_dn_option {
-        Some(ldap_dn) => ldap_dn,
+    let ldap_user = match ldap_user_option {
+        Some(ldap_user) => ldap_user,
         None => return HttpResponse::Unauthorized().finish()
     };
     
@@ -51,20 +69,24 @@ pub async fn login(body: web::Json<AuthPost>) -> HttpResponse {
     let user_option = get_user_by_studnet_number(username.clone()).ok();
     let user = match user_option {
         None => {
-            let new_user = NewUser::from(LdapUser {
-                username: username.clone(), 
-                ldap_dn 
-            });
+            let new_user = NewUser::from(ldap_user);
             match insert_user(new_user) {
                 Ok(u) => u,
                 Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
             } 
         },
-        Some(u) => u,
+        Some(u) => {
+            // accounts get moved around the directory tree
+            if u.ldap_dn != ldap_user.ldap_dn {
+                if let Err(e) = set_user_ldap_dn(u.id.clone(), ldap_user.ldap_dn) {
+                    error!("Failed updating the directory entry of {}: {}", u.username, e);
+                }
+            }
+            u
+        },
     };
 
-    let is_admin = user.role == Role::Admin;
-    match encode_jwt(username, is_admin) {
+    match encode_jwt(username, &user.role) {
         Ok(token) => HttpResponse::Ok().body(token),
         Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
     }
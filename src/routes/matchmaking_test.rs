@@ -1,10 +1,31 @@
 use actix_web::{HttpResponse, get};
-use crate::controllers::competitions::run_competitions_round;
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::{competitions::queue_competitions_round, jwt::authorize, safe_mode::is_safe_mode};
+use crate::models::{job::PublicJob, user::Role};
 
+/// Queues a round of every running competition, the returned jobs can be polled at
+/// `/jobs/{job_id}`.
+#[utoipa::path(
+    tag = "jobs",
+    responses(
+        (status = 202, description = "Accepted, runs in the background", body = [crate::models::job::PublicJob]),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/mm/test")]
-pub async fn mmt() -> HttpResponse {
-    match run_competitions_round() {
-        Ok(u) => HttpResponse::Ok().json(u),
-        Err(e) => HttpResponse::Unauthorized().body(e.to_string())
+pub async fn mmt(auth: BearerAuth) -> HttpResponse {
+    if let Err(response) = authorize(auth, &[Role::Admin]) {
+        return response;
+    }
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+    match queue_competitions_round() {
+        Ok(jobs) => HttpResponse::Accepted().json(
+            jobs.into_iter().map(PublicJob::from).collect::<Vec<PublicJob>>()
+        ),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
     }
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use actix_web::{HttpResponse, get};
+use crate::controllers::alert_signals::render_alert_signals;
+
+#[utoipa::path(
+    tag = "operations",
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+    ),
+)]
+#[get("/metrics")]
+pub async fn metrics() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(render_alert_signals())
+}
\ No newline at end of file
@@ -1,29 +1,157 @@
 pub mod user_me;
+pub mod user_role;
+pub mod user_update;
+pub mod user_delete;
 pub mod login;
 pub mod competition_id;
 pub mod competition_create;
+pub mod competition_clone;
+pub mod competition_archive;
+pub mod competition_archive_get;
+pub mod competition_delete;
+pub mod competition_restore;
 pub mod competition_running;
 pub mod competition_attended;
 pub mod competition_rounds;
 pub mod competition_team_count;
 pub mod competition_pack;
+pub mod competition_plagiarism;
+pub mod competition_plagiarism_get;
+pub mod competition_retention;
+pub mod competition_rating;
+pub mod competition_scoring;
+pub mod competition_submission_freeze;
+pub mod competition_registration;
+pub mod competition_leaderboard;
+pub mod competition_events;
+pub mod competition_participation;
 pub mod team_create;
 pub mod team_join;
 pub mod team_leave;
 pub mod team_disband;
+pub mod team_delete;
+pub mod team_restore;
 pub mod team_kick;
 pub mod team_get;
 pub mod team_get_all;
 pub mod team_bots;
 pub mod team_bot_change;
+pub mod team_bot_versions;
+pub mod team_bot_rollback;
 pub mod team_rename;
 pub mod team_id;
+pub mod team_participation;
+pub mod team_elo_history;
+pub mod team_stats;
+pub mod team_scouting;
+pub mod team_head_to_head;
 pub mod bot_upload;
+pub mod bot_test_match;
+pub mod bot_recompile;
+pub mod bot_delete;
+pub mod bot_restore;
 pub mod user_id;
 pub mod bot_win_rates;
 pub mod game_log;
 pub mod game_toggle_public;
 pub mod game_id;
 pub mod game_get_public;
+pub mod hook_create;
+pub mod hook_get_all;
+pub mod hook_delete;
+pub mod leniency_create;
+pub mod leniency_get_all;
+pub mod leniency_delete;
+pub mod queue_status;
+pub mod trace_timeline;
+pub mod practice_publish;
+pub mod practice_get_all;
+pub mod practice_delete;
+pub mod practice_match;
+pub mod competition_round_stats;
+pub mod competition_validation;
+pub mod competition_validation_get;
+pub mod team_violations;
+pub mod competition_evaluator;
+pub mod metrics;
+pub mod game_rematch;
 
-pub mod matchmaking_test;
\ No newline at end of file
+pub mod matchmaking_test;
+pub mod season_create;
+pub mod season_get_all;
+pub mod season_standings;
+pub mod competition_round_run;
+pub mod job_get;
+pub mod worker_lease;
+pub mod worker_bot;
+pub mod worker_result;
+pub mod webhook_create;
+pub mod webhook_get_all;
+pub mod webhook_delete;
+pub mod competition_discord;
+pub mod competition_discord_get;
+pub mod competition_discord_delete;
+pub mod competition_rate_limit;
+pub mod competition_rate_limit_get;
+pub mod game_frames;
+pub mod competition_featured;
+pub mod api_key_create;
+pub mod api_key_get_all;
+pub mod api_key_revoke;
+pub mod public_standings;
+pub mod public_games;
+pub mod public_replay;
+pub mod openapi_spec;
+pub mod api_docs;
+pub mod graphql_query;
+pub mod competition_games;
+pub mod competition_ratings_recompute;
+pub mod competition_ratings_report;
+pub mod competition_ratings_apply;
+pub mod competition_ratings_discard;
+pub mod game_result_override;
+pub mod competition_audit_log;
+pub mod competition_pairing;
+pub mod competition_pairing_get;
+pub mod user_lab_group;
+pub mod competition_round_pairing;
+pub mod competition_round_list;
+pub mod competition_round_get;
+pub mod competition_round_report;
+pub mod competition_crashes;
+pub mod game_errors;
+pub mod game_bot_output;
+pub mod competition_timeouts;
+pub mod competition_priority;
+pub mod competition_ladder;
+pub mod competition_ladder_bot;
+pub mod practice_queue_join;
+pub mod practice_queue_leave;
+pub mod practice_queue_games;
+pub mod challenge_create;
+pub mod challenge_accept;
+pub mod challenge_decline;
+pub mod challenges_team;
+pub mod challenge_replay;
+pub mod achievements_team;
+pub mod achievements_user;
+pub mod competition_standings;
+pub mod competition_grade_mapping;
+pub mod competition_grade_mapping_get;
+pub mod competition_grades;
+pub mod game_replay_download;
+pub mod search;
+pub mod team_privacy;
+pub mod user_export;
+pub mod gallery_publish;
+pub mod gallery_get_all;
+pub mod gallery_download;
+pub mod gallery_delete;
+pub mod competition_ghost_create;
+pub mod competition_ghosts;
+pub mod competition_ghost_delete;
+pub mod competition_submission_lock;
+pub mod team_slot_assign;
+pub mod team_slots;
+pub mod bot_metadata;
+pub mod competition_turn_times;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use actix_web::{HttpResponse, get};
+use utoipa::OpenApi;
+use crate::openapi::ApiDoc;
+
+#[get("/openapi.json")]
+pub async fn openapi_spec() -> HttpResponse {
+    HttpResponse::Ok().json(ApiDoc::openapi())
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, practice::remove_practice_bot_files},
+    db::{operations_practice_bots::{get_practice_bot_by_id, delete_practice_bot}, operations_teams::get_team_by_id},
+    models::user::Role,
+};
+
+#[utoipa::path(
+    tag = "practice",
+    params(("practice_bot_id" = String, Path, description = "Practice bot id")),
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/practice/{practice_bot_id}")]
+pub async fn practice_delete(auth: BearerAuth, practice_bot_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let practice_bot = match get_practice_bot_by_id(practice_bot_id.into_inner()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = match get_team_by_id(practice_bot.team_id.clone()) {
+        Ok(t) => requesting_user.id == t.owner || requesting_user.id == t.partner,
+        Err(_) => false,
+    };
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if let Err(e) = delete_practice_bot(practice_bot.id.clone()) {
+        return HttpResponse::InternalServerError().body(e.to_string());
+    }
+
+    match remove_practice_bot_files(&practice_bot.id) {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
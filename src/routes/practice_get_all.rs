@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_practice_bots::get_practice_bots_by_competition_id, operations_teams::get_teams_by_competition_id},
+    models::practice_bot::PublicPracticeBot,
+};
+
+#[utoipa::path(
+    tag = "practice",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::practice_bot::PublicPracticeBot]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/practice/all/{comp_id}")]
+pub async fn practice_get_all(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let comp_id = comp_id.into_inner();
+    let practice_bots = match get_practice_bots_by_competition_id(comp_id.clone()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let team_names: HashMap<String, String> = match get_teams_by_competition_id(comp_id) {
+        Ok(teams) => teams.into_iter().map(|t| (t.id, t.name)).collect(),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    HttpResponse::Ok().json(
+        practice_bots
+            .into_iter()
+            .map(|p| {
+                let team_name = team_names.get(&p.team_id).cloned();
+                PublicPracticeBot::new(p, team_name)
+            })
+            .collect::<Vec<PublicPracticeBot>>()
+    )
+}
\ No newline at end of file
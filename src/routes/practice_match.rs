@@ -0,0 +1,75 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, practice::run_practice_match, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    db::{operations_practice_bots::get_practice_bot_by_id, operations_teams::get_team_by_id},
+    models::errors::MatchMakerError,
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PracticeMatchRequest {
+    pub team_id: String,
+    pub practice_bot_id: String,
+}
+
+#[utoipa::path(
+    tag = "practice",
+    request_body = PracticeMatchRequest,
+    responses(
+        (status = 200, description = "Success", body = crate::models::practice_bot::PracticeMatchResult),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/practice/match")]
+pub async fn practice_match(auth: BearerAuth, body: web::Json<PracticeMatchRequest>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let team = match get_team_by_id(body.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if team.bot1.is_empty() || team.bot2.is_empty() {
+        return HttpResponse::BadRequest().body("Team has no bots selected");
+    }
+
+    let practice_bot = match get_practice_bot_by_id(body.practice_bot_id.clone()) {
+        Ok(p) => p,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if practice_bot.competition_id != team.competition_id {
+        return HttpResponse::BadRequest().body("Practice bot is from a different competition");
+    }
+
+    // test matches are unranked work, they yield to a running ranked round
+    let result = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+        run_practice_match(&team, &practice_bot)
+    }).await;
+
+    match result {
+        Ok(Ok(r)) => HttpResponse::Ok().json(r),
+        Ok(Err(MatchMakerError::ExecutionDisabled)) => HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
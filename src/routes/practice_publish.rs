@@ -0,0 +1,76 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, practice::publish_practice_bot, workload_gate::acquire_unranked_slot, safe_mode::is_safe_mode},
+    db::{operations_bot::get_bot_by_id, operations_teams::get_team_by_id, operations_practice_bots::get_practice_bots_by_competition_id},
+    models::{practice_bot::{NewPracticeBot, PublicPracticeBot}, errors::MatchMakerError},
+};
+
+#[utoipa::path(
+    tag = "practice",
+    request_body = crate::models::practice_bot::NewPracticeBot,
+    responses(
+        (status = 200, description = "Success", body = crate::models::practice_bot::PublicPracticeBot),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/practice/publish")]
+pub async fn practice_publish(auth: BearerAuth, body: web::Json<NewPracticeBot>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let new_practice_bot = body.into_inner();
+    if new_practice_bot.name.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Practice bot needs a name");
+    }
+
+    let bot = match get_bot_by_id(new_practice_bot.bot_id.clone()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let team = match get_team_by_id(bot.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if !bot.compile_error.is_empty() {
+        return HttpResponse::BadRequest().body("Bot doesn't compile");
+    }
+
+    match get_practice_bots_by_competition_id(team.competition_id.clone()) {
+        Ok(published) if published.iter().any(|p| p.bot_id == bot.id) => return HttpResponse::Conflict().body("Bot is already published"),
+        Ok(_) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    // compiling is unranked work, yields to a running ranked round
+    let team_name = team.name.clone();
+    let published = web::block(move || {
+        let _permit = acquire_unranked_slot(None);
+        publish_practice_bot(&bot, &team, new_practice_bot)
+    }).await;
+
+    match published {
+        Ok(Ok(practice_bot)) => HttpResponse::Ok().json(PublicPracticeBot::new(practice_bot, Some(team_name))),
+        Ok(Err(MatchMakerError::ExecutionDisabled)) => HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode"),
+        Ok(Err(e)) => HttpResponse::InternalServerError().body(e.to_string()),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
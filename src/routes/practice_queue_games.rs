@@ -0,0 +1,43 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_practice_queue::get_practice_games_by_team_id, operations_teams::get_team_by_id},
+    models::{practice_queue::PublicPracticeGame, user::Role},
+};
+
+/// The practice queue games of a team, newest first. Only visible to the team's members.
+#[utoipa::path(
+    tag = "practice",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::practice_queue::PublicPracticeGame]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/practice/queue/{team_id}/games")]
+pub async fn practice_queue_games(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let is_member = requesting_user.id == team.owner || requesting_user.id == team.partner;
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_practice_games_by_team_id(team.id) {
+        Ok(games) => HttpResponse::Ok().json(games.into_iter().map(PublicPracticeGame::from).collect::<Vec<PublicPracticeGame>>()),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
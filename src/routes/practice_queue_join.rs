@@ -0,0 +1,57 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, safe_mode::is_safe_mode},
+    db::{operations_practice_queue::{get_practice_queue_entry_by_team_id, insert_practice_queue_entry}, operations_teams::get_team_by_id},
+    models::practice_queue::{NewPracticeQueueEntry, PracticeQueueEntry, PublicPracticeQueueEntry},
+};
+
+/// Queues a team for an unranked practice game against another queued team of similar ELO.
+/// Queued games are played when the machine is otherwise idle.
+#[utoipa::path(
+    tag = "practice",
+    request_body = crate::models::practice_queue::NewPracticeQueueEntry,
+    responses(
+        (status = 200, description = "Success", body = crate::models::practice_queue::PublicPracticeQueueEntry),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Team is already queued"),
+        (status = 500, description = "Server error"),
+        (status = 503, description = "Disabled, e.g. in safe mode"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/practice/queue")]
+pub async fn practice_queue_join(auth: BearerAuth, body: web::Json<NewPracticeQueueEntry>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if is_safe_mode() {
+        return HttpResponse::ServiceUnavailable().body("Game execution is disabled in safe mode");
+    }
+
+    let team = match get_team_by_id(body.team_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if team.bot1.is_empty() || team.bot2.is_empty() {
+        return HttpResponse::BadRequest().body("Team has no bots selected");
+    }
+
+    if get_practice_queue_entry_by_team_id(team.id.clone()).is_ok() {
+        return HttpResponse::Conflict().body("Team is already queued");
+    }
+
+    match insert_practice_queue_entry(PracticeQueueEntry::new(team.competition_id, team.id)) {
+        Ok(entry) => HttpResponse::Ok().json(PublicPracticeQueueEntry::from(entry)),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
\ No newline at end of file
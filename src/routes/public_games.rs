@@ -0,0 +1,51 @@
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use crate::{
+    controllers::api_keys::authorize_api_key,
+    db::{operations_competition::get_competition_by_id, operations_game2v2::{get_games_by_competition_round, get_last_played_round}},
+    models::{featured_game::FeaturedQuery, game_2v2::PublicGame2v2},
+};
+
+/// Results of a round's games, the latest round with games unless `round` is given.
+#[utoipa::path(
+    tag = "public api",
+    params(
+        ("comp_id" = String, Path, description = "Competition id"),
+        crate::models::featured_game::FeaturedQuery,
+    ),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::game_2v2::PublicGame2v2]),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("api_key" = [])),
+)]
+#[get("/public/competitions/{comp_id}/games")]
+pub async fn public_games(req: HttpRequest, comp_id: web::Path<String>, query: web::Query<FeaturedQuery>) -> HttpResponse {
+    if let Err(response) = authorize_api_key(&req) {
+        return response;
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    let round = match query.round {
+        Some(r) => r,
+        None => match get_last_played_round(competition.id.clone()) {
+            Ok(Some(r)) => r,
+            Ok(None) => return HttpResponse::Ok().json(Vec::<PublicGame2v2>::new()),
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        },
+    };
+
+    match get_games_by_competition_round(competition.id, round) {
+        Ok(games) => HttpResponse::Ok().json(
+            games
+                .into_iter()
+                .map(PublicGame2v2::from)
+                .collect::<Vec<PublicGame2v2>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
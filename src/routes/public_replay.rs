@@ -0,0 +1,41 @@
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use crate::{
+    controllers::{api_keys::authorize_api_key, file_handler::read_replay},
+    db::operations_game2v2::get_game_by_id,
+    models::game_2v2::ReplayState,
+};
+
+/// The replay of a public game.
+#[utoipa::path(
+    tag = "public api",
+    params(("game_id" = String, Path, description = "Game id")),
+    responses(
+        (status = 200, description = "Success", body = String, content_type = "text/plain"),
+        (status = 404, description = "Not found"),
+        (status = 410, description = "No longer available"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("api_key" = [])),
+)]
+#[get("/public/games/{game_id}/replay")]
+pub async fn public_replay(req: HttpRequest, game_id: web::Path<String>) -> HttpResponse {
+    if let Err(response) = authorize_api_key(&req) {
+        return response;
+    }
+
+    let game = match get_game_by_id(game_id.into_inner()) {
+        Ok(g) if g.public => g,
+        _ => return HttpResponse::NotFound().finish(),
+    };
+
+    if game.replay_state == ReplayState::Deleted || game.replay_state == ReplayState::Dropped {
+        return HttpResponse::Gone().finish();
+    }
+
+    match read_replay(&game.log_file_path) {
+        Ok(contents) => HttpResponse::Ok()
+            .content_type("application/text; charset=utf-8")
+            .body(contents),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use actix_web::{HttpRequest, HttpResponse, get, web};
+use crate::{
+    controllers::{api_keys::authorize_api_key, leaderboard::build_leaderboard},
+    db::operations_competition::get_competition_by_id,
+};
+
+#[utoipa::path(
+    tag = "public api",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::leaderboard::LeaderboardEntry]),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("api_key" = [])),
+)]
+#[get("/public/competitions/{comp_id}/standings")]
+pub async fn public_standings(req: HttpRequest, comp_id: web::Path<String>) -> HttpResponse {
+    if let Err(response) = authorize_api_key(&req) {
+        return response;
+    }
+
+    let competition = match get_competition_by_id(comp_id.into_inner()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match build_leaderboard(&competition) {
+        Ok(entries) => HttpResponse::Ok().json(entries),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
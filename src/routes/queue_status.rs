@@ -0,0 +1,13 @@
+use actix_web::{HttpResponse, get};
+use crate::controllers::workload_gate::workload_status;
+
+#[utoipa::path(
+    tag = "operations",
+    responses(
+        (status = 200, description = "Success", body = crate::controllers::workload_gate::WorkloadStatus),
+    ),
+)]
+#[get("/queue")]
+pub async fn queue_status() -> HttpResponse {
+    HttpResponse::Ok().json(workload_status())
+}
\ No newline at end of file
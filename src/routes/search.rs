@@ -0,0 +1,40 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, search::search as run_search},
+    models::search::{SearchQuery, DEFAULT_SEARCH_RESULTS, MAX_SEARCH_RESULTS},
+};
+
+/// Teams, users and competitions whose name starts with the search term, for the dashboard's
+/// search bar.
+#[utoipa::path(
+    tag = "users",
+    params(crate::models::search::SearchQuery),
+    responses(
+        (status = 200, description = "Success", body = crate::models::search::SearchResults),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/search")]
+pub async fn search(auth: BearerAuth, query: web::Query<SearchQuery>) -> HttpResponse {
+    if exchange_token_for_user(auth).is_none() {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let query = query.into_inner();
+    if query.q.trim().is_empty() {
+        return HttpResponse::BadRequest().body("q can't be empty");
+    }
+    let limit = query.limit.unwrap_or(DEFAULT_SEARCH_RESULTS);
+    if !(1..=MAX_SEARCH_RESULTS).contains(&limit) {
+        return HttpResponse::BadRequest().body(format!("limit has to be between 1 and {}", MAX_SEARCH_RESULTS));
+    }
+
+    match run_search(&query.q, query.competition_id, limit) {
+        Ok(results) => HttpResponse::Ok().json(results),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
@@ -0,0 +1,51 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::get_competitions_by_ids;
+use crate::db::operations_seasons::insert_season;
+use crate::models::season::{NewSeason, PublicSeason};
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "seasons",
+    request_body = crate::models::season::NewSeason,
+    responses(
+        (status = 200, description = "Success", body = crate::models::season::PublicSeason),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/season")]
+pub async fn season_create(auth: BearerAuth, body: web::Json<NewSeason>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let mut new_season = body.into_inner();
+    new_season.name = new_season.name.trim().to_string();
+    if new_season.name.is_empty() {
+        return HttpResponse::BadRequest().body("Season name can't be empty");
+    }
+
+    new_season.competition_ids.sort();
+    new_season.competition_ids.dedup();
+    match get_competitions_by_ids(new_season.competition_ids.clone()) {
+        Ok(c) if c.len() == new_season.competition_ids.len() => (),
+        Ok(_) => return HttpResponse::BadRequest().body("Unknown competition in the season"),
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    }
+
+    let competition_ids = new_season.competition_ids.clone();
+    match insert_season(new_season) {
+        Ok(s) => HttpResponse::Ok().json(PublicSeason::new(s, competition_ids)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
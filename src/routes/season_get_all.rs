@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, get};
+use crate::{
+    db::operations_seasons::{get_all_seasons, get_season_competition_ids},
+    models::season::PublicSeason,
+};
+
+#[utoipa::path(
+    tag = "seasons",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::season::PublicSeason]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/seasons")]
+pub async fn season_get_all() -> HttpResponse {
+    let seasons = match get_all_seasons() {
+        Ok(s) => s,
+        Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+    };
+
+    let mut public_seasons = vec![];
+    for season in seasons.into_iter() {
+        let competition_ids = match get_season_competition_ids(season.id.clone()) {
+            Ok(ids) => ids,
+            Err(e) => return HttpResponse::InternalServerError().json(e.to_string()),
+        };
+        public_seasons.push(PublicSeason::new(season, competition_ids));
+    }
+
+    HttpResponse::Ok().json(public_seasons)
+}
\ No newline at end of file
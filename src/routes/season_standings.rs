@@ -0,0 +1,27 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    controllers::seasons::build_season_standings,
+    db::operations_seasons::get_season_by_id,
+};
+
+#[utoipa::path(
+    tag = "seasons",
+    params(("season_id" = String, Path, description = "Season id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::season::SeasonStandings),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/seasons/{season_id}/standings")]
+pub async fn season_standings(season_id: web::Path<String>) -> HttpResponse {
+    let season = match get_season_by_id(season_id.into_inner()) {
+        Ok(s) => s,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match build_season_standings(season) {
+        Ok(standings) => HttpResponse::Ok().json(standings),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
+    }
+}
\ No newline at end of file
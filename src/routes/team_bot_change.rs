@@ -1,19 +1,34 @@
 use actix_web::{HttpResponse, post, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::Local;
 use serde::Deserialize;
-use crate::controllers::jwt::exchange_token_for_user;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, bot_versions::activate_bot, submission_window::slot_changes_closed_reason, smoke_test::smoke_test_blocks_slot_reason};
 use crate::db::operations_bot::get_bot_by_id_and_team;
-use crate::db::operations_teams::{get_team_by_student_for_competition, set_team_bot};
+use crate::db::operations_competition::get_competition_by_id;
+use crate::db::operations_teams::get_team_by_student_for_competition;
 use crate::models::team::BotSelector;
 
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ChangeBotData {
     pub competition_id: String,
     pub bot: BotSelector,
     pub bot_id: String,
 }
 
+#[utoipa::path(
+    tag = "teams",
+    request_body = ChangeBotData,
+    responses(
+        (status = 200, description = "Success"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/team/bot")]
 pub async fn team_bot_change(auth: BearerAuth, body: web::Json<ChangeBotData>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
@@ -29,13 +44,30 @@ pub async fn team_bot_change(auth: BearerAuth, body: web::Json<ChangeBotData>) -
         Err(_) => return HttpResponse::Forbidden().finish(),
     };
 
+    // can the slots still be changed
+    let competition = match get_competition_by_id(team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if let Some(reason) = slot_changes_closed_reason(&competition, Local::now().naive_utc()) {
+        return HttpResponse::Forbidden().body(reason);
+    }
+
     // does bot exist?
     let bot = match get_bot_by_id_and_team(change_bot_data.bot_id, team.id.clone()) {
         Ok(b) => b,
         Err(_) => return HttpResponse::Forbidden().finish(),
     };
 
-    match set_team_bot(&team, change_bot_data.bot, bot.id) {
+    if !bot.compile_error.is_empty() {
+        return HttpResponse::BadRequest().body("Bot doesn't compile");
+    }
+
+    if let Some(reason) = smoke_test_blocks_slot_reason(&bot) {
+        return HttpResponse::BadRequest().body(reason);
+    }
+
+    match activate_bot(&team, change_bot_data.bot, &bot) {
         Ok(_) =>  HttpResponse::Ok().finish(),
         Err(_) => HttpResponse::InternalServerError().finish(),
     }
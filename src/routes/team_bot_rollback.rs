@@ -0,0 +1,85 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::Local;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, bot_versions::{activate_bot, rollback_target}, submission_window::slot_changes_closed_reason},
+    db::{operations_bot::get_bot_by_id, operations_bot_versions::get_bot_versions_by_team_id, operations_competition::get_competition_by_id, operations_teams::get_team_by_id},
+    models::{bot_version::{CompileStatus, PublicBotVersion}, team::BotSelector},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RollbackData {
+    pub team_id: String,
+    pub bot: BotSelector,
+    /// version to roll back to, the slot's previous bot if not set
+    pub version_id: Option<String>,
+}
+
+#[utoipa::path(
+    tag = "teams",
+    request_body = RollbackData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot_version::PublicBotVersion),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/team/bot/rollback")]
+pub async fn team_bot_rollback(auth: BearerAuth, body: web::Json<RollbackData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+    let rollback = body.into_inner();
+
+    let team = match get_team_by_id(rollback.team_id) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if let Some(reason) = slot_changes_closed_reason(&competition, Local::now().naive_utc()) {
+        return HttpResponse::Forbidden().body(reason);
+    }
+
+    let versions = match get_bot_versions_by_team_id(team.id.clone()) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let active_bot = match rollback.bot {
+        BotSelector::First => team.bot1.clone(),
+        BotSelector::Second => team.bot2.clone(),
+    };
+    let target = match rollback_target(versions, rollback.bot, &active_bot, rollback.version_id) {
+        Some(v) => v,
+        None => return HttpResponse::NotFound().body("No version to roll back to"),
+    };
+
+    if target.compile_status == CompileStatus::Failed {
+        return HttpResponse::BadRequest().body("Can't roll back to a bot that doesn't compile");
+    }
+
+    let bot = match get_bot_by_id(target.bot_id) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match activate_bot(&team, rollback.bot, &bot) {
+        Ok(version) => HttpResponse::Ok().json(PublicBotVersion::new(version, true)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_bot_versions::get_bot_versions_by_team_id, operations_teams::get_team_by_id},
+    models::{bot_version::PublicBotVersion, team::BotSelector, user::Role},
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::bot_version::PublicBotVersion]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/team/bot/versions/{team_id}")]
+pub async fn team_bot_versions(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let versions = match get_bot_versions_by_team_id(team.id.clone()) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // only the newest version of a slot is active, an older version of the same bot isn't
+    let mut seen_slots = vec![];
+    let versions: Vec<PublicBotVersion> = versions
+        .into_iter()
+        .map(|v| {
+            let active_bot = if v.slot == BotSelector::First { &team.bot1 } else { &team.bot2 };
+            let active = !seen_slots.contains(&v.slot) && v.bot_id == *active_bot;
+            seen_slots.push(v.slot);
+            PublicBotVersion::new(v, active)
+        })
+        .collect();
+
+    HttpResponse::Ok().json(versions)
+}
\ No newline at end of file
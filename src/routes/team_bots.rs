@@ -9,6 +9,17 @@ use crate::{
     },
 };
 
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::bot::PublicBot]),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/team/bots/{team_id}")]
 pub async fn team_bots(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -1,10 +1,23 @@
 use actix_web::{HttpResponse, post, web};
+use chrono::Local;
 use actix_web_httpauth::extractors::bearer::BearerAuth;
-use crate::controllers::jwt::exchange_token_for_user;
+use crate::controllers::{jwt::exchange_token_for_user, team_changes::validate_team_name, registration::registration_closed_reason};
 use crate::db::operations_competition::get_competition_by_id;
 use crate::db::operations_teams::create_team;
 use crate::models::team::{NewTeam, PublicTeam};
 
+#[utoipa::path(
+    tag = "teams",
+    request_body = crate::models::team::NewTeam,
+    responses(
+        (status = 200, description = "Success", body = crate::models::team::PublicTeam),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/team")]
 pub async fn team_create(auth: BearerAuth, body: web::Json<NewTeam>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
@@ -12,7 +25,11 @@ pub async fn team_create(auth: BearerAuth, body: web::Json<NewTeam>) -> HttpResp
         None => return HttpResponse::Unauthorized().finish()
     };
 
-    let new_team = body.into_inner();
+    let mut new_team = body.into_inner();
+    new_team.name = match validate_team_name(&new_team.name) {
+        Ok(n) => n,
+        Err(reason) => return HttpResponse::BadRequest().body(reason),
+    };
 
     if !user.id.eq(&new_team.owner) {
         return HttpResponse::Forbidden().finish();
@@ -20,11 +37,17 @@ pub async fn team_create(auth: BearerAuth, body: web::Json<NewTeam>) -> HttpResp
 
 
     // does competition exist
-    if let Err(_) = get_competition_by_id(new_team.competition_id.clone()) {
-        return HttpResponse::BadRequest().finish();
+    let competition = match get_competition_by_id(new_team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::BadRequest().finish(),
+    };
+
+    if let Some(reason) = registration_closed_reason(&competition, Local::now().naive_utc()) {
+        return HttpResponse::Forbidden().body(reason);
     }
 
-    match create_team(new_team) {
+    // past the cap teams are still registered, but wait for a team to drop out
+    match create_team(new_team, competition.max_teams) {
         Ok(c) => HttpResponse::Ok().json(PublicTeam::from(c)),
         Err(e) => HttpResponse::InternalServerError().json(e.to_string())
     }
@@ -1,14 +1,28 @@
 use actix_web::{HttpResponse, post, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use serde::Deserialize;
-use crate::controllers::jwt::exchange_token_for_user;
+use utoipa::ToSchema;
+use tracing::error;
+use crate::controllers::{jwt::exchange_token_for_user, team_changes::team_changes_closed_reason_by_id, registration::promote_waitlisted_teams};
+use crate::db::operations_competition::get_competition_by_id;
 use crate::db::operations_teams::{get_team_by_id, disband_team};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LeaveTeamData {
     pub team_id: String,
 }
 
+#[utoipa::path(
+    tag = "teams",
+    request_body = LeaveTeamData,
+    responses(
+        (status = 200, description = "Success"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/team/disband")]
 pub async fn team_disband(auth: BearerAuth, body: web::Json<LeaveTeamData>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
@@ -29,9 +43,23 @@ pub async fn team_disband(auth: BearerAuth, body: web::Json<LeaveTeamData>) -> H
         return HttpResponse::Forbidden().finish();
     }
 
-    match disband_team(team, user) {
-        Ok(_) =>  HttpResponse::Ok().finish(),
-        Err(_) => HttpResponse::InternalServerError().finish(),
+    match team_changes_closed_reason_by_id(team.competition_id.clone()) {
+        Ok(Some(reason)) => return HttpResponse::Forbidden().body(reason),
+        Ok(None) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
     }
 
+    let competition_id = team.competition_id.clone();
+    if disband_team(team, user).is_err() {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    // the spot is free for the next team on the waitlist
+    let promoted = get_competition_by_id(competition_id)
+        .and_then(|competition| promote_waitlisted_teams(&competition));
+    if let Err(e) = promoted {
+        error!("Failed promoting waitlisted teams: {}", e);
+    }
+    HttpResponse::Ok().finish()
+
 }
\ No newline at end of file
@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    models::elo_history::PublicEloHistory, 
+    db::operations_elo_history::get_elo_history_by_team_id,
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::elo_history::PublicEloHistory]),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/team/elo/{team_id}")]
+pub async fn team_elo_history(team_id: web::Path<String>) -> HttpResponse {
+    match get_elo_history_by_team_id(team_id.into_inner()) {
+        Ok(entries) => HttpResponse::Ok().json(
+            entries
+                .into_iter()
+                .map(PublicEloHistory::from)
+                .collect::<Vec<PublicEloHistory>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -6,6 +6,15 @@ use crate::{
     db::operations_teams::get_team_by_student,
 };
 
+#[utoipa::path(
+    tag = "teams",
+    responses(
+        (status = 200, description = "Success", body = [crate::models::team::PublicTeam]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/team")]
 pub async fn team_get(auth: BearerAuth) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
@@ -6,6 +6,17 @@ use crate::{
     db::operations_teams::get_teams_by_competition_id,
 };
 
+#[utoipa::path(
+    tag = "teams",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::team::PublicTeam]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/team/all/{comp_id}")]
 pub async fn team_get_all(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
     let requesting_user = match exchange_token_for_user(auth) {
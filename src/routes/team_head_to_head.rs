@@ -0,0 +1,57 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, head_to_head::build_head_to_head}, 
+    db::operations_teams::get_team_by_id, 
+    models::user::Role,
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(
+        ("team_a" = String, Path, description = "Team id"),
+        ("team_b" = String, Path, description = "Id of the other team"),
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::head_to_head::HeadToHead),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/teams/{team_a}/vs/{team_b}")]
+pub async fn team_head_to_head(auth: BearerAuth, path: web::Path<(String, String)>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let (team_a_id, team_b_id) = path.into_inner();
+
+    let team_a = match get_team_by_id(team_a_id) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+    let team_b = match get_team_by_id(team_b_id) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if team_a.competition_id != team_b.competition_id {
+        return HttpResponse::BadRequest().body("Teams are not in the same competition");
+    }
+
+    let is_member = [&team_a, &team_b]
+        .iter()
+        .any(|t| requesting_user.id == t.owner || requesting_user.id == t.partner);
+    if !is_member && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match build_head_to_head(team_a.id, team_b.id) {
+        Ok(h2h) => HttpResponse::Ok().json(h2h),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
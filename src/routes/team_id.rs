@@ -1,13 +1,35 @@
 use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
 use crate::{
+    controllers::jwt::exchange_token_for_user,
     models::team::PublicTeam, 
     db::operations_teams::get_team_by_id,
 };
 
+/// A team. Without a login the team is listed under its alias and without its members if
+/// it hides them.
+#[utoipa::path(
+    tag = "teams",
+    params(("id" = String, Path, description = "Id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::team::PublicTeam),
+        (status = 401, description = "Invalid credentials"),
+    ),
+    security((), ("bearer" = [])),
+)]
 #[get("/team/{id}")]
-pub async fn team_id(id: web::Path<String>) -> HttpResponse {
+pub async fn team_id(auth: Option<BearerAuth>, id: web::Path<String>) -> HttpResponse {
+    let logged_in = match auth {
+        None => false,
+        Some(token) => match exchange_token_for_user(token) {
+            Some(_) => true,
+            None => return HttpResponse::Unauthorized().finish(),
+        },
+    };
+
     match get_team_by_id(id.into_inner()) {
-        Ok(team) => HttpResponse::Ok().json(PublicTeam::from(team)),
+        Ok(team) if logged_in => HttpResponse::Ok().json(PublicTeam::from(team)),
+        Ok(team) => HttpResponse::Ok().json(PublicTeam::anonymized(team)),
         Err(_) => HttpResponse::Ok().finish()
     }
 }
\ No newline at end of file
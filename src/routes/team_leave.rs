@@ -1,14 +1,27 @@
 use actix_web::{HttpResponse, post, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use serde::Deserialize;
-use crate::controllers::jwt::exchange_token_for_user;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, team_changes::team_changes_closed_reason_by_id};
 use crate::db::operations_teams::{get_team_by_id, leave_team};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct LeaveTeamData {
     pub team_id: String,
 }
 
+#[utoipa::path(
+    tag = "teams",
+    request_body = LeaveTeamData,
+    responses(
+        (status = 200, description = "Success"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/team/leave")]
 pub async fn team_leave(auth: BearerAuth, body: web::Json<LeaveTeamData>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
@@ -35,6 +48,12 @@ pub async fn team_leave(auth: BearerAuth, body: web::Json<LeaveTeamData>) -> Htt
         return HttpResponse::BadRequest().finish();
     }
 
+    match team_changes_closed_reason_by_id(team.competition_id.clone()) {
+        Ok(Some(reason)) => return HttpResponse::Forbidden().body(reason),
+        Ok(None) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
     match leave_team(team, user) {
         Ok(_) =>  HttpResponse::Ok().finish(),
         Err(_) => HttpResponse::InternalServerError().finish(),
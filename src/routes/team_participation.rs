@@ -0,0 +1,52 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user, 
+    models::{participation::PublicParticipation, user::Role}, 
+    db::{
+        operations_teams::get_team_by_id, 
+        operations_participation::get_participations_by_team_id
+    },
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::participation::PublicParticipation]),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/team/participation/{team_id}")]
+pub async fn team_participation(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::BadRequest().finish(),
+    };
+
+    if 
+        requesting_user.id != team.owner && 
+        requesting_user.id != team.partner && 
+        requesting_user.role != Role::Admin 
+    {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    match get_participations_by_team_id(team.id) {
+        Ok(rows) => HttpResponse::Ok().json(
+            rows
+                .into_iter()
+                .map(PublicParticipation::from)
+                .collect::<Vec<PublicParticipation>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
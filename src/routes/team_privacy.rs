@@ -0,0 +1,60 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, team_changes::validate_team_name};
+use crate::db::operations_teams::{get_team_by_student_for_competition, set_team_privacy};
+use crate::models::team::PublicTeam;
+
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TeamPrivacyData {
+    pub competition_id: String,
+    /// shown instead of the team's name on public pages, empty to use the name
+    pub alias: String,
+    /// hide the owner and partner from requests without a login
+    pub hide_members: bool,
+}
+
+/// Sets the alias a team goes by on public pages and whether its members are hidden there.
+/// Unlike the name, it can be changed while team changes are closed.
+#[utoipa::path(
+    tag = "teams",
+    request_body = TeamPrivacyData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::team::PublicTeam),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/team/privacy")]
+pub async fn team_privacy(auth: BearerAuth, body: web::Json<TeamPrivacyData>) -> HttpResponse {
+    let user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let privacy_data = body.into_inner();
+
+    let team = match get_team_by_student_for_competition(user, privacy_data.competition_id) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::Forbidden().finish(),
+    };
+
+    let alias = if privacy_data.alias.trim().is_empty() {
+        "".to_string()
+    } else {
+        match validate_team_name(&privacy_data.alias) {
+            Ok(a) => a,
+            Err(reason) => return HttpResponse::BadRequest().body(reason),
+        }
+    };
+
+    match set_team_privacy(&team, alias, privacy_data.hide_members) {
+        Ok(t) => HttpResponse::Ok().json(PublicTeam::from(t)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -1,17 +1,30 @@
 use actix_web::{HttpResponse, post, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use serde::Deserialize;
-use crate::controllers::jwt::exchange_token_for_user;
+use utoipa::ToSchema;
+use crate::controllers::{jwt::exchange_token_for_user, team_changes::{team_changes_closed_reason_by_id, validate_team_name}};
 use crate::db::operations_teams::{get_team_by_student_for_competition, set_team_name};
 use crate::models::team::PublicTeam;
 
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ChangeNameData {
     pub name: String,
     pub competition_id: String,
 }
 
+#[utoipa::path(
+    tag = "teams",
+    request_body = ChangeNameData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::team::PublicTeam),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[post("/team/name")]
 pub async fn team_name_change(auth: BearerAuth, body: web::Json<ChangeNameData>) -> HttpResponse {
     let user = match exchange_token_for_user(auth) {
@@ -27,7 +40,18 @@ pub async fn team_name_change(auth: BearerAuth, body: web::Json<ChangeNameData>)
         Err(_) => return HttpResponse::Forbidden().finish(),
     };
 
-    match set_team_name(&team, change_name_data.name) {
+    let new_name = match validate_team_name(&change_name_data.name) {
+        Ok(n) => n,
+        Err(reason) => return HttpResponse::BadRequest().body(reason),
+    };
+
+    match team_changes_closed_reason_by_id(team.competition_id.clone()) {
+        Ok(Some(reason)) => return HttpResponse::Forbidden().body(reason),
+        Ok(None) => (),
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    match set_team_name(&team, new_name) {
         Ok(t) =>  HttpResponse::Ok().json(PublicTeam::from(t)),
         Err(_) => HttpResponse::InternalServerError().finish(),
     }
@@ -0,0 +1,58 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_teams::{get_deleted_team_by_id, get_team_by_id, get_teams_by_competition_id, restore_team};
+use crate::models::team::PublicTeam;
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::team::PublicTeam),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/team/restore/{team_id}")]
+pub async fn team_restore(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let team = match get_deleted_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    // members may have registered another team in the meantime
+    let competition_teams = match get_teams_by_competition_id(team.competition_id.clone()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    let members = [&team.owner, &team.partner];
+    let member_taken = competition_teams
+        .iter()
+        .any(|t| members.iter().any(|m| !m.is_empty() && (**m == t.owner || **m == t.partner)));
+    if member_taken {
+        return HttpResponse::Conflict().body("A member of the team is in another team of the competition");
+    }
+
+    if restore_team(team.id.clone()).is_err() {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    match get_team_by_id(team.id) {
+        Ok(t) => HttpResponse::Ok().json(PublicTeam::from(t)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    models::scouting::{PublicScoutingReport, ScoutingQuery, DEFAULT_SCOUTING_GAMES, MAX_SCOUTING_GAMES},
+    db::{operations_scouting::get_scouting_stats, operations_teams::get_team_by_id},
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(
+        ("team_id" = String, Path, description = "Team id"),
+        crate::models::scouting::ScoutingQuery,
+    ),
+    responses(
+        (status = 200, description = "Success", body = crate::models::scouting::PublicScoutingReport),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/teams/{team_id}/scouting")]
+pub async fn team_scouting(team_id: web::Path<String>, query: web::Query<ScoutingQuery>) -> HttpResponse {
+    let games = query.games.unwrap_or(DEFAULT_SCOUTING_GAMES);
+    if !(1..=MAX_SCOUTING_GAMES).contains(&games) {
+        return HttpResponse::BadRequest().body(format!("games has to be between 1 and {}", MAX_SCOUTING_GAMES));
+    }
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match get_scouting_stats(team.id.clone(), games) {
+        Ok(stats) => HttpResponse::Ok().json(PublicScoutingReport::new(team.id, team.name, stats)),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
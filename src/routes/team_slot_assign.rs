@@ -0,0 +1,88 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use chrono::Local;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, bot_versions::activate_bot, submission_window::slot_changes_closed_reason, smoke_test::smoke_test_blocks_slot_reason},
+    db::{operations_bot::get_bot_by_id_and_team, operations_competition::get_competition_by_id, operations_teams::get_team_by_id},
+    models::{bot_version::PublicBotVersion, team::BotSelector},
+};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SlotAssignmentData {
+    pub team_id: String,
+    pub slot: BotSelector,
+    pub bot_id: String,
+}
+
+/// Puts one of the team's uploaded bots into a slot and records it as the slot's new version.
+///
+/// The bot has to compile, must not have failed its smoke test and the competition has to
+/// accept submissions.
+#[utoipa::path(
+    tag = "teams",
+    request_body = SlotAssignmentData,
+    responses(
+        (status = 200, description = "Success", body = crate::models::bot_version::PublicBotVersion),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Conflicts with the current state"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/team/slot")]
+pub async fn team_slot_assign(auth: BearerAuth, body: web::Json<SlotAssignmentData>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+    let assignment = body.into_inner();
+
+    let team = match get_team_by_id(assignment.team_id) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let competition = match get_competition_by_id(team.competition_id.clone()) {
+        Ok(c) => c,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    if let Some(reason) = slot_changes_closed_reason(&competition, Local::now().naive_utc()) {
+        return HttpResponse::Forbidden().body(reason);
+    }
+
+    // only the team's own bots that weren't deleted
+    let bot = match get_bot_by_id_and_team(assignment.bot_id, team.id.clone()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if !bot.compile_error.is_empty() {
+        return HttpResponse::BadRequest().body("Bot doesn't compile");
+    }
+
+    if let Some(reason) = smoke_test_blocks_slot_reason(&bot) {
+        return HttpResponse::BadRequest().body(reason);
+    }
+
+    let active_bot = match assignment.slot {
+        BotSelector::First => &team.bot1,
+        BotSelector::Second => &team.bot2,
+    };
+    if *active_bot == bot.id {
+        return HttpResponse::Conflict().body("Bot is already in this slot");
+    }
+
+    match activate_bot(&team, assignment.slot, &bot) {
+        Ok(version) => HttpResponse::Ok().json(PublicBotVersion::new(version, true)),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::{operations_bot::get_bot_by_id, operations_bot_versions::get_bot_versions_by_team_id, operations_teams::get_team_by_id},
+    models::{bot::PublicBot, bot_version::BotSlot, team::BotSelector, user::Role},
+};
+
+/// The bots in both of the team's slots and when they were assigned.
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::bot_version::BotSlot]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/team/slots/{team_id}")]
+pub async fn team_slots(auth: BearerAuth, team_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let team = match get_team_by_id(team_id.into_inner()) {
+        Ok(t) => t,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if requesting_user.id != team.owner && requesting_user.id != team.partner && requesting_user.role != Role::Admin {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let versions = match get_bot_versions_by_team_id(team.id.clone()) {
+        Ok(v) => v,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    let mut slots = vec![];
+    for (slot, bot_id) in [(BotSelector::First, &team.bot1), (BotSelector::Second, &team.bot2)] {
+        if bot_id.is_empty() {
+            slots.push(BotSlot { slot, bot: None, assigned: None });
+            continue;
+        }
+        let bot = match get_bot_by_id(bot_id.clone()) {
+            Ok(b) => b,
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+        // versions are newest first, the slot's newest one is the active bot's
+        let assigned = versions
+            .iter()
+            .find(|v| v.slot == slot)
+            .filter(|v| v.bot_id == *bot_id)
+            .map(|v| v.created);
+        slots.push(BotSlot { slot, bot: Some(PublicBot::from(bot)), assigned });
+    }
+
+    HttpResponse::Ok().json(slots)
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use actix_web::{HttpResponse, get, web};
+use crate::{
+    models::team_stats::PublicTeamStats, 
+    db::{operations_team_stats::get_team_stats, operations_teams::get_team_by_id},
+};
+
+#[utoipa::path(
+    tag = "teams",
+    params(("team_id" = String, Path, description = "Team id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::team_stats::PublicTeamStats),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+)]
+#[get("/teams/{team_id}/stats")]
+pub async fn team_stats(team_id: web::Path<String>) -> HttpResponse {
+    let team_id = team_id.into_inner();
+
+    if get_team_by_id(team_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match get_team_stats(team_id.clone()) {
+        Ok(stats) => HttpResponse::Ok().json(PublicTeamStats::new(team_id, stats)),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
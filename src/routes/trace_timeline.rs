@@ -0,0 +1,36 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::{jwt::exchange_token_for_user, trace::get_trace_timeline}, 
+    models::user::Role,
+};
+
+#[utoipa::path(
+    tag = "operations",
+    params(("trace_id" = String, Path, description = "Trace id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::trace_span::TraceTimeline),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/trace/{trace_id}")]
+pub async fn trace_timeline(auth: BearerAuth, trace_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_trace_timeline(trace_id.into_inner()) {
+        Ok(timeline) if timeline.spans.is_empty() => HttpResponse::NotFound().finish(),
+        Ok(timeline) => HttpResponse::Ok().json(timeline),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use actix_web::{HttpResponse, delete, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_users::{erase_user, get_user_by_id};
+use crate::models::user::Role;
+
+/// Erases a user's personal data on request. Users that never played in a team are removed,
+/// others are anonymized so the results of their teams stay intact.
+#[utoipa::path(
+    tag = "users",
+    params(("user_id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::UserErasure),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[delete("/user/{user_id}")]
+pub async fn user_delete(auth: BearerAuth, user_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let user = match get_user_by_id(user_id.into_inner()) {
+        Ok(u) => u,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    if user.id == requesting_user.id {
+        return HttpResponse::BadRequest().body("Admins can't delete their own account");
+    }
+
+    match erase_user(user.id) {
+        Ok(erasure) => HttpResponse::Ok().json(erasure),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
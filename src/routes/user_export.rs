@@ -0,0 +1,42 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::{jwt::exchange_token_for_user, user_data::export_user_data};
+use crate::db::operations_users::get_user_by_id;
+use crate::models::user::Role;
+
+/// All data stored about a user, for the user themselves or an admin handling a data access
+/// request.
+#[utoipa::path(
+    tag = "users",
+    params(("user_id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::user_data::UserDataExport),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/user/{user_id}/export")]
+pub async fn user_export(auth: BearerAuth, user_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let user_id = user_id.into_inner();
+    if requesting_user.role != Role::Admin && requesting_user.id != user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let user = match get_user_by_id(user_id) {
+        Ok(u) => u,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match export_user_data(user) {
+        Ok(export) => HttpResponse::Ok().json(export),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
\ No newline at end of file
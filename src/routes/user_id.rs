@@ -2,6 +2,16 @@ use actix_web::{HttpResponse, get, web};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use crate::{controllers::jwt::exchange_token_for_user, models::user::PublicUser, db::operations_users::get_user_by_id};
 
+#[utoipa::path(
+    tag = "users",
+    params(("user_id" = String, Path, description = "User id")),
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::PublicUser),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/user/{user_id}")]
 pub async fn user_id(auth: BearerAuth, user_id: web::Path<String>) -> HttpResponse {
     if let None = exchange_token_for_user(auth) {
@@ -0,0 +1,40 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::authorize,
+    db::operations_users::{get_user_by_id, set_user_lab_group},
+    models::user::{PublicUser, Role, UserLabGroupChange},
+};
+
+/// Puts a user into a lab group, competitions can keep teams that share a lab group apart
+/// when pairing.
+#[utoipa::path(
+    tag = "users",
+    request_body = crate::models::user::UserLabGroupChange,
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::PublicUser),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/user/lab_group")]
+pub async fn user_lab_group(auth: BearerAuth, body: web::Json<UserLabGroupChange>) -> HttpResponse {
+    if let Err(response) = authorize(auth, &[Role::Admin]) {
+        return response;
+    }
+
+    let change = body.into_inner();
+    if get_user_by_id(change.user_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    if let Err(e) = set_user_lab_group(change.user_id.clone(), change.lab_group.trim().to_string()) {
+        return HttpResponse::InternalServerError().json(e.to_string());
+    }
+
+    match get_user_by_id(change.user_id) {
+        Ok(u) => HttpResponse::Ok().json(PublicUser::from(u)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
+    }
+}
\ No newline at end of file
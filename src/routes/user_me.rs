@@ -1,11 +1,19 @@
 use actix_web::{HttpResponse, get};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
-use crate::{controllers::jwt::exchange_token_for_user, models::user::PublicUser};
+use crate::{controllers::jwt::exchange_token_for_user, models::user::UserProfile};
 
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::UserProfile),
+        (status = 401, description = "Missing or invalid credentials"),
+    ),
+    security(("bearer" = [])),
+)]
 #[get("/user/me")]
 pub async fn user_me(auth: BearerAuth) -> HttpResponse {
     match exchange_token_for_user(auth) {
-        Some(u) => HttpResponse::Ok().json(PublicUser::from(u)),
+        Some(u) => HttpResponse::Ok().json(UserProfile::from(u)),
         None => HttpResponse::Unauthorized().finish()
     }
 }
\ No newline at end of file
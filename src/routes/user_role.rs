@@ -0,0 +1,50 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::authorize,
+    db::operations_users::{get_user_by_id, set_user_role},
+    models::user::{PublicUser, Role, UserRoleChange},
+};
+
+/// Changes the role of a user. The new role applies to tokens issued after the change,
+/// routes that check the role against the database see it immediately.
+#[utoipa::path(
+    tag = "users",
+    request_body = crate::models::user::UserRoleChange,
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::PublicUser),
+        (status = 400, description = "Invalid request"),
+        (status = 404, description = "Not found"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/user/role")]
+pub async fn user_role(auth: BearerAuth, body: web::Json<UserRoleChange>) -> HttpResponse {
+    let requesting_user = match authorize(auth, &[Role::Admin]) {
+        Ok(u) => u,
+        Err(response) => return response,
+    };
+
+    let change = body.into_inner();
+    if !["STUDENT", "ADMIN", "SPECTATOR"].contains(&change.role.as_str()) {
+        return HttpResponse::BadRequest().body("Role has to be one of STUDENT, ADMIN or SPECTATOR");
+    }
+
+    if change.user_id == requesting_user.id {
+        return HttpResponse::BadRequest().body("Admins can't change their own role");
+    }
+
+    if get_user_by_id(change.user_id.clone()).is_err() {
+        return HttpResponse::NotFound().finish();
+    }
+
+    if let Err(e) = set_user_role(change.user_id.clone(), &Role::from(change.role.as_str())) {
+        return HttpResponse::InternalServerError().json(e.to_string());
+    }
+
+    match get_user_by_id(change.user_id) {
+        Ok(u) => HttpResponse::Ok().json(PublicUser::from(u)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use actix_web::{HttpResponse, patch, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user,
+    db::operations_users::update_user_profile,
+    models::user::{UserProfile, UserProfileUpdate},
+};
+
+const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+
+#[utoipa::path(
+    tag = "users",
+    request_body = crate::models::user::UserProfileUpdate,
+    responses(
+        (status = 200, description = "Success", body = crate::models::user::UserProfile),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[patch("/user/me")]
+pub async fn user_update(auth: BearerAuth, body: web::Json<UserProfileUpdate>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    let update = body.into_inner();
+    let display_name = update.display_name
+        .map(|n| n.trim().to_string())
+        .unwrap_or(requesting_user.display_name);
+    let email = update.email
+        .map(|e| e.trim().to_string())
+        .unwrap_or(requesting_user.email);
+
+    if display_name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+        return HttpResponse::BadRequest().body(format!("Display name can have at most {} characters", MAX_DISPLAY_NAME_LENGTH));
+    }
+
+    if !email.is_empty() && (email.len() > 255 || !is_plausible_email(&email)) {
+        return HttpResponse::BadRequest().body("Invalid email address");
+    }
+
+    match update_user_profile(requesting_user.id, display_name, email) {
+        Ok(u) => HttpResponse::Ok().json(UserProfile::from(u)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string()),
+    }
+}
+
+fn is_plausible_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.') && !email.contains(char::is_whitespace),
+        None => false,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::controllers::jwt::exchange_token_for_user;
+use crate::db::operations_competition::get_competition_by_id;
+use crate::db::operations_webhooks::insert_webhook;
+use crate::models::webhook::{NewWebhook, PublicWebhook};
+use crate::models::user::Role;
+
+#[utoipa::path(
+    tag = "competitions",
+    request_body = crate::models::webhook::NewWebhook,
+    responses(
+        (status = 200, description = "Success", body = crate::models::webhook::PublicWebhook),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/webhook")]
+pub async fn webhook_create(auth: BearerAuth, body: web::Json<NewWebhook>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if Role::Admin != requesting_user.role {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let new_webhook = body.into_inner();
+
+    // does competition exist
+    if get_competition_by_id(new_webhook.competition_id.clone()).is_err() {
+        return HttpResponse::BadRequest().finish();
+    }
+
+    let url = new_webhook.url.trim();
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return HttpResponse::BadRequest().body("Webhook url must be an http(s) url");
+    }
+
+    match insert_webhook(new_webhook) {
+        Ok(w) => HttpResponse::Ok().json(PublicWebhook::from(w)),
+        Err(e) => HttpResponse::InternalServerError().json(e.to_string())
+    }
+}
\ No newline at end of file
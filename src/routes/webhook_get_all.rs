@@ -0,0 +1,40 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::jwt::exchange_token_for_user, 
+    models::{webhook::PublicWebhook, user::Role}, 
+    db::operations_webhooks::get_webhooks_by_competition_id,
+};
+
+#[utoipa::path(
+    tag = "competitions",
+    params(("comp_id" = String, Path, description = "Competition id")),
+    responses(
+        (status = 200, description = "Success", body = [crate::models::webhook::PublicWebhook]),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 403, description = "Not allowed"),
+        (status = 500, description = "Server error"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/webhook/all/{comp_id}")]
+pub async fn webhook_get_all(auth: BearerAuth, comp_id: web::Path<String>) -> HttpResponse {
+    let requesting_user = match exchange_token_for_user(auth) {
+        Some(u) => u,
+        None => return HttpResponse::Unauthorized().finish()
+    };
+
+    if requesting_user.role != Role::Admin {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    match get_webhooks_by_competition_id(comp_id.into_inner()) {
+        Ok(webhooks) => HttpResponse::Ok().json(
+            webhooks
+                .into_iter()
+                .map(PublicWebhook::from)
+                .collect::<Vec<PublicWebhook>>()
+        ),
+        Err(_) => HttpResponse::InternalServerError().finish()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use actix_web::{HttpResponse, get, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::match_dispatch::{is_worker_token, zip_compiled_bot},
+    db::operations_bot::get_bot_by_id,
+};
+
+/// The compiled files of a bot as a zip, for a match worker playing one of its games.
+#[utoipa::path(
+    tag = "workers",
+    params(("bot_id" = String, Path, description = "Bot id")),
+    responses(
+        (status = 200, description = "The archive", content_type = "application/zip"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 404, description = "Not found"),
+    ),
+    security(("bearer" = [])),
+)]
+#[get("/workers/bots/{bot_id}")]
+pub async fn worker_bot(auth: BearerAuth, bot_id: web::Path<String>) -> HttpResponse {
+    if !is_worker_token(auth.token()) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    // only known ids make it into the path
+    let bot = match get_bot_by_id(bot_id.into_inner()) {
+        Ok(b) => b,
+        Err(_) => return HttpResponse::NotFound().finish(),
+    };
+
+    match zip_compiled_bot(&bot.id) {
+        Ok(archive) => HttpResponse::Ok()
+            .content_type("application/zip")
+            .body(archive),
+        Err(e) => HttpResponse::NotFound().body(e.to_string()),
+    }
+}
\ No newline at end of file
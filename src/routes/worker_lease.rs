@@ -0,0 +1,34 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::match_dispatch::{is_worker_token, lease_remote_game},
+    models::remote_game::RemoteGameLease,
+};
+
+/// Gives a match worker the next game of a running round, `204 No Content` if there is none.
+#[utoipa::path(
+    tag = "workers",
+    request_body = crate::models::remote_game::RemoteGameLease,
+    responses(
+        (status = 200, description = "Success", body = crate::models::remote_game::RemoteGame),
+        (status = 204, description = "Nothing to hand out"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/workers/lease")]
+pub async fn worker_lease(auth: BearerAuth, body: web::Json<RemoteGameLease>) -> HttpResponse {
+    if !is_worker_token(auth.token()) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    if body.worker_id.trim().is_empty() {
+        return HttpResponse::BadRequest().body("Worker id is missing");
+    }
+
+    match lease_remote_game(body.worker_id.trim()) {
+        Some(game) => HttpResponse::Ok().json(game),
+        None => HttpResponse::NoContent().finish(),
+    }
+}
\ No newline at end of file
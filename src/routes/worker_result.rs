@@ -0,0 +1,37 @@
+use actix_web::{HttpResponse, post, web};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use crate::{
+    controllers::match_dispatch::{is_worker_token, complete_remote_game},
+    models::remote_game::RemoteGameResult,
+};
+
+/// Takes the output of a game a match worker played. Read as raw bytes, replays are far
+/// bigger than the JSON limit of the other routes.
+#[utoipa::path(
+    tag = "workers",
+    request_body = crate::models::remote_game::RemoteGameResult,
+    responses(
+        (status = 200, description = "Success"),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Missing or invalid credentials"),
+        (status = 409, description = "Conflicts with the current state"),
+    ),
+    security(("bearer" = [])),
+)]
+#[post("/workers/result")]
+pub async fn worker_result(auth: BearerAuth, body: web::Bytes) -> HttpResponse {
+    if !is_worker_token(auth.token()) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let result: RemoteGameResult = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(e) => return HttpResponse::BadRequest().body(e.to_string()),
+    };
+
+    if complete_remote_game(result) {
+        HttpResponse::Ok().finish()
+    } else {
+        HttpResponse::Conflict().body("The game was given to another worker")
+    }
+}
\ No newline at end of file